@@ -738,6 +738,14 @@ fn codegen_assert_terminator(
                 // `#[track_caller]` adds an implicit argument.
                 (LangItem::PanicInvalidEnumConstruction, vec![source, location])
             }
+            AssertKind::InvalidScalarValue { value, start, end } => {
+                let value = self.codegen_operand(bx, value).immediate();
+                let start = self.codegen_operand(bx, start).immediate();
+                let end = self.codegen_operand(bx, end).immediate();
+                // It's `fn panic_invalid_scalar_value(value: u128, start: u128, end: u128)`,
+                // `#[track_caller]` adds an implicit argument.
+                (LangItem::PanicInvalidScalarValue, vec![value, start, end, location])
+            }
             _ => {
                 // It's `pub fn panic_...()` and `#[track_caller]` adds an implicit argument.
                 (msg.panic_function(), vec![location])
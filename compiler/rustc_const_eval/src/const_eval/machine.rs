@@ -550,6 +550,11 @@ fn assert_panic(
             },
             NullPointerDereference => NullPointerDereference,
             InvalidEnumConstruction(source) => InvalidEnumConstruction(eval_to_int(source)?),
+            InvalidScalarValue { value, start, end } => InvalidScalarValue {
+                value: eval_to_int(value)?,
+                start: eval_to_int(start)?,
+                end: eval_to_int(end)?,
+            },
         };
         Err(ConstEvalErrKind::AssertFailure(err)).into()
     }
@@ -576,6 +576,7 @@ pub(super) fn init_fn_call(
             | ty::InstanceKind::CloneShim(..)
             | ty::InstanceKind::FnPtrAddrShim(..)
             | ty::InstanceKind::ThreadLocalShim(..)
+            | ty::InstanceKind::ColdPathShim(..)
             | ty::InstanceKind::AsyncDropGlueCtorShim(..)
             | ty::InstanceKind::AsyncDropGlue(..)
             | ty::InstanceKind::FutureDropPollShim(..)
@@ -93,11 +93,13 @@
 use std::{fs, process};
 
 pub use measureme::EventId;
-use measureme::{EventIdBuilder, Profiler, SerializableString, StringId};
+use measureme::{Profiler, StringId};
 use parking_lot::RwLock;
 use smallvec::SmallVec;
 use tracing::warn;
 
+pub use self::chrome::TraceString;
+use self::chrome::{ChromeTrace, IntervalGuard, TraceEventId};
 use crate::fx::FxHashMap;
 use crate::outline;
 use crate::sync::AtomicU64;
@@ -156,6 +158,15 @@ struct EventFilter: u16 {
 /// Something that uniquely identifies a query invocation.
 pub struct QueryInvocationId(pub u32);
 
+/// Which format to use for `-Z self-profile`
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum SelfProfileFormat {
+    /// Emit `measureme` profiling data, to be processed with its tools
+    MmProfdata,
+    /// Emit a Chrome trace, in the Trace Event Format
+    ChromeJson,
+}
+
 /// Which format to use for `-Z time-passes`
 #[derive(Clone, Copy, PartialEq, Hash, Debug)]
 pub enum TimePassesFormat {
@@ -284,7 +295,7 @@ pub fn generic_activity_with_arg<A>(
         A: Borrow<str> + Into<String>,
     {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
             let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
                 let event_arg = profiler.get_or_alloc_cached_string(event_arg);
@@ -327,7 +338,7 @@ pub fn generic_activity_with_arg_recorder<F>(
     {
         // Ensure this event will only be recorded when self-profiling is turned on.
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
 
             // Ensure the closure to create event arguments will only be called when argument
@@ -366,18 +377,27 @@ pub fn artifact_size<A>(&self, artifact_kind: &str, artifact_name: A, size: u64)
         A: Borrow<str> + Into<String>,
     {
         drop(self.exec(EventFilter::ARTIFACT_SIZES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(artifact_kind);
             let event_arg = profiler.get_or_alloc_cached_string(artifact_name);
             let event_id = builder.from_label_and_arg(event_label, event_arg);
             let thread_id = get_thread_id();
 
-            profiler.profiler.record_integer_event(
-                profiler.artifact_size_event_kind,
-                event_id,
-                thread_id,
-                size,
-            );
+            if let Some(chrome_trace) = &profiler.chrome_trace {
+                chrome_trace.record_integer_event(
+                    profiler.artifact_size_event_kind,
+                    TraceEventId::Concrete(event_id),
+                    thread_id,
+                    size,
+                );
+            } else {
+                profiler.profiler.record_integer_event(
+                    profiler.artifact_size_event_kind,
+                    event_id,
+                    thread_id,
+                    size,
+                );
+            }
 
             TimingGuard::none()
         }))
@@ -390,7 +410,7 @@ pub fn generic_activity_with_args(
         event_args: &[String],
     ) -> TimingGuard<'_> {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let event_label = profiler.get_or_alloc_cached_string(event_label);
             let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
                 let event_args: Vec<_> = event_args
@@ -485,9 +505,14 @@ fn instant_query_event(
         event_kind: fn(&SelfProfiler) -> StringId,
         query_invocation_id: QueryInvocationId,
     ) {
-        let event_id = StringId::new_virtual(query_invocation_id.0);
         let thread_id = get_thread_id();
         let profiler = self.profiler.as_ref().unwrap();
+        if let Some(chrome_trace) = &profiler.chrome_trace {
+            let event_id = TraceEventId::Query(query_invocation_id.0);
+            chrome_trace.record_instant_event(event_kind(profiler), event_id, thread_id);
+            return;
+        }
+        let event_id = StringId::new_virtual(query_invocation_id.0);
         profiler.profiler.record_instant_event(
             event_kind(profiler),
             EventId::from_virtual(event_id),
@@ -519,12 +544,21 @@ pub fn store_query_cache_hits(&self) {
         if self.event_filter_mask.contains(EventFilter::QUERY_CACHE_HIT_COUNTS) {
             let profiler = self.profiler.as_ref().unwrap();
             let query_hits = profiler.query_hits.read();
-            let builder = EventIdBuilder::new(&profiler.profiler);
+            let builder = profiler.event_id_builder();
             let thread_id = get_thread_id();
             for (query_invocation, hit_count) in query_hits.iter().enumerate() {
                 let hit_count = hit_count.load(Ordering::Relaxed);
                 // No need to record empty cache hit counts
                 if hit_count > 0 {
+                    if let Some(chrome_trace) = &profiler.chrome_trace {
+                        chrome_trace.record_integer_event(
+                            profiler.query_cache_hit_count_event_kind,
+                            TraceEventId::Query(query_invocation as u32),
+                            thread_id,
+                            hit_count,
+                        );
+                        continue;
+                    }
                     let event_id =
                         builder.from_label(StringId::new_virtual(query_invocation as u64));
                     profiler.profiler.record_integer_event(
@@ -589,6 +623,13 @@ pub struct SelfProfiler {
     profiler: Profiler,
     event_filter_mask: EventFilter,
 
+    /// The events to write as a Chrome trace, with `-Z self-profile-format=chrome-json`.
+    chrome_trace: Option<ChromeTrace>,
+    /// With `-Z self-profile-format=chrome-json`, `profiler` is only used to allocate strings, so
+    /// its own files go to a temporary directory, with the events streamed by `chrome_trace`.
+    /// Declared after `profiler` so that it outlives the files written when `profiler` is dropped.
+    _measureme_dir: Option<tempfile::TempDir>,
+
     string_cache: RwLock<FxHashMap<String, StringId>>,
 
     /// Recording individual query cache hits as "instant" measureme events
@@ -621,6 +662,7 @@ pub fn new(
         crate_name: Option<&str>,
         event_filters: Option<&[String]>,
         counter_name: &str,
+        format: SelfProfileFormat,
     ) -> Result<SelfProfiler, Box<dyn Error + Send + Sync>> {
         fs::create_dir_all(output_directory)?;
 
@@ -630,19 +672,43 @@ pub fn new(
         // ASLR is disabled and the heap is otherwise deterministic.
         let pid: u32 = process::id();
         let filename = format!("{crate_name}-{pid:07}.rustc_profile");
-        let path = output_directory.join(filename);
+        let (path, chrome_trace, measureme_dir) = match format {
+            SelfProfileFormat::MmProfdata => (output_directory.join(filename), None, None),
+            SelfProfileFormat::ChromeJson => {
+                if counter_name != "wall-time" {
+                    return Err(format!(
+                        "the `{counter_name}` counter is not supported with the `chrome-json` \
+                         format, which only records wall time"
+                    )
+                    .into());
+                }
+                let measureme_dir = tempfile::Builder::new().prefix("rustc").tempdir()?;
+                let chrome_trace = ChromeTrace::new(
+                    output_directory.join(filename + ".json"),
+                    measureme_dir.path().join("chrome-events"),
+                )?;
+                let path = measureme_dir.path().join("profile");
+                (path, Some(chrome_trace), Some(measureme_dir))
+            }
+        };
         let profiler =
             Profiler::with_counter(&path, measureme::counters::Counter::by_name(counter_name)?)?;
 
-        let query_event_kind = profiler.alloc_string("Query");
-        let generic_activity_event_kind = profiler.alloc_string("GenericActivity");
-        let incremental_load_result_event_kind = profiler.alloc_string("IncrementalLoadResult");
-        let incremental_result_hashing_event_kind =
-            profiler.alloc_string("IncrementalResultHashing");
-        let query_blocked_event_kind = profiler.alloc_string("QueryBlocked");
-        let query_cache_hit_event_kind = profiler.alloc_string("QueryCacheHit");
-        let artifact_size_event_kind = profiler.alloc_string("ArtifactSize");
-        let query_cache_hit_count_event_kind = profiler.alloc_string("QueryCacheHitCount");
+        let alloc_string = |s: &str| {
+            let string_id = profiler.alloc_string(s);
+            if let Some(chrome_trace) = &chrome_trace {
+                chrome_trace.record_string(string_id, s);
+            }
+            string_id
+        };
+        let query_event_kind = alloc_string("Query");
+        let generic_activity_event_kind = alloc_string("GenericActivity");
+        let incremental_load_result_event_kind = alloc_string("IncrementalLoadResult");
+        let incremental_result_hashing_event_kind = alloc_string("IncrementalResultHashing");
+        let query_blocked_event_kind = alloc_string("QueryBlocked");
+        let query_cache_hit_event_kind = alloc_string("QueryCacheHit");
+        let artifact_size_event_kind = alloc_string("ArtifactSize");
+        let query_cache_hit_count_event_kind = alloc_string("QueryCacheHitCount");
 
         let mut event_filter_mask = EventFilter::empty();
 
@@ -680,6 +746,8 @@ pub fn new(
         Ok(SelfProfiler {
             profiler,
             event_filter_mask,
+            chrome_trace,
+            _measureme_dir: measureme_dir,
             string_cache: RwLock::new(FxHashMap::default()),
             query_event_kind,
             generic_activity_event_kind,
@@ -695,8 +763,12 @@ pub fn new(
 
     /// Allocates a new string in the profiling data. Does not do any caching
     /// or deduplication.
-    pub fn alloc_string<STR: SerializableString + ?Sized>(&self, s: &STR) -> StringId {
-        self.profiler.alloc_string(s)
+    pub fn alloc_string<STR: TraceString + ?Sized>(&self, s: &STR) -> StringId {
+        let string_id = self.profiler.alloc_string(s);
+        if let Some(chrome_trace) = &self.chrome_trace {
+            chrome_trace.record_string(string_id, s);
+        }
+        string_id
     }
 
     /// Store a cache hit of a query invocation
@@ -741,13 +813,17 @@ pub fn get_or_alloc_cached_string<A>(&self, s: A) -> StringId
         match string_cache.entry(s.into()) {
             Entry::Occupied(e) => *e.get(),
             Entry::Vacant(e) => {
-                let string_id = self.profiler.alloc_string(&e.key()[..]);
+                let string_id = self.alloc_string(&e.key()[..]);
                 *e.insert(string_id)
             }
         }
     }
 
     pub fn map_query_invocation_id_to_string(&self, from: QueryInvocationId, to: StringId) {
+        if let Some(chrome_trace) = &self.chrome_trace {
+            chrome_trace.map_query_invocation_id_to_string(from.0, to);
+            return;
+        }
         let from = StringId::new_virtual(from.0);
         self.profiler.map_virtual_to_concrete_string(from, to);
     }
@@ -756,6 +832,12 @@ pub fn bulk_map_query_invocation_id_to_single_string<I>(&self, from: I, to: Stri
     where
         I: Iterator<Item = QueryInvocationId> + ExactSizeIterator,
     {
+        if let Some(chrome_trace) = &self.chrome_trace {
+            for qid in from {
+                chrome_trace.map_query_invocation_id_to_string(qid.0, to);
+            }
+            return;
+        }
         let from = from.map(|qid| StringId::new_virtual(qid.0));
         self.profiler.bulk_map_virtual_to_single_concrete_string(from, to);
     }
@@ -765,12 +847,52 @@ pub fn query_key_recording_enabled(&self) -> bool {
     }
 
     pub fn event_id_builder(&self) -> EventIdBuilder<'_> {
-        EventIdBuilder::new(&self.profiler)
+        EventIdBuilder { profiler: self, builder: measureme::EventIdBuilder::new(&self.profiler) }
+    }
+}
+
+impl Drop for SelfProfiler {
+    fn drop(&mut self) {
+        if let Some(chrome_trace) = &self.chrome_trace
+            && let Err(err) = chrome_trace.write()
+        {
+            warn!("failed to write the self-profile Chrome trace: {err}");
+        }
+    }
+}
+
+/// Creates the `EventId`s of events with arguments, see [`measureme::EventIdBuilder`].
+pub struct EventIdBuilder<'p> {
+    profiler: &'p SelfProfiler,
+    builder: measureme::EventIdBuilder<'p>,
+}
+
+impl EventIdBuilder<'_> {
+    #[inline]
+    pub fn from_label(&self, label: StringId) -> EventId {
+        self.builder.from_label(label)
+    }
+
+    pub fn from_label_and_arg(&self, label: StringId, arg: StringId) -> EventId {
+        self.from_label_and_args(label, &[arg])
+    }
+
+    pub fn from_label_and_args(&self, label: StringId, args: &[StringId]) -> EventId {
+        let event_id = self.builder.from_label_and_args(label, args);
+        if let Some(chrome_trace) = &self.profiler.chrome_trace {
+            chrome_trace.record_event_id(event_id, label, args);
+        }
+        event_id
     }
 }
 
+enum TimingGuardInner<'a> {
+    Measureme(measureme::TimingGuard<'a>),
+    Chrome(IntervalGuard<'a>),
+}
+
 #[must_use]
-pub struct TimingGuard<'a>(Option<measureme::TimingGuard<'a>>);
+pub struct TimingGuard<'a>(Option<TimingGuardInner<'a>>);
 
 impl<'a> TimingGuard<'a> {
     #[inline]
@@ -780,19 +902,29 @@ pub fn start(
         event_id: EventId,
     ) -> TimingGuard<'a> {
         let thread_id = get_thread_id();
+        if let Some(chrome_trace) = &profiler.chrome_trace {
+            let event_id = TraceEventId::Concrete(event_id);
+            let guard = chrome_trace.start_interval_event(event_kind, event_id, thread_id);
+            return TimingGuard(Some(TimingGuardInner::Chrome(guard)));
+        }
         let raw_profiler = &profiler.profiler;
         let timing_guard =
             raw_profiler.start_recording_interval_event(event_kind, event_id, thread_id);
-        TimingGuard(Some(timing_guard))
+        TimingGuard(Some(TimingGuardInner::Measureme(timing_guard)))
     }
 
     #[inline]
     pub fn finish_with_query_invocation_id(self, query_invocation_id: QueryInvocationId) {
         if let Some(guard) = self.0 {
-            outline(|| {
-                let event_id = StringId::new_virtual(query_invocation_id.0);
-                let event_id = EventId::from_virtual(event_id);
-                guard.finish_with_override_event_id(event_id);
+            outline(|| match guard {
+                TimingGuardInner::Measureme(guard) => {
+                    let event_id = StringId::new_virtual(query_invocation_id.0);
+                    let event_id = EventId::from_virtual(event_id);
+                    guard.finish_with_override_event_id(event_id);
+                }
+                TimingGuardInner::Chrome(guard) => {
+                    guard.finish_with_query_invocation_id(query_invocation_id.0)
+                }
             });
         }
     }
@@ -1010,5 +1142,6 @@ pub fn get_resident_set_size() -> Option<usize> {
     }
 }
 
+mod chrome;
 #[cfg(test)]
 mod tests;
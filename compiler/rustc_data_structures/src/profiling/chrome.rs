@@ -0,0 +1,471 @@
+//! Support for `-Z self-profile-format=chrome-json`, which writes the self-profiling events in
+//! the [Trace Event Format] understood by Perfetto and `chrome://tracing`, instead of the
+//! `measureme` format that needs separate tools to be post-processed.
+//!
+//! Strings are still allocated with `measureme`, because `StringId`s are used throughout the
+//! compiler to refer to them, but the trace keeps its own copy of every string that goes through
+//! the `SelfProfiler`. The events are buffered per thread and streamed to a temporary file in a
+//! compact binary form, so memory use does not grow with the length of the session and threads do
+//! not contend on a lock for every event. When the profiler is dropped, the events are read back
+//! and written out with their `StringId`s resolved. That is after the query strings have been
+//! allocated, so query events get the name of their query, and their key as an argument if query
+//! keys are recorded.
+//!
+//! [Trace Event Format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU/
+
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+use std::{mem, process, thread};
+
+use measureme::event_id::SEPARATOR_BYTE;
+use measureme::{EventId, SerializableString, StringComponent, StringId};
+use parking_lot::Mutex;
+
+use crate::fx::FxHashMap;
+
+/// The trace's copy of a string component, see [`StringComponent`].
+enum Component {
+    Value(Box<str>),
+    Ref(StringId),
+}
+
+/// A string that can be allocated through the `SelfProfiler`, and therefore copied into the
+/// trace.
+pub trait TraceString: SerializableString {
+    fn components(&self) -> Vec<StringComponent<'_>>;
+}
+
+impl TraceString for str {
+    fn components(&self) -> Vec<StringComponent<'_>> {
+        vec![StringComponent::Value(self)]
+    }
+}
+
+impl TraceString for [StringComponent<'_>] {
+    fn components(&self) -> Vec<StringComponent<'_>> {
+        // `StringComponent` is not `Clone`.
+        self.iter()
+            .map(|component| match *component {
+                StringComponent::Value(value) => StringComponent::Value(value),
+                StringComponent::Ref(string_id) => StringComponent::Ref(string_id),
+            })
+            .collect()
+    }
+}
+
+// `measureme` only implements `SerializableString` for arrays of up to 16 components.
+macro_rules! impl_trace_string_for_arrays {
+    ($($n:literal)*) => {$(
+        impl TraceString for [StringComponent<'_>; $n] {
+            fn components(&self) -> Vec<StringComponent<'_>> {
+                self[..].components()
+            }
+        }
+    )*};
+}
+
+impl_trace_string_for_arrays!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16);
+
+/// What an event is about, which only gets resolved to a string when the trace is written.
+#[derive(Clone, Copy)]
+pub(super) enum TraceEventId {
+    Concrete(EventId),
+    /// The event ID of a query invocation, only mapped to an actual string once the query
+    /// strings are allocated at the end of the compilation session.
+    Query(u32),
+}
+
+enum Payload {
+    Interval { end: u64 },
+    Instant,
+    Integer(u64),
+}
+
+struct TraceEvent {
+    kind: StringId,
+    id: TraceEventId,
+    thread_id: u32,
+    /// Nanoseconds since the profiler was created.
+    start: u64,
+    payload: Payload,
+}
+
+impl TraceEvent {
+    /// The size of an encoded event: the kind, the tag and value of the ID, the thread ID, the
+    /// start, and the tag and value of the payload.
+    const ENCODED_LEN: usize = 8 + 1 + 8 + 4 + 8 + 1 + 8;
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        let (id_tag, id) = match self.id {
+            TraceEventId::Concrete(event_id) => (0u8, event_id.to_string_id().as_u64()),
+            TraceEventId::Query(query_invocation_id) => (1, query_invocation_id.into()),
+        };
+        let (payload_tag, payload) = match self.payload {
+            Payload::Interval { end } => (0u8, end),
+            Payload::Instant => (1, 0),
+            Payload::Integer(value) => (2, value),
+        };
+        out.extend_from_slice(&self.kind.as_u64().to_le_bytes());
+        out.push(id_tag);
+        out.extend_from_slice(&id.to_le_bytes());
+        out.extend_from_slice(&self.thread_id.to_le_bytes());
+        out.extend_from_slice(&self.start.to_le_bytes());
+        out.push(payload_tag);
+        out.extend_from_slice(&payload.to_le_bytes());
+    }
+
+    fn decode(bytes: &[u8; Self::ENCODED_LEN]) -> TraceEvent {
+        let u32_at = |i: usize| u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap());
+        let u64_at = |i: usize| u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap());
+        let id = match bytes[8] {
+            0 => TraceEventId::Concrete(EventId::from_label(StringId::new(u64_at(9)))),
+            _ => TraceEventId::Query(u64_at(9) as u32),
+        };
+        let payload = match bytes[29] {
+            0 => Payload::Interval { end: u64_at(30) },
+            1 => Payload::Instant,
+            _ => Payload::Integer(u64_at(30)),
+        };
+        TraceEvent {
+            kind: StringId::new(u64_at(0)),
+            id,
+            thread_id: u32_at(17),
+            start: u64_at(21),
+            payload,
+        }
+    }
+}
+
+/// How many bytes of events a thread buffers before it writes them to the events file.
+const THREAD_BUFFER_LEN: usize = 64 * 1024;
+
+/// The source of the IDs of the traces, which tell apart the buffers of each trace in
+/// [`THREAD_BUFFERS`].
+static NEXT_TRACE_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The buffer of the current thread for every trace it recorded events into, by trace ID.
+    static THREAD_BUFFERS: RefCell<FxHashMap<usize, Arc<Mutex<Vec<u8>>>>> =
+        RefCell::new(FxHashMap::default());
+}
+
+/// The events that were written out of the thread buffers, and the first error writing them.
+struct EventsFile {
+    file: File,
+    result: io::Result<()>,
+}
+
+pub(super) struct ChromeTrace {
+    path: PathBuf,
+    id: usize,
+    start_time: Instant,
+    strings: Mutex<FxHashMap<StringId, Vec<Component>>>,
+    query_strings: Mutex<FxHashMap<u32, StringId>>,
+    /// The name of every thread that recorded an event, so that each of them gets its own named
+    /// track, e.g. one per worker thread of the parallel frontend.
+    thread_names: Mutex<FxHashMap<u32, String>>,
+    /// The buffers of every thread that recorded an event. Each thread only locks its own buffer
+    /// for every event, so the buffers are only contended when the trace is written.
+    thread_buffers: Mutex<Vec<Arc<Mutex<Vec<u8>>>>>,
+    events: Mutex<EventsFile>,
+}
+
+impl ChromeTrace {
+    /// Creates a trace that will be written to `path`, streaming the recorded events to
+    /// `events_path` until then.
+    pub(super) fn new(path: PathBuf, events_path: PathBuf) -> io::Result<ChromeTrace> {
+        let file = File::options().read(true).write(true).create_new(true).open(events_path)?;
+        Ok(ChromeTrace {
+            path,
+            id: NEXT_TRACE_ID.fetch_add(1, Ordering::Relaxed),
+            start_time: Instant::now(),
+            strings: Default::default(),
+            query_strings: Default::default(),
+            thread_names: Default::default(),
+            thread_buffers: Default::default(),
+            events: Mutex::new(EventsFile { file, result: Ok(()) }),
+        })
+    }
+
+    pub(super) fn record_string<STR: TraceString + ?Sized>(&self, string_id: StringId, s: &STR) {
+        let components = s
+            .components()
+            .into_iter()
+            .map(|component| match component {
+                StringComponent::Value(value) => Component::Value(value.into()),
+                StringComponent::Ref(string_id) => Component::Ref(string_id),
+            })
+            .collect();
+        self.strings.lock().insert(string_id, components);
+    }
+
+    /// Records the string of an event ID made of a label and arguments.
+    pub(super) fn record_event_id(&self, event_id: EventId, label: StringId, args: &[StringId]) {
+        let mut components = vec![Component::Ref(label)];
+        for &arg in args {
+            components.push(Component::Value(SEPARATOR_BYTE.into()));
+            components.push(Component::Ref(arg));
+        }
+        self.strings.lock().insert(event_id.to_string_id(), components);
+    }
+
+    pub(super) fn map_query_invocation_id_to_string(&self, from: u32, to: StringId) {
+        self.query_strings.lock().insert(from, to);
+    }
+
+    fn now(&self) -> u64 {
+        self.start_time.elapsed().as_nanos() as u64
+    }
+
+    fn record(&self, event: TraceEvent) {
+        let buffer = THREAD_BUFFERS.with_borrow_mut(|buffers| {
+            let buffer = buffers.entry(self.id).or_insert_with(|| {
+                let thread = thread::current();
+                let name = thread
+                    .name()
+                    .map_or_else(|| format!("thread {}", event.thread_id), str::to_owned);
+                self.thread_names.lock().insert(event.thread_id, name);
+
+                let buffer = Arc::new(Mutex::new(Vec::with_capacity(THREAD_BUFFER_LEN)));
+                self.thread_buffers.lock().push(Arc::clone(&buffer));
+                buffer
+            });
+            Arc::clone(buffer)
+        });
+
+        let mut buffer = buffer.lock();
+        event.encode(&mut buffer);
+        if buffer.len() + TraceEvent::ENCODED_LEN > THREAD_BUFFER_LEN {
+            self.flush(&mut buffer);
+        }
+    }
+
+    /// Moves the events of a thread buffer to the events file.
+    fn flush(&self, buffer: &mut Vec<u8>) {
+        let mut events = self.events.lock();
+        if events.result.is_ok() {
+            events.result = events.file.write_all(buffer);
+        }
+        buffer.clear();
+    }
+
+    pub(super) fn start_interval_event(
+        &self,
+        kind: StringId,
+        id: TraceEventId,
+        thread_id: u32,
+    ) -> IntervalGuard<'_> {
+        IntervalGuard { trace: self, kind, id, thread_id, start: self.now() }
+    }
+
+    pub(super) fn record_instant_event(&self, kind: StringId, id: TraceEventId, thread_id: u32) {
+        let start = self.now();
+        self.record(TraceEvent { kind, id, thread_id, start, payload: Payload::Instant });
+    }
+
+    pub(super) fn record_integer_event(
+        &self,
+        kind: StringId,
+        id: TraceEventId,
+        thread_id: u32,
+        value: u64,
+    ) {
+        let start = self.now();
+        self.record(TraceEvent { kind, id, thread_id, start, payload: Payload::Integer(value) });
+    }
+
+    fn resolve(
+        &self,
+        strings: &FxHashMap<StringId, Vec<Component>>,
+        string_id: StringId,
+    ) -> String {
+        let mut s = String::new();
+        // The components are pushed in reverse so that they are popped in order.
+        let mut pending: Vec<Result<&str, StringId>> = vec![Err(string_id)];
+        while let Some(next) = pending.pop() {
+            match next {
+                Ok(value) => s.push_str(value),
+                Err(string_id) => match strings.get(&string_id) {
+                    Some(components) => {
+                        pending.extend(components.iter().rev().map(|component| match component {
+                            Component::Value(value) => Ok(&**value),
+                            Component::Ref(string_id) => Err(*string_id),
+                        }))
+                    }
+                    None => s.push_str("<unknown>"),
+                },
+            }
+        }
+        s
+    }
+
+    fn event_text(
+        &self,
+        strings: &FxHashMap<StringId, Vec<Component>>,
+        query_strings: &FxHashMap<u32, StringId>,
+        id: TraceEventId,
+    ) -> String {
+        match id {
+            TraceEventId::Concrete(event_id) if event_id == EventId::INVALID => "<unknown>".into(),
+            TraceEventId::Concrete(event_id) => self.resolve(strings, event_id.to_string_id()),
+            TraceEventId::Query(query_invocation_id) => {
+                match query_strings.get(&query_invocation_id) {
+                    Some(&string_id) => self.resolve(strings, string_id),
+                    None => "<unknown query>".into(),
+                }
+            }
+        }
+    }
+
+    /// Writes the trace as a JSON object, with every event resolved to a name and arguments.
+    pub(super) fn write(&self) -> io::Result<()> {
+        for buffer in self.thread_buffers.lock().iter() {
+            let mut buffer = buffer.lock();
+            self.flush(&mut buffer);
+            // The thread may outlive the trace, so don't keep the memory of its buffer around.
+            *buffer = Vec::new();
+        }
+        let mut events = self.events.lock();
+        mem::replace(&mut events.result, Ok(()))?;
+        events.file.seek(SeekFrom::Start(0))?;
+        let mut events = BufReader::new(&events.file);
+
+        let strings = self.strings.lock();
+        let query_strings = self.query_strings.lock();
+        let pid = process::id();
+
+        let mut file = BufWriter::new(File::create(&self.path)?);
+        write!(file, "{{\"traceEvents\":[")?;
+
+        let thread_names = self.thread_names.lock();
+        let mut thread_names: Vec<_> = thread_names.iter().collect();
+        thread_names.sort();
+        let mut first = true;
+        for (thread_id, name) in thread_names {
+            if !first {
+                write!(file, ",")?;
+            }
+            first = false;
+            write!(file, "\n{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":{pid},")?;
+            write!(file, "\"tid\":{thread_id},\"args\":{{\"name\":")?;
+            write_json_string(&mut file, name)?;
+            write!(file, "}}}}")?;
+        }
+
+        let mut bytes = [0; TraceEvent::ENCODED_LEN];
+        loop {
+            match events.read_exact(&mut bytes) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err),
+            }
+            let event = TraceEvent::decode(&bytes);
+            if !first {
+                write!(file, ",")?;
+            }
+            first = false;
+
+            let text = self.event_text(&strings, &query_strings, event.id);
+            let mut parts = text.split(SEPARATOR_BYTE);
+            let name = parts.next().unwrap_or_default();
+            let kind = self.resolve(&strings, event.kind);
+
+            write!(file, "\n{{\"name\":")?;
+            write_json_string(&mut file, name)?;
+            write!(file, ",\"cat\":")?;
+            write_json_string(&mut file, &kind)?;
+            write!(file, ",\"pid\":{pid},\"tid\":{},\"ts\":", event.thread_id)?;
+            write_micros(&mut file, event.start)?;
+            match event.payload {
+                Payload::Interval { end } => {
+                    write!(file, ",\"ph\":\"X\",\"dur\":")?;
+                    write_micros(&mut file, end - event.start)?;
+                }
+                Payload::Instant | Payload::Integer(_) => {
+                    write!(file, ",\"ph\":\"i\",\"s\":\"t\"")?
+                }
+            }
+
+            write!(file, ",\"args\":{{")?;
+            let mut first_arg = true;
+            for (i, arg) in parts.enumerate() {
+                if !first_arg {
+                    write!(file, ",")?;
+                }
+                first_arg = false;
+                // Query events have a single argument, the query key.
+                if let TraceEventId::Query(_) = event.id {
+                    write!(file, "\"key\":")?;
+                } else {
+                    write!(file, "\"arg{i}\":")?;
+                }
+                write_json_string(&mut file, arg)?;
+            }
+            if let Payload::Integer(value) = event.payload {
+                if !first_arg {
+                    write!(file, ",")?;
+                }
+                write!(file, "\"value\":{value}")?;
+            }
+            write!(file, "}}}}")?;
+        }
+
+        writeln!(file, "\n],\"displayTimeUnit\":\"ms\"}}")?;
+        file.flush()
+    }
+}
+
+/// Records an interval event when dropped.
+pub(super) struct IntervalGuard<'a> {
+    trace: &'a ChromeTrace,
+    kind: StringId,
+    id: TraceEventId,
+    thread_id: u32,
+    start: u64,
+}
+
+impl IntervalGuard<'_> {
+    pub(super) fn finish_with_query_invocation_id(mut self, query_invocation_id: u32) {
+        self.id = TraceEventId::Query(query_invocation_id);
+        // The event is recorded when `self` is dropped.
+    }
+}
+
+impl Drop for IntervalGuard<'_> {
+    fn drop(&mut self) {
+        let end = self.trace.now();
+        self.trace.record(TraceEvent {
+            kind: self.kind,
+            id: self.id,
+            thread_id: self.thread_id,
+            start: self.start,
+            payload: Payload::Interval { end },
+        });
+    }
+}
+
+/// Writes nanoseconds as microseconds, the unit of the trace's timestamps.
+fn write_micros(out: &mut impl Write, nanos: u64) -> io::Result<()> {
+    write!(out, "{}.{:03}", nanos / 1000, nanos % 1000)
+}
+
+fn write_json_string(out: &mut impl Write, s: &str) -> io::Result<()> {
+    write!(out, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(out, "\\\"")?,
+            '\\' => write!(out, "\\\\")?,
+            '\n' => write!(out, "\\n")?,
+            '\r' => write!(out, "\\r")?,
+            '\t' => write!(out, "\\t")?,
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32)?,
+            c => write!(out, "{c}")?,
+        }
+    }
+    write!(out, "\"")
+}
@@ -247,6 +247,9 @@ pub fn run_compiler(at_args: &[String], callbacks: &mut (dyn Callbacks + Send))
     let has_input = input.is_some();
     let (odir, ofile) = make_output(&matches);
 
+    // With `--error-format=sarif`, the session's diagnostics go to the same log as those of
+    // `default_early_dcx`.
+    let sarif_log = default_early_dcx.sarif_log();
     drop(default_early_dcx);
 
     let mut config = interface::Config {
@@ -269,6 +272,7 @@ pub fn run_compiler(at_args: &[String], callbacks: &mut (dyn Callbacks + Send))
         registry: diagnostics_registry(),
         using_internal_features: &USING_INTERNAL_FEATURES,
         expanded_args: args,
+        sarif_log,
     };
 
     callbacks.config(&mut config);
@@ -0,0 +1,456 @@
+//! A [SARIF] emitter for errors.
+//!
+//! SARIF is the interchange format understood by code scanning platforms and the problem panes
+//! of many editors. Unlike the JSON emitter, which writes every diagnostic as soon as it is
+//! emitted, a SARIF log is a single document. So diagnostics are collected as they come in, and
+//! the log, with one `run` for the crate being compiled, is written once the emitter is dropped.
+//! An invocation of the compiler creates several diagnostic contexts, e.g. for the diagnostics
+//! issued before the session exists, so their emitters share a single log, see
+//! [`SarifEmitter::with_log`].
+//!
+//! Each diagnostic becomes a `result`:
+//! - its error code or lint name is the `ruleId`, with the rule listed in the tool's `rules`;
+//! - primary spans are its `locations`, while secondary spans and spans of subdiagnostics are
+//!   its `relatedLocations`;
+//! - machine applicable suggestions are its `fixes`. Other suggestions and subdiagnostics
+//!   without a span are appended to the message text, as they would be in the human output.
+//!
+//! [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use std::error::Report;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use derive_setters::Setters;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_data_structures::sync::IntoDynSyncSend;
+use rustc_error_messages::FluentArgs;
+use rustc_lint_defs::Applicability;
+use rustc_span::Span;
+use rustc_span::source_map::SourceMap;
+use serde::Serialize;
+
+use crate::diagnostic::IsLint;
+use crate::emitter::Emitter;
+use crate::registry::Registry;
+use crate::translation::{Translator, to_fluent_args};
+use crate::{CodeSuggestion, DiagInner, Level, MultiSpan, Substitution, Suggestions};
+
+#[cfg(test)]
+mod tests;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+#[derive(Setters)]
+pub struct SarifEmitter {
+    #[setters(skip)]
+    log: SharedLog,
+    #[setters(skip)]
+    sm: Option<Arc<SourceMap>>,
+    #[setters(skip)]
+    translator: Translator,
+    /// Whether to write a log even if nothing was emitted. Emitters for diagnostics issued before
+    /// the session exists turn this off, since the session's own emitter writes the crate's log.
+    write_empty_log: bool,
+}
+
+/// A log that the emitters of several diagnostic contexts collect their diagnostics in. It is
+/// written once the last emitter and handle that refer to it are dropped.
+#[derive(Clone)]
+pub struct SharedLog(Arc<Mutex<Log>>);
+
+impl SharedLog {
+    pub fn new(dst: Box<dyn Write + Send>) -> SharedLog {
+        let log = Log {
+            dst: IntoDynSyncSend(dst),
+            write_empty_log: false,
+            rules: FxIndexMap::default(),
+            results: Vec::new(),
+        };
+        SharedLog(Arc::new(Mutex::new(log)))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Log> {
+        // Emitting a diagnostic doesn't leave the log in an inconsistent state if it panics.
+        self.0.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// The diagnostics collected for a log.
+struct Log {
+    dst: IntoDynSyncSend<Box<dyn Write + Send>>,
+    /// Whether any of the emitters of this log wants it written even if nothing was emitted.
+    write_empty_log: bool,
+    /// The rules that `results` refer to, by id.
+    rules: FxIndexMap<String, ReportingDescriptor>,
+    results: Vec<SarifResult>,
+}
+
+impl SarifEmitter {
+    pub fn new(
+        dst: Box<dyn Write + Send>,
+        sm: Option<Arc<SourceMap>>,
+        translator: Translator,
+    ) -> SarifEmitter {
+        SarifEmitter::with_log(SharedLog::new(dst), sm, translator)
+    }
+
+    /// Makes an emitter that collects its diagnostics in `log`, so that an invocation of the
+    /// compiler writes a single log even if it uses several diagnostic contexts.
+    pub fn with_log(
+        log: SharedLog,
+        sm: Option<Arc<SourceMap>>,
+        translator: Translator,
+    ) -> SarifEmitter {
+        SarifEmitter { log, sm, translator, write_empty_log: true }
+    }
+
+    /// The log this emitter collects its diagnostics in, to pass to [`SarifEmitter::with_log`].
+    pub fn log(&self) -> SharedLog {
+        self.log.clone()
+    }
+
+    fn result(&mut self, diag: DiagInner) -> SarifResult {
+        let args = to_fluent_args(diag.args.iter());
+        let mut text = self.translator.translate_messages(&diag.messages, &args).into_owned();
+
+        let rule_id = if let Some(code) = diag.code {
+            Some((
+                code.to_string(),
+                Some(format!("https://doc.rust-lang.org/error_codes/{code}.html")),
+            ))
+        } else if let Some(IsLint { name, .. }) = &diag.is_lint {
+            Some((name.clone(), None))
+        } else {
+            None
+        };
+        let rule_index = rule_id.as_ref().map(|(id, help_uri)| {
+            let mut log = self.log.lock();
+            let entry = log.rules.entry(id.clone());
+            let index = entry.index();
+            entry.or_insert_with(|| ReportingDescriptor {
+                id: id.clone(),
+                help_uri: help_uri.clone(),
+            });
+            index
+        });
+
+        let (locations, mut related_locations) = self.locations(&diag.span, &args, None);
+        for child in &diag.children {
+            let message = self.translator.translate_messages(&child.messages, &args);
+            if child.span.primary_spans().is_empty() {
+                text.push_str(&format!("\n{}: {message}", child.level.to_str()));
+            } else {
+                let (primary, secondary) = self.locations(&child.span, &args, Some(&message));
+                related_locations.extend(primary);
+                related_locations.extend(secondary);
+            }
+        }
+
+        let suggestions = match &diag.suggestions {
+            Suggestions::Enabled(suggestions) => &suggestions[..],
+            Suggestions::Sealed(suggestions) => &suggestions[..],
+            Suggestions::Disabled => &[],
+        };
+        let mut fixes = Vec::new();
+        for CodeSuggestion { substitutions, msg, applicability, .. } in suggestions {
+            let message =
+                self.translator.translate_message(msg, &args).map_err(Report::new).unwrap();
+            if *applicability == Applicability::MachineApplicable {
+                // Every substitution is an alternative way to apply the suggestion.
+                fixes.extend(substitutions.iter().map(|substitution| Fix {
+                    description: Message { text: message.to_string() },
+                    artifact_changes: self.artifact_changes(substitution),
+                }));
+            } else {
+                text.push_str(&format!("\nhelp: {message}"));
+            }
+        }
+
+        SarifResult {
+            rule_id: rule_id.map(|(id, _)| id),
+            rule_index,
+            level: sarif_level(diag.level),
+            message: Message { text },
+            locations,
+            related_locations,
+            fixes,
+        }
+    }
+
+    /// Returns the locations of the primary and of the secondary spans of `msp`, with their
+    /// labels as message, or `message` for unlabelled spans.
+    fn locations(
+        &self,
+        msp: &MultiSpan,
+        args: &FluentArgs<'_>,
+        message: Option<&str>,
+    ) -> (Vec<Location>, Vec<Location>) {
+        let mut primary = Vec::new();
+        let mut secondary = Vec::new();
+        for span_label in msp.span_labels() {
+            let label = match &span_label.label {
+                Some(label) => {
+                    Some(self.translator.translate_message(label, args).unwrap().to_string())
+                }
+                None => message.map(str::to_owned),
+            };
+            let Some((artifact_location, region)) = self.region(span_label.span) else {
+                continue;
+            };
+            let location = Location {
+                physical_location: PhysicalLocation { artifact_location, region },
+                message: label.map(|text| Message { text }),
+            };
+            if span_label.is_primary {
+                primary.push(location);
+            } else {
+                secondary.push(location);
+            }
+        }
+        (primary, secondary)
+    }
+
+    fn artifact_changes(&self, substitution: &Substitution) -> Vec<ArtifactChange> {
+        let mut changes = FxIndexMap::<String, ArtifactChange>::default();
+        for part in &substitution.parts {
+            let Some((artifact_location, deleted_region)) = self.region(part.span) else {
+                continue;
+            };
+            changes
+                .entry(artifact_location.uri.clone())
+                .or_insert_with(|| ArtifactChange { artifact_location, replacements: Vec::new() })
+                .replacements
+                .push(Replacement {
+                    deleted_region,
+                    inserted_content: ArtifactContent { text: part.snippet.clone() },
+                });
+        }
+        changes.into_values().collect()
+    }
+
+    /// The file and region covered by `span`, if it points to actual source code.
+    fn region(&self, span: Span) -> Option<(ArtifactLocation, Region)> {
+        let sm = self.sm.as_ref()?;
+        if span.is_dummy() {
+            return None;
+        }
+        let start = sm.lookup_char_pos(span.lo());
+        let end = sm.lookup_char_pos(span.hi());
+        let byte_offset = start.file.original_relative_byte_pos(span.lo()).0;
+        let byte_end = start.file.original_relative_byte_pos(span.hi()).0;
+        Some((
+            ArtifactLocation { uri: sm.filename_for_diagnostics(&start.file.name).to_string() },
+            Region {
+                start_line: start.line,
+                start_column: start.col.0 + 1,
+                end_line: end.line,
+                end_column: end.col.0 + 1,
+                byte_offset,
+                byte_length: byte_end - byte_offset,
+            },
+        ))
+    }
+}
+
+fn sarif_level(level: Level) -> &'static str {
+    match level {
+        Level::Bug | Level::Fatal | Level::Error | Level::DelayedBug => "error",
+        Level::ForceWarning | Level::Warning => "warning",
+        Level::Note | Level::OnceNote | Level::Help | Level::OnceHelp | Level::FailureNote => {
+            "note"
+        }
+        Level::Allow | Level::Expect => "none",
+    }
+}
+
+impl Emitter for SarifEmitter {
+    fn emit_diagnostic(&mut self, mut diag: DiagInner, _registry: &Registry) {
+        // Summaries like "aborting due to 2 previous errors" are only meant for humans reading
+        // the rest of the output.
+        if diag.level == Level::FailureNote {
+            return;
+        }
+        // Like the human output, point at the invocations of macros from other crates rather
+        // than into their definitions, which are not part of the code being analysed.
+        self.fix_multispans_in_extern_macros(&mut diag.span, &mut diag.children);
+        let result = self.result(diag);
+        self.log.lock().results.push(result);
+    }
+
+    fn source_map(&self) -> Option<&SourceMap> {
+        self.sm.as_deref()
+    }
+
+    fn translator(&self) -> &Translator {
+        &self.translator
+    }
+}
+
+impl Drop for SarifEmitter {
+    fn drop(&mut self) {
+        if self.write_empty_log {
+            self.log.lock().write_empty_log = true;
+        }
+        // The log itself is written when the last `SharedLog` that refers to it is dropped.
+    }
+}
+
+impl Log {
+    fn write(&mut self) -> io::Result<()> {
+        let log = SarifLog {
+            schema: SCHEMA,
+            version: "2.1.0",
+            runs: [Run {
+                tool: Tool {
+                    driver: ToolComponent {
+                        name: "rustc",
+                        information_uri: "https://www.rust-lang.org/",
+                        rules: self.rules.values().collect(),
+                    },
+                },
+                // Columns are counted in characters, like in the JSON output.
+                column_kind: "unicodeCodePoints",
+                results: &self.results,
+            }],
+        };
+        serde_json::to_writer_pretty(&mut *self.dst, &log)?;
+        self.dst.write_all(b"\n")?;
+        self.dst.flush()
+    }
+}
+
+impl Drop for Log {
+    fn drop(&mut self) {
+        if self.results.is_empty() && !self.write_empty_log {
+            return;
+        }
+        // We may be unwinding from a fatal error, so don't panic. There isn't anywhere else to
+        // report the failure to either.
+        let _ = self.write();
+    }
+}
+
+// The following data types are provided just for serialisation, and follow the names of the
+// SARIF 2.1.0 schema.
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run<'a> {
+    tool: Tool<'a>,
+    column_kind: &'static str,
+    results: &'a [SarifResult],
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: ToolComponent<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent<'a> {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: Vec<&'a ReportingDescriptor>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    /// The error code (e.g. "E0308"), or the lint name.
+    id: String,
+    /// The error code's page in the error index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    /// Index of the rule in the tool's `rules`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    /// "error", "warning", "note" or "none".
+    level: &'static str,
+    message: Message,
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    /// 1-based.
+    start_line: usize,
+    /// 1-based, character offset.
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+    byte_offset: u32,
+    byte_length: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: ArtifactContent,
+}
+
+#[derive(Serialize)]
+struct ArtifactContent {
+    text: String,
+}
@@ -0,0 +1,186 @@
+use std::path::Path;
+use std::str;
+use std::sync::Mutex;
+
+use rustc_span::BytePos;
+use rustc_span::source_map::FilePathMapping;
+use serde_json::{Value, json};
+
+use super::*;
+use crate::codes::E0308;
+use crate::{DiagCtxt, DiagCtxtHandle};
+
+struct Shared<T> {
+    data: Arc<Mutex<T>>,
+}
+
+impl<T: Write> Write for Shared<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.data.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.data.lock().unwrap().flush()
+    }
+}
+
+/// Emits diagnostics about `code` with `f` and returns the resulting SARIF log.
+fn emit_sarif(code: &str, f: impl FnOnce(DiagCtxtHandle<'_>)) -> Value {
+    rustc_span::create_default_session_globals_then(|| {
+        let sm = Arc::new(SourceMap::new(FilePathMapping::empty()));
+        sm.new_source_file(Path::new("test.rs").to_owned().into(), code.to_owned());
+        let translator =
+            Translator::with_fallback_bundle(vec![crate::DEFAULT_LOCALE_RESOURCE], false);
+
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let emitter =
+            SarifEmitter::new(Box::new(Shared { data: output.clone() }), Some(sm), translator);
+        let dcx = DiagCtxt::new(Box::new(emitter));
+        f(dcx.handle());
+        // The log is only written once the emitter goes away.
+        drop(dcx);
+
+        let bytes = output.lock().unwrap();
+        serde_json::from_str(str::from_utf8(&bytes).unwrap()).unwrap()
+    })
+}
+
+fn span(lo: u32, hi: u32) -> Span {
+    Span::with_root_ctxt(BytePos(lo), BytePos(hi))
+}
+
+#[test]
+fn results() {
+    let log = emit_sarif("let x: u8 = 'a';\nlet y = 1;\n", |dcx| {
+        dcx.struct_span_err(span(12, 15), "mismatched types")
+            .with_code(E0308)
+            .with_span_label(span(7, 9), "expected due to this")
+            .with_note("a note")
+            .emit();
+        dcx.struct_span_warn(span(21, 22), "unused variable").emit();
+    });
+
+    assert_eq!(log["version"], "2.1.0");
+    let runs = log["runs"].as_array().unwrap();
+    assert_eq!(runs.len(), 1);
+    let run = &runs[0];
+    assert_eq!(run["columnKind"], "unicodeCodePoints");
+    assert_eq!(
+        run["tool"]["driver"]["rules"],
+        json!([{ "id": "E0308", "helpUri": "https://doc.rust-lang.org/error_codes/E0308.html" }])
+    );
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0],
+        json!({
+            "ruleId": "E0308",
+            "ruleIndex": 0,
+            "level": "error",
+            "message": { "text": "mismatched types\nnote: a note" },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "test.rs" },
+                    "region": {
+                        "startLine": 1,
+                        "startColumn": 13,
+                        "endLine": 1,
+                        "endColumn": 16,
+                        "byteOffset": 12,
+                        "byteLength": 3,
+                    },
+                },
+            }],
+            "relatedLocations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "test.rs" },
+                    "region": {
+                        "startLine": 1,
+                        "startColumn": 8,
+                        "endLine": 1,
+                        "endColumn": 10,
+                        "byteOffset": 7,
+                        "byteLength": 2,
+                    },
+                },
+                "message": { "text": "expected due to this" },
+            }],
+        })
+    );
+    assert_eq!(results[1]["level"], "warning");
+    assert_eq!(results[1]["locations"][0]["physicalLocation"]["region"]["startLine"], 2);
+    assert!(results[1].get("ruleId").is_none());
+}
+
+#[test]
+fn fixes() {
+    let log = emit_sarif("let x = 1;", |dcx| {
+        dcx.struct_span_warn(span(4, 5), "unused variable")
+            .with_span_suggestion(span(4, 5), "prefix it", "_x", Applicability::MachineApplicable)
+            .with_span_suggestion(span(0, 10), "remove it", "", Applicability::MaybeIncorrect)
+            .emit();
+    });
+
+    let result = &log["runs"][0]["results"][0];
+    assert_eq!(result["message"]["text"], "unused variable\nhelp: remove it");
+    assert_eq!(
+        result["fixes"],
+        json!([{
+            "description": { "text": "prefix it" },
+            "artifactChanges": [{
+                "artifactLocation": { "uri": "test.rs" },
+                "replacements": [{
+                    "deletedRegion": {
+                        "startLine": 1,
+                        "startColumn": 5,
+                        "endLine": 1,
+                        "endColumn": 6,
+                        "byteOffset": 4,
+                        "byteLength": 1,
+                    },
+                    "insertedContent": { "text": "_x" },
+                }],
+            }],
+        }])
+    );
+}
+
+#[test]
+fn shared_log() {
+    rustc_span::create_default_session_globals_then(|| {
+        let translator =
+            || Translator::with_fallback_bundle(vec![crate::DEFAULT_LOCALE_RESOURCE], false);
+        let early_output = Arc::new(Mutex::new(Vec::new()));
+        let other_output = Arc::new(Mutex::new(Vec::new()));
+
+        // Like the emitters of the early and of the session diagnostic contexts.
+        let early =
+            SarifEmitter::new(Box::new(Shared { data: early_output.clone() }), None, translator())
+                .write_empty_log(false);
+        let log = early.log();
+        let early_dcx = DiagCtxt::new(Box::new(early));
+        early_dcx.handle().struct_warn("early warning").emit();
+        let late_dcx = DiagCtxt::new(Box::new(SarifEmitter::with_log(log, None, translator())));
+        late_dcx.handle().struct_warn("late warning").emit();
+        // An emitter of another compiler session running in the same process.
+        let other =
+            SarifEmitter::new(Box::new(Shared { data: other_output.clone() }), None, translator());
+        let other_dcx = DiagCtxt::new(Box::new(other));
+        other_dcx.handle().struct_warn("other warning").emit();
+        drop(other_dcx);
+        drop(late_dcx);
+        // Nothing is written until every emitter of the log is gone.
+        assert!(early_output.lock().unwrap().is_empty());
+        drop(early_dcx);
+
+        let bytes = early_output.lock().unwrap();
+        let log: Value = serde_json::from_str(str::from_utf8(&bytes).unwrap()).unwrap();
+        let results = log["runs"][0]["results"].as_array().unwrap();
+        let messages: Vec<_> = results.iter().map(|result| &result["message"]["text"]).collect();
+        assert_eq!(messages, ["early warning", "late warning"]);
+        let bytes = other_output.lock().unwrap();
+        let log: Value = serde_json::from_str(str::from_utf8(&bytes).unwrap()).unwrap();
+        assert_eq!(log["runs"][0]["results"].as_array().unwrap().len(), 1);
+    })
+}
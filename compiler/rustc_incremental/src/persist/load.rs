@@ -31,6 +31,8 @@ pub enum LoadResult<T> {
     },
     /// The file either didn't exist or was produced by an incompatible compiler version.
     DataOutOfDate,
+    /// The file was produced with different command-line arguments.
+    DifferentCommandLineArgs,
     /// Loading the dep graph failed.
     LoadDepGraph(PathBuf, std::io::Error),
 }
@@ -45,7 +47,9 @@ pub fn open(self, sess: &Session) -> T {
             }
             (
                 Some(IncrementalStateAssertion::Loaded),
-                LoadResult::LoadDepGraph(..) | LoadResult::DataOutOfDate,
+                LoadResult::LoadDepGraph(..)
+                | LoadResult::DataOutOfDate
+                | LoadResult::DifferentCommandLineArgs,
             ) => {
                 sess.dcx().emit_fatal(errors::AssertLoaded);
             }
@@ -57,7 +61,7 @@ pub fn open(self, sess: &Session) -> T {
                 sess.dcx().emit_warn(errors::LoadDepGraph { path, err });
                 Default::default()
             }
-            LoadResult::DataOutOfDate => {
+            LoadResult::DataOutOfDate | LoadResult::DifferentCommandLineArgs => {
                 if let Err(err) = delete_all_session_dir_contents(sess) {
                     sess.dcx()
                         .emit_err(errors::DeleteIncompatible { path: dep_graph_path(sess), err });
@@ -152,6 +156,7 @@ fn load_dep_graph(
 
     match load_data(&path, sess) {
         LoadResult::DataOutOfDate => LoadResult::DataOutOfDate,
+        LoadResult::DifferentCommandLineArgs => LoadResult::DifferentCommandLineArgs,
         LoadResult::LoadDepGraph(path, err) => LoadResult::LoadDepGraph(path, err),
         LoadResult::Ok { data: (bytes, start_pos) } => {
             let Ok(mut decoder) = MemDecoder::new(&bytes, start_pos) else {
@@ -171,7 +176,7 @@ fn load_dep_graph(
                 debug!("load_dep_graph_new: differing commandline arg hashes");
 
                 // No need to do any further work
-                return LoadResult::DataOutOfDate;
+                return LoadResult::DifferentCommandLineArgs;
             }
 
             let dep_graph = SerializedDepGraph::decode::<DepsType>(&mut decoder, deps);
@@ -227,8 +232,13 @@ pub fn setup_dep_graph(sess: &Session, crate_name: Symbol, deps: &DepsType) -> D
     }
 
     res.and_then(|result| {
+        let different_args = matches!(result, LoadResult::DifferentCommandLineArgs);
         let (prev_graph, prev_work_products) = result.open(sess);
-        build_dep_graph(sess, prev_graph, prev_work_products)
+        let dep_graph = build_dep_graph(sess, prev_graph, prev_work_products)?;
+        if different_args {
+            dep_graph.explain_discarded_previous_session("the command-line arguments changed");
+        }
+        Some(dep_graph)
     })
     .unwrap_or_else(DepGraph::new_disabled)
 }
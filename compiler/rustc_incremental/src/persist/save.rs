@@ -1,15 +1,17 @@
 use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 use rustc_data_structures::fx::FxIndexMap;
 use rustc_data_structures::sync::join;
 use rustc_middle::dep_graph::{
-    DepGraph, SerializedDepGraph, WorkProduct, WorkProductId, WorkProductMap,
+    DepGraph, RedCause, SerializedDepGraph, WorkProduct, WorkProductId, WorkProductMap,
 };
 use rustc_middle::ty::TyCtxt;
 use rustc_serialize::Encodable as RustcEncodable;
 use rustc_serialize::opaque::{FileEncodeResult, FileEncoder};
 use rustc_session::Session;
+use serde_json::json;
 use tracing::debug;
 
 use super::data::*;
@@ -32,6 +34,9 @@ pub(crate) fn save_dep_graph(tcx: TyCtxt<'_>) {
         if sess.opts.incremental.is_none() {
             return;
         }
+        if let Some(path) = &sess.opts.unstable_opts.incremental_explain {
+            sess.time("incr_comp_explain", || save_explanation(tcx, path));
+        }
         // This is going to be deleted in finalize_session_directory, so let's not create it.
         if sess.dcx().has_errors_or_delayed_bugs().is_some() {
             return;
@@ -74,6 +79,47 @@ pub(crate) fn save_dep_graph(tcx: TyCtxt<'_>) {
     })
 }
 
+/// Writes the `-Z incremental-explain` report: for every query of the previous session that was
+/// evaluated again, the chain of first red dependencies that forced it, down to the input that
+/// changed.
+fn save_explanation(tcx: TyCtxt<'_>, path: &Path) {
+    let Some(explanation) = tcx.dep_graph.explain(tcx) else { return };
+
+    let reexecutions: Vec<_> = explanation
+        .reexecutions
+        .iter()
+        .map(|reexecution| {
+            let red_dependencies: Vec<_> =
+                reexecution.red_dependencies.iter().map(|node| format!("{node:?}")).collect();
+            let cause = match reexecution.cause {
+                RedCause::InputChanged => "input changed",
+                RedCause::Untracked => "untracked",
+                RedCause::Changed => "changed",
+                RedCause::NotForceable => "not forceable",
+            };
+            json!({
+                "query": format!("{:?}", reexecution.node),
+                "changed": reexecution.changed,
+                "red_dependencies": red_dependencies,
+                "cause": cause,
+            })
+        })
+        .collect();
+    let report = json!({
+        "discarded_previous_session": explanation.discarded_previous_session,
+        "reexecutions": reexecutions,
+    });
+
+    let contents = serde_json::to_string_pretty(&report).unwrap();
+    if let Err(err) = fs::write(path, contents) {
+        tcx.dcx().emit_err(errors::WriteNew {
+            name: "incremental explanation",
+            path: path.to_path_buf(),
+            err,
+        });
+    }
+}
+
 /// Saves the work product index.
 pub fn save_work_product_index(
     sess: &Session,
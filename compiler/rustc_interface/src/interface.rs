@@ -8,6 +8,7 @@
 use rustc_data_structures::jobserver::{self, Proxy};
 use rustc_data_structures::stable_hasher::StableHasher;
 use rustc_errors::registry::Registry;
+use rustc_errors::sarif::SharedLog;
 use rustc_errors::{DiagCtxtHandle, ErrorGuaranteed};
 use rustc_lint::LintStore;
 use rustc_middle::ty;
@@ -376,6 +377,10 @@ pub struct Config {
     /// This is mainly useful for other tools that reads that debuginfo to figure out
     /// how to call the compiler with the same arguments.
     pub expanded_args: Vec<String>,
+
+    /// The SARIF log that diagnostics are collected in with `--error-format=sarif`, e.g. the one
+    /// of the driver's `EarlyDiagCtxt`, so that they are written as a single log.
+    pub sarif_log: Option<SharedLog>,
 }
 
 /// Initialize jobserver before getting `jobserver::client` and `build_session`.
@@ -399,7 +404,8 @@ pub fn run_compiler<R: Send>(config: Config, f: impl FnOnce(&Compiler) -> R + Se
     rustc_data_structures::sync::set_dyn_thread_safe_mode(config.opts.unstable_opts.threads > 1);
 
     // Check jobserver before run_in_thread_pool_with_globals, which call jobserver::acquire_thread
-    let early_dcx = EarlyDiagCtxt::new(config.opts.error_format);
+    let early_dcx =
+        EarlyDiagCtxt::with_sarif_log(config.opts.error_format, config.sarif_log.clone());
     initialize_checked_jobserver(&early_dcx);
 
     crate::callbacks::setup_callbacks();
@@ -423,7 +429,8 @@ pub fn run_compiler<R: Send>(config: Config, f: impl FnOnce(&Compiler) -> R + Se
         |current_gcx, jobserver_proxy| {
             // The previous `early_dcx` can't be reused here because it doesn't
             // impl `Send`. Creating a new one is fine.
-            let early_dcx = EarlyDiagCtxt::new(config.opts.error_format);
+            let early_dcx =
+                EarlyDiagCtxt::with_sarif_log(config.opts.error_format, config.sarif_log.clone());
 
             let codegen_backend = match config.make_codegen_backend {
                 None => util::get_codegen_backend(
@@ -475,6 +482,7 @@ pub fn run_compiler<R: Send>(config: Config, f: impl FnOnce(&Compiler) -> R + Se
                 config.ice_file,
                 config.using_internal_features,
                 config.expanded_args,
+                config.sarif_log,
             );
 
             codegen_backend.init(&sess);
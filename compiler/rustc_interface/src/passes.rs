@@ -30,7 +30,9 @@
 };
 use rustc_passes::{abi_test, input_stats, layout_test};
 use rustc_resolve::Resolver;
-use rustc_session::config::{CrateType, Input, OutFileName, OutputFilenames, OutputType};
+use rustc_session::config::{
+    CrateType, Input, OutFileName, OutputFilenames, OutputType, PrintTypeSizesFormat,
+};
 use rustc_session::cstore::Untracked;
 use rustc_session::output::{collect_crate_types, filename_for_input};
 use rustc_session::parse::feature_err;
@@ -649,6 +651,12 @@ fn hash_iter_files<P: AsRef<Path>>(
                 checksum_hash_algo,
             ));
         }
+        if let Some(ref cgu_profile) = sess.opts.unstable_opts.cgu_partitioning_profile {
+            files.extend(hash_iter_files(
+                iter::once(normalize_path(cgu_profile.as_path().to_path_buf())),
+                checksum_hash_algo,
+            ));
+        }
 
         // Debugger visualizer files
         for debugger_visualizer in tcx.debugger_visualizers(LOCAL_CRATE) {
@@ -1227,7 +1235,10 @@ pub(crate) fn start_codegen<'tcx>(
     // This must run after monomorphization so that all generic types
     // have been instantiated.
     if tcx.sess.opts.unstable_opts.print_type_sizes {
-        tcx.sess.code_stats.print_type_sizes();
+        match tcx.sess.opts.unstable_opts.print_type_sizes_format {
+            PrintTypeSizesFormat::Text => tcx.sess.code_stats.print_type_sizes(),
+            PrintTypeSizesFormat::Json => tcx.sess.code_stats.print_type_sizes_json(),
+        }
     }
 
     (codegen, metadata)
@@ -5,7 +5,7 @@
 use std::sync::atomic::AtomicBool;
 
 use rustc_abi::Align;
-use rustc_data_structures::profiling::TimePassesFormat;
+use rustc_data_structures::profiling::{SelfProfileFormat, TimePassesFormat};
 use rustc_errors::emitter::HumanReadableErrorType;
 use rustc_errors::{ColorConfig, registry};
 use rustc_session::config::{
@@ -14,9 +14,9 @@
     Externs, FmtDebug, FunctionReturn, InliningThreshold, Input, InstrumentCoverage,
     InstrumentXRay, LinkSelfContained, LinkerPluginLto, LocationDetail, LtoCli, MirIncludeSpans,
     NextSolverConfig, Offload, OomStrategy, Options, OutFileName, OutputType, OutputTypes,
-    PAuthKey, PacRet, Passes, PatchableFunctionEntry, Polonius, ProcMacroExecutionStrategy, Strip,
-    SwitchWithOptPath, SymbolManglingVersion, WasiExecModel, build_configuration,
-    build_session_options, rustc_optgroups,
+    PAuthKey, PacRet, Passes, PatchableFunctionEntry, Polonius, PrintTypeSizesFormat,
+    ProcMacroExecutionStrategy, Strip, SwitchWithOptPath, SymbolManglingVersion, WasiExecModel,
+    build_configuration, build_session_options, rustc_optgroups,
 };
 use rustc_session::lint::Level;
 use rustc_session::search_paths::SearchPath;
@@ -78,6 +78,7 @@ fn sess_and_cfg<F>(args: &[&'static str], f: F)
             None,
             &USING_INTERNAL_FEATURES,
             Default::default(),
+            None,
         );
         let cfg = parse_cfg(sess.dcx(), matches.opt_strs("cfg"));
         let cfg = build_configuration(&sess, cfg);
@@ -697,12 +698,14 @@ macro_rules! untracked {
     untracked!(dump_mir_exclude_alloc_bytes, true);
     untracked!(dump_mir_exclude_pass_number, true);
     untracked!(dump_mir_graphviz, true);
+    untracked!(dump_mono_bloat, SwitchWithOptPath::Enabled(Some("mono-items-dir/".into())));
     untracked!(dump_mono_stats, SwitchWithOptPath::Enabled(Some("mono-items-dir/".into())));
     untracked!(dump_mono_stats_format, DumpMonoStatsFormat::Json);
     untracked!(dylib_lto, true);
     untracked!(emit_stack_sizes, true);
     untracked!(future_incompat_test, true);
     untracked!(identify_regions, true);
+    untracked!(incremental_explain, Some(PathBuf::from("explain.json")));
     untracked!(incremental_info, true);
     untracked!(incremental_verify_ich, true);
     untracked!(input_stats, true);
@@ -725,12 +728,14 @@ macro_rules! untracked {
     untracked!(print_llvm_passes, true);
     untracked!(print_mono_items, true);
     untracked!(print_type_sizes, true);
+    untracked!(print_type_sizes_format, PrintTypeSizesFormat::Json);
     untracked!(proc_macro_backtrace, true);
     untracked!(proc_macro_execution_strategy, ProcMacroExecutionStrategy::CrossThread);
     untracked!(profile_closures, true);
     untracked!(query_dep_graph, true);
     untracked!(self_profile, SwitchWithOptPath::Enabled(None));
     untracked!(self_profile_events, Some(vec![String::new()]));
+    untracked!(self_profile_format, SelfProfileFormat::ChromeJson);
     untracked!(shell_argfiles, true);
     untracked!(span_debug, true);
     untracked!(span_free_formats, true);
@@ -773,6 +778,7 @@ macro_rules! tracked {
             pac_ret: Some(PacRet { leaf: true, pc: true, key: PAuthKey::B })
         })
     );
+    tracked!(cgu_partitioning_profile, Some(PathBuf::from("abc")));
     tracked!(codegen_backend, Some("abc".to_string()));
     tracked!(
         coverage_options,
@@ -847,6 +853,7 @@ macro_rules! tracked {
     );
     tracked!(plt, Some(true));
     tracked!(polonius, Polonius::Legacy);
+    tracked!(polymorphize, true);
     tracked!(precise_enum_drop_elaboration, false);
     tracked!(profile_sample_use, Some(PathBuf::from("abc")));
     tracked!(profiler_runtime, "abc".to_string());
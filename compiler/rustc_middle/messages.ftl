@@ -20,6 +20,9 @@ middle_assert_gen_resume_after_panic = `gen` fn or block cannot be further itera
 middle_assert_invalid_enum_construction =
     trying to construct an enum from an invalid value `{$source}`
 
+middle_assert_invalid_scalar_value =
+    constructing an invalid value `{$value}`, expected a value in `{$start}..={$end}`
+
 middle_assert_misaligned_ptr_deref =
     misaligned pointer dereference: address must be a multiple of {$required} but is {$found}
 
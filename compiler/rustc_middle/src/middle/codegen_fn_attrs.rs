@@ -25,6 +25,14 @@ pub fn codegen_instance_attrs(
             }
         }
 
+        // Outlined cold paths keep the attributes of their function, like its target features,
+        // but they should not be inlined back into it.
+        if let InstanceKind::ColdPathShim(..) = instance_kind {
+            let attrs = attrs.to_mut();
+            attrs.flags.insert(CodegenFnAttrFlags::COLD);
+            attrs.inline = InlineAttr::Never;
+        }
+
         attrs
     }
 }
@@ -357,6 +357,12 @@ pub struct Body<'tcx> {
 
     pub tainted_by_errors: Option<ErrorGuaranteed>,
 
+    /// The bodies of the `ColdPathShim`s of this function, indexed by the `u32` of the shim.
+    ///
+    /// The cold paths are outlined at the end of the optimization pipeline. This keeps the
+    /// bodies of the shims with the MIR of the function, including in the crate metadata.
+    pub outlined_cold_paths: Vec<Body<'tcx>>,
+
     /// Coverage information collected from THIR/MIR during MIR building,
     /// to be used by the `InstrumentCoverage` pass.
     ///
@@ -416,6 +422,7 @@ pub fn new(
             is_polymorphic: false,
             injection_phase: None,
             tainted_by_errors,
+            outlined_cold_paths: Vec::new(),
             coverage_info_hi: None,
             function_coverage_info: None,
         };
@@ -447,6 +454,7 @@ pub fn new_cfg_only(basic_blocks: IndexVec<BasicBlock, BasicBlockData<'tcx>>) ->
             is_polymorphic: false,
             injection_phase: None,
             tainted_by_errors: None,
+            outlined_cold_paths: Vec::new(),
             coverage_info_hi: None,
             function_coverage_info: None,
         };
@@ -290,6 +290,7 @@ fn dump_path<'tcx>(
             }));
             s
         }
+        ty::InstanceKind::ColdPathShim(_, index) => format!(".cold-path-{index}"),
         _ => String::new(),
     };
 
@@ -377,6 +378,14 @@ pub fn write_mir_pretty<'tcx>(
         } else {
             let instance_mir = tcx.instance_mir(ty::InstanceKind::Item(def_id));
             render_body(w, instance_mir)?;
+
+            // The shims of the outlined cold paths are only built when codegen needs them, so
+            // build them here to show them after the function.
+            for index in 0..instance_mir.outlined_cold_paths.len() {
+                writeln!(w)?;
+                let shim = ty::InstanceKind::ColdPathShim(def_id, index as u32);
+                write_mir_fn(tcx, tcx.instance_mir(shim), &mut |_, _| Ok(()), w, options)?;
+            }
         }
     }
     Ok(())
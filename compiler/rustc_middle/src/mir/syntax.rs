@@ -1076,6 +1076,7 @@ pub enum AssertKind<O> {
     MisalignedPointerDereference { required: O, found: O },
     NullPointerDereference,
     InvalidEnumConstruction(O),
+    InvalidScalarValue { value: O, start: O, end: O },
 }
 
 #[derive(Clone, Debug, PartialEq, TyEncodable, TyDecodable, Hash, HashStable)]
@@ -209,6 +209,7 @@ pub fn panic_function(&self) -> LangItem {
             }
             NullPointerDereference => LangItem::PanicNullPointerDereference,
             InvalidEnumConstruction(_) => LangItem::PanicInvalidEnumConstruction,
+            InvalidScalarValue { .. } => LangItem::PanicInvalidScalarValue,
             ResumedAfterDrop(CoroutineKind::Coroutine(_)) => LangItem::PanicCoroutineResumedDrop,
             ResumedAfterDrop(CoroutineKind::Desugared(CoroutineDesugaring::Async, _)) => {
                 LangItem::PanicAsyncFnResumedDrop
@@ -288,6 +289,12 @@ pub fn fmt_assert_args<W: fmt::Write>(&self, f: &mut W) -> fmt::Result
             InvalidEnumConstruction(source) => {
                 write!(f, "\"trying to construct an enum from an invalid value {{}}\", {source:?}")
             }
+            InvalidScalarValue { value, start, end } => {
+                write!(
+                    f,
+                    "\"constructing an invalid value {{}}, expected a value in {{}}..={{}}\", {value:?}, {start:?}, {end:?}"
+                )
+            }
             ResumedAfterReturn(CoroutineKind::Coroutine(_)) => {
                 write!(f, "\"coroutine resumed after completion\"")
             }
@@ -372,6 +379,7 @@ pub fn diagnostic_message(&self) -> DiagMessage {
             }
             NullPointerDereference => middle_assert_null_ptr_deref,
             InvalidEnumConstruction(_) => middle_assert_invalid_enum_construction,
+            InvalidScalarValue { .. } => middle_assert_invalid_scalar_value,
             ResumedAfterDrop(CoroutineKind::Desugared(CoroutineDesugaring::Async, _)) => {
                 middle_assert_async_resume_after_drop
             }
@@ -428,6 +436,11 @@ macro_rules! add {
             InvalidEnumConstruction(source) => {
                 add!("source", format!("{source:#?}"));
             }
+            InvalidScalarValue { value, start, end } => {
+                add!("value", format!("{value:#?}"));
+                add!("start", format!("{start:#?}"));
+                add!("end", format!("{end:#?}"));
+            }
         }
     }
 }
@@ -348,6 +348,7 @@ fn super_source_scope_data(
                         | ty::InstanceKind::ReifyShim(_def_id, _)
                         | ty::InstanceKind::Virtual(_def_id, _)
                         | ty::InstanceKind::ThreadLocalShim(_def_id)
+                        | ty::InstanceKind::ColdPathShim(_def_id, _)
                         | ty::InstanceKind::ClosureOnceShim { call_once: _def_id, track_caller: _ }
                         | ty::InstanceKind::ConstructCoroutineInClosureShim {
                             coroutine_closure_def_id: _def_id,
@@ -652,6 +653,11 @@ fn super_assert_message(
                         self.visit_operand(required, location);
                         self.visit_operand(found, location);
                     }
+                    InvalidScalarValue { value, start, end } => {
+                        self.visit_operand(value, location);
+                        self.visit_operand(start, location);
+                        self.visit_operand(end, location);
+                    }
                 }
             }
 
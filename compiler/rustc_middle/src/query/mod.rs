@@ -2670,6 +2670,15 @@
         cache_on_disk_if { true }
     }
 
+    /// Returns the type parameters of a function that its optimized MIR does not use, which
+    /// `-Z polymorphize` collapses. Only used when that flag is set.
+    query unused_generic_params(def_id: DefId) -> ty::UnusedGenericParams {
+        desc { |tcx|
+            "determining which generic parameters are unused by `{}`",
+            tcx.def_path_str(def_id)
+        }
+    }
+
     query size_estimate(key: ty::Instance<'tcx>) -> usize {
         desc { "estimating codegen size of `{}`", key }
         cache_on_disk_if { true }
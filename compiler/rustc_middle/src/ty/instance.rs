@@ -147,6 +147,13 @@ pub enum InstanceKind<'tcx> {
     /// native support.
     ThreadLocalShim(DefId),
 
+    /// A cold path that `-Zmir-opt-level=3` outlined out of the function the `DefId` defines.
+    ///
+    /// Its MIR is the `u32`-th body in the `outlined_cold_paths` of the optimized MIR of the
+    /// function, which calls it through a function pointer. The shim takes a raw pointer to each
+    /// local of the function that the cold path uses, and never returns.
+    ColdPathShim(DefId, u32),
+
     /// Proxy shim for async drop of future (def_id, proxy_cor_ty, impl_cor_ty)
     FutureDropPollShim(DefId, Ty<'tcx>, Ty<'tcx>),
 
@@ -226,9 +233,11 @@ pub fn upstream_monomorphization(&self, tcx: TyCtxt<'tcx>) -> Option<CrateNum> {
         }
 
         match self.def {
-            InstanceKind::Item(def) => tcx
-                .upstream_monomorphizations_for(def)
-                .and_then(|monos| monos.get(&self.args).cloned()),
+            InstanceKind::Item(def) => {
+                // Upstream crates export the polymorphized instances they codegened.
+                let args = self.polymorphize(tcx).args;
+                tcx.upstream_monomorphizations_for(def).and_then(|monos| monos.get(&args).cloned())
+            }
             InstanceKind::DropGlue(_, Some(_)) => tcx.upstream_drop_glue_for(self.args),
             InstanceKind::AsyncDropGlue(_, _) => None,
             InstanceKind::FutureDropPollShim(_, _, _) => None,
@@ -251,6 +260,7 @@ pub fn def_id(self) -> DefId {
             | InstanceKind::Virtual(def_id, _)
             | InstanceKind::Intrinsic(def_id)
             | InstanceKind::ThreadLocalShim(def_id)
+            | InstanceKind::ColdPathShim(def_id, _)
             | InstanceKind::ClosureOnceShim { call_once: def_id, track_caller: _ }
             | ty::InstanceKind::ConstructCoroutineInClosureShim {
                 coroutine_closure_def_id: def_id,
@@ -281,6 +291,7 @@ pub fn def_id_if_not_guaranteed_local_codegen(self) -> Option<DefId> {
             | InstanceKind::Intrinsic(..)
             | InstanceKind::ClosureOnceShim { .. }
             | ty::InstanceKind::ConstructCoroutineInClosureShim { .. }
+            | InstanceKind::ColdPathShim(..)
             | InstanceKind::DropGlue(..)
             | InstanceKind::CloneShim(..)
             | InstanceKind::FnPtrAddrShim(..) => None,
@@ -338,6 +349,7 @@ pub fn has_polymorphic_mir_body(&self) -> bool {
         match *self {
             InstanceKind::CloneShim(..)
             | InstanceKind::ThreadLocalShim(..)
+            | InstanceKind::ColdPathShim(..)
             | InstanceKind::FnPtrAddrShim(..)
             | InstanceKind::FnPtrShim(..)
             | InstanceKind::DropGlue(_, Some(_))
@@ -414,6 +426,7 @@ pub fn fmt_instance(
         InstanceKind::ReifyShim(_, Some(ReifyReason::FnPtr)) => write!(f, " - shim(reify-fnptr)"),
         InstanceKind::ReifyShim(_, Some(ReifyReason::Vtable)) => write!(f, " - shim(reify-vtable)"),
         InstanceKind::ThreadLocalShim(_) => write!(f, " - shim(tls)"),
+        InstanceKind::ColdPathShim(_, index) => write!(f, " - shim(cold-path#{index})"),
         InstanceKind::Intrinsic(_) => write!(f, " - intrinsic"),
         InstanceKind::Virtual(_, num) => write!(f, " - virtual#{num}"),
         InstanceKind::FnPtrShim(_, ty) => write!(f, " - shim({ty})"),
@@ -911,6 +924,40 @@ pub fn try_resolve_item_for_coroutine(
         }
     }
 
+    /// With `-Z polymorphize`, replaces the type parameters of a function that its body does not
+    /// use with `()`, so that all the instantiations of the function that only differ in those
+    /// parameters share the same mono item and symbol. The signature of the function is part of
+    /// its body, so it is the same for all these instantiations.
+    ///
+    /// Instances that are not functions are returned unchanged.
+    pub fn polymorphize(self, tcx: TyCtxt<'tcx>) -> Instance<'tcx> {
+        if !tcx.sess.opts.unstable_opts.polymorphize {
+            return self;
+        }
+        let InstanceKind::Item(def_id) = self.def else {
+            return self;
+        };
+        if !matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+            || self.args.types().next().is_none()
+        {
+            return self;
+        }
+
+        let unused = tcx.unused_generic_params(def_id);
+        if unused.all_used() {
+            return self;
+        }
+        let args = tcx.mk_args_from_iter(self.args.iter().enumerate().map(|(index, arg)| {
+            if arg.as_type().is_some() && unused.is_unused(index as u32) {
+                tcx.types.unit.into()
+            } else {
+                arg
+            }
+        }));
+        debug!(?self, ?args, "polymorphized");
+        Instance { def: self.def, args }
+    }
+
     /// Depending on the kind of `InstanceKind`, the MIR body associated with an
     /// instance is expressed in terms of the generic parameters of `self.def_id()`, and in other
     /// cases the MIR body is expressed in terms of the types found in the generic parameter array.
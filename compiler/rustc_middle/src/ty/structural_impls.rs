@@ -227,6 +227,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     (),
     bool,
     usize,
+    u32,
     u64,
     // tidy-alphabetical-start
     crate::mir::Promoted,
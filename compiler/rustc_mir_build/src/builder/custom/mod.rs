@@ -59,6 +59,7 @@ pub(super) fn build_custom_mir<'tcx>(
         tainted_by_errors: None,
         injection_phase: None,
         pass_count: 0,
+        outlined_cold_paths: Vec::new(),
         coverage_info_hi: None,
         function_coverage_info: None,
     };
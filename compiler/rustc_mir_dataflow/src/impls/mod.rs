@@ -2,6 +2,7 @@
 mod initialized;
 mod liveness;
 mod storage_liveness;
+mod value_range;
 
 pub use self::borrowed_locals::{MaybeBorrowedLocals, borrowed_locals};
 pub use self::initialized::{
@@ -14,3 +15,4 @@
 pub use self::storage_liveness::{
     MaybeRequiresStorage, MaybeStorageDead, MaybeStorageLive, always_storage_live_locals,
 };
+pub use self::value_range::{ValueRange, ValueRangeAnalysis, ValueRangeState};
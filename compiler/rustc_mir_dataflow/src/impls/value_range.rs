@@ -0,0 +1,1168 @@
+//! A value range analysis, which finds an interval that contains the value of each tracked
+//! integer place.
+//!
+//! The places are the ones tracked by a [`Map`], so this analysis has the same limitations as
+//! the other analyses built on `value_analysis`: places whose address is taken are not tracked.
+//!
+//! Taking an edge of a `SwitchInt` refines the ranges of the places the branch depends on, e.g.
+//! `_2` is known to be in `0..=7` on the `otherwise` edge of:
+//!
+//! ```text
+//! _3 = Lt(copy _2, const 8_usize);
+//! switchInt(move _3) -> [0: bb2, otherwise: bb1];
+//! ```
+//!
+//! Besides the intervals, the analysis knows the strict inequalities between integer locals and
+//! the lengths of the slices that pointer locals point to, which such a branch also establishes
+//! when the range of `_4` is not known, e.g. after:
+//!
+//! ```text
+//! _4 = PtrMetadata(copy _1);
+//! _3 = Lt(copy _2, copy _4);
+//! switchInt(move _3) -> [0: bb2, otherwise: bb1];
+//! ```
+//!
+//! `_2` is less than the length of `*_1` in `bb1`, and so is any copy of `_2` compared to the
+//! length of `*_1` by a bounds check, until `_1` or `_2` is assigned again.
+
+use std::assert_matches::assert_matches;
+use std::fmt::Formatter;
+use std::slice;
+
+use rustc_data_structures::fx::FxIndexSet;
+use rustc_index::bit_set::DenseBitSet;
+use rustc_middle::mir::*;
+use rustc_middle::ty::{self, Ty, TyCtxt};
+use smallvec::SmallVec;
+
+use crate::fmt::DebugWithContext;
+use crate::lattice::{HasBottom, HasTop};
+use crate::value_analysis::{
+    Map, PlaceIndex, State, TrackElem, ValueOrPlace, debug_with_context, excluded_locals,
+};
+use crate::{Analysis, JoinSemiLattice};
+
+/// An interval of integers, the values of `bool` being `0` and `1`.
+///
+/// Joining two ranges widens the bounds that grow to the next power of two, or its negation, so
+/// that each bound changes a bounded number of times. Without this, a loop that increments a
+/// counter would need as many iterations to reach fixpoint as the counter has values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueRange {
+    Bottom,
+    /// The value is in `start..=end`.
+    Range(i128, i128),
+    Top,
+}
+
+impl ValueRange {
+    /// Returns the range that only contains `value`.
+    pub fn exactly(value: i128) -> ValueRange {
+        ValueRange::Range(value, value)
+    }
+
+    /// Returns the values of both ranges, or `Bottom` if there are none.
+    fn intersect(self, other: ValueRange) -> ValueRange {
+        match (self, other) {
+            (ValueRange::Bottom, _) | (_, ValueRange::Bottom) => ValueRange::Bottom,
+            (ValueRange::Top, range) | (range, ValueRange::Top) => range,
+            (ValueRange::Range(start, end), ValueRange::Range(other_start, other_end)) => {
+                let (start, end) = (start.max(other_start), end.min(other_end));
+                if start <= end { ValueRange::Range(start, end) } else { ValueRange::Bottom }
+            }
+        }
+    }
+
+    /// Removes `value` from the range if it is one of its bounds.
+    fn exclude(self, value: i128) -> ValueRange {
+        match self {
+            ValueRange::Range(start, end) if start == value && end == value => ValueRange::Bottom,
+            ValueRange::Range(start, end) if start == value => ValueRange::Range(start + 1, end),
+            ValueRange::Range(start, end) if end == value => ValueRange::Range(start, end - 1),
+            range => range,
+        }
+    }
+}
+
+/// The smallest bound that is `>= value` among `0`, `2^n - 1` and `-2^n`.
+fn widen_up(value: i128) -> i128 {
+    match value {
+        0 => 0,
+        1.. => (u128::MAX >> value.leading_zeros()) as i128,
+        _ => (1u128 << (u128::BITS - 1 - value.unsigned_abs().leading_zeros())).wrapping_neg()
+            as i128,
+    }
+}
+
+/// The largest bound that is `<= value` among `0`, `2^n - 1` and `-2^n`.
+fn widen_down(value: i128) -> i128 {
+    match value {
+        0 => 0,
+        1.. => (u128::MAX >> ((value as u128 + 1).leading_zeros() + 1)) as i128,
+        _ => value.unsigned_abs().next_power_of_two().wrapping_neg() as i128,
+    }
+}
+
+impl JoinSemiLattice for ValueRange {
+    fn join(&mut self, other: &Self) -> bool {
+        let result = match (*self, *other) {
+            (ValueRange::Top, _) | (_, ValueRange::Bottom) => return false,
+            (ValueRange::Bottom, other) => other,
+            (_, ValueRange::Top) => ValueRange::Top,
+            (ValueRange::Range(start, end), ValueRange::Range(other_start, other_end)) => {
+                if start <= other_start && other_end <= end {
+                    return false;
+                }
+                ValueRange::Range(
+                    if other_start < start { widen_down(other_start) } else { start },
+                    if other_end > end { widen_up(other_end) } else { end },
+                )
+            }
+        };
+        *self = result;
+        true
+    }
+}
+
+impl HasBottom for ValueRange {
+    const BOTTOM: Self = ValueRange::Bottom;
+
+    fn is_bottom(&self) -> bool {
+        matches!(self, ValueRange::Bottom)
+    }
+}
+
+impl HasTop for ValueRange {
+    const TOP: Self = ValueRange::Top;
+}
+
+/// A value that relations are tracked for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum RelationPlace {
+    /// The value of an integer local.
+    Local(Local),
+    /// The length of the slice that a pointer local points to.
+    Len(Local),
+}
+
+impl RelationPlace {
+    fn local(self) -> Local {
+        match self {
+            RelationPlace::Local(local) | RelationPlace::Len(local) => local,
+        }
+    }
+}
+
+/// The known relations between the values of [`RelationPlace`]s.
+///
+/// Joining two sets of relations keeps the relations that are in both.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Relations {
+    /// The pairs of places that hold the same value, in both orders.
+    equal: FxIndexSet<(RelationPlace, RelationPlace)>,
+    /// The pairs `(a, b)` such that `a < b`, including the ones implied by `equal`.
+    less_than: FxIndexSet<(RelationPlace, RelationPlace)>,
+}
+
+impl Relations {
+    /// Returns `place` and the places known to be equal to it.
+    fn equal_to(&self, place: RelationPlace) -> SmallVec<[RelationPlace; 2]> {
+        let mut places = SmallVec::new();
+        places.push(place);
+        places.extend(self.equal.iter().filter(|(a, _)| *a == place).map(|&(_, b)| b));
+        places
+    }
+
+    /// Returns the places known to be greater than `place`.
+    fn greater_than(&self, place: RelationPlace) -> impl Iterator<Item = RelationPlace> {
+        self.less_than.iter().filter(move |(a, _)| *a == place).map(|&(_, b)| b)
+    }
+
+    fn is_less_than(&self, a: RelationPlace, b: RelationPlace) -> bool {
+        self.less_than.contains(&(a, b))
+    }
+
+    /// Records that `a < b`.
+    fn add_less_than(&mut self, a: RelationPlace, b: RelationPlace) {
+        let greater = self.equal_to(b);
+        for a in self.equal_to(a) {
+            for &b in &greater {
+                self.less_than.insert((a, b));
+            }
+        }
+    }
+
+    /// Records that `place`, which has no relations, holds the value of `source`.
+    fn add_equal(&mut self, place: RelationPlace, source: RelationPlace) {
+        if place == source {
+            return;
+        }
+        for equal in self.equal_to(source) {
+            self.equal.insert((place, equal));
+            self.equal.insert((equal, place));
+        }
+        let relations: Vec<_> = self
+            .less_than
+            .iter()
+            .filter_map(|&(a, b)| match (a == source, b == source) {
+                (true, _) => Some((place, b)),
+                (_, true) => Some((a, place)),
+                _ => None,
+            })
+            .collect();
+        self.less_than.extend(relations);
+    }
+
+    /// Forgets the relations of `local` and of the length it points to, after it changed.
+    fn forget(&mut self, local: Local) {
+        let unchanged =
+            |&(a, b): &(RelationPlace, RelationPlace)| a.local() != local && b.local() != local;
+        self.equal.retain(unchanged);
+        self.less_than.retain(unchanged);
+    }
+
+    fn clear(&mut self) {
+        self.equal.clear();
+        self.less_than.clear();
+    }
+}
+
+impl JoinSemiLattice for Relations {
+    fn join(&mut self, other: &Self) -> bool {
+        let len = self.equal.len() + self.less_than.len();
+        self.equal.retain(|pair| other.equal.contains(pair));
+        self.less_than.retain(|pair| other.less_than.contains(pair));
+        self.equal.len() + self.less_than.len() != len
+    }
+}
+
+/// The state of a [`ValueRangeAnalysis`]: the ranges of the tracked places, and the relations
+/// between locals, which are only meaningful if the ranges are reachable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValueRangeState {
+    ranges: State<ValueRange>,
+    relations: Relations,
+}
+
+impl ValueRangeState {
+    pub fn is_reachable(&self) -> bool {
+        self.ranges.is_reachable()
+    }
+
+    fn set_unreachable(&mut self) {
+        self.ranges = State::Unreachable;
+        self.relations.clear();
+    }
+}
+
+impl JoinSemiLattice for ValueRangeState {
+    fn join(&mut self, other: &Self) -> bool {
+        match (self.is_reachable(), other.is_reachable()) {
+            (_, false) => false,
+            (false, true) => {
+                *self = other.clone();
+                true
+            }
+            (true, true) => {
+                let ranges_changed = self.ranges.join(&other.ranges);
+                let relations_changed = self.relations.join(&other.relations);
+                ranges_changed || relations_changed
+            }
+        }
+    }
+}
+
+/// Finds the range of values of the tracked places of integer or `bool` type.
+///
+/// Integers of more than 64 bits are not tracked, so that the bounds of every range, and the
+/// result of adding or subtracting them, fit in an `i128`.
+pub struct ValueRangeAnalysis<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    body: &'a Body<'tcx>,
+    map: Map<'tcx>,
+    typing_env: ty::TypingEnv<'tcx>,
+    /// The locals whose address is taken, which may change without being assigned, so that no
+    /// relations are tracked for them.
+    excluded: DenseBitSet<Local>,
+}
+
+/// What taking an edge of a `SwitchInt` tells about the value of the places it depends on.
+pub enum ValueRangeSwitchIntData<'tcx> {
+    /// The discriminant is a place of integer type.
+    Value(Place<'tcx>),
+    /// The discriminant is the result of `left op right`, where `op` is a comparison, and the
+    /// places hold the value of the operands of the comparison on every edge.
+    Comparison {
+        op: BinOp,
+        left: Operand<'tcx>,
+        right: Operand<'tcx>,
+        left_places: SmallVec<[Place<'tcx>; 2]>,
+        right_places: SmallVec<[Place<'tcx>; 2]>,
+    },
+}
+
+impl<'tcx> Analysis<'tcx> for ValueRangeAnalysis<'_, 'tcx> {
+    type Domain = ValueRangeState;
+
+    type SwitchIntData = ValueRangeSwitchIntData<'tcx>;
+
+    const NAME: &'static str = "ValueRangeAnalysis";
+
+    fn bottom_value(&self, _body: &Body<'tcx>) -> Self::Domain {
+        ValueRangeState { ranges: State::Unreachable, relations: Relations::default() }
+    }
+
+    fn initialize_start_block(&self, body: &Body<'tcx>, state: &mut Self::Domain) {
+        // The arguments can have any value, and the other locals are uninitialized.
+        assert_matches!(state.ranges, State::Unreachable);
+        state.ranges = State::new_reachable();
+        for arg in body.args_iter() {
+            state.ranges.flood(PlaceRef { local: arg, projection: &[] }, &self.map);
+        }
+    }
+
+    fn apply_primary_statement_effect(
+        &mut self,
+        state: &mut Self::Domain,
+        statement: &Statement<'tcx>,
+        _location: Location,
+    ) {
+        if state.is_reachable() {
+            self.handle_statement(statement, state);
+        }
+    }
+
+    fn apply_primary_terminator_effect<'mir>(
+        &mut self,
+        state: &mut Self::Domain,
+        terminator: &'mir Terminator<'tcx>,
+        _location: Location,
+    ) -> TerminatorEdges<'mir, 'tcx> {
+        if !state.is_reachable() {
+            return TerminatorEdges::None;
+        }
+        match &terminator.kind {
+            TerminatorKind::Drop { place, .. } => {
+                state.ranges.flood_with(place.as_ref(), &self.map, ValueRange::BOTTOM);
+                self.forget(state, *place);
+            }
+            TerminatorKind::SwitchInt { discr, targets } => {
+                // Only keep the edges that can be taken.
+                if let ValueRange::Range(start, end) = self.operand_range(discr, state)
+                    && start == end
+                    && let Some(value) = self.to_bits(discr.ty(self.body, self.tcx), start)
+                {
+                    return TerminatorEdges::Single(targets.target_for_value(value));
+                }
+            }
+            _ => {}
+        }
+        terminator.edges()
+    }
+
+    fn apply_call_return_effect(
+        &mut self,
+        state: &mut Self::Domain,
+        _block: BasicBlock,
+        return_places: CallReturnPlaces<'_, 'tcx>,
+    ) {
+        return_places.for_each(|place| {
+            state.ranges.flood(place.as_ref(), &self.map);
+            self.forget(state, place);
+        });
+    }
+
+    fn get_switch_int_data(
+        &mut self,
+        block: BasicBlock,
+        discr: &Operand<'tcx>,
+    ) -> Option<Self::SwitchIntData> {
+        let discr = discr.place()?;
+        let statements = &self.body[block].statements;
+
+        // Look for the comparison that computed the discriminant in this block, e.g.
+        // ```text
+        // _5 = copy _2;
+        // _4 = Lt(move _5, const 8_usize);
+        // switchInt(move _4) -> [0: bb2, otherwise: bb1];
+        // ```
+        let comparison = statements.iter().enumerate().rev().find_map(|(index, statement)| {
+            match &statement.kind {
+                StatementKind::Assign(box (lhs, rvalue)) if *lhs == discr => Some((index, rvalue)),
+                _ => None,
+            }
+        });
+        if let Some((index, Rvalue::BinaryOp(op, box (left, right)))) = comparison
+            && matches!(op, BinOp::Eq | BinOp::Ne | BinOp::Lt | BinOp::Le | BinOp::Gt | BinOp::Ge)
+            && self.bounds(left.ty(self.body, self.tcx)).is_some()
+            && [discr]
+                .into_iter()
+                .chain(left.place())
+                .chain(right.place())
+                .all(|place| !is_modified(&statements[index + 1..], place))
+        {
+            return Some(ValueRangeSwitchIntData::Comparison {
+                op: *op,
+                left: left.clone(),
+                right: right.clone(),
+                left_places: self.comparand_places(statements, index, left),
+                right_places: self.comparand_places(statements, index, right),
+            });
+        }
+
+        if self.bounds(discr.ty(self.body, self.tcx).ty).is_some() {
+            return Some(ValueRangeSwitchIntData::Value(discr));
+        }
+        None
+    }
+
+    fn apply_switch_int_edge_effect(
+        &mut self,
+        data: &mut Self::SwitchIntData,
+        state: &mut Self::Domain,
+        value: SwitchTargetValue,
+        targets: &SwitchTargets,
+    ) {
+        if !state.is_reachable() {
+            return;
+        }
+        match data {
+            ValueRangeSwitchIntData::Value(place) => {
+                let ty = place.ty(self.body, self.tcx).ty;
+                let mut range = state.ranges.get(place.as_ref(), &self.map).intersect(self.top(ty));
+                match value {
+                    SwitchTargetValue::Normal(value) => {
+                        range = range.intersect(ValueRange::exactly(self.from_bits(ty, value)));
+                    }
+                    SwitchTargetValue::Otherwise => {
+                        // The bounds of the range may be values of other targets.
+                        loop {
+                            let excluded =
+                                targets.all_values().iter().fold(range, |range, value| {
+                                    range.exclude(self.from_bits(ty, value.get()))
+                                });
+                            if excluded == range {
+                                break;
+                            }
+                            range = excluded;
+                        }
+                    }
+                }
+                self.refine(state, &[*place], range);
+            }
+            ValueRangeSwitchIntData::Comparison { op, left, right, left_places, right_places } => {
+                let holds = match value {
+                    SwitchTargetValue::Normal(value) => value != 0,
+                    SwitchTargetValue::Otherwise => {
+                        let values = targets.all_values();
+                        let has_false = values.iter().any(|value| value.get() == 0);
+                        let has_true = values.iter().any(|value| value.get() == 1);
+                        match (has_false, has_true) {
+                            (true, false) => true,
+                            (false, true) => false,
+                            _ => return,
+                        }
+                    }
+                };
+                let op = if holds { *op } else { negate_comparison(*op) };
+                if let Some(left) = self.relation_operand(left)
+                    && let Some(right) = self.relation_operand(right)
+                {
+                    match op {
+                        BinOp::Lt => state.relations.add_less_than(left, right),
+                        BinOp::Gt => state.relations.add_less_than(right, left),
+                        _ => {}
+                    }
+                }
+                let left_range = self.operand_range(left, state);
+                let right_range = self.operand_range(right, state);
+                let (
+                    ValueRange::Range(left_start, left_end),
+                    ValueRange::Range(right_start, right_end),
+                ) = (left_range, right_range)
+                else {
+                    return;
+                };
+                let (left_range, right_range) = match op {
+                    BinOp::Lt => (
+                        ValueRange::Range(left_start, left_end.min(right_end - 1)),
+                        ValueRange::Range(right_start.max(left_start + 1), right_end),
+                    ),
+                    BinOp::Le => (
+                        ValueRange::Range(left_start, left_end.min(right_end)),
+                        ValueRange::Range(right_start.max(left_start), right_end),
+                    ),
+                    BinOp::Gt => (
+                        ValueRange::Range(left_start.max(right_start + 1), left_end),
+                        ValueRange::Range(right_start, right_end.min(left_end - 1)),
+                    ),
+                    BinOp::Ge => (
+                        ValueRange::Range(left_start.max(right_start), left_end),
+                        ValueRange::Range(right_start, right_end.min(left_end)),
+                    ),
+                    BinOp::Eq => {
+                        let range = left_range.intersect(right_range);
+                        (range, range)
+                    }
+                    BinOp::Ne => {
+                        let left_range = if right_start == right_end {
+                            left_range.exclude(right_start)
+                        } else {
+                            left_range
+                        };
+                        let right_range = if left_start == left_end {
+                            right_range.exclude(left_start)
+                        } else {
+                            right_range
+                        };
+                        (left_range, right_range)
+                    }
+                    _ => unreachable!(),
+                };
+                self.refine(state, left_places, left_range);
+                self.refine(state, right_places, right_range);
+            }
+        }
+    }
+}
+
+/// Returns the comparison that holds when `op` does not.
+fn negate_comparison(op: BinOp) -> BinOp {
+    match op {
+        BinOp::Eq => BinOp::Ne,
+        BinOp::Ne => BinOp::Eq,
+        BinOp::Lt => BinOp::Ge,
+        BinOp::Le => BinOp::Gt,
+        BinOp::Gt => BinOp::Le,
+        BinOp::Ge => BinOp::Lt,
+        _ => unreachable!(),
+    }
+}
+
+/// Returns whether one of `statements` may change the value of `place`.
+fn is_modified(statements: &[Statement<'_>], place: Place<'_>) -> bool {
+    statements.iter().any(|statement| match &statement.kind {
+        StatementKind::Assign(box (lhs, _))
+        | StatementKind::SetDiscriminant { place: box lhs, .. }
+        | StatementKind::Deinit(box lhs) => lhs.local == place.local,
+        StatementKind::StorageLive(local) | StatementKind::StorageDead(local) => {
+            *local == place.local
+        }
+        _ => false,
+    })
+}
+
+impl<'a, 'tcx> ValueRangeAnalysis<'a, 'tcx> {
+    pub fn new(tcx: TyCtxt<'tcx>, body: &'a Body<'tcx>, map: Map<'tcx>) -> Self {
+        let excluded = excluded_locals(body);
+        ValueRangeAnalysis { tcx, body, map, typing_env: body.typing_env(tcx), excluded }
+    }
+
+    /// Returns the range of values of `operand`, which is `Top` if its type is not tracked.
+    pub fn operand_range(&self, operand: &Operand<'tcx>, state: &ValueRangeState) -> ValueRange {
+        let ty = operand.ty(self.body, self.tcx);
+        let range = match operand {
+            Operand::Constant(box constant) => {
+                match constant.const_.try_eval_scalar_int(self.tcx, self.typing_env) {
+                    Some(value) if self.bounds(ty).is_some() => {
+                        ValueRange::exactly(self.from_bits(ty, value.to_bits_unchecked()))
+                    }
+                    _ => ValueRange::Top,
+                }
+            }
+            Operand::Copy(place) | Operand::Move(place) => {
+                let range = state.ranges.get(place.as_ref(), &self.map);
+                match self.relation_operand(operand) {
+                    Some(place) => self.bound_by_relations(place, range, state),
+                    None => range,
+                }
+            }
+        };
+        range.intersect(self.top(ty))
+    }
+
+    /// Restricts `range`, the range of `place`, to the values less than the places known to be
+    /// greater than `place`.
+    fn bound_by_relations(
+        &self,
+        place: RelationPlace,
+        mut range: ValueRange,
+        state: &ValueRangeState,
+    ) -> ValueRange {
+        for greater in state.relations.greater_than(place) {
+            if let ValueRange::Range(_, end) = self.relation_range(greater, state) {
+                range = range.intersect(ValueRange::Range(i128::MIN, end - 1));
+            }
+        }
+        range
+    }
+
+    /// Returns the range of values of `place`, without using its relations.
+    fn relation_range(&self, place: RelationPlace, state: &ValueRangeState) -> ValueRange {
+        match place {
+            RelationPlace::Local(local) => state
+                .ranges
+                .get(Place::from(local).as_ref(), &self.map)
+                .intersect(self.top(self.body.local_decls[local].ty)),
+            RelationPlace::Len(local) => state
+                .ranges
+                .get_len(Place::from(local).as_ref(), &self.map)
+                .intersect(self.top(self.tcx.types.usize)),
+        }
+    }
+
+    /// Returns the local that relations are tracked for, if `place` is one.
+    fn relation_local(&self, place: Place<'tcx>) -> Option<Local> {
+        place.as_local().filter(|&local| !self.excluded.contains(local))
+    }
+
+    /// Returns the integer local that `operand` reads, if relations are tracked for it.
+    fn relation_operand(&self, operand: &Operand<'tcx>) -> Option<RelationPlace> {
+        let local = self.relation_local(operand.place()?)?;
+        self.bounds(self.body.local_decls[local].ty)?;
+        Some(RelationPlace::Local(local))
+    }
+
+    /// Returns the length of `place`, if it is the slice or `str` behind a pointer local that
+    /// relations are tracked for.
+    fn relation_pointee(&self, place: Place<'tcx>) -> Option<RelationPlace> {
+        match place.as_ref() {
+            PlaceRef { local, projection: [ProjectionElem::Deref] } => {
+                self.relation_len(Place::from(local))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the length of the slice or `str` that `place` points to, if relations are tracked
+    /// for it.
+    fn relation_len(&self, place: Place<'tcx>) -> Option<RelationPlace> {
+        let local = self.relation_local(place)?;
+        let pointee = self.body.local_decls[local].ty.builtin_deref(true)?;
+        matches!(pointee.kind(), ty::Slice(_) | ty::Str).then_some(RelationPlace::Len(local))
+    }
+
+    /// Forgets the relations of the local of `place`, if it changes when `place` is written to.
+    fn forget(&self, state: &mut ValueRangeState, place: Place<'tcx>) {
+        if !place.is_indirect() {
+            state.relations.forget(place.local);
+        }
+    }
+
+    /// Returns the relation place that is assigned to when `target` is.
+    fn relation_target(&self, target: Place<'tcx>) -> Option<RelationPlace> {
+        let local = self.relation_local(target)?;
+        if self.bounds(self.body.local_decls[local].ty).is_some() {
+            Some(RelationPlace::Local(local))
+        } else {
+            self.relation_len(target)
+        }
+    }
+
+    /// Returns the relation place whose value `rvalue` assigns to the relation place `target`.
+    fn relation_source(
+        &self,
+        target: RelationPlace,
+        rvalue: &Rvalue<'tcx>,
+    ) -> Option<RelationPlace> {
+        match (target, rvalue) {
+            (RelationPlace::Local(_), Rvalue::Use(operand)) => self.relation_operand(operand),
+            (
+                RelationPlace::Local(_),
+                Rvalue::UnaryOp(UnOp::PtrMetadata, Operand::Copy(place) | Operand::Move(place)),
+            )
+            | (RelationPlace::Len(_), Rvalue::Use(Operand::Copy(place) | Operand::Move(place))) => {
+                self.relation_len(*place)
+            }
+            (RelationPlace::Local(_), Rvalue::Len(place))
+            | (RelationPlace::Len(_), Rvalue::Ref(_, _, place) | Rvalue::RawPtr(_, place)) => {
+                self.relation_pointee(*place)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the smallest and largest values of `ty`, if values of this type are tracked.
+    fn bounds(&self, ty: Ty<'tcx>) -> Option<(i128, i128)> {
+        match ty.kind() {
+            ty::Bool => Some((0, 1)),
+            ty::Int(_) | ty::Uint(_) => {
+                let size = ty.primitive_size(self.tcx);
+                if size.bits() > 64 {
+                    None
+                } else if ty.is_signed() {
+                    Some((size.signed_int_min(), size.signed_int_max()))
+                } else {
+                    Some((0, size.unsigned_int_max() as i128))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the range of all the values of `ty`, or `Top` if its values are not tracked.
+    fn top(&self, ty: Ty<'tcx>) -> ValueRange {
+        self.bounds(ty).map_or(ValueRange::Top, |(min, max)| ValueRange::Range(min, max))
+    }
+
+    /// Interprets the bits of a value of a tracked type.
+    fn from_bits(&self, ty: Ty<'tcx>, bits: u128) -> i128 {
+        let size = ty.primitive_size(self.tcx);
+        if ty.is_signed() { size.sign_extend(bits) } else { size.truncate(bits) as i128 }
+    }
+
+    /// The opposite of `from_bits`.
+    fn to_bits(&self, ty: Ty<'tcx>, value: i128) -> Option<u128> {
+        self.bounds(ty)?;
+        Some(ty.primitive_size(self.tcx).truncate(value as u128))
+    }
+
+    /// Returns the places that hold the value of `operand`, the operand of the comparison at
+    /// `statements[index]`, until the end of the block.
+    fn comparand_places(
+        &self,
+        statements: &[Statement<'tcx>],
+        index: usize,
+        operand: &Operand<'tcx>,
+    ) -> SmallVec<[Place<'tcx>; 2]> {
+        let mut places = SmallVec::new();
+        let Some(place) = operand.place() else { return places };
+        places.push(place);
+
+        // The operand may be a copy of another place, made earlier in this block.
+        for (copy_index, statement) in statements[..index].iter().enumerate().rev() {
+            if !is_modified(slice::from_ref(statement), place) {
+                continue;
+            }
+            if let StatementKind::Assign(box (lhs, rvalue)) = &statement.kind
+                && *lhs == place
+                && let Rvalue::Use(Operand::Copy(source) | Operand::Move(source)) = rvalue
+                && !is_modified(&statements[copy_index + 1..], *source)
+            {
+                places.push(*source);
+            }
+            break;
+        }
+        places
+    }
+
+    /// Restricts the places to `range`, all of them holding the same value.
+    fn refine(&self, state: &mut ValueRangeState, places: &[Place<'tcx>], range: ValueRange) {
+        let empty = match range {
+            ValueRange::Range(start, end) => start > end,
+            range => range.is_bottom(),
+        };
+        if empty {
+            // The edge cannot be taken.
+            state.set_unreachable();
+            return;
+        }
+        for place in places {
+            if let Some(place) = self.map.find(place.as_ref()) {
+                let range = state.ranges.get_idx(place, &self.map).intersect(range);
+                if range.is_bottom() {
+                    state.set_unreachable();
+                    return;
+                }
+                state.ranges.insert_value_idx(place, range, &self.map);
+            }
+        }
+    }
+
+    fn handle_statement(&self, statement: &Statement<'tcx>, state: &mut ValueRangeState) {
+        match &statement.kind {
+            StatementKind::Assign(box (place, rvalue)) => {
+                self.handle_assign(*place, rvalue, state);
+            }
+            StatementKind::SetDiscriminant { box place, .. } => {
+                state.ranges.flood_discr(place.as_ref(), &self.map);
+                self.forget(state, *place);
+            }
+            StatementKind::StorageLive(local) | StatementKind::StorageDead(local) => {
+                // StorageLive leaves the local in an uninitialized state.
+                // StorageDead makes it UB to access the local afterwards.
+                let place = Place::from(*local);
+                state.ranges.flood_with(place.as_ref(), &self.map, ValueRange::BOTTOM);
+                self.forget(state, place);
+            }
+            StatementKind::Deinit(box place) => {
+                // Deinit makes the place uninitialized.
+                state.ranges.flood_with(place.as_ref(), &self.map, ValueRange::BOTTOM);
+                self.forget(state, *place);
+            }
+            StatementKind::Intrinsic(..)
+            | StatementKind::Retag(..)
+            | StatementKind::ConstEvalCounter
+            | StatementKind::Nop
+            | StatementKind::FakeRead(..)
+            | StatementKind::PlaceMention(..)
+            | StatementKind::Coverage(..)
+            | StatementKind::BackwardIncompatibleDropHint { .. }
+            | StatementKind::AscribeUserType(..) => {}
+        }
+    }
+
+    fn handle_assign(
+        &self,
+        target: Place<'tcx>,
+        rvalue: &Rvalue<'tcx>,
+        state: &mut ValueRangeState,
+    ) {
+        let relation = self.relation_target(target).map(|target_relation| {
+            (target_relation, self.relation_source(target_relation, rvalue))
+        });
+        self.assign_range(target, rvalue, state);
+        self.forget(state, target);
+        if let Some((target, Some(source))) = relation
+            && source.local() != target.local()
+        {
+            state.relations.add_equal(target, source);
+        }
+    }
+
+    fn assign_range(
+        &self,
+        target: Place<'tcx>,
+        rvalue: &Rvalue<'tcx>,
+        state: &mut ValueRangeState,
+    ) {
+        match rvalue {
+            Rvalue::Aggregate(kind, operands)
+                if matches!(
+                    **kind,
+                    AggregateKind::Tuple | AggregateKind::Adt(_, _, _, _, None)
+                ) && !target.ty(self.body, self.tcx).ty.is_enum() =>
+            {
+                state.ranges.flood(target.as_ref(), &self.map);
+                let Some(target) = self.map.find(target.as_ref()) else { return };
+                for (field_index, operand) in operands.iter_enumerated() {
+                    if let Some(field) = self.map.apply(target, TrackElem::Field(field_index)) {
+                        let value = self.handle_operand(operand, state);
+                        self.insert(&mut state.ranges, field, value);
+                    }
+                }
+            }
+            Rvalue::BinaryOp(op, box (left, right)) if op.is_overflowing() => {
+                state.ranges.flood(target.as_ref(), &self.map);
+                let Some(target) = self.map.find(target.as_ref()) else { return };
+                let (value, overflow) = self.overflowing_binary_op(*op, left, right, state);
+                if let Some(value_target) = self.map.apply(target, TrackElem::Field(0_u32.into())) {
+                    state.ranges.insert_value_idx(value_target, value, &self.map);
+                }
+                if let Some(overflow_target) =
+                    self.map.apply(target, TrackElem::Field(1_u32.into()))
+                {
+                    state.ranges.insert_value_idx(overflow_target, overflow, &self.map);
+                }
+            }
+            Rvalue::Cast(
+                CastKind::PointerCoercion(ty::adjustment::PointerCoercion::Unsize, _),
+                operand,
+                _,
+            ) => {
+                let pointer = self.handle_operand(operand, state);
+                state.ranges.assign(target.as_ref(), pointer, &self.map);
+
+                if let Some(target_len) = self.map.find_len(target.as_ref())
+                    && let Some(operand_ty) = operand.ty(self.body, self.tcx).builtin_deref(true)
+                    && let ty::Array(_, len) = operand_ty.kind()
+                    && let Some(len) = len.try_to_target_usize(self.tcx)
+                {
+                    let len = ValueRange::exactly(i128::from(len));
+                    state.ranges.insert_value_idx(target_len, len, &self.map);
+                }
+            }
+            _ => {
+                let result = self.handle_rvalue(rvalue, state);
+                state.ranges.assign(target.as_ref(), result, &self.map);
+            }
+        }
+    }
+
+    /// Assigns `value` to the tracked place `target`, which must have been flooded.
+    fn insert(
+        &self,
+        state: &mut State<ValueRange>,
+        target: PlaceIndex,
+        value: ValueOrPlace<ValueRange>,
+    ) {
+        match value {
+            ValueOrPlace::Value(value) => state.insert_value_idx(target, value, &self.map),
+            ValueOrPlace::Place(source) => state.insert_place_idx(target, source, &self.map),
+        }
+    }
+
+    fn handle_operand(
+        &self,
+        operand: &Operand<'tcx>,
+        state: &ValueRangeState,
+    ) -> ValueOrPlace<ValueRange> {
+        match operand {
+            Operand::Constant(..) => ValueOrPlace::Value(self.operand_range(operand, state)),
+            Operand::Copy(place) | Operand::Move(place) => {
+                self.map.find(place.as_ref()).map(ValueOrPlace::Place).unwrap_or(ValueOrPlace::TOP)
+            }
+        }
+    }
+
+    fn handle_rvalue(
+        &self,
+        rvalue: &Rvalue<'tcx>,
+        state: &ValueRangeState,
+    ) -> ValueOrPlace<ValueRange> {
+        let range = match rvalue {
+            Rvalue::Use(operand) => return self.handle_operand(operand, state),
+            Rvalue::CopyForDeref(place) => {
+                return self.handle_operand(&Operand::Copy(*place), state);
+            }
+            Rvalue::Len(place) => {
+                let place_ty = place.ty(self.body, self.tcx).ty;
+                if let ty::Array(_, len) = place_ty.kind()
+                    && let Some(len) = len.try_to_target_usize(self.tcx)
+                {
+                    ValueRange::exactly(i128::from(len))
+                } else if let [ProjectionElem::Deref] = place.projection[..] {
+                    state.ranges.get_len(place.local.into(), &self.map)
+                } else {
+                    ValueRange::Top
+                }
+            }
+            Rvalue::UnaryOp(UnOp::PtrMetadata, Operand::Copy(place) | Operand::Move(place)) => {
+                state.ranges.get_len(place.as_ref(), &self.map)
+            }
+            Rvalue::UnaryOp(op @ (UnOp::Not | UnOp::Neg), operand) => {
+                let ty = operand.ty(self.body, self.tcx);
+                match (self.operand_range(operand, state), self.bounds(ty)) {
+                    (ValueRange::Range(start, end), Some((min, max))) => match op {
+                        // Either `1 - x` for `bool`, `MAX - x` for unsigned integers or
+                        // `-1 - x` for signed ones.
+                        UnOp::Not => ValueRange::Range(min + max - end, min + max - start),
+                        // The negation of `MIN` wraps around.
+                        _ if start > min => ValueRange::Range(-end, -start),
+                        _ => ValueRange::Top,
+                    },
+                    (ValueRange::Bottom, _) => ValueRange::Bottom,
+                    _ => ValueRange::Top,
+                }
+            }
+            Rvalue::Cast(CastKind::IntToInt, operand, ty) => {
+                match (self.operand_range(operand, state), self.bounds(*ty)) {
+                    (ValueRange::Range(start, end), Some((min, max)))
+                        if min <= start && end <= max =>
+                    {
+                        ValueRange::Range(start, end)
+                    }
+                    (ValueRange::Bottom, _) => ValueRange::Bottom,
+                    _ => ValueRange::Top,
+                }
+            }
+            Rvalue::BinaryOp(op, box (left, right)) => self.binary_op(*op, left, right, state),
+            _ => ValueRange::Top,
+        };
+        ValueOrPlace::Value(range)
+    }
+
+    /// Returns the range of `left op right` for an operator that does not return an overflow
+    /// flag.
+    fn binary_op(
+        &self,
+        op: BinOp,
+        left: &Operand<'tcx>,
+        right: &Operand<'tcx>,
+        state: &ValueRangeState,
+    ) -> ValueRange {
+        let ty = left.ty(self.body, self.tcx);
+        let Some((min, max)) = self.bounds(ty) else { return ValueRange::Top };
+        if let Some(left) = self.relation_operand(left)
+            && let Some(right) = self.relation_operand(right)
+        {
+            let less = state.relations.is_less_than(left, right);
+            let greater = state.relations.is_less_than(right, left);
+            match op {
+                BinOp::Lt | BinOp::Le if less => return ValueRange::exactly(1),
+                BinOp::Gt | BinOp::Ge if greater => return ValueRange::exactly(1),
+                BinOp::Lt | BinOp::Le if greater => return ValueRange::exactly(0),
+                BinOp::Gt | BinOp::Ge if less => return ValueRange::exactly(0),
+                BinOp::Eq if less || greater => return ValueRange::exactly(0),
+                BinOp::Ne if less || greater => return ValueRange::exactly(1),
+                _ => {}
+            }
+        }
+        let (l_start, l_end, r_start, r_end) =
+            match (self.operand_range(left, state), self.operand_range(right, state)) {
+                (ValueRange::Range(l_start, l_end), ValueRange::Range(r_start, r_end)) => {
+                    (l_start, l_end, r_start, r_end)
+                }
+                (ValueRange::Bottom, _) | (_, ValueRange::Bottom) => return ValueRange::Bottom,
+                _ => return ValueRange::Top,
+            };
+        let boolean = |always: bool, never: bool| match (always, never) {
+            (true, _) => ValueRange::exactly(1),
+            (_, true) => ValueRange::exactly(0),
+            _ => ValueRange::Range(0, 1),
+        };
+
+        match op {
+            BinOp::Add | BinOp::Sub | BinOp::Mul => {
+                // These wrap around on overflow.
+                match self.arithmetic(op, l_start, l_end, r_start, r_end) {
+                    Some((start, end)) if min <= start && end <= max => {
+                        ValueRange::Range(start, end)
+                    }
+                    _ => ValueRange::Top,
+                }
+            }
+            BinOp::AddUnchecked | BinOp::SubUnchecked | BinOp::MulUnchecked => {
+                // Overflowing is UB.
+                match self.arithmetic(op, l_start, l_end, r_start, r_end) {
+                    Some((start, end)) => {
+                        ValueRange::Range(start, end).intersect(ValueRange::Range(min, max))
+                    }
+                    None => ValueRange::Top,
+                }
+            }
+            BinOp::Div if l_start >= 0 && r_start > 0 => {
+                ValueRange::Range(l_start / r_end, l_end / r_start)
+            }
+            BinOp::Rem if l_start >= 0 && r_start > 0 => {
+                if l_end < r_start {
+                    ValueRange::Range(l_start, l_end)
+                } else {
+                    ValueRange::Range(0, l_end.min(r_end - 1))
+                }
+            }
+            BinOp::BitAnd if l_start >= 0 || r_start >= 0 => {
+                // The result is not negative, and not larger than any non-negative operand.
+                let end = match (l_start >= 0, r_start >= 0) {
+                    (true, true) => l_end.min(r_end),
+                    (true, false) => l_end,
+                    _ => r_end,
+                };
+                ValueRange::Range(0, end)
+            }
+            BinOp::BitOr | BinOp::BitXor if l_start >= 0 && r_start >= 0 => {
+                // The result does not have more bits than the largest operand.
+                let end = widen_up(l_end.max(r_end));
+                let start = if op == BinOp::BitOr { l_start.max(r_start) } else { 0 };
+                ValueRange::Range(start, end)
+            }
+            BinOp::Shr | BinOp::ShrUnchecked if l_start >= 0 => {
+                let bits = i128::from(ty.primitive_size(self.tcx).bits());
+                if r_start >= 0 && r_end < bits {
+                    ValueRange::Range(l_start >> r_end, l_end >> r_start)
+                } else {
+                    ValueRange::Range(0, l_end)
+                }
+            }
+            BinOp::Eq if l_start == l_end && (l_start, l_end) == (r_start, r_end) => {
+                ValueRange::exactly(1)
+            }
+            BinOp::Eq => boolean(false, l_end < r_start || r_end < l_start),
+            BinOp::Ne if l_start == l_end && (l_start, l_end) == (r_start, r_end) => {
+                ValueRange::exactly(0)
+            }
+            BinOp::Ne => boolean(l_end < r_start || r_end < l_start, false),
+            BinOp::Lt => boolean(l_end < r_start, l_start >= r_end),
+            BinOp::Le => boolean(l_end <= r_start, l_start > r_end),
+            BinOp::Gt => boolean(l_start > r_end, l_end <= r_start),
+            BinOp::Ge => boolean(l_start >= r_end, l_end < r_start),
+            _ => ValueRange::Top,
+        }
+    }
+
+    /// Returns the ranges of the value and of the overflow flag of `left op right`, where `op`
+    /// is one of the `*WithOverflow` operators.
+    fn overflowing_binary_op(
+        &self,
+        op: BinOp,
+        left: &Operand<'tcx>,
+        right: &Operand<'tcx>,
+        state: &ValueRangeState,
+    ) -> (ValueRange, ValueRange) {
+        let ty = left.ty(self.body, self.tcx);
+        let Some((min, max)) = self.bounds(ty) else { return (ValueRange::Top, ValueRange::Top) };
+        match (self.operand_range(left, state), self.operand_range(right, state)) {
+            (ValueRange::Range(l_start, l_end), ValueRange::Range(r_start, r_end)) => {
+                let op = op.overflowing_to_wrapping().unwrap();
+                match self.arithmetic(op, l_start, l_end, r_start, r_end) {
+                    Some((start, end)) if min <= start && end <= max => {
+                        (ValueRange::Range(start, end), ValueRange::exactly(0))
+                    }
+                    Some((start, end)) if end < min || max < start => {
+                        (ValueRange::Top, ValueRange::exactly(1))
+                    }
+                    _ => (ValueRange::Top, ValueRange::Range(0, 1)),
+                }
+            }
+            (ValueRange::Bottom, _) | (_, ValueRange::Bottom) => {
+                (ValueRange::Bottom, ValueRange::Bottom)
+            }
+            _ => (ValueRange::Top, ValueRange::Top),
+        }
+    }
+
+    /// Returns the range of the mathematical result of `Add`, `Sub` or `Mul`, or their unchecked
+    /// variants, if it fits in an `i128`.
+    fn arithmetic(
+        &self,
+        op: BinOp,
+        l_start: i128,
+        l_end: i128,
+        r_start: i128,
+        r_end: i128,
+    ) -> Option<(i128, i128)> {
+        match op {
+            BinOp::Add | BinOp::AddUnchecked => Some((l_start + r_start, l_end + r_end)),
+            BinOp::Sub | BinOp::SubUnchecked => Some((l_start - r_end, l_end - r_start)),
+            BinOp::Mul | BinOp::MulUnchecked => {
+                let products = [
+                    l_start.checked_mul(r_start)?,
+                    l_start.checked_mul(r_end)?,
+                    l_end.checked_mul(r_start)?,
+                    l_end.checked_mul(r_end)?,
+                ];
+                Some((*products.iter().min()?, *products.iter().max()?))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// This is used to visualize the dataflow analysis.
+impl<'tcx> DebugWithContext<ValueRangeAnalysis<'_, 'tcx>> for ValueRangeState {
+    fn fmt_with(
+        &self,
+        ctxt: &ValueRangeAnalysis<'_, 'tcx>,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match &self.ranges {
+            State::Reachable(values) => debug_with_context(values, None, &ctxt.map, f)?,
+            State::Unreachable => return write!(f, "unreachable"),
+        }
+        for (a, b) in &self.relations.less_than {
+            writeln!(f, "{a:?} < {b:?}")?;
+        }
+        Ok(())
+    }
+
+    fn fmt_diff_with(
+        &self,
+        old: &Self,
+        ctxt: &ValueRangeAnalysis<'_, 'tcx>,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match (&self.ranges, &old.ranges) {
+            (State::Reachable(this), State::Reachable(old)) => {
+                debug_with_context(this, Some(old), &ctxt.map, f)?
+            }
+            _ => return Ok(()),
+        }
+        for (a, b) in old.relations.less_than.difference(&self.relations.less_than) {
+            writeln!(f, "\u{001f}-{a:?} < {b:?}")?;
+        }
+        for (a, b) in self.relations.less_than.difference(&old.relations.less_than) {
+            writeln!(f, "\u{001f}+{a:?} < {b:?}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,366 @@
+use rustc_abi::{BackendRepr, Primitive, Size, WrappingRange};
+use rustc_hir::LangItem;
+use rustc_index::IndexVec;
+use rustc_middle::mir::interpret::Scalar;
+use rustc_middle::mir::visit::Visitor;
+use rustc_middle::mir::*;
+use rustc_middle::ty::layout::IntegerExt;
+use rustc_middle::ty::{self, Ty, TyCtxt, TypingEnv};
+use rustc_session::Session;
+use tracing::debug;
+
+/// This pass inserts checks that the values of types with a restricted scalar validity range,
+/// like `bool`, `char`, `NonZero` or references, are in that range. Like `CheckEnums`, it only
+/// checks where invalid values are most likely to appear: when they are read through a raw
+/// pointer, and when they are created with a transmute. Enums are left to `CheckEnums`.
+///
+/// References to uninhabited types are not checked, as it is not settled whether creating one is
+/// undefined behavior.
+pub(super) struct CheckScalarValidity;
+
+impl<'tcx> crate::MirPass<'tcx> for CheckScalarValidity {
+    fn is_enabled(&self, sess: &Session) -> bool {
+        sess.ub_checks()
+    }
+
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        // This pass emits new panics. If for whatever reason we do not have a panic
+        // implementation, running this pass may cause otherwise-valid code to not compile.
+        if tcx.lang_items().get(LangItem::PanicImpl).is_none() {
+            return;
+        }
+
+        let typing_env = body.typing_env(tcx);
+        let basic_blocks = body.basic_blocks.as_mut();
+        let local_decls = &mut body.local_decls;
+
+        // This operation inserts new blocks. Each insertion changes the Location for all
+        // statements/blocks after. Iterating or visiting the MIR in order would require updating
+        // our current location after every insertion. By iterating backwards, we dodge this issue:
+        // The only Locations that an insertion changes have already been handled.
+        for block in basic_blocks.indices().rev() {
+            // The terminator comes last, so its checks are inserted first. Its operands include
+            // call arguments and `SwitchInt` discriminants.
+            let terminator = basic_blocks[block].terminator();
+            let location =
+                Location { block, statement_index: basic_blocks[block].statements.len() };
+            let source_info = terminator.source_info;
+
+            let mut finder = ScalarFinder::new(tcx, local_decls, typing_env);
+            finder.visit_terminator(terminator, location);
+            let scalars = finder.into_found_scalars();
+
+            insert_scalar_checks(tcx, local_decls, basic_blocks, scalars, source_info, location);
+
+            for statement_index in (0..basic_blocks[block].statements.len()).rev() {
+                let location = Location { block, statement_index };
+                let statement = &basic_blocks[block].statements[statement_index];
+                let source_info = statement.source_info;
+
+                let mut finder = ScalarFinder::new(tcx, local_decls, typing_env);
+                finder.visit_statement(statement, location);
+                let scalars = finder.into_found_scalars();
+
+                insert_scalar_checks(
+                    tcx,
+                    local_decls,
+                    basic_blocks,
+                    scalars,
+                    source_info,
+                    location,
+                );
+            }
+        }
+    }
+
+    fn is_required(&self) -> bool {
+        true
+    }
+}
+
+/// Where the value to check comes from.
+#[derive(Clone, Debug)]
+enum ScalarSource<'tcx> {
+    /// The value is read from this place, which is behind a raw pointer.
+    Load(Place<'tcx>),
+    /// The value is transmuted from this operand.
+    Transmute(Operand<'tcx>),
+}
+
+/// A value that must be in `valid_range`, when read as an unsigned integer of type `int_ty`.
+struct ScalarCheck<'tcx> {
+    source: ScalarSource<'tcx>,
+    int_ty: Ty<'tcx>,
+    size: Size,
+    valid_range: WrappingRange,
+}
+
+/// A [Visitor] that finds the reads and transmutes of values with a restricted validity range.
+struct ScalarFinder<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    local_decls: &'a mut LocalDecls<'tcx>,
+    typing_env: TypingEnv<'tcx>,
+    scalars: Vec<ScalarCheck<'tcx>>,
+}
+
+impl<'a, 'tcx> ScalarFinder<'a, 'tcx> {
+    fn new(
+        tcx: TyCtxt<'tcx>,
+        local_decls: &'a mut LocalDecls<'tcx>,
+        typing_env: TypingEnv<'tcx>,
+    ) -> Self {
+        ScalarFinder { tcx, local_decls, typing_env, scalars: Vec::new() }
+    }
+
+    /// Returns the found values and which checks should be inserted.
+    fn into_found_scalars(self) -> Vec<ScalarCheck<'tcx>> {
+        self.scalars
+    }
+
+    /// Returns the integer type, size and validity range of `ty`, if it is a scalar whose
+    /// validity range does not cover all its values.
+    fn restricted_scalar(&self, ty: Ty<'tcx>) -> Option<(Ty<'tcx>, Size, WrappingRange)> {
+        // Enums are checked by `CheckEnums`.
+        if let ty::Adt(adt_def, _) = ty.kind()
+            && adt_def.is_enum()
+        {
+            return None;
+        }
+        let layout = self.tcx.layout_of(self.typing_env.as_query_input(ty)).ok()?;
+        let BackendRepr::Scalar(scalar) = layout.backend_repr else {
+            return None;
+        };
+        if scalar.is_always_valid(&self.tcx) {
+            return None;
+        }
+        let int_ty = match scalar.primitive() {
+            Primitive::Int(integer, _) => integer.to_ty(self.tcx, false),
+            Primitive::Pointer(_) => {
+                self.tcx.data_layout.ptr_sized_integer().to_ty(self.tcx, false)
+            }
+            Primitive::Float(_) => return None,
+        };
+        Some((int_ty, scalar.size(&self.tcx), scalar.valid_range(&self.tcx)))
+    }
+
+    /// Records that the value of type `ty` from `source` must be checked against `valid_range`.
+    /// A `char` must additionally not be a surrogate, which its layout range does not exclude.
+    fn push_checks(
+        &mut self,
+        source: ScalarSource<'tcx>,
+        ty: Ty<'tcx>,
+        int_ty: Ty<'tcx>,
+        size: Size,
+        valid_range: WrappingRange,
+        check_surrogates: bool,
+    ) {
+        // Checks are inserted in reverse, so the surrogate check runs after the range check.
+        if check_surrogates && ty.is_char() {
+            self.scalars.push(ScalarCheck {
+                source: source.clone(),
+                int_ty,
+                size,
+                valid_range: WrappingRange { start: 0xE000, end: 0xD7FF },
+            });
+        }
+        self.scalars.push(ScalarCheck { source, int_ty, size, valid_range });
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ScalarFinder<'a, 'tcx> {
+    fn visit_operand(&mut self, operand: &Operand<'tcx>, location: Location) {
+        if let Operand::Copy(place) | Operand::Move(place) = operand
+            && place.is_indirect_first_projection()
+            && self.local_decls[place.local].ty.is_raw_ptr()
+            && let ty = place.ty(self.local_decls, self.tcx).ty
+            && let Some((int_ty, size, valid_range)) = self.restricted_scalar(ty)
+        {
+            self.push_checks(ScalarSource::Load(*place), ty, int_ty, size, valid_range, true);
+        }
+
+        self.super_operand(operand, location);
+    }
+
+    fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
+        if let Rvalue::Cast(CastKind::Transmute, op, ty) = rvalue
+            && let Some((int_ty, size, valid_range)) = self.restricted_scalar(*ty)
+        {
+            // A transmute from a type with the same or a smaller validity range cannot create
+            // an invalid value.
+            let op_ty = op.ty(self.local_decls, self.tcx);
+            let op_range =
+                self.tcx.layout_of(self.typing_env.as_query_input(op_ty)).ok().and_then(|layout| {
+                    match layout.backend_repr {
+                        BackendRepr::Scalar(scalar) => Some(scalar.valid_range(&self.tcx)),
+                        _ => None,
+                    }
+                });
+            if !op_range.is_some_and(|op_range| valid_range.contains_range(op_range, size)) {
+                self.push_checks(
+                    ScalarSource::Transmute(op.to_copy()),
+                    *ty,
+                    int_ty,
+                    size,
+                    valid_range,
+                    !op_ty.is_char(),
+                );
+            }
+        }
+
+        self.super_rvalue(rvalue, location);
+    }
+}
+
+fn split_block(
+    basic_blocks: &mut IndexVec<BasicBlock, BasicBlockData<'_>>,
+    location: Location,
+) -> BasicBlock {
+    let block_data = &mut basic_blocks[location.block];
+
+    // Drain every statement after this one and move the current terminator to a new basic block.
+    let new_block = BasicBlockData::new_stmts(
+        block_data.statements.split_off(location.statement_index),
+        block_data.terminator.take(),
+        block_data.is_cleanup,
+    );
+
+    basic_blocks.push(new_block)
+}
+
+fn insert_scalar_checks<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    local_decls: &mut IndexVec<Local, LocalDecl<'tcx>>,
+    basic_blocks: &mut IndexVec<BasicBlock, BasicBlockData<'tcx>>,
+    checks: Vec<ScalarCheck<'tcx>>,
+    source_info: SourceInfo,
+    location: Location,
+) {
+    for check in checks {
+        debug!("Inserting scalar validity check for {:?}", check.source);
+        let new_block = split_block(basic_blocks, location);
+        insert_scalar_check(
+            tcx,
+            local_decls,
+            &mut basic_blocks[location.block],
+            check,
+            source_info,
+            new_block,
+        );
+    }
+}
+
+fn insert_scalar_check<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    local_decls: &mut IndexVec<Local, LocalDecl<'tcx>>,
+    block_data: &mut BasicBlockData<'tcx>,
+    check: ScalarCheck<'tcx>,
+    source_info: SourceInfo,
+    new_block: BasicBlock,
+) {
+    let ScalarCheck { source, int_ty, size, valid_range } = check;
+    let value = local_decls.push(LocalDecl::with_source_info(int_ty, source_info)).into();
+    match source {
+        ScalarSource::Load(place) => {
+            // Read the value as an integer, as reading an invalid value with its own type would
+            // already be UB.
+            let ty = place.ty(&*local_decls, tcx).ty;
+            let raw_ptr_ty = Ty::new_imm_ptr(tcx, ty);
+            let raw_ptr = local_decls.push(LocalDecl::with_source_info(raw_ptr_ty, source_info));
+            let rvalue = Rvalue::RawPtr(RawPtrKind::Const, place);
+            block_data.statements.push(Statement::new(
+                source_info,
+                StatementKind::Assign(Box::new((raw_ptr.into(), rvalue))),
+            ));
+
+            let int_ptr_ty = Ty::new_imm_ptr(tcx, int_ty);
+            let int_ptr = local_decls.push(LocalDecl::with_source_info(int_ptr_ty, source_info));
+            let rvalue =
+                Rvalue::Cast(CastKind::PtrToPtr, Operand::Copy(raw_ptr.into()), int_ptr_ty);
+            block_data.statements.push(Statement::new(
+                source_info,
+                StatementKind::Assign(Box::new((int_ptr.into(), rvalue))),
+            ));
+
+            let rvalue = Rvalue::Use(Operand::Copy(tcx.mk_place_deref(int_ptr.into())));
+            block_data.statements.push(Statement::new(
+                source_info,
+                StatementKind::Assign(Box::new((value, rvalue))),
+            ));
+        }
+        ScalarSource::Transmute(source_op) => {
+            let rvalue = Rvalue::Cast(CastKind::Transmute, source_op, int_ty);
+            block_data.statements.push(Statement::new(
+                source_info,
+                StatementKind::Assign(Box::new((value, rvalue))),
+            ));
+        }
+    }
+
+    // Widen the value to a u128 for the panic message.
+    let value_u128 =
+        local_decls.push(LocalDecl::with_source_info(tcx.types.u128, source_info)).into();
+    let rvalue = Rvalue::Cast(CastKind::IntToInt, Operand::Copy(value), tcx.types.u128);
+    block_data
+        .statements
+        .push(Statement::new(source_info, StatementKind::Assign(Box::new((value_u128, rvalue)))));
+
+    // Compare the value against the valid range. This is a `WrappingRange`, so subtract its
+    // start with wrapping arithmetic in the type of the value, and compare with its length.
+    let int_const = |bits: u128| {
+        Operand::Constant(Box::new(ConstOperand {
+            span: source_info.span,
+            user_ty: None,
+            const_: Const::Val(ConstValue::Scalar(Scalar::from_uint(bits, size)), int_ty),
+        }))
+    };
+    let u128_const = |bits: u128| {
+        Operand::Constant(Box::new(ConstOperand {
+            span: source_info.span,
+            user_ty: None,
+            const_: Const::Val(ConstValue::from_u128(bits), tcx.types.u128),
+        }))
+    };
+
+    let value_diff = local_decls.push(LocalDecl::with_source_info(int_ty, source_info)).into();
+    block_data.statements.push(Statement::new(
+        source_info,
+        StatementKind::Assign(Box::new((
+            value_diff,
+            Rvalue::BinaryOp(
+                BinOp::Sub,
+                Box::new((Operand::Copy(value), int_const(valid_range.start))),
+            ),
+        ))),
+    ));
+
+    let is_ok = local_decls.push(LocalDecl::with_source_info(tcx.types.bool, source_info)).into();
+    let range_len = size.truncate(valid_range.end.wrapping_sub(valid_range.start));
+    block_data.statements.push(Statement::new(
+        source_info,
+        StatementKind::Assign(Box::new((
+            is_ok,
+            Rvalue::BinaryOp(
+                BinOp::Le,
+                Box::new((Operand::Copy(value_diff), int_const(range_len))),
+            ),
+        ))),
+    ));
+
+    block_data.terminator = Some(Terminator {
+        source_info,
+        kind: TerminatorKind::Assert {
+            cond: Operand::Copy(is_ok),
+            expected: true,
+            target: new_block,
+            msg: Box::new(AssertKind::InvalidScalarValue {
+                value: Operand::Copy(value_u128),
+                start: u128_const(valid_range.start),
+                end: u128_const(valid_range.end),
+            }),
+            // This calls panic_invalid_scalar_value, which is #[rustc_nounwind].
+            // We never want to insert an unwind into unsafe code, because unwinding could
+            // make a failing UB check turn into much worse UB when we start unwinding.
+            unwind: UnwindAction::Unreachable,
+        },
+    });
+}
@@ -724,6 +724,11 @@ fn check_mir_is_available<'tcx, I: Inliner<'tcx>>(
             debug!("instance without MIR (intrinsic / virtual)");
             return Err("implementation limitation -- cannot inline intrinsic");
         }
+        // The MIR of this shim is outlined from the optimized MIR of its function.
+        InstanceKind::ColdPathShim(..) => {
+            debug!("outlined cold path");
+            return Err("implementation limitation -- cannot inline outlined cold path");
+        }
 
         // FIXME(#127030): `ConstParamHasTy` has bad interactions with
         // the drop shim builder, which does not evaluate predicates in
@@ -37,6 +37,9 @@ fn should_recurse<'tcx>(tcx: TyCtxt<'tcx>, callee: ty::Instance<'tcx>) -> bool {
         // This shim does not call any other functions, thus there can be no recursion.
         InstanceKind::FnPtrAddrShim(..) => return false,
 
+        // This shim is only called through function pointers, which the inliner does not follow.
+        InstanceKind::ColdPathShim(..) => return false,
+
         // FIXME: A not fully instantiated drop shim can cause ICEs if one attempts to
         // have its MIR built. Likely oli-obk just screwed up the `ParamEnv`s, so this
         // needs some more analysis.
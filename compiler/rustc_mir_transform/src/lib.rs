@@ -51,6 +51,7 @@
 mod lint;
 mod lint_tail_expr_drop_order;
 mod patch;
+mod polymorphize;
 mod shim;
 mod ssa;
 
@@ -120,6 +121,7 @@ macro_rules! declare_passes {
     mod check_enums : CheckEnums;
     mod check_const_item_mutation : CheckConstItemMutation;
     mod check_null : CheckNull;
+    mod check_scalar_validity : CheckScalarValidity;
     mod check_packed_ref : CheckPackedRef;
     // This pass is public to allow external drivers to perform MIR cleanup
     pub mod cleanup_post_borrowck : CleanupPostBorrowck;
@@ -155,12 +157,14 @@ mod dead_store_elimination : DeadStoreElimination {
     mod mentioned_items : MentionedItems;
     mod multiple_return_terminators : MultipleReturnTerminators;
     mod nrvo : RenameReturnPlace;
+    mod outline_cold_paths : OutlineColdPaths;
     mod post_drop_elaboration : CheckLiveDrops;
     mod prettify : ReorderBasicBlocks, ReorderLocals;
     mod promote_consts : PromoteTemps;
     mod ref_prop : ReferencePropagation;
     mod remove_noop_landing_pads : RemoveNoopLandingPads;
     mod remove_place_mention : RemovePlaceMention;
+    mod remove_redundant_asserts : RemoveRedundantAsserts;
     mod remove_storage_markers : RemoveStorageMarkers;
     mod remove_uninit_drops : RemoveUninitDrops;
     mod remove_unneeded_drops : RemoveUnneededDrops;
@@ -220,6 +224,7 @@ pub fn provide(providers: &mut Providers) {
         mir_inliner_callees: inline::cycle::mir_inliner_callees,
         promoted_mir,
         deduced_param_attrs: deduce_param_attrs::deduced_param_attrs,
+        unused_generic_params: polymorphize::unused_generic_params,
         coroutine_by_move_body_def_id: coroutine::coroutine_by_move_body_def_id,
         ..providers.queries
     };
@@ -668,6 +673,7 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
             &check_alignment::CheckAlignment,
             &check_null::CheckNull,
             &check_enums::CheckEnums,
+            &check_scalar_validity::CheckScalarValidity,
             // Before inlining: trim down MIR with passes to reduce inlining work.
 
             // Has to be done before inlining, otherwise actual call will be almost always inlined.
@@ -705,12 +711,15 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
             &simplify::SimplifyLocals::AfterGVN,
             &match_branches::MatchBranchSimplification,
             &dataflow_const_prop::DataflowConstProp,
+            &remove_redundant_asserts::RemoveRedundantAsserts,
             &single_use_consts::SingleUseConsts,
             &o1(simplify_branches::SimplifyConstCondition::AfterConstProp),
             &jump_threading::JumpThreading,
             &early_otherwise_branch::EarlyOtherwiseBranch,
             &simplify_comparison_integral::SimplifyComparisonIntegral,
             &dest_prop::DestinationPropagation,
+            // Late, so that the cold paths are as optimized as the rest of the function.
+            &outline_cold_paths::OutlineColdPaths,
             &o1(simplify_branches::SimplifyConstCondition::Final),
             &o1(remove_noop_landing_pads::RemoveNoopLandingPads),
             &o1(simplify::SimplifyCfg::Final),
@@ -781,6 +790,10 @@ fn inner_optimized_mir(tcx: TyCtxt<'_>, did: LocalDefId) -> Body<'_> {
 
     run_optimization_passes(tcx, &mut body);
 
+    if tcx.sess.opts.unstable_opts.polymorphize {
+        polymorphize::dump_unused_generic_params(tcx, &body);
+    }
+
     body
 }
 
@@ -0,0 +1,566 @@
+//! This pass moves the cold paths of a function, like the ones that end in a panic, out of it
+//! and into separate `#[cold]` functions, the `ColdPathShim`s. This keeps the code of the hot
+//! paths together and makes the function smaller, so cheaper to inline.
+//!
+//! A cold path starts at a block that calls a `#[cold]` function, or at the chain of blocks that
+//! only lead to such a block, and is made of all the blocks that this entry block dominates. It
+//! can only be outlined if it never returns or jumps back to the rest of the function: the shim
+//! never returns either, so the entry block is replaced by a call to the shim with no target.
+//!
+//! The blocks of the cold path are copied into the shim, which takes an argument for each local
+//! that the cold path shares with the rest of the function. As the cold path never goes back to
+//! the function, the function can only see what the cold path does to a local through a pointer
+//! to it. So a `Copy` local whose address is never taken, and which the cold path reads but never
+//! writes, is passed by value. The shim takes a raw pointer to the other shared locals, and
+//! accesses them through it, which keeps their addresses the same. The other locals of the cold
+//! path become locals of the shim.
+//!
+//! The bodies of the shims are stored in the MIR of the function, and `make_shim` turns them into
+//! the MIR of the `ColdPathShim` instances. As the shims are referred to through function pointer
+//! constants, whose instances are not instantiated with the generic arguments of the function,
+//! only non-generic functions are considered.
+
+use std::borrow::Cow;
+
+use rustc_hir::def::DefKind;
+use rustc_index::bit_set::DenseBitSet;
+use rustc_index::{Idx, IndexVec};
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
+use rustc_middle::mir::interpret::{CTFE_ALLOC_SALT, Pointer, Scalar};
+use rustc_middle::mir::visit::{MutVisitor, PlaceContext, Visitor};
+use rustc_middle::mir::*;
+use rustc_middle::ty::{self, Instance, InstanceKind, Ty, TyCtxt};
+use rustc_mir_dataflow::Analysis;
+use rustc_mir_dataflow::impls::{MaybeStorageDead, always_storage_live_locals, borrowed_locals};
+use rustc_span::source_map::Spanned;
+use tracing::{debug, instrument};
+
+/// Cold paths smaller than this, in statements and terminators, are not worth a call.
+const MIN_COLD_PATH_SIZE: usize = 4;
+
+/// Moves the cold paths of a function into `ColdPathShim`s, see the module docs.
+///
+/// Only non-generic functions are handled: a function that requires monomorphization is left as
+/// it is, since the function pointer constants that call its shims would not be instantiated
+/// with its generic arguments.
+pub(super) struct OutlineColdPaths;
+
+impl<'tcx> crate::MirPass<'tcx> for OutlineColdPaths {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 3
+    }
+
+    #[instrument(skip_all level = "debug")]
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        let def_id = body.source.def_id();
+        debug!(?def_id);
+        if !can_outline_from(tcx, body) {
+            return;
+        }
+
+        let cold_paths = find_cold_paths(tcx, body);
+        if cold_paths.is_empty() {
+            return;
+        }
+
+        let borrowed = borrowed_locals(body);
+        let always_live_locals = always_storage_live_locals(body);
+        let mut maybe_dead = MaybeStorageDead::new(Cow::Owned(always_live_locals))
+            .iterate_to_fixpoint(tcx, body, None)
+            .into_results_cursor(body);
+
+        // How many times each local is mentioned in the function, with the return place and the
+        // arguments counting as mentioned by the caller.
+        let mut mentions = CountMentions { mentions: IndexVec::from_elem(0, &body.local_decls) };
+        for (block, data) in body.basic_blocks.iter_enumerated() {
+            mentions.visit_basic_block_data(block, data);
+        }
+        for local in (0..=body.arg_count).map(Local::new) {
+            mentions.mentions[local] += 1;
+        }
+
+        let mut outlined = Vec::new();
+        for cold_path in cold_paths {
+            maybe_dead.seek_to_block_start(cold_path.entry);
+            let Some(shared) =
+                shared_locals(tcx, body, &cold_path, &mentions, &borrowed, maybe_dead.get())
+            else {
+                continue;
+            };
+            let index = u32::try_from(body.outlined_cold_paths.len() + outlined.len()).unwrap();
+            let shim = build_shim_body(tcx, body, &cold_path, &shared, index);
+            outlined.push((cold_path, shared, shim));
+        }
+
+        // The cold paths are disjoint, and only their entry block is modified, so this does not
+        // invalidate the analyses above.
+        for (cold_path, shared, shim) in outlined {
+            let index = u32::try_from(body.outlined_cold_paths.len()).unwrap();
+            call_shim(tcx, body, &cold_path, &shared, &shim, index);
+            body.outlined_cold_paths.push(shim);
+        }
+
+        // The rest of the blocks of the cold paths are now unreachable, and are removed by the
+        // `SimplifyCfg` pass that follows.
+    }
+
+    fn is_required(&self) -> bool {
+        false
+    }
+}
+
+fn can_outline_from<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> bool {
+    let InstanceKind::Item(def_id) = body.source.instance else {
+        return false;
+    };
+    if body.source.promoted.is_some()
+        || body.coroutine.is_some()
+        || !matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+        || tcx.generics_of(def_id).requires_monomorphization(tcx)
+    {
+        return false;
+    }
+
+    // The shims keep the symbol-independent attributes of the function, so we leave the
+    // functions with a fixed symbol alone. The shims cannot forward the location of the caller of
+    // `#[track_caller]` functions either.
+    let attrs = tcx.codegen_fn_attrs(def_id);
+    !attrs.contains_extern_indicator()
+        && !attrs.flags.intersects(
+            CodegenFnAttrFlags::COLD | CodegenFnAttrFlags::NAKED | CodegenFnAttrFlags::TRACK_CALLER,
+        )
+}
+
+#[derive(Debug)]
+struct ColdPath {
+    entry: BasicBlock,
+    /// The blocks that `entry` dominates, starting with `entry`.
+    blocks: Vec<BasicBlock>,
+    /// Whether the cold path can unwind out of the function.
+    unwinds: bool,
+}
+
+/// Finds the cold paths that can be outlined, which are disjoint.
+fn find_cold_paths<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> Vec<ColdPath> {
+    let mut in_cold_path = DenseBitSet::new_empty(body.basic_blocks.len());
+    let mut cold_paths = Vec::new();
+    // Visiting the blocks in reverse postorder finds the cold paths that contain other ones
+    // first.
+    for &block in body.basic_blocks.reverse_postorder() {
+        let data = &body.basic_blocks[block];
+        if data.is_cleanup || in_cold_path.contains(block) || !calls_cold_fn(tcx, body, data) {
+            continue;
+        }
+
+        let entry = cold_path_entry(body, block, &in_cold_path);
+        let Some(cold_path) = cold_path_from(body, entry) else {
+            debug!("{block:?} does not start a cold path that can be outlined");
+            continue;
+        };
+        let size: usize =
+            cold_path.blocks.iter().map(|&b| body.basic_blocks[b].statements.len() + 1).sum();
+        if size < MIN_COLD_PATH_SIZE || cold_path.blocks.iter().any(|&b| in_cold_path.contains(b)) {
+            continue;
+        }
+
+        debug!(?cold_path);
+        for &block in &cold_path.blocks {
+            in_cold_path.insert(block);
+        }
+        cold_paths.push(cold_path);
+    }
+    cold_paths
+}
+
+fn calls_cold_fn<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>, data: &BasicBlockData<'tcx>) -> bool {
+    let TerminatorKind::Call { func, .. } = &data.terminator().kind else {
+        return false;
+    };
+    let ty::FnDef(def_id, _) = *func.ty(body, tcx).kind() else {
+        return false;
+    };
+    tcx.def_kind(def_id).has_codegen_attrs()
+        && tcx.codegen_fn_attrs(def_id).flags.contains(CodegenFnAttrFlags::COLD)
+}
+
+/// Extends the cold path that starts at `block` with the chain of blocks that only lead to it.
+fn cold_path_entry(
+    body: &Body<'_>,
+    mut block: BasicBlock,
+    in_cold_path: &DenseBitSet<BasicBlock>,
+) -> BasicBlock {
+    let predecessors = body.basic_blocks.predecessors();
+    while let [pred] = predecessors[block][..]
+        && pred != START_BLOCK
+        && !in_cold_path.contains(pred)
+        && !body.basic_blocks[pred].is_cleanup
+        && body.basic_blocks[pred].terminator().successors().all(|succ| succ == block)
+    {
+        block = pred;
+    }
+    block
+}
+
+/// Returns the blocks dominated by `entry`, if none of them leaves them to return or to go to
+/// another block of the function.
+fn cold_path_from(body: &Body<'_>, entry: BasicBlock) -> Option<ColdPath> {
+    if entry == START_BLOCK || body.basic_blocks[entry].is_cleanup {
+        return None;
+    }
+
+    let dominators = body.basic_blocks.dominators();
+    let mut blocks = vec![entry];
+    let mut visited = DenseBitSet::new_empty(body.basic_blocks.len());
+    visited.insert(entry);
+    let mut unwinds = false;
+    let mut i = 0;
+    while let Some(&block) = blocks.get(i) {
+        i += 1;
+        let terminator = body.basic_blocks[block].terminator();
+        match terminator.kind {
+            TerminatorKind::Return
+            | TerminatorKind::TailCall { .. }
+            | TerminatorKind::Yield { .. }
+            | TerminatorKind::CoroutineDrop => return None,
+            TerminatorKind::UnwindResume => unwinds = true,
+            _ => {}
+        }
+        if let Some(UnwindAction::Continue) = terminator.unwind() {
+            unwinds = true;
+        }
+        for succ in terminator.successors() {
+            if !dominators.dominates(entry, succ) {
+                return None;
+            }
+            if visited.insert(succ) {
+                blocks.push(succ);
+            }
+        }
+    }
+
+    Some(ColdPath { entry, blocks, unwinds })
+}
+
+struct CountMentions {
+    mentions: IndexVec<Local, u32>,
+}
+
+impl<'tcx> Visitor<'tcx> for CountMentions {
+    fn visit_local(&mut self, local: Local, _: PlaceContext, _: Location) {
+        self.mentions[local] += 1;
+    }
+}
+
+/// Collects the locals that a cold path mentions, and the ones it writes.
+struct CollectMentions<'a> {
+    mentions: &'a mut IndexVec<Local, u32>,
+    index_locals: &'a mut DenseBitSet<Local>,
+    written: &'a mut DenseBitSet<Local>,
+}
+
+impl<'tcx> Visitor<'tcx> for CollectMentions<'_> {
+    fn visit_local(&mut self, local: Local, context: PlaceContext, _: Location) {
+        self.mentions[local] += 1;
+        if context.is_mutating_use() {
+            self.written.insert(local);
+        }
+    }
+
+    fn visit_projection_elem(
+        &mut self,
+        place_ref: PlaceRef<'tcx>,
+        elem: PlaceElem<'tcx>,
+        context: PlaceContext,
+        location: Location,
+    ) {
+        if let ProjectionElem::Index(local) = elem {
+            self.index_locals.insert(local);
+        }
+        self.super_projection_elem(place_ref, elem, context, location);
+    }
+}
+
+/// Collects the locals that are read as a whole, so must be initialized.
+struct CollectReads<'a> {
+    read: &'a mut DenseBitSet<Local>,
+}
+
+impl<'tcx> Visitor<'tcx> for CollectReads<'_> {
+    fn visit_operand(&mut self, operand: &Operand<'tcx>, location: Location) {
+        if let Operand::Copy(place) | Operand::Move(place) = operand
+            && let Some(local) = place.as_local()
+        {
+            self.read.insert(local);
+        }
+        self.super_operand(operand, location);
+    }
+
+    fn visit_projection_elem(
+        &mut self,
+        place_ref: PlaceRef<'tcx>,
+        elem: PlaceElem<'tcx>,
+        context: PlaceContext,
+        location: Location,
+    ) {
+        if let ProjectionElem::Index(local) = elem {
+            self.read.insert(local);
+        }
+        self.super_projection_elem(place_ref, elem, context, location);
+    }
+}
+
+/// A local that a cold path shares with the rest of the function.
+#[derive(Copy, Clone, Debug)]
+struct SharedLocal {
+    local: Local,
+    /// Whether the shim takes a pointer to the local, instead of its value.
+    by_pointer: bool,
+}
+
+/// The local of the shim for a local of the function.
+#[derive(Copy, Clone, Debug)]
+enum ShimLocal {
+    /// The local is shared with the rest of the function, and this argument points to it.
+    Pointee(Local),
+    /// The local is shared with the rest of the function, and this argument is its value.
+    Value(Local),
+    /// The local is only used by the cold path.
+    Own(Local),
+}
+
+/// Returns the locals that `cold_path` shares with the rest of the function, in order, if they
+/// can all be passed to the shim.
+fn shared_locals<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &Body<'tcx>,
+    cold_path: &ColdPath,
+    mentions: &CountMentions,
+    borrowed: &DenseBitSet<Local>,
+    maybe_dead: &DenseBitSet<Local>,
+) -> Option<Vec<SharedLocal>> {
+    let mut cold_path_mentions = IndexVec::from_elem(0, &body.local_decls);
+    let mut index_locals = DenseBitSet::new_empty(body.local_decls.len());
+    let mut written = DenseBitSet::new_empty(body.local_decls.len());
+    let mut collector = CollectMentions {
+        mentions: &mut cold_path_mentions,
+        index_locals: &mut index_locals,
+        written: &mut written,
+    };
+    for &block in &cold_path.blocks {
+        collector.visit_basic_block_data(block, &body.basic_blocks[block]);
+    }
+
+    // Copying a local to pass it by value requires it to be initialized. It is if the cold path
+    // reads it before it can branch, as it does not write it.
+    let mut read = DenseBitSet::new_empty(body.local_decls.len());
+    let mut visited = DenseBitSet::new_empty(body.basic_blocks.len());
+    let mut block = cold_path.entry;
+    while visited.insert(block) {
+        let data = &body.basic_blocks[block];
+        CollectReads { read: &mut read }.visit_basic_block_data(block, data);
+        let TerminatorKind::Goto { target } = data.terminator().kind else {
+            break;
+        };
+        block = target;
+    }
+
+    let typing_env = body.typing_env(tcx);
+    let mut shared = Vec::new();
+    for (local, &count) in cold_path_mentions.iter_enumerated() {
+        if count == 0 || count == mentions.mentions[local] {
+            continue;
+        }
+        // The shared locals must be live when the cold path starts. The locals used as indices
+        // cannot be replaced by the pointee of an argument, so must be passed by value.
+        let ty = body.local_decls[local].ty;
+        let by_pointer = borrowed.contains(local)
+            || written.contains(local)
+            || !read.contains(local)
+            || !tcx.type_is_copy_modulo_regions(typing_env, ty);
+        if maybe_dead.contains(local)
+            || (by_pointer && index_locals.contains(local))
+            || !ty.is_sized(tcx, typing_env)
+        {
+            debug!("cannot pass {local:?} to the shim");
+            return None;
+        }
+        shared.push(SharedLocal { local, by_pointer });
+    }
+    Some(shared)
+}
+
+/// Builds the body of the shim, from the blocks of `cold_path`.
+fn build_shim_body<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &Body<'tcx>,
+    cold_path: &ColdPath,
+    shared: &[SharedLocal],
+    index: u32,
+) -> Body<'tcx> {
+    let def_id = body.source.def_id();
+    let span = body.span;
+
+    // The shim never returns, and takes each shared local or a pointer to it.
+    let mut local_decls = IndexVec::with_capacity(1 + shared.len());
+    local_decls.push(LocalDecl::new(tcx.types.never, span));
+    let mut locals = IndexVec::from_elem(None, &body.local_decls);
+    for &SharedLocal { local, by_pointer } in shared {
+        let decl = &body.local_decls[local];
+        let span = decl.source_info.span;
+        locals[local] = Some(if by_pointer {
+            ShimLocal::Pointee(
+                local_decls.push(LocalDecl::new(Ty::new_mut_ptr(tcx, decl.ty), span)),
+            )
+        } else {
+            ShimLocal::Value(local_decls.push(LocalDecl::new(decl.ty, span)))
+        });
+    }
+
+    let mut blocks = IndexVec::with_capacity(cold_path.blocks.len());
+    let mut block_map = IndexVec::from_elem_n(None, body.basic_blocks.len());
+    for &block in &cold_path.blocks {
+        block_map[block] = Some(blocks.push(body.basic_blocks[block].clone()));
+    }
+
+    let mut renamer = RenameLocals { tcx, body, locals, local_decls };
+    for (block, data) in blocks.iter_enumerated_mut() {
+        renamer.visit_basic_block_data(block, data);
+        data.terminator_mut().successors_mut(|target| *target = block_map[*target].unwrap());
+    }
+
+    let mut shim = Body::new(
+        MirSource::from_instance(InstanceKind::ColdPathShim(def_id, index)),
+        blocks,
+        body.source_scopes.clone(),
+        renamer.local_decls,
+        IndexVec::new(),
+        shared.len(),
+        vec![],
+        span,
+        None,
+        body.tainted_by_errors,
+    );
+    // The blocks come from an optimized body, but `make_shim` still has to separate the
+    // dereferences of the arguments from the ones of the places they point to.
+    shim.phase = MirPhase::Runtime(RuntimePhase::PostCleanup);
+    shim
+}
+
+/// Renames the locals of the blocks of a cold path to the ones of the shim.
+struct RenameLocals<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    body: &'a Body<'tcx>,
+    locals: IndexVec<Local, Option<ShimLocal>>,
+    local_decls: IndexVec<Local, LocalDecl<'tcx>>,
+}
+
+impl<'tcx> RenameLocals<'_, 'tcx> {
+    fn shim_local(&mut self, local: Local) -> ShimLocal {
+        *self.locals[local].get_or_insert_with(|| {
+            let mut decl = self.body.local_decls[local].clone();
+            decl.user_ty = None;
+            ShimLocal::Own(self.local_decls.push(decl))
+        })
+    }
+}
+
+impl<'tcx> MutVisitor<'tcx> for RenameLocals<'_, 'tcx> {
+    fn tcx(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn visit_statement(&mut self, statement: &mut Statement<'tcx>, location: Location) {
+        // The storage of the shared locals belongs to the function, and it never resumes.
+        if let StatementKind::StorageLive(local) | StatementKind::StorageDead(local) =
+            statement.kind
+            && let ShimLocal::Pointee(_) | ShimLocal::Value(_) = self.shim_local(local)
+        {
+            statement.make_nop();
+            return;
+        }
+        self.super_statement(statement, location);
+    }
+
+    fn visit_local(&mut self, local: &mut Local, _: PlaceContext, _: Location) {
+        // The locals behind a pointer only occur as the base of places, which `visit_place`
+        // dereferences.
+        match self.shim_local(*local) {
+            ShimLocal::Pointee(arg) | ShimLocal::Value(arg) | ShimLocal::Own(arg) => *local = arg,
+        }
+    }
+
+    fn visit_place(&mut self, place: &mut Place<'tcx>, context: PlaceContext, location: Location) {
+        let shared = matches!(self.shim_local(place.local), ShimLocal::Pointee(_));
+        self.super_place(place, context, location);
+        if shared {
+            *place = self
+                .tcx
+                .mk_place_deref(Place::from(place.local))
+                .project_deeper(place.projection, self.tcx);
+        }
+    }
+}
+
+/// Replaces the entry block of `cold_path` with a call to its shim.
+fn call_shim<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &mut Body<'tcx>,
+    cold_path: &ColdPath,
+    shared: &[SharedLocal],
+    shim: &Body<'tcx>,
+    index: u32,
+) {
+    let def_id = body.source.def_id();
+    let source_info = body.basic_blocks[cold_path.entry].terminator().source_info;
+    let span = source_info.span;
+
+    let instance = Instance {
+        def: InstanceKind::ColdPathShim(def_id, index),
+        args: tcx.erase_regions(ty::GenericArgs::identity_for_item(tcx, def_id)),
+    };
+    let sig = tcx.mk_fn_sig(
+        shim.args_iter().map(|arg| shim.local_decls[arg].ty),
+        shim.return_ty(),
+        false,
+        rustc_hir::Safety::Safe,
+        rustc_abi::ExternAbi::Rust,
+    );
+    let fn_ptr_ty = Ty::new_fn_ptr(tcx, ty::Binder::dummy(sig));
+    let fn_ptr = Pointer::from(tcx.reserve_and_set_fn_alloc(instance, CTFE_ALLOC_SALT));
+    let func = Operand::Constant(Box::new(ConstOperand {
+        span,
+        user_ty: None,
+        const_: Const::Val(ConstValue::Scalar(Scalar::from_pointer(fn_ptr, &tcx)), fn_ptr_ty),
+    }));
+
+    let mut statements = Vec::with_capacity(shared.len());
+    let mut args = Vec::with_capacity(shared.len());
+    for &SharedLocal { local, by_pointer } in shared {
+        if !by_pointer {
+            args.push(Spanned { node: Operand::Copy(Place::from(local)), span });
+            continue;
+        }
+        let ty = Ty::new_mut_ptr(tcx, body.local_decls[local].ty);
+        let ptr = body.local_decls.push(LocalDecl::new(ty, span));
+        let rvalue = Rvalue::RawPtr(RawPtrKind::Mut, Place::from(local));
+        statements.push(Statement::new(
+            source_info,
+            StatementKind::Assign(Box::new((Place::from(ptr), rvalue))),
+        ));
+        args.push(Spanned { node: Operand::Move(Place::from(ptr)), span });
+    }
+    let destination = body.local_decls.push(LocalDecl::new(tcx.types.never, span));
+
+    let data = &mut body.basic_blocks_mut()[cold_path.entry];
+    data.statements = statements;
+    data.terminator_mut().kind = TerminatorKind::Call {
+        func,
+        args: args.into_boxed_slice(),
+        destination: Place::from(destination),
+        target: None,
+        // Without an unwinding path out of the cold path, the shim cannot unwind either.
+        unwind: if cold_path.unwinds { UnwindAction::Continue } else { UnwindAction::Unreachable },
+        call_source: CallSource::Misc,
+        fn_span: span,
+    };
+}
@@ -0,0 +1,194 @@
+//! Polymorphization analysis, which finds the type parameters of a function that its optimized
+//! MIR does not use.
+//!
+//! With `-Z polymorphize`, `Instance::polymorphize` replaces such parameters with `()` when a
+//! function is collected as a mono item, and when the symbol of one of its instances is
+//! computed. All the instantiations of the function that only differ in unused parameters then
+//! share the same code. This is sound because the body of such instantiations, including the
+//! types of its arguments and return value, is the same whatever the unused parameters are.
+//!
+//! The analysis is conservative: any mention of a parameter in the body counts as a use, even
+//! behind a pointer, and so does any mention in a predicate together with a used parameter. The
+//! promoted constants of the body are the exception: they are instantiated with all the
+//! parameters of the function, so only the parameters that their own body mentions are used.
+//!
+//! With `-Z dump-mir=polymorphize`, the result of the analysis is written at the top of a dump
+//! of the optimized MIR of each function.
+
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_middle::mir::visit::{TyContext, Visitor};
+use rustc_middle::mir::{
+    Body, Const, ConstOperand, Location, PassWhere, UnevaluatedConst, dump_enabled, dump_mir,
+};
+use rustc_middle::ty::{
+    self, GenericArgsRef, GenericParamDefKind, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable,
+    TypeVisitableExt, TypeVisitor, UnusedGenericParams,
+};
+use tracing::{debug, instrument};
+
+#[instrument(level = "debug", skip(tcx))]
+pub(super) fn unused_generic_params<'tcx>(tcx: TyCtxt<'tcx>, def_id: DefId) -> UnusedGenericParams {
+    assert!(tcx.sess.opts.unstable_opts.polymorphize);
+    assert!(matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn));
+
+    // Without MIR, we cannot know which parameters are used.
+    if !tcx.is_mir_available(def_id) {
+        return UnusedGenericParams::new_all_used();
+    }
+    unused_generic_params_of_body(tcx, def_id, tcx.optimized_mir(def_id))
+}
+
+/// Writes the result of the analysis for `body`, the optimized MIR of a function, to a MIR dump.
+///
+/// This is called while the optimized MIR is computed, so the analysis is run on `body` rather
+/// than through the query.
+pub(super) fn dump_unused_generic_params<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) {
+    let def_id = body.source.def_id();
+    if !matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+        || !dump_enabled(tcx, "polymorphize", def_id)
+    {
+        return;
+    }
+
+    let unused_parameters = unused_generic_params_of_body(tcx, def_id, body);
+    let generics = tcx.generics_of(def_id);
+    let unused_names: Vec<_> = (0..generics.count())
+        .filter(|&index| unused_parameters.is_unused(index as u32))
+        .map(|index| generics.param_at(index, tcx).name.to_string())
+        .collect();
+    dump_mir(tcx, false, "polymorphize", &0, body, |pass_where, w| {
+        if let PassWhere::BeforeCFG = pass_where {
+            if unused_names.is_empty() {
+                writeln!(w, "// unused generic parameters: none")?;
+            } else {
+                writeln!(w, "// unused generic parameters: {}", unused_names.join(", "))?;
+            }
+            writeln!(w)?;
+        }
+        Ok(())
+    });
+}
+
+fn unused_generic_params_of_body<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: DefId,
+    body: &Body<'tcx>,
+) -> UnusedGenericParams {
+    // Parameters past the capacity of the set are always considered used.
+    let generics = tcx.generics_of(def_id);
+    let count = generics.count().min(u32::BITS as usize);
+    let mut unused_parameters = UnusedGenericParams::new_all_unused(count as u32);
+    for index in 0..count {
+        // Lifetimes are erased, and the value of const parameters may be observed.
+        if !matches!(generics.param_at(index, tcx).kind, GenericParamDefKind::Type { .. }) {
+            unused_parameters.mark_used(index as u32);
+        }
+    }
+    if unused_parameters.all_used() {
+        return unused_parameters;
+    }
+
+    MarkUsedGenericParams { tcx, def_id, unused_parameters: &mut unused_parameters }
+        .visit_body_and_mentioned_items(body);
+    debug!(?unused_parameters, "after visiting the body");
+
+    mark_used_by_predicates(tcx, def_id, &mut unused_parameters);
+    debug!(?unused_parameters, "after visiting the predicates");
+
+    unused_parameters
+}
+
+/// Marks the parameters that are mentioned in a predicate together with a used parameter as
+/// used, until no more parameters are marked. This keeps the instances that polymorphization
+/// creates closer to ones that satisfy the predicates of the function.
+fn mark_used_by_predicates<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: DefId,
+    unused_parameters: &mut UnusedGenericParams,
+) {
+    let predicates = tcx.predicates_of(def_id).instantiate_identity(tcx).predicates;
+    loop {
+        let before = unused_parameters.bits();
+        for predicate in &predicates {
+            let mut mentioned = UnusedGenericParams::new_all_unused(u32::BITS);
+            predicate.visit_with(&mut MarkUsedGenericParams {
+                tcx,
+                def_id,
+                unused_parameters: &mut mentioned,
+            });
+            // Does the predicate mention a used parameter?
+            if mentioned.bits() | unused_parameters.bits() != u32::MAX {
+                predicate.visit_with(&mut MarkUsedGenericParams { tcx, def_id, unused_parameters });
+            }
+        }
+        if unused_parameters.bits() == before {
+            break;
+        }
+    }
+}
+
+/// Marks every type parameter that is visited as used.
+struct MarkUsedGenericParams<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    /// The function whose body is visited, and whose promoted constants are visited in place of
+    /// the constants that refer to them.
+    def_id: DefId,
+    unused_parameters: &'a mut UnusedGenericParams,
+}
+
+impl<'tcx> MarkUsedGenericParams<'_, 'tcx> {
+    /// Visits `body`, and the items that it mentions but that optimizations may have removed
+    /// from it, which must still be instantiated with the parameters of the function. These are
+    /// not recorded for promoted constants.
+    fn visit_body_and_mentioned_items(&mut self, body: &Body<'tcx>) {
+        self.visit_body(body);
+        for item in body.mentioned_items.iter().flatten() {
+            item.node.visit_with(self);
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for MarkUsedGenericParams<'_, 'tcx> {
+    fn visit_const_operand(&mut self, constant: &ConstOperand<'tcx>, location: Location) {
+        match constant.const_ {
+            Const::Unevaluated(UnevaluatedConst { def, args, promoted }, ty) => {
+                ty.visit_with(self);
+                match promoted {
+                    // The arguments of a promoted constant are the parameters of the function.
+                    Some(promoted) if def == self.def_id => {
+                        let promoted = &self.tcx.promoted_mir(def)[promoted];
+                        self.visit_body_and_mentioned_items(promoted);
+                    }
+                    _ => args.visit_with(self),
+                }
+            }
+            _ => self.super_const_operand(constant, location),
+        }
+    }
+
+    fn visit_ty_const(&mut self, ct: ty::Const<'tcx>, _location: Location) {
+        ct.visit_with(self);
+    }
+
+    fn visit_ty(&mut self, ty: Ty<'tcx>, _: TyContext) {
+        ty.visit_with(self);
+    }
+
+    fn visit_args(&mut self, args: &GenericArgsRef<'tcx>, _location: Location) {
+        args.visit_with(self);
+    }
+}
+
+impl<'tcx> TypeVisitor<TyCtxt<'tcx>> for MarkUsedGenericParams<'_, 'tcx> {
+    fn visit_ty(&mut self, ty: Ty<'tcx>) {
+        if !ty.has_param() {
+            return;
+        }
+        if let ty::Param(param) = *ty.kind() {
+            self.unused_parameters.mark_used(param.index);
+        } else {
+            ty.super_visit_with(self);
+        }
+    }
+}
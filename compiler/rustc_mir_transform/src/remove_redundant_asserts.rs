@@ -0,0 +1,88 @@
+//! This pass removes the `Assert` terminators whose condition is known to hold, like bounds
+//! checks and overflow checks, using a value range analysis.
+//!
+//! Besides the ranges of integers, the analysis knows when an index was compared to the length
+//! of the slice it indexes, so that the bounds check of `s[i]` is removed after `i < s.len()`,
+//! and so is the overflow check of `i + 1`.
+//!
+//! LLVM is usually able to remove these checks on its own, but not always across loops, and
+//! other codegen backends may not do it at all.
+
+use rustc_middle::mir::*;
+use rustc_middle::ty::TyCtxt;
+use rustc_mir_dataflow::impls::{ValueRange, ValueRangeAnalysis, ValueRangeState};
+use rustc_mir_dataflow::value_analysis::Map;
+use rustc_mir_dataflow::{Analysis, ResultsVisitor, visit_reachable_results};
+use tracing::{debug, debug_span, instrument};
+
+use super::simplify::simplify_cfg;
+
+// These limits are the same as the ones of `DataflowConstProp`, which does a similar analysis.
+// If `tcx.sess.mir_opt_level() >= 4`, we ignore the limits (this can become very expensive).
+const BLOCK_LIMIT: usize = 100;
+const PLACE_LIMIT: usize = 100;
+
+pub(super) struct RemoveRedundantAsserts;
+
+impl<'tcx> crate::MirPass<'tcx> for RemoveRedundantAsserts {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 3
+    }
+
+    #[instrument(skip_all level = "debug")]
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        debug!(def_id = ?body.source.def_id());
+        if tcx.sess.mir_opt_level() < 4 && body.basic_blocks.len() > BLOCK_LIMIT {
+            debug!("aborted RemoveRedundantAsserts due too many basic blocks");
+            return;
+        }
+
+        let place_limit = if tcx.sess.mir_opt_level() < 4 { Some(PLACE_LIMIT) } else { None };
+        let map = Map::new(tcx, body, place_limit);
+
+        let mut ranges = debug_span!("analyze").in_scope(|| {
+            ValueRangeAnalysis::new(tcx, body, map).iterate_to_fixpoint(tcx, body, None)
+        });
+        let mut collector = Collector { redundant_asserts: Vec::new() };
+        visit_reachable_results(body, &mut ranges.analysis, &ranges.results, &mut collector);
+
+        let redundant_asserts = collector.redundant_asserts;
+        if redundant_asserts.is_empty() {
+            return;
+        }
+        for block in redundant_asserts {
+            let terminator = body.basic_blocks_mut()[block].terminator_mut();
+            let TerminatorKind::Assert { target, .. } = terminator.kind else { unreachable!() };
+            debug!("removing {:?} in {:?}", terminator.kind, block);
+            terminator.kind = TerminatorKind::Goto { target };
+        }
+
+        // The cleanup blocks of the removed asserts may now be unreachable.
+        simplify_cfg(tcx, body);
+    }
+
+    fn is_required(&self) -> bool {
+        false
+    }
+}
+
+struct Collector {
+    redundant_asserts: Vec<BasicBlock>,
+}
+
+impl<'tcx> ResultsVisitor<'tcx, ValueRangeAnalysis<'_, 'tcx>> for Collector {
+    fn visit_after_early_terminator_effect(
+        &mut self,
+        analysis: &mut ValueRangeAnalysis<'_, 'tcx>,
+        state: &ValueRangeState,
+        terminator: &Terminator<'tcx>,
+        location: Location,
+    ) {
+        if let TerminatorKind::Assert { cond, expected, .. } = &terminator.kind
+            && state.is_reachable()
+            && analysis.operand_range(cond, state) == ValueRange::exactly(i128::from(*expected))
+        {
+            self.redundant_asserts.push(location.block);
+        }
+    }
+}
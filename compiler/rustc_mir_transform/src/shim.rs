@@ -20,9 +20,9 @@
 use crate::elaborate_drop::{DropElaborator, DropFlagMode, DropStyle, Unwind, elaborate_drop};
 use crate::patch::MirPatch;
 use crate::{
-    abort_unwinding_calls, add_call_guards, add_moves_for_packed_drops, deref_separator, inline,
-    instsimplify, mentioned_items, pass_manager as pm, remove_noop_landing_pads,
-    run_optimization_passes, simplify,
+    RequiredConstsVisitor, abort_unwinding_calls, add_call_guards, add_moves_for_packed_drops,
+    deref_separator, inline, instsimplify, mentioned_items, pass_manager as pm,
+    remove_noop_landing_pads, run_optimization_passes, simplify,
 };
 
 mod async_destructor_ctor;
@@ -163,6 +163,7 @@ fn make_shim<'tcx>(tcx: TyCtxt<'tcx>, instance: ty::InstanceKind<'tcx>) -> Body<
             build_drop_shim(tcx, def_id, ty)
         }
         ty::InstanceKind::ThreadLocalShim(..) => build_thread_local_shim(tcx, instance),
+        ty::InstanceKind::ColdPathShim(def_id, index) => build_cold_path_shim(tcx, def_id, index),
         ty::InstanceKind::CloneShim(def_id, ty) => build_clone_shim(tcx, def_id, ty),
         ty::InstanceKind::FnPtrAddrShim(def_id, ty) => build_fn_ptr_addr_shim(tcx, def_id, ty),
         ty::InstanceKind::FutureDropPollShim(def_id, proxy_ty, impl_ty) => {
@@ -530,6 +531,14 @@ fn build_thread_local_shim<'tcx>(
     )
 }
 
+/// Builds the shim of the cold path that `OutlineColdPaths` outlined out of `def_id`.
+fn build_cold_path_shim<'tcx>(tcx: TyCtxt<'tcx>, def_id: DefId, index: u32) -> Body<'tcx> {
+    let mut body = tcx.optimized_mir(def_id).outlined_cold_paths[index as usize].clone();
+    // Unlike other shims, this one mentions the constants of the cold path.
+    RequiredConstsVisitor::compute_required_consts(&mut body);
+    body
+}
+
 /// Builds a `Clone::clone` shim for `self_ty`. Here, `def_id` is `Clone::clone`.
 fn build_clone_shim<'tcx>(tcx: TyCtxt<'tcx>, def_id: DefId, self_ty: Ty<'tcx>) -> Body<'tcx> {
     debug!("build_clone_shim(def_id={:?})", def_id);
@@ -36,9 +36,15 @@ monomorphize_abi_required_target_feature =
   } here
   .help = consider enabling it globally (`-C target-feature=+{$required_feature}`) or locally (`#[target_feature(enable="{$required_feature}")]`)
 
+monomorphize_couldnt_dump_mono_bloat =
+    unexpected error occurred while dumping the monomorphization bloat report: {$error}
+
 monomorphize_couldnt_dump_mono_stats =
     unexpected error occurred while dumping monomorphization stats: {$error}
 
+monomorphize_couldnt_read_cgu_partitioning_profile =
+    couldn't read the CGU partitioning profile `{$path}`: {$error}
+
 monomorphize_encountered_error_while_instantiating =
     the above error was encountered while instantiating `{$formatted_item}`
 
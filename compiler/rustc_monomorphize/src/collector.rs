@@ -831,6 +831,9 @@ fn visit_terminator(&mut self, terminator: &mir::Terminator<'tcx>, location: Loc
                 mir::AssertKind::InvalidEnumConstruction(_) => {
                     push_mono_lang_item(self, LangItem::PanicInvalidEnumConstruction);
                 }
+                mir::AssertKind::InvalidScalarValue { .. } => {
+                    push_mono_lang_item(self, LangItem::PanicInvalidScalarValue);
+                }
                 _ => {
                     push_mono_lang_item(self, msg.panic_function());
                 }
@@ -944,6 +947,9 @@ fn visit_instance_use<'tcx>(
         ty::InstanceKind::ThreadLocalShim(..) => {
             bug!("{:?} being reified", instance);
         }
+        ty::InstanceKind::ColdPathShim(..) => {
+            bug!("{:?} is only used through function pointer constants", instance);
+        }
         ty::InstanceKind::DropGlue(_, None) => {
             // Don't need to emit noop drop glue if we are calling directly.
             //
@@ -1125,7 +1131,7 @@ fn create_fn_mono_item<'tcx>(
         crate::util::dump_closure_profile(tcx, instance);
     }
 
-    respan(source, MonoItem::Fn(instance))
+    respan(source, MonoItem::Fn(instance.polymorphize(tcx)))
 }
 
 /// Creates a `MonoItem` for each method that is referenced by the vtable for
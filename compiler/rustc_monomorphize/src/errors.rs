@@ -45,12 +45,25 @@ pub(crate) struct SymbolAlreadyDefined {
     pub symbol: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(monomorphize_couldnt_dump_mono_bloat)]
+pub(crate) struct CouldntDumpMonoBloat {
+    pub error: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(monomorphize_couldnt_dump_mono_stats)]
 pub(crate) struct CouldntDumpMonoStats {
     pub error: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(monomorphize_couldnt_read_cgu_partitioning_profile)]
+pub(crate) struct CouldntReadCguPartitioningProfile {
+    pub path: PathBuf,
+    pub error: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(monomorphize_encountered_error_while_instantiating)]
 pub(crate) struct EncounteredErrorWhileInstantiating {
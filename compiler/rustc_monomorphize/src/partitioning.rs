@@ -93,6 +93,7 @@
 //! inlining, even when they are not marked `#[inline]`.
 
 mod autodiff;
+mod profile;
 
 use std::cmp;
 use std::collections::hash_map::Entry;
@@ -125,11 +126,13 @@
 use tracing::debug;
 
 use crate::collector::{self, MonoItemCollectionStrategy, UsageMap};
-use crate::errors::{CouldntDumpMonoStats, SymbolAlreadyDefined};
+use crate::errors::{CouldntDumpMonoBloat, CouldntDumpMonoStats, SymbolAlreadyDefined};
 
 struct PartitioningCx<'a, 'tcx> {
     tcx: TyCtxt<'tcx>,
     usage_map: &'a UsageMap<'tcx>,
+    /// The call edges of `-Z cgu-partitioning-profile`, from the hottest to the coldest.
+    call_edges: &'a [profile::CallEdge<'tcx>],
 }
 
 struct PlacedMonoItems<'tcx> {
@@ -150,7 +153,7 @@ fn partition<'tcx, I>(
 {
     let _prof_timer = tcx.prof.generic_activity("cgu_partitioning");
 
-    let cx = &PartitioningCx { tcx, usage_map };
+    let cx = &PartitioningCx { tcx, usage_map, call_edges: &[] };
 
     // Place all mono items into a codegen unit. `place_mono_items` is
     // responsible for initializing the CGU size estimates.
@@ -163,6 +166,18 @@ fn partition<'tcx, I>(
         placed
     };
 
+    // Co-locate the hot items with their callers, if we have a profile.
+    let call_edges = match &tcx.sess.opts.unstable_opts.cgu_partitioning_profile {
+        Some(path) => profile::load_call_edges(tcx, path, &codegen_units),
+        None => Vec::new(),
+    };
+    let cx = &PartitioningCx { call_edges: &call_edges, ..*cx };
+    if !call_edges.is_empty() {
+        let _prof_timer = tcx.prof.generic_activity("cgu_partitioning_place_hot_items");
+        profile::place_hot_items(cx, &mut codegen_units);
+        debug_dump(tcx, "PROFILE", &codegen_units);
+    }
+
     // Merge until we don't exceed the max CGU count.
     // `merge_codegen_units` is responsible for updating the CGU size
     // estimates.
@@ -305,21 +320,21 @@ fn place_mono_items<'tcx, I>(cx: &PartitioningCx<'_, 'tcx>, mono_items: I) -> Pl
         cgu.compute_size_estimate();
     }
 
-    return PlacedMonoItems { codegen_units, internalization_candidates };
-
-    fn get_reachable_inlined_items<'tcx>(
-        tcx: TyCtxt<'tcx>,
-        item: MonoItem<'tcx>,
-        usage_map: &UsageMap<'tcx>,
-        visited: &mut FxIndexSet<MonoItem<'tcx>>,
-    ) {
-        usage_map.for_each_inlined_used_item(tcx, item, |inlined_item| {
-            let is_new = visited.insert(inlined_item);
-            if is_new {
-                get_reachable_inlined_items(tcx, inlined_item, usage_map, visited);
-            }
-        });
-    }
+    PlacedMonoItems { codegen_units, internalization_candidates }
+}
+
+fn get_reachable_inlined_items<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    item: MonoItem<'tcx>,
+    usage_map: &UsageMap<'tcx>,
+    visited: &mut FxIndexSet<MonoItem<'tcx>>,
+) {
+    usage_map.for_each_inlined_used_item(tcx, item, |inlined_item| {
+        let is_new = visited.insert(inlined_item);
+        if is_new {
+            get_reachable_inlined_items(tcx, inlined_item, usage_map, visited);
+        }
+    });
 }
 
 // This function requires the CGUs to be sorted by name on input, and ensures
@@ -351,26 +366,43 @@ fn merge_codegen_units<'tcx>(
     // getting any bigger, if we can avoid it. When we have more than N CGUs
     // then at least one of the biggest N will have to grow. codegen_units[N-1]
     // is the smallest of those, and so has the most room to grow.
+    //
+    // With `-Z cgu-partitioning-profile`, the CGU with the heaviest calls to
+    // or from codegen_units[N-1] is merged instead, and the overlap of
+    // inlined items only breaks ties.
     let max_codegen_units = cx.tcx.sess.codegen_units().as_usize();
+    let mut call_graph = (!cx.call_edges.is_empty() && codegen_units.len() > max_codegen_units)
+        .then(|| profile::CallGraph::new(cx.call_edges, codegen_units));
     while codegen_units.len() > max_codegen_units {
         // Sort small CGUs to the back.
         codegen_units.sort_by_key(|cgu| cmp::Reverse(cgu.size_estimate()));
 
         let cgu_dst = &codegen_units[max_codegen_units - 1];
+        let weights =
+            call_graph.as_ref().map(|graph| graph.cgu_weights(cgu_dst)).unwrap_or_default();
 
         // Find the CGU that overlaps the most with `cgu_dst`. In the case of a
         // tie, favour the earlier (bigger) CGU.
+        let mut max_weight = 0;
         let mut max_overlap = 0;
         let mut max_overlap_i = max_codegen_units;
         for (i, cgu_src) in codegen_units.iter().enumerate().skip(max_codegen_units) {
-            if cgu_src.size_estimate() <= max_overlap {
-                // None of the remaining overlaps can exceed `max_overlap`, so
-                // stop looking.
-                break;
+            let weight = weights.get(&cgu_src.name()).copied().unwrap_or(0);
+            if weight < max_weight
+                || (weight == max_weight && cgu_src.size_estimate() <= max_overlap)
+            {
+                if weights.is_empty() {
+                    // None of the remaining overlaps can exceed `max_overlap`,
+                    // so stop looking.
+                    break;
+                }
+                // A smaller CGU may still have heavier calls to `cgu_dst`.
+                continue;
             }
 
             let overlap = compute_inlined_overlap(cgu_dst, cgu_src);
-            if overlap > max_overlap {
+            if (weight, overlap) > (max_weight, max_overlap) {
+                max_weight = weight;
                 max_overlap = overlap;
                 max_overlap_i = i;
             }
@@ -378,6 +410,9 @@ fn merge_codegen_units<'tcx>(
 
         let mut cgu_src = codegen_units.swap_remove(max_overlap_i);
         let cgu_dst = &mut codegen_units[max_codegen_units - 1];
+        if let Some(call_graph) = &mut call_graph {
+            call_graph.merge(&cgu_src, cgu_dst.name());
+        }
 
         // Move the items from `cgu_src` to `cgu_dst`. Some of them may be
         // duplicate inlined items, in which case the destination CGU is
@@ -629,7 +664,8 @@ fn characteristic_def_id_of_mono_item<'tcx>(
     match mono_item {
         MonoItem::Fn(instance) => {
             let def_id = match instance.def {
-                ty::InstanceKind::Item(def) => def,
+                // Outlined cold paths go with their function.
+                ty::InstanceKind::Item(def) | ty::InstanceKind::ColdPathShim(def, _) => def,
                 ty::InstanceKind::VTableShim(..)
                 | ty::InstanceKind::ReifyShim(..)
                 | ty::InstanceKind::FnPtrShim(..)
@@ -812,6 +848,7 @@ fn mono_item_visibility<'tcx>(
         | InstanceKind::Intrinsic(..)
         | InstanceKind::ClosureOnceShim { .. }
         | InstanceKind::ConstructCoroutineInClosureShim { .. }
+        | InstanceKind::ColdPathShim(..)
         | InstanceKind::DropGlue(..)
         | InstanceKind::CloneShim(..)
         | InstanceKind::FnPtrAddrShim(..) => return Visibility::Hidden,
@@ -1185,6 +1222,20 @@ fn collect_and_partition_mono_items(tcx: TyCtxt<'_>, (): ()) -> MonoItemPartitio
         tcx.dcx().emit_fatal(CouldntDumpMonoStats { error: err.to_string() });
     }
 
+    // Output the attribution of monomorphization bloat per generic item
+    if let SwitchWithOptPath::Enabled(ref path) = tcx.sess.opts.unstable_opts.dump_mono_bloat
+        && let Err(err) = dump_mono_bloat(
+            tcx,
+            &items,
+            &usage_map,
+            codegen_units,
+            path,
+            tcx.crate_name(LOCAL_CRATE),
+        )
+    {
+        tcx.dcx().emit_fatal(CouldntDumpMonoBloat { error: err.to_string() });
+    }
+
     if tcx.sess.opts.unstable_opts.print_mono_items {
         let mut item_to_cgus: UnordMap<_, Vec<_>> = Default::default();
 
@@ -1318,6 +1369,172 @@ struct MonoItem {
     Ok(())
 }
 
+/// Outputs the instantiations of every generic function, grouped by definition, with their
+/// estimated size and the chain of uses that led the collector to each of them, to a file in
+/// the given output directory.
+fn dump_mono_bloat<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    items: &[MonoItem<'tcx>],
+    usage_map: &UsageMap<'tcx>,
+    codegen_units: &[CodegenUnit<'tcx>],
+    output_directory: &Option<PathBuf>,
+    crate_name: Symbol,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let output_directory = if let Some(directory) = output_directory {
+        fs::create_dir_all(directory)?;
+        directory
+    } else {
+        Path::new(".")
+    };
+
+    let format = tcx.sess.opts.unstable_opts.dump_mono_stats_format;
+    let ext = format.extension();
+    let filename = format!("{crate_name}.mono_bloat.{ext}");
+    let output_path = output_directory.join(&filename);
+    let mut file = File::create_buffered(&output_path)?;
+
+    // Items that are inlined get a copy in every codegen unit that uses them.
+    let mut copies: UnordMap<MonoItem<'tcx>, usize> = Default::default();
+    for cgu in codegen_units {
+        for &mono_item in cgu.items().keys() {
+            *copies.entry(mono_item).or_default() += 1;
+        }
+    }
+
+    #[derive(serde::Serialize)]
+    struct Instantiation {
+        name: String,
+        size_estimate: usize,
+        copies: usize,
+        /// From the non-generic item that caused this instantiation, to the instantiation.
+        used_by: Vec<String>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct GenericItem {
+        name: String,
+        instantiation_count: usize,
+        size_estimate: usize,
+        codegen_size_estimate: usize,
+        instantiations: Vec<Instantiation>,
+    }
+
+    let mut items_per_def_id: FxIndexMap<DefId, Vec<MonoItem<'tcx>>> = Default::default();
+    for &mono_item in items {
+        if mono_item.is_generic_fn() {
+            items_per_def_id.entry(mono_item.def_id()).or_default().push(mono_item);
+        }
+    }
+
+    let mut report: Vec<_> = items_per_def_id
+        .into_iter()
+        .map(|(def_id, items)| {
+            let mut instantiations: Vec<_> = items
+                .into_iter()
+                .map(|mono_item| {
+                    let size_estimate = mono_item.size_estimate(tcx);
+                    let copies = copies.get(&mono_item).copied().unwrap_or(0);
+                    let used_by = mono_use_chain(tcx, usage_map, mono_item)
+                        .into_iter()
+                        .map(|item| with_no_trimmed_paths!(item.to_string()))
+                        .collect();
+                    let name = with_no_trimmed_paths!(mono_item.to_string());
+                    Instantiation { name, size_estimate, copies, used_by }
+                })
+                .collect();
+            instantiations.sort_by(|a, b| {
+                (b.size_estimate * b.copies, &a.name).cmp(&(a.size_estimate * a.copies, &b.name))
+            });
+            GenericItem {
+                name: with_no_trimmed_paths!(tcx.def_path_str(def_id)),
+                instantiation_count: instantiations.len(),
+                size_estimate: instantiations.iter().map(|i| i.size_estimate).sum(),
+                codegen_size_estimate: instantiations
+                    .iter()
+                    .map(|i| i.size_estimate * i.copies)
+                    .sum(),
+                instantiations,
+            }
+        })
+        .collect();
+    // Heaviest first, by the size of what actually gets codegened
+    report.sort_by(|a, b| {
+        (b.codegen_size_estimate, &a.name).cmp(&(a.codegen_size_estimate, &b.name))
+    });
+
+    match format {
+        DumpMonoStatsFormat::Json => serde_json::to_writer(file, &report)?,
+        DumpMonoStatsFormat::Markdown => {
+            for GenericItem {
+                name,
+                instantiation_count,
+                size_estimate,
+                codegen_size_estimate,
+                instantiations,
+            } in report
+            {
+                writeln!(file, "## `{name}`")?;
+                writeln!(file)?;
+                writeln!(
+                    file,
+                    "{instantiation_count} instantiations, total estimated size \
+                    {size_estimate} ({codegen_size_estimate} including copies)"
+                )?;
+                writeln!(file)?;
+                writeln!(file, "| Instantiation | Estimated Size | Copies | Used By |")?;
+                writeln!(file, "| --- | ---: | ---: | --- |")?;
+                for Instantiation { name, size_estimate, copies, used_by } in instantiations {
+                    let used_by = used_by
+                        .iter()
+                        .map(|item| format!("`{item}`"))
+                        .collect::<Vec<_>>()
+                        .join(" → ");
+                    writeln!(file, "| `{name}` | {size_estimate} | {copies} | {used_by} |")?;
+                }
+                writeln!(file)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds the shortest chain of uses in the collector's usage map that leads from a non-generic
+/// item, the root of the instantiation, to `item`. If the uses never reach a non-generic item,
+/// the chain starts at the last generic user found instead. The chain ends with `item` itself,
+/// and is just `item` if nothing uses it, e.g. because it is a root itself.
+fn mono_use_chain<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    usage_map: &UsageMap<'tcx>,
+    item: MonoItem<'tcx>,
+) -> Vec<MonoItem<'tcx>> {
+    // Breadth first search backwards through the users, remembering who we came from.
+    let mut next_in_chain: FxIndexMap<MonoItem<'tcx>, Option<MonoItem<'tcx>>> = Default::default();
+    next_in_chain.insert(item, None);
+    let mut root = item;
+    let mut i = 0;
+    while let Some((&current, _)) = next_in_chain.get_index(i) {
+        if current != item && !current.is_generic_fn() {
+            root = current;
+            break;
+        }
+        // Sort the users, the usage map is filled in a nondeterministic order.
+        let mut users = usage_map.get_user_items(current).to_vec();
+        users.sort_by_cached_key(|user| user.symbol_name(tcx));
+        for user in users {
+            next_in_chain.entry(user).or_insert(Some(current));
+        }
+        root = current;
+        i += 1;
+    }
+
+    let mut chain = vec![root];
+    while let Some(&Some(next)) = next_in_chain.get(chain.last().unwrap()) {
+        chain.push(next);
+    }
+    chain
+}
+
 pub(crate) fn provide(providers: &mut Providers) {
     providers.collect_and_partition_mono_items = collect_and_partition_mono_items;
 
@@ -0,0 +1,222 @@
+//! Profile-guided partitioning, enabled with `-Z cgu-partitioning-profile`.
+//!
+//! The profile is a text file of call edges between the symbols of the crate, with one
+//! `<caller symbol> <callee symbol> <count>` edge per line. Blank lines and lines starting with
+//! `#` are ignored, and so are the edges between symbols that are not defined in the crate.
+//!
+//! The edges are used twice. After the items are placed, the callee of every hot edge is moved
+//! into the codegen unit of its caller, from the hottest edge to the coldest, so that LLVM can
+//! inline hot callees and keep hot code together, and ThinLTO has less to import. Then, when
+//! codegen units are merged, the ones with the heaviest calls between them are merged first.
+
+use std::path::Path;
+use std::{cmp, fs};
+
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap, FxIndexSet};
+use rustc_middle::mir::mono::{CodegenUnit, Linkage, MonoItem, MonoItemData, Visibility};
+use rustc_middle::ty::TyCtxt;
+use rustc_span::Symbol;
+use tracing::debug;
+
+use super::{PartitioningCx, get_reachable_inlined_items};
+use crate::errors::CouldntReadCguPartitioningProfile;
+
+/// Edges whose count is less than the count of the hottest edge divided by this are too cold to
+/// move items around for, but they are still taken into account when merging.
+const HOT_EDGE_RATIO: u64 = 1000;
+
+/// The calls from one root item to another, as counted by the profile.
+pub(super) struct CallEdge<'tcx> {
+    caller: MonoItem<'tcx>,
+    callee: MonoItem<'tcx>,
+    count: u64,
+}
+
+/// Reads the call edges of `path` between the root items of `codegen_units`, sorted from the
+/// hottest to the coldest.
+pub(super) fn load_call_edges<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    path: &Path,
+    codegen_units: &[CodegenUnit<'tcx>],
+) -> Vec<CallEdge<'tcx>> {
+    let error = |error: String| -> ! {
+        tcx.dcx().emit_fatal(CouldntReadCguPartitioningProfile { path: path.to_path_buf(), error })
+    };
+    let profile = fs::read_to_string(path).unwrap_or_else(|err| error(err.to_string()));
+
+    let mut root_items = FxHashMap::default();
+    for cgu in codegen_units {
+        for (&item, data) in cgu.items() {
+            if !data.inlined {
+                root_items.insert(item.symbol_name(tcx).name, item);
+            }
+        }
+    }
+
+    // Sum the counts of the edges that appear several times, in the order of the file.
+    let mut counts: FxIndexMap<(MonoItem<'tcx>, MonoItem<'tcx>), u64> = Default::default();
+    for (index, line) in profile.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let (Some(caller), Some(callee), Some(count), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            error(format!(
+                "line {}: expected `<caller symbol> <callee symbol> <count>`",
+                index + 1
+            ));
+        };
+        let Ok(count) = count.parse::<u64>() else {
+            error(format!("line {}: invalid count `{count}`", index + 1));
+        };
+        if let Some(&caller) = root_items.get(caller)
+            && let Some(&callee) = root_items.get(callee)
+            && caller != callee
+        {
+            let total = counts.entry((caller, callee)).or_default();
+            *total = total.saturating_add(count);
+        }
+    }
+
+    let mut edges: Vec<_> = counts
+        .into_iter()
+        .filter(|&(_, count)| count > 0)
+        .map(|((caller, callee), count)| CallEdge { caller, callee, count })
+        .collect();
+    edges.sort_by_key(|edge| cmp::Reverse(edge.count));
+    debug!("loaded {} call edges from {}", edges.len(), path.display());
+    edges
+}
+
+/// Moves the callee of every hot edge into the codegen unit of its caller, unless a hotter edge
+/// already placed it. The codegen units stay sorted by name, but the ones that end up empty are
+/// removed.
+pub(super) fn place_hot_items<'tcx>(
+    cx: &PartitioningCx<'_, 'tcx>,
+    codegen_units: &mut Vec<CodegenUnit<'tcx>>,
+) {
+    let Some(hottest) = cx.call_edges.first() else {
+        return;
+    };
+    let threshold = hottest.count / HOT_EDGE_RATIO;
+
+    let mut root_cgus = FxHashMap::default();
+    for (index, cgu) in codegen_units.iter().enumerate() {
+        for (&item, data) in cgu.items() {
+            if !data.inlined {
+                root_cgus.insert(item, index);
+            }
+        }
+    }
+
+    // The items that a hotter edge already put in the right codegen unit.
+    let mut placed = FxHashSet::default();
+    let mut modified = FxIndexSet::default();
+    for edge in cx.call_edges.iter().take_while(|edge| edge.count >= threshold) {
+        let caller_cgu = root_cgus[&edge.caller];
+        let callee_cgu = root_cgus[&edge.callee];
+        if caller_cgu != callee_cgu && !placed.contains(&edge.callee) {
+            debug!("moving {} next to {}", edge.callee, edge.caller);
+            let data = codegen_units[callee_cgu].items_mut().swap_remove(&edge.callee).unwrap();
+            codegen_units[caller_cgu].items_mut().insert(edge.callee, data);
+            root_cgus.insert(edge.callee, caller_cgu);
+            modified.insert(caller_cgu);
+            modified.insert(callee_cgu);
+        }
+        placed.insert(edge.caller);
+        placed.insert(edge.callee);
+    }
+
+    // Update the inlined items of the modified codegen units, which may need the ones of the
+    // items they got, and no longer need the ones of the items they lost.
+    for index in modified {
+        let cgu = &mut codegen_units[index];
+        let mut reachable_inlined_items = FxIndexSet::default();
+        for (&item, data) in cgu.items() {
+            if !data.inlined {
+                get_reachable_inlined_items(
+                    cx.tcx,
+                    item,
+                    cx.usage_map,
+                    &mut reachable_inlined_items,
+                );
+            }
+        }
+        cgu.items_mut()
+            .retain(|item, data| !data.inlined || reachable_inlined_items.contains(item));
+        for inlined_item in reachable_inlined_items {
+            // This is a CGU-private copy.
+            cgu.items_mut().entry(inlined_item).or_insert_with(|| MonoItemData {
+                inlined: true,
+                linkage: Linkage::Internal,
+                visibility: Visibility::Default,
+                size_estimate: inlined_item.size_estimate(cx.tcx),
+            });
+        }
+        cgu.compute_size_estimate();
+    }
+
+    codegen_units.retain(|cgu| !cgu.items().is_empty());
+}
+
+/// The call edges between the root items of the codegen units that are being merged, indexed by
+/// item, so that the weight of the calls of a codegen unit only depends on the edges of its items.
+pub(super) struct CallGraph<'tcx> {
+    /// The codegen unit of every root item with call edges.
+    item_cgus: FxHashMap<MonoItem<'tcx>, Symbol>,
+    /// The callers and callees of every root item, with the count of the calls.
+    neighbours: FxHashMap<MonoItem<'tcx>, Vec<(MonoItem<'tcx>, u64)>>,
+}
+
+impl<'tcx> CallGraph<'tcx> {
+    pub(super) fn new(call_edges: &[CallEdge<'tcx>], codegen_units: &[CodegenUnit<'tcx>]) -> Self {
+        let mut neighbours: FxHashMap<_, Vec<_>> = FxHashMap::default();
+        for edge in call_edges {
+            neighbours.entry(edge.caller).or_default().push((edge.callee, edge.count));
+            neighbours.entry(edge.callee).or_default().push((edge.caller, edge.count));
+        }
+
+        let mut item_cgus = FxHashMap::default();
+        for cgu in codegen_units {
+            for (&item, data) in cgu.items() {
+                if !data.inlined && neighbours.contains_key(&item) {
+                    item_cgus.insert(item, cgu.name());
+                }
+            }
+        }
+        CallGraph { item_cgus, neighbours }
+    }
+
+    /// Computes the count of the calls between the root items of `cgu` and the ones of every
+    /// other codegen unit, by name.
+    pub(super) fn cgu_weights(&self, cgu: &CodegenUnit<'tcx>) -> FxHashMap<Symbol, u64> {
+        let mut weights: FxHashMap<Symbol, u64> = FxHashMap::default();
+        for (item, data) in cgu.items() {
+            if data.inlined {
+                continue;
+            }
+            for (other, count) in self.neighbours.get(item).into_iter().flatten() {
+                let other_cgu = self.item_cgus[other];
+                if other_cgu != cgu.name() {
+                    let weight = weights.entry(other_cgu).or_default();
+                    *weight = weight.saturating_add(*count);
+                }
+            }
+        }
+        weights
+    }
+
+    /// Records that the root items of `cgu_src` are about to be merged into `cgu_dst`.
+    pub(super) fn merge(&mut self, cgu_src: &CodegenUnit<'tcx>, cgu_dst: Symbol) {
+        for (item, data) in cgu_src.items() {
+            if !data.inlined
+                && let Some(cgu) = self.item_cgus.get_mut(item)
+            {
+                *cgu = cgu_dst;
+            }
+        }
+    }
+}
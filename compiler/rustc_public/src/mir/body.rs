@@ -270,6 +270,7 @@ pub enum AssertMessage {
     MisalignedPointerDereference { required: Operand, found: Operand },
     NullPointerDereference,
     InvalidEnumConstruction(Operand),
+    InvalidScalarValue { value: Operand, start: Operand, end: Operand },
 }
 
 impl AssertMessage {
@@ -345,6 +346,7 @@ pub fn description(&self) -> Result<&'static str, Error> {
             AssertMessage::InvalidEnumConstruction(_) => {
                 Ok("trying to construct an enum from an invalid value")
             }
+            AssertMessage::InvalidScalarValue { .. } => Ok("constructing an invalid value"),
         }
     }
 }
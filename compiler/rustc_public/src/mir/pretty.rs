@@ -316,6 +316,15 @@ fn pretty_assert_message<W: Write>(writer: &mut W, msg: &AssertMessage) -> io::R
             let pretty_op = pretty_operand(op);
             write!(writer, "\"trying to construct an enum from an invalid value {{}}\",{pretty_op}")
         }
+        AssertMessage::InvalidScalarValue { value, start, end } => {
+            let pretty_value = pretty_operand(value);
+            let pretty_start = pretty_operand(start);
+            let pretty_end = pretty_operand(end);
+            write!(
+                writer,
+                "\"constructing an invalid value {{}}, expected a value in {{}}..={{}}\",{pretty_value}, {pretty_start}, {pretty_end}"
+            )
+        }
         AssertMessage::ResumedAfterReturn(_)
         | AssertMessage::ResumedAfterPanic(_)
         | AssertMessage::ResumedAfterDrop(_) => {
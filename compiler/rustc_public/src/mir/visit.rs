@@ -379,6 +379,11 @@ fn super_assert_msg(&mut self, msg: &$($mutability)? AssertMessage, location: Lo
                         self.visit_operand(required, location);
                         self.visit_operand(found, location);
                     }
+                    AssertMessage::InvalidScalarValue { value, start, end } => {
+                        self.visit_operand(value, location);
+                        self.visit_operand(start, location);
+                        self.visit_operand(end, location);
+                    }
                 }
             }
         }
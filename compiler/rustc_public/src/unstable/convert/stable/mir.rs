@@ -573,6 +573,13 @@ fn stable<'cx>(
             AssertKind::InvalidEnumConstruction(source) => {
                 crate::mir::AssertMessage::InvalidEnumConstruction(source.stable(tables, cx))
             }
+            AssertKind::InvalidScalarValue { value, start, end } => {
+                crate::mir::AssertMessage::InvalidScalarValue {
+                    value: value.stable(tables, cx),
+                    start: start.stable(tables, cx),
+                    end: end.stable(tables, cx),
+                }
+            }
         }
     }
 }
@@ -0,0 +1,124 @@
+//! Support for `-Z incremental-explain`, which records why the queries of the previous session
+//! could not be reused.
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::sync::Lock;
+
+use super::graph::{DepNodeColor, DepNodeColorMap};
+use super::serialized::{SerializedDepGraph, SerializedDepNodeIndex};
+use super::{DepContext, DepNode, Deps};
+
+/// Why a node was red, at the end of a chain of first red dependencies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RedCause {
+    /// The node is an input of the dependency graph, like the HIR of an item or the hash of an
+    /// upstream crate, which is always evaluated again, and its result changed.
+    InputChanged,
+    /// The node depends on state that is not tracked by the dependency graph, so it can never
+    /// be reused.
+    Untracked,
+    /// The node was evaluated again and its result changed, even though no red dependency was
+    /// recorded for it, e.g. because its result was fed by another query.
+    Changed,
+    /// The node could not be evaluated again on its own, e.g. because its key does not exist
+    /// anymore, so nothing that depends on it can be reused.
+    NotForceable,
+}
+
+/// A node of the previous session that could not be marked green and was evaluated again.
+#[derive(Debug)]
+pub struct Reexecution {
+    pub node: DepNode,
+    /// Whether evaluating the node again changed its result. Nodes of `no_hash` queries always
+    /// count as changed.
+    pub changed: bool,
+    /// The first red dependency of `node`, followed by the first red dependency of that
+    /// dependency and so on, down to the node that is red for the reason given by `cause`.
+    pub red_dependencies: Vec<DepNode>,
+    pub cause: RedCause,
+}
+
+/// Everything `-Z incremental-explain` knows about why the previous session was not reused.
+#[derive(Debug)]
+pub struct Explanation {
+    /// Why the previous session was discarded as a whole, if it was, e.g. because the
+    /// command-line arguments changed.
+    pub discarded_previous_session: Option<&'static str>,
+    pub reexecutions: Vec<Reexecution>,
+}
+
+/// Records, for every node of the previous session that could not be marked green, which of its
+/// dependencies prevented it.
+#[derive(Default)]
+pub(super) struct RedDependencies {
+    first: Lock<FxHashMap<SerializedDepNodeIndex, SerializedDepNodeIndex>>,
+    /// Why the previous session was discarded as a whole, if it was.
+    discarded_previous_session: Lock<Option<&'static str>>,
+}
+
+impl RedDependencies {
+    pub(super) fn record(&self, node: SerializedDepNodeIndex, dependency: SerializedDepNodeIndex) {
+        // A dependency that could not be forced is not colored, so we may try to mark the same
+        // node green again later. Keep the dependency that failed first.
+        self.first.lock().entry(node).or_insert(dependency);
+    }
+
+    pub(super) fn discard_previous_session(&self, reason: &'static str) {
+        *self.discarded_previous_session.lock() = Some(reason);
+    }
+
+    pub(super) fn explain<Tcx: DepContext>(
+        &self,
+        tcx: Tcx,
+        previous: &SerializedDepGraph,
+        colors: &DepNodeColorMap,
+    ) -> Explanation {
+        let first = self.first.lock();
+
+        // Nodes that are not colored were only tried, never evaluated, e.g. because they are
+        // the dependency of something that could not be forced.
+        // `nodes` is sorted below so we can allow this lint here.
+        #[allow(rustc::potential_query_instability)]
+        let mut nodes: Vec<_> =
+            first.keys().copied().filter(|&index| colors.get(index).is_some()).collect();
+        nodes.sort_unstable_by_key(|index| index.as_u32());
+
+        let reexecutions = nodes
+            .into_iter()
+            .map(|index| {
+                let mut red_dependencies = Vec::new();
+                let mut current = index;
+                while let Some(&dependency) = first.get(&current) {
+                    red_dependencies.push(previous.index_to_node(dependency));
+                    current = dependency;
+                }
+
+                let cause = match colors.get(current) {
+                    Some(DepNodeColor::Red) => {
+                        let kind = previous.index_to_node(current).kind;
+                        if kind == Tcx::Deps::DEP_KIND_RED {
+                            RedCause::Untracked
+                        } else if tcx.is_eval_always(kind) {
+                            RedCause::InputChanged
+                        } else {
+                            RedCause::Changed
+                        }
+                    }
+                    Some(DepNodeColor::Green(_)) | None => RedCause::NotForceable,
+                };
+
+                Reexecution {
+                    node: previous.index_to_node(index),
+                    changed: matches!(colors.get(index), Some(DepNodeColor::Red)),
+                    red_dependencies,
+                    cause,
+                }
+            })
+            .collect();
+
+        Explanation {
+            discarded_previous_session: *self.discarded_previous_session.lock(),
+            reexecutions,
+        }
+    }
+}
@@ -22,6 +22,7 @@
 #[cfg(debug_assertions)]
 use {super::debug::EdgeFilter, std::env};
 
+use super::explain::{Explanation, RedDependencies};
 use super::query::DepGraphQuery;
 use super::serialized::{GraphEncoder, SerializedDepGraph, SerializedDepNodeIndex};
 use super::{DepContext, DepKind, DepNode, Deps, HasDepContext, WorkProductId};
@@ -107,6 +108,10 @@ pub(crate) struct DepGraphData<D: Deps> {
     /// a particular query result was decoded from disk
     /// (not just marked green)
     debug_loaded_from_disk: Lock<FxHashSet<DepNode>>,
+
+    /// The first dependency that was not green for every node that could not be marked green.
+    /// Only recorded with `-Z incremental-explain`.
+    red_dependencies: Option<RedDependencies>,
 }
 
 pub fn hash_result<R>(hcx: &mut StableHashingContext<'_>, result: &R) -> Fingerprint
@@ -162,6 +167,12 @@ pub fn new(
                 previous: prev_graph,
                 colors,
                 debug_loaded_from_disk: Default::default(),
+                red_dependencies: session
+                    .opts
+                    .unstable_opts
+                    .incremental_explain
+                    .is_some()
+                    .then(Default::default),
             })),
             virtual_dep_node_index: Arc::new(AtomicU32::new(0)),
         }
@@ -973,7 +984,12 @@ fn try_mark_previous_green<Qcx: QueryContext<Deps = D>>(
         let prev_deps = self.previous.edge_targets_from(prev_dep_node_index);
 
         for dep_dep_node_index in prev_deps {
-            self.try_mark_parent_green(qcx, dep_dep_node_index, Some(&frame))?;
+            if self.try_mark_parent_green(qcx, dep_dep_node_index, Some(&frame)).is_none() {
+                if let Some(red_dependencies) = &self.red_dependencies {
+                    red_dependencies.record(prev_dep_node_index, dep_dep_node_index);
+                }
+                return None;
+            }
         }
 
         // If we got here without hitting a `return` that means that all
@@ -1044,6 +1060,22 @@ pub fn exec_cache_promotions<Tcx: DepContext>(&self, tcx: Tcx) {
         }
     }
 
+    /// Records that the previous session was not loaded at all, for `-Z incremental-explain`.
+    pub fn explain_discarded_previous_session(&self, reason: &'static str) {
+        if let Some(red_dependencies) = self.data.as_ref().and_then(|d| d.red_dependencies.as_ref())
+        {
+            red_dependencies.discard_previous_session(reason);
+        }
+    }
+
+    /// Explains why each node of the previous session that was evaluated again could not be
+    /// reused. Returns `None` unless `-Z incremental-explain` is enabled.
+    pub fn explain<Tcx: DepContext<Deps = D>>(&self, tcx: Tcx) -> Option<Explanation> {
+        let data = self.data.as_ref()?;
+        let red_dependencies = data.red_dependencies.as_ref()?;
+        Some(red_dependencies.explain(tcx, &data.previous, &data.colors))
+    }
+
     pub fn finish_encoding(&self) -> FileEncodeResult {
         if let Some(data) = &self.data { data.current.encoder.finish(&data.current) } else { Ok(0) }
     }
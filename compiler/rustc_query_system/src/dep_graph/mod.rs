@@ -1,6 +1,7 @@
 pub mod debug;
 pub mod dep_node;
 mod edges;
+mod explain;
 mod graph;
 mod query;
 mod serialized;
@@ -8,6 +9,7 @@
 use std::panic;
 
 pub use dep_node::{DepKind, DepKindStruct, DepNode, DepNodeParams, WorkProductId};
+pub use explain::{Explanation, RedCause, Reexecution};
 pub(crate) use graph::DepGraphData;
 pub use graph::{DepGraph, DepNodeIndex, TaskDepsRef, WorkProduct, WorkProductMap, hash_result};
 pub use query::DepGraphQuery;
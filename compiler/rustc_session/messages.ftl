@@ -11,6 +11,8 @@ session_cannot_enable_crt_static_linux = sanitizer is incompatible with statical
 
 session_cannot_mix_and_match_sanitizers = `-Zsanitizer={$first}` is incompatible with `-Zsanitizer={$second}`
 
+session_cgu_partitioning_profile_file_does_not_exist = file `{$path}` passed to `-Z cgu-partitioning-profile` does not exist
+
 session_cli_feature_diagnostic_help =
     add `-Zcrate-attr="feature({$feature})"` to the command-line options to enable
 
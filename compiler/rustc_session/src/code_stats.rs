@@ -4,6 +4,7 @@
 use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::sync::Lock;
 use rustc_span::Symbol;
+use serde::Serialize;
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct VariantInfo {
@@ -59,6 +60,47 @@ pub enum DataTypeKind {
     Coroutine,
 }
 
+impl DataTypeKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            DataTypeKind::Struct => "struct",
+            DataTypeKind::Union => "union",
+            DataTypeKind::Enum => "enum",
+            DataTypeKind::Closure => "closure",
+            DataTypeKind::Coroutine => "coroutine",
+        }
+    }
+}
+
+/// How the variant of an enum or coroutine is encoded, see `rustc_abi::TagEncoding`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TagEncodingInfo {
+    Direct,
+    Niche {
+        untagged_variant: Symbol,
+        /// The variants whose tag is stored in the niche of `untagged_variant`.
+        niche_variants: Vec<Symbol>,
+        niche_start: u128,
+    },
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TagInfo {
+    pub offset: u64,
+    pub size: u64,
+    pub encoding: TagEncodingInfo,
+}
+
+/// The largest niche of a type, see `rustc_abi::Niche`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NicheInfo {
+    pub offset: u64,
+    pub size: u64,
+    /// The valid values of the niche, which may wrap around.
+    pub valid_range_start: u128,
+    pub valid_range_end: u128,
+}
+
 #[derive(PartialEq, Eq, Hash, Debug)]
 pub struct TypeSizeInfo {
     pub kind: DataTypeKind,
@@ -68,6 +110,8 @@ pub struct TypeSizeInfo {
     pub packed: bool,
     pub opt_discr_size: Option<u64>,
     pub variants: Vec<VariantInfo>,
+    pub tag: Option<TagInfo>,
+    pub largest_niche: Option<NicheInfo>,
 }
 
 #[derive(Default)]
@@ -87,6 +131,8 @@ pub fn record_type_size<S: ToString>(
         packed: bool,
         opt_discr_size: Option<Size>,
         mut variants: Vec<VariantInfo>,
+        tag: Option<TagInfo>,
+        largest_niche: Option<NicheInfo>,
     ) {
         // Sort variants so the largest ones are shown first. A stable sort is
         // used here so that source code order is preserved for all variants
@@ -104,12 +150,13 @@ pub fn record_type_size<S: ToString>(
             packed,
             opt_discr_size: opt_discr_size.map(|s| s.bytes()),
             variants,
+            tag,
+            largest_niche,
         };
         self.type_sizes.borrow_mut().insert(info);
     }
 
-    pub fn print_type_sizes(&self) {
-        let type_sizes = self.type_sizes.borrow();
+    fn sorted_type_sizes(type_sizes: &FxHashSet<TypeSizeInfo>) -> Vec<&TypeSizeInfo> {
         // We will soon sort, so the initial order does not matter.
         #[allow(rustc::potential_query_instability)]
         let mut sorted: Vec<_> = type_sizes.iter().collect();
@@ -117,8 +164,12 @@ pub fn print_type_sizes(&self) {
         // Primary sort: large-to-small.
         // Secondary sort: description (dictionary order)
         sorted.sort_by_key(|info| (cmp::Reverse(info.overall_size), &info.type_description));
+        sorted
+    }
 
-        for info in sorted {
+    pub fn print_type_sizes(&self) {
+        let type_sizes = self.type_sizes.borrow();
+        for info in Self::sorted_type_sizes(&type_sizes) {
             let TypeSizeInfo { type_description, overall_size, align, kind, variants, .. } = info;
             println!(
                 "print-type-size type: `{type_description}`: {overall_size} bytes, alignment: {align} bytes"
@@ -211,4 +262,166 @@ pub fn print_type_sizes(&self) {
             }
         }
     }
+
+    /// Prints the layouts recorded for `-Z print-type-sizes` as JSON, one type per line, for
+    /// `-Z print-type-sizes-format=json`.
+    pub fn print_type_sizes_json(&self) {
+        let type_sizes = self.type_sizes.borrow();
+        for info in Self::sorted_type_sizes(&type_sizes) {
+            println!("{}", serde_json::to_string(&TypeLayoutJson::new(info)).unwrap());
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct TypeLayoutJson {
+    #[serde(rename = "type")]
+    type_description: String,
+    kind: &'static str,
+    size: u64,
+    align: u64,
+    packed: bool,
+    tag: Option<TagJson>,
+    niche: Option<NicheJson>,
+    variants: Vec<VariantJson>,
+}
+
+#[derive(Serialize)]
+struct TagJson {
+    offset: u64,
+    size: u64,
+    #[serde(flatten)]
+    encoding: TagEncodingJson,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "encoding", rename_all = "snake_case")]
+enum TagEncodingJson {
+    Direct,
+    /// `niche_start` is a string, as it may not fit in the integers that JSON readers handle.
+    Niche {
+        untagged_variant: String,
+        niche_variants: Vec<String>,
+        niche_start: String,
+    },
+}
+
+#[derive(Serialize)]
+struct NicheJson {
+    offset: u64,
+    size: u64,
+    valid_range: ValidRangeJson,
+}
+
+/// The bounds are strings, as they may not fit in the integers that JSON readers handle.
+#[derive(Serialize)]
+struct ValidRangeJson {
+    start: String,
+    end: String,
+}
+
+#[derive(Serialize)]
+struct VariantJson {
+    name: Option<String>,
+    size: u64,
+    align: u64,
+    fields: Vec<FieldJson>,
+    /// The bytes of the type that are neither part of a field of this variant nor of the tag.
+    padding: Vec<PaddingJson>,
+}
+
+#[derive(Serialize)]
+struct FieldJson {
+    name: String,
+    kind: String,
+    offset: u64,
+    size: u64,
+    align: u64,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    type_name: Option<String>,
+}
+
+#[derive(Serialize)]
+struct PaddingJson {
+    offset: u64,
+    size: u64,
+}
+
+impl TypeLayoutJson {
+    fn new(info: &TypeSizeInfo) -> TypeLayoutJson {
+        let tag = info.tag.as_ref().map(|tag| TagJson {
+            offset: tag.offset,
+            size: tag.size,
+            encoding: match tag.encoding {
+                TagEncodingInfo::Direct => TagEncodingJson::Direct,
+                TagEncodingInfo::Niche { untagged_variant, ref niche_variants, niche_start } => {
+                    TagEncodingJson::Niche {
+                        untagged_variant: untagged_variant.to_string(),
+                        niche_variants: niche_variants.iter().map(|v| v.to_string()).collect(),
+                        niche_start: niche_start.to_string(),
+                    }
+                }
+            },
+        });
+        let niche = info.largest_niche.map(|niche| NicheJson {
+            offset: niche.offset,
+            size: niche.size,
+            valid_range: ValidRangeJson {
+                start: niche.valid_range_start.to_string(),
+                end: niche.valid_range_end.to_string(),
+            },
+        });
+
+        let variants = info
+            .variants
+            .iter()
+            .map(|variant| {
+                let mut fields = variant.fields.clone();
+                fields.sort_by_key(|f| (f.offset, f.size));
+
+                // Every byte that is not covered by a field or by the tag is padding.
+                let mut occupied: Vec<_> =
+                    fields.iter().map(|f| (f.offset, f.offset + f.size)).collect();
+                occupied.extend(info.tag.as_ref().map(|tag| (tag.offset, tag.offset + tag.size)));
+                occupied.sort();
+                let mut padding = vec![];
+                let mut end = 0;
+                for (start, range_end) in occupied.into_iter().chain([(info.overall_size, 0)]) {
+                    if start > end {
+                        padding.push(PaddingJson { offset: end, size: start - end });
+                    }
+                    end = cmp::max(end, range_end);
+                }
+
+                VariantJson {
+                    name: variant.name.map(|name| name.to_string()),
+                    size: variant.size,
+                    align: variant.align,
+                    fields: fields
+                        .into_iter()
+                        .map(|f| FieldJson {
+                            name: f.name.to_string(),
+                            kind: f.kind.to_string(),
+                            offset: f.offset,
+                            size: f.size,
+                            align: f.align,
+                            type_name: f.type_name.map(|name| name.to_string()),
+                        })
+                        .collect(),
+                    padding,
+                }
+            })
+            .collect();
+
+        TypeLayoutJson {
+            type_description: info.type_description.clone(),
+            kind: info.kind.as_str(),
+            size: info.overall_size,
+            align: info.align,
+            packed: info.packed,
+            tag,
+            niche,
+            variants,
+        }
+    }
 }
@@ -854,6 +854,8 @@ pub enum ErrorOutputType {
         json_rendered: HumanReadableErrorType,
         color_config: ColorConfig,
     },
+    /// A single SARIF 2.1.0 log, consumed by code scanning platforms and IDEs.
+    Sarif,
 }
 
 #[derive(Clone, Hash, Debug)]
@@ -2085,11 +2087,13 @@ pub fn parse_error_format(
                 kind: HumanReadableErrorType::Unicode,
                 color_config,
             },
+            Some("sarif") => ErrorOutputType::Sarif,
             Some(arg) => {
                 early_dcx.set_error_format(ErrorOutputType::HumanReadable { color_config, .. });
                 early_dcx.early_fatal(format!(
                     "argument for `--error-format` must be `human`, `human-annotate-rs`, \
-                    `human-unicode`, `json`, `pretty-json` or `short` (instead was `{arg}`)"
+                    `human-unicode`, `json`, `pretty-json`, `sarif` or `short` \
+                    (instead was `{arg}`)"
                 ))
             }
         }
@@ -2149,6 +2153,7 @@ fn check_error_format_stability(
     }
     let format = match format {
         ErrorOutputType::Json { pretty: true, .. } => "pretty-json",
+        ErrorOutputType::Sarif => "sarif",
         ErrorOutputType::HumanReadable { kind, .. } => match kind {
             HumanReadableErrorType::AnnotateSnippet => "human-annotate-rs",
             HumanReadableErrorType::Unicode => "human-unicode",
@@ -3456,6 +3461,15 @@ pub enum CollapseMacroDebuginfo {
     Yes = 3,
 }
 
+/// Which format to use for `-Z print-type-sizes`
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum PrintTypeSizesFormat {
+    /// Print human readable text
+    Text,
+    /// Print one JSON object per type
+    Json,
+}
+
 /// Which format to use for `-Z dump-mono-stats`
 #[derive(Clone, Copy, PartialEq, Hash, Debug)]
 pub enum DumpMonoStatsFormat {
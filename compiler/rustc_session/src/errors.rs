@@ -102,6 +102,12 @@ pub(crate) struct ProfileSampleUseFileDoesNotExist<'a> {
     pub(crate) path: &'a std::path::Path,
 }
 
+#[derive(Diagnostic)]
+#[diag(session_cgu_partitioning_profile_file_does_not_exist)]
+pub(crate) struct CguPartitioningProfileFileDoesNotExist<'a> {
+    pub(crate) path: &'a std::path::Path,
+}
+
 #[derive(Diagnostic)]
 #[diag(session_target_requires_unwind_tables)]
 pub(crate) struct TargetRequiresUnwindTables;
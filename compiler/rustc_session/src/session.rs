@@ -18,6 +18,7 @@
     DynEmitter, HumanEmitter, HumanReadableErrorType, OutputTheme, stderr_destination,
 };
 use rustc_errors::json::JsonEmitter;
+use rustc_errors::sarif::{SarifEmitter, SharedLog};
 use rustc_errors::timings::TimingSectionHandler;
 use rustc_errors::translation::Translator;
 use rustc_errors::{
@@ -37,7 +38,9 @@
 };
 
 use crate::code_stats::CodeStats;
-pub use crate::code_stats::{DataTypeKind, FieldInfo, FieldKind, SizeKind, VariantInfo};
+pub use crate::code_stats::{
+    DataTypeKind, FieldInfo, FieldKind, NicheInfo, SizeKind, TagEncodingInfo, TagInfo, VariantInfo,
+};
 use crate::config::{
     self, CoverageLevel, CrateType, DebugInfo, ErrorOutputType, FunctionReturn, Input,
     InstrumentCoverage, OptLevel, OutFileName, OutputType, RemapPathScopeComponents,
@@ -959,6 +962,7 @@ fn default_emitter(
     sopts: &config::Options,
     source_map: Arc<SourceMap>,
     translator: Translator,
+    sarif_log: Option<SharedLog>,
 ) -> Box<DynEmitter> {
     let macro_backtrace = sopts.unstable_opts.macro_backtrace;
     let track_diagnostics = sopts.unstable_opts.track_diagnostics;
@@ -1023,6 +1027,11 @@ fn default_emitter(
             .track_diagnostics(track_diagnostics)
             .terminal_url(terminal_url),
         ),
+        config::ErrorOutputType::Sarif => {
+            let log = sarif_log
+                .unwrap_or_else(|| SharedLog::new(Box::new(io::BufWriter::new(io::stderr()))));
+            Box::new(SarifEmitter::with_log(log, source_map, translator))
+        }
     }
 }
 
@@ -1041,6 +1050,7 @@ pub fn build_session(
     ice_file: Option<PathBuf>,
     using_internal_features: &'static AtomicBool,
     expanded_args: Vec<String>,
+    sarif_log: Option<SharedLog>,
 ) -> Session {
     // FIXME: This is not general enough to make the warning lint completely override
     // normal diagnostic warnings, since the warning lint can also be denied and changed
@@ -1061,7 +1071,7 @@ pub fn build_session(
         ),
     };
     let source_map = rustc_span::source_map::get_source_map().unwrap();
-    let emitter = default_emitter(&sopts, Arc::clone(&source_map), translator);
+    let emitter = default_emitter(&sopts, Arc::clone(&source_map), translator, sarif_log);
 
     let mut dcx = DiagCtxt::new(emitter)
         .with_flags(sopts.unstable_opts.dcx_flags(can_emit_warnings))
@@ -1086,6 +1096,7 @@ pub fn build_session(
             sopts.crate_name.as_deref(),
             sopts.unstable_opts.self_profile_events.as_deref(),
             &sopts.unstable_opts.self_profile_counter,
+            sopts.unstable_opts.self_profile_format,
         );
         match profiler {
             Ok(profiler) => Some(Arc::new(profiler)),
@@ -1204,6 +1215,13 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
         }
     }
 
+    // And for the call edges used to partition codegen units.
+    if let Some(ref path) = sess.opts.unstable_opts.cgu_partitioning_profile {
+        if !path.exists() {
+            sess.dcx().emit_err(errors::CguPartitioningProfileFileDoesNotExist { path });
+        }
+    }
+
     // Unwind tables cannot be disabled if the target requires them.
     if let Some(include_uwtables) = sess.opts.cg.force_unwind_tables {
         if sess.target.requires_uwtable && !include_uwtables {
@@ -1440,12 +1458,20 @@ enum IncrCompSession {
 /// A wrapper around an [`DiagCtxt`] that is used for early error emissions.
 pub struct EarlyDiagCtxt {
     dcx: DiagCtxt,
+    /// The log of the emitter with `ErrorOutputType::Sarif`.
+    sarif_log: Option<SharedLog>,
 }
 
 impl EarlyDiagCtxt {
     pub fn new(output: ErrorOutputType) -> Self {
-        let emitter = mk_emitter(output);
-        Self { dcx: DiagCtxt::new(emitter) }
+        Self::with_sarif_log(output, None)
+    }
+
+    /// Like `new`, but with `ErrorOutputType::Sarif` the diagnostics are collected in
+    /// `sarif_log`, if any, e.g. the log of the session's emitter.
+    pub fn with_sarif_log(output: ErrorOutputType, sarif_log: Option<SharedLog>) -> Self {
+        let (emitter, sarif_log) = mk_emitter(output, sarif_log);
+        Self { dcx: DiagCtxt::new(emitter), sarif_log }
     }
 
     /// Swap out the underlying dcx once we acquire the user's preference on error emission
@@ -1453,8 +1479,15 @@ pub fn new(output: ErrorOutputType) -> Self {
     pub fn set_error_format(&mut self, output: ErrorOutputType) {
         assert!(self.dcx.handle().has_errors().is_none());
 
-        let emitter = mk_emitter(output);
+        let (emitter, sarif_log) = mk_emitter(output, self.sarif_log.take());
         self.dcx = DiagCtxt::new(emitter);
+        self.sarif_log = sarif_log;
+    }
+
+    /// The SARIF log of this context, to pass to the session with `interface::Config::sarif_log`,
+    /// so that an invocation of the compiler writes a single log.
+    pub fn sarif_log(&self) -> Option<SharedLog> {
+        self.sarif_log.clone()
     }
 
     #[allow(rustc::untranslatable_diagnostic)]
@@ -1501,7 +1534,10 @@ pub fn early_struct_warn(&self, msg: impl Into<DiagMessage>) -> Diag<'_, ()> {
     }
 }
 
-fn mk_emitter(output: ErrorOutputType) -> Box<DynEmitter> {
+fn mk_emitter(
+    output: ErrorOutputType,
+    sarif_log: Option<SharedLog>,
+) -> (Box<DynEmitter>, Option<SharedLog>) {
     // FIXME(#100717): early errors aren't translated at the moment, so this is fine, but it will
     // need to reference every crate that might emit an early error for translation to work.
     let translator =
@@ -1529,8 +1565,19 @@ fn mk_emitter(output: ErrorOutputType) -> Box<DynEmitter> {
                 color_config,
             ))
         }
+        config::ErrorOutputType::Sarif => {
+            let log = sarif_log
+                .unwrap_or_else(|| SharedLog::new(Box::new(io::BufWriter::new(io::stderr()))));
+            let emitter = SarifEmitter::with_log(
+                log.clone(),
+                Some(Arc::new(SourceMap::new(FilePathMapping::empty()))),
+                translator,
+            )
+            .write_empty_log(false);
+            return (Box::new(emitter), Some(log));
+        }
     };
-    emitter
+    (emitter, None)
 }
 
 pub trait RemapFileNameExt {
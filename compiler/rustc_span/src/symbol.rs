@@ -1586,6 +1586,7 @@
         panic_in_cleanup,
         panic_info,
         panic_invalid_enum_construction,
+        panic_invalid_scalar_value,
         panic_location,
         panic_misaligned_pointer_dereference,
         panic_nounwind,
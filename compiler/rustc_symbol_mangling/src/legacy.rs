@@ -87,6 +87,9 @@ pub(super) fn mangle<'tcx>(
         ty::InstanceKind::VTableShim(..) => {
             printer.write_str("{{vtable-shim}}").unwrap();
         }
+        ty::InstanceKind::ColdPathShim(_, index) => {
+            write!(printer, "{{{{cold-path-shim-{index}}}}}").unwrap();
+        }
         ty::InstanceKind::ReifyShim(_, reason) => {
             printer.write_str("{{reify-shim").unwrap();
             match reason {
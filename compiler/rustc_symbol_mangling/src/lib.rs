@@ -133,6 +133,13 @@ pub fn provide(providers: &mut Providers) {
 // instance from the local crate. In particular, it will also look up the
 // correct symbol name of instances from upstream crates.
 fn symbol_name_provider<'tcx>(tcx: TyCtxt<'tcx>, instance: Instance<'tcx>) -> ty::SymbolName<'tcx> {
+    // With `-Z polymorphize`, only the polymorphized instance is codegened, and all the instances
+    // that share it refer to its symbol.
+    let polymorphized = instance.polymorphize(tcx);
+    if polymorphized != instance {
+        return tcx.symbol_name(polymorphized);
+    }
+
     let symbol_name = compute_symbol_name(tcx, instance, || {
         // This closure determines the instantiating crate for instances that
         // need an instantiating-crate-suffix for their symbol name, in order
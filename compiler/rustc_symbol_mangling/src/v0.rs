@@ -70,6 +70,9 @@ pub(super) fn mangle<'tcx>(
         };
         let drop_ty = cor_args.first().unwrap().expect_ty();
         cx.print_def_path(def_id, tcx.mk_args(&[GenericArg::from(drop_ty)])).unwrap()
+    } else if let ty::InstanceKind::ColdPathShim(_, index) = instance.def {
+        cx.path_append_ns(|cx| cx.print_def_path(def_id, args), 'S', index.into(), "cold_path")
+            .unwrap()
     } else if let Some(shim_kind) = shim_kind {
         cx.path_append_ns(|cx| cx.print_def_path(def_id, args), 'S', 0, shim_kind).unwrap()
     } else {
@@ -43,6 +43,17 @@ fn fn_sig_for_fn_abi<'tcx>(
         );
     }
 
+    if let InstanceKind::ColdPathShim(def_id, index) = instance.def {
+        let body = &tcx.optimized_mir(def_id).outlined_cold_paths[index as usize];
+        return tcx.mk_fn_sig(
+            body.args_iter().map(|arg| body.local_decls[arg].ty),
+            body.return_ty(),
+            false,
+            hir::Safety::Safe,
+            rustc_abi::ExternAbi::Rust,
+        );
+    }
+
     let ty = instance.ty(tcx, typing_env);
     match *ty.kind() {
         ty::FnDef(def_id, args) => {
@@ -471,9 +482,11 @@ fn fn_abi_new_uncached<'tcx>(
     let (caller_location, determined_fn_def_id, is_virtual_call) = if let Some(instance) = instance
     {
         let is_virtual_call = matches!(instance.def, ty::InstanceKind::Virtual(..));
+        // The parameters of an outlined cold path are not the ones of its function.
+        let is_cold_path = matches!(instance.def, ty::InstanceKind::ColdPathShim(..));
         (
             instance.def.requires_caller_location(tcx).then(|| tcx.caller_location_ty()),
-            if is_virtual_call { None } else { Some(instance.def_id()) },
+            if is_virtual_call || is_cold_path { None } else { Some(instance.def_id()) },
             is_virtual_call,
         )
     } else {
@@ -17,7 +17,9 @@
 use rustc_middle::ty::{
     self, AdtDef, CoroutineArgsExt, EarlyBinder, PseudoCanonicalInput, Ty, TyCtxt, TypeVisitableExt,
 };
-use rustc_session::{DataTypeKind, FieldInfo, FieldKind, SizeKind, VariantInfo};
+use rustc_session::{
+    DataTypeKind, FieldInfo, FieldKind, NicheInfo, SizeKind, TagEncodingInfo, TagInfo, VariantInfo,
+};
 use rustc_span::{Symbol, sym};
 use tracing::{debug, instrument};
 use {rustc_abi as abi, rustc_hir as hir};
@@ -725,6 +727,13 @@ fn record_layout_for_printing<'tcx>(cx: &LayoutCx<'tcx>, layout: TyAndLayout<'tc
             packed,
             opt_discr_size,
             variants,
+            tag_info(cx, layout),
+            layout.largest_niche.map(|niche| NicheInfo {
+                offset: niche.offset.bytes(),
+                size: niche.value.size(cx).bytes(),
+                valid_range_start: niche.valid_range.start,
+                valid_range_end: niche.valid_range.end,
+            }),
         );
     };
 
@@ -756,6 +765,36 @@ fn record_layout_for_printing<'tcx>(cx: &LayoutCx<'tcx>, layout: TyAndLayout<'tc
     };
 }
 
+fn tag_info<'tcx>(cx: &LayoutCx<'tcx>, layout: TyAndLayout<'tcx>) -> Option<TagInfo> {
+    let Variants::Multiple { tag, ref tag_encoding, tag_field, .. } = layout.variants else {
+        return None;
+    };
+
+    let variant_name = |index: VariantIdx| match *layout.ty.kind() {
+        ty::Adt(adt_def, _) => adt_def.variant(index).name,
+        ty::Coroutine(..) => Symbol::intern(&ty::CoroutineArgs::variant_name(index)),
+        _ => Symbol::intern(&index.as_usize().to_string()),
+    };
+    let encoding = match *tag_encoding {
+        TagEncoding::Direct => TagEncodingInfo::Direct,
+        TagEncoding::Niche { untagged_variant, ref niche_variants, niche_start } => {
+            TagEncodingInfo::Niche {
+                untagged_variant: variant_name(untagged_variant),
+                niche_variants: (niche_variants.start().as_u32()..=niche_variants.end().as_u32())
+                    .map(|index| variant_name(VariantIdx::from_u32(index)))
+                    .collect(),
+                niche_start,
+            }
+        }
+    };
+
+    Some(TagInfo {
+        offset: layout.fields.offset(tag_field.as_usize()).bytes(),
+        size: tag.size(cx).bytes(),
+        encoding,
+    })
+}
+
 fn variant_info_for_adt<'tcx>(
     cx: &LayoutCx<'tcx>,
     layout: TyAndLayout<'tcx>,
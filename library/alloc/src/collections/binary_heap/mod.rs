@@ -144,6 +144,7 @@
 #![stable(feature = "rust1", since = "1.0.0")]
 
 use core::alloc::Allocator;
+use core::cmp::{self, Ordering, Reverse};
 use core::iter::{FusedIterator, InPlaceIterable, SourceIter, TrustedFused, TrustedLen};
 use core::mem::{self, ManuallyDrop, swap};
 use core::num::NonZero;
@@ -537,6 +538,122 @@ pub fn with_capacity(capacity: usize) -> BinaryHeap<T> {
     }
 }
 
+impl<T> BinaryHeap<T> {
+    /// Returns the `k` smallest items of `iter`, in ascending order.
+    ///
+    /// At most `k` items are held at any time, in a bounded `BinaryHeap`, so this takes
+    /// *O*(*n* \* log(*k*)) time and *O*(*k*) memory, where *n* is the number of items produced
+    /// by the iterator. If it produces fewer than `k` items, all of them are returned.
+    ///
+    /// This is preferable to collecting and sorting everything when `k` is much smaller than
+    /// *n*. For a slice that can be reordered in place, see [`slice::partial_sort_unstable`]
+    /// instead.
+    ///
+    /// [`slice::partial_sort_unstable`]: ../../../std/primitive.slice.html#method.partial_sort_unstable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_k_select)]
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let v = [5, 1, 8, 3, 9, 2, 7];
+    /// assert_eq!(BinaryHeap::k_smallest_from_iter(v, 3), [1, 2, 3]);
+    /// assert_eq!(BinaryHeap::k_smallest_from_iter(v, 100).len(), 7);
+    /// ```
+    #[unstable(feature = "binary_heap_k_select", issue = "none")]
+    pub fn k_smallest_from_iter<I: IntoIterator<Item = T>>(iter: I, k: usize) -> Vec<T>
+    where
+        T: Ord,
+    {
+        bounded_select(iter.into_iter(), k)
+    }
+
+    /// Returns the `k` largest items of `iter`, in descending order.
+    ///
+    /// Like [`k_smallest_from_iter`], this takes *O*(*n* \* log(*k*)) time and *O*(*k*) memory.
+    ///
+    /// [`k_smallest_from_iter`]: BinaryHeap::k_smallest_from_iter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_k_select)]
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let v = [5, 1, 8, 3, 9, 2, 7];
+    /// assert_eq!(BinaryHeap::k_largest_from_iter(v, 3), [9, 8, 7]);
+    /// ```
+    #[unstable(feature = "binary_heap_k_select", issue = "none")]
+    pub fn k_largest_from_iter<I: IntoIterator<Item = T>>(iter: I, k: usize) -> Vec<T>
+    where
+        T: Ord,
+    {
+        let reversed = iter.into_iter().map(Reverse);
+        bounded_select(reversed, k).into_iter().map(|Reverse(x)| x).collect()
+    }
+
+    /// Returns the `k` items of `iter` with the smallest keys, in ascending key order.
+    ///
+    /// The key function is called exactly once per item. Items with equal keys are returned in
+    /// an unspecified order.
+    ///
+    /// Like [`k_smallest_from_iter`], this takes *O*(*n* \* log(*k*)) time and *O*(*k*) memory.
+    ///
+    /// [`k_smallest_from_iter`]: BinaryHeap::k_smallest_from_iter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_k_select)]
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let words = ["kiwi", "fig", "banana", "apple", "plum"];
+    /// let shortest = BinaryHeap::k_smallest_by_key_from_iter(words, 2, |w| w.len());
+    /// assert_eq!(shortest, ["fig", "kiwi"]);
+    /// ```
+    #[unstable(feature = "binary_heap_k_select", issue = "none")]
+    pub fn k_smallest_by_key_from_iter<I, K, F>(iter: I, k: usize, mut f: F) -> Vec<T>
+    where
+        I: IntoIterator<Item = T>,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let keyed = iter.into_iter().map(|item| Keyed { key: f(&item), item });
+        bounded_select(keyed, k).into_iter().map(|keyed| keyed.item).collect()
+    }
+
+    /// Returns the `k` items of `iter` with the largest keys, in descending key order.
+    ///
+    /// The key function is called exactly once per item. Items with equal keys are returned in
+    /// an unspecified order.
+    ///
+    /// Like [`k_smallest_from_iter`], this takes *O*(*n* \* log(*k*)) time and *O*(*k*) memory.
+    ///
+    /// [`k_smallest_from_iter`]: BinaryHeap::k_smallest_from_iter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_k_select)]
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let words = ["kiwi", "fig", "banana", "apple", "plum"];
+    /// let longest = BinaryHeap::k_largest_by_key_from_iter(words, 2, |w| w.len());
+    /// assert_eq!(longest, ["banana", "apple"]);
+    /// ```
+    #[unstable(feature = "binary_heap_k_select", issue = "none")]
+    pub fn k_largest_by_key_from_iter<I, K, F>(iter: I, k: usize, mut f: F) -> Vec<T>
+    where
+        I: IntoIterator<Item = T>,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let keyed = iter.into_iter().map(|item| Reverse(Keyed { key: f(&item), item }));
+        bounded_select(keyed, k).into_iter().map(|Reverse(keyed)| keyed.item).collect()
+    }
+}
+
 impl<T: Ord, A: Allocator> BinaryHeap<T, A> {
     /// Creates an empty `BinaryHeap` as a max-heap, using `A` as allocator.
     ///
@@ -1973,3 +2090,49 @@ fn extend_reserve(&mut self, additional: usize) {
         self.reserve(additional);
     }
 }
+
+/// Keeps the `k` smallest items seen in a max-heap whose top is the current
+/// worst candidate, replacing it whenever a smaller item arrives.
+fn bounded_select<T: Ord>(iter: impl Iterator<Item = T>, k: usize) -> Vec<T> {
+    if k == 0 {
+        return Vec::new();
+    }
+    // `k` may be arbitrarily large, so only trust the iterator's lower bound.
+    let mut heap = BinaryHeap::with_capacity(cmp::min(k, iter.size_hint().0));
+    for item in iter {
+        if heap.len() < k {
+            heap.push(item);
+        } else if let Some(mut top) = heap.peek_mut() {
+            if item < *top {
+                *top = item;
+            }
+        }
+    }
+    heap.into_sorted_vec()
+}
+
+/// An item ordered only by its cached key.
+struct Keyed<K, T> {
+    key: K,
+    item: T,
+}
+
+impl<K: Ord, T> PartialEq for Keyed<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<K: Ord, T> Eq for Keyed<K, T> {}
+
+impl<K: Ord, T> PartialOrd for Keyed<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> Ord for Keyed<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
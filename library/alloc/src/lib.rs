@@ -144,6 +144,7 @@
 #![feature(slice_index_methods)]
 #![feature(slice_iter_mut_as_mut_slice)]
 #![feature(slice_ptr_get)]
+#![feature(slice_radix_sort)]
 #![feature(slice_range)]
 #![feature(std_internals)]
 #![feature(str_internals)]
@@ -22,6 +22,8 @@
 pub use core::slice::EscapeAscii;
 #[stable(feature = "get_many_mut", since = "1.86.0")]
 pub use core::slice::GetDisjointMutError;
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+pub use core::slice::RadixKey;
 #[stable(feature = "slice_get_slice", since = "1.28.0")]
 pub use core::slice::SliceIndex;
 #[cfg(not(no_global_oom_handling))]
@@ -353,6 +355,90 @@ macro_rules! sort_by_key {
         sort_by_key!(usize, self, f)
     }
 
+    /// Sorts the slice in ascending order with a radix sort, preserving the initial order of
+    /// equal elements.
+    ///
+    /// Instead of comparing elements, a radix sort distributes them by the bytes of their
+    /// [`RadixKey`] representation, which is often several times faster than a comparison sort
+    /// for large slices of integers or floats. Floats are ordered like [`f64::total_cmp`].
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* *k*), where
+    /// *k* is the size of `T` in bytes. It allocates a buffer of `self.len()` elements; see
+    /// [`radix_sort_unstable`](slice::radix_sort_unstable) for an in-place variant.
+    ///
+    /// # Current implementation
+    ///
+    /// The current implementation is an LSD radix sort that skips passes over bytes which are
+    /// the same for every element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_radix_sort)]
+    ///
+    /// let mut v = [300u64, 7, 1 << 40, 0, 7];
+    /// v.radix_sort();
+    /// assert_eq!(v, [0, 7, 7, 300, 1 << 40]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "slice_radix_sort", issue = "none")]
+    #[inline]
+    pub fn radix_sort(&mut self)
+    where
+        T: RadixKey,
+    {
+        let mut scratch = self.to_vec();
+        sort::radix::sort_stable_with_scratch(self, &mut scratch, &mut |x: &T| *x);
+    }
+
+    /// Sorts the slice in ascending order with a radix sort on a key extraction function,
+    /// preserving the initial order of equal elements.
+    ///
+    /// Like [`sort_by_cached_key`](slice::sort_by_cached_key), this calls the key function only
+    /// once per element, so it also suits expensive keys.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* *k*), where
+    /// *k* is the size of `K` in bytes. It allocates buffers for the keys and their positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_radix_sort)]
+    ///
+    /// let mut v = ["ccc", "a", "bb", "d"];
+    /// v.sort_by_radix_key(|s| s.len());
+    /// assert_eq!(v, ["a", "d", "bb", "ccc"]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "slice_radix_sort", issue = "none")]
+    #[inline]
+    pub fn sort_by_radix_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: RadixKey,
+    {
+        let len = self.len();
+        if len < 2 {
+            return;
+        }
+
+        let mut indices: Vec<(K, usize)> =
+            self.iter().map(f).enumerate().map(|(i, k)| (k, i)).collect();
+        let mut scratch = indices.clone();
+        // A stable sort keeps the indices of equal keys in their original order.
+        sort::radix::sort_stable_with_scratch(&mut indices, &mut scratch, &mut |&(k, _)| k);
+        for i in 0..len {
+            let mut index = indices[i].1;
+            while index < i {
+                index = indices[index].1;
+            }
+            indices[i].1 = index;
+            self.swap(i, index);
+        }
+    }
+
     /// Copies `self` into a new `Vec`.
     ///
     /// # Examples
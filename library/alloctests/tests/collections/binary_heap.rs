@@ -81,6 +81,43 @@ fn test_drain_sorted_collect() {
     assert_eq!(sorted, vec![10, 9, 8, 7, 6, 5, 4, 3, 2, 2, 1, 1, 0]);
 }
 
+#[test]
+fn test_k_smallest_and_largest() {
+    let data = [5, 1, 8, 3, 9, 2, 7, 3];
+    assert!(BinaryHeap::k_smallest_from_iter(data, 0).is_empty());
+    assert_eq!(BinaryHeap::k_smallest_from_iter(data, 3), [1, 2, 3]);
+    assert_eq!(BinaryHeap::k_smallest_from_iter(data, 4), [1, 2, 3, 3]);
+    assert_eq!(BinaryHeap::k_largest_from_iter(data, 3), [9, 8, 7]);
+    assert!(BinaryHeap::k_largest_from_iter(data, 0).is_empty());
+
+    let mut sorted = data.to_vec();
+    sorted.sort();
+    assert_eq!(BinaryHeap::k_smallest_from_iter(data, usize::MAX), sorted);
+    sorted.reverse();
+    assert_eq!(
+        BinaryHeap::k_largest_from_iter(data.iter(), 100),
+        sorted.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_k_smallest_by_key() {
+    let data = [(3, 'a'), (-1, 'b'), (4, 'c'), (-1, 'd'), (-5, 'e'), (9, 'f')];
+    let mut calls = 0;
+    let smallest = BinaryHeap::k_smallest_by_key_from_iter(data, 2, |&(x, _)| {
+        calls += 1;
+        x.abs()
+    });
+    assert_eq!(calls, data.len());
+    let mut chars: Vec<_> = smallest.iter().map(|&(_, c)| c).collect();
+    chars.sort();
+    assert_eq!(chars, ['b', 'd']);
+
+    let largest = BinaryHeap::k_largest_by_key_from_iter(data, 3, |&(x, _)| x.abs());
+    assert_eq!(largest, [(9, 'f'), (-5, 'e'), (4, 'c')]);
+    assert!(BinaryHeap::k_largest_by_key_from_iter(data, 0, |&(x, _)| x).is_empty());
+}
+
 fn check_exact_size_iterator<I: ExactSizeIterator>(len: usize, it: I) {
     let mut it = it;
 
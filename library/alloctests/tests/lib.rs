@@ -19,12 +19,14 @@
 #![feature(unboxed_closures)]
 #![feature(binary_heap_into_iter_sorted)]
 #![feature(binary_heap_drain_sorted)]
+#![feature(binary_heap_k_select)]
 #![feature(slice_ptr_get)]
 #![feature(inplace_iteration)]
 #![feature(iter_advance_by)]
 #![feature(iter_next_chunk)]
 #![feature(round_char_boundary)]
 #![feature(slice_partition_dedup)]
+#![feature(slice_radix_sort)]
 #![feature(string_from_utf8_lossy_owned)]
 #![feature(string_remove_matches)]
 #![feature(const_btree_len)]
@@ -36,6 +38,9 @@
 #![feature(drain_keep_rest)]
 #![feature(local_waker)]
 #![feature(str_as_str)]
+#![feature(str_case_fold)]
+#![feature(str_normalize)]
+#![feature(str_segmentation)]
 #![feature(strict_provenance_lints)]
 #![feature(vec_deque_pop_if)]
 #![feature(vec_deque_truncate_front)]
@@ -1679,3 +1679,80 @@ fn test_chunk_by_mut() {
     assert_eq!(iter.next(), Some(&mut [2, 2, 2][..]));
     assert_eq!(iter.next_back(), None);
 }
+
+#[test]
+fn test_radix_sort() {
+    // A small LCG is enough to get keys that differ in every byte.
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next = move || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        state
+    };
+
+    for len in [0, 1, 2, 31, 32, 33, 100, 1000, 5000] {
+        let v: Vec<u64> = (0..len).map(|_| next()).collect();
+        let mut expected = v.clone();
+        expected.sort();
+        let mut a = v.clone();
+        a.radix_sort();
+        assert_eq!(a, expected);
+        let mut b = v.clone();
+        b.radix_sort_unstable();
+        assert_eq!(b, expected);
+
+        // Few distinct values exercise the skipped passes and the small buckets.
+        let v: Vec<i16> = v.iter().map(|&x| (x % 7) as i16 - 3).collect();
+        let mut expected = v.clone();
+        expected.sort();
+        let mut a = v.clone();
+        a.radix_sort();
+        assert_eq!(a, expected);
+        let mut b = v.clone();
+        b.radix_sort_unstable();
+        assert_eq!(b, expected);
+
+        let v: Vec<f64> = (0..len)
+            .map(|i| match i % 5 {
+                0 => f64::from_bits(next()),
+                1 => -0.0,
+                2 => 0.0,
+                3 => -(next() as f64),
+                _ => next() as f64 / 3.0,
+            })
+            .collect();
+        let bits = |v: &[f64]| v.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        let mut expected = v.clone();
+        expected.sort_by(f64::total_cmp);
+        let mut a = v.clone();
+        a.radix_sort();
+        assert_eq!(bits(&a), bits(&expected));
+        let mut b = v.clone();
+        b.radix_sort_unstable();
+        assert_eq!(bits(&b), bits(&expected));
+    }
+
+    let mut v = ['é', 'a', '\u{10FFFF}', 'Z', '\0'];
+    v.radix_sort_unstable();
+    assert_eq!(v, ['\0', 'Z', 'a', 'é', '\u{10FFFF}']);
+    let mut v = [i128::MAX, -1, i128::MIN, 0];
+    v.radix_sort();
+    assert_eq!(v, [i128::MIN, -1, 0, i128::MAX]);
+}
+
+#[test]
+fn test_sort_by_radix_key_is_stable() {
+    let mut v: Vec<(u8, usize)> = (0..2000).map(|i| ((i * 7 % 13) as u8, i)).collect();
+    let mut expected = v.clone();
+    expected.sort_by_key(|&(k, _)| k);
+    let mut calls = 0;
+    v.sort_by_radix_key(|&(k, _)| {
+        calls += 1;
+        k
+    });
+    assert_eq!(v, expected);
+    assert_eq!(calls, 2000);
+
+    let mut v: Vec<(u32, usize)> = (0..100).map(|i| (100 - i as u32, i)).collect();
+    v.sort_unstable_by_radix_key(|&(k, _)| k);
+    assert!(v.is_sorted_by_key(|&(k, _)| k));
+}
@@ -6,6 +6,10 @@
 use std::cmp::Ordering::{Equal, Greater, Less};
 use std::str::{from_utf8, from_utf8_unchecked};
 
+mod case_folding_test_data;
+mod normalization_test_data;
+mod segmentation_test_data;
+
 #[test]
 fn test_le() {
     assert!("" <= "");
@@ -1877,6 +1881,212 @@ fn to_uppercase() {
     assert_eq!("aéǅßﬁᾀ".to_uppercase(), "AÉǄSSFIἈΙ");
 }
 
+#[test]
+fn nfd_nfkd() {
+    assert_eq!("".nfd().to_string(), "");
+    assert_eq!("\u{c5}str\u{f6}m".nfd().to_string(), "A\u{30a}stro\u{308}m");
+    // Marks are stably sorted by combining class.
+    assert_eq!("a\u{301}\u{323}".nfd().to_string(), "a\u{323}\u{301}");
+    assert_eq!("\u{1f8f}".nfd().to_string(), "\u{391}\u{314}\u{342}\u{345}");
+    assert_eq!(
+        "\u{d55c}\u{ae00}".nfd().to_string(),
+        "\u{1112}\u{1161}\u{11ab}\u{1100}\u{1173}\u{11af}"
+    );
+    assert_eq!("\u{fb01}".nfd().to_string(), "\u{fb01}");
+    assert_eq!("\u{fb01}".nfkd().to_string(), "fi");
+
+    // Long runs of marks are reordered without an allocation.
+    let marks = "a".to_string() + &"\u{301}\u{323}".repeat(40);
+    let expected = "a".to_string() + &"\u{323}".repeat(40) + &"\u{301}".repeat(40);
+    assert_eq!(marks.nfd().to_string(), expected);
+}
+
+#[test]
+fn nfc_nfkc() {
+    assert_eq!("".nfc().to_string(), "");
+    assert_eq!("A\u{30a}stro\u{308}m".nfc().to_string(), "\u{c5}str\u{f6}m");
+    assert_eq!("a\u{301}\u{323}".nfc().to_string(), "\u{1ea1}\u{301}");
+    assert_eq!("\u{391}\u{314}\u{342}\u{345}".nfc().to_string(), "\u{1f8f}");
+    assert_eq!(
+        "\u{1112}\u{1161}\u{11ab}\u{1100}\u{1173}\u{11af}".nfc().to_string(),
+        "\u{d55c}\u{ae00}"
+    );
+    // Singletons and composition exclusions are not recomposed.
+    assert_eq!("\u{2126}\u{212b}".nfc().to_string(), "\u{3a9}\u{c5}");
+    assert_eq!("\u{958}".nfc().to_string(), "\u{915}\u{93c}");
+    // A leading mark has nothing to compose with.
+    assert_eq!("\u{301}a\u{301}".nfc().to_string(), "\u{301}\u{e1}");
+    // Two starters compose only when adjacent.
+    assert_eq!("\u{b47}\u{b3e}".nfc().to_string(), "\u{b4b}");
+    assert_eq!("\u{b47}\u{301}\u{b3e}".nfc().to_string(), "\u{b47}\u{301}\u{b3e}");
+    assert_eq!("\u{2460}\u{ff8a}\u{ff9f}".nfc().to_string(), "\u{2460}\u{ff8a}\u{ff9f}");
+    assert_eq!("\u{2460}\u{ff8a}\u{ff9f}".nfkc().to_string(), "1\u{30d1}");
+
+    let s = "Z\u{fc}rich, \u{1e9b}\u{323}, \u{d55c}\u{ae00}";
+    assert_eq!(s.nfc().collect::<String>().nfd().to_string(), s.nfd().to_string());
+}
+
+#[test]
+fn normalize_long_mark_run() {
+    // Runs longer than the reordering buffer must still come out sorted, in linear time.
+    let n = 10_000;
+    let s = "\u{1ea1}".to_string() + &"\u{334}\u{301}\u{323}".repeat(n) + "b";
+    let sorted = "\u{334}".repeat(n) + &"\u{323}".repeat(n);
+    assert_eq!(
+        s.nfd().to_string(),
+        "a".to_string() + &sorted + "\u{323}" + &"\u{301}".repeat(n) + "b"
+    );
+    assert_eq!(s.nfc().to_string(), "\u{1ea1}".to_string() + &sorted + &"\u{301}".repeat(n) + "b");
+
+    // A run without a starter.
+    let s = "\u{301}\u{334}".repeat(n) + "a";
+    let expected = "\u{334}".repeat(n) + &"\u{301}".repeat(n) + "a";
+    assert_eq!(s.nfd().to_string(), expected);
+    assert_eq!(s.nfc().to_string(), expected);
+}
+
+#[test]
+fn normalization_test() {
+    // The invariants that NormalizationTest.txt states for each case.
+    fn check([source, nfc, nfd, nfkc, nfkd]: [&str; 5]) {
+        for s in [source, nfc, nfd] {
+            assert_eq!(s.nfc().to_string(), nfc, "NFC of {s:?}");
+            assert_eq!(s.nfd().to_string(), nfd, "NFD of {s:?}");
+        }
+        for s in [nfkc, nfkd] {
+            assert_eq!(s.nfc().to_string(), nfkc, "NFC of {s:?}");
+            assert_eq!(s.nfd().to_string(), nfkd, "NFD of {s:?}");
+        }
+        for s in [source, nfc, nfd, nfkc, nfkd] {
+            assert_eq!(s.nfkc().to_string(), nfkc, "NFKC of {s:?}");
+            assert_eq!(s.nfkd().to_string(), nfkd, "NFKD of {s:?}");
+        }
+    }
+
+    for &case in normalization_test_data::SEQUENCES {
+        check(case);
+    }
+    let mut listed = normalization_test_data::CHARACTERS.iter().peekable();
+    for c in char::MIN..=char::MAX {
+        let s = c.to_string();
+        match listed.next_if(|[source, ..]| *source == s) {
+            Some(&case) => check(case),
+            None => check([s.as_str(); 5]),
+        }
+    }
+    assert_eq!(listed.next(), None);
+}
+
+#[test]
+fn case_fold() {
+    assert_eq!("".case_fold().to_string(), "");
+    assert_eq!("Hello".case_fold().to_string(), "hello");
+    assert_eq!("\u{df}\u{1e9e}\u{fb03}".case_fold().to_string(), "ssssffi");
+    assert_eq!("\u{130}".case_fold().to_string(), "i\u{307}");
+    assert_eq!(
+        "\u{3a3}\u{391}\u{3a3} \u{3c2}".case_fold().to_string(),
+        "\u{3c3}\u{3b1}\u{3c3} \u{3c3}"
+    );
+    assert_eq!("\u{1c5}".case_fold().to_string(), "\u{1c6}");
+    assert!("STRASSE".case_fold().eq("stra\u{df}e".case_fold()));
+    assert!(!"strasse".case_fold().eq("strase".case_fold()));
+}
+
+#[test]
+fn case_folding_test() {
+    let mut listed = case_folding_test_data::CASE_FOLDING.iter().peekable();
+    for c in char::MIN..=char::MAX {
+        let s = c.to_string();
+        let folded = listed.next_if(|&&(from, _)| from == c).map_or(&*s, |&(_, to)| to);
+        assert_eq!(s.case_fold().to_string(), folded, "case folding of {c:?}");
+    }
+    assert_eq!(listed.next(), None);
+}
+
+#[test]
+fn graphemes() {
+    fn graphemes(s: &str) -> Vec<&str> {
+        s.graphemes().collect()
+    }
+
+    assert!(graphemes("").is_empty());
+    assert_eq!(graphemes("abc"), ["a", "b", "c"]);
+    assert_eq!(graphemes("a\r\n\n\r"), ["a", "\r\n", "\n", "\r"]);
+    assert_eq!(graphemes("e\u{301}\u{328}x"), ["e\u{301}\u{328}", "x"]);
+    // Conjoining jamo and precomposed syllables.
+    assert_eq!(
+        graphemes("\u{1100}\u{1161}\u{11a8}\u{ac00}\u{11a8}"),
+        ["\u{1100}\u{1161}\u{11a8}", "\u{ac00}\u{11a8}"]
+    );
+    // Regional indicators pair up into flags.
+    assert_eq!(graphemes("\u{1f1eb}\u{1f1f7}\u{1f1e9}"), ["\u{1f1eb}\u{1f1f7}", "\u{1f1e9}"]);
+    // Emoji ZWJ sequences and modifiers.
+    assert_eq!(
+        graphemes("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{1f44d}\u{1f3fb}"),
+        ["\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}", "\u{1f44d}\u{1f3fb}"]
+    );
+    assert_eq!(graphemes("a\u{200d}\u{1f469}"), ["a\u{200d}", "\u{1f469}"]);
+    // Prepended concatenation marks and spacing marks.
+    assert_eq!(graphemes("\u{600}1a\u{903}"), ["\u{600}1", "a\u{903}"]);
+    // Indic conjuncts: consonant, virama, consonant.
+    assert_eq!(
+        graphemes("\u{915}\u{94d}\u{937}\u{93f}\u{915}"),
+        ["\u{915}\u{94d}\u{937}\u{93f}", "\u{915}"]
+    );
+
+    let mut iter = "a\u{301}b".graphemes();
+    assert_eq!(iter.as_str(), "a\u{301}b");
+    assert_eq!(iter.next(), Some("a\u{301}"));
+    assert_eq!(iter.as_str(), "b");
+    assert_eq!(iter.next(), Some("b"));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn unicode_words() {
+    fn words(s: &str) -> Vec<&str> {
+        s.unicode_words().collect()
+    }
+
+    assert!(words("").is_empty());
+    assert!(words("  ...,  \u{1f600}").is_empty());
+    assert_eq!(
+        words("The quick (\"brown\") fox can't jump 32.3 feet, right?"),
+        ["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"]
+    );
+    assert_eq!(
+        words("1,000.5 a_b x2 \u{5d0}\"\u{5d1}"),
+        ["1,000.5", "a_b", "x2", "\u{5d0}\"\u{5d1}"]
+    );
+    // Extending and format characters don't break words.
+    assert_eq!(words("cafe\u{301}\u{ad}s"), ["cafe\u{301}\u{ad}s"]);
+    // Katakana runs form words, but every ideograph is a word of its own.
+    assert_eq!(
+        words("\u{30a2}\u{30fc}\u{30c8} \u{4e2d}\u{6587}"),
+        ["\u{30a2}\u{30fc}\u{30c8}", "\u{4e2d}", "\u{6587}"]
+    );
+    assert_eq!(words("a.b. c:d e.\r\nf"), ["a.b", "c:d", "e", "f"]);
+}
+
+#[test]
+fn graphemes_break_test() {
+    for &expected in segmentation_test_data::GRAPHEME_BREAK_TEST {
+        let s = expected.concat();
+        assert_eq!(s.graphemes().collect::<Vec<_>>(), expected, "{s:?}");
+    }
+}
+
+#[test]
+fn unicode_words_break_test() {
+    for &segments in segmentation_test_data::WORD_BREAK_TEST {
+        let s = segments.concat();
+        let expected: Vec<_> =
+            segments.iter().copied().filter(|w| w.chars().any(char::is_alphanumeric)).collect();
+        assert_eq!(s.unicode_words().collect::<Vec<_>>(), expected, "{s:?}");
+    }
+}
+
 #[test]
 fn test_into_string() {
     // The only way to acquire a Box<str> in the first place is through a String, so just
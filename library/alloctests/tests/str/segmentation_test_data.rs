@@ -0,0 +1,2936 @@
+//! The test cases of the Unicode 16.0.0 [GraphemeBreakTest.txt] and [WordBreakTest.txt]. Each
+//! case is the list of segments that the string made up by joining them splits into.
+//!
+//! [GraphemeBreakTest.txt]: https://www.unicode.org/Public/16.0.0/ucd/auxiliary/GraphemeBreakTest.txt
+//! [WordBreakTest.txt]: https://www.unicode.org/Public/16.0.0/ucd/auxiliary/WordBreakTest.txt
+
+/// Extended grapheme clusters.
+pub(super) static GRAPHEME_BREAK_TEST: &[&[&str]] = &[
+    &[" ", " "],
+    &[" \u{308}", " "],
+    &[" ", "\u{d}"],
+    &[" \u{308}", "\u{d}"],
+    &[" ", "\u{a}"],
+    &[" \u{308}", "\u{a}"],
+    &[" ", "\u{1}"],
+    &[" \u{308}", "\u{1}"],
+    &[" \u{200c}"],
+    &[" \u{308}\u{200c}"],
+    &[" ", "\u{1f1e6}"],
+    &[" \u{308}", "\u{1f1e6}"],
+    &[" ", "\u{600}"],
+    &[" \u{308}", "\u{600}"],
+    &[" ", "\u{1100}"],
+    &[" \u{308}", "\u{1100}"],
+    &[" ", "\u{1160}"],
+    &[" \u{308}", "\u{1160}"],
+    &[" ", "\u{11a8}"],
+    &[" \u{308}", "\u{11a8}"],
+    &[" ", "\u{ac00}"],
+    &[" \u{308}", "\u{ac00}"],
+    &[" ", "\u{ac01}"],
+    &[" \u{308}", "\u{ac01}"],
+    &[" ", "\u{904}"],
+    &[" \u{308}", "\u{904}"],
+    &[" ", "\u{d4e}"],
+    &[" \u{308}", "\u{d4e}"],
+    &[" ", "\u{915}"],
+    &[" \u{308}", "\u{915}"],
+    &[" ", "\u{231a}"],
+    &[" \u{308}", "\u{231a}"],
+    &[" \u{300}"],
+    &[" \u{308}\u{300}"],
+    &[" \u{900}"],
+    &[" \u{308}\u{900}"],
+    &[" \u{94d}"],
+    &[" \u{308}\u{94d}"],
+    &[" \u{200d}"],
+    &[" \u{308}\u{200d}"],
+    &[" ", "\u{378}"],
+    &[" \u{308}", "\u{378}"],
+    &["\u{d}", " "],
+    &["\u{d}", "\u{308}", " "],
+    &["\u{d}", "\u{d}"],
+    &["\u{d}", "\u{308}", "\u{d}"],
+    &["\u{d}\u{a}"],
+    &["\u{d}", "\u{308}", "\u{a}"],
+    &["\u{d}", "\u{1}"],
+    &["\u{d}", "\u{308}", "\u{1}"],
+    &["\u{d}", "\u{200c}"],
+    &["\u{d}", "\u{308}\u{200c}"],
+    &["\u{d}", "\u{1f1e6}"],
+    &["\u{d}", "\u{308}", "\u{1f1e6}"],
+    &["\u{d}", "\u{600}"],
+    &["\u{d}", "\u{308}", "\u{600}"],
+    &["\u{d}", "\u{a03}"],
+    &["\u{d}", "\u{1100}"],
+    &["\u{d}", "\u{308}", "\u{1100}"],
+    &["\u{d}", "\u{1160}"],
+    &["\u{d}", "\u{308}", "\u{1160}"],
+    &["\u{d}", "\u{11a8}"],
+    &["\u{d}", "\u{308}", "\u{11a8}"],
+    &["\u{d}", "\u{ac00}"],
+    &["\u{d}", "\u{308}", "\u{ac00}"],
+    &["\u{d}", "\u{ac01}"],
+    &["\u{d}", "\u{308}", "\u{ac01}"],
+    &["\u{d}", "\u{903}"],
+    &["\u{d}", "\u{904}"],
+    &["\u{d}", "\u{308}", "\u{904}"],
+    &["\u{d}", "\u{d4e}"],
+    &["\u{d}", "\u{308}", "\u{d4e}"],
+    &["\u{d}", "\u{915}"],
+    &["\u{d}", "\u{308}", "\u{915}"],
+    &["\u{d}", "\u{231a}"],
+    &["\u{d}", "\u{308}", "\u{231a}"],
+    &["\u{d}", "\u{300}"],
+    &["\u{d}", "\u{308}\u{300}"],
+    &["\u{d}", "\u{900}"],
+    &["\u{d}", "\u{308}\u{900}"],
+    &["\u{d}", "\u{94d}"],
+    &["\u{d}", "\u{308}\u{94d}"],
+    &["\u{d}", "\u{200d}"],
+    &["\u{d}", "\u{308}\u{200d}"],
+    &["\u{d}", "\u{378}"],
+    &["\u{d}", "\u{308}", "\u{378}"],
+    &["\u{a}", " "],
+    &["\u{a}", "\u{308}", " "],
+    &["\u{a}", "\u{d}"],
+    &["\u{a}", "\u{308}", "\u{d}"],
+    &["\u{a}", "\u{a}"],
+    &["\u{a}", "\u{308}", "\u{a}"],
+    &["\u{a}", "\u{1}"],
+    &["\u{a}", "\u{308}", "\u{1}"],
+    &["\u{a}", "\u{200c}"],
+    &["\u{a}", "\u{308}\u{200c}"],
+    &["\u{a}", "\u{1f1e6}"],
+    &["\u{a}", "\u{308}", "\u{1f1e6}"],
+    &["\u{a}", "\u{600}"],
+    &["\u{a}", "\u{308}", "\u{600}"],
+    &["\u{a}", "\u{a03}"],
+    &["\u{a}", "\u{1100}"],
+    &["\u{a}", "\u{308}", "\u{1100}"],
+    &["\u{a}", "\u{1160}"],
+    &["\u{a}", "\u{308}", "\u{1160}"],
+    &["\u{a}", "\u{11a8}"],
+    &["\u{a}", "\u{308}", "\u{11a8}"],
+    &["\u{a}", "\u{ac00}"],
+    &["\u{a}", "\u{308}", "\u{ac00}"],
+    &["\u{a}", "\u{ac01}"],
+    &["\u{a}", "\u{308}", "\u{ac01}"],
+    &["\u{a}", "\u{903}"],
+    &["\u{a}", "\u{904}"],
+    &["\u{a}", "\u{308}", "\u{904}"],
+    &["\u{a}", "\u{d4e}"],
+    &["\u{a}", "\u{308}", "\u{d4e}"],
+    &["\u{a}", "\u{915}"],
+    &["\u{a}", "\u{308}", "\u{915}"],
+    &["\u{a}", "\u{231a}"],
+    &["\u{a}", "\u{308}", "\u{231a}"],
+    &["\u{a}", "\u{300}"],
+    &["\u{a}", "\u{308}\u{300}"],
+    &["\u{a}", "\u{900}"],
+    &["\u{a}", "\u{308}\u{900}"],
+    &["\u{a}", "\u{94d}"],
+    &["\u{a}", "\u{308}\u{94d}"],
+    &["\u{a}", "\u{200d}"],
+    &["\u{a}", "\u{308}\u{200d}"],
+    &["\u{a}", "\u{378}"],
+    &["\u{a}", "\u{308}", "\u{378}"],
+    &["\u{1}", " "],
+    &["\u{1}", "\u{308}", " "],
+    &["\u{1}", "\u{d}"],
+    &["\u{1}", "\u{308}", "\u{d}"],
+    &["\u{1}", "\u{a}"],
+    &["\u{1}", "\u{308}", "\u{a}"],
+    &["\u{1}", "\u{1}"],
+    &["\u{1}", "\u{308}", "\u{1}"],
+    &["\u{1}", "\u{200c}"],
+    &["\u{1}", "\u{308}\u{200c}"],
+    &["\u{1}", "\u{1f1e6}"],
+    &["\u{1}", "\u{308}", "\u{1f1e6}"],
+    &["\u{1}", "\u{600}"],
+    &["\u{1}", "\u{308}", "\u{600}"],
+    &["\u{1}", "\u{a03}"],
+    &["\u{1}", "\u{1100}"],
+    &["\u{1}", "\u{308}", "\u{1100}"],
+    &["\u{1}", "\u{1160}"],
+    &["\u{1}", "\u{308}", "\u{1160}"],
+    &["\u{1}", "\u{11a8}"],
+    &["\u{1}", "\u{308}", "\u{11a8}"],
+    &["\u{1}", "\u{ac00}"],
+    &["\u{1}", "\u{308}", "\u{ac00}"],
+    &["\u{1}", "\u{ac01}"],
+    &["\u{1}", "\u{308}", "\u{ac01}"],
+    &["\u{1}", "\u{903}"],
+    &["\u{1}", "\u{904}"],
+    &["\u{1}", "\u{308}", "\u{904}"],
+    &["\u{1}", "\u{d4e}"],
+    &["\u{1}", "\u{308}", "\u{d4e}"],
+    &["\u{1}", "\u{915}"],
+    &["\u{1}", "\u{308}", "\u{915}"],
+    &["\u{1}", "\u{231a}"],
+    &["\u{1}", "\u{308}", "\u{231a}"],
+    &["\u{1}", "\u{300}"],
+    &["\u{1}", "\u{308}\u{300}"],
+    &["\u{1}", "\u{900}"],
+    &["\u{1}", "\u{308}\u{900}"],
+    &["\u{1}", "\u{94d}"],
+    &["\u{1}", "\u{308}\u{94d}"],
+    &["\u{1}", "\u{200d}"],
+    &["\u{1}", "\u{308}\u{200d}"],
+    &["\u{1}", "\u{378}"],
+    &["\u{1}", "\u{308}", "\u{378}"],
+    &["\u{200c}", " "],
+    &["\u{200c}\u{308}", " "],
+    &["\u{200c}", "\u{d}"],
+    &["\u{200c}\u{308}", "\u{d}"],
+    &["\u{200c}", "\u{a}"],
+    &["\u{200c}\u{308}", "\u{a}"],
+    &["\u{200c}", "\u{1}"],
+    &["\u{200c}\u{308}", "\u{1}"],
+    &["\u{200c}\u{200c}"],
+    &["\u{200c}\u{308}\u{200c}"],
+    &["\u{200c}", "\u{1f1e6}"],
+    &["\u{200c}\u{308}", "\u{1f1e6}"],
+    &["\u{200c}", "\u{600}"],
+    &["\u{200c}\u{308}", "\u{600}"],
+    &["\u{200c}", "\u{1100}"],
+    &["\u{200c}\u{308}", "\u{1100}"],
+    &["\u{200c}", "\u{1160}"],
+    &["\u{200c}\u{308}", "\u{1160}"],
+    &["\u{200c}", "\u{11a8}"],
+    &["\u{200c}\u{308}", "\u{11a8}"],
+    &["\u{200c}", "\u{ac00}"],
+    &["\u{200c}\u{308}", "\u{ac00}"],
+    &["\u{200c}", "\u{ac01}"],
+    &["\u{200c}\u{308}", "\u{ac01}"],
+    &["\u{200c}", "\u{904}"],
+    &["\u{200c}\u{308}", "\u{904}"],
+    &["\u{200c}", "\u{d4e}"],
+    &["\u{200c}\u{308}", "\u{d4e}"],
+    &["\u{200c}", "\u{915}"],
+    &["\u{200c}\u{308}", "\u{915}"],
+    &["\u{200c}", "\u{231a}"],
+    &["\u{200c}\u{308}", "\u{231a}"],
+    &["\u{200c}\u{300}"],
+    &["\u{200c}\u{308}\u{300}"],
+    &["\u{200c}\u{900}"],
+    &["\u{200c}\u{308}\u{900}"],
+    &["\u{200c}\u{94d}"],
+    &["\u{200c}\u{308}\u{94d}"],
+    &["\u{200c}\u{200d}"],
+    &["\u{200c}\u{308}\u{200d}"],
+    &["\u{200c}", "\u{378}"],
+    &["\u{200c}\u{308}", "\u{378}"],
+    &["\u{1f1e6}", " "],
+    &["\u{1f1e6}\u{308}", " "],
+    &["\u{1f1e6}", "\u{d}"],
+    &["\u{1f1e6}\u{308}", "\u{d}"],
+    &["\u{1f1e6}", "\u{a}"],
+    &["\u{1f1e6}\u{308}", "\u{a}"],
+    &["\u{1f1e6}", "\u{1}"],
+    &["\u{1f1e6}\u{308}", "\u{1}"],
+    &["\u{1f1e6}\u{200c}"],
+    &["\u{1f1e6}\u{308}\u{200c}"],
+    &["\u{1f1e6}\u{1f1e6}"],
+    &["\u{1f1e6}\u{308}", "\u{1f1e6}"],
+    &["\u{1f1e6}", "\u{600}"],
+    &["\u{1f1e6}\u{308}", "\u{600}"],
+    &["\u{1f1e6}", "\u{1100}"],
+    &["\u{1f1e6}\u{308}", "\u{1100}"],
+    &["\u{1f1e6}", "\u{1160}"],
+    &["\u{1f1e6}\u{308}", "\u{1160}"],
+    &["\u{1f1e6}", "\u{11a8}"],
+    &["\u{1f1e6}\u{308}", "\u{11a8}"],
+    &["\u{1f1e6}", "\u{ac00}"],
+    &["\u{1f1e6}\u{308}", "\u{ac00}"],
+    &["\u{1f1e6}", "\u{ac01}"],
+    &["\u{1f1e6}\u{308}", "\u{ac01}"],
+    &["\u{1f1e6}", "\u{904}"],
+    &["\u{1f1e6}\u{308}", "\u{904}"],
+    &["\u{1f1e6}", "\u{d4e}"],
+    &["\u{1f1e6}\u{308}", "\u{d4e}"],
+    &["\u{1f1e6}", "\u{915}"],
+    &["\u{1f1e6}\u{308}", "\u{915}"],
+    &["\u{1f1e6}", "\u{231a}"],
+    &["\u{1f1e6}\u{308}", "\u{231a}"],
+    &["\u{1f1e6}\u{300}"],
+    &["\u{1f1e6}\u{308}\u{300}"],
+    &["\u{1f1e6}\u{900}"],
+    &["\u{1f1e6}\u{308}\u{900}"],
+    &["\u{1f1e6}\u{94d}"],
+    &["\u{1f1e6}\u{308}\u{94d}"],
+    &["\u{1f1e6}\u{200d}"],
+    &["\u{1f1e6}\u{308}\u{200d}"],
+    &["\u{1f1e6}", "\u{378}"],
+    &["\u{1f1e6}\u{308}", "\u{378}"],
+    &["\u{600}\u{308}", " "],
+    &["\u{600}", "\u{d}"],
+    &["\u{600}\u{308}", "\u{d}"],
+    &["\u{600}", "\u{a}"],
+    &["\u{600}\u{308}", "\u{a}"],
+    &["\u{600}", "\u{1}"],
+    &["\u{600}\u{308}", "\u{1}"],
+    &["\u{600}\u{200c}"],
+    &["\u{600}\u{308}\u{200c}"],
+    &["\u{600}\u{308}", "\u{1f1e6}"],
+    &["\u{600}\u{308}", "\u{600}"],
+    &["\u{600}\u{308}", "\u{1100}"],
+    &["\u{600}\u{308}", "\u{1160}"],
+    &["\u{600}\u{308}", "\u{11a8}"],
+    &["\u{600}\u{308}", "\u{ac00}"],
+    &["\u{600}\u{308}", "\u{ac01}"],
+    &["\u{600}\u{308}", "\u{904}"],
+    &["\u{600}\u{308}", "\u{d4e}"],
+    &["\u{600}\u{308}", "\u{915}"],
+    &["\u{600}\u{308}", "\u{231a}"],
+    &["\u{600}\u{300}"],
+    &["\u{600}\u{308}\u{300}"],
+    &["\u{600}\u{900}"],
+    &["\u{600}\u{308}\u{900}"],
+    &["\u{600}\u{94d}"],
+    &["\u{600}\u{308}\u{94d}"],
+    &["\u{600}\u{200d}"],
+    &["\u{600}\u{308}\u{200d}"],
+    &["\u{600}\u{308}", "\u{378}"],
+    &["\u{a03}", " "],
+    &["\u{a03}\u{308}", " "],
+    &["\u{a03}", "\u{d}"],
+    &["\u{a03}\u{308}", "\u{d}"],
+    &["\u{a03}", "\u{a}"],
+    &["\u{a03}\u{308}", "\u{a}"],
+    &["\u{a03}", "\u{1}"],
+    &["\u{a03}\u{308}", "\u{1}"],
+    &["\u{a03}\u{200c}"],
+    &["\u{a03}\u{308}\u{200c}"],
+    &["\u{a03}", "\u{1f1e6}"],
+    &["\u{a03}\u{308}", "\u{1f1e6}"],
+    &["\u{a03}", "\u{600}"],
+    &["\u{a03}\u{308}", "\u{600}"],
+    &["\u{a03}", "\u{1100}"],
+    &["\u{a03}\u{308}", "\u{1100}"],
+    &["\u{a03}", "\u{1160}"],
+    &["\u{a03}\u{308}", "\u{1160}"],
+    &["\u{a03}", "\u{11a8}"],
+    &["\u{a03}\u{308}", "\u{11a8}"],
+    &["\u{a03}", "\u{ac00}"],
+    &["\u{a03}\u{308}", "\u{ac00}"],
+    &["\u{a03}", "\u{ac01}"],
+    &["\u{a03}\u{308}", "\u{ac01}"],
+    &["\u{a03}", "\u{904}"],
+    &["\u{a03}\u{308}", "\u{904}"],
+    &["\u{a03}", "\u{d4e}"],
+    &["\u{a03}\u{308}", "\u{d4e}"],
+    &["\u{a03}", "\u{915}"],
+    &["\u{a03}\u{308}", "\u{915}"],
+    &["\u{a03}", "\u{231a}"],
+    &["\u{a03}\u{308}", "\u{231a}"],
+    &["\u{a03}\u{300}"],
+    &["\u{a03}\u{308}\u{300}"],
+    &["\u{a03}\u{900}"],
+    &["\u{a03}\u{308}\u{900}"],
+    &["\u{a03}\u{94d}"],
+    &["\u{a03}\u{308}\u{94d}"],
+    &["\u{a03}\u{200d}"],
+    &["\u{a03}\u{308}\u{200d}"],
+    &["\u{a03}", "\u{378}"],
+    &["\u{a03}\u{308}", "\u{378}"],
+    &["\u{1100}", " "],
+    &["\u{1100}\u{308}", " "],
+    &["\u{1100}", "\u{d}"],
+    &["\u{1100}\u{308}", "\u{d}"],
+    &["\u{1100}", "\u{a}"],
+    &["\u{1100}\u{308}", "\u{a}"],
+    &["\u{1100}", "\u{1}"],
+    &["\u{1100}\u{308}", "\u{1}"],
+    &["\u{1100}\u{200c}"],
+    &["\u{1100}\u{308}\u{200c}"],
+    &["\u{1100}", "\u{1f1e6}"],
+    &["\u{1100}\u{308}", "\u{1f1e6}"],
+    &["\u{1100}", "\u{600}"],
+    &["\u{1100}\u{308}", "\u{600}"],
+    &["\u{1100}\u{1100}"],
+    &["\u{1100}\u{308}", "\u{1100}"],
+    &["\u{1100}\u{1160}"],
+    &["\u{1100}\u{308}", "\u{1160}"],
+    &["\u{1100}", "\u{11a8}"],
+    &["\u{1100}\u{308}", "\u{11a8}"],
+    &["\u{1100}\u{ac00}"],
+    &["\u{1100}\u{308}", "\u{ac00}"],
+    &["\u{1100}\u{ac01}"],
+    &["\u{1100}\u{308}", "\u{ac01}"],
+    &["\u{1100}", "\u{904}"],
+    &["\u{1100}\u{308}", "\u{904}"],
+    &["\u{1100}", "\u{d4e}"],
+    &["\u{1100}\u{308}", "\u{d4e}"],
+    &["\u{1100}", "\u{915}"],
+    &["\u{1100}\u{308}", "\u{915}"],
+    &["\u{1100}", "\u{231a}"],
+    &["\u{1100}\u{308}", "\u{231a}"],
+    &["\u{1100}\u{300}"],
+    &["\u{1100}\u{308}\u{300}"],
+    &["\u{1100}\u{900}"],
+    &["\u{1100}\u{308}\u{900}"],
+    &["\u{1100}\u{94d}"],
+    &["\u{1100}\u{308}\u{94d}"],
+    &["\u{1100}\u{200d}"],
+    &["\u{1100}\u{308}\u{200d}"],
+    &["\u{1100}", "\u{378}"],
+    &["\u{1100}\u{308}", "\u{378}"],
+    &["\u{1160}", " "],
+    &["\u{1160}\u{308}", " "],
+    &["\u{1160}", "\u{d}"],
+    &["\u{1160}\u{308}", "\u{d}"],
+    &["\u{1160}", "\u{a}"],
+    &["\u{1160}\u{308}", "\u{a}"],
+    &["\u{1160}", "\u{1}"],
+    &["\u{1160}\u{308}", "\u{1}"],
+    &["\u{1160}\u{200c}"],
+    &["\u{1160}\u{308}\u{200c}"],
+    &["\u{1160}", "\u{1f1e6}"],
+    &["\u{1160}\u{308}", "\u{1f1e6}"],
+    &["\u{1160}", "\u{600}"],
+    &["\u{1160}\u{308}", "\u{600}"],
+    &["\u{1160}", "\u{1100}"],
+    &["\u{1160}\u{308}", "\u{1100}"],
+    &["\u{1160}\u{1160}"],
+    &["\u{1160}\u{308}", "\u{1160}"],
+    &["\u{1160}\u{11a8}"],
+    &["\u{1160}\u{308}", "\u{11a8}"],
+    &["\u{1160}", "\u{ac00}"],
+    &["\u{1160}\u{308}", "\u{ac00}"],
+    &["\u{1160}", "\u{ac01}"],
+    &["\u{1160}\u{308}", "\u{ac01}"],
+    &["\u{1160}", "\u{904}"],
+    &["\u{1160}\u{308}", "\u{904}"],
+    &["\u{1160}", "\u{d4e}"],
+    &["\u{1160}\u{308}", "\u{d4e}"],
+    &["\u{1160}", "\u{915}"],
+    &["\u{1160}\u{308}", "\u{915}"],
+    &["\u{1160}", "\u{231a}"],
+    &["\u{1160}\u{308}", "\u{231a}"],
+    &["\u{1160}\u{300}"],
+    &["\u{1160}\u{308}\u{300}"],
+    &["\u{1160}\u{900}"],
+    &["\u{1160}\u{308}\u{900}"],
+    &["\u{1160}\u{94d}"],
+    &["\u{1160}\u{308}\u{94d}"],
+    &["\u{1160}\u{200d}"],
+    &["\u{1160}\u{308}\u{200d}"],
+    &["\u{1160}", "\u{378}"],
+    &["\u{1160}\u{308}", "\u{378}"],
+    &["\u{11a8}", " "],
+    &["\u{11a8}\u{308}", " "],
+    &["\u{11a8}", "\u{d}"],
+    &["\u{11a8}\u{308}", "\u{d}"],
+    &["\u{11a8}", "\u{a}"],
+    &["\u{11a8}\u{308}", "\u{a}"],
+    &["\u{11a8}", "\u{1}"],
+    &["\u{11a8}\u{308}", "\u{1}"],
+    &["\u{11a8}\u{200c}"],
+    &["\u{11a8}\u{308}\u{200c}"],
+    &["\u{11a8}", "\u{1f1e6}"],
+    &["\u{11a8}\u{308}", "\u{1f1e6}"],
+    &["\u{11a8}", "\u{600}"],
+    &["\u{11a8}\u{308}", "\u{600}"],
+    &["\u{11a8}", "\u{1100}"],
+    &["\u{11a8}\u{308}", "\u{1100}"],
+    &["\u{11a8}", "\u{1160}"],
+    &["\u{11a8}\u{308}", "\u{1160}"],
+    &["\u{11a8}\u{11a8}"],
+    &["\u{11a8}\u{308}", "\u{11a8}"],
+    &["\u{11a8}", "\u{ac00}"],
+    &["\u{11a8}\u{308}", "\u{ac00}"],
+    &["\u{11a8}", "\u{ac01}"],
+    &["\u{11a8}\u{308}", "\u{ac01}"],
+    &["\u{11a8}", "\u{904}"],
+    &["\u{11a8}\u{308}", "\u{904}"],
+    &["\u{11a8}", "\u{d4e}"],
+    &["\u{11a8}\u{308}", "\u{d4e}"],
+    &["\u{11a8}", "\u{915}"],
+    &["\u{11a8}\u{308}", "\u{915}"],
+    &["\u{11a8}", "\u{231a}"],
+    &["\u{11a8}\u{308}", "\u{231a}"],
+    &["\u{11a8}\u{300}"],
+    &["\u{11a8}\u{308}\u{300}"],
+    &["\u{11a8}\u{900}"],
+    &["\u{11a8}\u{308}\u{900}"],
+    &["\u{11a8}\u{94d}"],
+    &["\u{11a8}\u{308}\u{94d}"],
+    &["\u{11a8}\u{200d}"],
+    &["\u{11a8}\u{308}\u{200d}"],
+    &["\u{11a8}", "\u{378}"],
+    &["\u{11a8}\u{308}", "\u{378}"],
+    &["\u{ac00}", " "],
+    &["\u{ac00}\u{308}", " "],
+    &["\u{ac00}", "\u{d}"],
+    &["\u{ac00}\u{308}", "\u{d}"],
+    &["\u{ac00}", "\u{a}"],
+    &["\u{ac00}\u{308}", "\u{a}"],
+    &["\u{ac00}", "\u{1}"],
+    &["\u{ac00}\u{308}", "\u{1}"],
+    &["\u{ac00}\u{200c}"],
+    &["\u{ac00}\u{308}\u{200c}"],
+    &["\u{ac00}", "\u{1f1e6}"],
+    &["\u{ac00}\u{308}", "\u{1f1e6}"],
+    &["\u{ac00}", "\u{600}"],
+    &["\u{ac00}\u{308}", "\u{600}"],
+    &["\u{ac00}", "\u{1100}"],
+    &["\u{ac00}\u{308}", "\u{1100}"],
+    &["\u{ac00}\u{1160}"],
+    &["\u{ac00}\u{308}", "\u{1160}"],
+    &["\u{ac00}\u{11a8}"],
+    &["\u{ac00}\u{308}", "\u{11a8}"],
+    &["\u{ac00}", "\u{ac00}"],
+    &["\u{ac00}\u{308}", "\u{ac00}"],
+    &["\u{ac00}", "\u{ac01}"],
+    &["\u{ac00}\u{308}", "\u{ac01}"],
+    &["\u{ac00}", "\u{904}"],
+    &["\u{ac00}\u{308}", "\u{904}"],
+    &["\u{ac00}", "\u{d4e}"],
+    &["\u{ac00}\u{308}", "\u{d4e}"],
+    &["\u{ac00}", "\u{915}"],
+    &["\u{ac00}\u{308}", "\u{915}"],
+    &["\u{ac00}", "\u{231a}"],
+    &["\u{ac00}\u{308}", "\u{231a}"],
+    &["\u{ac00}\u{300}"],
+    &["\u{ac00}\u{308}\u{300}"],
+    &["\u{ac00}\u{900}"],
+    &["\u{ac00}\u{308}\u{900}"],
+    &["\u{ac00}\u{94d}"],
+    &["\u{ac00}\u{308}\u{94d}"],
+    &["\u{ac00}\u{200d}"],
+    &["\u{ac00}\u{308}\u{200d}"],
+    &["\u{ac00}", "\u{378}"],
+    &["\u{ac00}\u{308}", "\u{378}"],
+    &["\u{ac01}", " "],
+    &["\u{ac01}\u{308}", " "],
+    &["\u{ac01}", "\u{d}"],
+    &["\u{ac01}\u{308}", "\u{d}"],
+    &["\u{ac01}", "\u{a}"],
+    &["\u{ac01}\u{308}", "\u{a}"],
+    &["\u{ac01}", "\u{1}"],
+    &["\u{ac01}\u{308}", "\u{1}"],
+    &["\u{ac01}\u{200c}"],
+    &["\u{ac01}\u{308}\u{200c}"],
+    &["\u{ac01}", "\u{1f1e6}"],
+    &["\u{ac01}\u{308}", "\u{1f1e6}"],
+    &["\u{ac01}", "\u{600}"],
+    &["\u{ac01}\u{308}", "\u{600}"],
+    &["\u{ac01}", "\u{1100}"],
+    &["\u{ac01}\u{308}", "\u{1100}"],
+    &["\u{ac01}", "\u{1160}"],
+    &["\u{ac01}\u{308}", "\u{1160}"],
+    &["\u{ac01}\u{11a8}"],
+    &["\u{ac01}\u{308}", "\u{11a8}"],
+    &["\u{ac01}", "\u{ac00}"],
+    &["\u{ac01}\u{308}", "\u{ac00}"],
+    &["\u{ac01}", "\u{ac01}"],
+    &["\u{ac01}\u{308}", "\u{ac01}"],
+    &["\u{ac01}", "\u{904}"],
+    &["\u{ac01}\u{308}", "\u{904}"],
+    &["\u{ac01}", "\u{d4e}"],
+    &["\u{ac01}\u{308}", "\u{d4e}"],
+    &["\u{ac01}", "\u{915}"],
+    &["\u{ac01}\u{308}", "\u{915}"],
+    &["\u{ac01}", "\u{231a}"],
+    &["\u{ac01}\u{308}", "\u{231a}"],
+    &["\u{ac01}\u{300}"],
+    &["\u{ac01}\u{308}\u{300}"],
+    &["\u{ac01}\u{900}"],
+    &["\u{ac01}\u{308}\u{900}"],
+    &["\u{ac01}\u{94d}"],
+    &["\u{ac01}\u{308}\u{94d}"],
+    &["\u{ac01}\u{200d}"],
+    &["\u{ac01}\u{308}\u{200d}"],
+    &["\u{ac01}", "\u{378}"],
+    &["\u{ac01}\u{308}", "\u{378}"],
+    &["\u{903}", " "],
+    &["\u{903}\u{308}", " "],
+    &["\u{903}", "\u{d}"],
+    &["\u{903}\u{308}", "\u{d}"],
+    &["\u{903}", "\u{a}"],
+    &["\u{903}\u{308}", "\u{a}"],
+    &["\u{903}", "\u{1}"],
+    &["\u{903}\u{308}", "\u{1}"],
+    &["\u{903}\u{200c}"],
+    &["\u{903}\u{308}\u{200c}"],
+    &["\u{903}", "\u{1f1e6}"],
+    &["\u{903}\u{308}", "\u{1f1e6}"],
+    &["\u{903}", "\u{600}"],
+    &["\u{903}\u{308}", "\u{600}"],
+    &["\u{903}", "\u{1100}"],
+    &["\u{903}\u{308}", "\u{1100}"],
+    &["\u{903}", "\u{1160}"],
+    &["\u{903}\u{308}", "\u{1160}"],
+    &["\u{903}", "\u{11a8}"],
+    &["\u{903}\u{308}", "\u{11a8}"],
+    &["\u{903}", "\u{ac00}"],
+    &["\u{903}\u{308}", "\u{ac00}"],
+    &["\u{903}", "\u{ac01}"],
+    &["\u{903}\u{308}", "\u{ac01}"],
+    &["\u{903}", "\u{904}"],
+    &["\u{903}\u{308}", "\u{904}"],
+    &["\u{903}", "\u{d4e}"],
+    &["\u{903}\u{308}", "\u{d4e}"],
+    &["\u{903}", "\u{915}"],
+    &["\u{903}\u{308}", "\u{915}"],
+    &["\u{903}", "\u{231a}"],
+    &["\u{903}\u{308}", "\u{231a}"],
+    &["\u{903}\u{300}"],
+    &["\u{903}\u{308}\u{300}"],
+    &["\u{903}\u{900}"],
+    &["\u{903}\u{308}\u{900}"],
+    &["\u{903}\u{94d}"],
+    &["\u{903}\u{308}\u{94d}"],
+    &["\u{903}\u{200d}"],
+    &["\u{903}\u{308}\u{200d}"],
+    &["\u{903}", "\u{378}"],
+    &["\u{903}\u{308}", "\u{378}"],
+    &["\u{904}", " "],
+    &["\u{904}\u{308}", " "],
+    &["\u{904}", "\u{d}"],
+    &["\u{904}\u{308}", "\u{d}"],
+    &["\u{904}", "\u{a}"],
+    &["\u{904}\u{308}", "\u{a}"],
+    &["\u{904}", "\u{1}"],
+    &["\u{904}\u{308}", "\u{1}"],
+    &["\u{904}\u{200c}"],
+    &["\u{904}\u{308}\u{200c}"],
+    &["\u{904}", "\u{1f1e6}"],
+    &["\u{904}\u{308}", "\u{1f1e6}"],
+    &["\u{904}", "\u{600}"],
+    &["\u{904}\u{308}", "\u{600}"],
+    &["\u{904}", "\u{1100}"],
+    &["\u{904}\u{308}", "\u{1100}"],
+    &["\u{904}", "\u{1160}"],
+    &["\u{904}\u{308}", "\u{1160}"],
+    &["\u{904}", "\u{11a8}"],
+    &["\u{904}\u{308}", "\u{11a8}"],
+    &["\u{904}", "\u{ac00}"],
+    &["\u{904}\u{308}", "\u{ac00}"],
+    &["\u{904}", "\u{ac01}"],
+    &["\u{904}\u{308}", "\u{ac01}"],
+    &["\u{904}", "\u{904}"],
+    &["\u{904}\u{308}", "\u{904}"],
+    &["\u{904}", "\u{d4e}"],
+    &["\u{904}\u{308}", "\u{d4e}"],
+    &["\u{904}", "\u{915}"],
+    &["\u{904}\u{308}", "\u{915}"],
+    &["\u{904}", "\u{231a}"],
+    &["\u{904}\u{308}", "\u{231a}"],
+    &["\u{904}\u{300}"],
+    &["\u{904}\u{308}\u{300}"],
+    &["\u{904}\u{900}"],
+    &["\u{904}\u{308}\u{900}"],
+    &["\u{904}\u{94d}"],
+    &["\u{904}\u{308}\u{94d}"],
+    &["\u{904}\u{200d}"],
+    &["\u{904}\u{308}\u{200d}"],
+    &["\u{904}", "\u{378}"],
+    &["\u{904}\u{308}", "\u{378}"],
+    &["\u{d4e}\u{308}", " "],
+    &["\u{d4e}", "\u{d}"],
+    &["\u{d4e}\u{308}", "\u{d}"],
+    &["\u{d4e}", "\u{a}"],
+    &["\u{d4e}\u{308}", "\u{a}"],
+    &["\u{d4e}", "\u{1}"],
+    &["\u{d4e}\u{308}", "\u{1}"],
+    &["\u{d4e}\u{200c}"],
+    &["\u{d4e}\u{308}\u{200c}"],
+    &["\u{d4e}\u{308}", "\u{1f1e6}"],
+    &["\u{d4e}\u{308}", "\u{600}"],
+    &["\u{d4e}\u{308}", "\u{1100}"],
+    &["\u{d4e}\u{308}", "\u{1160}"],
+    &["\u{d4e}\u{308}", "\u{11a8}"],
+    &["\u{d4e}\u{308}", "\u{ac00}"],
+    &["\u{d4e}\u{308}", "\u{ac01}"],
+    &["\u{d4e}\u{308}", "\u{904}"],
+    &["\u{d4e}\u{308}", "\u{d4e}"],
+    &["\u{d4e}\u{308}", "\u{915}"],
+    &["\u{d4e}\u{308}", "\u{231a}"],
+    &["\u{d4e}\u{300}"],
+    &["\u{d4e}\u{308}\u{300}"],
+    &["\u{d4e}\u{900}"],
+    &["\u{d4e}\u{308}\u{900}"],
+    &["\u{d4e}\u{94d}"],
+    &["\u{d4e}\u{308}\u{94d}"],
+    &["\u{d4e}\u{200d}"],
+    &["\u{d4e}\u{308}\u{200d}"],
+    &["\u{d4e}\u{308}", "\u{378}"],
+    &["\u{915}", " "],
+    &["\u{915}\u{308}", " "],
+    &["\u{915}", "\u{d}"],
+    &["\u{915}\u{308}", "\u{d}"],
+    &["\u{915}", "\u{a}"],
+    &["\u{915}\u{308}", "\u{a}"],
+    &["\u{915}", "\u{1}"],
+    &["\u{915}\u{308}", "\u{1}"],
+    &["\u{915}\u{200c}"],
+    &["\u{915}\u{308}\u{200c}"],
+    &["\u{915}", "\u{1f1e6}"],
+    &["\u{915}\u{308}", "\u{1f1e6}"],
+    &["\u{915}", "\u{600}"],
+    &["\u{915}\u{308}", "\u{600}"],
+    &["\u{915}", "\u{1100}"],
+    &["\u{915}\u{308}", "\u{1100}"],
+    &["\u{915}", "\u{1160}"],
+    &["\u{915}\u{308}", "\u{1160}"],
+    &["\u{915}", "\u{11a8}"],
+    &["\u{915}\u{308}", "\u{11a8}"],
+    &["\u{915}", "\u{ac00}"],
+    &["\u{915}\u{308}", "\u{ac00}"],
+    &["\u{915}", "\u{ac01}"],
+    &["\u{915}\u{308}", "\u{ac01}"],
+    &["\u{915}", "\u{904}"],
+    &["\u{915}\u{308}", "\u{904}"],
+    &["\u{915}", "\u{d4e}"],
+    &["\u{915}\u{308}", "\u{d4e}"],
+    &["\u{915}", "\u{915}"],
+    &["\u{915}\u{308}", "\u{915}"],
+    &["\u{915}", "\u{231a}"],
+    &["\u{915}\u{308}", "\u{231a}"],
+    &["\u{915}\u{300}"],
+    &["\u{915}\u{308}\u{300}"],
+    &["\u{915}\u{900}"],
+    &["\u{915}\u{308}\u{900}"],
+    &["\u{915}\u{94d}"],
+    &["\u{915}\u{308}\u{94d}"],
+    &["\u{915}\u{200d}"],
+    &["\u{915}\u{308}\u{200d}"],
+    &["\u{915}", "\u{378}"],
+    &["\u{915}\u{308}", "\u{378}"],
+    &["\u{231a}", " "],
+    &["\u{231a}\u{308}", " "],
+    &["\u{231a}", "\u{d}"],
+    &["\u{231a}\u{308}", "\u{d}"],
+    &["\u{231a}", "\u{a}"],
+    &["\u{231a}\u{308}", "\u{a}"],
+    &["\u{231a}", "\u{1}"],
+    &["\u{231a}\u{308}", "\u{1}"],
+    &["\u{231a}\u{200c}"],
+    &["\u{231a}\u{308}\u{200c}"],
+    &["\u{231a}", "\u{1f1e6}"],
+    &["\u{231a}\u{308}", "\u{1f1e6}"],
+    &["\u{231a}", "\u{600}"],
+    &["\u{231a}\u{308}", "\u{600}"],
+    &["\u{231a}", "\u{1100}"],
+    &["\u{231a}\u{308}", "\u{1100}"],
+    &["\u{231a}", "\u{1160}"],
+    &["\u{231a}\u{308}", "\u{1160}"],
+    &["\u{231a}", "\u{11a8}"],
+    &["\u{231a}\u{308}", "\u{11a8}"],
+    &["\u{231a}", "\u{ac00}"],
+    &["\u{231a}\u{308}", "\u{ac00}"],
+    &["\u{231a}", "\u{ac01}"],
+    &["\u{231a}\u{308}", "\u{ac01}"],
+    &["\u{231a}", "\u{904}"],
+    &["\u{231a}\u{308}", "\u{904}"],
+    &["\u{231a}", "\u{d4e}"],
+    &["\u{231a}\u{308}", "\u{d4e}"],
+    &["\u{231a}", "\u{915}"],
+    &["\u{231a}\u{308}", "\u{915}"],
+    &["\u{231a}", "\u{231a}"],
+    &["\u{231a}\u{308}", "\u{231a}"],
+    &["\u{231a}\u{300}"],
+    &["\u{231a}\u{308}\u{300}"],
+    &["\u{231a}\u{900}"],
+    &["\u{231a}\u{308}\u{900}"],
+    &["\u{231a}\u{94d}"],
+    &["\u{231a}\u{308}\u{94d}"],
+    &["\u{231a}\u{200d}"],
+    &["\u{231a}\u{308}\u{200d}"],
+    &["\u{231a}", "\u{378}"],
+    &["\u{231a}\u{308}", "\u{378}"],
+    &["\u{300}", " "],
+    &["\u{300}\u{308}", " "],
+    &["\u{300}", "\u{d}"],
+    &["\u{300}\u{308}", "\u{d}"],
+    &["\u{300}", "\u{a}"],
+    &["\u{300}\u{308}", "\u{a}"],
+    &["\u{300}", "\u{1}"],
+    &["\u{300}\u{308}", "\u{1}"],
+    &["\u{300}\u{200c}"],
+    &["\u{300}\u{308}\u{200c}"],
+    &["\u{300}", "\u{1f1e6}"],
+    &["\u{300}\u{308}", "\u{1f1e6}"],
+    &["\u{300}", "\u{600}"],
+    &["\u{300}\u{308}", "\u{600}"],
+    &["\u{300}", "\u{1100}"],
+    &["\u{300}\u{308}", "\u{1100}"],
+    &["\u{300}", "\u{1160}"],
+    &["\u{300}\u{308}", "\u{1160}"],
+    &["\u{300}", "\u{11a8}"],
+    &["\u{300}\u{308}", "\u{11a8}"],
+    &["\u{300}", "\u{ac00}"],
+    &["\u{300}\u{308}", "\u{ac00}"],
+    &["\u{300}", "\u{ac01}"],
+    &["\u{300}\u{308}", "\u{ac01}"],
+    &["\u{300}", "\u{904}"],
+    &["\u{300}\u{308}", "\u{904}"],
+    &["\u{300}", "\u{d4e}"],
+    &["\u{300}\u{308}", "\u{d4e}"],
+    &["\u{300}", "\u{915}"],
+    &["\u{300}\u{308}", "\u{915}"],
+    &["\u{300}", "\u{231a}"],
+    &["\u{300}\u{308}", "\u{231a}"],
+    &["\u{300}\u{300}"],
+    &["\u{300}\u{308}\u{300}"],
+    &["\u{300}\u{900}"],
+    &["\u{300}\u{308}\u{900}"],
+    &["\u{300}\u{94d}"],
+    &["\u{300}\u{308}\u{94d}"],
+    &["\u{300}\u{200d}"],
+    &["\u{300}\u{308}\u{200d}"],
+    &["\u{300}", "\u{378}"],
+    &["\u{300}\u{308}", "\u{378}"],
+    &["\u{900}", " "],
+    &["\u{900}\u{308}", " "],
+    &["\u{900}", "\u{d}"],
+    &["\u{900}\u{308}", "\u{d}"],
+    &["\u{900}", "\u{a}"],
+    &["\u{900}\u{308}", "\u{a}"],
+    &["\u{900}", "\u{1}"],
+    &["\u{900}\u{308}", "\u{1}"],
+    &["\u{900}\u{200c}"],
+    &["\u{900}\u{308}\u{200c}"],
+    &["\u{900}", "\u{1f1e6}"],
+    &["\u{900}\u{308}", "\u{1f1e6}"],
+    &["\u{900}", "\u{600}"],
+    &["\u{900}\u{308}", "\u{600}"],
+    &["\u{900}", "\u{1100}"],
+    &["\u{900}\u{308}", "\u{1100}"],
+    &["\u{900}", "\u{1160}"],
+    &["\u{900}\u{308}", "\u{1160}"],
+    &["\u{900}", "\u{11a8}"],
+    &["\u{900}\u{308}", "\u{11a8}"],
+    &["\u{900}", "\u{ac00}"],
+    &["\u{900}\u{308}", "\u{ac00}"],
+    &["\u{900}", "\u{ac01}"],
+    &["\u{900}\u{308}", "\u{ac01}"],
+    &["\u{900}", "\u{904}"],
+    &["\u{900}\u{308}", "\u{904}"],
+    &["\u{900}", "\u{d4e}"],
+    &["\u{900}\u{308}", "\u{d4e}"],
+    &["\u{900}", "\u{915}"],
+    &["\u{900}\u{308}", "\u{915}"],
+    &["\u{900}", "\u{231a}"],
+    &["\u{900}\u{308}", "\u{231a}"],
+    &["\u{900}\u{300}"],
+    &["\u{900}\u{308}\u{300}"],
+    &["\u{900}\u{900}"],
+    &["\u{900}\u{308}\u{900}"],
+    &["\u{900}\u{94d}"],
+    &["\u{900}\u{308}\u{94d}"],
+    &["\u{900}\u{200d}"],
+    &["\u{900}\u{308}\u{200d}"],
+    &["\u{900}", "\u{378}"],
+    &["\u{900}\u{308}", "\u{378}"],
+    &["\u{94d}", " "],
+    &["\u{94d}\u{308}", " "],
+    &["\u{94d}", "\u{d}"],
+    &["\u{94d}\u{308}", "\u{d}"],
+    &["\u{94d}", "\u{a}"],
+    &["\u{94d}\u{308}", "\u{a}"],
+    &["\u{94d}", "\u{1}"],
+    &["\u{94d}\u{308}", "\u{1}"],
+    &["\u{94d}\u{200c}"],
+    &["\u{94d}\u{308}\u{200c}"],
+    &["\u{94d}", "\u{1f1e6}"],
+    &["\u{94d}\u{308}", "\u{1f1e6}"],
+    &["\u{94d}", "\u{600}"],
+    &["\u{94d}\u{308}", "\u{600}"],
+    &["\u{94d}", "\u{1100}"],
+    &["\u{94d}\u{308}", "\u{1100}"],
+    &["\u{94d}", "\u{1160}"],
+    &["\u{94d}\u{308}", "\u{1160}"],
+    &["\u{94d}", "\u{11a8}"],
+    &["\u{94d}\u{308}", "\u{11a8}"],
+    &["\u{94d}", "\u{ac00}"],
+    &["\u{94d}\u{308}", "\u{ac00}"],
+    &["\u{94d}", "\u{ac01}"],
+    &["\u{94d}\u{308}", "\u{ac01}"],
+    &["\u{94d}", "\u{904}"],
+    &["\u{94d}\u{308}", "\u{904}"],
+    &["\u{94d}", "\u{d4e}"],
+    &["\u{94d}\u{308}", "\u{d4e}"],
+    &["\u{94d}", "\u{915}"],
+    &["\u{94d}\u{308}", "\u{915}"],
+    &["\u{94d}", "\u{231a}"],
+    &["\u{94d}\u{308}", "\u{231a}"],
+    &["\u{94d}\u{300}"],
+    &["\u{94d}\u{308}\u{300}"],
+    &["\u{94d}\u{900}"],
+    &["\u{94d}\u{308}\u{900}"],
+    &["\u{94d}\u{94d}"],
+    &["\u{94d}\u{308}\u{94d}"],
+    &["\u{94d}\u{200d}"],
+    &["\u{94d}\u{308}\u{200d}"],
+    &["\u{94d}", "\u{378}"],
+    &["\u{94d}\u{308}", "\u{378}"],
+    &["\u{200d}", " "],
+    &["\u{200d}\u{308}", " "],
+    &["\u{200d}", "\u{d}"],
+    &["\u{200d}\u{308}", "\u{d}"],
+    &["\u{200d}", "\u{a}"],
+    &["\u{200d}\u{308}", "\u{a}"],
+    &["\u{200d}", "\u{1}"],
+    &["\u{200d}\u{308}", "\u{1}"],
+    &["\u{200d}\u{200c}"],
+    &["\u{200d}\u{308}\u{200c}"],
+    &["\u{200d}", "\u{1f1e6}"],
+    &["\u{200d}\u{308}", "\u{1f1e6}"],
+    &["\u{200d}", "\u{600}"],
+    &["\u{200d}\u{308}", "\u{600}"],
+    &["\u{200d}", "\u{1100}"],
+    &["\u{200d}\u{308}", "\u{1100}"],
+    &["\u{200d}", "\u{1160}"],
+    &["\u{200d}\u{308}", "\u{1160}"],
+    &["\u{200d}", "\u{11a8}"],
+    &["\u{200d}\u{308}", "\u{11a8}"],
+    &["\u{200d}", "\u{ac00}"],
+    &["\u{200d}\u{308}", "\u{ac00}"],
+    &["\u{200d}", "\u{ac01}"],
+    &["\u{200d}\u{308}", "\u{ac01}"],
+    &["\u{200d}", "\u{904}"],
+    &["\u{200d}\u{308}", "\u{904}"],
+    &["\u{200d}", "\u{d4e}"],
+    &["\u{200d}\u{308}", "\u{d4e}"],
+    &["\u{200d}", "\u{915}"],
+    &["\u{200d}\u{308}", "\u{915}"],
+    &["\u{200d}", "\u{231a}"],
+    &["\u{200d}\u{308}", "\u{231a}"],
+    &["\u{200d}\u{300}"],
+    &["\u{200d}\u{308}\u{300}"],
+    &["\u{200d}\u{900}"],
+    &["\u{200d}\u{308}\u{900}"],
+    &["\u{200d}\u{94d}"],
+    &["\u{200d}\u{308}\u{94d}"],
+    &["\u{200d}\u{200d}"],
+    &["\u{200d}\u{308}\u{200d}"],
+    &["\u{200d}", "\u{378}"],
+    &["\u{200d}\u{308}", "\u{378}"],
+    &["\u{378}", " "],
+    &["\u{378}\u{308}", " "],
+    &["\u{378}", "\u{d}"],
+    &["\u{378}\u{308}", "\u{d}"],
+    &["\u{378}", "\u{a}"],
+    &["\u{378}\u{308}", "\u{a}"],
+    &["\u{378}", "\u{1}"],
+    &["\u{378}\u{308}", "\u{1}"],
+    &["\u{378}\u{200c}"],
+    &["\u{378}\u{308}\u{200c}"],
+    &["\u{378}", "\u{1f1e6}"],
+    &["\u{378}\u{308}", "\u{1f1e6}"],
+    &["\u{378}", "\u{600}"],
+    &["\u{378}\u{308}", "\u{600}"],
+    &["\u{378}", "\u{1100}"],
+    &["\u{378}\u{308}", "\u{1100}"],
+    &["\u{378}", "\u{1160}"],
+    &["\u{378}\u{308}", "\u{1160}"],
+    &["\u{378}", "\u{11a8}"],
+    &["\u{378}\u{308}", "\u{11a8}"],
+    &["\u{378}", "\u{ac00}"],
+    &["\u{378}\u{308}", "\u{ac00}"],
+    &["\u{378}", "\u{ac01}"],
+    &["\u{378}\u{308}", "\u{ac01}"],
+    &["\u{378}", "\u{904}"],
+    &["\u{378}\u{308}", "\u{904}"],
+    &["\u{378}", "\u{d4e}"],
+    &["\u{378}\u{308}", "\u{d4e}"],
+    &["\u{378}", "\u{915}"],
+    &["\u{378}\u{308}", "\u{915}"],
+    &["\u{378}", "\u{231a}"],
+    &["\u{378}\u{308}", "\u{231a}"],
+    &["\u{378}\u{300}"],
+    &["\u{378}\u{308}\u{300}"],
+    &["\u{378}\u{900}"],
+    &["\u{378}\u{308}\u{900}"],
+    &["\u{378}\u{94d}"],
+    &["\u{378}\u{308}\u{94d}"],
+    &["\u{378}\u{200d}"],
+    &["\u{378}\u{308}\u{200d}"],
+    &["\u{378}", "\u{378}"],
+    &["\u{378}\u{308}", "\u{378}"],
+    &["\u{d}\u{a}", "a", "\u{a}", "\u{308}"],
+    &["a\u{308}"],
+    &[" \u{200d}", "\u{646}"],
+    &["\u{646}\u{200d}", " "],
+    &["\u{1100}\u{1100}"],
+    &["\u{ac00}\u{11a8}", "\u{1100}"],
+    &["\u{ac01}\u{11a8}", "\u{1100}"],
+    &["\u{1f1e6}\u{1f1e7}", "\u{1f1e8}", "b"],
+    &["a", "\u{1f1e6}\u{1f1e7}", "\u{1f1e8}", "b"],
+    &["a", "\u{1f1e6}\u{1f1e7}\u{200d}", "\u{1f1e8}", "b"],
+    &["a", "\u{1f1e6}\u{200d}", "\u{1f1e7}\u{1f1e8}", "b"],
+    &["a", "\u{1f1e6}\u{1f1e7}", "\u{1f1e8}\u{1f1e9}", "b"],
+    &["a\u{200d}"],
+    &["a\u{308}", "b"],
+    &["\u{1f476}\u{1f3ff}", "\u{1f476}"],
+    &["a\u{1f3ff}", "\u{1f476}"],
+    &["a\u{1f3ff}", "\u{1f476}\u{200d}\u{1f6d1}"],
+    &["\u{1f476}\u{1f3ff}\u{308}\u{200d}\u{1f476}\u{1f3ff}"],
+    &["\u{1f6d1}\u{200d}\u{1f6d1}"],
+    &["a\u{200d}", "\u{1f6d1}"],
+    &["\u{2701}\u{200d}\u{2701}"],
+    &["a\u{200d}", "\u{2701}"],
+    &["\u{915}", "\u{924}"],
+    &["\u{915}\u{94d}", "a"],
+    &["a\u{94d}", "\u{924}"],
+    &["?\u{94d}", "\u{924}"],
+    &[" \u{a03}"],
+    &[" \u{308}\u{a03}"],
+    &[" \u{903}"],
+    &[" \u{308}\u{903}"],
+    &["\u{d}", "\u{308}\u{a03}"],
+    &["\u{d}", "\u{308}\u{903}"],
+    &["\u{a}", "\u{308}\u{a03}"],
+    &["\u{a}", "\u{308}\u{903}"],
+    &["\u{1}", "\u{308}\u{a03}"],
+    &["\u{1}", "\u{308}\u{903}"],
+    &["\u{200c}\u{a03}"],
+    &["\u{200c}\u{308}\u{a03}"],
+    &["\u{200c}\u{903}"],
+    &["\u{200c}\u{308}\u{903}"],
+    &["\u{1f1e6}\u{a03}"],
+    &["\u{1f1e6}\u{308}\u{a03}"],
+    &["\u{1f1e6}\u{903}"],
+    &["\u{1f1e6}\u{308}\u{903}"],
+    &["\u{600} "],
+    &["\u{600}\u{1f1e6}"],
+    &["\u{600}\u{600}"],
+    &["\u{600}\u{a03}"],
+    &["\u{600}\u{308}\u{a03}"],
+    &["\u{600}\u{1100}"],
+    &["\u{600}\u{1160}"],
+    &["\u{600}\u{11a8}"],
+    &["\u{600}\u{ac00}"],
+    &["\u{600}\u{ac01}"],
+    &["\u{600}\u{903}"],
+    &["\u{600}\u{308}\u{903}"],
+    &["\u{600}\u{904}"],
+    &["\u{600}\u{d4e}"],
+    &["\u{600}\u{915}"],
+    &["\u{600}\u{231a}"],
+    &["\u{600}\u{378}"],
+    &["\u{a03}\u{a03}"],
+    &["\u{a03}\u{308}\u{a03}"],
+    &["\u{a03}\u{903}"],
+    &["\u{a03}\u{308}\u{903}"],
+    &["\u{1100}\u{a03}"],
+    &["\u{1100}\u{308}\u{a03}"],
+    &["\u{1100}\u{903}"],
+    &["\u{1100}\u{308}\u{903}"],
+    &["\u{1160}\u{a03}"],
+    &["\u{1160}\u{308}\u{a03}"],
+    &["\u{1160}\u{903}"],
+    &["\u{1160}\u{308}\u{903}"],
+    &["\u{11a8}\u{a03}"],
+    &["\u{11a8}\u{308}\u{a03}"],
+    &["\u{11a8}\u{903}"],
+    &["\u{11a8}\u{308}\u{903}"],
+    &["\u{ac00}\u{a03}"],
+    &["\u{ac00}\u{308}\u{a03}"],
+    &["\u{ac00}\u{903}"],
+    &["\u{ac00}\u{308}\u{903}"],
+    &["\u{ac01}\u{a03}"],
+    &["\u{ac01}\u{308}\u{a03}"],
+    &["\u{ac01}\u{903}"],
+    &["\u{ac01}\u{308}\u{903}"],
+    &["\u{903}\u{a03}"],
+    &["\u{903}\u{308}\u{a03}"],
+    &["\u{903}\u{903}"],
+    &["\u{903}\u{308}\u{903}"],
+    &["\u{904}\u{a03}"],
+    &["\u{904}\u{308}\u{a03}"],
+    &["\u{904}\u{903}"],
+    &["\u{904}\u{308}\u{903}"],
+    &["\u{d4e} "],
+    &["\u{d4e}\u{1f1e6}"],
+    &["\u{d4e}\u{600}"],
+    &["\u{d4e}\u{a03}"],
+    &["\u{d4e}\u{308}\u{a03}"],
+    &["\u{d4e}\u{1100}"],
+    &["\u{d4e}\u{1160}"],
+    &["\u{d4e}\u{11a8}"],
+    &["\u{d4e}\u{ac00}"],
+    &["\u{d4e}\u{ac01}"],
+    &["\u{d4e}\u{903}"],
+    &["\u{d4e}\u{308}\u{903}"],
+    &["\u{d4e}\u{904}"],
+    &["\u{d4e}\u{d4e}"],
+    &["\u{d4e}\u{915}"],
+    &["\u{d4e}\u{231a}"],
+    &["\u{d4e}\u{378}"],
+    &["\u{915}\u{a03}"],
+    &["\u{915}\u{308}\u{a03}"],
+    &["\u{915}\u{903}"],
+    &["\u{915}\u{308}\u{903}"],
+    &["\u{231a}\u{a03}"],
+    &["\u{231a}\u{308}\u{a03}"],
+    &["\u{231a}\u{903}"],
+    &["\u{231a}\u{308}\u{903}"],
+    &["\u{300}\u{a03}"],
+    &["\u{300}\u{308}\u{a03}"],
+    &["\u{300}\u{903}"],
+    &["\u{300}\u{308}\u{903}"],
+    &["\u{900}\u{a03}"],
+    &["\u{900}\u{308}\u{a03}"],
+    &["\u{900}\u{903}"],
+    &["\u{900}\u{308}\u{903}"],
+    &["\u{94d}\u{a03}"],
+    &["\u{94d}\u{308}\u{a03}"],
+    &["\u{94d}\u{903}"],
+    &["\u{94d}\u{308}\u{903}"],
+    &["\u{200d}\u{a03}"],
+    &["\u{200d}\u{308}\u{a03}"],
+    &["\u{200d}\u{903}"],
+    &["\u{200d}\u{308}\u{903}"],
+    &["\u{378}\u{a03}"],
+    &["\u{378}\u{308}\u{a03}"],
+    &["\u{378}\u{903}"],
+    &["\u{378}\u{308}\u{903}"],
+    &["a\u{903}", "b"],
+    &["a", "\u{600}b"],
+    &["\u{915}\u{94d}\u{924}"],
+    &["\u{915}\u{94d}\u{94d}\u{924}"],
+    &["\u{915}\u{94d}\u{200d}\u{924}"],
+    &["\u{915}\u{93c}\u{200d}\u{94d}\u{924}"],
+    &["\u{915}\u{93c}\u{94d}\u{200d}\u{924}"],
+    &["\u{915}\u{94d}\u{924}\u{94d}\u{92f}"],
+    &["\u{915}\u{94d}\u{94d}\u{924}"],
+];
+
+/// Word segments, including those between words.
+pub(super) static WORD_BREAK_TEST: &[&[&str]] = &[
+    &["\u{1}", "\u{1}"],
+    &["\u{1}\u{308}", "\u{1}"],
+    &["\u{1}", "\u{d}"],
+    &["\u{1}\u{308}", "\u{d}"],
+    &["\u{1}", "\u{a}"],
+    &["\u{1}\u{308}", "\u{a}"],
+    &["\u{1}", "\u{b}"],
+    &["\u{1}\u{308}", "\u{b}"],
+    &["\u{1}", "\u{3031}"],
+    &["\u{1}\u{308}", "\u{3031}"],
+    &["\u{1}", "A"],
+    &["\u{1}\u{308}", "A"],
+    &["\u{1}", ":"],
+    &["\u{1}\u{308}", ":"],
+    &["\u{1}", ","],
+    &["\u{1}\u{308}", ","],
+    &["\u{1}", "."],
+    &["\u{1}\u{308}", "."],
+    &["\u{1}", "0"],
+    &["\u{1}\u{308}", "0"],
+    &["\u{1}", "_"],
+    &["\u{1}\u{308}", "_"],
+    &["\u{1}", "\u{1f1e6}"],
+    &["\u{1}\u{308}", "\u{1f1e6}"],
+    &["\u{1}", "\u{5d0}"],
+    &["\u{1}\u{308}", "\u{5d0}"],
+    &["\u{1}", "\u{22}"],
+    &["\u{1}\u{308}", "\u{22}"],
+    &["\u{1}", "'"],
+    &["\u{1}\u{308}", "'"],
+    &["\u{1}", "\u{231a}"],
+    &["\u{1}\u{308}", "\u{231a}"],
+    &["\u{1}", " "],
+    &["\u{1}\u{308}", " "],
+    &["\u{1}\u{ad}"],
+    &["\u{1}\u{308}\u{ad}"],
+    &["\u{1}\u{300}"],
+    &["\u{1}\u{308}\u{300}"],
+    &["\u{1}\u{200d}"],
+    &["\u{1}\u{308}\u{200d}"],
+    &["\u{1}", "a\u{2060}"],
+    &["\u{1}\u{308}", "a\u{2060}"],
+    &["\u{1}", "a", ":"],
+    &["\u{1}\u{308}", "a", ":"],
+    &["\u{1}", "a", "'"],
+    &["\u{1}\u{308}", "a", "'"],
+    &["\u{1}", "a", "'\u{2060}"],
+    &["\u{1}\u{308}", "a", "'\u{2060}"],
+    &["\u{1}", "a", ","],
+    &["\u{1}\u{308}", "a", ","],
+    &["\u{1}", "1", ":"],
+    &["\u{1}\u{308}", "1", ":"],
+    &["\u{1}", "1", "'"],
+    &["\u{1}\u{308}", "1", "'"],
+    &["\u{1}", "1", ","],
+    &["\u{1}\u{308}", "1", ","],
+    &["\u{1}", "1", ".\u{2060}"],
+    &["\u{1}\u{308}", "1", ".\u{2060}"],
+    &["\u{d}", "\u{1}"],
+    &["\u{d}", "\u{308}", "\u{1}"],
+    &["\u{d}", "\u{d}"],
+    &["\u{d}", "\u{308}", "\u{d}"],
+    &["\u{d}\u{a}"],
+    &["\u{d}", "\u{308}", "\u{a}"],
+    &["\u{d}", "\u{b}"],
+    &["\u{d}", "\u{308}", "\u{b}"],
+    &["\u{d}", "\u{3031}"],
+    &["\u{d}", "\u{308}", "\u{3031}"],
+    &["\u{d}", "A"],
+    &["\u{d}", "\u{308}", "A"],
+    &["\u{d}", ":"],
+    &["\u{d}", "\u{308}", ":"],
+    &["\u{d}", ","],
+    &["\u{d}", "\u{308}", ","],
+    &["\u{d}", "."],
+    &["\u{d}", "\u{308}", "."],
+    &["\u{d}", "0"],
+    &["\u{d}", "\u{308}", "0"],
+    &["\u{d}", "_"],
+    &["\u{d}", "\u{308}", "_"],
+    &["\u{d}", "\u{1f1e6}"],
+    &["\u{d}", "\u{308}", "\u{1f1e6}"],
+    &["\u{d}", "\u{5d0}"],
+    &["\u{d}", "\u{308}", "\u{5d0}"],
+    &["\u{d}", "\u{22}"],
+    &["\u{d}", "\u{308}", "\u{22}"],
+    &["\u{d}", "'"],
+    &["\u{d}", "\u{308}", "'"],
+    &["\u{d}", "\u{231a}"],
+    &["\u{d}", "\u{308}", "\u{231a}"],
+    &["\u{d}", " "],
+    &["\u{d}", "\u{308}", " "],
+    &["\u{d}", "\u{ad}"],
+    &["\u{d}", "\u{308}\u{ad}"],
+    &["\u{d}", "\u{300}"],
+    &["\u{d}", "\u{308}\u{300}"],
+    &["\u{d}", "\u{200d}"],
+    &["\u{d}", "\u{308}\u{200d}"],
+    &["\u{d}", "a\u{2060}"],
+    &["\u{d}", "\u{308}", "a\u{2060}"],
+    &["\u{d}", "a", ":"],
+    &["\u{d}", "\u{308}", "a", ":"],
+    &["\u{d}", "a", "'"],
+    &["\u{d}", "\u{308}", "a", "'"],
+    &["\u{d}", "a", "'\u{2060}"],
+    &["\u{d}", "\u{308}", "a", "'\u{2060}"],
+    &["\u{d}", "a", ","],
+    &["\u{d}", "\u{308}", "a", ","],
+    &["\u{d}", "1", ":"],
+    &["\u{d}", "\u{308}", "1", ":"],
+    &["\u{d}", "1", "'"],
+    &["\u{d}", "\u{308}", "1", "'"],
+    &["\u{d}", "1", ","],
+    &["\u{d}", "\u{308}", "1", ","],
+    &["\u{d}", "1", ".\u{2060}"],
+    &["\u{d}", "\u{308}", "1", ".\u{2060}"],
+    &["\u{a}", "\u{1}"],
+    &["\u{a}", "\u{308}", "\u{1}"],
+    &["\u{a}", "\u{d}"],
+    &["\u{a}", "\u{308}", "\u{d}"],
+    &["\u{a}", "\u{a}"],
+    &["\u{a}", "\u{308}", "\u{a}"],
+    &["\u{a}", "\u{b}"],
+    &["\u{a}", "\u{308}", "\u{b}"],
+    &["\u{a}", "\u{3031}"],
+    &["\u{a}", "\u{308}", "\u{3031}"],
+    &["\u{a}", "A"],
+    &["\u{a}", "\u{308}", "A"],
+    &["\u{a}", ":"],
+    &["\u{a}", "\u{308}", ":"],
+    &["\u{a}", ","],
+    &["\u{a}", "\u{308}", ","],
+    &["\u{a}", "."],
+    &["\u{a}", "\u{308}", "."],
+    &["\u{a}", "0"],
+    &["\u{a}", "\u{308}", "0"],
+    &["\u{a}", "_"],
+    &["\u{a}", "\u{308}", "_"],
+    &["\u{a}", "\u{1f1e6}"],
+    &["\u{a}", "\u{308}", "\u{1f1e6}"],
+    &["\u{a}", "\u{5d0}"],
+    &["\u{a}", "\u{308}", "\u{5d0}"],
+    &["\u{a}", "\u{22}"],
+    &["\u{a}", "\u{308}", "\u{22}"],
+    &["\u{a}", "'"],
+    &["\u{a}", "\u{308}", "'"],
+    &["\u{a}", "\u{231a}"],
+    &["\u{a}", "\u{308}", "\u{231a}"],
+    &["\u{a}", " "],
+    &["\u{a}", "\u{308}", " "],
+    &["\u{a}", "\u{ad}"],
+    &["\u{a}", "\u{308}\u{ad}"],
+    &["\u{a}", "\u{300}"],
+    &["\u{a}", "\u{308}\u{300}"],
+    &["\u{a}", "\u{200d}"],
+    &["\u{a}", "\u{308}\u{200d}"],
+    &["\u{a}", "a\u{2060}"],
+    &["\u{a}", "\u{308}", "a\u{2060}"],
+    &["\u{a}", "a", ":"],
+    &["\u{a}", "\u{308}", "a", ":"],
+    &["\u{a}", "a", "'"],
+    &["\u{a}", "\u{308}", "a", "'"],
+    &["\u{a}", "a", "'\u{2060}"],
+    &["\u{a}", "\u{308}", "a", "'\u{2060}"],
+    &["\u{a}", "a", ","],
+    &["\u{a}", "\u{308}", "a", ","],
+    &["\u{a}", "1", ":"],
+    &["\u{a}", "\u{308}", "1", ":"],
+    &["\u{a}", "1", "'"],
+    &["\u{a}", "\u{308}", "1", "'"],
+    &["\u{a}", "1", ","],
+    &["\u{a}", "\u{308}", "1", ","],
+    &["\u{a}", "1", ".\u{2060}"],
+    &["\u{a}", "\u{308}", "1", ".\u{2060}"],
+    &["\u{b}", "\u{1}"],
+    &["\u{b}", "\u{308}", "\u{1}"],
+    &["\u{b}", "\u{d}"],
+    &["\u{b}", "\u{308}", "\u{d}"],
+    &["\u{b}", "\u{a}"],
+    &["\u{b}", "\u{308}", "\u{a}"],
+    &["\u{b}", "\u{b}"],
+    &["\u{b}", "\u{308}", "\u{b}"],
+    &["\u{b}", "\u{3031}"],
+    &["\u{b}", "\u{308}", "\u{3031}"],
+    &["\u{b}", "A"],
+    &["\u{b}", "\u{308}", "A"],
+    &["\u{b}", ":"],
+    &["\u{b}", "\u{308}", ":"],
+    &["\u{b}", ","],
+    &["\u{b}", "\u{308}", ","],
+    &["\u{b}", "."],
+    &["\u{b}", "\u{308}", "."],
+    &["\u{b}", "0"],
+    &["\u{b}", "\u{308}", "0"],
+    &["\u{b}", "_"],
+    &["\u{b}", "\u{308}", "_"],
+    &["\u{b}", "\u{1f1e6}"],
+    &["\u{b}", "\u{308}", "\u{1f1e6}"],
+    &["\u{b}", "\u{5d0}"],
+    &["\u{b}", "\u{308}", "\u{5d0}"],
+    &["\u{b}", "\u{22}"],
+    &["\u{b}", "\u{308}", "\u{22}"],
+    &["\u{b}", "'"],
+    &["\u{b}", "\u{308}", "'"],
+    &["\u{b}", "\u{231a}"],
+    &["\u{b}", "\u{308}", "\u{231a}"],
+    &["\u{b}", " "],
+    &["\u{b}", "\u{308}", " "],
+    &["\u{b}", "\u{ad}"],
+    &["\u{b}", "\u{308}\u{ad}"],
+    &["\u{b}", "\u{300}"],
+    &["\u{b}", "\u{308}\u{300}"],
+    &["\u{b}", "\u{200d}"],
+    &["\u{b}", "\u{308}\u{200d}"],
+    &["\u{b}", "a\u{2060}"],
+    &["\u{b}", "\u{308}", "a\u{2060}"],
+    &["\u{b}", "a", ":"],
+    &["\u{b}", "\u{308}", "a", ":"],
+    &["\u{b}", "a", "'"],
+    &["\u{b}", "\u{308}", "a", "'"],
+    &["\u{b}", "a", "'\u{2060}"],
+    &["\u{b}", "\u{308}", "a", "'\u{2060}"],
+    &["\u{b}", "a", ","],
+    &["\u{b}", "\u{308}", "a", ","],
+    &["\u{b}", "1", ":"],
+    &["\u{b}", "\u{308}", "1", ":"],
+    &["\u{b}", "1", "'"],
+    &["\u{b}", "\u{308}", "1", "'"],
+    &["\u{b}", "1", ","],
+    &["\u{b}", "\u{308}", "1", ","],
+    &["\u{b}", "1", ".\u{2060}"],
+    &["\u{b}", "\u{308}", "1", ".\u{2060}"],
+    &["\u{3031}", "\u{1}"],
+    &["\u{3031}\u{308}", "\u{1}"],
+    &["\u{3031}", "\u{d}"],
+    &["\u{3031}\u{308}", "\u{d}"],
+    &["\u{3031}", "\u{a}"],
+    &["\u{3031}\u{308}", "\u{a}"],
+    &["\u{3031}", "\u{b}"],
+    &["\u{3031}\u{308}", "\u{b}"],
+    &["\u{3031}\u{3031}"],
+    &["\u{3031}\u{308}\u{3031}"],
+    &["\u{3031}", "A"],
+    &["\u{3031}\u{308}", "A"],
+    &["\u{3031}", ":"],
+    &["\u{3031}\u{308}", ":"],
+    &["\u{3031}", ","],
+    &["\u{3031}\u{308}", ","],
+    &["\u{3031}", "."],
+    &["\u{3031}\u{308}", "."],
+    &["\u{3031}", "0"],
+    &["\u{3031}\u{308}", "0"],
+    &["\u{3031}_"],
+    &["\u{3031}\u{308}_"],
+    &["\u{3031}", "\u{1f1e6}"],
+    &["\u{3031}\u{308}", "\u{1f1e6}"],
+    &["\u{3031}", "\u{5d0}"],
+    &["\u{3031}\u{308}", "\u{5d0}"],
+    &["\u{3031}", "\u{22}"],
+    &["\u{3031}\u{308}", "\u{22}"],
+    &["\u{3031}", "'"],
+    &["\u{3031}\u{308}", "'"],
+    &["\u{3031}", "\u{231a}"],
+    &["\u{3031}\u{308}", "\u{231a}"],
+    &["\u{3031}", " "],
+    &["\u{3031}\u{308}", " "],
+    &["\u{3031}\u{ad}"],
+    &["\u{3031}\u{308}\u{ad}"],
+    &["\u{3031}\u{300}"],
+    &["\u{3031}\u{308}\u{300}"],
+    &["\u{3031}\u{200d}"],
+    &["\u{3031}\u{308}\u{200d}"],
+    &["\u{3031}", "a\u{2060}"],
+    &["\u{3031}\u{308}", "a\u{2060}"],
+    &["\u{3031}", "a", ":"],
+    &["\u{3031}\u{308}", "a", ":"],
+    &["\u{3031}", "a", "'"],
+    &["\u{3031}\u{308}", "a", "'"],
+    &["\u{3031}", "a", "'\u{2060}"],
+    &["\u{3031}\u{308}", "a", "'\u{2060}"],
+    &["\u{3031}", "a", ","],
+    &["\u{3031}\u{308}", "a", ","],
+    &["\u{3031}", "1", ":"],
+    &["\u{3031}\u{308}", "1", ":"],
+    &["\u{3031}", "1", "'"],
+    &["\u{3031}\u{308}", "1", "'"],
+    &["\u{3031}", "1", ","],
+    &["\u{3031}\u{308}", "1", ","],
+    &["\u{3031}", "1", ".\u{2060}"],
+    &["\u{3031}\u{308}", "1", ".\u{2060}"],
+    &["A", "\u{1}"],
+    &["A\u{308}", "\u{1}"],
+    &["A", "\u{d}"],
+    &["A\u{308}", "\u{d}"],
+    &["A", "\u{a}"],
+    &["A\u{308}", "\u{a}"],
+    &["A", "\u{b}"],
+    &["A\u{308}", "\u{b}"],
+    &["A", "\u{3031}"],
+    &["A\u{308}", "\u{3031}"],
+    &["AA"],
+    &["A\u{308}A"],
+    &["A", ":"],
+    &["A\u{308}", ":"],
+    &["A", ","],
+    &["A\u{308}", ","],
+    &["A", "."],
+    &["A\u{308}", "."],
+    &["A0"],
+    &["A\u{308}0"],
+    &["A_"],
+    &["A\u{308}_"],
+    &["A", "\u{1f1e6}"],
+    &["A\u{308}", "\u{1f1e6}"],
+    &["A\u{5d0}"],
+    &["A\u{308}\u{5d0}"],
+    &["A", "\u{22}"],
+    &["A\u{308}", "\u{22}"],
+    &["A", "'"],
+    &["A\u{308}", "'"],
+    &["A", "\u{231a}"],
+    &["A\u{308}", "\u{231a}"],
+    &["A", " "],
+    &["A\u{308}", " "],
+    &["A\u{ad}"],
+    &["A\u{308}\u{ad}"],
+    &["A\u{300}"],
+    &["A\u{308}\u{300}"],
+    &["A\u{200d}"],
+    &["A\u{308}\u{200d}"],
+    &["Aa\u{2060}"],
+    &["A\u{308}a\u{2060}"],
+    &["Aa", ":"],
+    &["A\u{308}a", ":"],
+    &["Aa", "'"],
+    &["A\u{308}a", "'"],
+    &["Aa", "'\u{2060}"],
+    &["A\u{308}a", "'\u{2060}"],
+    &["Aa", ","],
+    &["A\u{308}a", ","],
+    &["A1", ":"],
+    &["A\u{308}1", ":"],
+    &["A1", "'"],
+    &["A\u{308}1", "'"],
+    &["A1", ","],
+    &["A\u{308}1", ","],
+    &["A1", ".\u{2060}"],
+    &["A\u{308}1", ".\u{2060}"],
+    &[":", "\u{1}"],
+    &[":\u{308}", "\u{1}"],
+    &[":", "\u{d}"],
+    &[":\u{308}", "\u{d}"],
+    &[":", "\u{a}"],
+    &[":\u{308}", "\u{a}"],
+    &[":", "\u{b}"],
+    &[":\u{308}", "\u{b}"],
+    &[":", "\u{3031}"],
+    &[":\u{308}", "\u{3031}"],
+    &[":", "A"],
+    &[":\u{308}", "A"],
+    &[":", ":"],
+    &[":\u{308}", ":"],
+    &[":", ","],
+    &[":\u{308}", ","],
+    &[":", "."],
+    &[":\u{308}", "."],
+    &[":", "0"],
+    &[":\u{308}", "0"],
+    &[":", "_"],
+    &[":\u{308}", "_"],
+    &[":", "\u{1f1e6}"],
+    &[":\u{308}", "\u{1f1e6}"],
+    &[":", "\u{5d0}"],
+    &[":\u{308}", "\u{5d0}"],
+    &[":", "\u{22}"],
+    &[":\u{308}", "\u{22}"],
+    &[":", "'"],
+    &[":\u{308}", "'"],
+    &[":", "\u{231a}"],
+    &[":\u{308}", "\u{231a}"],
+    &[":", " "],
+    &[":\u{308}", " "],
+    &[":\u{ad}"],
+    &[":\u{308}\u{ad}"],
+    &[":\u{300}"],
+    &[":\u{308}\u{300}"],
+    &[":\u{200d}"],
+    &[":\u{308}\u{200d}"],
+    &[":", "a\u{2060}"],
+    &[":\u{308}", "a\u{2060}"],
+    &[":", "a", ":"],
+    &[":\u{308}", "a", ":"],
+    &[":", "a", "'"],
+    &[":\u{308}", "a", "'"],
+    &[":", "a", "'\u{2060}"],
+    &[":\u{308}", "a", "'\u{2060}"],
+    &[":", "a", ","],
+    &[":\u{308}", "a", ","],
+    &[":", "1", ":"],
+    &[":\u{308}", "1", ":"],
+    &[":", "1", "'"],
+    &[":\u{308}", "1", "'"],
+    &[":", "1", ","],
+    &[":\u{308}", "1", ","],
+    &[":", "1", ".\u{2060}"],
+    &[":\u{308}", "1", ".\u{2060}"],
+    &[",", "\u{1}"],
+    &[",\u{308}", "\u{1}"],
+    &[",", "\u{d}"],
+    &[",\u{308}", "\u{d}"],
+    &[",", "\u{a}"],
+    &[",\u{308}", "\u{a}"],
+    &[",", "\u{b}"],
+    &[",\u{308}", "\u{b}"],
+    &[",", "\u{3031}"],
+    &[",\u{308}", "\u{3031}"],
+    &[",", "A"],
+    &[",\u{308}", "A"],
+    &[",", ":"],
+    &[",\u{308}", ":"],
+    &[",", ","],
+    &[",\u{308}", ","],
+    &[",", "."],
+    &[",\u{308}", "."],
+    &[",", "0"],
+    &[",\u{308}", "0"],
+    &[",", "_"],
+    &[",\u{308}", "_"],
+    &[",", "\u{1f1e6}"],
+    &[",\u{308}", "\u{1f1e6}"],
+    &[",", "\u{5d0}"],
+    &[",\u{308}", "\u{5d0}"],
+    &[",", "\u{22}"],
+    &[",\u{308}", "\u{22}"],
+    &[",", "'"],
+    &[",\u{308}", "'"],
+    &[",", "\u{231a}"],
+    &[",\u{308}", "\u{231a}"],
+    &[",", " "],
+    &[",\u{308}", " "],
+    &[",\u{ad}"],
+    &[",\u{308}\u{ad}"],
+    &[",\u{300}"],
+    &[",\u{308}\u{300}"],
+    &[",\u{200d}"],
+    &[",\u{308}\u{200d}"],
+    &[",", "a\u{2060}"],
+    &[",\u{308}", "a\u{2060}"],
+    &[",", "a", ":"],
+    &[",\u{308}", "a", ":"],
+    &[",", "a", "'"],
+    &[",\u{308}", "a", "'"],
+    &[",", "a", "'\u{2060}"],
+    &[",\u{308}", "a", "'\u{2060}"],
+    &[",", "a", ","],
+    &[",\u{308}", "a", ","],
+    &[",", "1", ":"],
+    &[",\u{308}", "1", ":"],
+    &[",", "1", "'"],
+    &[",\u{308}", "1", "'"],
+    &[",", "1", ","],
+    &[",\u{308}", "1", ","],
+    &[",", "1", ".\u{2060}"],
+    &[",\u{308}", "1", ".\u{2060}"],
+    &[".", "\u{1}"],
+    &[".\u{308}", "\u{1}"],
+    &[".", "\u{d}"],
+    &[".\u{308}", "\u{d}"],
+    &[".", "\u{a}"],
+    &[".\u{308}", "\u{a}"],
+    &[".", "\u{b}"],
+    &[".\u{308}", "\u{b}"],
+    &[".", "\u{3031}"],
+    &[".\u{308}", "\u{3031}"],
+    &[".", "A"],
+    &[".\u{308}", "A"],
+    &[".", ":"],
+    &[".\u{308}", ":"],
+    &[".", ","],
+    &[".\u{308}", ","],
+    &[".", "."],
+    &[".\u{308}", "."],
+    &[".", "0"],
+    &[".\u{308}", "0"],
+    &[".", "_"],
+    &[".\u{308}", "_"],
+    &[".", "\u{1f1e6}"],
+    &[".\u{308}", "\u{1f1e6}"],
+    &[".", "\u{5d0}"],
+    &[".\u{308}", "\u{5d0}"],
+    &[".", "\u{22}"],
+    &[".\u{308}", "\u{22}"],
+    &[".", "'"],
+    &[".\u{308}", "'"],
+    &[".", "\u{231a}"],
+    &[".\u{308}", "\u{231a}"],
+    &[".", " "],
+    &[".\u{308}", " "],
+    &[".\u{ad}"],
+    &[".\u{308}\u{ad}"],
+    &[".\u{300}"],
+    &[".\u{308}\u{300}"],
+    &[".\u{200d}"],
+    &[".\u{308}\u{200d}"],
+    &[".", "a\u{2060}"],
+    &[".\u{308}", "a\u{2060}"],
+    &[".", "a", ":"],
+    &[".\u{308}", "a", ":"],
+    &[".", "a", "'"],
+    &[".\u{308}", "a", "'"],
+    &[".", "a", "'\u{2060}"],
+    &[".\u{308}", "a", "'\u{2060}"],
+    &[".", "a", ","],
+    &[".\u{308}", "a", ","],
+    &[".", "1", ":"],
+    &[".\u{308}", "1", ":"],
+    &[".", "1", "'"],
+    &[".\u{308}", "1", "'"],
+    &[".", "1", ","],
+    &[".\u{308}", "1", ","],
+    &[".", "1", ".\u{2060}"],
+    &[".\u{308}", "1", ".\u{2060}"],
+    &["0", "\u{1}"],
+    &["0\u{308}", "\u{1}"],
+    &["0", "\u{d}"],
+    &["0\u{308}", "\u{d}"],
+    &["0", "\u{a}"],
+    &["0\u{308}", "\u{a}"],
+    &["0", "\u{b}"],
+    &["0\u{308}", "\u{b}"],
+    &["0", "\u{3031}"],
+    &["0\u{308}", "\u{3031}"],
+    &["0A"],
+    &["0\u{308}A"],
+    &["0", ":"],
+    &["0\u{308}", ":"],
+    &["0", ","],
+    &["0\u{308}", ","],
+    &["0", "."],
+    &["0\u{308}", "."],
+    &["00"],
+    &["0\u{308}0"],
+    &["0_"],
+    &["0\u{308}_"],
+    &["0", "\u{1f1e6}"],
+    &["0\u{308}", "\u{1f1e6}"],
+    &["0\u{5d0}"],
+    &["0\u{308}\u{5d0}"],
+    &["0", "\u{22}"],
+    &["0\u{308}", "\u{22}"],
+    &["0", "'"],
+    &["0\u{308}", "'"],
+    &["0", "\u{231a}"],
+    &["0\u{308}", "\u{231a}"],
+    &["0", " "],
+    &["0\u{308}", " "],
+    &["0\u{ad}"],
+    &["0\u{308}\u{ad}"],
+    &["0\u{300}"],
+    &["0\u{308}\u{300}"],
+    &["0\u{200d}"],
+    &["0\u{308}\u{200d}"],
+    &["0a\u{2060}"],
+    &["0\u{308}a\u{2060}"],
+    &["0a", ":"],
+    &["0\u{308}a", ":"],
+    &["0a", "'"],
+    &["0\u{308}a", "'"],
+    &["0a", "'\u{2060}"],
+    &["0\u{308}a", "'\u{2060}"],
+    &["0a", ","],
+    &["0\u{308}a", ","],
+    &["01", ":"],
+    &["0\u{308}1", ":"],
+    &["01", "'"],
+    &["0\u{308}1", "'"],
+    &["01", ","],
+    &["0\u{308}1", ","],
+    &["01", ".\u{2060}"],
+    &["0\u{308}1", ".\u{2060}"],
+    &["_", "\u{1}"],
+    &["_\u{308}", "\u{1}"],
+    &["_", "\u{d}"],
+    &["_\u{308}", "\u{d}"],
+    &["_", "\u{a}"],
+    &["_\u{308}", "\u{a}"],
+    &["_", "\u{b}"],
+    &["_\u{308}", "\u{b}"],
+    &["_\u{3031}"],
+    &["_\u{308}\u{3031}"],
+    &["_A"],
+    &["_\u{308}A"],
+    &["_", ":"],
+    &["_\u{308}", ":"],
+    &["_", ","],
+    &["_\u{308}", ","],
+    &["_", "."],
+    &["_\u{308}", "."],
+    &["_0"],
+    &["_\u{308}0"],
+    &["__"],
+    &["_\u{308}_"],
+    &["_", "\u{1f1e6}"],
+    &["_\u{308}", "\u{1f1e6}"],
+    &["_\u{5d0}"],
+    &["_\u{308}\u{5d0}"],
+    &["_", "\u{22}"],
+    &["_\u{308}", "\u{22}"],
+    &["_", "'"],
+    &["_\u{308}", "'"],
+    &["_", "\u{231a}"],
+    &["_\u{308}", "\u{231a}"],
+    &["_", " "],
+    &["_\u{308}", " "],
+    &["_\u{ad}"],
+    &["_\u{308}\u{ad}"],
+    &["_\u{300}"],
+    &["_\u{308}\u{300}"],
+    &["_\u{200d}"],
+    &["_\u{308}\u{200d}"],
+    &["_a\u{2060}"],
+    &["_\u{308}a\u{2060}"],
+    &["_a", ":"],
+    &["_\u{308}a", ":"],
+    &["_a", "'"],
+    &["_\u{308}a", "'"],
+    &["_a", "'\u{2060}"],
+    &["_\u{308}a", "'\u{2060}"],
+    &["_a", ","],
+    &["_\u{308}a", ","],
+    &["_1", ":"],
+    &["_\u{308}1", ":"],
+    &["_1", "'"],
+    &["_\u{308}1", "'"],
+    &["_1", ","],
+    &["_\u{308}1", ","],
+    &["_1", ".\u{2060}"],
+    &["_\u{308}1", ".\u{2060}"],
+    &["\u{1f1e6}", "\u{1}"],
+    &["\u{1f1e6}\u{308}", "\u{1}"],
+    &["\u{1f1e6}", "\u{d}"],
+    &["\u{1f1e6}\u{308}", "\u{d}"],
+    &["\u{1f1e6}", "\u{a}"],
+    &["\u{1f1e6}\u{308}", "\u{a}"],
+    &["\u{1f1e6}", "\u{b}"],
+    &["\u{1f1e6}\u{308}", "\u{b}"],
+    &["\u{1f1e6}", "\u{3031}"],
+    &["\u{1f1e6}\u{308}", "\u{3031}"],
+    &["\u{1f1e6}", "A"],
+    &["\u{1f1e6}\u{308}", "A"],
+    &["\u{1f1e6}", ":"],
+    &["\u{1f1e6}\u{308}", ":"],
+    &["\u{1f1e6}", ","],
+    &["\u{1f1e6}\u{308}", ","],
+    &["\u{1f1e6}", "."],
+    &["\u{1f1e6}\u{308}", "."],
+    &["\u{1f1e6}", "0"],
+    &["\u{1f1e6}\u{308}", "0"],
+    &["\u{1f1e6}", "_"],
+    &["\u{1f1e6}\u{308}", "_"],
+    &["\u{1f1e6}\u{1f1e6}"],
+    &["\u{1f1e6}\u{308}\u{1f1e6}"],
+    &["\u{1f1e6}", "\u{5d0}"],
+    &["\u{1f1e6}\u{308}", "\u{5d0}"],
+    &["\u{1f1e6}", "\u{22}"],
+    &["\u{1f1e6}\u{308}", "\u{22}"],
+    &["\u{1f1e6}", "'"],
+    &["\u{1f1e6}\u{308}", "'"],
+    &["\u{1f1e6}", "\u{231a}"],
+    &["\u{1f1e6}\u{308}", "\u{231a}"],
+    &["\u{1f1e6}", " "],
+    &["\u{1f1e6}\u{308}", " "],
+    &["\u{1f1e6}\u{ad}"],
+    &["\u{1f1e6}\u{308}\u{ad}"],
+    &["\u{1f1e6}\u{300}"],
+    &["\u{1f1e6}\u{308}\u{300}"],
+    &["\u{1f1e6}\u{200d}"],
+    &["\u{1f1e6}\u{308}\u{200d}"],
+    &["\u{1f1e6}", "a\u{2060}"],
+    &["\u{1f1e6}\u{308}", "a\u{2060}"],
+    &["\u{1f1e6}", "a", ":"],
+    &["\u{1f1e6}\u{308}", "a", ":"],
+    &["\u{1f1e6}", "a", "'"],
+    &["\u{1f1e6}\u{308}", "a", "'"],
+    &["\u{1f1e6}", "a", "'\u{2060}"],
+    &["\u{1f1e6}\u{308}", "a", "'\u{2060}"],
+    &["\u{1f1e6}", "a", ","],
+    &["\u{1f1e6}\u{308}", "a", ","],
+    &["\u{1f1e6}", "1", ":"],
+    &["\u{1f1e6}\u{308}", "1", ":"],
+    &["\u{1f1e6}", "1", "'"],
+    &["\u{1f1e6}\u{308}", "1", "'"],
+    &["\u{1f1e6}", "1", ","],
+    &["\u{1f1e6}\u{308}", "1", ","],
+    &["\u{1f1e6}", "1", ".\u{2060}"],
+    &["\u{1f1e6}\u{308}", "1", ".\u{2060}"],
+    &["\u{5d0}", "\u{1}"],
+    &["\u{5d0}\u{308}", "\u{1}"],
+    &["\u{5d0}", "\u{d}"],
+    &["\u{5d0}\u{308}", "\u{d}"],
+    &["\u{5d0}", "\u{a}"],
+    &["\u{5d0}\u{308}", "\u{a}"],
+    &["\u{5d0}", "\u{b}"],
+    &["\u{5d0}\u{308}", "\u{b}"],
+    &["\u{5d0}", "\u{3031}"],
+    &["\u{5d0}\u{308}", "\u{3031}"],
+    &["\u{5d0}A"],
+    &["\u{5d0}\u{308}A"],
+    &["\u{5d0}", ":"],
+    &["\u{5d0}\u{308}", ":"],
+    &["\u{5d0}", ","],
+    &["\u{5d0}\u{308}", ","],
+    &["\u{5d0}", "."],
+    &["\u{5d0}\u{308}", "."],
+    &["\u{5d0}0"],
+    &["\u{5d0}\u{308}0"],
+    &["\u{5d0}_"],
+    &["\u{5d0}\u{308}_"],
+    &["\u{5d0}", "\u{1f1e6}"],
+    &["\u{5d0}\u{308}", "\u{1f1e6}"],
+    &["\u{5d0}\u{5d0}"],
+    &["\u{5d0}\u{308}\u{5d0}"],
+    &["\u{5d0}", "\u{22}"],
+    &["\u{5d0}\u{308}", "\u{22}"],
+    &["\u{5d0}'"],
+    &["\u{5d0}\u{308}'"],
+    &["\u{5d0}", "\u{231a}"],
+    &["\u{5d0}\u{308}", "\u{231a}"],
+    &["\u{5d0}", " "],
+    &["\u{5d0}\u{308}", " "],
+    &["\u{5d0}\u{ad}"],
+    &["\u{5d0}\u{308}\u{ad}"],
+    &["\u{5d0}\u{300}"],
+    &["\u{5d0}\u{308}\u{300}"],
+    &["\u{5d0}\u{200d}"],
+    &["\u{5d0}\u{308}\u{200d}"],
+    &["\u{5d0}a\u{2060}"],
+    &["\u{5d0}\u{308}a\u{2060}"],
+    &["\u{5d0}a", ":"],
+    &["\u{5d0}\u{308}a", ":"],
+    &["\u{5d0}a", "'"],
+    &["\u{5d0}\u{308}a", "'"],
+    &["\u{5d0}a", "'\u{2060}"],
+    &["\u{5d0}\u{308}a", "'\u{2060}"],
+    &["\u{5d0}a", ","],
+    &["\u{5d0}\u{308}a", ","],
+    &["\u{5d0}1", ":"],
+    &["\u{5d0}\u{308}1", ":"],
+    &["\u{5d0}1", "'"],
+    &["\u{5d0}\u{308}1", "'"],
+    &["\u{5d0}1", ","],
+    &["\u{5d0}\u{308}1", ","],
+    &["\u{5d0}1", ".\u{2060}"],
+    &["\u{5d0}\u{308}1", ".\u{2060}"],
+    &["\u{22}", "\u{1}"],
+    &["\u{22}\u{308}", "\u{1}"],
+    &["\u{22}", "\u{d}"],
+    &["\u{22}\u{308}", "\u{d}"],
+    &["\u{22}", "\u{a}"],
+    &["\u{22}\u{308}", "\u{a}"],
+    &["\u{22}", "\u{b}"],
+    &["\u{22}\u{308}", "\u{b}"],
+    &["\u{22}", "\u{3031}"],
+    &["\u{22}\u{308}", "\u{3031}"],
+    &["\u{22}", "A"],
+    &["\u{22}\u{308}", "A"],
+    &["\u{22}", ":"],
+    &["\u{22}\u{308}", ":"],
+    &["\u{22}", ","],
+    &["\u{22}\u{308}", ","],
+    &["\u{22}", "."],
+    &["\u{22}\u{308}", "."],
+    &["\u{22}", "0"],
+    &["\u{22}\u{308}", "0"],
+    &["\u{22}", "_"],
+    &["\u{22}\u{308}", "_"],
+    &["\u{22}", "\u{1f1e6}"],
+    &["\u{22}\u{308}", "\u{1f1e6}"],
+    &["\u{22}", "\u{5d0}"],
+    &["\u{22}\u{308}", "\u{5d0}"],
+    &["\u{22}", "\u{22}"],
+    &["\u{22}\u{308}", "\u{22}"],
+    &["\u{22}", "'"],
+    &["\u{22}\u{308}", "'"],
+    &["\u{22}", "\u{231a}"],
+    &["\u{22}\u{308}", "\u{231a}"],
+    &["\u{22}", " "],
+    &["\u{22}\u{308}", " "],
+    &["\u{22}\u{ad}"],
+    &["\u{22}\u{308}\u{ad}"],
+    &["\u{22}\u{300}"],
+    &["\u{22}\u{308}\u{300}"],
+    &["\u{22}\u{200d}"],
+    &["\u{22}\u{308}\u{200d}"],
+    &["\u{22}", "a\u{2060}"],
+    &["\u{22}\u{308}", "a\u{2060}"],
+    &["\u{22}", "a", ":"],
+    &["\u{22}\u{308}", "a", ":"],
+    &["\u{22}", "a", "'"],
+    &["\u{22}\u{308}", "a", "'"],
+    &["\u{22}", "a", "'\u{2060}"],
+    &["\u{22}\u{308}", "a", "'\u{2060}"],
+    &["\u{22}", "a", ","],
+    &["\u{22}\u{308}", "a", ","],
+    &["\u{22}", "1", ":"],
+    &["\u{22}\u{308}", "1", ":"],
+    &["\u{22}", "1", "'"],
+    &["\u{22}\u{308}", "1", "'"],
+    &["\u{22}", "1", ","],
+    &["\u{22}\u{308}", "1", ","],
+    &["\u{22}", "1", ".\u{2060}"],
+    &["\u{22}\u{308}", "1", ".\u{2060}"],
+    &["'", "\u{1}"],
+    &["'\u{308}", "\u{1}"],
+    &["'", "\u{d}"],
+    &["'\u{308}", "\u{d}"],
+    &["'", "\u{a}"],
+    &["'\u{308}", "\u{a}"],
+    &["'", "\u{b}"],
+    &["'\u{308}", "\u{b}"],
+    &["'", "\u{3031}"],
+    &["'\u{308}", "\u{3031}"],
+    &["'", "A"],
+    &["'\u{308}", "A"],
+    &["'", ":"],
+    &["'\u{308}", ":"],
+    &["'", ","],
+    &["'\u{308}", ","],
+    &["'", "."],
+    &["'\u{308}", "."],
+    &["'", "0"],
+    &["'\u{308}", "0"],
+    &["'", "_"],
+    &["'\u{308}", "_"],
+    &["'", "\u{1f1e6}"],
+    &["'\u{308}", "\u{1f1e6}"],
+    &["'", "\u{5d0}"],
+    &["'\u{308}", "\u{5d0}"],
+    &["'", "\u{22}"],
+    &["'\u{308}", "\u{22}"],
+    &["'", "'"],
+    &["'\u{308}", "'"],
+    &["'", "\u{231a}"],
+    &["'\u{308}", "\u{231a}"],
+    &["'", " "],
+    &["'\u{308}", " "],
+    &["'\u{ad}"],
+    &["'\u{308}\u{ad}"],
+    &["'\u{300}"],
+    &["'\u{308}\u{300}"],
+    &["'\u{200d}"],
+    &["'\u{308}\u{200d}"],
+    &["'", "a\u{2060}"],
+    &["'\u{308}", "a\u{2060}"],
+    &["'", "a", ":"],
+    &["'\u{308}", "a", ":"],
+    &["'", "a", "'"],
+    &["'\u{308}", "a", "'"],
+    &["'", "a", "'\u{2060}"],
+    &["'\u{308}", "a", "'\u{2060}"],
+    &["'", "a", ","],
+    &["'\u{308}", "a", ","],
+    &["'", "1", ":"],
+    &["'\u{308}", "1", ":"],
+    &["'", "1", "'"],
+    &["'\u{308}", "1", "'"],
+    &["'", "1", ","],
+    &["'\u{308}", "1", ","],
+    &["'", "1", ".\u{2060}"],
+    &["'\u{308}", "1", ".\u{2060}"],
+    &["\u{231a}", "\u{1}"],
+    &["\u{231a}\u{308}", "\u{1}"],
+    &["\u{231a}", "\u{d}"],
+    &["\u{231a}\u{308}", "\u{d}"],
+    &["\u{231a}", "\u{a}"],
+    &["\u{231a}\u{308}", "\u{a}"],
+    &["\u{231a}", "\u{b}"],
+    &["\u{231a}\u{308}", "\u{b}"],
+    &["\u{231a}", "\u{3031}"],
+    &["\u{231a}\u{308}", "\u{3031}"],
+    &["\u{231a}", "A"],
+    &["\u{231a}\u{308}", "A"],
+    &["\u{231a}", ":"],
+    &["\u{231a}\u{308}", ":"],
+    &["\u{231a}", ","],
+    &["\u{231a}\u{308}", ","],
+    &["\u{231a}", "."],
+    &["\u{231a}\u{308}", "."],
+    &["\u{231a}", "0"],
+    &["\u{231a}\u{308}", "0"],
+    &["\u{231a}", "_"],
+    &["\u{231a}\u{308}", "_"],
+    &["\u{231a}", "\u{1f1e6}"],
+    &["\u{231a}\u{308}", "\u{1f1e6}"],
+    &["\u{231a}", "\u{5d0}"],
+    &["\u{231a}\u{308}", "\u{5d0}"],
+    &["\u{231a}", "\u{22}"],
+    &["\u{231a}\u{308}", "\u{22}"],
+    &["\u{231a}", "'"],
+    &["\u{231a}\u{308}", "'"],
+    &["\u{231a}", "\u{231a}"],
+    &["\u{231a}\u{308}", "\u{231a}"],
+    &["\u{231a}", " "],
+    &["\u{231a}\u{308}", " "],
+    &["\u{231a}\u{ad}"],
+    &["\u{231a}\u{308}\u{ad}"],
+    &["\u{231a}\u{300}"],
+    &["\u{231a}\u{308}\u{300}"],
+    &["\u{231a}\u{200d}"],
+    &["\u{231a}\u{308}\u{200d}"],
+    &["\u{231a}", "a\u{2060}"],
+    &["\u{231a}\u{308}", "a\u{2060}"],
+    &["\u{231a}", "a", ":"],
+    &["\u{231a}\u{308}", "a", ":"],
+    &["\u{231a}", "a", "'"],
+    &["\u{231a}\u{308}", "a", "'"],
+    &["\u{231a}", "a", "'\u{2060}"],
+    &["\u{231a}\u{308}", "a", "'\u{2060}"],
+    &["\u{231a}", "a", ","],
+    &["\u{231a}\u{308}", "a", ","],
+    &["\u{231a}", "1", ":"],
+    &["\u{231a}\u{308}", "1", ":"],
+    &["\u{231a}", "1", "'"],
+    &["\u{231a}\u{308}", "1", "'"],
+    &["\u{231a}", "1", ","],
+    &["\u{231a}\u{308}", "1", ","],
+    &["\u{231a}", "1", ".\u{2060}"],
+    &["\u{231a}\u{308}", "1", ".\u{2060}"],
+    &[" ", "\u{1}"],
+    &[" \u{308}", "\u{1}"],
+    &[" ", "\u{d}"],
+    &[" \u{308}", "\u{d}"],
+    &[" ", "\u{a}"],
+    &[" \u{308}", "\u{a}"],
+    &[" ", "\u{b}"],
+    &[" \u{308}", "\u{b}"],
+    &[" ", "\u{3031}"],
+    &[" \u{308}", "\u{3031}"],
+    &[" ", "A"],
+    &[" \u{308}", "A"],
+    &[" ", ":"],
+    &[" \u{308}", ":"],
+    &[" ", ","],
+    &[" \u{308}", ","],
+    &[" ", "."],
+    &[" \u{308}", "."],
+    &[" ", "0"],
+    &[" \u{308}", "0"],
+    &[" ", "_"],
+    &[" \u{308}", "_"],
+    &[" ", "\u{1f1e6}"],
+    &[" \u{308}", "\u{1f1e6}"],
+    &[" ", "\u{5d0}"],
+    &[" \u{308}", "\u{5d0}"],
+    &[" ", "\u{22}"],
+    &[" \u{308}", "\u{22}"],
+    &[" ", "'"],
+    &[" \u{308}", "'"],
+    &[" ", "\u{231a}"],
+    &[" \u{308}", "\u{231a}"],
+    &["  "],
+    &[" \u{308}", " "],
+    &[" \u{ad}"],
+    &[" \u{308}\u{ad}"],
+    &[" \u{300}"],
+    &[" \u{308}\u{300}"],
+    &[" \u{200d}"],
+    &[" \u{308}\u{200d}"],
+    &[" ", "a\u{2060}"],
+    &[" \u{308}", "a\u{2060}"],
+    &[" ", "a", ":"],
+    &[" \u{308}", "a", ":"],
+    &[" ", "a", "'"],
+    &[" \u{308}", "a", "'"],
+    &[" ", "a", "'\u{2060}"],
+    &[" \u{308}", "a", "'\u{2060}"],
+    &[" ", "a", ","],
+    &[" \u{308}", "a", ","],
+    &[" ", "1", ":"],
+    &[" \u{308}", "1", ":"],
+    &[" ", "1", "'"],
+    &[" \u{308}", "1", "'"],
+    &[" ", "1", ","],
+    &[" \u{308}", "1", ","],
+    &[" ", "1", ".\u{2060}"],
+    &[" \u{308}", "1", ".\u{2060}"],
+    &["\u{ad}", "\u{1}"],
+    &["\u{ad}\u{308}", "\u{1}"],
+    &["\u{ad}", "\u{d}"],
+    &["\u{ad}\u{308}", "\u{d}"],
+    &["\u{ad}", "\u{a}"],
+    &["\u{ad}\u{308}", "\u{a}"],
+    &["\u{ad}", "\u{b}"],
+    &["\u{ad}\u{308}", "\u{b}"],
+    &["\u{ad}", "\u{3031}"],
+    &["\u{ad}\u{308}", "\u{3031}"],
+    &["\u{ad}", "A"],
+    &["\u{ad}\u{308}", "A"],
+    &["\u{ad}", ":"],
+    &["\u{ad}\u{308}", ":"],
+    &["\u{ad}", ","],
+    &["\u{ad}\u{308}", ","],
+    &["\u{ad}", "."],
+    &["\u{ad}\u{308}", "."],
+    &["\u{ad}", "0"],
+    &["\u{ad}\u{308}", "0"],
+    &["\u{ad}", "_"],
+    &["\u{ad}\u{308}", "_"],
+    &["\u{ad}", "\u{1f1e6}"],
+    &["\u{ad}\u{308}", "\u{1f1e6}"],
+    &["\u{ad}", "\u{5d0}"],
+    &["\u{ad}\u{308}", "\u{5d0}"],
+    &["\u{ad}", "\u{22}"],
+    &["\u{ad}\u{308}", "\u{22}"],
+    &["\u{ad}", "'"],
+    &["\u{ad}\u{308}", "'"],
+    &["\u{ad}", "\u{231a}"],
+    &["\u{ad}\u{308}", "\u{231a}"],
+    &["\u{ad}", " "],
+    &["\u{ad}\u{308}", " "],
+    &["\u{ad}\u{ad}"],
+    &["\u{ad}\u{308}\u{ad}"],
+    &["\u{ad}\u{300}"],
+    &["\u{ad}\u{308}\u{300}"],
+    &["\u{ad}\u{200d}"],
+    &["\u{ad}\u{308}\u{200d}"],
+    &["\u{ad}", "a\u{2060}"],
+    &["\u{ad}\u{308}", "a\u{2060}"],
+    &["\u{ad}", "a", ":"],
+    &["\u{ad}\u{308}", "a", ":"],
+    &["\u{ad}", "a", "'"],
+    &["\u{ad}\u{308}", "a", "'"],
+    &["\u{ad}", "a", "'\u{2060}"],
+    &["\u{ad}\u{308}", "a", "'\u{2060}"],
+    &["\u{ad}", "a", ","],
+    &["\u{ad}\u{308}", "a", ","],
+    &["\u{ad}", "1", ":"],
+    &["\u{ad}\u{308}", "1", ":"],
+    &["\u{ad}", "1", "'"],
+    &["\u{ad}\u{308}", "1", "'"],
+    &["\u{ad}", "1", ","],
+    &["\u{ad}\u{308}", "1", ","],
+    &["\u{ad}", "1", ".\u{2060}"],
+    &["\u{ad}\u{308}", "1", ".\u{2060}"],
+    &["\u{300}", "\u{1}"],
+    &["\u{300}\u{308}", "\u{1}"],
+    &["\u{300}", "\u{d}"],
+    &["\u{300}\u{308}", "\u{d}"],
+    &["\u{300}", "\u{a}"],
+    &["\u{300}\u{308}", "\u{a}"],
+    &["\u{300}", "\u{b}"],
+    &["\u{300}\u{308}", "\u{b}"],
+    &["\u{300}", "\u{3031}"],
+    &["\u{300}\u{308}", "\u{3031}"],
+    &["\u{300}", "A"],
+    &["\u{300}\u{308}", "A"],
+    &["\u{300}", ":"],
+    &["\u{300}\u{308}", ":"],
+    &["\u{300}", ","],
+    &["\u{300}\u{308}", ","],
+    &["\u{300}", "."],
+    &["\u{300}\u{308}", "."],
+    &["\u{300}", "0"],
+    &["\u{300}\u{308}", "0"],
+    &["\u{300}", "_"],
+    &["\u{300}\u{308}", "_"],
+    &["\u{300}", "\u{1f1e6}"],
+    &["\u{300}\u{308}", "\u{1f1e6}"],
+    &["\u{300}", "\u{5d0}"],
+    &["\u{300}\u{308}", "\u{5d0}"],
+    &["\u{300}", "\u{22}"],
+    &["\u{300}\u{308}", "\u{22}"],
+    &["\u{300}", "'"],
+    &["\u{300}\u{308}", "'"],
+    &["\u{300}", "\u{231a}"],
+    &["\u{300}\u{308}", "\u{231a}"],
+    &["\u{300}", " "],
+    &["\u{300}\u{308}", " "],
+    &["\u{300}\u{ad}"],
+    &["\u{300}\u{308}\u{ad}"],
+    &["\u{300}\u{300}"],
+    &["\u{300}\u{308}\u{300}"],
+    &["\u{300}\u{200d}"],
+    &["\u{300}\u{308}\u{200d}"],
+    &["\u{300}", "a\u{2060}"],
+    &["\u{300}\u{308}", "a\u{2060}"],
+    &["\u{300}", "a", ":"],
+    &["\u{300}\u{308}", "a", ":"],
+    &["\u{300}", "a", "'"],
+    &["\u{300}\u{308}", "a", "'"],
+    &["\u{300}", "a", "'\u{2060}"],
+    &["\u{300}\u{308}", "a", "'\u{2060}"],
+    &["\u{300}", "a", ","],
+    &["\u{300}\u{308}", "a", ","],
+    &["\u{300}", "1", ":"],
+    &["\u{300}\u{308}", "1", ":"],
+    &["\u{300}", "1", "'"],
+    &["\u{300}\u{308}", "1", "'"],
+    &["\u{300}", "1", ","],
+    &["\u{300}\u{308}", "1", ","],
+    &["\u{300}", "1", ".\u{2060}"],
+    &["\u{300}\u{308}", "1", ".\u{2060}"],
+    &["\u{200d}", "\u{1}"],
+    &["\u{200d}\u{308}", "\u{1}"],
+    &["\u{200d}", "\u{d}"],
+    &["\u{200d}\u{308}", "\u{d}"],
+    &["\u{200d}", "\u{a}"],
+    &["\u{200d}\u{308}", "\u{a}"],
+    &["\u{200d}", "\u{b}"],
+    &["\u{200d}\u{308}", "\u{b}"],
+    &["\u{200d}", "\u{3031}"],
+    &["\u{200d}\u{308}", "\u{3031}"],
+    &["\u{200d}", "A"],
+    &["\u{200d}\u{308}", "A"],
+    &["\u{200d}", ":"],
+    &["\u{200d}\u{308}", ":"],
+    &["\u{200d}", ","],
+    &["\u{200d}\u{308}", ","],
+    &["\u{200d}", "."],
+    &["\u{200d}\u{308}", "."],
+    &["\u{200d}", "0"],
+    &["\u{200d}\u{308}", "0"],
+    &["\u{200d}", "_"],
+    &["\u{200d}\u{308}", "_"],
+    &["\u{200d}", "\u{1f1e6}"],
+    &["\u{200d}\u{308}", "\u{1f1e6}"],
+    &["\u{200d}", "\u{5d0}"],
+    &["\u{200d}\u{308}", "\u{5d0}"],
+    &["\u{200d}", "\u{22}"],
+    &["\u{200d}\u{308}", "\u{22}"],
+    &["\u{200d}", "'"],
+    &["\u{200d}\u{308}", "'"],
+    &["\u{200d}\u{231a}"],
+    &["\u{200d}\u{308}", "\u{231a}"],
+    &["\u{200d}", " "],
+    &["\u{200d}\u{308}", " "],
+    &["\u{200d}\u{ad}"],
+    &["\u{200d}\u{308}\u{ad}"],
+    &["\u{200d}\u{300}"],
+    &["\u{200d}\u{308}\u{300}"],
+    &["\u{200d}\u{200d}"],
+    &["\u{200d}\u{308}\u{200d}"],
+    &["\u{200d}", "a\u{2060}"],
+    &["\u{200d}\u{308}", "a\u{2060}"],
+    &["\u{200d}", "a", ":"],
+    &["\u{200d}\u{308}", "a", ":"],
+    &["\u{200d}", "a", "'"],
+    &["\u{200d}\u{308}", "a", "'"],
+    &["\u{200d}", "a", "'\u{2060}"],
+    &["\u{200d}\u{308}", "a", "'\u{2060}"],
+    &["\u{200d}", "a", ","],
+    &["\u{200d}\u{308}", "a", ","],
+    &["\u{200d}", "1", ":"],
+    &["\u{200d}\u{308}", "1", ":"],
+    &["\u{200d}", "1", "'"],
+    &["\u{200d}\u{308}", "1", "'"],
+    &["\u{200d}", "1", ","],
+    &["\u{200d}\u{308}", "1", ","],
+    &["\u{200d}", "1", ".\u{2060}"],
+    &["\u{200d}\u{308}", "1", ".\u{2060}"],
+    &["a\u{2060}", "\u{1}"],
+    &["a\u{2060}\u{308}", "\u{1}"],
+    &["a\u{2060}", "\u{d}"],
+    &["a\u{2060}\u{308}", "\u{d}"],
+    &["a\u{2060}", "\u{a}"],
+    &["a\u{2060}\u{308}", "\u{a}"],
+    &["a\u{2060}", "\u{b}"],
+    &["a\u{2060}\u{308}", "\u{b}"],
+    &["a\u{2060}", "\u{3031}"],
+    &["a\u{2060}\u{308}", "\u{3031}"],
+    &["a\u{2060}A"],
+    &["a\u{2060}\u{308}A"],
+    &["a\u{2060}", ":"],
+    &["a\u{2060}\u{308}", ":"],
+    &["a\u{2060}", ","],
+    &["a\u{2060}\u{308}", ","],
+    &["a\u{2060}", "."],
+    &["a\u{2060}\u{308}", "."],
+    &["a\u{2060}0"],
+    &["a\u{2060}\u{308}0"],
+    &["a\u{2060}_"],
+    &["a\u{2060}\u{308}_"],
+    &["a\u{2060}", "\u{1f1e6}"],
+    &["a\u{2060}\u{308}", "\u{1f1e6}"],
+    &["a\u{2060}\u{5d0}"],
+    &["a\u{2060}\u{308}\u{5d0}"],
+    &["a\u{2060}", "\u{22}"],
+    &["a\u{2060}\u{308}", "\u{22}"],
+    &["a\u{2060}", "'"],
+    &["a\u{2060}\u{308}", "'"],
+    &["a\u{2060}", "\u{231a}"],
+    &["a\u{2060}\u{308}", "\u{231a}"],
+    &["a\u{2060}", " "],
+    &["a\u{2060}\u{308}", " "],
+    &["a\u{2060}\u{ad}"],
+    &["a\u{2060}\u{308}\u{ad}"],
+    &["a\u{2060}\u{300}"],
+    &["a\u{2060}\u{308}\u{300}"],
+    &["a\u{2060}\u{200d}"],
+    &["a\u{2060}\u{308}\u{200d}"],
+    &["a\u{2060}a\u{2060}"],
+    &["a\u{2060}\u{308}a\u{2060}"],
+    &["a\u{2060}a", ":"],
+    &["a\u{2060}\u{308}a", ":"],
+    &["a\u{2060}a", "'"],
+    &["a\u{2060}\u{308}a", "'"],
+    &["a\u{2060}a", "'\u{2060}"],
+    &["a\u{2060}\u{308}a", "'\u{2060}"],
+    &["a\u{2060}a", ","],
+    &["a\u{2060}\u{308}a", ","],
+    &["a\u{2060}1", ":"],
+    &["a\u{2060}\u{308}1", ":"],
+    &["a\u{2060}1", "'"],
+    &["a\u{2060}\u{308}1", "'"],
+    &["a\u{2060}1", ","],
+    &["a\u{2060}\u{308}1", ","],
+    &["a\u{2060}1", ".\u{2060}"],
+    &["a\u{2060}\u{308}1", ".\u{2060}"],
+    &["a", ":", "\u{1}"],
+    &["a", ":\u{308}", "\u{1}"],
+    &["a", ":", "\u{d}"],
+    &["a", ":\u{308}", "\u{d}"],
+    &["a", ":", "\u{a}"],
+    &["a", ":\u{308}", "\u{a}"],
+    &["a", ":", "\u{b}"],
+    &["a", ":\u{308}", "\u{b}"],
+    &["a", ":", "\u{3031}"],
+    &["a", ":\u{308}", "\u{3031}"],
+    &["a:A"],
+    &["a:\u{308}A"],
+    &["a", ":", ":"],
+    &["a", ":\u{308}", ":"],
+    &["a", ":", ","],
+    &["a", ":\u{308}", ","],
+    &["a", ":", "."],
+    &["a", ":\u{308}", "."],
+    &["a", ":", "0"],
+    &["a", ":\u{308}", "0"],
+    &["a", ":", "_"],
+    &["a", ":\u{308}", "_"],
+    &["a", ":", "\u{1f1e6}"],
+    &["a", ":\u{308}", "\u{1f1e6}"],
+    &["a:\u{5d0}"],
+    &["a:\u{308}\u{5d0}"],
+    &["a", ":", "\u{22}"],
+    &["a", ":\u{308}", "\u{22}"],
+    &["a", ":", "'"],
+    &["a", ":\u{308}", "'"],
+    &["a", ":", "\u{231a}"],
+    &["a", ":\u{308}", "\u{231a}"],
+    &["a", ":", " "],
+    &["a", ":\u{308}", " "],
+    &["a", ":\u{ad}"],
+    &["a", ":\u{308}\u{ad}"],
+    &["a", ":\u{300}"],
+    &["a", ":\u{308}\u{300}"],
+    &["a", ":\u{200d}"],
+    &["a", ":\u{308}\u{200d}"],
+    &["a:a\u{2060}"],
+    &["a:\u{308}a\u{2060}"],
+    &["a:a", ":"],
+    &["a:\u{308}a", ":"],
+    &["a:a", "'"],
+    &["a:\u{308}a", "'"],
+    &["a:a", "'\u{2060}"],
+    &["a:\u{308}a", "'\u{2060}"],
+    &["a:a", ","],
+    &["a:\u{308}a", ","],
+    &["a", ":", "1", ":"],
+    &["a", ":\u{308}", "1", ":"],
+    &["a", ":", "1", "'"],
+    &["a", ":\u{308}", "1", "'"],
+    &["a", ":", "1", ","],
+    &["a", ":\u{308}", "1", ","],
+    &["a", ":", "1", ".\u{2060}"],
+    &["a", ":\u{308}", "1", ".\u{2060}"],
+    &["a", "'", "\u{1}"],
+    &["a", "'\u{308}", "\u{1}"],
+    &["a", "'", "\u{d}"],
+    &["a", "'\u{308}", "\u{d}"],
+    &["a", "'", "\u{a}"],
+    &["a", "'\u{308}", "\u{a}"],
+    &["a", "'", "\u{b}"],
+    &["a", "'\u{308}", "\u{b}"],
+    &["a", "'", "\u{3031}"],
+    &["a", "'\u{308}", "\u{3031}"],
+    &["a'A"],
+    &["a'\u{308}A"],
+    &["a", "'", ":"],
+    &["a", "'\u{308}", ":"],
+    &["a", "'", ","],
+    &["a", "'\u{308}", ","],
+    &["a", "'", "."],
+    &["a", "'\u{308}", "."],
+    &["a", "'", "0"],
+    &["a", "'\u{308}", "0"],
+    &["a", "'", "_"],
+    &["a", "'\u{308}", "_"],
+    &["a", "'", "\u{1f1e6}"],
+    &["a", "'\u{308}", "\u{1f1e6}"],
+    &["a'\u{5d0}"],
+    &["a'\u{308}\u{5d0}"],
+    &["a", "'", "\u{22}"],
+    &["a", "'\u{308}", "\u{22}"],
+    &["a", "'", "'"],
+    &["a", "'\u{308}", "'"],
+    &["a", "'", "\u{231a}"],
+    &["a", "'\u{308}", "\u{231a}"],
+    &["a", "'", " "],
+    &["a", "'\u{308}", " "],
+    &["a", "'\u{ad}"],
+    &["a", "'\u{308}\u{ad}"],
+    &["a", "'\u{300}"],
+    &["a", "'\u{308}\u{300}"],
+    &["a", "'\u{200d}"],
+    &["a", "'\u{308}\u{200d}"],
+    &["a'a\u{2060}"],
+    &["a'\u{308}a\u{2060}"],
+    &["a'a", ":"],
+    &["a'\u{308}a", ":"],
+    &["a'a", "'"],
+    &["a'\u{308}a", "'"],
+    &["a'a", "'\u{2060}"],
+    &["a'\u{308}a", "'\u{2060}"],
+    &["a'a", ","],
+    &["a'\u{308}a", ","],
+    &["a", "'", "1", ":"],
+    &["a", "'\u{308}", "1", ":"],
+    &["a", "'", "1", "'"],
+    &["a", "'\u{308}", "1", "'"],
+    &["a", "'", "1", ","],
+    &["a", "'\u{308}", "1", ","],
+    &["a", "'", "1", ".\u{2060}"],
+    &["a", "'\u{308}", "1", ".\u{2060}"],
+    &["a", "'\u{2060}", "\u{1}"],
+    &["a", "'\u{2060}\u{308}", "\u{1}"],
+    &["a", "'\u{2060}", "\u{d}"],
+    &["a", "'\u{2060}\u{308}", "\u{d}"],
+    &["a", "'\u{2060}", "\u{a}"],
+    &["a", "'\u{2060}\u{308}", "\u{a}"],
+    &["a", "'\u{2060}", "\u{b}"],
+    &["a", "'\u{2060}\u{308}", "\u{b}"],
+    &["a", "'\u{2060}", "\u{3031}"],
+    &["a", "'\u{2060}\u{308}", "\u{3031}"],
+    &["a'\u{2060}A"],
+    &["a'\u{2060}\u{308}A"],
+    &["a", "'\u{2060}", ":"],
+    &["a", "'\u{2060}\u{308}", ":"],
+    &["a", "'\u{2060}", ","],
+    &["a", "'\u{2060}\u{308}", ","],
+    &["a", "'\u{2060}", "."],
+    &["a", "'\u{2060}\u{308}", "."],
+    &["a", "'\u{2060}", "0"],
+    &["a", "'\u{2060}\u{308}", "0"],
+    &["a", "'\u{2060}", "_"],
+    &["a", "'\u{2060}\u{308}", "_"],
+    &["a", "'\u{2060}", "\u{1f1e6}"],
+    &["a", "'\u{2060}\u{308}", "\u{1f1e6}"],
+    &["a'\u{2060}\u{5d0}"],
+    &["a'\u{2060}\u{308}\u{5d0}"],
+    &["a", "'\u{2060}", "\u{22}"],
+    &["a", "'\u{2060}\u{308}", "\u{22}"],
+    &["a", "'\u{2060}", "'"],
+    &["a", "'\u{2060}\u{308}", "'"],
+    &["a", "'\u{2060}", "\u{231a}"],
+    &["a", "'\u{2060}\u{308}", "\u{231a}"],
+    &["a", "'\u{2060}", " "],
+    &["a", "'\u{2060}\u{308}", " "],
+    &["a", "'\u{2060}\u{ad}"],
+    &["a", "'\u{2060}\u{308}\u{ad}"],
+    &["a", "'\u{2060}\u{300}"],
+    &["a", "'\u{2060}\u{308}\u{300}"],
+    &["a", "'\u{2060}\u{200d}"],
+    &["a", "'\u{2060}\u{308}\u{200d}"],
+    &["a'\u{2060}a\u{2060}"],
+    &["a'\u{2060}\u{308}a\u{2060}"],
+    &["a'\u{2060}a", ":"],
+    &["a'\u{2060}\u{308}a", ":"],
+    &["a'\u{2060}a", "'"],
+    &["a'\u{2060}\u{308}a", "'"],
+    &["a'\u{2060}a", "'\u{2060}"],
+    &["a'\u{2060}\u{308}a", "'\u{2060}"],
+    &["a'\u{2060}a", ","],
+    &["a'\u{2060}\u{308}a", ","],
+    &["a", "'\u{2060}", "1", ":"],
+    &["a", "'\u{2060}\u{308}", "1", ":"],
+    &["a", "'\u{2060}", "1", "'"],
+    &["a", "'\u{2060}\u{308}", "1", "'"],
+    &["a", "'\u{2060}", "1", ","],
+    &["a", "'\u{2060}\u{308}", "1", ","],
+    &["a", "'\u{2060}", "1", ".\u{2060}"],
+    &["a", "'\u{2060}\u{308}", "1", ".\u{2060}"],
+    &["a", ",", "\u{1}"],
+    &["a", ",\u{308}", "\u{1}"],
+    &["a", ",", "\u{d}"],
+    &["a", ",\u{308}", "\u{d}"],
+    &["a", ",", "\u{a}"],
+    &["a", ",\u{308}", "\u{a}"],
+    &["a", ",", "\u{b}"],
+    &["a", ",\u{308}", "\u{b}"],
+    &["a", ",", "\u{3031}"],
+    &["a", ",\u{308}", "\u{3031}"],
+    &["a", ",", "A"],
+    &["a", ",\u{308}", "A"],
+    &["a", ",", ":"],
+    &["a", ",\u{308}", ":"],
+    &["a", ",", ","],
+    &["a", ",\u{308}", ","],
+    &["a", ",", "."],
+    &["a", ",\u{308}", "."],
+    &["a", ",", "0"],
+    &["a", ",\u{308}", "0"],
+    &["a", ",", "_"],
+    &["a", ",\u{308}", "_"],
+    &["a", ",", "\u{1f1e6}"],
+    &["a", ",\u{308}", "\u{1f1e6}"],
+    &["a", ",", "\u{5d0}"],
+    &["a", ",\u{308}", "\u{5d0}"],
+    &["a", ",", "\u{22}"],
+    &["a", ",\u{308}", "\u{22}"],
+    &["a", ",", "'"],
+    &["a", ",\u{308}", "'"],
+    &["a", ",", "\u{231a}"],
+    &["a", ",\u{308}", "\u{231a}"],
+    &["a", ",", " "],
+    &["a", ",\u{308}", " "],
+    &["a", ",\u{ad}"],
+    &["a", ",\u{308}\u{ad}"],
+    &["a", ",\u{300}"],
+    &["a", ",\u{308}\u{300}"],
+    &["a", ",\u{200d}"],
+    &["a", ",\u{308}\u{200d}"],
+    &["a", ",", "a\u{2060}"],
+    &["a", ",\u{308}", "a\u{2060}"],
+    &["a", ",", "a", ":"],
+    &["a", ",\u{308}", "a", ":"],
+    &["a", ",", "a", "'"],
+    &["a", ",\u{308}", "a", "'"],
+    &["a", ",", "a", "'\u{2060}"],
+    &["a", ",\u{308}", "a", "'\u{2060}"],
+    &["a", ",", "a", ","],
+    &["a", ",\u{308}", "a", ","],
+    &["a", ",", "1", ":"],
+    &["a", ",\u{308}", "1", ":"],
+    &["a", ",", "1", "'"],
+    &["a", ",\u{308}", "1", "'"],
+    &["a", ",", "1", ","],
+    &["a", ",\u{308}", "1", ","],
+    &["a", ",", "1", ".\u{2060}"],
+    &["a", ",\u{308}", "1", ".\u{2060}"],
+    &["1", ":", "\u{1}"],
+    &["1", ":\u{308}", "\u{1}"],
+    &["1", ":", "\u{d}"],
+    &["1", ":\u{308}", "\u{d}"],
+    &["1", ":", "\u{a}"],
+    &["1", ":\u{308}", "\u{a}"],
+    &["1", ":", "\u{b}"],
+    &["1", ":\u{308}", "\u{b}"],
+    &["1", ":", "\u{3031}"],
+    &["1", ":\u{308}", "\u{3031}"],
+    &["1", ":", "A"],
+    &["1", ":\u{308}", "A"],
+    &["1", ":", ":"],
+    &["1", ":\u{308}", ":"],
+    &["1", ":", ","],
+    &["1", ":\u{308}", ","],
+    &["1", ":", "."],
+    &["1", ":\u{308}", "."],
+    &["1", ":", "0"],
+    &["1", ":\u{308}", "0"],
+    &["1", ":", "_"],
+    &["1", ":\u{308}", "_"],
+    &["1", ":", "\u{1f1e6}"],
+    &["1", ":\u{308}", "\u{1f1e6}"],
+    &["1", ":", "\u{5d0}"],
+    &["1", ":\u{308}", "\u{5d0}"],
+    &["1", ":", "\u{22}"],
+    &["1", ":\u{308}", "\u{22}"],
+    &["1", ":", "'"],
+    &["1", ":\u{308}", "'"],
+    &["1", ":", "\u{231a}"],
+    &["1", ":\u{308}", "\u{231a}"],
+    &["1", ":", " "],
+    &["1", ":\u{308}", " "],
+    &["1", ":\u{ad}"],
+    &["1", ":\u{308}\u{ad}"],
+    &["1", ":\u{300}"],
+    &["1", ":\u{308}\u{300}"],
+    &["1", ":\u{200d}"],
+    &["1", ":\u{308}\u{200d}"],
+    &["1", ":", "a\u{2060}"],
+    &["1", ":\u{308}", "a\u{2060}"],
+    &["1", ":", "a", ":"],
+    &["1", ":\u{308}", "a", ":"],
+    &["1", ":", "a", "'"],
+    &["1", ":\u{308}", "a", "'"],
+    &["1", ":", "a", "'\u{2060}"],
+    &["1", ":\u{308}", "a", "'\u{2060}"],
+    &["1", ":", "a", ","],
+    &["1", ":\u{308}", "a", ","],
+    &["1", ":", "1", ":"],
+    &["1", ":\u{308}", "1", ":"],
+    &["1", ":", "1", "'"],
+    &["1", ":\u{308}", "1", "'"],
+    &["1", ":", "1", ","],
+    &["1", ":\u{308}", "1", ","],
+    &["1", ":", "1", ".\u{2060}"],
+    &["1", ":\u{308}", "1", ".\u{2060}"],
+    &["1", "'", "\u{1}"],
+    &["1", "'\u{308}", "\u{1}"],
+    &["1", "'", "\u{d}"],
+    &["1", "'\u{308}", "\u{d}"],
+    &["1", "'", "\u{a}"],
+    &["1", "'\u{308}", "\u{a}"],
+    &["1", "'", "\u{b}"],
+    &["1", "'\u{308}", "\u{b}"],
+    &["1", "'", "\u{3031}"],
+    &["1", "'\u{308}", "\u{3031}"],
+    &["1", "'", "A"],
+    &["1", "'\u{308}", "A"],
+    &["1", "'", ":"],
+    &["1", "'\u{308}", ":"],
+    &["1", "'", ","],
+    &["1", "'\u{308}", ","],
+    &["1", "'", "."],
+    &["1", "'\u{308}", "."],
+    &["1'0"],
+    &["1'\u{308}0"],
+    &["1", "'", "_"],
+    &["1", "'\u{308}", "_"],
+    &["1", "'", "\u{1f1e6}"],
+    &["1", "'\u{308}", "\u{1f1e6}"],
+    &["1", "'", "\u{5d0}"],
+    &["1", "'\u{308}", "\u{5d0}"],
+    &["1", "'", "\u{22}"],
+    &["1", "'\u{308}", "\u{22}"],
+    &["1", "'", "'"],
+    &["1", "'\u{308}", "'"],
+    &["1", "'", "\u{231a}"],
+    &["1", "'\u{308}", "\u{231a}"],
+    &["1", "'", " "],
+    &["1", "'\u{308}", " "],
+    &["1", "'\u{ad}"],
+    &["1", "'\u{308}\u{ad}"],
+    &["1", "'\u{300}"],
+    &["1", "'\u{308}\u{300}"],
+    &["1", "'\u{200d}"],
+    &["1", "'\u{308}\u{200d}"],
+    &["1", "'", "a\u{2060}"],
+    &["1", "'\u{308}", "a\u{2060}"],
+    &["1", "'", "a", ":"],
+    &["1", "'\u{308}", "a", ":"],
+    &["1", "'", "a", "'"],
+    &["1", "'\u{308}", "a", "'"],
+    &["1", "'", "a", "'\u{2060}"],
+    &["1", "'\u{308}", "a", "'\u{2060}"],
+    &["1", "'", "a", ","],
+    &["1", "'\u{308}", "a", ","],
+    &["1'1", ":"],
+    &["1'\u{308}1", ":"],
+    &["1'1", "'"],
+    &["1'\u{308}1", "'"],
+    &["1'1", ","],
+    &["1'\u{308}1", ","],
+    &["1'1", ".\u{2060}"],
+    &["1'\u{308}1", ".\u{2060}"],
+    &["1", ",", "\u{1}"],
+    &["1", ",\u{308}", "\u{1}"],
+    &["1", ",", "\u{d}"],
+    &["1", ",\u{308}", "\u{d}"],
+    &["1", ",", "\u{a}"],
+    &["1", ",\u{308}", "\u{a}"],
+    &["1", ",", "\u{b}"],
+    &["1", ",\u{308}", "\u{b}"],
+    &["1", ",", "\u{3031}"],
+    &["1", ",\u{308}", "\u{3031}"],
+    &["1", ",", "A"],
+    &["1", ",\u{308}", "A"],
+    &["1", ",", ":"],
+    &["1", ",\u{308}", ":"],
+    &["1", ",", ","],
+    &["1", ",\u{308}", ","],
+    &["1", ",", "."],
+    &["1", ",\u{308}", "."],
+    &["1,0"],
+    &["1,\u{308}0"],
+    &["1", ",", "_"],
+    &["1", ",\u{308}", "_"],
+    &["1", ",", "\u{1f1e6}"],
+    &["1", ",\u{308}", "\u{1f1e6}"],
+    &["1", ",", "\u{5d0}"],
+    &["1", ",\u{308}", "\u{5d0}"],
+    &["1", ",", "\u{22}"],
+    &["1", ",\u{308}", "\u{22}"],
+    &["1", ",", "'"],
+    &["1", ",\u{308}", "'"],
+    &["1", ",", "\u{231a}"],
+    &["1", ",\u{308}", "\u{231a}"],
+    &["1", ",", " "],
+    &["1", ",\u{308}", " "],
+    &["1", ",\u{ad}"],
+    &["1", ",\u{308}\u{ad}"],
+    &["1", ",\u{300}"],
+    &["1", ",\u{308}\u{300}"],
+    &["1", ",\u{200d}"],
+    &["1", ",\u{308}\u{200d}"],
+    &["1", ",", "a\u{2060}"],
+    &["1", ",\u{308}", "a\u{2060}"],
+    &["1", ",", "a", ":"],
+    &["1", ",\u{308}", "a", ":"],
+    &["1", ",", "a", "'"],
+    &["1", ",\u{308}", "a", "'"],
+    &["1", ",", "a", "'\u{2060}"],
+    &["1", ",\u{308}", "a", "'\u{2060}"],
+    &["1", ",", "a", ","],
+    &["1", ",\u{308}", "a", ","],
+    &["1,1", ":"],
+    &["1,\u{308}1", ":"],
+    &["1,1", "'"],
+    &["1,\u{308}1", "'"],
+    &["1,1", ","],
+    &["1,\u{308}1", ","],
+    &["1,1", ".\u{2060}"],
+    &["1,\u{308}1", ".\u{2060}"],
+    &["1", ".\u{2060}", "\u{1}"],
+    &["1", ".\u{2060}\u{308}", "\u{1}"],
+    &["1", ".\u{2060}", "\u{d}"],
+    &["1", ".\u{2060}\u{308}", "\u{d}"],
+    &["1", ".\u{2060}", "\u{a}"],
+    &["1", ".\u{2060}\u{308}", "\u{a}"],
+    &["1", ".\u{2060}", "\u{b}"],
+    &["1", ".\u{2060}\u{308}", "\u{b}"],
+    &["1", ".\u{2060}", "\u{3031}"],
+    &["1", ".\u{2060}\u{308}", "\u{3031}"],
+    &["1", ".\u{2060}", "A"],
+    &["1", ".\u{2060}\u{308}", "A"],
+    &["1", ".\u{2060}", ":"],
+    &["1", ".\u{2060}\u{308}", ":"],
+    &["1", ".\u{2060}", ","],
+    &["1", ".\u{2060}\u{308}", ","],
+    &["1", ".\u{2060}", "."],
+    &["1", ".\u{2060}\u{308}", "."],
+    &["1.\u{2060}0"],
+    &["1.\u{2060}\u{308}0"],
+    &["1", ".\u{2060}", "_"],
+    &["1", ".\u{2060}\u{308}", "_"],
+    &["1", ".\u{2060}", "\u{1f1e6}"],
+    &["1", ".\u{2060}\u{308}", "\u{1f1e6}"],
+    &["1", ".\u{2060}", "\u{5d0}"],
+    &["1", ".\u{2060}\u{308}", "\u{5d0}"],
+    &["1", ".\u{2060}", "\u{22}"],
+    &["1", ".\u{2060}\u{308}", "\u{22}"],
+    &["1", ".\u{2060}", "'"],
+    &["1", ".\u{2060}\u{308}", "'"],
+    &["1", ".\u{2060}", "\u{231a}"],
+    &["1", ".\u{2060}\u{308}", "\u{231a}"],
+    &["1", ".\u{2060}", " "],
+    &["1", ".\u{2060}\u{308}", " "],
+    &["1", ".\u{2060}\u{ad}"],
+    &["1", ".\u{2060}\u{308}\u{ad}"],
+    &["1", ".\u{2060}\u{300}"],
+    &["1", ".\u{2060}\u{308}\u{300}"],
+    &["1", ".\u{2060}\u{200d}"],
+    &["1", ".\u{2060}\u{308}\u{200d}"],
+    &["1", ".\u{2060}", "a\u{2060}"],
+    &["1", ".\u{2060}\u{308}", "a\u{2060}"],
+    &["1", ".\u{2060}", "a", ":"],
+    &["1", ".\u{2060}\u{308}", "a", ":"],
+    &["1", ".\u{2060}", "a", "'"],
+    &["1", ".\u{2060}\u{308}", "a", "'"],
+    &["1", ".\u{2060}", "a", "'\u{2060}"],
+    &["1", ".\u{2060}\u{308}", "a", "'\u{2060}"],
+    &["1", ".\u{2060}", "a", ","],
+    &["1", ".\u{2060}\u{308}", "a", ","],
+    &["1.\u{2060}1", ":"],
+    &["1.\u{2060}\u{308}1", ":"],
+    &["1.\u{2060}1", "'"],
+    &["1.\u{2060}\u{308}1", "'"],
+    &["1.\u{2060}1", ","],
+    &["1.\u{2060}\u{308}1", ","],
+    &["1.\u{2060}1", ".\u{2060}"],
+    &["1.\u{2060}\u{308}1", ".\u{2060}"],
+    &["\u{d}\u{a}", "a", "\u{a}", "\u{308}"],
+    &["a\u{308}"],
+    &[" \u{200d}", "\u{646}"],
+    &["\u{646}\u{200d}", " "],
+    &[
+        "\u{671}\u{644}\u{631}\u{64e}\u{651}\u{62d}\u{650}\u{64a}\u{645}\u{650}",
+        " ",
+        "\u{6dd}\u{661}",
+    ],
+    &["\u{721}\u{719}\u{721}\u{718}\u{72a}\u{710}", " ", "\u{70f}\u{71d}\u{717}"],
+    &["\u{72c}\u{70f}\u{72b}\u{712}\u{718}"],
+    &["AAA"],
+    &["A:A"],
+    &["A", ":", ":", "A"],
+    &["\u{5d0}'"],
+    &["\u{5d0}\u{22}\u{5d0}"],
+    &["A00A"],
+    &["0,0"],
+    &["0", ",", ",", "0"],
+    &["\u{3031}\u{3031}"],
+    &["A_0_\u{3031}_"],
+    &["A__A"],
+    &["\u{1f1e6}\u{1f1e7}", "\u{1f1e8}", "b"],
+    &["a", "\u{1f1e6}\u{1f1e7}", "\u{1f1e8}", "b"],
+    &["a", "\u{1f1e6}\u{1f1e7}\u{200d}", "\u{1f1e8}", "b"],
+    &["a", "\u{1f1e6}\u{200d}\u{1f1e7}", "\u{1f1e8}", "b"],
+    &["a", "\u{1f1e6}\u{1f1e7}", "\u{1f1e8}\u{1f1e9}", "b"],
+    &["\u{1f476}\u{1f3ff}", "\u{1f476}"],
+    &["\u{1f6d1}\u{200d}\u{1f6d1}"],
+    &["a\u{200d}\u{1f6d1}"],
+    &["\u{2701}\u{200d}\u{2701}"],
+    &["a\u{200d}\u{2701}"],
+    &["\u{1f476}\u{1f3ff}\u{308}\u{200d}\u{1f476}\u{1f3ff}"],
+    &["\u{1f6d1}\u{1f3ff}"],
+    &["\u{200d}\u{1f6d1}\u{1f3ff}"],
+    &["\u{200d}\u{1f6d1}"],
+    &["\u{200d}\u{1f6d1}"],
+    &["\u{1f6d1}", "\u{1f6d1}"],
+    &["a\u{308}\u{200d}\u{308}b"],
+    &["a", "  ", "b"],
+    &["1", ":", ":", "1"],
+    &["1_1", ":", ":", "1"],
+    &["1_a", ":", ":", "1"],
+    &["1", ":", ":", "a"],
+    &["1_1", ":", ":", "a"],
+    &["1_a", ":", ":", "a"],
+    &["1", ":", ".", "1"],
+    &["1_1", ":", ".", "1"],
+    &["1_a", ":", ".", "1"],
+    &["1", ":", ".", "a"],
+    &["1_1", ":", ".", "a"],
+    &["1_a", ":", ".", "a"],
+    &["1", ":", ",", "1"],
+    &["1_1", ":", ",", "1"],
+    &["1_a", ":", ",", "1"],
+    &["1", ":", ",", "a"],
+    &["1_1", ":", ",", "a"],
+    &["1_a", ":", ",", "a"],
+    &["1", ".", ":", "1"],
+    &["1_1", ".", ":", "1"],
+    &["1_a", ".", ":", "1"],
+    &["1", ".", ":", "a"],
+    &["1_1", ".", ":", "a"],
+    &["1_a", ".", ":", "a"],
+    &["1", ".", ".", "1"],
+    &["1_1", ".", ".", "1"],
+    &["1_a", ".", ".", "1"],
+    &["1", ".", ".", "a"],
+    &["1_1", ".", ".", "a"],
+    &["1_a", ".", ".", "a"],
+    &["1", ".", ",", "1"],
+    &["1_1", ".", ",", "1"],
+    &["1_a", ".", ",", "1"],
+    &["1", ".", ",", "a"],
+    &["1_1", ".", ",", "a"],
+    &["1_a", ".", ",", "a"],
+    &["1", ",", ":", "1"],
+    &["1_1", ",", ":", "1"],
+    &["1_a", ",", ":", "1"],
+    &["1", ",", ":", "a"],
+    &["1_1", ",", ":", "a"],
+    &["1_a", ",", ":", "a"],
+    &["1", ",", ".", "1"],
+    &["1_1", ",", ".", "1"],
+    &["1_a", ",", ".", "1"],
+    &["1", ",", ".", "a"],
+    &["1_1", ",", ".", "a"],
+    &["1_a", ",", ".", "a"],
+    &["1", ",", ",", "1"],
+    &["1_1", ",", ",", "1"],
+    &["1_a", ",", ",", "1"],
+    &["1", ",", ",", "a"],
+    &["1_1", ",", ",", "a"],
+    &["1_a", ",", ",", "a"],
+    &["a", ":", ":", "1"],
+    &["a_1", ":", ":", "1"],
+    &["a_a", ":", ":", "1"],
+    &["a", ":", ":", "a"],
+    &["a_1", ":", ":", "a"],
+    &["a_a", ":", ":", "a"],
+    &["a", ":", ".", "1"],
+    &["a_1", ":", ".", "1"],
+    &["a_a", ":", ".", "1"],
+    &["a", ":", ".", "a"],
+    &["a_1", ":", ".", "a"],
+    &["a_a", ":", ".", "a"],
+    &["a", ":", ",", "1"],
+    &["a_1", ":", ",", "1"],
+    &["a_a", ":", ",", "1"],
+    &["a", ":", ",", "a"],
+    &["a_1", ":", ",", "a"],
+    &["a_a", ":", ",", "a"],
+    &["a", ".", ":", "1"],
+    &["a_1", ".", ":", "1"],
+    &["a_a", ".", ":", "1"],
+    &["a", ".", ":", "a"],
+    &["a_1", ".", ":", "a"],
+    &["a_a", ".", ":", "a"],
+    &["a", ".", ".", "1"],
+    &["a_1", ".", ".", "1"],
+    &["a_a", ".", ".", "1"],
+    &["a", ".", ".", "a"],
+    &["a_1", ".", ".", "a"],
+    &["a_a", ".", ".", "a"],
+    &["a", ".", ",", "1"],
+    &["a_1", ".", ",", "1"],
+    &["a_a", ".", ",", "1"],
+    &["a", ".", ",", "a"],
+    &["a_1", ".", ",", "a"],
+    &["a_a", ".", ",", "a"],
+    &["a", ",", ":", "1"],
+    &["a_1", ",", ":", "1"],
+    &["a_a", ",", ":", "1"],
+    &["a", ",", ":", "a"],
+    &["a_1", ",", ":", "a"],
+    &["a_a", ",", ":", "a"],
+    &["a", ",", ".", "1"],
+    &["a_1", ",", ".", "1"],
+    &["a_a", ",", ".", "1"],
+    &["a", ",", ".", "a"],
+    &["a_1", ",", ".", "a"],
+    &["a_a", ",", ".", "a"],
+    &["a", ",", ",", "1"],
+    &["a_1", ",", ",", "1"],
+    &["a_a", ",", ",", "1"],
+    &["a", ",", ",", "a"],
+    &["a_1", ",", ",", "a"],
+    &["a_a", ",", ",", "a"],
+];
@@ -484,11 +484,11 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 }
 
 #[derive(Debug, Clone)]
-struct CaseMappingIter(core::array::IntoIter<char, 3>);
+pub(crate) struct CaseMappingIter(core::array::IntoIter<char, 3>);
 
 impl CaseMappingIter {
     #[inline]
-    fn new(chars: [char; 3]) -> CaseMappingIter {
+    pub(crate) fn new(chars: [char; 3]) -> CaseMappingIter {
         let mut iter = chars.into_iter();
         if chars[2] == '\0' {
             iter.next_back();
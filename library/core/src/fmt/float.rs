@@ -1,6 +1,7 @@
 use crate::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
 use crate::mem::MaybeUninit;
 use crate::num::{flt2dec, fmt as numfmt};
+use crate::str;
 
 #[doc(hidden)]
 trait GeneralFormat: PartialOrd {
@@ -282,3 +283,218 @@ fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "{:#034x}", self.to_bits())
     }
 }
+
+/// Selects how [`f64::format_into_with`] and its counterparts on the other float types lay out
+/// their output.
+///
+/// Every mode produces the same text as the corresponding formatting trait with no flags other
+/// than the precision.
+#[unstable(feature = "float_format_into", issue = "none")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FloatFormat {
+    /// The shortest decimal representation that roundtrips, like `{}`.
+    ///
+    /// Very large and very small values are written out in full, so this can take up to a few
+    /// hundred bytes for an `f64`.
+    Decimal,
+    /// The shortest roundtripping representation in scientific notation, like `{:e}`, or like
+    /// `{:E}` if `upper` is set.
+    Exponent {
+        /// Whether to write the exponent marker as `E` instead of `e`.
+        upper: bool,
+    },
+    /// Rounded to exactly `precision` fractional digits, like `{:.precision}`.
+    Fixed {
+        /// The number of digits after the decimal point.
+        precision: u16,
+    },
+    /// Scientific notation rounded to exactly `precision` fractional digits, like
+    /// `{:.precision$e}`, or like `{:.precision$E}` if `upper` is set.
+    FixedExponent {
+        /// The number of digits after the decimal point of the significand.
+        precision: u16,
+        /// Whether to write the exponent marker as `E` instead of `e`.
+        upper: bool,
+    },
+}
+
+/// The buffer length that [`f64::format_into`] and its counterparts require. It fits the
+/// longest `{:?}` output of an `f64`, such as `-2.2250738585072014e-308`, with room to spare.
+const FORMAT_INTO_LEN: usize = 32;
+
+/// Writes `formatted` to the start of `out`, if it fits.
+fn write_formatted<'a>(formatted: &numfmt::Formatted<'_>, out: &'a mut [u8]) -> Option<&'a str> {
+    let len = formatted.write(out)?;
+    // SAFETY: `flt2dec` produces only ASCII characters.
+    Some(unsafe { str::from_utf8_unchecked(&out[..len]) })
+}
+
+// Don't inline this so callers don't use the stack space this function
+// requires unless they have to.
+#[inline(never)]
+fn float_format_shortest_into<'a, T>(
+    num: T,
+    upper: Option<bool>,
+    min_precision: usize,
+    out: &'a mut [u8],
+) -> Option<&'a str>
+where
+    T: flt2dec::DecodableFloat,
+{
+    let mut buf: [MaybeUninit<u8>; flt2dec::MAX_SIG_DIGITS] =
+        [MaybeUninit::uninit(); flt2dec::MAX_SIG_DIGITS];
+    let mut parts: [MaybeUninit<numfmt::Part<'_>>; 6] = [MaybeUninit::uninit(); 6];
+    let formatted = match upper {
+        None => flt2dec::to_shortest_str(
+            flt2dec::strategy::grisu::format_shortest,
+            num,
+            flt2dec::Sign::Minus,
+            min_precision,
+            &mut buf,
+            &mut parts,
+        ),
+        Some(upper) => flt2dec::to_shortest_exp_str(
+            flt2dec::strategy::grisu::format_shortest,
+            num,
+            flt2dec::Sign::Minus,
+            (0, 0),
+            upper,
+            &mut buf,
+            &mut parts,
+        ),
+    };
+    write_formatted(&formatted, out)
+}
+
+// Don't inline this so callers don't use the stack space this function
+// requires unless they have to.
+#[inline(never)]
+fn float_format_exact_into<'a, T>(
+    num: T,
+    upper: Option<bool>,
+    precision: u16,
+    out: &'a mut [u8],
+) -> Option<&'a str>
+where
+    T: flt2dec::DecodableFloat,
+{
+    let mut buf: [MaybeUninit<u8>; 1024] = [MaybeUninit::uninit(); 1024]; // enough for f32 and f64
+    let mut parts: [MaybeUninit<numfmt::Part<'_>>; 6] = [MaybeUninit::uninit(); 6];
+    let formatted = match upper {
+        None => flt2dec::to_exact_fixed_str(
+            flt2dec::strategy::grisu::format_exact,
+            num,
+            flt2dec::Sign::Minus,
+            precision.into(),
+            &mut buf,
+            &mut parts,
+        ),
+        // 1 integral digit + `precision` fractional digits = `precision + 1` total digits
+        Some(upper) => flt2dec::to_exact_exp_str(
+            flt2dec::strategy::grisu::format_exact,
+            num,
+            flt2dec::Sign::Minus,
+            usize::from(precision) + 1,
+            upper,
+            &mut buf,
+            &mut parts,
+        ),
+    };
+    write_formatted(&formatted, out)
+}
+
+macro_rules! format_into {
+    ($($ty:ident)*) => {
+        $(
+            impl $ty {
+                /// Writes the shortest representation of `self` that roundtrips into `buf`,
+                /// and returns it as a string slice.
+                ///
+                /// The output is the same as that of `{:?}`: it uses scientific notation for
+                /// very large and very small magnitudes, and otherwise always includes a
+                /// decimal point. This avoids the [`Formatter`] machinery entirely.
+                ///
+                /// `buf` must be at least 32 bytes long, which is checked at compile time.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// #![feature(float_format_into)]
+                /// # #![feature(f16)]
+                ///
+                /// let mut buf = [0; 32];
+                #[doc = concat!("assert_eq!(1.5", stringify!($ty), ".format_into(&mut buf), \"1.5\");")]
+                #[doc = concat!("assert_eq!((-2.0", stringify!($ty), ").format_into(&mut buf), \"-2.0\");")]
+                #[doc = concat!("assert_eq!(", stringify!($ty), "::NAN.format_into(&mut buf), \"NaN\");")]
+                /// ```
+                #[unstable(feature = "float_format_into", issue = "none")]
+                pub fn format_into<const N: usize>(self, buf: &mut [u8; N]) -> &str {
+                    const { assert!(N >= FORMAT_INTO_LEN, "buffer is too small for `format_into`") };
+                    let upper = if self.already_rounded_value_should_use_exponential() {
+                        Some(false)
+                    } else {
+                        None
+                    };
+                    match float_format_shortest_into(self, upper, 1, buf) {
+                        Some(s) => s,
+                        None => unreachable!(),
+                    }
+                }
+
+                /// Writes `self` into `buf` using the layout selected by `format`, and returns
+                /// it as a string slice.
+                ///
+                /// Returns `None` if `buf` is too short for the output. Bytes past the end of
+                /// the returned string may have been overwritten either way.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// #![feature(float_format_into)]
+                /// # #![feature(f16)]
+                /// use core::fmt::FloatFormat;
+                ///
+                /// let mut buf = [0; 16];
+                #[doc = concat!("let x = 12.5", stringify!($ty), ";")]
+                /// assert_eq!(x.format_into_with(FloatFormat::Decimal, &mut buf), Some("12.5"));
+                /// assert_eq!(
+                ///     x.format_into_with(FloatFormat::Exponent { upper: false }, &mut buf),
+                ///     Some("1.25e1"),
+                /// );
+                /// assert_eq!(x.format_into_with(FloatFormat::Fixed { precision: 2 }, &mut buf), Some("12.50"));
+                /// assert_eq!(
+                ///     x.format_into_with(FloatFormat::FixedExponent { precision: 0, upper: true }, &mut buf),
+                ///     Some("1E1"),
+                /// );
+                /// assert_eq!(x.format_into_with(FloatFormat::Fixed { precision: 20 }, &mut buf), None);
+                /// ```
+                #[unstable(feature = "float_format_into", issue = "none")]
+                pub fn format_into_with(self, format: FloatFormat, buf: &mut [u8]) -> Option<&str> {
+                    match format {
+                        FloatFormat::Decimal => float_format_shortest_into(self, None, 0, buf),
+                        FloatFormat::Exponent { upper } => {
+                            float_format_shortest_into(self, Some(upper), 0, buf)
+                        }
+                        FloatFormat::Fixed { precision } => {
+                            float_format_exact_into(self, None, precision, buf)
+                        }
+                        FloatFormat::FixedExponent { precision, upper } => {
+                            float_format_exact_into(self, Some(upper), precision, buf)
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+format_into! { f32 f64 }
+
+#[cfg(target_has_reliable_f16)]
+format_into! { f16 }
+
+// FIXME(f16_f128): `f128` has no `format_into` yet. `flt2dec` decodes floats into a 64-bit
+// mantissa, which cannot hold the 113 bits of an `f128`, and the formatting traits of `f128`
+// only print its bits for the same reason. Add it here, behind `target_has_reliable_f128`,
+// once `flt2dec` supports wider floats.
@@ -34,6 +34,9 @@ pub enum Alignment {
     Center,
 }
 
+#[cfg(not(no_fp_fmt_parse))]
+#[unstable(feature = "float_format_into", issue = "none")]
+pub use float::FloatFormat;
 #[unstable(feature = "int_format_into", issue = "138215")]
 pub use num_buffer::{NumBuffer, NumBufferTrait};
 
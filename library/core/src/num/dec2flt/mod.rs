@@ -88,9 +88,10 @@
 )]
 
 use self::common::BiasedFp;
+use self::decimal::Decimal;
 use self::float::RawFloat;
 use self::lemire::compute_float;
-use self::parse::{parse_inf_nan, parse_number};
+use self::parse::{parse_inf_nan, parse_number, parse_partial_inf_nan, parse_partial_number};
 use self::slow::parse_long_mantissa;
 use crate::error::Error;
 use crate::fmt;
@@ -108,7 +109,7 @@
 pub mod parse;
 
 macro_rules! from_str_float_impl {
-    ($t:ty) => {
+    ($t:ty $(, $doc_feature:literal)?) => {
         #[stable(feature = "rust1", since = "1.0.0")]
         impl FromStr for $t {
             type Err = ParseFloatError;
@@ -169,11 +170,41 @@ fn from_str(src: &str) -> Result<Self, ParseFloatError> {
                 dec2flt(src)
             }
         }
+
+        impl $t {
+            /// Parses a float from the start of an ASCII-byte slice, returning it
+            /// along with the number of bytes that were consumed.
+            ///
+            /// This accepts the same grammar as [`FromStr`], but stops at the end of the
+            /// longest valid prefix instead of rejecting trailing bytes. An exponent marker
+            /// that is not followed by digits is not consumed. The input does not need to
+            /// be valid UTF-8.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if `src` is empty or does not start with a valid float.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(parse_prefix)]
+            $(#[doc = concat!("#![feature(", $doc_feature, ")]")])?
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($t), "::parse_prefix(b\"2.5e3,x\"), Ok((2500.0, 5)));")]
+            #[doc = concat!("assert_eq!(", stringify!($t), "::parse_prefix(b\"1e+\"), Ok((1.0, 1)));")]
+            #[doc = concat!("assert!(", stringify!($t), "::parse_prefix(b\"x1.0\").is_err());")]
+            /// ```
+            #[unstable(feature = "parse_prefix", issue = "none")]
+            #[inline(never)]
+            pub fn parse_prefix(src: &[u8]) -> Result<($t, usize), ParseFloatError> {
+                dec2flt_prefix(src)
+            }
+        }
     };
 }
 
 #[cfg(target_has_reliable_f16)]
-from_str_float_impl!(f16);
+from_str_float_impl!(f16, "f16");
 from_str_float_impl!(f32);
 from_str_float_impl!(f64);
 
@@ -277,9 +308,47 @@ pub fn dec2flt<F: RawFloat>(s: &str) -> Result<F, ParseFloatError> {
         None => return Err(pfe_invalid()),
     };
     num.negative = negative;
+    Ok(decimal_to_float(num, s))
+}
+
+/// Converts a decimal number at the start of a byte slice into a floating point number,
+/// returning it along with the number of bytes that were consumed.
+///
+/// Unlike [`dec2flt`], trailing bytes after the longest valid number are not an error,
+/// and the input does not need to be UTF-8.
+#[inline(always)] // Will be inlined into a function with `#[inline(never)]`, like `dec2flt`
+pub fn dec2flt_prefix<F: RawFloat>(s: &[u8]) -> Result<(F, usize), ParseFloatError> {
+    let c = if let Some(&c) = s.first() {
+        c
+    } else {
+        return Err(pfe_empty());
+    };
+    let negative = c == b'-';
+    let sign_len = (c == b'-' || c == b'+') as usize;
+    let s = &s[sign_len..];
+    if s.is_empty() {
+        return Err(pfe_invalid());
+    }
+
+    if let Some((mut num, len)) = parse_partial_number(s) {
+        num.negative = negative;
+        return Ok((decimal_to_float(num, &s[..len]), sign_len + len));
+    }
+    match parse_partial_inf_nan(s, negative) {
+        Some((value, len)) => Ok((value, sign_len + len)),
+        None => Err(pfe_invalid()),
+    }
+}
+
+/// Rounds a parsed decimal to the closest machine float.
+///
+/// `s` must be exactly the unsigned digits that `num` was parsed from, since
+/// the slow path needs to re-read them.
+#[inline(always)]
+fn decimal_to_float<F: RawFloat>(num: Decimal, s: &[u8]) -> F {
     if !cfg!(feature = "optimize_for_size") {
         if let Some(value) = num.try_fast_path::<F>() {
-            return Ok(value);
+            return value;
         }
     }
 
@@ -304,5 +373,5 @@ pub fn dec2flt<F: RawFloat>(s: &str) -> Result<F, ParseFloatError> {
     if num.negative {
         float = -float;
     }
-    Ok(float)
+    float
 }
@@ -99,8 +99,9 @@ fn parse_scientific(s_ref: &mut &[u8]) -> Option<i64> {
 /// Parse a partial, non-special floating point number.
 ///
 /// This creates a representation of the float as the
-/// significant digits and the decimal exponent.
-fn parse_partial_number(mut s: &[u8]) -> Option<(Decimal, usize)> {
+/// significant digits and the decimal exponent, along with the
+/// number of bytes that make up the number.
+pub(crate) fn parse_partial_number(mut s: &[u8]) -> Option<(Decimal, usize)> {
     debug_assert!(!s.is_empty());
 
     // parse initial digits before dot
@@ -135,10 +136,14 @@ fn parse_partial_number(mut s: &[u8]) -> Option<(Decimal, usize)> {
     let mut exp_number = 0_i64;
     if let Some((&c, s_next)) = s.split_first() {
         if c == b'e' || c == b'E' {
-            s = s_next;
-            // If None, we have no trailing digits after exponent, or an invalid float.
-            exp_number = parse_scientific(&mut s)?;
-            exponent += exp_number;
+            let mut s_exp = s_next;
+            // If None, we have no trailing digits after the exponent, so the number ends before
+            // the `e`. Callers that require the whole input to be consumed will then reject it.
+            if let Some(exp) = parse_scientific(&mut s_exp) {
+                s = s_exp;
+                exp_number = exp;
+                exponent += exp_number;
+            }
         }
     }
 
@@ -239,3 +244,20 @@ pub(crate) fn parse_inf_nan<F: RawFloat>(s: &[u8], negative: bool) -> Option<F>
 
     if negative { Some(-float) } else { Some(float) }
 }
+
+/// Try to parse a special, non-finite float at the start of `s`,
+/// returning it along with the number of bytes it spans.
+pub(crate) fn parse_partial_inf_nan<F: RawFloat>(s: &[u8], negative: bool) -> Option<(F, usize)> {
+    // Check the longest spelling first, so that "infinity" is not cut short at "inf".
+    if s.len() >= 8 {
+        if let Some(float) = parse_inf_nan(&s[..8], negative) {
+            return Some((float, 8));
+        }
+    }
+    if s.len() >= 3 {
+        if let Some(float) = parse_inf_nan(&s[..3], negative) {
+            return Some((float, 3));
+        }
+    }
+    None
+}
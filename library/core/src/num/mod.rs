@@ -1623,6 +1623,72 @@ macro_rules! run_checked_loop {
                 }
                 Ok(result)
             }
+
+            /// Parses an integer with decimal digits from the start of an ASCII-byte slice,
+            /// returning it along with the number of bytes that were consumed.
+            ///
+            /// This accepts an optional sign followed by digits, like [`from_ascii`], but stops
+            /// at the first byte that is not a digit instead of rejecting it.
+            ///
+            /// [`from_ascii`]: Self::from_ascii
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(parse_prefix)]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($int_ty), "::parse_prefix(b\"+10,20\"), Ok((10, 3)));")]
+            #[doc = concat!("assert!(", stringify!($int_ty), "::parse_prefix(b\",20\").is_err());")]
+            /// ```
+            #[unstable(feature = "parse_prefix", issue = "none")]
+            #[inline]
+            pub const fn parse_prefix(src: &[u8]) -> Result<($int_ty, usize), ParseIntError> {
+                <$int_ty>::parse_prefix_radix(src, 10)
+            }
+
+            /// Parses an integer with digits in a given base from the start of an ASCII-byte
+            /// slice, returning it along with the number of bytes that were consumed.
+            ///
+            /// This accepts an optional sign followed by digits, like [`from_ascii_radix`], but
+            /// stops at the first byte that is not a digit in `radix` instead of rejecting it.
+            ///
+            /// [`from_ascii_radix`]: Self::from_ascii_radix
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 36.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(parse_prefix)]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($int_ty), "::parse_prefix_radix(b\"7fz\", 16), Ok((127, 2)));")]
+            /// ```
+            #[unstable(feature = "parse_prefix", issue = "none")]
+            #[inline]
+            pub const fn parse_prefix_radix(
+                src: &[u8],
+                radix: u32,
+            ) -> Result<($int_ty, usize), ParseIntError> {
+                if 2 > radix || radix > 36 {
+                    from_ascii_radix_panic(radix);
+                }
+
+                let mut len = match src {
+                    [b'+' | b'-', ..] => 1,
+                    _ => 0,
+                };
+                while len < src.len() && (src[len] as char).is_digit(radix) {
+                    len += 1;
+                }
+                // Hand the digits we found to the whole-input parser, which reports a missing
+                // digit, a misplaced sign and overflow the same way it always does.
+                match <$int_ty>::from_ascii_radix(src.split_at(len).0, radix) {
+                    Ok(value) => Ok((value, len)),
+                    Err(err) => Err(err),
+                }
+            }
         }
     )*}
 }
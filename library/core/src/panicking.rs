@@ -330,6 +330,24 @@ fn panic_invalid_enum_construction(source: u128) -> ! {
     )
 }
 
+#[cfg_attr(not(feature = "panic_immediate_abort"), inline(never), cold, optimize(size))]
+#[cfg_attr(feature = "panic_immediate_abort", inline)]
+#[track_caller]
+#[lang = "panic_invalid_scalar_value"] // needed by codegen for panic on invalid scalar values.
+#[rustc_nounwind] // `CheckScalarValidity` MIR pass requires this function to never unwind
+fn panic_invalid_scalar_value(value: u128, start: u128, end: u128) -> ! {
+    if cfg!(feature = "panic_immediate_abort") {
+        super::intrinsics::abort()
+    }
+
+    panic_nounwind_fmt(
+        format_args!(
+            "constructing an invalid value {value:#x}, expected a value in {start:#x}..={end:#x}"
+        ),
+        /* force_no_backtrace */ false,
+    )
+}
+
 /// Panics because we cannot unwind out of a function.
 ///
 /// This is a separate function to avoid the codesize impact of each crate containing the string to
@@ -1,6 +1,7 @@
 //! Random value generation.
 
 use crate::range::RangeFull;
+use crate::{ops, range};
 
 /// A source of randomness.
 #[unstable(feature = "random", issue = "130703")]
@@ -58,3 +59,361 @@ fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> $t {
 impl_primitive!(i128);
 impl_primitive!(usize);
 impl_primitive!(isize);
+
+macro_rules! impl_range {
+    ($t:ty, $u:ty) => {
+        impl Distribution<$t> for ops::Range<$t> {
+            /// Samples a value uniformly from `start..end`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty.
+            fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> $t {
+                assert!(self.start < self.end, "cannot sample from an empty range");
+                let n = (self.end as $u).wrapping_sub(self.start as $u);
+                self.start.wrapping_add(below::<$u>(source, n) as $t)
+            }
+        }
+
+        impl Distribution<$t> for ops::RangeInclusive<$t> {
+            /// Samples a value uniformly from `start..=end`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty.
+            fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> $t {
+                let (start, end) = (*self.start(), *self.end());
+                assert!(start <= end, "cannot sample from an empty range");
+                // A full-width range wraps `n` around to zero, which `below` treats as "any value".
+                let n = (end as $u).wrapping_sub(start as $u).wrapping_add(1);
+                start.wrapping_add(below::<$u>(source, n) as $t)
+            }
+        }
+
+        impl Distribution<$t> for range::Range<$t> {
+            fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> $t {
+                (self.start..self.end).sample(source)
+            }
+        }
+
+        impl Distribution<$t> for range::RangeInclusive<$t> {
+            fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> $t {
+                (self.start..=self.end).sample(source)
+            }
+        }
+    };
+}
+
+/// Unsigned integers that can be sampled below an exclusive bound.
+trait Below: Copy {
+    fn below(source: &mut (impl RandomSource + ?Sized), n: Self) -> Self;
+}
+
+macro_rules! impl_below {
+    ($($u:ty),*) => {$(
+        impl Below for $u {
+            /// Lemire's nearly-divisionless method: multiply a random word by `n` and keep
+            /// the high half, rejecting the few low halves that would bias the result.
+            ///
+            /// See <https://arxiv.org/abs/1805.10941>.
+            #[inline]
+            fn below(source: &mut (impl RandomSource + ?Sized), n: $u) -> $u {
+                let x: $u = RangeFull.sample(source);
+                if n == 0 {
+                    return x;
+                }
+                let (mut lo, mut hi) = x.widening_mul(n);
+                if lo < n {
+                    let threshold = n.wrapping_neg() % n;
+                    while lo < threshold {
+                        let x: $u = RangeFull.sample(source);
+                        (lo, hi) = x.widening_mul(n);
+                    }
+                }
+                hi
+            }
+        }
+    )*};
+}
+
+impl_below!(u8, u16, u32, u64, u128, usize);
+
+/// Returns a uniformly distributed value in `0..n`, or any value at all if `n` is zero.
+#[inline]
+fn below<U: Below>(source: &mut (impl RandomSource + ?Sized), n: U) -> U {
+    U::below(source, n)
+}
+
+impl_range!(u8, u8);
+impl_range!(i8, u8);
+impl_range!(u16, u16);
+impl_range!(i16, u16);
+impl_range!(u32, u32);
+impl_range!(i32, u32);
+impl_range!(u64, u64);
+impl_range!(i64, u64);
+impl_range!(u128, u128);
+impl_range!(i128, u128);
+impl_range!(usize, usize);
+impl_range!(isize, usize);
+
+macro_rules! impl_float {
+    ($f:ty, $u:ty) => {
+        impl Distribution<$f> for ops::Range<$f> {
+            /// Samples a value uniformly from `start..end`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty or if its width is not finite.
+            fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> $f {
+                let (start, end) = (self.start, self.end);
+                let width = end - start;
+                assert!(start < end && width.is_finite(), "cannot sample from an invalid range");
+                loop {
+                    // Rounding can land exactly on `end`; resample so the bound stays exclusive.
+                    let value = start + width * unit::<$f, $u>(source, false);
+                    if value < end {
+                        return value;
+                    }
+                }
+            }
+        }
+
+        impl Distribution<$f> for ops::RangeInclusive<$f> {
+            /// Samples a value uniformly from `start..=end`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty or if its width is not finite.
+            fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> $f {
+                let (start, end) = (*self.start(), *self.end());
+                let width = end - start;
+                assert!(start <= end && width.is_finite(), "cannot sample from an invalid range");
+                (start + width * unit::<$f, $u>(source, true)).min(end)
+            }
+        }
+
+        impl Distribution<$f> for range::Range<$f> {
+            fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> $f {
+                (self.start..self.end).sample(source)
+            }
+        }
+
+        impl Distribution<$f> for range::RangeInclusive<$f> {
+            fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> $f {
+                (self.start..=self.end).sample(source)
+            }
+        }
+
+        impl Unit<$u> for $f {
+            #[inline]
+            fn unit(source: &mut (impl RandomSource + ?Sized), inclusive: bool) -> $f {
+                // Keep as many random bits as the significand holds, so that every output is
+                // an exact multiple of `2^-MANTISSA_DIGITS`.
+                let x: $u = RangeFull.sample(source);
+                let bits = x >> (<$u>::BITS - <$f>::MANTISSA_DIGITS);
+                if inclusive {
+                    let max = <$u>::MAX >> (<$u>::BITS - <$f>::MANTISSA_DIGITS);
+                    bits as $f / max as $f
+                } else {
+                    // `EPSILON / 2` is exactly `2^-MANTISSA_DIGITS`.
+                    bits as $f * (<$f>::EPSILON / 2.0)
+                }
+            }
+        }
+    };
+}
+
+/// Floats that can be sampled uniformly from `[0, 1)` or `[0, 1]`.
+trait Unit<U> {
+    fn unit(source: &mut (impl RandomSource + ?Sized), inclusive: bool) -> Self;
+}
+
+#[inline]
+fn unit<F: Unit<U>, U>(source: &mut (impl RandomSource + ?Sized), inclusive: bool) -> F {
+    F::unit(source, inclusive)
+}
+
+impl_float!(f16, u16);
+impl_float!(f32, u32);
+impl_float!(f64, u64);
+impl_float!(f128, u128);
+
+/// Shuffles `slice` in place, so that every permutation is equally likely.
+///
+/// This is the Fisher-Yates shuffle, and makes one bounded draw from `source` per element.
+#[unstable(feature = "random", issue = "130703")]
+pub fn shuffle<T>(slice: &mut [T], source: &mut (impl RandomSource + ?Sized)) {
+    for i in (1..slice.len()).rev() {
+        let j = (0..=i).sample(source);
+        slice.swap(i, j);
+    }
+}
+
+/// Returns a uniformly chosen element of `slice`, or `None` if it is empty.
+#[unstable(feature = "random", issue = "130703")]
+pub fn choose<'a, T>(slice: &'a [T], source: &mut (impl RandomSource + ?Sized)) -> Option<&'a T> {
+    if slice.is_empty() {
+        return None;
+    }
+    let i = (0..slice.len()).sample(source);
+    Some(&slice[i])
+}
+
+/// Returns a uniformly chosen mutable element of `slice`, or `None` if it is empty.
+#[unstable(feature = "random", issue = "130703")]
+pub fn choose_mut<'a, T>(
+    slice: &'a mut [T],
+    source: &mut (impl RandomSource + ?Sized),
+) -> Option<&'a mut T> {
+    if slice.is_empty() {
+        return None;
+    }
+    let i = (0..slice.len()).sample(source);
+    Some(&mut slice[i])
+}
+
+/// A small, fast, seedable pseudo-random number generator.
+///
+/// `SeededRng` is an implementation of [xoshiro256++], which passes common statistical test
+/// suites and has a period of 2<sup>256</sup> - 1. It is intended for simulations, property
+/// tests and other places where a reproducible stream of numbers is useful.
+///
+/// The output stream for a given seed is stable: it will not change across releases, on any
+/// platform. It is **not** cryptographically secure and must not be used where an attacker could
+/// benefit from predicting its output. Use an OS-backed source for that instead.
+///
+/// [xoshiro256++]: https://prng.di.unimi.it/
+///
+/// # Examples
+///
+/// ```
+/// #![feature(random)]
+/// use core::random::{Distribution, SeededRng};
+///
+/// let mut rng = SeededRng::seed_from_u64(42);
+/// let mut same = SeededRng::seed_from_u64(42);
+/// let roll: u8 = (1..=6).sample(&mut rng);
+/// assert_eq!(roll, (1..=6).sample(&mut same));
+/// ```
+#[unstable(feature = "random", issue = "130703")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeededRng {
+    state: [u64; 4],
+}
+
+impl SeededRng {
+    /// Creates a generator from a 256-bit seed.
+    ///
+    /// The seed is read as four little-endian `u64` words that make up the generator state.
+    /// Since the all-zero state would only ever produce zeroes, an all-zero seed is replaced by
+    /// the state of [`SeededRng::seed_from_u64(0)`](Self::seed_from_u64).
+    #[unstable(feature = "random", issue = "130703")]
+    #[must_use]
+    pub const fn from_seed(seed: [u8; 32]) -> SeededRng {
+        let mut state = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            let mut word = [0; 8];
+            let mut j = 0;
+            while j < 8 {
+                word[j] = seed[i * 8 + j];
+                j += 1;
+            }
+            state[i] = u64::from_le_bytes(word);
+            i += 1;
+        }
+        if state[0] | state[1] | state[2] | state[3] == 0 {
+            return SeededRng::seed_from_u64(0);
+        }
+        SeededRng { state }
+    }
+
+    /// Creates a generator from a 64-bit seed.
+    ///
+    /// The seed is expanded into the full state with SplitMix64, so that similar seeds still
+    /// produce unrelated streams.
+    #[unstable(feature = "random", issue = "130703")]
+    #[must_use]
+    pub const fn seed_from_u64(mut seed: u64) -> SeededRng {
+        const fn splitmix64(x: &mut u64) -> u64 {
+            *x = x.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = *x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        }
+
+        let state = [
+            splitmix64(&mut seed),
+            splitmix64(&mut seed),
+            splitmix64(&mut seed),
+            splitmix64(&mut seed),
+        ];
+        SeededRng { state }
+    }
+
+    /// Returns the next 64 bits of the stream.
+    #[unstable(feature = "random", issue = "130703")]
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Splits off an independent generator.
+    ///
+    /// The returned generator continues the current stream, while `self` jumps 2<sup>128</sup>
+    /// steps ahead. The two streams therefore never overlap in practice, and the result is as
+    /// reproducible as the original seed.
+    #[unstable(feature = "random", issue = "130703")]
+    #[must_use = "forking advances `self` even if the new generator is unused"]
+    pub fn fork(&mut self) -> SeededRng {
+        let child = self.clone();
+        self.jump();
+        child
+    }
+
+    /// Advances the state by 2<sup>128</sup> steps.
+    fn jump(&mut self) {
+        const JUMP: [u64; 4] =
+            [0x180ec6d33cfd0aba, 0xd5a61266f0c9392c, 0xa9582618e03fc9aa, 0x39abdc4529b1661c];
+
+        let mut acc = [0; 4];
+        for word in JUMP {
+            for bit in 0..64 {
+                if word & (1 << bit) != 0 {
+                    for (a, s) in acc.iter_mut().zip(self.state) {
+                        *a ^= s;
+                    }
+                }
+                self.next_u64();
+            }
+        }
+        self.state = acc;
+    }
+}
+
+#[unstable(feature = "random", issue = "130703")]
+impl RandomSource for SeededRng {
+    /// Fills `bytes` with the little-endian bytes of successive outputs, discarding the unused
+    /// tail of the last one.
+    fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        let mut chunks = bytes.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let rest = chunks.into_remainder();
+        if !rest.is_empty() {
+            rest.copy_from_slice(&self.next_u64().to_le_bytes()[..rest.len()]);
+        }
+    }
+}
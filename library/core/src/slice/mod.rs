@@ -74,6 +74,8 @@
 pub use raw::{from_mut_ptr_range, from_ptr_range};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use raw::{from_raw_parts, from_raw_parts_mut};
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+pub use sort::radix::RadixKey;
 
 /// Calculates the direction and split point of a one-sided range.
 ///
@@ -3207,6 +3209,72 @@ pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
         sort::unstable::sort(self, &mut |a, b| f(a).lt(&f(b)));
     }
 
+    /// Sorts the slice in ascending order with a radix sort, **without** preserving the initial
+    /// order of equal elements.
+    ///
+    /// Instead of comparing elements, a radix sort distributes them by the bytes of their
+    /// [`RadixKey`] representation, which is often several times faster than a comparison sort
+    /// for large slices of integers or floats. Floats are ordered like [`f64::total_cmp`].
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements), in-place (i.e., does not
+    /// allocate), and *O*(*n* \* *k*), where *k* is the size of `T` in bytes.
+    ///
+    /// # Current implementation
+    ///
+    /// The current implementation is an MSD radix sort, also known as American flag sort, that
+    /// finishes small buckets with insertion sort.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_radix_sort)]
+    ///
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    /// v.radix_sort_unstable();
+    /// assert_eq!(v, [-5, -3, 1, 2, 4]);
+    ///
+    /// let mut v = [2.5f64, -0.0, f64::NEG_INFINITY, 0.0, -1.0];
+    /// v.radix_sort_unstable();
+    /// assert_eq!(v.map(f64::to_bits), [f64::NEG_INFINITY, -1.0, -0.0, 0.0, 2.5].map(f64::to_bits));
+    /// ```
+    #[unstable(feature = "slice_radix_sort", issue = "none")]
+    #[inline]
+    pub fn radix_sort_unstable(&mut self)
+    where
+        T: RadixKey,
+    {
+        sort::radix::sort_unstable(self, &mut |x: &T| *x);
+    }
+
+    /// Sorts the slice in ascending order with a radix sort on a key extraction function,
+    /// **without** preserving the initial order of equal elements.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements), in-place (i.e., does not
+    /// allocate), and *O*(*n* \* *k*), where *k* is the size of `K` in bytes. The key
+    /// function is called *O*(*n* \* *k*) times, so it should be cheap; for expensive keys
+    /// see `sort_by_radix_key`, which calls it once per element.
+    ///
+    /// See [`radix_sort_unstable`](slice::radix_sort_unstable) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_radix_sort)]
+    ///
+    /// let mut v = [(3, 'c'), (1, 'a'), (2, 'b')];
+    /// v.sort_unstable_by_radix_key(|&(k, _)| k as u32);
+    /// assert_eq!(v, [(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// ```
+    #[unstable(feature = "slice_radix_sort", issue = "none")]
+    #[inline]
+    pub fn sort_unstable_by_radix_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: RadixKey,
+    {
+        sort::radix::sort_unstable(self, &mut f);
+    }
+
     /// Reorders the slice such that the element at `index` is at a sort-order position. All
     /// elements before `index` will be `<=` to this value, and all elements after will be `>=` to
     /// it.
@@ -3406,6 +3474,111 @@ pub fn select_nth_unstable_by_key<K, F>(
         sort::select::partition_at_index(self, index, |a: &T, b: &T| f(a).lt(&f(b)))
     }
 
+    /// Reorders the slice such that its `k` smallest elements are at the front, in ascending
+    /// order, **without** preserving the initial order of equal elements.
+    ///
+    /// The order of the remaining elements is unspecified. If `k` is at least the length of the
+    /// slice, the whole slice is sorted.
+    ///
+    /// This is equivalent to sorting the slice and only looking at the first `k` elements, but
+    /// faster when `k` is small: it is unstable (i.e. may reorder equal elements), in-place
+    /// (i.e. does not allocate), and *O*(*n* + *k* \* log(*k*)) on average.
+    ///
+    /// # Current implementation
+    ///
+    /// The current implementation moves the `k` smallest elements to the front with the same
+    /// algorithm as [`select_nth_unstable`], then sorts them with the same algorithm as
+    /// [`sort_unstable`].
+    ///
+    /// [`select_nth_unstable`]: slice::select_nth_unstable
+    /// [`sort_unstable`]: slice::sort_unstable
+    ///
+    /// # Panics
+    ///
+    /// May panic if the implementation of [`Ord`] for `T` does not implement a [total order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_partial_sort)]
+    ///
+    /// let mut v = [-5, 4, 2, -3, 1];
+    /// v.partial_sort_unstable(3);
+    /// assert_eq!(v[..3], [-5, -3, 1]);
+    /// ```
+    ///
+    /// [total order]: https://en.wikipedia.org/wiki/Total_order
+    #[unstable(feature = "slice_partial_sort", issue = "none")]
+    #[inline]
+    pub fn partial_sort_unstable(&mut self, k: usize)
+    where
+        T: Ord,
+    {
+        sort::select::partial_sort(self, k, T::lt);
+    }
+
+    /// Reorders the slice with a comparator function such that its `k` smallest elements are
+    /// at the front, in ascending order, **without** preserving the initial order of equal
+    /// elements.
+    ///
+    /// See [`partial_sort_unstable`](slice::partial_sort_unstable) for details.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `compare` does not implement a [total order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_partial_sort)]
+    ///
+    /// let mut v = [-5, 4, 2, -3, 1];
+    /// // The two largest elements, in descending order.
+    /// v.partial_sort_unstable_by(2, |a, b| b.cmp(a));
+    /// assert_eq!(v[..2], [4, 2]);
+    /// ```
+    ///
+    /// [total order]: https://en.wikipedia.org/wiki/Total_order
+    #[unstable(feature = "slice_partial_sort", issue = "none")]
+    #[inline]
+    pub fn partial_sort_unstable_by<F>(&mut self, k: usize, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::select::partial_sort(self, k, |a: &T, b: &T| compare(a, b) == Less);
+    }
+
+    /// Reorders the slice with a key extraction function such that the `k` elements with the
+    /// smallest keys are at the front, in ascending order of their keys, **without** preserving
+    /// the initial order of equal elements.
+    ///
+    /// See [`partial_sort_unstable`](slice::partial_sort_unstable) for details.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the implementation of [`Ord`] for `K` does not implement a [total order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_partial_sort)]
+    ///
+    /// let mut v = [-5i32, 4, 2, -3, 1];
+    /// v.partial_sort_unstable_by_key(2, |k| k.abs());
+    /// assert_eq!(v[..2], [1, 2]);
+    /// ```
+    ///
+    /// [total order]: https://en.wikipedia.org/wiki/Total_order
+    #[unstable(feature = "slice_partial_sort", issue = "none")]
+    #[inline]
+    pub fn partial_sort_unstable_by_key<K, F>(&mut self, k: usize, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        sort::select::partial_sort(self, k, |a: &T, b: &T| f(a).lt(&f(b)));
+    }
+
     /// Moves all consecutive repeated elements to the end of the slice according to the
     /// [`PartialEq`] trait implementation.
     ///
@@ -0,0 +1,230 @@
+//! This module contains radix sorts, which order elements by the bytes of an integer-like key
+//! instead of by comparing them.
+
+use crate::mem::{self, SizedTypeProperties};
+use crate::slice::sort::shared::smallsort::insertion_sort_shift_left;
+
+/// A sort key that can be ordered byte by byte, as used by radix sorting.
+///
+/// The order given by comparing [`radix_byte`] from the most significant byte
+/// (`BYTES - 1`) down to the least significant one (`0`) is the order the sort produces.
+/// For integers and `char` this is their usual order. For floats it is the order of
+/// [`f64::total_cmp`] and its counterparts, so that negative zero sorts before positive zero
+/// and NaNs sort to the ends according to their sign.
+///
+/// [`radix_byte`]: RadixKey::radix_byte
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+pub trait RadixKey: Copy {
+    /// The number of bytes in the key.
+    const BYTES: usize;
+
+    /// Returns byte `i` of the key, counting from the least significant byte.
+    ///
+    /// `i` is always less than [`BYTES`](RadixKey::BYTES).
+    fn radix_byte(self, i: usize) -> u8;
+}
+
+macro_rules! impl_radix_key_unsigned {
+    ($($t:ty)*) => {$(
+        #[unstable(feature = "slice_radix_sort", issue = "none")]
+        impl RadixKey for $t {
+            const BYTES: usize = size_of::<$t>();
+
+            #[inline]
+            fn radix_byte(self, i: usize) -> u8 {
+                (self >> (8 * i)) as u8
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_radix_key_signed {
+    ($($t:ty => $u:ty),*) => {$(
+        #[unstable(feature = "slice_radix_sort", issue = "none")]
+        impl RadixKey for $t {
+            const BYTES: usize = size_of::<$t>();
+
+            #[inline]
+            fn radix_byte(self, i: usize) -> u8 {
+                // Flipping the sign bit maps `MIN..=MAX` onto `0..=<$u>::MAX` in order.
+                let bits = (self as $u) ^ (1 << (<$u>::BITS - 1));
+                (bits >> (8 * i)) as u8
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_radix_key_float {
+    ($($t:ty => $u:ty),*) => {$(
+        #[unstable(feature = "slice_radix_sort", issue = "none")]
+        impl RadixKey for $t {
+            const BYTES: usize = size_of::<$t>();
+
+            #[inline]
+            fn radix_byte(self, i: usize) -> u8 {
+                // The same transformation as `total_cmp`: negative values have all bits flipped
+                // so that larger magnitudes sort first, and positive values get the sign bit set
+                // so that they sort after all negative ones.
+                let bits = self.to_bits();
+                let sign = 1 << (<$u>::BITS - 1);
+                let bits = if bits & sign != 0 { !bits } else { bits | sign };
+                (bits >> (8 * i)) as u8
+            }
+        }
+    )*};
+}
+
+impl_radix_key_unsigned! { u8 u16 u32 u64 u128 usize }
+impl_radix_key_signed! { i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize }
+impl_radix_key_float! { f16 => u16, f32 => u32, f64 => u64, f128 => u128 }
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl RadixKey for bool {
+    const BYTES: usize = 1;
+
+    #[inline]
+    fn radix_byte(self, _i: usize) -> u8 {
+        self as u8
+    }
+}
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl RadixKey for char {
+    const BYTES: usize = 4;
+
+    #[inline]
+    fn radix_byte(self, i: usize) -> u8 {
+        (self as u32).radix_byte(i)
+    }
+}
+
+/// Buckets at most this long are finished with insertion sort instead of further radix passes.
+const SMALL_SORT_THRESHOLD: usize = 32;
+
+/// In-place, unstable MSD radix sort, also known as American flag sort.
+///
+/// This does not allocate, and recurses at most `K::BYTES` levels deep.
+#[inline(always)]
+pub fn sort_unstable<T, K: RadixKey, F: FnMut(&T) -> K>(v: &mut [T], key: &mut F) {
+    // Arrays of zero-sized types are always all-equal, and thus sorted.
+    if T::IS_ZST || v.len() < 2 || K::BYTES == 0 {
+        return;
+    }
+    msd_sort(v, key, K::BYTES - 1);
+}
+
+fn msd_sort<T, K: RadixKey, F: FnMut(&T) -> K>(v: &mut [T], key: &mut F, mut byte: usize) {
+    let len = v.len();
+    if len <= SMALL_SORT_THRESHOLD {
+        insertion_sort_shift_left(v, 1, &mut |a, b| key_lt(key(a), key(b), byte));
+        return;
+    }
+
+    let mut counts = [0usize; 256];
+    loop {
+        counts.fill(0);
+        for x in v.iter() {
+            counts[key(x).radix_byte(byte) as usize] += 1;
+        }
+        // Skip bytes that every element shares without touching the slice.
+        if !counts.contains(&len) {
+            break;
+        }
+        if byte == 0 {
+            return;
+        }
+        byte -= 1;
+    }
+
+    let mut starts = [0usize; 256];
+    let mut ends = [0usize; 256];
+    let mut sum = 0;
+    for b in 0..256 {
+        starts[b] = sum;
+        sum += counts[b];
+        ends[b] = sum;
+    }
+
+    // Walk each bucket and swap every misplaced element straight into the next free slot of the
+    // bucket it belongs to. Once a bucket's cursor reaches its end, the bucket is complete.
+    let mut next = starts;
+    for b in 0..256 {
+        while next[b] < ends[b] {
+            let d = key(&v[next[b]]).radix_byte(byte) as usize;
+            if d == b {
+                next[b] += 1;
+            } else {
+                v.swap(next[b], next[d]);
+                next[d] += 1;
+            }
+        }
+    }
+
+    if byte > 0 {
+        for b in 0..256 {
+            if counts[b] > 1 {
+                msd_sort(&mut v[starts[b]..ends[b]], key, byte - 1);
+            }
+        }
+    }
+}
+
+/// Compares two keys on bytes `top` down to `0`, the only ones that can still differ.
+#[inline]
+fn key_lt<K: RadixKey>(a: K, b: K, top: usize) -> bool {
+    for i in (0..=top).rev() {
+        let (x, y) = (a.radix_byte(i), b.radix_byte(i));
+        if x != y {
+            return x < y;
+        }
+    }
+    false
+}
+
+/// Stable LSD radix sort, using `scratch` as the second buffer of each pass.
+///
+/// `scratch` must be at least as long as `v`; its contents are overwritten.
+pub fn sort_stable_with_scratch<T: Copy, K: RadixKey, F: FnMut(&T) -> K>(
+    v: &mut [T],
+    scratch: &mut [T],
+    key: &mut F,
+) {
+    let len = v.len();
+    if len < 2 {
+        return;
+    }
+    let mut src = v;
+    let mut dst = &mut scratch[..len];
+    let mut in_scratch = false;
+
+    for byte in 0..K::BYTES {
+        let mut counts = [0usize; 256];
+        for x in src.iter() {
+            counts[key(x).radix_byte(byte) as usize] += 1;
+        }
+        // Every element would stay where it is.
+        if counts.contains(&len) {
+            continue;
+        }
+
+        let mut offsets = [0usize; 256];
+        let mut sum = 0;
+        for b in 0..256 {
+            offsets[b] = sum;
+            sum += counts[b];
+        }
+        for x in src.iter() {
+            let b = key(x).radix_byte(byte) as usize;
+            dst[offsets[b]] = *x;
+            offsets[b] += 1;
+        }
+
+        mem::swap(&mut src, &mut dst);
+        in_scratch = !in_scratch;
+    }
+
+    // After an odd number of passes the result lives in `scratch`.
+    if in_scratch {
+        dst.copy_from_slice(src);
+    }
+}
@@ -1,4 +1,5 @@
-//! This module contains the implementation for `slice::select_nth_unstable`.
+//! This module contains the implementation for `slice::select_nth_unstable`, which
+//! `slice::partial_sort_unstable` builds on.
 //! It uses an introselect algorithm based on ipnsort by Lukas Bergdoll and Orson Peters,
 //! published at: <https://github.com/Voultapher/sort-research-rs/tree/main/ipnsort>
 //!
@@ -6,12 +7,13 @@
 //! for pivot selection. Using this as a fallback ensures O(n) worst case running time with
 //! better performance than one would get using heapsort as fallback.
 
-use crate::cfg_select;
 use crate::mem::{self, SizedTypeProperties};
 #[cfg(not(feature = "optimize_for_size"))]
 use crate::slice::sort::shared::pivot::choose_pivot;
 use crate::slice::sort::shared::smallsort::insertion_sort_shift_left;
+use crate::slice::sort::unstable;
 use crate::slice::sort::unstable::quicksort::partition;
+use crate::{cfg_select, cmp};
 
 /// Reorders the slice such that the element at `index` is at its final sorted position.
 pub(crate) fn partition_at_index<T, F>(
@@ -58,6 +60,25 @@ pub(crate) fn partition_at_index<T, F>(
     (left, pivot, right)
 }
 
+/// Moves the `k` smallest elements of `v` to its front and sorts them, leaving the rest of the
+/// slice in unspecified order. Sorts all of `v` if `k >= v.len()`.
+pub(crate) fn partial_sort<T, F>(v: &mut [T], k: usize, mut is_less: F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let k = cmp::min(k, v.len());
+    if k == 0 || T::IS_ZST {
+        return;
+    }
+
+    if k < v.len() {
+        // Afterwards nothing before `k` is greater than anything from `k` on, so only the front
+        // still needs sorting.
+        partition_at_index(v, k, &mut is_less);
+    }
+    unstable::sort(&mut v[..k], &mut is_less);
+}
+
 // For small sub-slices it's faster to use a dedicated small-sort, but because it is only called at
 // most once, it doesn't make sense to use something more sophisticated than insertion-sort.
 const INSERTION_SORT_THRESHOLD: usize = 16;
@@ -3,7 +3,7 @@
 use super::pattern::{DoubleEndedSearcher, Pattern, ReverseSearcher, Searcher};
 use super::validations::{next_code_point, next_code_point_reverse};
 use super::{
-    BytesIsNotEmpty, CharEscapeDebugContinue, CharEscapeDefault, CharEscapeUnicode,
+    BytesIsNotEmpty, CharCaseFold, CharEscapeDebugContinue, CharEscapeDefault, CharEscapeUnicode,
     IsAsciiWhitespace, IsNotEmpty, IsWhitespace, LinesMap, UnsafeBytesToStr, from_utf8_unchecked,
 };
 use crate::fmt::{self, Write};
@@ -14,6 +14,8 @@
 use crate::num::NonZero;
 use crate::ops::Try;
 use crate::slice::{self, Split as SliceSplit};
+use crate::unicode::normalize::{Decompositions, Recompositions};
+use crate::unicode::segment::{next_grapheme_len, next_word_len};
 use crate::{char as char_mod, option};
 
 /// An iterator over the [`char`]s of a string slice.
@@ -1608,3 +1610,202 @@ impl<'a> FusedIterator for $Name<'a> {}
 }
 
 escape_types_impls!(EscapeDebug, EscapeDefault, EscapeUnicode);
+
+/// The return type of [`str::nfd`].
+#[unstable(feature = "str_normalize", issue = "none")]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Nfd<'a> {
+    pub(super) inner: Decompositions<Chars<'a>>,
+}
+
+/// The return type of [`str::nfkd`].
+#[unstable(feature = "str_normalize", issue = "none")]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Nfkd<'a> {
+    pub(super) inner: Decompositions<Chars<'a>>,
+}
+
+/// The return type of [`str::nfc`].
+#[unstable(feature = "str_normalize", issue = "none")]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Nfc<'a> {
+    pub(super) inner: Recompositions<Chars<'a>>,
+}
+
+/// The return type of [`str::nfkc`].
+#[unstable(feature = "str_normalize", issue = "none")]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Nfkc<'a> {
+    pub(super) inner: Recompositions<Chars<'a>>,
+}
+
+macro_rules! normalization_types_impls {
+    ($( $Name: ident ),+) => {$(
+        #[unstable(feature = "str_normalize", issue = "none")]
+        impl<'a> fmt::Display for $Name<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.clone().try_for_each(|c| f.write_char(c))
+            }
+        }
+
+        #[unstable(feature = "str_normalize", issue = "none")]
+        impl<'a> Iterator for $Name<'a> {
+            type Item = char;
+
+            #[inline]
+            fn next(&mut self) -> Option<char> { self.inner.next() }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+        }
+
+        #[unstable(feature = "str_normalize", issue = "none")]
+        impl<'a> FusedIterator for $Name<'a> {}
+    )+}
+}
+
+normalization_types_impls!(Nfd, Nfkd, Nfc, Nfkc);
+
+/// The return type of [`str::case_fold`].
+#[unstable(feature = "str_case_fold", issue = "none")]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CaseFold<'a> {
+    pub(super) inner: FlatMap<Chars<'a>, char_mod::CaseMappingIter, CharCaseFold>,
+}
+
+#[unstable(feature = "str_case_fold", issue = "none")]
+impl<'a> fmt::Display for CaseFold<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.clone().try_for_each(|c| f.write_char(c))
+    }
+}
+
+#[unstable(feature = "str_case_fold", issue = "none")]
+impl<'a> Iterator for CaseFold<'a> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, fold: Fold) -> Acc
+    where
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.inner.fold(init, fold)
+    }
+}
+
+#[unstable(feature = "str_case_fold", issue = "none")]
+impl<'a> FusedIterator for CaseFold<'a> {}
+
+/// An iterator over the extended grapheme clusters of a string slice.
+///
+/// This struct is created by the [`graphemes`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`graphemes`]: str::graphemes
+#[unstable(feature = "str_segmentation", issue = "none")]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Graphemes<'a> {
+    pub(super) rest: &'a str,
+}
+
+impl<'a> Graphemes<'a> {
+    /// Views the underlying data as a subslice of the original data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_segmentation)]
+    ///
+    /// let mut graphemes = "e\u{301}a".graphemes();
+    ///
+    /// assert_eq!(graphemes.as_str(), "e\u{301}a");
+    /// graphemes.next();
+    /// assert_eq!(graphemes.as_str(), "a");
+    /// graphemes.next();
+    /// assert_eq!(graphemes.as_str(), "");
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "str_segmentation", issue = "none")]
+    pub fn as_str(&self) -> &'a str {
+        self.rest
+    }
+}
+
+#[unstable(feature = "str_segmentation", issue = "none")]
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (grapheme, rest) = self.rest.split_at(next_grapheme_len(self.rest));
+        self.rest = rest;
+        Some(grapheme)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // A grapheme cluster can be arbitrarily long, but it takes at least one byte.
+        (usize::from(!self.rest.is_empty()), Some(self.rest.len()))
+    }
+}
+
+#[unstable(feature = "str_segmentation", issue = "none")]
+impl<'a> FusedIterator for Graphemes<'a> {}
+
+/// An iterator over the words of a string slice, as delimited by Unicode word boundaries.
+///
+/// This struct is created by the [`unicode_words`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`unicode_words`]: str::unicode_words
+#[unstable(feature = "str_segmentation", issue = "none")]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct UnicodeWords<'a> {
+    pub(super) rest: &'a str,
+}
+
+#[unstable(feature = "str_segmentation", issue = "none")]
+impl<'a> Iterator for UnicodeWords<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while !self.rest.is_empty() {
+            let (segment, rest) = self.rest.split_at(next_word_len(self.rest));
+            self.rest = rest;
+            // Skip the segments between words: whitespace, punctuation, symbols and so on.
+            if segment.chars().any(char::is_alphanumeric) {
+                return Some(segment);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.rest.len()))
+    }
+}
+
+#[unstable(feature = "str_segmentation", issue = "none")]
+impl<'a> FusedIterator for UnicodeWords<'a> {}
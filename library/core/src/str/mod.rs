@@ -18,6 +18,8 @@
 use crate::ops::Range;
 use crate::slice::{self, SliceIndex};
 use crate::ub_checks::assert_unsafe_precondition;
+use crate::unicode::conversions;
+use crate::unicode::normalize::{Decompositions, Form, Recompositions};
 use crate::{ascii, mem};
 
 pub mod pattern;
@@ -44,6 +46,12 @@
 pub use iter::{Bytes, CharIndices, Chars, Lines, SplitWhitespace};
 #[stable(feature = "str_escape", since = "1.34.0")]
 pub use iter::{EscapeDebug, EscapeDefault, EscapeUnicode};
+#[unstable(feature = "str_case_fold", issue = "none")]
+pub use iter::CaseFold;
+#[unstable(feature = "str_normalize", issue = "none")]
+pub use iter::{Nfc, Nfd, Nfkc, Nfkd};
+#[unstable(feature = "str_segmentation", issue = "none")]
+pub use iter::{Graphemes, UnicodeWords};
 #[stable(feature = "str_match_indices", since = "1.5.0")]
 pub use iter::{MatchIndices, RMatchIndices};
 use iter::{MatchIndicesInternal, MatchesInternal, SplitInternal, SplitNInternal};
@@ -3032,6 +3040,180 @@ pub fn escape_unicode(&self) -> EscapeUnicode<'_> {
         EscapeUnicode { inner: self.chars().flat_map(CharEscapeUnicode) }
     }
 
+    /// Returns an iterator over the characters of `self` in [Normalization Form D][nf], the
+    /// canonical decomposition.
+    ///
+    /// Precomposed characters are decomposed into a base character and combining marks, and
+    /// combining marks are put in canonical order. Two strings are canonically equivalent if and
+    /// only if their NFD forms are equal.
+    ///
+    /// [nf]: https://www.unicode.org/reports/tr15/
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalize)]
+    ///
+    /// assert_eq!("\u{e9}".nfd().to_string(), "e\u{301}");
+    /// // Combining marks are reordered: the dot below (class 220) goes before the acute (230).
+    /// assert_eq!("e\u{301}\u{323}".nfd().to_string(), "e\u{323}\u{301}");
+    /// ```
+    #[must_use = "this returns the normalized string as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "str_normalize", issue = "none")]
+    pub fn nfd(&self) -> Nfd<'_> {
+        Nfd { inner: Decompositions::new(self.chars(), Form::Canonical) }
+    }
+
+    /// Returns an iterator over the characters of `self` in [Normalization Form KD][nf], the
+    /// compatibility decomposition.
+    ///
+    /// Like [`nfd`](str::nfd), but also replaces compatibility characters, such as ligatures and
+    /// full-width forms, with their plain equivalents.
+    ///
+    /// [nf]: https://www.unicode.org/reports/tr15/
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalize)]
+    ///
+    /// assert_eq!("\u{fb01}ne \u{ff21}".nfkd().to_string(), "fine A");
+    /// ```
+    #[must_use = "this returns the normalized string as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "str_normalize", issue = "none")]
+    pub fn nfkd(&self) -> Nfkd<'_> {
+        Nfkd { inner: Decompositions::new(self.chars(), Form::Compatibility) }
+    }
+
+    /// Returns an iterator over the characters of `self` in [Normalization Form C][nf], the
+    /// canonical composition.
+    ///
+    /// This is the canonical decomposition followed by recombining base characters and marks into
+    /// precomposed characters wherever possible. Most text is already in NFC, which makes it the
+    /// usual choice for storing or comparing text.
+    ///
+    /// [nf]: https://www.unicode.org/reports/tr15/
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalize)]
+    ///
+    /// assert_eq!("e\u{301}".nfc().to_string(), "\u{e9}");
+    /// // Hangul jamo compose into syllables.
+    /// assert_eq!("\u{1112}\u{1161}\u{11ab}".nfc().to_string(), "\u{d55c}");
+    /// ```
+    #[must_use = "this returns the normalized string as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "str_normalize", issue = "none")]
+    pub fn nfc(&self) -> Nfc<'_> {
+        Nfc { inner: Recompositions::new(self.chars(), Form::Canonical) }
+    }
+
+    /// Returns an iterator over the characters of `self` in [Normalization Form KC][nf], the
+    /// compatibility composition.
+    ///
+    /// Like [`nfc`](str::nfc), but starting from the compatibility decomposition.
+    ///
+    /// [nf]: https://www.unicode.org/reports/tr15/
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalize)]
+    ///
+    /// assert_eq!("\u{fb01}anc\u{e9}".nfkc().to_string(), "fianc\u{e9}");
+    /// ```
+    #[must_use = "this returns the normalized string as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "str_normalize", issue = "none")]
+    pub fn nfkc(&self) -> Nfkc<'_> {
+        Nfkc { inner: Recompositions::new(self.chars(), Form::Compatibility) }
+    }
+
+    /// Returns an iterator over the [full case folding][cf] of `self`.
+    ///
+    /// Case folding maps characters that differ only in case to the same characters, so it is the
+    /// right tool for case-insensitive comparison, unlike [`to_lowercase`]: for example `'ß'`
+    /// folds to `"ss"`, like `'S'` and `'s'` do. The mapping does not depend on the language, so
+    /// the special Turkic handling of dotted and dotless I is not applied.
+    ///
+    /// Case folding does not normalize. To compare strings that may not be in the same
+    /// normalization form, compare the [`nfd`](str::nfd) of each string's folding instead.
+    ///
+    /// [cf]: https://www.unicode.org/reports/tr44/#CaseFolding.txt
+    /// [`to_lowercase`]: ../std/primitive.str.html#method.to_lowercase
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_case_fold)]
+    ///
+    /// assert_eq!("Stra\u{df}e".case_fold().to_string(), "strasse");
+    /// assert!("STRASSE".case_fold().eq("stra\u{df}e".case_fold()));
+    /// ```
+    #[must_use = "this returns the folded string as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "str_case_fold", issue = "none")]
+    pub fn case_fold(&self) -> CaseFold<'_> {
+        CaseFold { inner: self.chars().flat_map(CharCaseFold) }
+    }
+
+    /// Returns an iterator over the [extended grapheme clusters][uax29] of a string slice.
+    ///
+    /// A grapheme cluster is what a reader would usually call a single character: a base
+    /// character with any combining marks, a Hangul syllable spelled with conjoining jamo, a flag
+    /// made of two regional indicators, an emoji ZWJ sequence and so on. The clusters are
+    /// returned in order and together make up the whole string.
+    ///
+    /// [uax29]: https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_segmentation)]
+    ///
+    /// let graphemes: Vec<&str> = "e\u{301}\u{1f1eb}\u{1f1f7}\r\n!".graphemes().collect();
+    /// assert_eq!(graphemes, ["e\u{301}", "\u{1f1eb}\u{1f1f7}", "\r\n", "!"]);
+    /// ```
+    #[unstable(feature = "str_segmentation", issue = "none")]
+    #[inline]
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        Graphemes { rest: self }
+    }
+
+    /// Returns an iterator over the words of a string slice, as delimited by the default
+    /// [Unicode word boundaries][uax29].
+    ///
+    /// Only segments containing an alphanumeric character are returned, so whitespace and
+    /// punctuation between words are skipped. Unlike [`split_whitespace`], this keeps
+    /// contractions and numbers like `"can't"` and `"3.14"` together while splitting off the
+    /// surrounding punctuation.
+    ///
+    /// [uax29]: https://www.unicode.org/reports/tr29/#Word_Boundaries
+    /// [`split_whitespace`]: str::split_whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_segmentation)]
+    ///
+    /// let words: Vec<&str> = "The quick (\"brown\") fox can't jump 32.3 feet, right?"
+    ///     .unicode_words()
+    ///     .collect();
+    /// assert_eq!(
+    ///     words,
+    ///     ["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"],
+    /// );
+    /// ```
+    #[unstable(feature = "str_segmentation", issue = "none")]
+    #[inline]
+    pub fn unicode_words(&self) -> UnicodeWords<'_> {
+        UnicodeWords { rest: self }
+    }
+
     /// Returns the range that a substring points to.
     ///
     /// Returns `None` if `substr` does not point within `self`.
@@ -3133,6 +3315,11 @@ struct CharEscapeDefault impl Fn = |c: char| -> char::EscapeDefault {
         c.escape_default()
     };
 
+    #[derive(Clone)]
+    struct CharCaseFold impl Fn = |c: char| -> char::CaseMappingIter {
+        char::CaseMappingIter::new(conversions::to_folded(c))
+    };
+
     #[derive(Clone)]
     struct IsWhitespace impl Fn = |c: char| -> bool {
         c.is_whitespace()
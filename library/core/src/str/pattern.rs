@@ -1001,8 +1001,8 @@ fn is_contained_in(self, haystack: &str) -> bool {
                     all(target_arch = "loongarch64", target_feature = "lsx")
                 ))]
                 if self.len() <= 32 {
-                    if let Some(result) = simd_contains(self, haystack) {
-                        return result;
+                    if let Some(result) = simd_find(self.as_bytes(), haystack.as_bytes()) {
+                        return result.is_some();
                     }
                 }
 
@@ -1053,6 +1053,535 @@ fn as_utf8_pattern(&self) -> Option<Utf8Pattern<'_>> {
     }
 }
 
+/////////////////////////////////////////////////////////////////////////////
+// Impl for MultiStr
+/////////////////////////////////////////////////////////////////////////////
+
+/// A pattern that matches any one of several strings.
+///
+/// Matches are found leftmost-first: a match that starts earlier in the haystack always wins,
+/// and among needles that match at the same position, the one listed first is used. Searching
+/// from the back instead finds the match that ends last, again preferring earlier needles on
+/// ties. As with `&str`, matches never overlap.
+///
+/// The needles are compiled into an [Aho-Corasick] automaton, so a search reads each byte of the
+/// haystack about once, however many needles there are. As `core` cannot allocate, the automaton
+/// has room for a fixed number of states, one per distinct prefix of the needles, up to 255 in
+/// total. Needles that need more are instead checked one after another at each position whose
+/// first byte could start one of them.
+///
+/// [Aho-Corasick]: https://en.wikipedia.org/wiki/Aho%E2%80%93Corasick_algorithm
+///
+/// # Panics
+///
+/// [`MultiStr::new`] panics if any of the needles is empty.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pattern)]
+/// use std::str::pattern::MultiStr;
+///
+/// let keywords = MultiStr::new(&["let", "fn", "letter"]);
+/// assert_eq!("a letter".find(keywords), Some(2));
+///
+/// let v: Vec<&str> = "fn f() { let x; }".matches(keywords).collect();
+/// assert_eq!(v, ["fn", "let"]);
+///
+/// let v: Vec<&str> = "a, b; c".split(MultiStr::new(&[", ", "; "])).collect();
+/// assert_eq!(v, ["a", "b", "c"]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct MultiStr<'b> {
+    needles: &'b [&'b str],
+    /// The bytes that the needles start with.
+    first_bytes: ByteSet,
+    /// The bytes that the needles end with.
+    last_bytes: ByteSet,
+    /// The length of the shortest needle, or `usize::MAX` if there are none.
+    min_len: usize,
+}
+
+impl<'b> MultiStr<'b> {
+    /// Creates a pattern that matches any of `needles`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the needles is empty.
+    pub const fn new(needles: &'b [&'b str]) -> MultiStr<'b> {
+        let mut first_bytes = ByteSet::new();
+        let mut last_bytes = ByteSet::new();
+        let mut min_len = usize::MAX;
+        let mut i = 0;
+        while i < needles.len() {
+            let needle = needles[i].as_bytes();
+            assert!(!needle.is_empty(), "MultiStr needles must not be empty");
+            first_bytes.insert(needle[0]);
+            last_bytes.insert(needle[needle.len() - 1]);
+            if needle.len() < min_len {
+                min_len = needle.len();
+            }
+            i += 1;
+        }
+        MultiStr { needles, first_bytes, last_bytes, min_len }
+    }
+
+    /// Finds the leftmost match that lies entirely within `haystack[start..end]`.
+    fn find(&self, haystack: &[u8], start: usize, end: usize) -> Option<(usize, usize)> {
+        let mut i = start;
+        while end - i >= self.min_len {
+            // Skip ahead to a byte that can start a needle; most positions fail here.
+            i += self.first_bytes.position(&haystack[i..=end - self.min_len])?;
+            let window = &haystack[i..end];
+            for needle in self.needles {
+                if window.starts_with(needle.as_bytes()) {
+                    return Some((i, i + needle.len()));
+                }
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Finds the match that ends last and lies entirely within `haystack[start..end]`.
+    fn rfind(&self, haystack: &[u8], start: usize, end: usize) -> Option<(usize, usize)> {
+        let mut j = end;
+        while j - start >= self.min_len {
+            let offset = self.last_bytes.rposition(&haystack[start + self.min_len - 1..j])?;
+            j = start + self.min_len + offset;
+            let window = &haystack[start..j];
+            for needle in self.needles {
+                if window.ends_with(needle.as_bytes()) {
+                    return Some((j - needle.len(), j));
+                }
+            }
+            j -= 1;
+        }
+        None
+    }
+}
+
+/// A set of bytes, used to reject candidate positions for [`MultiStr`] cheaply.
+#[derive(Copy, Clone, Debug)]
+struct ByteSet {
+    bits: [u64; 4],
+    /// The only byte in the set, if it has exactly one, so that `memchr` can be used.
+    single: Option<u8>,
+    len: u16,
+}
+
+impl ByteSet {
+    const fn new() -> ByteSet {
+        ByteSet { bits: [0; 4], single: None, len: 0 }
+    }
+
+    const fn insert(&mut self, byte: u8) {
+        if !self.contains(byte) {
+            self.bits[byte as usize / 64] |= 1 << (byte % 64);
+            self.len += 1;
+            self.single = if self.len == 1 { Some(byte) } else { None };
+        }
+    }
+
+    #[inline]
+    const fn contains(&self, byte: u8) -> bool {
+        self.bits[byte as usize / 64] & (1 << (byte % 64)) != 0
+    }
+
+    /// Returns the index of the first byte of `bytes` that is in the set.
+    #[inline]
+    fn position(&self, bytes: &[u8]) -> Option<usize> {
+        match self.single {
+            Some(byte) => memchr::memchr(byte, bytes),
+            None => bytes.iter().position(|&b| self.contains(b)),
+        }
+    }
+
+    /// Returns the index of the last byte of `bytes` that is in the set.
+    #[inline]
+    fn rposition(&self, bytes: &[u8]) -> Option<usize> {
+        match self.single {
+            Some(byte) => memchr::memrchr(byte, bytes),
+            None => bytes.iter().rposition(|&b| self.contains(b)),
+        }
+    }
+}
+
+/// The most states, the root included, that the [`Automaton`] of a [`MultiStr`] can have.
+const MAX_STATES: usize = 256;
+
+/// The [`State::needle`] of states that do not end a needle.
+const NO_NEEDLE: u32 = u32::MAX;
+
+/// An Aho-Corasick automaton for the needles of a [`MultiStr`], which reads them back to front
+/// if it is `reverse`, to search from the back of the haystack.
+///
+/// Its states are the nodes of the trie of the needles, with the root as state 0. Each state
+/// links to the state of the longest proper suffix of its path that is in the trie, which is
+/// where the search continues when the next byte of the haystack has no edge in the trie.
+#[derive(Clone)]
+struct Automaton {
+    reverse: bool,
+    /// The number of states in use.
+    len: usize,
+    /// The children of the root by byte, or 0 for none. Most bytes of a haystack are looked up
+    /// here, so they are not in a list like the children of other states.
+    root: [u16; 256],
+    states: [State; MAX_STATES],
+}
+
+#[derive(Copy, Clone, Debug)]
+struct State {
+    /// The byte on the edge from the parent.
+    byte: u8,
+    /// The length of the path to this state.
+    depth: u16,
+    /// The first child of this state, or 0 for none.
+    first_child: u16,
+    /// The next child of the parent of this state, or 0 for none.
+    next_sibling: u16,
+    /// The state of the longest proper suffix of the path that is in the trie.
+    fail: u16,
+    /// The deepest state that ends a needle among this one and those reached through `fail`, or
+    /// 0 for none.
+    output: u16,
+    /// The index of the first needle that ends in this state, or [`NO_NEEDLE`].
+    needle: u32,
+}
+
+impl State {
+    const EMPTY: State = State {
+        byte: 0,
+        depth: 0,
+        first_child: 0,
+        next_sibling: 0,
+        fail: 0,
+        output: 0,
+        needle: NO_NEEDLE,
+    };
+}
+
+impl Automaton {
+    /// Builds the automaton of `needles`, or returns `None` if it would need too many states.
+    fn new(needles: &[&str], reverse: bool) -> Option<Automaton> {
+        if needles.len() >= NO_NEEDLE as usize {
+            return None;
+        }
+        let mut automaton =
+            Automaton { reverse, len: 1, root: [0; 256], states: [State::EMPTY; MAX_STATES] };
+        for (index, needle) in needles.iter().enumerate() {
+            let needle = needle.as_bytes();
+            let mut state = 0;
+            for i in 0..needle.len() {
+                let byte = if reverse { needle[needle.len() - 1 - i] } else { needle[i] };
+                state = match automaton.child(state, byte) {
+                    Some(child) => child,
+                    None => automaton.add_child(state, byte)?,
+                };
+            }
+            let state = &mut automaton.states[state as usize];
+            if state.needle == NO_NEEDLE {
+                state.needle = index as u32;
+            }
+        }
+
+        // The suffix links point to shallower states, so they are set in breadth-first order.
+        let mut queue = [0u16; MAX_STATES];
+        let mut queued = 1;
+        for head in 0..MAX_STATES {
+            if head == queued {
+                break;
+            }
+            let parent = queue[head];
+            let mut child = automaton.states[parent as usize].first_child;
+            while child != 0 {
+                let State { byte, needle, next_sibling, .. } = automaton.states[child as usize];
+                let fail = if parent == 0 {
+                    0
+                } else {
+                    automaton.next(automaton.states[parent as usize].fail, byte)
+                };
+                let output = if needle == NO_NEEDLE {
+                    automaton.states[fail as usize].output
+                } else {
+                    child
+                };
+                let state = &mut automaton.states[child as usize];
+                state.fail = fail;
+                state.output = output;
+                queue[queued] = child;
+                queued += 1;
+                child = next_sibling;
+            }
+        }
+        Some(automaton)
+    }
+
+    fn add_child(&mut self, parent: u16, byte: u8) -> Option<u16> {
+        if self.len == MAX_STATES {
+            return None;
+        }
+        let child = self.len as u16;
+        self.len += 1;
+        let parent_state = &mut self.states[parent as usize];
+        let depth = parent_state.depth + 1;
+        let next_sibling = parent_state.first_child;
+        parent_state.first_child = child;
+        self.states[child as usize] = State { byte, depth, next_sibling, ..State::EMPTY };
+        if parent == 0 {
+            self.root[byte as usize] = child;
+        }
+        Some(child)
+    }
+
+    #[inline]
+    fn child(&self, state: u16, byte: u8) -> Option<u16> {
+        if state == 0 {
+            let child = self.root[byte as usize];
+            return if child == 0 { None } else { Some(child) };
+        }
+        let mut child = self.states[state as usize].first_child;
+        while child != 0 {
+            if self.states[child as usize].byte == byte {
+                return Some(child);
+            }
+            child = self.states[child as usize].next_sibling;
+        }
+        None
+    }
+
+    /// Returns the state after reading `byte` in `state`.
+    #[inline]
+    fn next(&self, mut state: u16, byte: u8) -> u16 {
+        while state != 0 {
+            if let Some(child) = self.child(state, byte) {
+                return child;
+            }
+            state = self.states[state as usize].fail;
+        }
+        self.root[byte as usize]
+    }
+
+    /// Finds the leftmost-first match that lies entirely within `haystack[start..end]`, or, if
+    /// the automaton is reversed, the match that ends last. `first_bytes` are the bytes the
+    /// needles start with, as read by the automaton.
+    fn find(
+        &self,
+        haystack: &[u8],
+        start: usize,
+        end: usize,
+        first_bytes: &ByteSet,
+    ) -> Option<(usize, usize)> {
+        // Offsets below count the bytes read, from `start` onwards, or from `end` backwards if
+        // the automaton is reversed.
+        let len = end - start;
+        let mut read = 0;
+        let mut state = 0;
+        // The offset, length and needle of the best match so far.
+        let mut best: Option<(usize, usize, u32)> = None;
+        while read < len {
+            if state == 0 {
+                // Nothing is partially matched, so skip ahead to a byte that can start a needle,
+                // unless every later match would start after the one already found.
+                if best.is_some() {
+                    break;
+                }
+                read += if self.reverse {
+                    let last = first_bytes.rposition(&haystack[start..end - read])?;
+                    len - read - 1 - last
+                } else {
+                    first_bytes.position(&haystack[start + read..end])?
+                };
+            }
+            let byte = if self.reverse { haystack[end - 1 - read] } else { haystack[start + read] };
+            state = self.next(state, byte);
+            read += 1;
+
+            // Any match found from here on starts within the path of the current state.
+            let depth = self.states[state as usize].depth as usize;
+            if let Some((offset, ..)) = best
+                && read - depth > offset
+            {
+                break;
+            }
+            // The matches that end here, from the longest to the shortest.
+            let mut output = self.states[state as usize].output;
+            while output != 0 {
+                let State { depth, fail, needle, .. } = self.states[output as usize];
+                let found = (read - depth as usize, depth as usize, needle);
+                match best {
+                    Some((offset, ..)) if found.0 > offset => break,
+                    Some((offset, _, best_needle)) if found.0 == offset && needle > best_needle => {
+                    }
+                    _ => best = Some(found),
+                }
+                output = self.states[fail as usize].output;
+            }
+        }
+        let (offset, len, _) = best?;
+        Some(if self.reverse {
+            (end - offset - len, end - offset)
+        } else {
+            (start + offset, start + offset + len)
+        })
+    }
+}
+
+impl fmt::Debug for Automaton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Automaton")
+            .field("reverse", &self.reverse)
+            .field("states", &&self.states[..self.len])
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'b> Pattern for MultiStr<'b> {
+    type Searcher<'a> = MultiStrSearcher<'a, 'b>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &str) -> MultiStrSearcher<'_, 'b> {
+        MultiStrSearcher {
+            haystack,
+            pattern: self,
+            position: 0,
+            end: haystack.len(),
+            matcher: MultiStrMatcher::Unbuilt,
+        }
+    }
+}
+
+/// Associated type for `<MultiStr<'b> as Pattern>::Searcher<'a>`.
+#[derive(Clone, Debug)]
+pub struct MultiStrSearcher<'a, 'b> {
+    haystack: &'a str,
+    pattern: MultiStr<'b>,
+    /// Everything before `position` has been consumed by the forward search.
+    position: usize,
+    /// Everything after `end` has been consumed by the backward search.
+    end: usize,
+    /// How to search in the direction searched last. Searching in the other direction builds it
+    /// again, since few searchers are used in both.
+    matcher: MultiStrMatcher,
+}
+
+#[derive(Clone, Debug)]
+enum MultiStrMatcher {
+    Unbuilt,
+    Automaton(Automaton),
+    /// The needles need too many states for an automaton in this direction, so the search
+    /// checks them one after another.
+    TooLarge {
+        reverse: bool,
+    },
+}
+
+impl<'a, 'b> MultiStrSearcher<'a, 'b> {
+    /// Finds the next match in the part of the haystack that is not consumed yet, the one that
+    /// ends last if `reverse`.
+    fn find(&mut self, reverse: bool) -> Option<(usize, usize)> {
+        let is_built = match self.matcher {
+            MultiStrMatcher::Unbuilt => false,
+            MultiStrMatcher::Automaton(ref automaton) => automaton.reverse == reverse,
+            MultiStrMatcher::TooLarge { reverse: built_reverse } => built_reverse == reverse,
+        };
+        if !is_built {
+            self.matcher = match Automaton::new(self.pattern.needles, reverse) {
+                Some(automaton) => MultiStrMatcher::Automaton(automaton),
+                None => MultiStrMatcher::TooLarge { reverse },
+            };
+        }
+
+        let haystack = self.haystack.as_bytes();
+        let (start, end) = (self.position, self.end);
+        match (&self.matcher, reverse) {
+            (MultiStrMatcher::Automaton(automaton), false) => {
+                automaton.find(haystack, start, end, &self.pattern.first_bytes)
+            }
+            (MultiStrMatcher::Automaton(automaton), true) => {
+                automaton.find(haystack, start, end, &self.pattern.last_bytes)
+            }
+            (_, false) => self.pattern.find(haystack, start, end),
+            (_, true) => self.pattern.rfind(haystack, start, end),
+        }
+    }
+}
+
+// Matches of valid UTF-8 needles in a valid UTF-8 haystack always start and end on char
+// boundaries, so every step below does too.
+unsafe impl<'a, 'b> Searcher<'a> for MultiStrSearcher<'a, 'b> {
+    #[inline]
+    fn haystack(&self) -> &'a str {
+        self.haystack
+    }
+
+    #[inline]
+    fn next(&mut self) -> SearchStep {
+        let start = self.position;
+        if start == self.end {
+            return SearchStep::Done;
+        }
+        match self.find(false) {
+            Some((a, b)) if a == start => {
+                self.position = b;
+                SearchStep::Match(a, b)
+            }
+            Some((a, _)) => {
+                self.position = a;
+                SearchStep::Reject(start, a)
+            }
+            None => {
+                self.position = self.end;
+                SearchStep::Reject(start, self.end)
+            }
+        }
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        let found = self.find(false);
+        self.position = match found {
+            Some((_, b)) => b,
+            None => self.end,
+        };
+        found
+    }
+}
+
+unsafe impl<'a, 'b> ReverseSearcher<'a> for MultiStrSearcher<'a, 'b> {
+    #[inline]
+    fn next_back(&mut self) -> SearchStep {
+        let end = self.end;
+        if end == self.position {
+            return SearchStep::Done;
+        }
+        match self.find(true) {
+            Some((a, b)) if b == end => {
+                self.end = a;
+                SearchStep::Match(a, b)
+            }
+            Some((_, b)) => {
+                self.end = b;
+                SearchStep::Reject(b, end)
+            }
+            None => {
+                self.end = self.position;
+                SearchStep::Reject(self.position, end)
+            }
+        }
+    }
+
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        let found = self.find(true);
+        self.end = match found {
+            Some((a, _)) => a,
+            None => self.position,
+        };
+        found
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////
 // Two Way substring searcher
 /////////////////////////////////////////////////////////////////////////////
@@ -1179,6 +1708,34 @@ fn next_match(&mut self) -> Option<(usize, usize)> {
             },
             StrSearcherImpl::TwoWay(ref mut searcher) => {
                 let is_long = searcher.memory == usize::MAX;
+
+                // Short needles are found faster by probing a whole vector of candidate
+                // positions at a time. Jumping the two-way cursor is fine as long as its
+                // memory of partial matches is discarded.
+                #[cfg(any(
+                    all(target_arch = "x86_64", target_feature = "sse2"),
+                    all(target_arch = "loongarch64", target_feature = "lsx")
+                ))]
+                if (2..=32).contains(&self.needle.len()) {
+                    let rest = &self.haystack.as_bytes()[searcher.position..];
+                    if let Some(found) = simd_find(self.needle.as_bytes(), rest) {
+                        if !is_long {
+                            searcher.memory = 0;
+                        }
+                        return match found {
+                            Some(offset) => {
+                                let start = searcher.position + offset;
+                                searcher.position = start + self.needle.len();
+                                Some((start, searcher.position))
+                            }
+                            None => {
+                                searcher.position = self.haystack.len();
+                                None
+                            }
+                        };
+                    }
+                }
+
                 // write out `true` and `false` cases to encourage the compiler
                 // to specialize the two cases separately.
                 if is_long {
@@ -1780,16 +2337,16 @@ fn matching(a: usize, b: usize) -> Self::Output {
 /// For haystacks smaller than vector-size + needle length it falls back to
 /// a naive O(n*m) search so this implementation should not be called on larger needles.
 ///
+/// Returns `None` if the needle is degenerate for this approach, and otherwise the start of
+/// the leftmost match, if any.
+///
 /// [0]: http://0x80.pl/articles/simd-strfind.html#sse-avx2
 #[cfg(any(
     all(target_arch = "x86_64", target_feature = "sse2"),
     all(target_arch = "loongarch64", target_feature = "lsx")
 ))]
 #[inline]
-fn simd_contains(needle: &str, haystack: &str) -> Option<bool> {
-    let needle = needle.as_bytes();
-    let haystack = haystack.as_bytes();
-
+fn simd_find(needle: &[u8], haystack: &[u8]) -> Option<Option<usize>> {
     debug_assert!(needle.len() > 1);
 
     use crate::ops::BitAnd;
@@ -1818,7 +2375,7 @@ fn simd_contains(needle: &str, haystack: &str) -> Option<bool> {
 
     // do a naive search if the haystack is too small to fit
     if haystack.len() < Block::LEN + last_byte_offset {
-        return Some(haystack.windows(needle.len()).any(|c| c == needle));
+        return Some(haystack.windows(needle.len()).position(|c| c == needle));
     }
 
     let first_probe: Block = Block::splat(first_probe);
@@ -1829,11 +2386,7 @@ fn simd_contains(needle: &str, haystack: &str) -> Option<bool> {
 
     // this #[cold] is load-bearing, benchmark before removing it...
     let check_mask = #[cold]
-    |idx, mask: u16, skip: bool| -> bool {
-        if skip {
-            return false;
-        }
-
+    |idx, mask: u16| -> Option<usize> {
         // and so is this. optimizations are weird.
         let mut mask = mask;
 
@@ -1845,12 +2398,12 @@ fn simd_contains(needle: &str, haystack: &str) -> Option<bool> {
             unsafe {
                 let sub = haystack.get_unchecked(offset..).get_unchecked(..trimmed_needle.len());
                 if small_slice_eq(sub, trimmed_needle) {
-                    return true;
+                    return Some(offset - 1);
                 }
             }
             mask &= !(1 << trailing);
         }
-        false
+        None
     };
 
     let test_chunk = |idx| -> u16 {
@@ -1870,27 +2423,32 @@ fn simd_contains(needle: &str, haystack: &str) -> Option<bool> {
     };
 
     let mut i = 0;
-    let mut result = false;
     // The loop condition must ensure that there's enough headroom to read LANE bytes,
     // and not only at the current index but also at the index shifted by block_offset
     const UNROLL: usize = 4;
-    while i + last_byte_offset + UNROLL * Block::LEN < haystack.len() && !result {
+    while i + last_byte_offset + UNROLL * Block::LEN < haystack.len() {
         let mut masks = [0u16; UNROLL];
         for j in 0..UNROLL {
             masks[j] = test_chunk(i + j * Block::LEN);
         }
+        // Chunks are checked in order, and each mask from its lowest bit up, so the first hit
+        // is the leftmost match.
         for j in 0..UNROLL {
             let mask = masks[j];
             if mask != 0 {
-                result |= check_mask(i + j * Block::LEN, mask, result);
+                if let Some(found) = check_mask(i + j * Block::LEN, mask) {
+                    return Some(Some(found));
+                }
             }
         }
         i += UNROLL * Block::LEN;
     }
-    while i + last_byte_offset + Block::LEN < haystack.len() && !result {
+    while i + last_byte_offset + Block::LEN < haystack.len() {
         let mask = test_chunk(i);
         if mask != 0 {
-            result |= check_mask(i, mask, result);
+            if let Some(found) = check_mask(i, mask) {
+                return Some(Some(found));
+            }
         }
         i += Block::LEN;
     }
@@ -1898,14 +2456,15 @@ fn simd_contains(needle: &str, haystack: &str) -> Option<bool> {
     // Process the tail that didn't fit into LANES-sized steps.
     // This simply repeats the same procedure but as right-aligned chunk instead
     // of a left-aligned one. The last byte must be exactly flush with the string end so
-    // we don't miss a single byte or read out of bounds.
+    // we don't miss a single byte or read out of bounds. Any positions it shares with the
+    // previous chunk were already found not to match.
     let i = haystack.len() - last_byte_offset - Block::LEN;
     let mask = test_chunk(i);
     if mask != 0 {
-        result |= check_mask(i, mask, result);
+        return Some(check_mask(i, mask));
     }
 
-    Some(result)
+    Some(None)
 }
 
 /// Compares short slices for equality.
@@ -16,7 +16,9 @@
 pub(crate) use unicode_data::uppercase::lookup as Uppercase;
 pub(crate) use unicode_data::white_space::lookup as White_Space;
 
+pub(crate) mod normalize;
 pub(crate) mod printable;
+pub(crate) mod segment;
 
 #[allow(unreachable_pub)]
 mod unicode_data;
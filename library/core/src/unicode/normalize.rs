@@ -0,0 +1,420 @@
+//! Unicode normalization ([UAX #15]) over borrowed character streams.
+//!
+//! Normalization has to reorder combining marks and look ahead for characters that compose with
+//! an earlier starter, so both adaptors buffer the current run (a starter and the non-starters
+//! after it) once, like other UAX #15 implementations. Without an allocator the buffer has a
+//! fixed size, which is enough for any text in the Stream-Safe Text Format. Longer runs, which
+//! only occur in unusual or malicious text, are handled with a pass over their overflowing part
+//! for each combining class in them, so the work stays linear in the length of the input.
+//!
+//! [UAX #15]: https://www.unicode.org/reports/tr15/
+
+use super::unicode_data::normalization::{
+    combining_class, compose, decompose_canonical, decompose_compatible,
+};
+use crate::iter::{Chain, FusedIterator, Once, Take};
+
+// Hangul syllables are decomposed and composed algorithmically, see section 3.12 of the
+// Unicode Standard.
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Number of characters of a run that the adaptors buffer. A starter followed by the 30
+/// non-starters that the Stream-Safe Text Format allows fits.
+const RUN_CAPACITY: usize = 32;
+
+/// Which decomposition mappings to apply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Form {
+    /// Canonical decomposition, as used by NFD and NFC.
+    Canonical,
+    /// Compatibility decomposition, as used by NFKD and NFKC.
+    Compatibility,
+}
+
+/// The fully decomposed characters of `iter`, in their original (not canonical) order.
+#[derive(Clone, Debug)]
+struct RawDecompositions<I> {
+    iter: I,
+    form: Form,
+    /// The rest of the decomposition of the last character taken from `iter`.
+    pending: &'static [char],
+    /// The trailing jamo of a decomposed Hangul syllable, if any.
+    jamo: [Option<char>; 2],
+}
+
+impl<I: Iterator<Item = char>> Iterator for RawDecompositions<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some((&c, rest)) = self.pending.split_first() {
+            self.pending = rest;
+            return Some(c);
+        }
+        if let Some(c) = self.jamo[0].take() {
+            self.jamo = [self.jamo[1].take(), None];
+            return Some(c);
+        }
+
+        let c = self.iter.next()?;
+        let s_index = (c as u32).wrapping_sub(S_BASE);
+        if s_index < S_COUNT {
+            // SAFETY: all of these are in the Hangul Jamo block.
+            let (l, v, t) = unsafe {
+                (
+                    char::from_u32_unchecked(L_BASE + s_index / N_COUNT),
+                    char::from_u32_unchecked(V_BASE + (s_index % N_COUNT) / T_COUNT),
+                    char::from_u32_unchecked(T_BASE + s_index % T_COUNT),
+                )
+            };
+            self.jamo = [Some(v), if s_index % T_COUNT != 0 { Some(t) } else { None }];
+            return Some(l);
+        }
+
+        let decomposition = match self.form {
+            Form::Canonical => decompose_canonical(c),
+            Form::Compatibility => decompose_compatible(c),
+        };
+        match decomposition {
+            Some([first, rest @ ..]) => {
+                self.pending = rest;
+                Some(*first)
+            }
+            _ => Some(c),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let extra = self.pending.len() + self.jamo.iter().flatten().count();
+        (self.iter.size_hint().0.saturating_add(extra), None)
+    }
+}
+
+/// The canonically ordered decomposition of a character stream: NFD or NFKD.
+#[derive(Clone, Debug)]
+pub(crate) struct Decompositions<I> {
+    raw: RawDecompositions<I>,
+    /// The first character of the next run, already taken from `raw`.
+    peeked: Option<char>,
+    /// The start of the current run, stably sorted by combining class.
+    chars: [char; RUN_CAPACITY],
+    /// The combining classes of `chars`.
+    classes: [u8; RUN_CAPACITY],
+    /// Number of characters in `chars`.
+    len: usize,
+    /// Number of characters of `chars` emitted so far.
+    pos: usize,
+    /// The rest of the current run, if it doesn't fit in `chars`.
+    overflow: Option<Overflow<I>>,
+}
+
+/// The part of a run that doesn't fit in the buffer of [`Decompositions`]. It is emitted one
+/// combining class at a time, with a pass over it for each class.
+#[derive(Clone, Debug)]
+struct Overflow<I> {
+    /// The first character of the part, followed by the rest of it.
+    part: Chain<Once<char>, Take<RawDecompositions<I>>>,
+    /// The pass for the combining class being emitted.
+    pass: Chain<Once<char>, Take<RawDecompositions<I>>>,
+    /// The combining class being emitted.
+    class: u8,
+    /// The smallest greater combining class that the pass has seen so far.
+    next_class: Option<u8>,
+}
+
+impl<I: Iterator<Item = char> + Clone> Overflow<I> {
+    /// Returns the next character of class `self.class` in the part.
+    fn next_in_pass(&mut self) -> Option<char> {
+        for c in &mut self.pass {
+            let class = combining_class(c);
+            if class == self.class {
+                return Some(c);
+            }
+            if class > self.class {
+                self.next_class = Some(self.next_class.map_or(class, |next| next.min(class)));
+            }
+        }
+        None
+    }
+}
+
+impl<I: Iterator<Item = char> + Clone> Decompositions<I> {
+    pub(crate) fn new(iter: I, form: Form) -> Self {
+        Decompositions {
+            raw: RawDecompositions { iter, form, pending: &[], jamo: [None, None] },
+            peeked: None,
+            chars: ['\0'; RUN_CAPACITY],
+            classes: [0; RUN_CAPACITY],
+            len: 0,
+            pos: 0,
+            overflow: None,
+        }
+    }
+
+    /// Reads the next run into the buffer, and sets up `overflow` if it doesn't fit.
+    fn fill(&mut self) -> Option<()> {
+        let first = self.peeked.take().or_else(|| self.raw.next())?;
+        self.chars[0] = first;
+        self.classes[0] = combining_class(first);
+        self.len = 1;
+        self.pos = 0;
+
+        // The first character that doesn't fit, the rest of the run after it, and its length.
+        let mut overflow: Option<(char, RawDecompositions<I>, usize)> = None;
+        while let Some(c) = self.raw.next() {
+            let class = combining_class(c);
+            if class == 0 {
+                self.peeked = Some(c);
+                break;
+            }
+            if let Some((_, _, len)) = &mut overflow {
+                *len += 1;
+            } else if self.len < RUN_CAPACITY {
+                // Insertion sort, keeping characters of equal classes in their original order.
+                // Only the first character of a run can be a starter, so this is the canonical
+                // ordering algorithm.
+                let mut i = self.len;
+                while i > 0 && self.classes[i - 1] > class {
+                    self.chars[i] = self.chars[i - 1];
+                    self.classes[i] = self.classes[i - 1];
+                    i -= 1;
+                }
+                self.chars[i] = c;
+                self.classes[i] = class;
+                self.len += 1;
+            } else {
+                overflow = Some((c, self.raw.clone(), 0));
+            }
+        }
+
+        if let Some((first, rest, len)) = overflow {
+            let part = crate::iter::once(first).chain(rest.take(len));
+            // The part has no starters, so the pass for class 0 only finds the smallest class.
+            self.overflow = Some(Overflow { pass: part.clone(), part, class: 0, next_class: None });
+        }
+        Some(())
+    }
+}
+
+impl<I: Iterator<Item = char> + Clone> Iterator for Decompositions<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            let Some(overflow) = &mut self.overflow else {
+                if self.pos < self.len {
+                    self.pos += 1;
+                    return Some(self.chars[self.pos - 1]);
+                }
+                self.fill()?;
+                continue;
+            };
+
+            // The buffered characters of a class come before the overflowing ones, since they
+            // came first in the run.
+            if self.pos < self.len && self.classes[self.pos] <= overflow.class {
+                self.pos += 1;
+                return Some(self.chars[self.pos - 1]);
+            }
+            if let Some(c) = overflow.next_in_pass() {
+                return Some(c);
+            }
+            let buffered = self.classes[self.pos..self.len].first().copied();
+            match overflow.next_class.into_iter().chain(buffered).min() {
+                Some(class) => {
+                    overflow.class = class;
+                    overflow.next_class = None;
+                    overflow.pass = overflow.part.clone();
+                }
+                None => self.overflow = None,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The overflowing part of the run, if any, was already taken from `raw`, and is left out.
+        let (lower, _) = self.raw.size_hint();
+        let buffered = self.len - self.pos + usize::from(self.peeked.is_some());
+        (lower.saturating_add(buffered), None)
+    }
+}
+
+impl<I: Iterator<Item = char> + Clone> FusedIterator for Decompositions<I> where I: FusedIterator {}
+
+/// The canonical composition of a character stream: NFC or NFKC.
+#[derive(Clone, Debug)]
+pub(crate) struct Recompositions<I> {
+    iter: Decompositions<I>,
+    /// The first character of the next run, already taken from `iter`.
+    peeked: Option<char>,
+    /// The current run after composition: its starter, followed by the characters that were not
+    /// composed into it.
+    chars: [char; RUN_CAPACITY],
+    /// Number of characters in `chars`.
+    len: usize,
+    /// Number of characters of `chars` emitted so far.
+    pos: usize,
+    /// The rest of the current run, if the characters left after composition don't fit in
+    /// `chars`.
+    overflow: Option<ComposedOverflow<I>>,
+}
+
+/// The part of a run that doesn't fit in the buffer of [`Recompositions`]. It is replayed from
+/// the decomposition, skipping what was composed into the starter.
+#[derive(Clone, Debug)]
+struct ComposedOverflow<I> {
+    /// The first character of the part, if it wasn't emitted yet.
+    first: Option<char>,
+    /// The decomposition, positioned after `first`.
+    rest: Decompositions<I>,
+    /// Number of characters of the run left in `rest`.
+    remaining: usize,
+    /// The starter, as composed so far.
+    starter: char,
+    /// The combining class of the last character that was not composed into the starter.
+    last_class: u8,
+}
+
+impl<I: Iterator<Item = char> + Clone> Iterator for ComposedOverflow<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some(c) = self.first.take() {
+            return Some(c);
+        }
+        while self.remaining > 0 {
+            let c = self.rest.next()?;
+            self.remaining -= 1;
+            let class = combining_class(c);
+            if !is_blocked(Some(self.last_class), class)
+                && let Some(composed) = compose_pair(self.starter, c)
+            {
+                self.starter = composed;
+                continue;
+            }
+            self.last_class = class;
+            return Some(c);
+        }
+        None
+    }
+}
+
+impl<I: Iterator<Item = char> + Clone> Recompositions<I> {
+    pub(crate) fn new(iter: I, form: Form) -> Self {
+        Recompositions {
+            iter: Decompositions::new(iter, form),
+            peeked: None,
+            chars: ['\0'; RUN_CAPACITY],
+            len: 0,
+            pos: 0,
+            overflow: None,
+        }
+    }
+}
+
+/// Composes `a` and `b`, if they form a primary composite.
+fn compose_pair(a: char, b: char) -> Option<char> {
+    let (a, b) = (a as u32, b as u32);
+    let l_index = a.wrapping_sub(L_BASE);
+    let v_index = b.wrapping_sub(V_BASE);
+    if l_index < L_COUNT && v_index < V_COUNT {
+        // SAFETY: this is a Hangul syllable.
+        return Some(unsafe {
+            char::from_u32_unchecked(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT)
+        });
+    }
+    let s_index = a.wrapping_sub(S_BASE);
+    let t_index = b.wrapping_sub(T_BASE);
+    if s_index < S_COUNT && s_index % T_COUNT == 0 && t_index.wrapping_sub(1) < T_COUNT - 1 {
+        // SAFETY: this is a Hangul syllable.
+        return Some(unsafe { char::from_u32_unchecked(a + t_index) });
+    }
+    // SAFETY: both were `char`s a moment ago.
+    compose(unsafe { char::from_u32_unchecked(a) }, unsafe { char::from_u32_unchecked(b) })
+}
+
+/// Whether a character of combining class `class` is blocked from the starter, given the class of
+/// the last uncomposed character since the starter.
+fn is_blocked(last_class: Option<u8>, class: u8) -> bool {
+    last_class.is_some_and(|last| last == 0 || last >= class)
+}
+
+impl<I: Iterator<Item = char> + Clone> Iterator for Recompositions<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.pos < self.len {
+            self.pos += 1;
+            return Some(self.chars[self.pos - 1]);
+        }
+        if let Some(overflow) = &mut self.overflow {
+            if let Some(c) = overflow.next() {
+                return Some(c);
+            }
+            self.overflow = None;
+        }
+
+        let first = self.peeked.take().or_else(|| self.iter.next())?;
+        if combining_class(first) != 0 {
+            // A non-starter with no starter to compose with.
+            return Some(first);
+        }
+
+        // Compose the run into its starter, buffering the characters that are left.
+        let mut starter = first;
+        let mut last_class = None;
+        self.len = 1;
+        self.pos = 1;
+        while let Some(c) = self.iter.next() {
+            let class = combining_class(c);
+            let composed =
+                if is_blocked(last_class, class) { None } else { compose_pair(starter, c) };
+            match composed {
+                Some(composed) => starter = composed,
+                None if class == 0 => {
+                    self.peeked = Some(c);
+                    break;
+                }
+                None => last_class = Some(class),
+            }
+
+            if let Some(overflow) = &mut self.overflow {
+                overflow.remaining += 1;
+            } else if composed.is_none() {
+                if self.len < RUN_CAPACITY {
+                    self.chars[self.len] = c;
+                    self.len += 1;
+                } else {
+                    self.overflow = Some(ComposedOverflow {
+                        first: Some(c),
+                        rest: self.iter.clone(),
+                        remaining: 0,
+                        starter,
+                        last_class: class,
+                    });
+                }
+            }
+        }
+
+        self.chars[0] = starter;
+        Some(starter)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // A starter can absorb all the characters after it, so all we know is that the buffered
+        // ones are still to come, and that anything else produces at least one more character.
+        let buffered = self.len - self.pos;
+        let (lower, _) = self.iter.size_hint();
+        let more = self.peeked.is_some() || lower > 0;
+        (buffered + usize::from(more), None)
+    }
+}
+
+impl<I: Iterator<Item = char> + Clone> FusedIterator for Recompositions<I> where I: FusedIterator {}
@@ -0,0 +1,171 @@
+//! Extended grapheme cluster and word boundaries ([UAX #29]).
+//!
+//! Both searches only ever look at the text following the start of the current segment, plus a
+//! little lookahead for the word rules that need it, so the iterators built on top of them never
+//! have to remember anything but the unconsumed rest of the string.
+//!
+//! [UAX #29]: https://www.unicode.org/reports/tr29/
+
+use super::unicode_data::grapheme_cluster_break::{
+    self as gcb, GraphemeCat, IndicConjunctBreak, indic_conjunct_break,
+};
+use super::unicode_data::word_break::{self as wb, WordCat};
+
+/// Progress through a conjunct cluster, for GB9c.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Conjunct {
+    None,
+    /// After a consonant, possibly followed by extending characters.
+    Consonant,
+    /// After a consonant followed by at least one linker.
+    Linked,
+}
+
+/// Progress through an emoji ZWJ sequence, for GB11.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Emoji {
+    None,
+    /// After an extended pictographic character, possibly followed by extending characters.
+    Pictographic,
+    /// After the zero width joiner of `Pictographic Extend* ZWJ`.
+    Joined,
+}
+
+/// Returns the length in bytes of the extended grapheme cluster at the start of `s`, or zero if
+/// `s` is empty.
+pub(crate) fn next_grapheme_len(s: &str) -> usize {
+    use GraphemeCat::*;
+
+    let mut chars = s.char_indices();
+    let Some((_, first)) = chars.next() else { return 0 };
+    let mut prev = gcb::lookup(first);
+    let mut conjunct = Conjunct::None.advance(first);
+    let mut emoji = Emoji::None.advance(prev);
+    let mut regional_indicators = usize::from(prev == RegionalIndicator);
+
+    for (i, c) in chars {
+        let next = gcb::lookup(c);
+        let boundary = match (prev, next) {
+            (Cr, Lf) => false,
+            (Control | Cr | Lf, _) | (_, Control | Cr | Lf) => true,
+            (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => false,
+            (_, Extend | Zwj | SpacingMark) | (Prepend, _) => false,
+            (Zwj, ExtendedPictographic) => emoji != Emoji::Joined,
+            (RegionalIndicator, RegionalIndicator) => regional_indicators % 2 == 0,
+            _ => {
+                conjunct != Conjunct::Linked
+                    || indic_conjunct_break(c) != IndicConjunctBreak::Consonant
+            }
+        };
+        if boundary {
+            return i;
+        }
+
+        conjunct = conjunct.advance(c);
+        emoji = emoji.advance(next);
+        regional_indicators = if next == RegionalIndicator { regional_indicators + 1 } else { 0 };
+        prev = next;
+    }
+    s.len()
+}
+
+impl Conjunct {
+    fn advance(self, c: char) -> Self {
+        match (self, indic_conjunct_break(c)) {
+            (_, IndicConjunctBreak::Consonant) => Conjunct::Consonant,
+            (Conjunct::None, IndicConjunctBreak::Linker) => Conjunct::None,
+            (_, IndicConjunctBreak::Linker) => Conjunct::Linked,
+            (state, IndicConjunctBreak::Extend) => state,
+            (_, IndicConjunctBreak::None) => Conjunct::None,
+        }
+    }
+}
+
+impl Emoji {
+    fn advance(self, next: GraphemeCat) -> Self {
+        match (self, next) {
+            (_, GraphemeCat::ExtendedPictographic) => Emoji::Pictographic,
+            (Emoji::Pictographic, GraphemeCat::Extend) => Emoji::Pictographic,
+            (Emoji::Pictographic, GraphemeCat::Zwj) => Emoji::Joined,
+            _ => Emoji::None,
+        }
+    }
+}
+
+/// Returns the length in bytes of the word segment at the start of `s`, or zero if `s` is empty.
+///
+/// Segments are words, but also runs of whitespace, punctuation characters and so on.
+pub(crate) fn next_word_len(s: &str) -> usize {
+    use WordCat::*;
+
+    fn is_ignored(cat: WordCat) -> bool {
+        matches!(cat, Extend | Format | Zwj)
+    }
+    fn is_ah_letter(cat: WordCat) -> bool {
+        matches!(cat, ALetter | HebrewLetter)
+    }
+    fn is_mid_letter(cat: WordCat) -> bool {
+        matches!(cat, MidLetter | MidNumLet | SingleQuote)
+    }
+    fn is_mid_num(cat: WordCat) -> bool {
+        matches!(cat, MidNum | MidNumLet | SingleQuote)
+    }
+
+    let mut chars = s.char_indices();
+    let Some((_, first)) = chars.next() else { return 0 };
+    // The category of the previous character, even if it is an extending or format character.
+    let mut last = wb::lookup(first);
+    // The categories of the two previous characters, ignoring extending and format characters
+    // as in WB4.
+    let mut prev = last;
+    let mut prev_prev = None;
+    let mut regional_indicators = usize::from(prev == RegionalIndicator);
+
+    while let Some((i, c)) = chars.next() {
+        let next = wb::lookup(c);
+        // The category of the character after `c`, ignoring extending and format characters.
+        let lookahead = || chars.clone().map(|(_, c)| wb::lookup(c)).find(|&cat| !is_ignored(cat));
+
+        let keep = match (last, next) {
+            (Cr, Lf) => true,
+            (Newline | Cr | Lf, _) | (_, Newline | Cr | Lf) => false,
+            (Zwj, _) if gcb::lookup(c) == GraphemeCat::ExtendedPictographic => true,
+            (WSegSpace, WSegSpace) => true,
+            (_, next) if is_ignored(next) => {
+                last = next;
+                continue;
+            }
+            _ => match (prev, next) {
+                (p, n) if is_ah_letter(p) && is_ah_letter(n) => true,
+                (HebrewLetter, SingleQuote) => true,
+                (p, n) if is_ah_letter(p) && is_mid_letter(n) => {
+                    lookahead().is_some_and(is_ah_letter)
+                }
+                (p, n) if is_mid_letter(p) && is_ah_letter(n) => {
+                    prev_prev.is_some_and(is_ah_letter)
+                }
+                (HebrewLetter, DoubleQuote) => lookahead() == Some(HebrewLetter),
+                (DoubleQuote, HebrewLetter) => prev_prev == Some(HebrewLetter),
+                (Numeric, Numeric) => true,
+                (p, Numeric) if is_ah_letter(p) => true,
+                (Numeric, n) if is_ah_letter(n) => true,
+                (p, Numeric) if is_mid_num(p) => prev_prev == Some(Numeric),
+                (Numeric, n) if is_mid_num(n) => lookahead() == Some(Numeric),
+                (Katakana, Katakana) => true,
+                (ALetter | HebrewLetter | Numeric | Katakana | ExtendNumLet, ExtendNumLet) => true,
+                (ExtendNumLet, ALetter | HebrewLetter | Numeric | Katakana) => true,
+                (RegionalIndicator, RegionalIndicator) => regional_indicators % 2 == 1,
+                _ => false,
+            },
+        };
+        if !keep {
+            return i;
+        }
+
+        last = next;
+        prev_prev = Some(prev);
+        prev = next;
+        regional_indicators = if next == RegionalIndicator { regional_indicators + 1 } else { 0 };
+    }
+    s.len()
+}
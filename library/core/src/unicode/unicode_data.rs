@@ -772,6 +772,23 @@ pub mod conversions {
         }
     }
 
+    pub fn to_folded(c: char) -> [char; 3] {
+        if c.is_ascii() {
+            [(c as u8).to_ascii_lowercase() as char, '\0', '\0']
+        } else {
+            FOLDCASE_TABLE
+                .binary_search_by(|&(key, _)| key.cmp(&c))
+                .map(|i| {
+                    let u = FOLDCASE_TABLE[i].1;
+                    char::from_u32(u).map(|c| [c, '\0', '\0']).unwrap_or_else(|| {
+                        // SAFETY: Index comes from statically generated table
+                        unsafe { *FOLDCASE_TABLE_MULTI.get_unchecked((u & (INDEX_MASK - 1)) as usize) }
+                    })
+                })
+                .unwrap_or([c, '\0', '\0'])
+        }
+    }
+
     static LOWERCASE_TABLE: &[(char, u32)] = &[
         ('\u{c0}', 224), ('\u{c1}', 225), ('\u{c2}', 226), ('\u{c3}', 227), ('\u{c4}', 228),
         ('\u{c5}', 229), ('\u{c6}', 230), ('\u{c7}', 231), ('\u{c8}', 232), ('\u{c9}', 233),
@@ -1549,4 +1566,5103 @@ pub mod conversions {
         ['\u{544}', '\u{53b}', '\u{0}'], ['\u{54e}', '\u{546}', '\u{0}'],
         ['\u{544}', '\u{53d}', '\u{0}'],
     ];
+
+    static FOLDCASE_TABLE: &[(char, u32)] = &[
+        ('\u{b5}', 956), ('\u{c0}', 224), ('\u{c1}', 225), ('\u{c2}', 226), ('\u{c3}', 227),
+        ('\u{c4}', 228), ('\u{c5}', 229), ('\u{c6}', 230), ('\u{c7}', 231), ('\u{c8}', 232),
+        ('\u{c9}', 233), ('\u{ca}', 234), ('\u{cb}', 235), ('\u{cc}', 236), ('\u{cd}', 237),
+        ('\u{ce}', 238), ('\u{cf}', 239), ('\u{d0}', 240), ('\u{d1}', 241), ('\u{d2}', 242),
+        ('\u{d3}', 243), ('\u{d4}', 244), ('\u{d5}', 245), ('\u{d6}', 246), ('\u{d8}', 248),
+        ('\u{d9}', 249), ('\u{da}', 250), ('\u{db}', 251), ('\u{dc}', 252), ('\u{dd}', 253),
+        ('\u{de}', 254), ('\u{df}', 4194304), ('\u{100}', 257), ('\u{102}', 259), ('\u{104}', 261),
+        ('\u{106}', 263), ('\u{108}', 265), ('\u{10a}', 267), ('\u{10c}', 269), ('\u{10e}', 271),
+        ('\u{110}', 273), ('\u{112}', 275), ('\u{114}', 277), ('\u{116}', 279), ('\u{118}', 281),
+        ('\u{11a}', 283), ('\u{11c}', 285), ('\u{11e}', 287), ('\u{120}', 289), ('\u{122}', 291),
+        ('\u{124}', 293), ('\u{126}', 295), ('\u{128}', 297), ('\u{12a}', 299), ('\u{12c}', 301),
+        ('\u{12e}', 303), ('\u{130}', 4194305), ('\u{132}', 307), ('\u{134}', 309),
+        ('\u{136}', 311), ('\u{139}', 314), ('\u{13b}', 316), ('\u{13d}', 318), ('\u{13f}', 320),
+        ('\u{141}', 322), ('\u{143}', 324), ('\u{145}', 326), ('\u{147}', 328),
+        ('\u{149}', 4194306), ('\u{14a}', 331), ('\u{14c}', 333), ('\u{14e}', 335),
+        ('\u{150}', 337), ('\u{152}', 339), ('\u{154}', 341), ('\u{156}', 343), ('\u{158}', 345),
+        ('\u{15a}', 347), ('\u{15c}', 349), ('\u{15e}', 351), ('\u{160}', 353), ('\u{162}', 355),
+        ('\u{164}', 357), ('\u{166}', 359), ('\u{168}', 361), ('\u{16a}', 363), ('\u{16c}', 365),
+        ('\u{16e}', 367), ('\u{170}', 369), ('\u{172}', 371), ('\u{174}', 373), ('\u{176}', 375),
+        ('\u{178}', 255), ('\u{179}', 378), ('\u{17b}', 380), ('\u{17d}', 382), ('\u{17f}', 115),
+        ('\u{181}', 595), ('\u{182}', 387), ('\u{184}', 389), ('\u{186}', 596), ('\u{187}', 392),
+        ('\u{189}', 598), ('\u{18a}', 599), ('\u{18b}', 396), ('\u{18e}', 477), ('\u{18f}', 601),
+        ('\u{190}', 603), ('\u{191}', 402), ('\u{193}', 608), ('\u{194}', 611), ('\u{196}', 617),
+        ('\u{197}', 616), ('\u{198}', 409), ('\u{19c}', 623), ('\u{19d}', 626), ('\u{19f}', 629),
+        ('\u{1a0}', 417), ('\u{1a2}', 419), ('\u{1a4}', 421), ('\u{1a6}', 640), ('\u{1a7}', 424),
+        ('\u{1a9}', 643), ('\u{1ac}', 429), ('\u{1ae}', 648), ('\u{1af}', 432), ('\u{1b1}', 650),
+        ('\u{1b2}', 651), ('\u{1b3}', 436), ('\u{1b5}', 438), ('\u{1b7}', 658), ('\u{1b8}', 441),
+        ('\u{1bc}', 445), ('\u{1c4}', 454), ('\u{1c5}', 454), ('\u{1c7}', 457), ('\u{1c8}', 457),
+        ('\u{1ca}', 460), ('\u{1cb}', 460), ('\u{1cd}', 462), ('\u{1cf}', 464), ('\u{1d1}', 466),
+        ('\u{1d3}', 468), ('\u{1d5}', 470), ('\u{1d7}', 472), ('\u{1d9}', 474), ('\u{1db}', 476),
+        ('\u{1de}', 479), ('\u{1e0}', 481), ('\u{1e2}', 483), ('\u{1e4}', 485), ('\u{1e6}', 487),
+        ('\u{1e8}', 489), ('\u{1ea}', 491), ('\u{1ec}', 493), ('\u{1ee}', 495),
+        ('\u{1f0}', 4194307), ('\u{1f1}', 499), ('\u{1f2}', 499), ('\u{1f4}', 501),
+        ('\u{1f6}', 405), ('\u{1f7}', 447), ('\u{1f8}', 505), ('\u{1fa}', 507), ('\u{1fc}', 509),
+        ('\u{1fe}', 511), ('\u{200}', 513), ('\u{202}', 515), ('\u{204}', 517), ('\u{206}', 519),
+        ('\u{208}', 521), ('\u{20a}', 523), ('\u{20c}', 525), ('\u{20e}', 527), ('\u{210}', 529),
+        ('\u{212}', 531), ('\u{214}', 533), ('\u{216}', 535), ('\u{218}', 537), ('\u{21a}', 539),
+        ('\u{21c}', 541), ('\u{21e}', 543), ('\u{220}', 414), ('\u{222}', 547), ('\u{224}', 549),
+        ('\u{226}', 551), ('\u{228}', 553), ('\u{22a}', 555), ('\u{22c}', 557), ('\u{22e}', 559),
+        ('\u{230}', 561), ('\u{232}', 563), ('\u{23a}', 11365), ('\u{23b}', 572), ('\u{23d}', 410),
+        ('\u{23e}', 11366), ('\u{241}', 578), ('\u{243}', 384), ('\u{244}', 649), ('\u{245}', 652),
+        ('\u{246}', 583), ('\u{248}', 585), ('\u{24a}', 587), ('\u{24c}', 589), ('\u{24e}', 591),
+        ('\u{345}', 953), ('\u{370}', 881), ('\u{372}', 883), ('\u{376}', 887), ('\u{37f}', 1011),
+        ('\u{386}', 940), ('\u{388}', 941), ('\u{389}', 942), ('\u{38a}', 943), ('\u{38c}', 972),
+        ('\u{38e}', 973), ('\u{38f}', 974), ('\u{390}', 4194308), ('\u{391}', 945),
+        ('\u{392}', 946), ('\u{393}', 947), ('\u{394}', 948), ('\u{395}', 949), ('\u{396}', 950),
+        ('\u{397}', 951), ('\u{398}', 952), ('\u{399}', 953), ('\u{39a}', 954), ('\u{39b}', 955),
+        ('\u{39c}', 956), ('\u{39d}', 957), ('\u{39e}', 958), ('\u{39f}', 959), ('\u{3a0}', 960),
+        ('\u{3a1}', 961), ('\u{3a3}', 963), ('\u{3a4}', 964), ('\u{3a5}', 965), ('\u{3a6}', 966),
+        ('\u{3a7}', 967), ('\u{3a8}', 968), ('\u{3a9}', 969), ('\u{3aa}', 970), ('\u{3ab}', 971),
+        ('\u{3b0}', 4194309), ('\u{3c2}', 963), ('\u{3cf}', 983), ('\u{3d0}', 946),
+        ('\u{3d1}', 952), ('\u{3d5}', 966), ('\u{3d6}', 960), ('\u{3d8}', 985), ('\u{3da}', 987),
+        ('\u{3dc}', 989), ('\u{3de}', 991), ('\u{3e0}', 993), ('\u{3e2}', 995), ('\u{3e4}', 997),
+        ('\u{3e6}', 999), ('\u{3e8}', 1001), ('\u{3ea}', 1003), ('\u{3ec}', 1005),
+        ('\u{3ee}', 1007), ('\u{3f0}', 954), ('\u{3f1}', 961), ('\u{3f4}', 952), ('\u{3f5}', 949),
+        ('\u{3f7}', 1016), ('\u{3f9}', 1010), ('\u{3fa}', 1019), ('\u{3fd}', 891), ('\u{3fe}', 892),
+        ('\u{3ff}', 893), ('\u{400}', 1104), ('\u{401}', 1105), ('\u{402}', 1106),
+        ('\u{403}', 1107), ('\u{404}', 1108), ('\u{405}', 1109), ('\u{406}', 1110),
+        ('\u{407}', 1111), ('\u{408}', 1112), ('\u{409}', 1113), ('\u{40a}', 1114),
+        ('\u{40b}', 1115), ('\u{40c}', 1116), ('\u{40d}', 1117), ('\u{40e}', 1118),
+        ('\u{40f}', 1119), ('\u{410}', 1072), ('\u{411}', 1073), ('\u{412}', 1074),
+        ('\u{413}', 1075), ('\u{414}', 1076), ('\u{415}', 1077), ('\u{416}', 1078),
+        ('\u{417}', 1079), ('\u{418}', 1080), ('\u{419}', 1081), ('\u{41a}', 1082),
+        ('\u{41b}', 1083), ('\u{41c}', 1084), ('\u{41d}', 1085), ('\u{41e}', 1086),
+        ('\u{41f}', 1087), ('\u{420}', 1088), ('\u{421}', 1089), ('\u{422}', 1090),
+        ('\u{423}', 1091), ('\u{424}', 1092), ('\u{425}', 1093), ('\u{426}', 1094),
+        ('\u{427}', 1095), ('\u{428}', 1096), ('\u{429}', 1097), ('\u{42a}', 1098),
+        ('\u{42b}', 1099), ('\u{42c}', 1100), ('\u{42d}', 1101), ('\u{42e}', 1102),
+        ('\u{42f}', 1103), ('\u{460}', 1121), ('\u{462}', 1123), ('\u{464}', 1125),
+        ('\u{466}', 1127), ('\u{468}', 1129), ('\u{46a}', 1131), ('\u{46c}', 1133),
+        ('\u{46e}', 1135), ('\u{470}', 1137), ('\u{472}', 1139), ('\u{474}', 1141),
+        ('\u{476}', 1143), ('\u{478}', 1145), ('\u{47a}', 1147), ('\u{47c}', 1149),
+        ('\u{47e}', 1151), ('\u{480}', 1153), ('\u{48a}', 1163), ('\u{48c}', 1165),
+        ('\u{48e}', 1167), ('\u{490}', 1169), ('\u{492}', 1171), ('\u{494}', 1173),
+        ('\u{496}', 1175), ('\u{498}', 1177), ('\u{49a}', 1179), ('\u{49c}', 1181),
+        ('\u{49e}', 1183), ('\u{4a0}', 1185), ('\u{4a2}', 1187), ('\u{4a4}', 1189),
+        ('\u{4a6}', 1191), ('\u{4a8}', 1193), ('\u{4aa}', 1195), ('\u{4ac}', 1197),
+        ('\u{4ae}', 1199), ('\u{4b0}', 1201), ('\u{4b2}', 1203), ('\u{4b4}', 1205),
+        ('\u{4b6}', 1207), ('\u{4b8}', 1209), ('\u{4ba}', 1211), ('\u{4bc}', 1213),
+        ('\u{4be}', 1215), ('\u{4c0}', 1231), ('\u{4c1}', 1218), ('\u{4c3}', 1220),
+        ('\u{4c5}', 1222), ('\u{4c7}', 1224), ('\u{4c9}', 1226), ('\u{4cb}', 1228),
+        ('\u{4cd}', 1230), ('\u{4d0}', 1233), ('\u{4d2}', 1235), ('\u{4d4}', 1237),
+        ('\u{4d6}', 1239), ('\u{4d8}', 1241), ('\u{4da}', 1243), ('\u{4dc}', 1245),
+        ('\u{4de}', 1247), ('\u{4e0}', 1249), ('\u{4e2}', 1251), ('\u{4e4}', 1253),
+        ('\u{4e6}', 1255), ('\u{4e8}', 1257), ('\u{4ea}', 1259), ('\u{4ec}', 1261),
+        ('\u{4ee}', 1263), ('\u{4f0}', 1265), ('\u{4f2}', 1267), ('\u{4f4}', 1269),
+        ('\u{4f6}', 1271), ('\u{4f8}', 1273), ('\u{4fa}', 1275), ('\u{4fc}', 1277),
+        ('\u{4fe}', 1279), ('\u{500}', 1281), ('\u{502}', 1283), ('\u{504}', 1285),
+        ('\u{506}', 1287), ('\u{508}', 1289), ('\u{50a}', 1291), ('\u{50c}', 1293),
+        ('\u{50e}', 1295), ('\u{510}', 1297), ('\u{512}', 1299), ('\u{514}', 1301),
+        ('\u{516}', 1303), ('\u{518}', 1305), ('\u{51a}', 1307), ('\u{51c}', 1309),
+        ('\u{51e}', 1311), ('\u{520}', 1313), ('\u{522}', 1315), ('\u{524}', 1317),
+        ('\u{526}', 1319), ('\u{528}', 1321), ('\u{52a}', 1323), ('\u{52c}', 1325),
+        ('\u{52e}', 1327), ('\u{531}', 1377), ('\u{532}', 1378), ('\u{533}', 1379),
+        ('\u{534}', 1380), ('\u{535}', 1381), ('\u{536}', 1382), ('\u{537}', 1383),
+        ('\u{538}', 1384), ('\u{539}', 1385), ('\u{53a}', 1386), ('\u{53b}', 1387),
+        ('\u{53c}', 1388), ('\u{53d}', 1389), ('\u{53e}', 1390), ('\u{53f}', 1391),
+        ('\u{540}', 1392), ('\u{541}', 1393), ('\u{542}', 1394), ('\u{543}', 1395),
+        ('\u{544}', 1396), ('\u{545}', 1397), ('\u{546}', 1398), ('\u{547}', 1399),
+        ('\u{548}', 1400), ('\u{549}', 1401), ('\u{54a}', 1402), ('\u{54b}', 1403),
+        ('\u{54c}', 1404), ('\u{54d}', 1405), ('\u{54e}', 1406), ('\u{54f}', 1407),
+        ('\u{550}', 1408), ('\u{551}', 1409), ('\u{552}', 1410), ('\u{553}', 1411),
+        ('\u{554}', 1412), ('\u{555}', 1413), ('\u{556}', 1414), ('\u{587}', 4194310),
+        ('\u{10a0}', 11520), ('\u{10a1}', 11521), ('\u{10a2}', 11522), ('\u{10a3}', 11523),
+        ('\u{10a4}', 11524), ('\u{10a5}', 11525), ('\u{10a6}', 11526), ('\u{10a7}', 11527),
+        ('\u{10a8}', 11528), ('\u{10a9}', 11529), ('\u{10aa}', 11530), ('\u{10ab}', 11531),
+        ('\u{10ac}', 11532), ('\u{10ad}', 11533), ('\u{10ae}', 11534), ('\u{10af}', 11535),
+        ('\u{10b0}', 11536), ('\u{10b1}', 11537), ('\u{10b2}', 11538), ('\u{10b3}', 11539),
+        ('\u{10b4}', 11540), ('\u{10b5}', 11541), ('\u{10b6}', 11542), ('\u{10b7}', 11543),
+        ('\u{10b8}', 11544), ('\u{10b9}', 11545), ('\u{10ba}', 11546), ('\u{10bb}', 11547),
+        ('\u{10bc}', 11548), ('\u{10bd}', 11549), ('\u{10be}', 11550), ('\u{10bf}', 11551),
+        ('\u{10c0}', 11552), ('\u{10c1}', 11553), ('\u{10c2}', 11554), ('\u{10c3}', 11555),
+        ('\u{10c4}', 11556), ('\u{10c5}', 11557), ('\u{10c7}', 11559), ('\u{10cd}', 11565),
+        ('\u{13f8}', 5104), ('\u{13f9}', 5105), ('\u{13fa}', 5106), ('\u{13fb}', 5107),
+        ('\u{13fc}', 5108), ('\u{13fd}', 5109), ('\u{1c80}', 1074), ('\u{1c81}', 1076),
+        ('\u{1c82}', 1086), ('\u{1c83}', 1089), ('\u{1c84}', 1090), ('\u{1c85}', 1090),
+        ('\u{1c86}', 1098), ('\u{1c87}', 1123), ('\u{1c88}', 42571), ('\u{1c89}', 7306),
+        ('\u{1c90}', 4304), ('\u{1c91}', 4305), ('\u{1c92}', 4306), ('\u{1c93}', 4307),
+        ('\u{1c94}', 4308), ('\u{1c95}', 4309), ('\u{1c96}', 4310), ('\u{1c97}', 4311),
+        ('\u{1c98}', 4312), ('\u{1c99}', 4313), ('\u{1c9a}', 4314), ('\u{1c9b}', 4315),
+        ('\u{1c9c}', 4316), ('\u{1c9d}', 4317), ('\u{1c9e}', 4318), ('\u{1c9f}', 4319),
+        ('\u{1ca0}', 4320), ('\u{1ca1}', 4321), ('\u{1ca2}', 4322), ('\u{1ca3}', 4323),
+        ('\u{1ca4}', 4324), ('\u{1ca5}', 4325), ('\u{1ca6}', 4326), ('\u{1ca7}', 4327),
+        ('\u{1ca8}', 4328), ('\u{1ca9}', 4329), ('\u{1caa}', 4330), ('\u{1cab}', 4331),
+        ('\u{1cac}', 4332), ('\u{1cad}', 4333), ('\u{1cae}', 4334), ('\u{1caf}', 4335),
+        ('\u{1cb0}', 4336), ('\u{1cb1}', 4337), ('\u{1cb2}', 4338), ('\u{1cb3}', 4339),
+        ('\u{1cb4}', 4340), ('\u{1cb5}', 4341), ('\u{1cb6}', 4342), ('\u{1cb7}', 4343),
+        ('\u{1cb8}', 4344), ('\u{1cb9}', 4345), ('\u{1cba}', 4346), ('\u{1cbd}', 4349),
+        ('\u{1cbe}', 4350), ('\u{1cbf}', 4351), ('\u{1e00}', 7681), ('\u{1e02}', 7683),
+        ('\u{1e04}', 7685), ('\u{1e06}', 7687), ('\u{1e08}', 7689), ('\u{1e0a}', 7691),
+        ('\u{1e0c}', 7693), ('\u{1e0e}', 7695), ('\u{1e10}', 7697), ('\u{1e12}', 7699),
+        ('\u{1e14}', 7701), ('\u{1e16}', 7703), ('\u{1e18}', 7705), ('\u{1e1a}', 7707),
+        ('\u{1e1c}', 7709), ('\u{1e1e}', 7711), ('\u{1e20}', 7713), ('\u{1e22}', 7715),
+        ('\u{1e24}', 7717), ('\u{1e26}', 7719), ('\u{1e28}', 7721), ('\u{1e2a}', 7723),
+        ('\u{1e2c}', 7725), ('\u{1e2e}', 7727), ('\u{1e30}', 7729), ('\u{1e32}', 7731),
+        ('\u{1e34}', 7733), ('\u{1e36}', 7735), ('\u{1e38}', 7737), ('\u{1e3a}', 7739),
+        ('\u{1e3c}', 7741), ('\u{1e3e}', 7743), ('\u{1e40}', 7745), ('\u{1e42}', 7747),
+        ('\u{1e44}', 7749), ('\u{1e46}', 7751), ('\u{1e48}', 7753), ('\u{1e4a}', 7755),
+        ('\u{1e4c}', 7757), ('\u{1e4e}', 7759), ('\u{1e50}', 7761), ('\u{1e52}', 7763),
+        ('\u{1e54}', 7765), ('\u{1e56}', 7767), ('\u{1e58}', 7769), ('\u{1e5a}', 7771),
+        ('\u{1e5c}', 7773), ('\u{1e5e}', 7775), ('\u{1e60}', 7777), ('\u{1e62}', 7779),
+        ('\u{1e64}', 7781), ('\u{1e66}', 7783), ('\u{1e68}', 7785), ('\u{1e6a}', 7787),
+        ('\u{1e6c}', 7789), ('\u{1e6e}', 7791), ('\u{1e70}', 7793), ('\u{1e72}', 7795),
+        ('\u{1e74}', 7797), ('\u{1e76}', 7799), ('\u{1e78}', 7801), ('\u{1e7a}', 7803),
+        ('\u{1e7c}', 7805), ('\u{1e7e}', 7807), ('\u{1e80}', 7809), ('\u{1e82}', 7811),
+        ('\u{1e84}', 7813), ('\u{1e86}', 7815), ('\u{1e88}', 7817), ('\u{1e8a}', 7819),
+        ('\u{1e8c}', 7821), ('\u{1e8e}', 7823), ('\u{1e90}', 7825), ('\u{1e92}', 7827),
+        ('\u{1e94}', 7829), ('\u{1e96}', 4194311), ('\u{1e97}', 4194312), ('\u{1e98}', 4194313),
+        ('\u{1e99}', 4194314), ('\u{1e9a}', 4194315), ('\u{1e9b}', 7777), ('\u{1e9e}', 4194316),
+        ('\u{1ea0}', 7841), ('\u{1ea2}', 7843), ('\u{1ea4}', 7845), ('\u{1ea6}', 7847),
+        ('\u{1ea8}', 7849), ('\u{1eaa}', 7851), ('\u{1eac}', 7853), ('\u{1eae}', 7855),
+        ('\u{1eb0}', 7857), ('\u{1eb2}', 7859), ('\u{1eb4}', 7861), ('\u{1eb6}', 7863),
+        ('\u{1eb8}', 7865), ('\u{1eba}', 7867), ('\u{1ebc}', 7869), ('\u{1ebe}', 7871),
+        ('\u{1ec0}', 7873), ('\u{1ec2}', 7875), ('\u{1ec4}', 7877), ('\u{1ec6}', 7879),
+        ('\u{1ec8}', 7881), ('\u{1eca}', 7883), ('\u{1ecc}', 7885), ('\u{1ece}', 7887),
+        ('\u{1ed0}', 7889), ('\u{1ed2}', 7891), ('\u{1ed4}', 7893), ('\u{1ed6}', 7895),
+        ('\u{1ed8}', 7897), ('\u{1eda}', 7899), ('\u{1edc}', 7901), ('\u{1ede}', 7903),
+        ('\u{1ee0}', 7905), ('\u{1ee2}', 7907), ('\u{1ee4}', 7909), ('\u{1ee6}', 7911),
+        ('\u{1ee8}', 7913), ('\u{1eea}', 7915), ('\u{1eec}', 7917), ('\u{1eee}', 7919),
+        ('\u{1ef0}', 7921), ('\u{1ef2}', 7923), ('\u{1ef4}', 7925), ('\u{1ef6}', 7927),
+        ('\u{1ef8}', 7929), ('\u{1efa}', 7931), ('\u{1efc}', 7933), ('\u{1efe}', 7935),
+        ('\u{1f08}', 7936), ('\u{1f09}', 7937), ('\u{1f0a}', 7938), ('\u{1f0b}', 7939),
+        ('\u{1f0c}', 7940), ('\u{1f0d}', 7941), ('\u{1f0e}', 7942), ('\u{1f0f}', 7943),
+        ('\u{1f18}', 7952), ('\u{1f19}', 7953), ('\u{1f1a}', 7954), ('\u{1f1b}', 7955),
+        ('\u{1f1c}', 7956), ('\u{1f1d}', 7957), ('\u{1f28}', 7968), ('\u{1f29}', 7969),
+        ('\u{1f2a}', 7970), ('\u{1f2b}', 7971), ('\u{1f2c}', 7972), ('\u{1f2d}', 7973),
+        ('\u{1f2e}', 7974), ('\u{1f2f}', 7975), ('\u{1f38}', 7984), ('\u{1f39}', 7985),
+        ('\u{1f3a}', 7986), ('\u{1f3b}', 7987), ('\u{1f3c}', 7988), ('\u{1f3d}', 7989),
+        ('\u{1f3e}', 7990), ('\u{1f3f}', 7991), ('\u{1f48}', 8000), ('\u{1f49}', 8001),
+        ('\u{1f4a}', 8002), ('\u{1f4b}', 8003), ('\u{1f4c}', 8004), ('\u{1f4d}', 8005),
+        ('\u{1f50}', 4194317), ('\u{1f52}', 4194318), ('\u{1f54}', 4194319), ('\u{1f56}', 4194320),
+        ('\u{1f59}', 8017), ('\u{1f5b}', 8019), ('\u{1f5d}', 8021), ('\u{1f5f}', 8023),
+        ('\u{1f68}', 8032), ('\u{1f69}', 8033), ('\u{1f6a}', 8034), ('\u{1f6b}', 8035),
+        ('\u{1f6c}', 8036), ('\u{1f6d}', 8037), ('\u{1f6e}', 8038), ('\u{1f6f}', 8039),
+        ('\u{1f80}', 4194321), ('\u{1f81}', 4194322), ('\u{1f82}', 4194323), ('\u{1f83}', 4194324),
+        ('\u{1f84}', 4194325), ('\u{1f85}', 4194326), ('\u{1f86}', 4194327), ('\u{1f87}', 4194328),
+        ('\u{1f88}', 4194329), ('\u{1f89}', 4194330), ('\u{1f8a}', 4194331), ('\u{1f8b}', 4194332),
+        ('\u{1f8c}', 4194333), ('\u{1f8d}', 4194334), ('\u{1f8e}', 4194335), ('\u{1f8f}', 4194336),
+        ('\u{1f90}', 4194337), ('\u{1f91}', 4194338), ('\u{1f92}', 4194339), ('\u{1f93}', 4194340),
+        ('\u{1f94}', 4194341), ('\u{1f95}', 4194342), ('\u{1f96}', 4194343), ('\u{1f97}', 4194344),
+        ('\u{1f98}', 4194345), ('\u{1f99}', 4194346), ('\u{1f9a}', 4194347), ('\u{1f9b}', 4194348),
+        ('\u{1f9c}', 4194349), ('\u{1f9d}', 4194350), ('\u{1f9e}', 4194351), ('\u{1f9f}', 4194352),
+        ('\u{1fa0}', 4194353), ('\u{1fa1}', 4194354), ('\u{1fa2}', 4194355), ('\u{1fa3}', 4194356),
+        ('\u{1fa4}', 4194357), ('\u{1fa5}', 4194358), ('\u{1fa6}', 4194359), ('\u{1fa7}', 4194360),
+        ('\u{1fa8}', 4194361), ('\u{1fa9}', 4194362), ('\u{1faa}', 4194363), ('\u{1fab}', 4194364),
+        ('\u{1fac}', 4194365), ('\u{1fad}', 4194366), ('\u{1fae}', 4194367), ('\u{1faf}', 4194368),
+        ('\u{1fb2}', 4194369), ('\u{1fb3}', 4194370), ('\u{1fb4}', 4194371), ('\u{1fb6}', 4194372),
+        ('\u{1fb7}', 4194373), ('\u{1fb8}', 8112), ('\u{1fb9}', 8113), ('\u{1fba}', 8048),
+        ('\u{1fbb}', 8049), ('\u{1fbc}', 4194374), ('\u{1fbe}', 953), ('\u{1fc2}', 4194375),
+        ('\u{1fc3}', 4194376), ('\u{1fc4}', 4194377), ('\u{1fc6}', 4194378), ('\u{1fc7}', 4194379),
+        ('\u{1fc8}', 8050), ('\u{1fc9}', 8051), ('\u{1fca}', 8052), ('\u{1fcb}', 8053),
+        ('\u{1fcc}', 4194380), ('\u{1fd2}', 4194381), ('\u{1fd3}', 4194382), ('\u{1fd6}', 4194383),
+        ('\u{1fd7}', 4194384), ('\u{1fd8}', 8144), ('\u{1fd9}', 8145), ('\u{1fda}', 8054),
+        ('\u{1fdb}', 8055), ('\u{1fe2}', 4194385), ('\u{1fe3}', 4194386), ('\u{1fe4}', 4194387),
+        ('\u{1fe6}', 4194388), ('\u{1fe7}', 4194389), ('\u{1fe8}', 8160), ('\u{1fe9}', 8161),
+        ('\u{1fea}', 8058), ('\u{1feb}', 8059), ('\u{1fec}', 8165), ('\u{1ff2}', 4194390),
+        ('\u{1ff3}', 4194391), ('\u{1ff4}', 4194392), ('\u{1ff6}', 4194393), ('\u{1ff7}', 4194394),
+        ('\u{1ff8}', 8056), ('\u{1ff9}', 8057), ('\u{1ffa}', 8060), ('\u{1ffb}', 8061),
+        ('\u{1ffc}', 4194395), ('\u{2126}', 969), ('\u{212a}', 107), ('\u{212b}', 229),
+        ('\u{2132}', 8526), ('\u{2160}', 8560), ('\u{2161}', 8561), ('\u{2162}', 8562),
+        ('\u{2163}', 8563), ('\u{2164}', 8564), ('\u{2165}', 8565), ('\u{2166}', 8566),
+        ('\u{2167}', 8567), ('\u{2168}', 8568), ('\u{2169}', 8569), ('\u{216a}', 8570),
+        ('\u{216b}', 8571), ('\u{216c}', 8572), ('\u{216d}', 8573), ('\u{216e}', 8574),
+        ('\u{216f}', 8575), ('\u{2183}', 8580), ('\u{24b6}', 9424), ('\u{24b7}', 9425),
+        ('\u{24b8}', 9426), ('\u{24b9}', 9427), ('\u{24ba}', 9428), ('\u{24bb}', 9429),
+        ('\u{24bc}', 9430), ('\u{24bd}', 9431), ('\u{24be}', 9432), ('\u{24bf}', 9433),
+        ('\u{24c0}', 9434), ('\u{24c1}', 9435), ('\u{24c2}', 9436), ('\u{24c3}', 9437),
+        ('\u{24c4}', 9438), ('\u{24c5}', 9439), ('\u{24c6}', 9440), ('\u{24c7}', 9441),
+        ('\u{24c8}', 9442), ('\u{24c9}', 9443), ('\u{24ca}', 9444), ('\u{24cb}', 9445),
+        ('\u{24cc}', 9446), ('\u{24cd}', 9447), ('\u{24ce}', 9448), ('\u{24cf}', 9449),
+        ('\u{2c00}', 11312), ('\u{2c01}', 11313), ('\u{2c02}', 11314), ('\u{2c03}', 11315),
+        ('\u{2c04}', 11316), ('\u{2c05}', 11317), ('\u{2c06}', 11318), ('\u{2c07}', 11319),
+        ('\u{2c08}', 11320), ('\u{2c09}', 11321), ('\u{2c0a}', 11322), ('\u{2c0b}', 11323),
+        ('\u{2c0c}', 11324), ('\u{2c0d}', 11325), ('\u{2c0e}', 11326), ('\u{2c0f}', 11327),
+        ('\u{2c10}', 11328), ('\u{2c11}', 11329), ('\u{2c12}', 11330), ('\u{2c13}', 11331),
+        ('\u{2c14}', 11332), ('\u{2c15}', 11333), ('\u{2c16}', 11334), ('\u{2c17}', 11335),
+        ('\u{2c18}', 11336), ('\u{2c19}', 11337), ('\u{2c1a}', 11338), ('\u{2c1b}', 11339),
+        ('\u{2c1c}', 11340), ('\u{2c1d}', 11341), ('\u{2c1e}', 11342), ('\u{2c1f}', 11343),
+        ('\u{2c20}', 11344), ('\u{2c21}', 11345), ('\u{2c22}', 11346), ('\u{2c23}', 11347),
+        ('\u{2c24}', 11348), ('\u{2c25}', 11349), ('\u{2c26}', 11350), ('\u{2c27}', 11351),
+        ('\u{2c28}', 11352), ('\u{2c29}', 11353), ('\u{2c2a}', 11354), ('\u{2c2b}', 11355),
+        ('\u{2c2c}', 11356), ('\u{2c2d}', 11357), ('\u{2c2e}', 11358), ('\u{2c2f}', 11359),
+        ('\u{2c60}', 11361), ('\u{2c62}', 619), ('\u{2c63}', 7549), ('\u{2c64}', 637),
+        ('\u{2c67}', 11368), ('\u{2c69}', 11370), ('\u{2c6b}', 11372), ('\u{2c6d}', 593),
+        ('\u{2c6e}', 625), ('\u{2c6f}', 592), ('\u{2c70}', 594), ('\u{2c72}', 11379),
+        ('\u{2c75}', 11382), ('\u{2c7e}', 575), ('\u{2c7f}', 576), ('\u{2c80}', 11393),
+        ('\u{2c82}', 11395), ('\u{2c84}', 11397), ('\u{2c86}', 11399), ('\u{2c88}', 11401),
+        ('\u{2c8a}', 11403), ('\u{2c8c}', 11405), ('\u{2c8e}', 11407), ('\u{2c90}', 11409),
+        ('\u{2c92}', 11411), ('\u{2c94}', 11413), ('\u{2c96}', 11415), ('\u{2c98}', 11417),
+        ('\u{2c9a}', 11419), ('\u{2c9c}', 11421), ('\u{2c9e}', 11423), ('\u{2ca0}', 11425),
+        ('\u{2ca2}', 11427), ('\u{2ca4}', 11429), ('\u{2ca6}', 11431), ('\u{2ca8}', 11433),
+        ('\u{2caa}', 11435), ('\u{2cac}', 11437), ('\u{2cae}', 11439), ('\u{2cb0}', 11441),
+        ('\u{2cb2}', 11443), ('\u{2cb4}', 11445), ('\u{2cb6}', 11447), ('\u{2cb8}', 11449),
+        ('\u{2cba}', 11451), ('\u{2cbc}', 11453), ('\u{2cbe}', 11455), ('\u{2cc0}', 11457),
+        ('\u{2cc2}', 11459), ('\u{2cc4}', 11461), ('\u{2cc6}', 11463), ('\u{2cc8}', 11465),
+        ('\u{2cca}', 11467), ('\u{2ccc}', 11469), ('\u{2cce}', 11471), ('\u{2cd0}', 11473),
+        ('\u{2cd2}', 11475), ('\u{2cd4}', 11477), ('\u{2cd6}', 11479), ('\u{2cd8}', 11481),
+        ('\u{2cda}', 11483), ('\u{2cdc}', 11485), ('\u{2cde}', 11487), ('\u{2ce0}', 11489),
+        ('\u{2ce2}', 11491), ('\u{2ceb}', 11500), ('\u{2ced}', 11502), ('\u{2cf2}', 11507),
+        ('\u{a640}', 42561), ('\u{a642}', 42563), ('\u{a644}', 42565), ('\u{a646}', 42567),
+        ('\u{a648}', 42569), ('\u{a64a}', 42571), ('\u{a64c}', 42573), ('\u{a64e}', 42575),
+        ('\u{a650}', 42577), ('\u{a652}', 42579), ('\u{a654}', 42581), ('\u{a656}', 42583),
+        ('\u{a658}', 42585), ('\u{a65a}', 42587), ('\u{a65c}', 42589), ('\u{a65e}', 42591),
+        ('\u{a660}', 42593), ('\u{a662}', 42595), ('\u{a664}', 42597), ('\u{a666}', 42599),
+        ('\u{a668}', 42601), ('\u{a66a}', 42603), ('\u{a66c}', 42605), ('\u{a680}', 42625),
+        ('\u{a682}', 42627), ('\u{a684}', 42629), ('\u{a686}', 42631), ('\u{a688}', 42633),
+        ('\u{a68a}', 42635), ('\u{a68c}', 42637), ('\u{a68e}', 42639), ('\u{a690}', 42641),
+        ('\u{a692}', 42643), ('\u{a694}', 42645), ('\u{a696}', 42647), ('\u{a698}', 42649),
+        ('\u{a69a}', 42651), ('\u{a722}', 42787), ('\u{a724}', 42789), ('\u{a726}', 42791),
+        ('\u{a728}', 42793), ('\u{a72a}', 42795), ('\u{a72c}', 42797), ('\u{a72e}', 42799),
+        ('\u{a732}', 42803), ('\u{a734}', 42805), ('\u{a736}', 42807), ('\u{a738}', 42809),
+        ('\u{a73a}', 42811), ('\u{a73c}', 42813), ('\u{a73e}', 42815), ('\u{a740}', 42817),
+        ('\u{a742}', 42819), ('\u{a744}', 42821), ('\u{a746}', 42823), ('\u{a748}', 42825),
+        ('\u{a74a}', 42827), ('\u{a74c}', 42829), ('\u{a74e}', 42831), ('\u{a750}', 42833),
+        ('\u{a752}', 42835), ('\u{a754}', 42837), ('\u{a756}', 42839), ('\u{a758}', 42841),
+        ('\u{a75a}', 42843), ('\u{a75c}', 42845), ('\u{a75e}', 42847), ('\u{a760}', 42849),
+        ('\u{a762}', 42851), ('\u{a764}', 42853), ('\u{a766}', 42855), ('\u{a768}', 42857),
+        ('\u{a76a}', 42859), ('\u{a76c}', 42861), ('\u{a76e}', 42863), ('\u{a779}', 42874),
+        ('\u{a77b}', 42876), ('\u{a77d}', 7545), ('\u{a77e}', 42879), ('\u{a780}', 42881),
+        ('\u{a782}', 42883), ('\u{a784}', 42885), ('\u{a786}', 42887), ('\u{a78b}', 42892),
+        ('\u{a78d}', 613), ('\u{a790}', 42897), ('\u{a792}', 42899), ('\u{a796}', 42903),
+        ('\u{a798}', 42905), ('\u{a79a}', 42907), ('\u{a79c}', 42909), ('\u{a79e}', 42911),
+        ('\u{a7a0}', 42913), ('\u{a7a2}', 42915), ('\u{a7a4}', 42917), ('\u{a7a6}', 42919),
+        ('\u{a7a8}', 42921), ('\u{a7aa}', 614), ('\u{a7ab}', 604), ('\u{a7ac}', 609),
+        ('\u{a7ad}', 620), ('\u{a7ae}', 618), ('\u{a7b0}', 670), ('\u{a7b1}', 647),
+        ('\u{a7b2}', 669), ('\u{a7b3}', 43859), ('\u{a7b4}', 42933), ('\u{a7b6}', 42935),
+        ('\u{a7b8}', 42937), ('\u{a7ba}', 42939), ('\u{a7bc}', 42941), ('\u{a7be}', 42943),
+        ('\u{a7c0}', 42945), ('\u{a7c2}', 42947), ('\u{a7c4}', 42900), ('\u{a7c5}', 642),
+        ('\u{a7c6}', 7566), ('\u{a7c7}', 42952), ('\u{a7c9}', 42954), ('\u{a7cb}', 612),
+        ('\u{a7cc}', 42957), ('\u{a7d0}', 42961), ('\u{a7d6}', 42967), ('\u{a7d8}', 42969),
+        ('\u{a7da}', 42971), ('\u{a7dc}', 411), ('\u{a7f5}', 42998), ('\u{ab70}', 5024),
+        ('\u{ab71}', 5025), ('\u{ab72}', 5026), ('\u{ab73}', 5027), ('\u{ab74}', 5028),
+        ('\u{ab75}', 5029), ('\u{ab76}', 5030), ('\u{ab77}', 5031), ('\u{ab78}', 5032),
+        ('\u{ab79}', 5033), ('\u{ab7a}', 5034), ('\u{ab7b}', 5035), ('\u{ab7c}', 5036),
+        ('\u{ab7d}', 5037), ('\u{ab7e}', 5038), ('\u{ab7f}', 5039), ('\u{ab80}', 5040),
+        ('\u{ab81}', 5041), ('\u{ab82}', 5042), ('\u{ab83}', 5043), ('\u{ab84}', 5044),
+        ('\u{ab85}', 5045), ('\u{ab86}', 5046), ('\u{ab87}', 5047), ('\u{ab88}', 5048),
+        ('\u{ab89}', 5049), ('\u{ab8a}', 5050), ('\u{ab8b}', 5051), ('\u{ab8c}', 5052),
+        ('\u{ab8d}', 5053), ('\u{ab8e}', 5054), ('\u{ab8f}', 5055), ('\u{ab90}', 5056),
+        ('\u{ab91}', 5057), ('\u{ab92}', 5058), ('\u{ab93}', 5059), ('\u{ab94}', 5060),
+        ('\u{ab95}', 5061), ('\u{ab96}', 5062), ('\u{ab97}', 5063), ('\u{ab98}', 5064),
+        ('\u{ab99}', 5065), ('\u{ab9a}', 5066), ('\u{ab9b}', 5067), ('\u{ab9c}', 5068),
+        ('\u{ab9d}', 5069), ('\u{ab9e}', 5070), ('\u{ab9f}', 5071), ('\u{aba0}', 5072),
+        ('\u{aba1}', 5073), ('\u{aba2}', 5074), ('\u{aba3}', 5075), ('\u{aba4}', 5076),
+        ('\u{aba5}', 5077), ('\u{aba6}', 5078), ('\u{aba7}', 5079), ('\u{aba8}', 5080),
+        ('\u{aba9}', 5081), ('\u{abaa}', 5082), ('\u{abab}', 5083), ('\u{abac}', 5084),
+        ('\u{abad}', 5085), ('\u{abae}', 5086), ('\u{abaf}', 5087), ('\u{abb0}', 5088),
+        ('\u{abb1}', 5089), ('\u{abb2}', 5090), ('\u{abb3}', 5091), ('\u{abb4}', 5092),
+        ('\u{abb5}', 5093), ('\u{abb6}', 5094), ('\u{abb7}', 5095), ('\u{abb8}', 5096),
+        ('\u{abb9}', 5097), ('\u{abba}', 5098), ('\u{abbb}', 5099), ('\u{abbc}', 5100),
+        ('\u{abbd}', 5101), ('\u{abbe}', 5102), ('\u{abbf}', 5103), ('\u{fb00}', 4194396),
+        ('\u{fb01}', 4194397), ('\u{fb02}', 4194398), ('\u{fb03}', 4194399), ('\u{fb04}', 4194400),
+        ('\u{fb05}', 4194401), ('\u{fb06}', 4194402), ('\u{fb13}', 4194403), ('\u{fb14}', 4194404),
+        ('\u{fb15}', 4194405), ('\u{fb16}', 4194406), ('\u{fb17}', 4194407), ('\u{ff21}', 65345),
+        ('\u{ff22}', 65346), ('\u{ff23}', 65347), ('\u{ff24}', 65348), ('\u{ff25}', 65349),
+        ('\u{ff26}', 65350), ('\u{ff27}', 65351), ('\u{ff28}', 65352), ('\u{ff29}', 65353),
+        ('\u{ff2a}', 65354), ('\u{ff2b}', 65355), ('\u{ff2c}', 65356), ('\u{ff2d}', 65357),
+        ('\u{ff2e}', 65358), ('\u{ff2f}', 65359), ('\u{ff30}', 65360), ('\u{ff31}', 65361),
+        ('\u{ff32}', 65362), ('\u{ff33}', 65363), ('\u{ff34}', 65364), ('\u{ff35}', 65365),
+        ('\u{ff36}', 65366), ('\u{ff37}', 65367), ('\u{ff38}', 65368), ('\u{ff39}', 65369),
+        ('\u{ff3a}', 65370), ('\u{10400}', 66600), ('\u{10401}', 66601), ('\u{10402}', 66602),
+        ('\u{10403}', 66603), ('\u{10404}', 66604), ('\u{10405}', 66605), ('\u{10406}', 66606),
+        ('\u{10407}', 66607), ('\u{10408}', 66608), ('\u{10409}', 66609), ('\u{1040a}', 66610),
+        ('\u{1040b}', 66611), ('\u{1040c}', 66612), ('\u{1040d}', 66613), ('\u{1040e}', 66614),
+        ('\u{1040f}', 66615), ('\u{10410}', 66616), ('\u{10411}', 66617), ('\u{10412}', 66618),
+        ('\u{10413}', 66619), ('\u{10414}', 66620), ('\u{10415}', 66621), ('\u{10416}', 66622),
+        ('\u{10417}', 66623), ('\u{10418}', 66624), ('\u{10419}', 66625), ('\u{1041a}', 66626),
+        ('\u{1041b}', 66627), ('\u{1041c}', 66628), ('\u{1041d}', 66629), ('\u{1041e}', 66630),
+        ('\u{1041f}', 66631), ('\u{10420}', 66632), ('\u{10421}', 66633), ('\u{10422}', 66634),
+        ('\u{10423}', 66635), ('\u{10424}', 66636), ('\u{10425}', 66637), ('\u{10426}', 66638),
+        ('\u{10427}', 66639), ('\u{104b0}', 66776), ('\u{104b1}', 66777), ('\u{104b2}', 66778),
+        ('\u{104b3}', 66779), ('\u{104b4}', 66780), ('\u{104b5}', 66781), ('\u{104b6}', 66782),
+        ('\u{104b7}', 66783), ('\u{104b8}', 66784), ('\u{104b9}', 66785), ('\u{104ba}', 66786),
+        ('\u{104bb}', 66787), ('\u{104bc}', 66788), ('\u{104bd}', 66789), ('\u{104be}', 66790),
+        ('\u{104bf}', 66791), ('\u{104c0}', 66792), ('\u{104c1}', 66793), ('\u{104c2}', 66794),
+        ('\u{104c3}', 66795), ('\u{104c4}', 66796), ('\u{104c5}', 66797), ('\u{104c6}', 66798),
+        ('\u{104c7}', 66799), ('\u{104c8}', 66800), ('\u{104c9}', 66801), ('\u{104ca}', 66802),
+        ('\u{104cb}', 66803), ('\u{104cc}', 66804), ('\u{104cd}', 66805), ('\u{104ce}', 66806),
+        ('\u{104cf}', 66807), ('\u{104d0}', 66808), ('\u{104d1}', 66809), ('\u{104d2}', 66810),
+        ('\u{104d3}', 66811), ('\u{10570}', 66967), ('\u{10571}', 66968), ('\u{10572}', 66969),
+        ('\u{10573}', 66970), ('\u{10574}', 66971), ('\u{10575}', 66972), ('\u{10576}', 66973),
+        ('\u{10577}', 66974), ('\u{10578}', 66975), ('\u{10579}', 66976), ('\u{1057a}', 66977),
+        ('\u{1057c}', 66979), ('\u{1057d}', 66980), ('\u{1057e}', 66981), ('\u{1057f}', 66982),
+        ('\u{10580}', 66983), ('\u{10581}', 66984), ('\u{10582}', 66985), ('\u{10583}', 66986),
+        ('\u{10584}', 66987), ('\u{10585}', 66988), ('\u{10586}', 66989), ('\u{10587}', 66990),
+        ('\u{10588}', 66991), ('\u{10589}', 66992), ('\u{1058a}', 66993), ('\u{1058c}', 66995),
+        ('\u{1058d}', 66996), ('\u{1058e}', 66997), ('\u{1058f}', 66998), ('\u{10590}', 66999),
+        ('\u{10591}', 67000), ('\u{10592}', 67001), ('\u{10594}', 67003), ('\u{10595}', 67004),
+        ('\u{10c80}', 68800), ('\u{10c81}', 68801), ('\u{10c82}', 68802), ('\u{10c83}', 68803),
+        ('\u{10c84}', 68804), ('\u{10c85}', 68805), ('\u{10c86}', 68806), ('\u{10c87}', 68807),
+        ('\u{10c88}', 68808), ('\u{10c89}', 68809), ('\u{10c8a}', 68810), ('\u{10c8b}', 68811),
+        ('\u{10c8c}', 68812), ('\u{10c8d}', 68813), ('\u{10c8e}', 68814), ('\u{10c8f}', 68815),
+        ('\u{10c90}', 68816), ('\u{10c91}', 68817), ('\u{10c92}', 68818), ('\u{10c93}', 68819),
+        ('\u{10c94}', 68820), ('\u{10c95}', 68821), ('\u{10c96}', 68822), ('\u{10c97}', 68823),
+        ('\u{10c98}', 68824), ('\u{10c99}', 68825), ('\u{10c9a}', 68826), ('\u{10c9b}', 68827),
+        ('\u{10c9c}', 68828), ('\u{10c9d}', 68829), ('\u{10c9e}', 68830), ('\u{10c9f}', 68831),
+        ('\u{10ca0}', 68832), ('\u{10ca1}', 68833), ('\u{10ca2}', 68834), ('\u{10ca3}', 68835),
+        ('\u{10ca4}', 68836), ('\u{10ca5}', 68837), ('\u{10ca6}', 68838), ('\u{10ca7}', 68839),
+        ('\u{10ca8}', 68840), ('\u{10ca9}', 68841), ('\u{10caa}', 68842), ('\u{10cab}', 68843),
+        ('\u{10cac}', 68844), ('\u{10cad}', 68845), ('\u{10cae}', 68846), ('\u{10caf}', 68847),
+        ('\u{10cb0}', 68848), ('\u{10cb1}', 68849), ('\u{10cb2}', 68850), ('\u{10d50}', 68976),
+        ('\u{10d51}', 68977), ('\u{10d52}', 68978), ('\u{10d53}', 68979), ('\u{10d54}', 68980),
+        ('\u{10d55}', 68981), ('\u{10d56}', 68982), ('\u{10d57}', 68983), ('\u{10d58}', 68984),
+        ('\u{10d59}', 68985), ('\u{10d5a}', 68986), ('\u{10d5b}', 68987), ('\u{10d5c}', 68988),
+        ('\u{10d5d}', 68989), ('\u{10d5e}', 68990), ('\u{10d5f}', 68991), ('\u{10d60}', 68992),
+        ('\u{10d61}', 68993), ('\u{10d62}', 68994), ('\u{10d63}', 68995), ('\u{10d64}', 68996),
+        ('\u{10d65}', 68997), ('\u{118a0}', 71872), ('\u{118a1}', 71873), ('\u{118a2}', 71874),
+        ('\u{118a3}', 71875), ('\u{118a4}', 71876), ('\u{118a5}', 71877), ('\u{118a6}', 71878),
+        ('\u{118a7}', 71879), ('\u{118a8}', 71880), ('\u{118a9}', 71881), ('\u{118aa}', 71882),
+        ('\u{118ab}', 71883), ('\u{118ac}', 71884), ('\u{118ad}', 71885), ('\u{118ae}', 71886),
+        ('\u{118af}', 71887), ('\u{118b0}', 71888), ('\u{118b1}', 71889), ('\u{118b2}', 71890),
+        ('\u{118b3}', 71891), ('\u{118b4}', 71892), ('\u{118b5}', 71893), ('\u{118b6}', 71894),
+        ('\u{118b7}', 71895), ('\u{118b8}', 71896), ('\u{118b9}', 71897), ('\u{118ba}', 71898),
+        ('\u{118bb}', 71899), ('\u{118bc}', 71900), ('\u{118bd}', 71901), ('\u{118be}', 71902),
+        ('\u{118bf}', 71903), ('\u{16e40}', 93792), ('\u{16e41}', 93793), ('\u{16e42}', 93794),
+        ('\u{16e43}', 93795), ('\u{16e44}', 93796), ('\u{16e45}', 93797), ('\u{16e46}', 93798),
+        ('\u{16e47}', 93799), ('\u{16e48}', 93800), ('\u{16e49}', 93801), ('\u{16e4a}', 93802),
+        ('\u{16e4b}', 93803), ('\u{16e4c}', 93804), ('\u{16e4d}', 93805), ('\u{16e4e}', 93806),
+        ('\u{16e4f}', 93807), ('\u{16e50}', 93808), ('\u{16e51}', 93809), ('\u{16e52}', 93810),
+        ('\u{16e53}', 93811), ('\u{16e54}', 93812), ('\u{16e55}', 93813), ('\u{16e56}', 93814),
+        ('\u{16e57}', 93815), ('\u{16e58}', 93816), ('\u{16e59}', 93817), ('\u{16e5a}', 93818),
+        ('\u{16e5b}', 93819), ('\u{16e5c}', 93820), ('\u{16e5d}', 93821), ('\u{16e5e}', 93822),
+        ('\u{16e5f}', 93823), ('\u{1e900}', 125218), ('\u{1e901}', 125219), ('\u{1e902}', 125220),
+        ('\u{1e903}', 125221), ('\u{1e904}', 125222), ('\u{1e905}', 125223), ('\u{1e906}', 125224),
+        ('\u{1e907}', 125225), ('\u{1e908}', 125226), ('\u{1e909}', 125227), ('\u{1e90a}', 125228),
+        ('\u{1e90b}', 125229), ('\u{1e90c}', 125230), ('\u{1e90d}', 125231), ('\u{1e90e}', 125232),
+        ('\u{1e90f}', 125233), ('\u{1e910}', 125234), ('\u{1e911}', 125235), ('\u{1e912}', 125236),
+        ('\u{1e913}', 125237), ('\u{1e914}', 125238), ('\u{1e915}', 125239), ('\u{1e916}', 125240),
+        ('\u{1e917}', 125241), ('\u{1e918}', 125242), ('\u{1e919}', 125243), ('\u{1e91a}', 125244),
+        ('\u{1e91b}', 125245), ('\u{1e91c}', 125246), ('\u{1e91d}', 125247), ('\u{1e91e}', 125248),
+        ('\u{1e91f}', 125249), ('\u{1e920}', 125250), ('\u{1e921}', 125251),
+    ];
+
+    static FOLDCASE_TABLE_MULTI: &[[char; 3]] = &[
+        ['s', 's', '\u{0}'], ['i', '\u{307}', '\u{0}'], ['\u{2bc}', 'n', '\u{0}'],
+        ['j', '\u{30c}', '\u{0}'], ['\u{3b9}', '\u{308}', '\u{301}'],
+        ['\u{3c5}', '\u{308}', '\u{301}'], ['\u{565}', '\u{582}', '\u{0}'],
+        ['h', '\u{331}', '\u{0}'], ['t', '\u{308}', '\u{0}'], ['w', '\u{30a}', '\u{0}'],
+        ['y', '\u{30a}', '\u{0}'], ['a', '\u{2be}', '\u{0}'], ['s', 's', '\u{0}'],
+        ['\u{3c5}', '\u{313}', '\u{0}'], ['\u{3c5}', '\u{313}', '\u{300}'],
+        ['\u{3c5}', '\u{313}', '\u{301}'], ['\u{3c5}', '\u{313}', '\u{342}'],
+        ['\u{1f00}', '\u{3b9}', '\u{0}'], ['\u{1f01}', '\u{3b9}', '\u{0}'],
+        ['\u{1f02}', '\u{3b9}', '\u{0}'], ['\u{1f03}', '\u{3b9}', '\u{0}'],
+        ['\u{1f04}', '\u{3b9}', '\u{0}'], ['\u{1f05}', '\u{3b9}', '\u{0}'],
+        ['\u{1f06}', '\u{3b9}', '\u{0}'], ['\u{1f07}', '\u{3b9}', '\u{0}'],
+        ['\u{1f00}', '\u{3b9}', '\u{0}'], ['\u{1f01}', '\u{3b9}', '\u{0}'],
+        ['\u{1f02}', '\u{3b9}', '\u{0}'], ['\u{1f03}', '\u{3b9}', '\u{0}'],
+        ['\u{1f04}', '\u{3b9}', '\u{0}'], ['\u{1f05}', '\u{3b9}', '\u{0}'],
+        ['\u{1f06}', '\u{3b9}', '\u{0}'], ['\u{1f07}', '\u{3b9}', '\u{0}'],
+        ['\u{1f20}', '\u{3b9}', '\u{0}'], ['\u{1f21}', '\u{3b9}', '\u{0}'],
+        ['\u{1f22}', '\u{3b9}', '\u{0}'], ['\u{1f23}', '\u{3b9}', '\u{0}'],
+        ['\u{1f24}', '\u{3b9}', '\u{0}'], ['\u{1f25}', '\u{3b9}', '\u{0}'],
+        ['\u{1f26}', '\u{3b9}', '\u{0}'], ['\u{1f27}', '\u{3b9}', '\u{0}'],
+        ['\u{1f20}', '\u{3b9}', '\u{0}'], ['\u{1f21}', '\u{3b9}', '\u{0}'],
+        ['\u{1f22}', '\u{3b9}', '\u{0}'], ['\u{1f23}', '\u{3b9}', '\u{0}'],
+        ['\u{1f24}', '\u{3b9}', '\u{0}'], ['\u{1f25}', '\u{3b9}', '\u{0}'],
+        ['\u{1f26}', '\u{3b9}', '\u{0}'], ['\u{1f27}', '\u{3b9}', '\u{0}'],
+        ['\u{1f60}', '\u{3b9}', '\u{0}'], ['\u{1f61}', '\u{3b9}', '\u{0}'],
+        ['\u{1f62}', '\u{3b9}', '\u{0}'], ['\u{1f63}', '\u{3b9}', '\u{0}'],
+        ['\u{1f64}', '\u{3b9}', '\u{0}'], ['\u{1f65}', '\u{3b9}', '\u{0}'],
+        ['\u{1f66}', '\u{3b9}', '\u{0}'], ['\u{1f67}', '\u{3b9}', '\u{0}'],
+        ['\u{1f60}', '\u{3b9}', '\u{0}'], ['\u{1f61}', '\u{3b9}', '\u{0}'],
+        ['\u{1f62}', '\u{3b9}', '\u{0}'], ['\u{1f63}', '\u{3b9}', '\u{0}'],
+        ['\u{1f64}', '\u{3b9}', '\u{0}'], ['\u{1f65}', '\u{3b9}', '\u{0}'],
+        ['\u{1f66}', '\u{3b9}', '\u{0}'], ['\u{1f67}', '\u{3b9}', '\u{0}'],
+        ['\u{1f70}', '\u{3b9}', '\u{0}'], ['\u{3b1}', '\u{3b9}', '\u{0}'],
+        ['\u{3ac}', '\u{3b9}', '\u{0}'], ['\u{3b1}', '\u{342}', '\u{0}'],
+        ['\u{3b1}', '\u{342}', '\u{3b9}'], ['\u{3b1}', '\u{3b9}', '\u{0}'],
+        ['\u{1f74}', '\u{3b9}', '\u{0}'], ['\u{3b7}', '\u{3b9}', '\u{0}'],
+        ['\u{3ae}', '\u{3b9}', '\u{0}'], ['\u{3b7}', '\u{342}', '\u{0}'],
+        ['\u{3b7}', '\u{342}', '\u{3b9}'], ['\u{3b7}', '\u{3b9}', '\u{0}'],
+        ['\u{3b9}', '\u{308}', '\u{300}'], ['\u{3b9}', '\u{308}', '\u{301}'],
+        ['\u{3b9}', '\u{342}', '\u{0}'], ['\u{3b9}', '\u{308}', '\u{342}'],
+        ['\u{3c5}', '\u{308}', '\u{300}'], ['\u{3c5}', '\u{308}', '\u{301}'],
+        ['\u{3c1}', '\u{313}', '\u{0}'], ['\u{3c5}', '\u{342}', '\u{0}'],
+        ['\u{3c5}', '\u{308}', '\u{342}'], ['\u{1f7c}', '\u{3b9}', '\u{0}'],
+        ['\u{3c9}', '\u{3b9}', '\u{0}'], ['\u{3ce}', '\u{3b9}', '\u{0}'],
+        ['\u{3c9}', '\u{342}', '\u{0}'], ['\u{3c9}', '\u{342}', '\u{3b9}'],
+        ['\u{3c9}', '\u{3b9}', '\u{0}'], ['f', 'f', '\u{0}'], ['f', 'i', '\u{0}'],
+        ['f', 'l', '\u{0}'], ['f', 'f', 'i'], ['f', 'f', 'l'], ['s', 't', '\u{0}'],
+        ['s', 't', '\u{0}'], ['\u{574}', '\u{576}', '\u{0}'], ['\u{574}', '\u{565}', '\u{0}'],
+        ['\u{574}', '\u{56b}', '\u{0}'], ['\u{57e}', '\u{576}', '\u{0}'],
+        ['\u{574}', '\u{56d}', '\u{0}'],
+    ];
+}
+
+#[rustfmt::skip]
+pub mod normalization {
+    pub fn combining_class(c: char) -> u8 {
+        let i = COMBINING_CLASS_TABLE.partition_point(|&(_, end, _)| end < c);
+        match COMBINING_CLASS_TABLE.get(i) {
+            Some(&(start, _, class)) if start <= c => class,
+            _ => 0,
+        }
+    }
+
+    pub fn decompose_canonical(c: char) -> Option<&'static [char]> {
+        decompose(c, CANONICAL_DECOMPOSED_TABLE, CANONICAL_DECOMPOSED_CHARS)
+    }
+
+    pub fn decompose_compatible(c: char) -> Option<&'static [char]> {
+        decompose(c, COMPATIBILITY_DECOMPOSED_TABLE, COMPATIBILITY_DECOMPOSED_CHARS)
+            .or_else(|| decompose_canonical(c))
+    }
+
+    fn decompose(
+        c: char,
+        table: &[(char, u16, u8)],
+        chars: &'static [char],
+    ) -> Option<&'static [char]> {
+        // Nothing below U+00A0 decomposes.
+        if (c as u32) < 0xa0 {
+            return None;
+        }
+        let i = table.binary_search_by(|&(key, _, _)| key.cmp(&c)).ok()?;
+        let (_, start, len) = table[i];
+        chars.get(start as usize..start as usize + len as usize)
+    }
+
+    pub fn compose(a: char, b: char) -> Option<char> {
+        COMPOSITION_TABLE
+            .binary_search_by(|&(x, y, _)| (x, y).cmp(&(a, b)))
+            .ok()
+            .map(|i| COMPOSITION_TABLE[i].2)
+    }
+
+    static COMBINING_CLASS_TABLE: &[(char, char, u8)] = &[
+        ('\u{300}', '\u{314}', 230), ('\u{315}', '\u{315}', 232), ('\u{316}', '\u{319}', 220),
+        ('\u{31a}', '\u{31a}', 232), ('\u{31b}', '\u{31b}', 216), ('\u{31c}', '\u{320}', 220),
+        ('\u{321}', '\u{322}', 202), ('\u{323}', '\u{326}', 220), ('\u{327}', '\u{328}', 202),
+        ('\u{329}', '\u{333}', 220), ('\u{334}', '\u{338}', 1), ('\u{339}', '\u{33c}', 220),
+        ('\u{33d}', '\u{344}', 230), ('\u{345}', '\u{345}', 240), ('\u{346}', '\u{346}', 230),
+        ('\u{347}', '\u{349}', 220), ('\u{34a}', '\u{34c}', 230), ('\u{34d}', '\u{34e}', 220),
+        ('\u{350}', '\u{352}', 230), ('\u{353}', '\u{356}', 220), ('\u{357}', '\u{357}', 230),
+        ('\u{358}', '\u{358}', 232), ('\u{359}', '\u{35a}', 220), ('\u{35b}', '\u{35b}', 230),
+        ('\u{35c}', '\u{35c}', 233), ('\u{35d}', '\u{35e}', 234), ('\u{35f}', '\u{35f}', 233),
+        ('\u{360}', '\u{361}', 234), ('\u{362}', '\u{362}', 233), ('\u{363}', '\u{36f}', 230),
+        ('\u{483}', '\u{487}', 230), ('\u{591}', '\u{591}', 220), ('\u{592}', '\u{595}', 230),
+        ('\u{596}', '\u{596}', 220), ('\u{597}', '\u{599}', 230), ('\u{59a}', '\u{59a}', 222),
+        ('\u{59b}', '\u{59b}', 220), ('\u{59c}', '\u{5a1}', 230), ('\u{5a2}', '\u{5a7}', 220),
+        ('\u{5a8}', '\u{5a9}', 230), ('\u{5aa}', '\u{5aa}', 220), ('\u{5ab}', '\u{5ac}', 230),
+        ('\u{5ad}', '\u{5ad}', 222), ('\u{5ae}', '\u{5ae}', 228), ('\u{5af}', '\u{5af}', 230),
+        ('\u{5b0}', '\u{5b0}', 10), ('\u{5b1}', '\u{5b1}', 11), ('\u{5b2}', '\u{5b2}', 12),
+        ('\u{5b3}', '\u{5b3}', 13), ('\u{5b4}', '\u{5b4}', 14), ('\u{5b5}', '\u{5b5}', 15),
+        ('\u{5b6}', '\u{5b6}', 16), ('\u{5b7}', '\u{5b7}', 17), ('\u{5b8}', '\u{5b8}', 18),
+        ('\u{5b9}', '\u{5ba}', 19), ('\u{5bb}', '\u{5bb}', 20), ('\u{5bc}', '\u{5bc}', 21),
+        ('\u{5bd}', '\u{5bd}', 22), ('\u{5bf}', '\u{5bf}', 23), ('\u{5c1}', '\u{5c1}', 24),
+        ('\u{5c2}', '\u{5c2}', 25), ('\u{5c4}', '\u{5c4}', 230), ('\u{5c5}', '\u{5c5}', 220),
+        ('\u{5c7}', '\u{5c7}', 18), ('\u{610}', '\u{617}', 230), ('\u{618}', '\u{618}', 30),
+        ('\u{619}', '\u{619}', 31), ('\u{61a}', '\u{61a}', 32), ('\u{64b}', '\u{64b}', 27),
+        ('\u{64c}', '\u{64c}', 28), ('\u{64d}', '\u{64d}', 29), ('\u{64e}', '\u{64e}', 30),
+        ('\u{64f}', '\u{64f}', 31), ('\u{650}', '\u{650}', 32), ('\u{651}', '\u{651}', 33),
+        ('\u{652}', '\u{652}', 34), ('\u{653}', '\u{654}', 230), ('\u{655}', '\u{656}', 220),
+        ('\u{657}', '\u{65b}', 230), ('\u{65c}', '\u{65c}', 220), ('\u{65d}', '\u{65e}', 230),
+        ('\u{65f}', '\u{65f}', 220), ('\u{670}', '\u{670}', 35), ('\u{6d6}', '\u{6dc}', 230),
+        ('\u{6df}', '\u{6e2}', 230), ('\u{6e3}', '\u{6e3}', 220), ('\u{6e4}', '\u{6e4}', 230),
+        ('\u{6e7}', '\u{6e8}', 230), ('\u{6ea}', '\u{6ea}', 220), ('\u{6eb}', '\u{6ec}', 230),
+        ('\u{6ed}', '\u{6ed}', 220), ('\u{711}', '\u{711}', 36), ('\u{730}', '\u{730}', 230),
+        ('\u{731}', '\u{731}', 220), ('\u{732}', '\u{733}', 230), ('\u{734}', '\u{734}', 220),
+        ('\u{735}', '\u{736}', 230), ('\u{737}', '\u{739}', 220), ('\u{73a}', '\u{73a}', 230),
+        ('\u{73b}', '\u{73c}', 220), ('\u{73d}', '\u{73d}', 230), ('\u{73e}', '\u{73e}', 220),
+        ('\u{73f}', '\u{741}', 230), ('\u{742}', '\u{742}', 220), ('\u{743}', '\u{743}', 230),
+        ('\u{744}', '\u{744}', 220), ('\u{745}', '\u{745}', 230), ('\u{746}', '\u{746}', 220),
+        ('\u{747}', '\u{747}', 230), ('\u{748}', '\u{748}', 220), ('\u{749}', '\u{74a}', 230),
+        ('\u{7eb}', '\u{7f1}', 230), ('\u{7f2}', '\u{7f2}', 220), ('\u{7f3}', '\u{7f3}', 230),
+        ('\u{7fd}', '\u{7fd}', 220), ('\u{816}', '\u{819}', 230), ('\u{81b}', '\u{823}', 230),
+        ('\u{825}', '\u{827}', 230), ('\u{829}', '\u{82d}', 230), ('\u{859}', '\u{85b}', 220),
+        ('\u{897}', '\u{898}', 230), ('\u{899}', '\u{89b}', 220), ('\u{89c}', '\u{89f}', 230),
+        ('\u{8ca}', '\u{8ce}', 230), ('\u{8cf}', '\u{8d3}', 220), ('\u{8d4}', '\u{8e1}', 230),
+        ('\u{8e3}', '\u{8e3}', 220), ('\u{8e4}', '\u{8e5}', 230), ('\u{8e6}', '\u{8e6}', 220),
+        ('\u{8e7}', '\u{8e8}', 230), ('\u{8e9}', '\u{8e9}', 220), ('\u{8ea}', '\u{8ec}', 230),
+        ('\u{8ed}', '\u{8ef}', 220), ('\u{8f0}', '\u{8f0}', 27), ('\u{8f1}', '\u{8f1}', 28),
+        ('\u{8f2}', '\u{8f2}', 29), ('\u{8f3}', '\u{8f5}', 230), ('\u{8f6}', '\u{8f6}', 220),
+        ('\u{8f7}', '\u{8f8}', 230), ('\u{8f9}', '\u{8fa}', 220), ('\u{8fb}', '\u{8ff}', 230),
+        ('\u{93c}', '\u{93c}', 7), ('\u{94d}', '\u{94d}', 9), ('\u{951}', '\u{951}', 230),
+        ('\u{952}', '\u{952}', 220), ('\u{953}', '\u{954}', 230), ('\u{9bc}', '\u{9bc}', 7),
+        ('\u{9cd}', '\u{9cd}', 9), ('\u{9fe}', '\u{9fe}', 230), ('\u{a3c}', '\u{a3c}', 7),
+        ('\u{a4d}', '\u{a4d}', 9), ('\u{abc}', '\u{abc}', 7), ('\u{acd}', '\u{acd}', 9),
+        ('\u{b3c}', '\u{b3c}', 7), ('\u{b4d}', '\u{b4d}', 9), ('\u{bcd}', '\u{bcd}', 9),
+        ('\u{c3c}', '\u{c3c}', 7), ('\u{c4d}', '\u{c4d}', 9), ('\u{c55}', '\u{c55}', 84),
+        ('\u{c56}', '\u{c56}', 91), ('\u{cbc}', '\u{cbc}', 7), ('\u{ccd}', '\u{ccd}', 9),
+        ('\u{d3b}', '\u{d3c}', 9), ('\u{d4d}', '\u{d4d}', 9), ('\u{dca}', '\u{dca}', 9),
+        ('\u{e38}', '\u{e39}', 103), ('\u{e3a}', '\u{e3a}', 9), ('\u{e48}', '\u{e4b}', 107),
+        ('\u{eb8}', '\u{eb9}', 118), ('\u{eba}', '\u{eba}', 9), ('\u{ec8}', '\u{ecb}', 122),
+        ('\u{f18}', '\u{f19}', 220), ('\u{f35}', '\u{f35}', 220), ('\u{f37}', '\u{f37}', 220),
+        ('\u{f39}', '\u{f39}', 216), ('\u{f71}', '\u{f71}', 129), ('\u{f72}', '\u{f72}', 130),
+        ('\u{f74}', '\u{f74}', 132), ('\u{f7a}', '\u{f7d}', 130), ('\u{f80}', '\u{f80}', 130),
+        ('\u{f82}', '\u{f83}', 230), ('\u{f84}', '\u{f84}', 9), ('\u{f86}', '\u{f87}', 230),
+        ('\u{fc6}', '\u{fc6}', 220), ('\u{1037}', '\u{1037}', 7), ('\u{1039}', '\u{103a}', 9),
+        ('\u{108d}', '\u{108d}', 220), ('\u{135d}', '\u{135f}', 230), ('\u{1714}', '\u{1715}', 9),
+        ('\u{1734}', '\u{1734}', 9), ('\u{17d2}', '\u{17d2}', 9), ('\u{17dd}', '\u{17dd}', 230),
+        ('\u{18a9}', '\u{18a9}', 228), ('\u{1939}', '\u{1939}', 222), ('\u{193a}', '\u{193a}', 230),
+        ('\u{193b}', '\u{193b}', 220), ('\u{1a17}', '\u{1a17}', 230), ('\u{1a18}', '\u{1a18}', 220),
+        ('\u{1a60}', '\u{1a60}', 9), ('\u{1a75}', '\u{1a7c}', 230), ('\u{1a7f}', '\u{1a7f}', 220),
+        ('\u{1ab0}', '\u{1ab4}', 230), ('\u{1ab5}', '\u{1aba}', 220), ('\u{1abb}', '\u{1abc}', 230),
+        ('\u{1abd}', '\u{1abd}', 220), ('\u{1abf}', '\u{1ac0}', 220), ('\u{1ac1}', '\u{1ac2}', 230),
+        ('\u{1ac3}', '\u{1ac4}', 220), ('\u{1ac5}', '\u{1ac9}', 230), ('\u{1aca}', '\u{1aca}', 220),
+        ('\u{1acb}', '\u{1ace}', 230), ('\u{1b34}', '\u{1b34}', 7), ('\u{1b44}', '\u{1b44}', 9),
+        ('\u{1b6b}', '\u{1b6b}', 230), ('\u{1b6c}', '\u{1b6c}', 220), ('\u{1b6d}', '\u{1b73}', 230),
+        ('\u{1baa}', '\u{1bab}', 9), ('\u{1be6}', '\u{1be6}', 7), ('\u{1bf2}', '\u{1bf3}', 9),
+        ('\u{1c37}', '\u{1c37}', 7), ('\u{1cd0}', '\u{1cd2}', 230), ('\u{1cd4}', '\u{1cd4}', 1),
+        ('\u{1cd5}', '\u{1cd9}', 220), ('\u{1cda}', '\u{1cdb}', 230), ('\u{1cdc}', '\u{1cdf}', 220),
+        ('\u{1ce0}', '\u{1ce0}', 230), ('\u{1ce2}', '\u{1ce8}', 1), ('\u{1ced}', '\u{1ced}', 220),
+        ('\u{1cf4}', '\u{1cf4}', 230), ('\u{1cf8}', '\u{1cf9}', 230), ('\u{1dc0}', '\u{1dc1}', 230),
+        ('\u{1dc2}', '\u{1dc2}', 220), ('\u{1dc3}', '\u{1dc9}', 230), ('\u{1dca}', '\u{1dca}', 220),
+        ('\u{1dcb}', '\u{1dcc}', 230), ('\u{1dcd}', '\u{1dcd}', 234), ('\u{1dce}', '\u{1dce}', 214),
+        ('\u{1dcf}', '\u{1dcf}', 220), ('\u{1dd0}', '\u{1dd0}', 202), ('\u{1dd1}', '\u{1df5}', 230),
+        ('\u{1df6}', '\u{1df6}', 232), ('\u{1df7}', '\u{1df8}', 228), ('\u{1df9}', '\u{1df9}', 220),
+        ('\u{1dfa}', '\u{1dfa}', 218), ('\u{1dfb}', '\u{1dfb}', 230), ('\u{1dfc}', '\u{1dfc}', 233),
+        ('\u{1dfd}', '\u{1dfd}', 220), ('\u{1dfe}', '\u{1dfe}', 230), ('\u{1dff}', '\u{1dff}', 220),
+        ('\u{20d0}', '\u{20d1}', 230), ('\u{20d2}', '\u{20d3}', 1), ('\u{20d4}', '\u{20d7}', 230),
+        ('\u{20d8}', '\u{20da}', 1), ('\u{20db}', '\u{20dc}', 230), ('\u{20e1}', '\u{20e1}', 230),
+        ('\u{20e5}', '\u{20e6}', 1), ('\u{20e7}', '\u{20e7}', 230), ('\u{20e8}', '\u{20e8}', 220),
+        ('\u{20e9}', '\u{20e9}', 230), ('\u{20ea}', '\u{20eb}', 1), ('\u{20ec}', '\u{20ef}', 220),
+        ('\u{20f0}', '\u{20f0}', 230), ('\u{2cef}', '\u{2cf1}', 230), ('\u{2d7f}', '\u{2d7f}', 9),
+        ('\u{2de0}', '\u{2dff}', 230), ('\u{302a}', '\u{302a}', 218), ('\u{302b}', '\u{302b}', 228),
+        ('\u{302c}', '\u{302c}', 232), ('\u{302d}', '\u{302d}', 222), ('\u{302e}', '\u{302f}', 224),
+        ('\u{3099}', '\u{309a}', 8), ('\u{a66f}', '\u{a66f}', 230), ('\u{a674}', '\u{a67d}', 230),
+        ('\u{a69e}', '\u{a69f}', 230), ('\u{a6f0}', '\u{a6f1}', 230), ('\u{a806}', '\u{a806}', 9),
+        ('\u{a82c}', '\u{a82c}', 9), ('\u{a8c4}', '\u{a8c4}', 9), ('\u{a8e0}', '\u{a8f1}', 230),
+        ('\u{a92b}', '\u{a92d}', 220), ('\u{a953}', '\u{a953}', 9), ('\u{a9b3}', '\u{a9b3}', 7),
+        ('\u{a9c0}', '\u{a9c0}', 9), ('\u{aab0}', '\u{aab0}', 230), ('\u{aab2}', '\u{aab3}', 230),
+        ('\u{aab4}', '\u{aab4}', 220), ('\u{aab7}', '\u{aab8}', 230), ('\u{aabe}', '\u{aabf}', 230),
+        ('\u{aac1}', '\u{aac1}', 230), ('\u{aaf6}', '\u{aaf6}', 9), ('\u{abed}', '\u{abed}', 9),
+        ('\u{fb1e}', '\u{fb1e}', 26), ('\u{fe20}', '\u{fe26}', 230), ('\u{fe27}', '\u{fe2d}', 220),
+        ('\u{fe2e}', '\u{fe2f}', 230), ('\u{101fd}', '\u{101fd}', 220),
+        ('\u{102e0}', '\u{102e0}', 220), ('\u{10376}', '\u{1037a}', 230),
+        ('\u{10a0d}', '\u{10a0d}', 220), ('\u{10a0f}', '\u{10a0f}', 230),
+        ('\u{10a38}', '\u{10a38}', 230), ('\u{10a39}', '\u{10a39}', 1),
+        ('\u{10a3a}', '\u{10a3a}', 220), ('\u{10a3f}', '\u{10a3f}', 9),
+        ('\u{10ae5}', '\u{10ae5}', 230), ('\u{10ae6}', '\u{10ae6}', 220),
+        ('\u{10d24}', '\u{10d27}', 230), ('\u{10d69}', '\u{10d6d}', 230),
+        ('\u{10eab}', '\u{10eac}', 230), ('\u{10efd}', '\u{10eff}', 220),
+        ('\u{10f46}', '\u{10f47}', 220), ('\u{10f48}', '\u{10f4a}', 230),
+        ('\u{10f4b}', '\u{10f4b}', 220), ('\u{10f4c}', '\u{10f4c}', 230),
+        ('\u{10f4d}', '\u{10f50}', 220), ('\u{10f82}', '\u{10f82}', 230),
+        ('\u{10f83}', '\u{10f83}', 220), ('\u{10f84}', '\u{10f84}', 230),
+        ('\u{10f85}', '\u{10f85}', 220), ('\u{11046}', '\u{11046}', 9),
+        ('\u{11070}', '\u{11070}', 9), ('\u{1107f}', '\u{1107f}', 9), ('\u{110b9}', '\u{110b9}', 9),
+        ('\u{110ba}', '\u{110ba}', 7), ('\u{11100}', '\u{11102}', 230),
+        ('\u{11133}', '\u{11134}', 9), ('\u{11173}', '\u{11173}', 7), ('\u{111c0}', '\u{111c0}', 9),
+        ('\u{111ca}', '\u{111ca}', 7), ('\u{11235}', '\u{11235}', 9), ('\u{11236}', '\u{11236}', 7),
+        ('\u{112e9}', '\u{112e9}', 7), ('\u{112ea}', '\u{112ea}', 9), ('\u{1133b}', '\u{1133c}', 7),
+        ('\u{1134d}', '\u{1134d}', 9), ('\u{11366}', '\u{1136c}', 230),
+        ('\u{11370}', '\u{11374}', 230), ('\u{113ce}', '\u{113d0}', 9),
+        ('\u{11442}', '\u{11442}', 9), ('\u{11446}', '\u{11446}', 7),
+        ('\u{1145e}', '\u{1145e}', 230), ('\u{114c2}', '\u{114c2}', 9),
+        ('\u{114c3}', '\u{114c3}', 7), ('\u{115bf}', '\u{115bf}', 9), ('\u{115c0}', '\u{115c0}', 7),
+        ('\u{1163f}', '\u{1163f}', 9), ('\u{116b6}', '\u{116b6}', 9), ('\u{116b7}', '\u{116b7}', 7),
+        ('\u{1172b}', '\u{1172b}', 9), ('\u{11839}', '\u{11839}', 9), ('\u{1183a}', '\u{1183a}', 7),
+        ('\u{1193d}', '\u{1193e}', 9), ('\u{11943}', '\u{11943}', 7), ('\u{119e0}', '\u{119e0}', 9),
+        ('\u{11a34}', '\u{11a34}', 9), ('\u{11a47}', '\u{11a47}', 9), ('\u{11a99}', '\u{11a99}', 9),
+        ('\u{11c3f}', '\u{11c3f}', 9), ('\u{11d42}', '\u{11d42}', 7), ('\u{11d44}', '\u{11d45}', 9),
+        ('\u{11d97}', '\u{11d97}', 9), ('\u{11f41}', '\u{11f42}', 9), ('\u{1612f}', '\u{1612f}', 9),
+        ('\u{16af0}', '\u{16af4}', 1), ('\u{16b30}', '\u{16b36}', 230),
+        ('\u{16ff0}', '\u{16ff1}', 6), ('\u{1bc9e}', '\u{1bc9e}', 1),
+        ('\u{1d165}', '\u{1d166}', 216), ('\u{1d167}', '\u{1d169}', 1),
+        ('\u{1d16d}', '\u{1d16d}', 226), ('\u{1d16e}', '\u{1d172}', 216),
+        ('\u{1d17b}', '\u{1d182}', 220), ('\u{1d185}', '\u{1d189}', 230),
+        ('\u{1d18a}', '\u{1d18b}', 220), ('\u{1d1aa}', '\u{1d1ad}', 230),
+        ('\u{1d242}', '\u{1d244}', 230), ('\u{1e000}', '\u{1e006}', 230),
+        ('\u{1e008}', '\u{1e018}', 230), ('\u{1e01b}', '\u{1e021}', 230),
+        ('\u{1e023}', '\u{1e024}', 230), ('\u{1e026}', '\u{1e02a}', 230),
+        ('\u{1e08f}', '\u{1e08f}', 230), ('\u{1e130}', '\u{1e136}', 230),
+        ('\u{1e2ae}', '\u{1e2ae}', 230), ('\u{1e2ec}', '\u{1e2ef}', 230),
+        ('\u{1e4ec}', '\u{1e4ed}', 232), ('\u{1e4ee}', '\u{1e4ee}', 220),
+        ('\u{1e4ef}', '\u{1e4ef}', 230), ('\u{1e5ee}', '\u{1e5ee}', 230),
+        ('\u{1e5ef}', '\u{1e5ef}', 220), ('\u{1e8d0}', '\u{1e8d6}', 220),
+        ('\u{1e944}', '\u{1e949}', 230), ('\u{1e94a}', '\u{1e94a}', 7),
+    ];
+
+    static CANONICAL_DECOMPOSED_TABLE: &[(char, u16, u8)] = &[
+        ('\u{c0}', 0, 2), ('\u{c1}', 2, 2), ('\u{c2}', 4, 2), ('\u{c3}', 6, 2), ('\u{c4}', 8, 2),
+        ('\u{c5}', 10, 2), ('\u{c7}', 12, 2), ('\u{c8}', 14, 2), ('\u{c9}', 16, 2),
+        ('\u{ca}', 18, 2), ('\u{cb}', 20, 2), ('\u{cc}', 22, 2), ('\u{cd}', 24, 2),
+        ('\u{ce}', 26, 2), ('\u{cf}', 28, 2), ('\u{d1}', 30, 2), ('\u{d2}', 32, 2),
+        ('\u{d3}', 34, 2), ('\u{d4}', 36, 2), ('\u{d5}', 38, 2), ('\u{d6}', 40, 2),
+        ('\u{d9}', 42, 2), ('\u{da}', 44, 2), ('\u{db}', 46, 2), ('\u{dc}', 48, 2),
+        ('\u{dd}', 50, 2), ('\u{e0}', 52, 2), ('\u{e1}', 54, 2), ('\u{e2}', 56, 2),
+        ('\u{e3}', 58, 2), ('\u{e4}', 60, 2), ('\u{e5}', 62, 2), ('\u{e7}', 64, 2),
+        ('\u{e8}', 66, 2), ('\u{e9}', 68, 2), ('\u{ea}', 70, 2), ('\u{eb}', 72, 2),
+        ('\u{ec}', 74, 2), ('\u{ed}', 76, 2), ('\u{ee}', 78, 2), ('\u{ef}', 80, 2),
+        ('\u{f1}', 82, 2), ('\u{f2}', 84, 2), ('\u{f3}', 86, 2), ('\u{f4}', 88, 2),
+        ('\u{f5}', 90, 2), ('\u{f6}', 92, 2), ('\u{f9}', 94, 2), ('\u{fa}', 96, 2),
+        ('\u{fb}', 98, 2), ('\u{fc}', 100, 2), ('\u{fd}', 102, 2), ('\u{ff}', 104, 2),
+        ('\u{100}', 106, 2), ('\u{101}', 108, 2), ('\u{102}', 110, 2), ('\u{103}', 112, 2),
+        ('\u{104}', 114, 2), ('\u{105}', 116, 2), ('\u{106}', 118, 2), ('\u{107}', 120, 2),
+        ('\u{108}', 122, 2), ('\u{109}', 124, 2), ('\u{10a}', 126, 2), ('\u{10b}', 128, 2),
+        ('\u{10c}', 130, 2), ('\u{10d}', 132, 2), ('\u{10e}', 134, 2), ('\u{10f}', 136, 2),
+        ('\u{112}', 138, 2), ('\u{113}', 140, 2), ('\u{114}', 142, 2), ('\u{115}', 144, 2),
+        ('\u{116}', 146, 2), ('\u{117}', 148, 2), ('\u{118}', 150, 2), ('\u{119}', 152, 2),
+        ('\u{11a}', 154, 2), ('\u{11b}', 156, 2), ('\u{11c}', 158, 2), ('\u{11d}', 160, 2),
+        ('\u{11e}', 162, 2), ('\u{11f}', 164, 2), ('\u{120}', 166, 2), ('\u{121}', 168, 2),
+        ('\u{122}', 170, 2), ('\u{123}', 172, 2), ('\u{124}', 174, 2), ('\u{125}', 176, 2),
+        ('\u{128}', 178, 2), ('\u{129}', 180, 2), ('\u{12a}', 182, 2), ('\u{12b}', 184, 2),
+        ('\u{12c}', 186, 2), ('\u{12d}', 188, 2), ('\u{12e}', 190, 2), ('\u{12f}', 192, 2),
+        ('\u{130}', 194, 2), ('\u{134}', 196, 2), ('\u{135}', 198, 2), ('\u{136}', 200, 2),
+        ('\u{137}', 202, 2), ('\u{139}', 204, 2), ('\u{13a}', 206, 2), ('\u{13b}', 208, 2),
+        ('\u{13c}', 210, 2), ('\u{13d}', 212, 2), ('\u{13e}', 214, 2), ('\u{143}', 216, 2),
+        ('\u{144}', 218, 2), ('\u{145}', 220, 2), ('\u{146}', 222, 2), ('\u{147}', 224, 2),
+        ('\u{148}', 226, 2), ('\u{14c}', 228, 2), ('\u{14d}', 230, 2), ('\u{14e}', 232, 2),
+        ('\u{14f}', 234, 2), ('\u{150}', 236, 2), ('\u{151}', 238, 2), ('\u{154}', 240, 2),
+        ('\u{155}', 242, 2), ('\u{156}', 244, 2), ('\u{157}', 246, 2), ('\u{158}', 248, 2),
+        ('\u{159}', 250, 2), ('\u{15a}', 252, 2), ('\u{15b}', 254, 2), ('\u{15c}', 256, 2),
+        ('\u{15d}', 258, 2), ('\u{15e}', 260, 2), ('\u{15f}', 262, 2), ('\u{160}', 264, 2),
+        ('\u{161}', 266, 2), ('\u{162}', 268, 2), ('\u{163}', 270, 2), ('\u{164}', 272, 2),
+        ('\u{165}', 274, 2), ('\u{168}', 276, 2), ('\u{169}', 278, 2), ('\u{16a}', 280, 2),
+        ('\u{16b}', 282, 2), ('\u{16c}', 284, 2), ('\u{16d}', 286, 2), ('\u{16e}', 288, 2),
+        ('\u{16f}', 290, 2), ('\u{170}', 292, 2), ('\u{171}', 294, 2), ('\u{172}', 296, 2),
+        ('\u{173}', 298, 2), ('\u{174}', 300, 2), ('\u{175}', 302, 2), ('\u{176}', 304, 2),
+        ('\u{177}', 306, 2), ('\u{178}', 308, 2), ('\u{179}', 310, 2), ('\u{17a}', 312, 2),
+        ('\u{17b}', 314, 2), ('\u{17c}', 316, 2), ('\u{17d}', 318, 2), ('\u{17e}', 320, 2),
+        ('\u{1a0}', 322, 2), ('\u{1a1}', 324, 2), ('\u{1af}', 326, 2), ('\u{1b0}', 328, 2),
+        ('\u{1cd}', 330, 2), ('\u{1ce}', 332, 2), ('\u{1cf}', 334, 2), ('\u{1d0}', 336, 2),
+        ('\u{1d1}', 338, 2), ('\u{1d2}', 340, 2), ('\u{1d3}', 342, 2), ('\u{1d4}', 344, 2),
+        ('\u{1d5}', 346, 3), ('\u{1d6}', 349, 3), ('\u{1d7}', 352, 3), ('\u{1d8}', 355, 3),
+        ('\u{1d9}', 358, 3), ('\u{1da}', 361, 3), ('\u{1db}', 364, 3), ('\u{1dc}', 367, 3),
+        ('\u{1de}', 370, 3), ('\u{1df}', 373, 3), ('\u{1e0}', 376, 3), ('\u{1e1}', 379, 3),
+        ('\u{1e2}', 382, 2), ('\u{1e3}', 384, 2), ('\u{1e6}', 386, 2), ('\u{1e7}', 388, 2),
+        ('\u{1e8}', 390, 2), ('\u{1e9}', 392, 2), ('\u{1ea}', 394, 2), ('\u{1eb}', 396, 2),
+        ('\u{1ec}', 398, 3), ('\u{1ed}', 401, 3), ('\u{1ee}', 404, 2), ('\u{1ef}', 406, 2),
+        ('\u{1f0}', 408, 2), ('\u{1f4}', 410, 2), ('\u{1f5}', 412, 2), ('\u{1f8}', 414, 2),
+        ('\u{1f9}', 416, 2), ('\u{1fa}', 418, 3), ('\u{1fb}', 421, 3), ('\u{1fc}', 424, 2),
+        ('\u{1fd}', 426, 2), ('\u{1fe}', 428, 2), ('\u{1ff}', 430, 2), ('\u{200}', 432, 2),
+        ('\u{201}', 434, 2), ('\u{202}', 436, 2), ('\u{203}', 438, 2), ('\u{204}', 440, 2),
+        ('\u{205}', 442, 2), ('\u{206}', 444, 2), ('\u{207}', 446, 2), ('\u{208}', 448, 2),
+        ('\u{209}', 450, 2), ('\u{20a}', 452, 2), ('\u{20b}', 454, 2), ('\u{20c}', 456, 2),
+        ('\u{20d}', 458, 2), ('\u{20e}', 460, 2), ('\u{20f}', 462, 2), ('\u{210}', 464, 2),
+        ('\u{211}', 466, 2), ('\u{212}', 468, 2), ('\u{213}', 470, 2), ('\u{214}', 472, 2),
+        ('\u{215}', 474, 2), ('\u{216}', 476, 2), ('\u{217}', 478, 2), ('\u{218}', 480, 2),
+        ('\u{219}', 482, 2), ('\u{21a}', 484, 2), ('\u{21b}', 486, 2), ('\u{21e}', 488, 2),
+        ('\u{21f}', 490, 2), ('\u{226}', 492, 2), ('\u{227}', 494, 2), ('\u{228}', 496, 2),
+        ('\u{229}', 498, 2), ('\u{22a}', 500, 3), ('\u{22b}', 503, 3), ('\u{22c}', 506, 3),
+        ('\u{22d}', 509, 3), ('\u{22e}', 512, 2), ('\u{22f}', 514, 2), ('\u{230}', 516, 3),
+        ('\u{231}', 519, 3), ('\u{232}', 522, 2), ('\u{233}', 524, 2), ('\u{340}', 526, 1),
+        ('\u{341}', 527, 1), ('\u{343}', 528, 1), ('\u{344}', 529, 2), ('\u{374}', 531, 1),
+        ('\u{37e}', 532, 1), ('\u{385}', 533, 2), ('\u{386}', 535, 2), ('\u{387}', 537, 1),
+        ('\u{388}', 538, 2), ('\u{389}', 540, 2), ('\u{38a}', 542, 2), ('\u{38c}', 544, 2),
+        ('\u{38e}', 546, 2), ('\u{38f}', 548, 2), ('\u{390}', 550, 3), ('\u{3aa}', 553, 2),
+        ('\u{3ab}', 555, 2), ('\u{3ac}', 557, 2), ('\u{3ad}', 559, 2), ('\u{3ae}', 561, 2),
+        ('\u{3af}', 563, 2), ('\u{3b0}', 565, 3), ('\u{3ca}', 568, 2), ('\u{3cb}', 570, 2),
+        ('\u{3cc}', 572, 2), ('\u{3cd}', 574, 2), ('\u{3ce}', 576, 2), ('\u{3d3}', 578, 2),
+        ('\u{3d4}', 580, 2), ('\u{400}', 582, 2), ('\u{401}', 584, 2), ('\u{403}', 586, 2),
+        ('\u{407}', 588, 2), ('\u{40c}', 590, 2), ('\u{40d}', 592, 2), ('\u{40e}', 594, 2),
+        ('\u{419}', 596, 2), ('\u{439}', 598, 2), ('\u{450}', 600, 2), ('\u{451}', 602, 2),
+        ('\u{453}', 604, 2), ('\u{457}', 606, 2), ('\u{45c}', 608, 2), ('\u{45d}', 610, 2),
+        ('\u{45e}', 612, 2), ('\u{476}', 614, 2), ('\u{477}', 616, 2), ('\u{4c1}', 618, 2),
+        ('\u{4c2}', 620, 2), ('\u{4d0}', 622, 2), ('\u{4d1}', 624, 2), ('\u{4d2}', 626, 2),
+        ('\u{4d3}', 628, 2), ('\u{4d6}', 630, 2), ('\u{4d7}', 632, 2), ('\u{4da}', 634, 2),
+        ('\u{4db}', 636, 2), ('\u{4dc}', 638, 2), ('\u{4dd}', 640, 2), ('\u{4de}', 642, 2),
+        ('\u{4df}', 644, 2), ('\u{4e2}', 646, 2), ('\u{4e3}', 648, 2), ('\u{4e4}', 650, 2),
+        ('\u{4e5}', 652, 2), ('\u{4e6}', 654, 2), ('\u{4e7}', 656, 2), ('\u{4ea}', 658, 2),
+        ('\u{4eb}', 660, 2), ('\u{4ec}', 662, 2), ('\u{4ed}', 664, 2), ('\u{4ee}', 666, 2),
+        ('\u{4ef}', 668, 2), ('\u{4f0}', 670, 2), ('\u{4f1}', 672, 2), ('\u{4f2}', 674, 2),
+        ('\u{4f3}', 676, 2), ('\u{4f4}', 678, 2), ('\u{4f5}', 680, 2), ('\u{4f8}', 682, 2),
+        ('\u{4f9}', 684, 2), ('\u{622}', 686, 2), ('\u{623}', 688, 2), ('\u{624}', 690, 2),
+        ('\u{625}', 692, 2), ('\u{626}', 694, 2), ('\u{6c0}', 696, 2), ('\u{6c2}', 698, 2),
+        ('\u{6d3}', 700, 2), ('\u{929}', 702, 2), ('\u{931}', 704, 2), ('\u{934}', 706, 2),
+        ('\u{958}', 708, 2), ('\u{959}', 710, 2), ('\u{95a}', 712, 2), ('\u{95b}', 714, 2),
+        ('\u{95c}', 716, 2), ('\u{95d}', 718, 2), ('\u{95e}', 720, 2), ('\u{95f}', 722, 2),
+        ('\u{9cb}', 724, 2), ('\u{9cc}', 726, 2), ('\u{9dc}', 728, 2), ('\u{9dd}', 730, 2),
+        ('\u{9df}', 732, 2), ('\u{a33}', 734, 2), ('\u{a36}', 736, 2), ('\u{a59}', 738, 2),
+        ('\u{a5a}', 740, 2), ('\u{a5b}', 742, 2), ('\u{a5e}', 744, 2), ('\u{b48}', 746, 2),
+        ('\u{b4b}', 748, 2), ('\u{b4c}', 750, 2), ('\u{b5c}', 752, 2), ('\u{b5d}', 754, 2),
+        ('\u{b94}', 756, 2), ('\u{bca}', 758, 2), ('\u{bcb}', 760, 2), ('\u{bcc}', 762, 2),
+        ('\u{c48}', 764, 2), ('\u{cc0}', 766, 2), ('\u{cc7}', 768, 2), ('\u{cc8}', 770, 2),
+        ('\u{cca}', 772, 2), ('\u{ccb}', 774, 3), ('\u{d4a}', 777, 2), ('\u{d4b}', 779, 2),
+        ('\u{d4c}', 781, 2), ('\u{dda}', 783, 2), ('\u{ddc}', 785, 2), ('\u{ddd}', 787, 3),
+        ('\u{dde}', 790, 2), ('\u{f43}', 792, 2), ('\u{f4d}', 794, 2), ('\u{f52}', 796, 2),
+        ('\u{f57}', 798, 2), ('\u{f5c}', 800, 2), ('\u{f69}', 802, 2), ('\u{f73}', 804, 2),
+        ('\u{f75}', 806, 2), ('\u{f76}', 808, 2), ('\u{f78}', 810, 2), ('\u{f81}', 812, 2),
+        ('\u{f93}', 814, 2), ('\u{f9d}', 816, 2), ('\u{fa2}', 818, 2), ('\u{fa7}', 820, 2),
+        ('\u{fac}', 822, 2), ('\u{fb9}', 824, 2), ('\u{1026}', 826, 2), ('\u{1b06}', 828, 2),
+        ('\u{1b08}', 830, 2), ('\u{1b0a}', 832, 2), ('\u{1b0c}', 834, 2), ('\u{1b0e}', 836, 2),
+        ('\u{1b12}', 838, 2), ('\u{1b3b}', 840, 2), ('\u{1b3d}', 842, 2), ('\u{1b40}', 844, 2),
+        ('\u{1b41}', 846, 2), ('\u{1b43}', 848, 2), ('\u{1e00}', 850, 2), ('\u{1e01}', 852, 2),
+        ('\u{1e02}', 854, 2), ('\u{1e03}', 856, 2), ('\u{1e04}', 858, 2), ('\u{1e05}', 860, 2),
+        ('\u{1e06}', 862, 2), ('\u{1e07}', 864, 2), ('\u{1e08}', 866, 3), ('\u{1e09}', 869, 3),
+        ('\u{1e0a}', 872, 2), ('\u{1e0b}', 874, 2), ('\u{1e0c}', 876, 2), ('\u{1e0d}', 878, 2),
+        ('\u{1e0e}', 880, 2), ('\u{1e0f}', 882, 2), ('\u{1e10}', 884, 2), ('\u{1e11}', 886, 2),
+        ('\u{1e12}', 888, 2), ('\u{1e13}', 890, 2), ('\u{1e14}', 892, 3), ('\u{1e15}', 895, 3),
+        ('\u{1e16}', 898, 3), ('\u{1e17}', 901, 3), ('\u{1e18}', 904, 2), ('\u{1e19}', 906, 2),
+        ('\u{1e1a}', 908, 2), ('\u{1e1b}', 910, 2), ('\u{1e1c}', 912, 3), ('\u{1e1d}', 915, 3),
+        ('\u{1e1e}', 918, 2), ('\u{1e1f}', 920, 2), ('\u{1e20}', 922, 2), ('\u{1e21}', 924, 2),
+        ('\u{1e22}', 926, 2), ('\u{1e23}', 928, 2), ('\u{1e24}', 930, 2), ('\u{1e25}', 932, 2),
+        ('\u{1e26}', 934, 2), ('\u{1e27}', 936, 2), ('\u{1e28}', 938, 2), ('\u{1e29}', 940, 2),
+        ('\u{1e2a}', 942, 2), ('\u{1e2b}', 944, 2), ('\u{1e2c}', 946, 2), ('\u{1e2d}', 948, 2),
+        ('\u{1e2e}', 950, 3), ('\u{1e2f}', 953, 3), ('\u{1e30}', 956, 2), ('\u{1e31}', 958, 2),
+        ('\u{1e32}', 960, 2), ('\u{1e33}', 962, 2), ('\u{1e34}', 964, 2), ('\u{1e35}', 966, 2),
+        ('\u{1e36}', 968, 2), ('\u{1e37}', 970, 2), ('\u{1e38}', 972, 3), ('\u{1e39}', 975, 3),
+        ('\u{1e3a}', 978, 2), ('\u{1e3b}', 980, 2), ('\u{1e3c}', 982, 2), ('\u{1e3d}', 984, 2),
+        ('\u{1e3e}', 986, 2), ('\u{1e3f}', 988, 2), ('\u{1e40}', 990, 2), ('\u{1e41}', 992, 2),
+        ('\u{1e42}', 994, 2), ('\u{1e43}', 996, 2), ('\u{1e44}', 998, 2), ('\u{1e45}', 1000, 2),
+        ('\u{1e46}', 1002, 2), ('\u{1e47}', 1004, 2), ('\u{1e48}', 1006, 2), ('\u{1e49}', 1008, 2),
+        ('\u{1e4a}', 1010, 2), ('\u{1e4b}', 1012, 2), ('\u{1e4c}', 1014, 3), ('\u{1e4d}', 1017, 3),
+        ('\u{1e4e}', 1020, 3), ('\u{1e4f}', 1023, 3), ('\u{1e50}', 1026, 3), ('\u{1e51}', 1029, 3),
+        ('\u{1e52}', 1032, 3), ('\u{1e53}', 1035, 3), ('\u{1e54}', 1038, 2), ('\u{1e55}', 1040, 2),
+        ('\u{1e56}', 1042, 2), ('\u{1e57}', 1044, 2), ('\u{1e58}', 1046, 2), ('\u{1e59}', 1048, 2),
+        ('\u{1e5a}', 1050, 2), ('\u{1e5b}', 1052, 2), ('\u{1e5c}', 1054, 3), ('\u{1e5d}', 1057, 3),
+        ('\u{1e5e}', 1060, 2), ('\u{1e5f}', 1062, 2), ('\u{1e60}', 1064, 2), ('\u{1e61}', 1066, 2),
+        ('\u{1e62}', 1068, 2), ('\u{1e63}', 1070, 2), ('\u{1e64}', 1072, 3), ('\u{1e65}', 1075, 3),
+        ('\u{1e66}', 1078, 3), ('\u{1e67}', 1081, 3), ('\u{1e68}', 1084, 3), ('\u{1e69}', 1087, 3),
+        ('\u{1e6a}', 1090, 2), ('\u{1e6b}', 1092, 2), ('\u{1e6c}', 1094, 2), ('\u{1e6d}', 1096, 2),
+        ('\u{1e6e}', 1098, 2), ('\u{1e6f}', 1100, 2), ('\u{1e70}', 1102, 2), ('\u{1e71}', 1104, 2),
+        ('\u{1e72}', 1106, 2), ('\u{1e73}', 1108, 2), ('\u{1e74}', 1110, 2), ('\u{1e75}', 1112, 2),
+        ('\u{1e76}', 1114, 2), ('\u{1e77}', 1116, 2), ('\u{1e78}', 1118, 3), ('\u{1e79}', 1121, 3),
+        ('\u{1e7a}', 1124, 3), ('\u{1e7b}', 1127, 3), ('\u{1e7c}', 1130, 2), ('\u{1e7d}', 1132, 2),
+        ('\u{1e7e}', 1134, 2), ('\u{1e7f}', 1136, 2), ('\u{1e80}', 1138, 2), ('\u{1e81}', 1140, 2),
+        ('\u{1e82}', 1142, 2), ('\u{1e83}', 1144, 2), ('\u{1e84}', 1146, 2), ('\u{1e85}', 1148, 2),
+        ('\u{1e86}', 1150, 2), ('\u{1e87}', 1152, 2), ('\u{1e88}', 1154, 2), ('\u{1e89}', 1156, 2),
+        ('\u{1e8a}', 1158, 2), ('\u{1e8b}', 1160, 2), ('\u{1e8c}', 1162, 2), ('\u{1e8d}', 1164, 2),
+        ('\u{1e8e}', 1166, 2), ('\u{1e8f}', 1168, 2), ('\u{1e90}', 1170, 2), ('\u{1e91}', 1172, 2),
+        ('\u{1e92}', 1174, 2), ('\u{1e93}', 1176, 2), ('\u{1e94}', 1178, 2), ('\u{1e95}', 1180, 2),
+        ('\u{1e96}', 1182, 2), ('\u{1e97}', 1184, 2), ('\u{1e98}', 1186, 2), ('\u{1e99}', 1188, 2),
+        ('\u{1e9b}', 1190, 2), ('\u{1ea0}', 1192, 2), ('\u{1ea1}', 1194, 2), ('\u{1ea2}', 1196, 2),
+        ('\u{1ea3}', 1198, 2), ('\u{1ea4}', 1200, 3), ('\u{1ea5}', 1203, 3), ('\u{1ea6}', 1206, 3),
+        ('\u{1ea7}', 1209, 3), ('\u{1ea8}', 1212, 3), ('\u{1ea9}', 1215, 3), ('\u{1eaa}', 1218, 3),
+        ('\u{1eab}', 1221, 3), ('\u{1eac}', 1224, 3), ('\u{1ead}', 1227, 3), ('\u{1eae}', 1230, 3),
+        ('\u{1eaf}', 1233, 3), ('\u{1eb0}', 1236, 3), ('\u{1eb1}', 1239, 3), ('\u{1eb2}', 1242, 3),
+        ('\u{1eb3}', 1245, 3), ('\u{1eb4}', 1248, 3), ('\u{1eb5}', 1251, 3), ('\u{1eb6}', 1254, 3),
+        ('\u{1eb7}', 1257, 3), ('\u{1eb8}', 1260, 2), ('\u{1eb9}', 1262, 2), ('\u{1eba}', 1264, 2),
+        ('\u{1ebb}', 1266, 2), ('\u{1ebc}', 1268, 2), ('\u{1ebd}', 1270, 2), ('\u{1ebe}', 1272, 3),
+        ('\u{1ebf}', 1275, 3), ('\u{1ec0}', 1278, 3), ('\u{1ec1}', 1281, 3), ('\u{1ec2}', 1284, 3),
+        ('\u{1ec3}', 1287, 3), ('\u{1ec4}', 1290, 3), ('\u{1ec5}', 1293, 3), ('\u{1ec6}', 1296, 3),
+        ('\u{1ec7}', 1299, 3), ('\u{1ec8}', 1302, 2), ('\u{1ec9}', 1304, 2), ('\u{1eca}', 1306, 2),
+        ('\u{1ecb}', 1308, 2), ('\u{1ecc}', 1310, 2), ('\u{1ecd}', 1312, 2), ('\u{1ece}', 1314, 2),
+        ('\u{1ecf}', 1316, 2), ('\u{1ed0}', 1318, 3), ('\u{1ed1}', 1321, 3), ('\u{1ed2}', 1324, 3),
+        ('\u{1ed3}', 1327, 3), ('\u{1ed4}', 1330, 3), ('\u{1ed5}', 1333, 3), ('\u{1ed6}', 1336, 3),
+        ('\u{1ed7}', 1339, 3), ('\u{1ed8}', 1342, 3), ('\u{1ed9}', 1345, 3), ('\u{1eda}', 1348, 3),
+        ('\u{1edb}', 1351, 3), ('\u{1edc}', 1354, 3), ('\u{1edd}', 1357, 3), ('\u{1ede}', 1360, 3),
+        ('\u{1edf}', 1363, 3), ('\u{1ee0}', 1366, 3), ('\u{1ee1}', 1369, 3), ('\u{1ee2}', 1372, 3),
+        ('\u{1ee3}', 1375, 3), ('\u{1ee4}', 1378, 2), ('\u{1ee5}', 1380, 2), ('\u{1ee6}', 1382, 2),
+        ('\u{1ee7}', 1384, 2), ('\u{1ee8}', 1386, 3), ('\u{1ee9}', 1389, 3), ('\u{1eea}', 1392, 3),
+        ('\u{1eeb}', 1395, 3), ('\u{1eec}', 1398, 3), ('\u{1eed}', 1401, 3), ('\u{1eee}', 1404, 3),
+        ('\u{1eef}', 1407, 3), ('\u{1ef0}', 1410, 3), ('\u{1ef1}', 1413, 3), ('\u{1ef2}', 1416, 2),
+        ('\u{1ef3}', 1418, 2), ('\u{1ef4}', 1420, 2), ('\u{1ef5}', 1422, 2), ('\u{1ef6}', 1424, 2),
+        ('\u{1ef7}', 1426, 2), ('\u{1ef8}', 1428, 2), ('\u{1ef9}', 1430, 2), ('\u{1f00}', 1432, 2),
+        ('\u{1f01}', 1434, 2), ('\u{1f02}', 1436, 3), ('\u{1f03}', 1439, 3), ('\u{1f04}', 1442, 3),
+        ('\u{1f05}', 1445, 3), ('\u{1f06}', 1448, 3), ('\u{1f07}', 1451, 3), ('\u{1f08}', 1454, 2),
+        ('\u{1f09}', 1456, 2), ('\u{1f0a}', 1458, 3), ('\u{1f0b}', 1461, 3), ('\u{1f0c}', 1464, 3),
+        ('\u{1f0d}', 1467, 3), ('\u{1f0e}', 1470, 3), ('\u{1f0f}', 1473, 3), ('\u{1f10}', 1476, 2),
+        ('\u{1f11}', 1478, 2), ('\u{1f12}', 1480, 3), ('\u{1f13}', 1483, 3), ('\u{1f14}', 1486, 3),
+        ('\u{1f15}', 1489, 3), ('\u{1f18}', 1492, 2), ('\u{1f19}', 1494, 2), ('\u{1f1a}', 1496, 3),
+        ('\u{1f1b}', 1499, 3), ('\u{1f1c}', 1502, 3), ('\u{1f1d}', 1505, 3), ('\u{1f20}', 1508, 2),
+        ('\u{1f21}', 1510, 2), ('\u{1f22}', 1512, 3), ('\u{1f23}', 1515, 3), ('\u{1f24}', 1518, 3),
+        ('\u{1f25}', 1521, 3), ('\u{1f26}', 1524, 3), ('\u{1f27}', 1527, 3), ('\u{1f28}', 1530, 2),
+        ('\u{1f29}', 1532, 2), ('\u{1f2a}', 1534, 3), ('\u{1f2b}', 1537, 3), ('\u{1f2c}', 1540, 3),
+        ('\u{1f2d}', 1543, 3), ('\u{1f2e}', 1546, 3), ('\u{1f2f}', 1549, 3), ('\u{1f30}', 1552, 2),
+        ('\u{1f31}', 1554, 2), ('\u{1f32}', 1556, 3), ('\u{1f33}', 1559, 3), ('\u{1f34}', 1562, 3),
+        ('\u{1f35}', 1565, 3), ('\u{1f36}', 1568, 3), ('\u{1f37}', 1571, 3), ('\u{1f38}', 1574, 2),
+        ('\u{1f39}', 1576, 2), ('\u{1f3a}', 1578, 3), ('\u{1f3b}', 1581, 3), ('\u{1f3c}', 1584, 3),
+        ('\u{1f3d}', 1587, 3), ('\u{1f3e}', 1590, 3), ('\u{1f3f}', 1593, 3), ('\u{1f40}', 1596, 2),
+        ('\u{1f41}', 1598, 2), ('\u{1f42}', 1600, 3), ('\u{1f43}', 1603, 3), ('\u{1f44}', 1606, 3),
+        ('\u{1f45}', 1609, 3), ('\u{1f48}', 1612, 2), ('\u{1f49}', 1614, 2), ('\u{1f4a}', 1616, 3),
+        ('\u{1f4b}', 1619, 3), ('\u{1f4c}', 1622, 3), ('\u{1f4d}', 1625, 3), ('\u{1f50}', 1628, 2),
+        ('\u{1f51}', 1630, 2), ('\u{1f52}', 1632, 3), ('\u{1f53}', 1635, 3), ('\u{1f54}', 1638, 3),
+        ('\u{1f55}', 1641, 3), ('\u{1f56}', 1644, 3), ('\u{1f57}', 1647, 3), ('\u{1f59}', 1650, 2),
+        ('\u{1f5b}', 1652, 3), ('\u{1f5d}', 1655, 3), ('\u{1f5f}', 1658, 3), ('\u{1f60}', 1661, 2),
+        ('\u{1f61}', 1663, 2), ('\u{1f62}', 1665, 3), ('\u{1f63}', 1668, 3), ('\u{1f64}', 1671, 3),
+        ('\u{1f65}', 1674, 3), ('\u{1f66}', 1677, 3), ('\u{1f67}', 1680, 3), ('\u{1f68}', 1683, 2),
+        ('\u{1f69}', 1685, 2), ('\u{1f6a}', 1687, 3), ('\u{1f6b}', 1690, 3), ('\u{1f6c}', 1693, 3),
+        ('\u{1f6d}', 1696, 3), ('\u{1f6e}', 1699, 3), ('\u{1f6f}', 1702, 3), ('\u{1f70}', 1705, 2),
+        ('\u{1f71}', 1707, 2), ('\u{1f72}', 1709, 2), ('\u{1f73}', 1711, 2), ('\u{1f74}', 1713, 2),
+        ('\u{1f75}', 1715, 2), ('\u{1f76}', 1717, 2), ('\u{1f77}', 1719, 2), ('\u{1f78}', 1721, 2),
+        ('\u{1f79}', 1723, 2), ('\u{1f7a}', 1725, 2), ('\u{1f7b}', 1727, 2), ('\u{1f7c}', 1729, 2),
+        ('\u{1f7d}', 1731, 2), ('\u{1f80}', 1733, 3), ('\u{1f81}', 1736, 3), ('\u{1f82}', 1739, 4),
+        ('\u{1f83}', 1743, 4), ('\u{1f84}', 1747, 4), ('\u{1f85}', 1751, 4), ('\u{1f86}', 1755, 4),
+        ('\u{1f87}', 1759, 4), ('\u{1f88}', 1763, 3), ('\u{1f89}', 1766, 3), ('\u{1f8a}', 1769, 4),
+        ('\u{1f8b}', 1773, 4), ('\u{1f8c}', 1777, 4), ('\u{1f8d}', 1781, 4), ('\u{1f8e}', 1785, 4),
+        ('\u{1f8f}', 1789, 4), ('\u{1f90}', 1793, 3), ('\u{1f91}', 1796, 3), ('\u{1f92}', 1799, 4),
+        ('\u{1f93}', 1803, 4), ('\u{1f94}', 1807, 4), ('\u{1f95}', 1811, 4), ('\u{1f96}', 1815, 4),
+        ('\u{1f97}', 1819, 4), ('\u{1f98}', 1823, 3), ('\u{1f99}', 1826, 3), ('\u{1f9a}', 1829, 4),
+        ('\u{1f9b}', 1833, 4), ('\u{1f9c}', 1837, 4), ('\u{1f9d}', 1841, 4), ('\u{1f9e}', 1845, 4),
+        ('\u{1f9f}', 1849, 4), ('\u{1fa0}', 1853, 3), ('\u{1fa1}', 1856, 3), ('\u{1fa2}', 1859, 4),
+        ('\u{1fa3}', 1863, 4), ('\u{1fa4}', 1867, 4), ('\u{1fa5}', 1871, 4), ('\u{1fa6}', 1875, 4),
+        ('\u{1fa7}', 1879, 4), ('\u{1fa8}', 1883, 3), ('\u{1fa9}', 1886, 3), ('\u{1faa}', 1889, 4),
+        ('\u{1fab}', 1893, 4), ('\u{1fac}', 1897, 4), ('\u{1fad}', 1901, 4), ('\u{1fae}', 1905, 4),
+        ('\u{1faf}', 1909, 4), ('\u{1fb0}', 1913, 2), ('\u{1fb1}', 1915, 2), ('\u{1fb2}', 1917, 3),
+        ('\u{1fb3}', 1920, 2), ('\u{1fb4}', 1922, 3), ('\u{1fb6}', 1925, 2), ('\u{1fb7}', 1927, 3),
+        ('\u{1fb8}', 1930, 2), ('\u{1fb9}', 1932, 2), ('\u{1fba}', 1934, 2), ('\u{1fbb}', 1936, 2),
+        ('\u{1fbc}', 1938, 2), ('\u{1fbe}', 1940, 1), ('\u{1fc1}', 1941, 2), ('\u{1fc2}', 1943, 3),
+        ('\u{1fc3}', 1946, 2), ('\u{1fc4}', 1948, 3), ('\u{1fc6}', 1951, 2), ('\u{1fc7}', 1953, 3),
+        ('\u{1fc8}', 1956, 2), ('\u{1fc9}', 1958, 2), ('\u{1fca}', 1960, 2), ('\u{1fcb}', 1962, 2),
+        ('\u{1fcc}', 1964, 2), ('\u{1fcd}', 1966, 2), ('\u{1fce}', 1968, 2), ('\u{1fcf}', 1970, 2),
+        ('\u{1fd0}', 1972, 2), ('\u{1fd1}', 1974, 2), ('\u{1fd2}', 1976, 3), ('\u{1fd3}', 1979, 3),
+        ('\u{1fd6}', 1982, 2), ('\u{1fd7}', 1984, 3), ('\u{1fd8}', 1987, 2), ('\u{1fd9}', 1989, 2),
+        ('\u{1fda}', 1991, 2), ('\u{1fdb}', 1993, 2), ('\u{1fdd}', 1995, 2), ('\u{1fde}', 1997, 2),
+        ('\u{1fdf}', 1999, 2), ('\u{1fe0}', 2001, 2), ('\u{1fe1}', 2003, 2), ('\u{1fe2}', 2005, 3),
+        ('\u{1fe3}', 2008, 3), ('\u{1fe4}', 2011, 2), ('\u{1fe5}', 2013, 2), ('\u{1fe6}', 2015, 2),
+        ('\u{1fe7}', 2017, 3), ('\u{1fe8}', 2020, 2), ('\u{1fe9}', 2022, 2), ('\u{1fea}', 2024, 2),
+        ('\u{1feb}', 2026, 2), ('\u{1fec}', 2028, 2), ('\u{1fed}', 2030, 2), ('\u{1fee}', 2032, 2),
+        ('\u{1fef}', 2034, 1), ('\u{1ff2}', 2035, 3), ('\u{1ff3}', 2038, 2), ('\u{1ff4}', 2040, 3),
+        ('\u{1ff6}', 2043, 2), ('\u{1ff7}', 2045, 3), ('\u{1ff8}', 2048, 2), ('\u{1ff9}', 2050, 2),
+        ('\u{1ffa}', 2052, 2), ('\u{1ffb}', 2054, 2), ('\u{1ffc}', 2056, 2), ('\u{1ffd}', 2058, 1),
+        ('\u{2000}', 2059, 1), ('\u{2001}', 2060, 1), ('\u{2126}', 2061, 1), ('\u{212a}', 2062, 1),
+        ('\u{212b}', 2063, 2), ('\u{219a}', 2065, 2), ('\u{219b}', 2067, 2), ('\u{21ae}', 2069, 2),
+        ('\u{21cd}', 2071, 2), ('\u{21ce}', 2073, 2), ('\u{21cf}', 2075, 2), ('\u{2204}', 2077, 2),
+        ('\u{2209}', 2079, 2), ('\u{220c}', 2081, 2), ('\u{2224}', 2083, 2), ('\u{2226}', 2085, 2),
+        ('\u{2241}', 2087, 2), ('\u{2244}', 2089, 2), ('\u{2247}', 2091, 2), ('\u{2249}', 2093, 2),
+        ('\u{2260}', 2095, 2), ('\u{2262}', 2097, 2), ('\u{226d}', 2099, 2), ('\u{226e}', 2101, 2),
+        ('\u{226f}', 2103, 2), ('\u{2270}', 2105, 2), ('\u{2271}', 2107, 2), ('\u{2274}', 2109, 2),
+        ('\u{2275}', 2111, 2), ('\u{2278}', 2113, 2), ('\u{2279}', 2115, 2), ('\u{2280}', 2117, 2),
+        ('\u{2281}', 2119, 2), ('\u{2284}', 2121, 2), ('\u{2285}', 2123, 2), ('\u{2288}', 2125, 2),
+        ('\u{2289}', 2127, 2), ('\u{22ac}', 2129, 2), ('\u{22ad}', 2131, 2), ('\u{22ae}', 2133, 2),
+        ('\u{22af}', 2135, 2), ('\u{22e0}', 2137, 2), ('\u{22e1}', 2139, 2), ('\u{22e2}', 2141, 2),
+        ('\u{22e3}', 2143, 2), ('\u{22ea}', 2145, 2), ('\u{22eb}', 2147, 2), ('\u{22ec}', 2149, 2),
+        ('\u{22ed}', 2151, 2), ('\u{2329}', 2153, 1), ('\u{232a}', 2154, 1), ('\u{2adc}', 2155, 2),
+        ('\u{304c}', 2157, 2), ('\u{304e}', 2159, 2), ('\u{3050}', 2161, 2), ('\u{3052}', 2163, 2),
+        ('\u{3054}', 2165, 2), ('\u{3056}', 2167, 2), ('\u{3058}', 2169, 2), ('\u{305a}', 2171, 2),
+        ('\u{305c}', 2173, 2), ('\u{305e}', 2175, 2), ('\u{3060}', 2177, 2), ('\u{3062}', 2179, 2),
+        ('\u{3065}', 2181, 2), ('\u{3067}', 2183, 2), ('\u{3069}', 2185, 2), ('\u{3070}', 2187, 2),
+        ('\u{3071}', 2189, 2), ('\u{3073}', 2191, 2), ('\u{3074}', 2193, 2), ('\u{3076}', 2195, 2),
+        ('\u{3077}', 2197, 2), ('\u{3079}', 2199, 2), ('\u{307a}', 2201, 2), ('\u{307c}', 2203, 2),
+        ('\u{307d}', 2205, 2), ('\u{3094}', 2207, 2), ('\u{309e}', 2209, 2), ('\u{30ac}', 2211, 2),
+        ('\u{30ae}', 2213, 2), ('\u{30b0}', 2215, 2), ('\u{30b2}', 2217, 2), ('\u{30b4}', 2219, 2),
+        ('\u{30b6}', 2221, 2), ('\u{30b8}', 2223, 2), ('\u{30ba}', 2225, 2), ('\u{30bc}', 2227, 2),
+        ('\u{30be}', 2229, 2), ('\u{30c0}', 2231, 2), ('\u{30c2}', 2233, 2), ('\u{30c5}', 2235, 2),
+        ('\u{30c7}', 2237, 2), ('\u{30c9}', 2239, 2), ('\u{30d0}', 2241, 2), ('\u{30d1}', 2243, 2),
+        ('\u{30d3}', 2245, 2), ('\u{30d4}', 2247, 2), ('\u{30d6}', 2249, 2), ('\u{30d7}', 2251, 2),
+        ('\u{30d9}', 2253, 2), ('\u{30da}', 2255, 2), ('\u{30dc}', 2257, 2), ('\u{30dd}', 2259, 2),
+        ('\u{30f4}', 2261, 2), ('\u{30f7}', 2263, 2), ('\u{30f8}', 2265, 2), ('\u{30f9}', 2267, 2),
+        ('\u{30fa}', 2269, 2), ('\u{30fe}', 2271, 2), ('\u{f900}', 2273, 1), ('\u{f901}', 2274, 1),
+        ('\u{f902}', 2275, 1), ('\u{f903}', 2276, 1), ('\u{f904}', 2277, 1), ('\u{f905}', 2278, 1),
+        ('\u{f906}', 2279, 1), ('\u{f907}', 2280, 1), ('\u{f908}', 2281, 1), ('\u{f909}', 2282, 1),
+        ('\u{f90a}', 2283, 1), ('\u{f90b}', 2284, 1), ('\u{f90c}', 2285, 1), ('\u{f90d}', 2286, 1),
+        ('\u{f90e}', 2287, 1), ('\u{f90f}', 2288, 1), ('\u{f910}', 2289, 1), ('\u{f911}', 2290, 1),
+        ('\u{f912}', 2291, 1), ('\u{f913}', 2292, 1), ('\u{f914}', 2293, 1), ('\u{f915}', 2294, 1),
+        ('\u{f916}', 2295, 1), ('\u{f917}', 2296, 1), ('\u{f918}', 2297, 1), ('\u{f919}', 2298, 1),
+        ('\u{f91a}', 2299, 1), ('\u{f91b}', 2300, 1), ('\u{f91c}', 2301, 1), ('\u{f91d}', 2302, 1),
+        ('\u{f91e}', 2303, 1), ('\u{f91f}', 2304, 1), ('\u{f920}', 2305, 1), ('\u{f921}', 2306, 1),
+        ('\u{f922}', 2307, 1), ('\u{f923}', 2308, 1), ('\u{f924}', 2309, 1), ('\u{f925}', 2310, 1),
+        ('\u{f926}', 2311, 1), ('\u{f927}', 2312, 1), ('\u{f928}', 2313, 1), ('\u{f929}', 2314, 1),
+        ('\u{f92a}', 2315, 1), ('\u{f92b}', 2316, 1), ('\u{f92c}', 2317, 1), ('\u{f92d}', 2318, 1),
+        ('\u{f92e}', 2319, 1), ('\u{f92f}', 2320, 1), ('\u{f930}', 2321, 1), ('\u{f931}', 2322, 1),
+        ('\u{f932}', 2323, 1), ('\u{f933}', 2324, 1), ('\u{f934}', 2325, 1), ('\u{f935}', 2326, 1),
+        ('\u{f936}', 2327, 1), ('\u{f937}', 2328, 1), ('\u{f938}', 2329, 1), ('\u{f939}', 2330, 1),
+        ('\u{f93a}', 2331, 1), ('\u{f93b}', 2332, 1), ('\u{f93c}', 2333, 1), ('\u{f93d}', 2334, 1),
+        ('\u{f93e}', 2335, 1), ('\u{f93f}', 2336, 1), ('\u{f940}', 2337, 1), ('\u{f941}', 2338, 1),
+        ('\u{f942}', 2339, 1), ('\u{f943}', 2340, 1), ('\u{f944}', 2341, 1), ('\u{f945}', 2342, 1),
+        ('\u{f946}', 2343, 1), ('\u{f947}', 2344, 1), ('\u{f948}', 2345, 1), ('\u{f949}', 2346, 1),
+        ('\u{f94a}', 2347, 1), ('\u{f94b}', 2348, 1), ('\u{f94c}', 2349, 1), ('\u{f94d}', 2350, 1),
+        ('\u{f94e}', 2351, 1), ('\u{f94f}', 2352, 1), ('\u{f950}', 2353, 1), ('\u{f951}', 2354, 1),
+        ('\u{f952}', 2355, 1), ('\u{f953}', 2356, 1), ('\u{f954}', 2357, 1), ('\u{f955}', 2358, 1),
+        ('\u{f956}', 2359, 1), ('\u{f957}', 2360, 1), ('\u{f958}', 2361, 1), ('\u{f959}', 2362, 1),
+        ('\u{f95a}', 2363, 1), ('\u{f95b}', 2364, 1), ('\u{f95c}', 2365, 1), ('\u{f95d}', 2366, 1),
+        ('\u{f95e}', 2367, 1), ('\u{f95f}', 2368, 1), ('\u{f960}', 2369, 1), ('\u{f961}', 2370, 1),
+        ('\u{f962}', 2371, 1), ('\u{f963}', 2372, 1), ('\u{f964}', 2373, 1), ('\u{f965}', 2374, 1),
+        ('\u{f966}', 2375, 1), ('\u{f967}', 2376, 1), ('\u{f968}', 2377, 1), ('\u{f969}', 2378, 1),
+        ('\u{f96a}', 2379, 1), ('\u{f96b}', 2380, 1), ('\u{f96c}', 2381, 1), ('\u{f96d}', 2382, 1),
+        ('\u{f96e}', 2383, 1), ('\u{f96f}', 2384, 1), ('\u{f970}', 2385, 1), ('\u{f971}', 2386, 1),
+        ('\u{f972}', 2387, 1), ('\u{f973}', 2388, 1), ('\u{f974}', 2389, 1), ('\u{f975}', 2390, 1),
+        ('\u{f976}', 2391, 1), ('\u{f977}', 2392, 1), ('\u{f978}', 2393, 1), ('\u{f979}', 2394, 1),
+        ('\u{f97a}', 2395, 1), ('\u{f97b}', 2396, 1), ('\u{f97c}', 2397, 1), ('\u{f97d}', 2398, 1),
+        ('\u{f97e}', 2399, 1), ('\u{f97f}', 2400, 1), ('\u{f980}', 2401, 1), ('\u{f981}', 2402, 1),
+        ('\u{f982}', 2403, 1), ('\u{f983}', 2404, 1), ('\u{f984}', 2405, 1), ('\u{f985}', 2406, 1),
+        ('\u{f986}', 2407, 1), ('\u{f987}', 2408, 1), ('\u{f988}', 2409, 1), ('\u{f989}', 2410, 1),
+        ('\u{f98a}', 2411, 1), ('\u{f98b}', 2412, 1), ('\u{f98c}', 2413, 1), ('\u{f98d}', 2414, 1),
+        ('\u{f98e}', 2415, 1), ('\u{f98f}', 2416, 1), ('\u{f990}', 2417, 1), ('\u{f991}', 2418, 1),
+        ('\u{f992}', 2419, 1), ('\u{f993}', 2420, 1), ('\u{f994}', 2421, 1), ('\u{f995}', 2422, 1),
+        ('\u{f996}', 2423, 1), ('\u{f997}', 2424, 1), ('\u{f998}', 2425, 1), ('\u{f999}', 2426, 1),
+        ('\u{f99a}', 2427, 1), ('\u{f99b}', 2428, 1), ('\u{f99c}', 2429, 1), ('\u{f99d}', 2430, 1),
+        ('\u{f99e}', 2431, 1), ('\u{f99f}', 2432, 1), ('\u{f9a0}', 2433, 1), ('\u{f9a1}', 2434, 1),
+        ('\u{f9a2}', 2435, 1), ('\u{f9a3}', 2436, 1), ('\u{f9a4}', 2437, 1), ('\u{f9a5}', 2438, 1),
+        ('\u{f9a6}', 2439, 1), ('\u{f9a7}', 2440, 1), ('\u{f9a8}', 2441, 1), ('\u{f9a9}', 2442, 1),
+        ('\u{f9aa}', 2443, 1), ('\u{f9ab}', 2444, 1), ('\u{f9ac}', 2445, 1), ('\u{f9ad}', 2446, 1),
+        ('\u{f9ae}', 2447, 1), ('\u{f9af}', 2448, 1), ('\u{f9b0}', 2449, 1), ('\u{f9b1}', 2450, 1),
+        ('\u{f9b2}', 2451, 1), ('\u{f9b3}', 2452, 1), ('\u{f9b4}', 2453, 1), ('\u{f9b5}', 2454, 1),
+        ('\u{f9b6}', 2455, 1), ('\u{f9b7}', 2456, 1), ('\u{f9b8}', 2457, 1), ('\u{f9b9}', 2458, 1),
+        ('\u{f9ba}', 2459, 1), ('\u{f9bb}', 2460, 1), ('\u{f9bc}', 2461, 1), ('\u{f9bd}', 2462, 1),
+        ('\u{f9be}', 2463, 1), ('\u{f9bf}', 2464, 1), ('\u{f9c0}', 2465, 1), ('\u{f9c1}', 2466, 1),
+        ('\u{f9c2}', 2467, 1), ('\u{f9c3}', 2468, 1), ('\u{f9c4}', 2469, 1), ('\u{f9c5}', 2470, 1),
+        ('\u{f9c6}', 2471, 1), ('\u{f9c7}', 2472, 1), ('\u{f9c8}', 2473, 1), ('\u{f9c9}', 2474, 1),
+        ('\u{f9ca}', 2475, 1), ('\u{f9cb}', 2476, 1), ('\u{f9cc}', 2477, 1), ('\u{f9cd}', 2478, 1),
+        ('\u{f9ce}', 2479, 1), ('\u{f9cf}', 2480, 1), ('\u{f9d0}', 2481, 1), ('\u{f9d1}', 2482, 1),
+        ('\u{f9d2}', 2483, 1), ('\u{f9d3}', 2484, 1), ('\u{f9d4}', 2485, 1), ('\u{f9d5}', 2486, 1),
+        ('\u{f9d6}', 2487, 1), ('\u{f9d7}', 2488, 1), ('\u{f9d8}', 2489, 1), ('\u{f9d9}', 2490, 1),
+        ('\u{f9da}', 2491, 1), ('\u{f9db}', 2492, 1), ('\u{f9dc}', 2493, 1), ('\u{f9dd}', 2494, 1),
+        ('\u{f9de}', 2495, 1), ('\u{f9df}', 2496, 1), ('\u{f9e0}', 2497, 1), ('\u{f9e1}', 2498, 1),
+        ('\u{f9e2}', 2499, 1), ('\u{f9e3}', 2500, 1), ('\u{f9e4}', 2501, 1), ('\u{f9e5}', 2502, 1),
+        ('\u{f9e6}', 2503, 1), ('\u{f9e7}', 2504, 1), ('\u{f9e8}', 2505, 1), ('\u{f9e9}', 2506, 1),
+        ('\u{f9ea}', 2507, 1), ('\u{f9eb}', 2508, 1), ('\u{f9ec}', 2509, 1), ('\u{f9ed}', 2510, 1),
+        ('\u{f9ee}', 2511, 1), ('\u{f9ef}', 2512, 1), ('\u{f9f0}', 2513, 1), ('\u{f9f1}', 2514, 1),
+        ('\u{f9f2}', 2515, 1), ('\u{f9f3}', 2516, 1), ('\u{f9f4}', 2517, 1), ('\u{f9f5}', 2518, 1),
+        ('\u{f9f6}', 2519, 1), ('\u{f9f7}', 2520, 1), ('\u{f9f8}', 2521, 1), ('\u{f9f9}', 2522, 1),
+        ('\u{f9fa}', 2523, 1), ('\u{f9fb}', 2524, 1), ('\u{f9fc}', 2525, 1), ('\u{f9fd}', 2526, 1),
+        ('\u{f9fe}', 2527, 1), ('\u{f9ff}', 2528, 1), ('\u{fa00}', 2529, 1), ('\u{fa01}', 2530, 1),
+        ('\u{fa02}', 2531, 1), ('\u{fa03}', 2532, 1), ('\u{fa04}', 2533, 1), ('\u{fa05}', 2534, 1),
+        ('\u{fa06}', 2535, 1), ('\u{fa07}', 2536, 1), ('\u{fa08}', 2537, 1), ('\u{fa09}', 2538, 1),
+        ('\u{fa0a}', 2539, 1), ('\u{fa0b}', 2540, 1), ('\u{fa0c}', 2541, 1), ('\u{fa0d}', 2542, 1),
+        ('\u{fa10}', 2543, 1), ('\u{fa12}', 2544, 1), ('\u{fa15}', 2545, 1), ('\u{fa16}', 2546, 1),
+        ('\u{fa17}', 2547, 1), ('\u{fa18}', 2548, 1), ('\u{fa19}', 2549, 1), ('\u{fa1a}', 2550, 1),
+        ('\u{fa1b}', 2551, 1), ('\u{fa1c}', 2552, 1), ('\u{fa1d}', 2553, 1), ('\u{fa1e}', 2554, 1),
+        ('\u{fa20}', 2555, 1), ('\u{fa22}', 2556, 1), ('\u{fa25}', 2557, 1), ('\u{fa26}', 2558, 1),
+        ('\u{fa2a}', 2559, 1), ('\u{fa2b}', 2560, 1), ('\u{fa2c}', 2561, 1), ('\u{fa2d}', 2562, 1),
+        ('\u{fa2e}', 2563, 1), ('\u{fa2f}', 2564, 1), ('\u{fa30}', 2565, 1), ('\u{fa31}', 2566, 1),
+        ('\u{fa32}', 2567, 1), ('\u{fa33}', 2568, 1), ('\u{fa34}', 2569, 1), ('\u{fa35}', 2570, 1),
+        ('\u{fa36}', 2571, 1), ('\u{fa37}', 2572, 1), ('\u{fa38}', 2573, 1), ('\u{fa39}', 2574, 1),
+        ('\u{fa3a}', 2575, 1), ('\u{fa3b}', 2576, 1), ('\u{fa3c}', 2577, 1), ('\u{fa3d}', 2578, 1),
+        ('\u{fa3e}', 2579, 1), ('\u{fa3f}', 2580, 1), ('\u{fa40}', 2581, 1), ('\u{fa41}', 2582, 1),
+        ('\u{fa42}', 2583, 1), ('\u{fa43}', 2584, 1), ('\u{fa44}', 2585, 1), ('\u{fa45}', 2586, 1),
+        ('\u{fa46}', 2587, 1), ('\u{fa47}', 2588, 1), ('\u{fa48}', 2589, 1), ('\u{fa49}', 2590, 1),
+        ('\u{fa4a}', 2591, 1), ('\u{fa4b}', 2592, 1), ('\u{fa4c}', 2593, 1), ('\u{fa4d}', 2594, 1),
+        ('\u{fa4e}', 2595, 1), ('\u{fa4f}', 2596, 1), ('\u{fa50}', 2597, 1), ('\u{fa51}', 2598, 1),
+        ('\u{fa52}', 2599, 1), ('\u{fa53}', 2600, 1), ('\u{fa54}', 2601, 1), ('\u{fa55}', 2602, 1),
+        ('\u{fa56}', 2603, 1), ('\u{fa57}', 2604, 1), ('\u{fa58}', 2605, 1), ('\u{fa59}', 2606, 1),
+        ('\u{fa5a}', 2607, 1), ('\u{fa5b}', 2608, 1), ('\u{fa5c}', 2609, 1), ('\u{fa5d}', 2610, 1),
+        ('\u{fa5e}', 2611, 1), ('\u{fa5f}', 2612, 1), ('\u{fa60}', 2613, 1), ('\u{fa61}', 2614, 1),
+        ('\u{fa62}', 2615, 1), ('\u{fa63}', 2616, 1), ('\u{fa64}', 2617, 1), ('\u{fa65}', 2618, 1),
+        ('\u{fa66}', 2619, 1), ('\u{fa67}', 2620, 1), ('\u{fa68}', 2621, 1), ('\u{fa69}', 2622, 1),
+        ('\u{fa6a}', 2623, 1), ('\u{fa6b}', 2624, 1), ('\u{fa6c}', 2625, 1), ('\u{fa6d}', 2626, 1),
+        ('\u{fa70}', 2627, 1), ('\u{fa71}', 2628, 1), ('\u{fa72}', 2629, 1), ('\u{fa73}', 2630, 1),
+        ('\u{fa74}', 2631, 1), ('\u{fa75}', 2632, 1), ('\u{fa76}', 2633, 1), ('\u{fa77}', 2634, 1),
+        ('\u{fa78}', 2635, 1), ('\u{fa79}', 2636, 1), ('\u{fa7a}', 2637, 1), ('\u{fa7b}', 2638, 1),
+        ('\u{fa7c}', 2639, 1), ('\u{fa7d}', 2640, 1), ('\u{fa7e}', 2641, 1), ('\u{fa7f}', 2642, 1),
+        ('\u{fa80}', 2643, 1), ('\u{fa81}', 2644, 1), ('\u{fa82}', 2645, 1), ('\u{fa83}', 2646, 1),
+        ('\u{fa84}', 2647, 1), ('\u{fa85}', 2648, 1), ('\u{fa86}', 2649, 1), ('\u{fa87}', 2650, 1),
+        ('\u{fa88}', 2651, 1), ('\u{fa89}', 2652, 1), ('\u{fa8a}', 2653, 1), ('\u{fa8b}', 2654, 1),
+        ('\u{fa8c}', 2655, 1), ('\u{fa8d}', 2656, 1), ('\u{fa8e}', 2657, 1), ('\u{fa8f}', 2658, 1),
+        ('\u{fa90}', 2659, 1), ('\u{fa91}', 2660, 1), ('\u{fa92}', 2661, 1), ('\u{fa93}', 2662, 1),
+        ('\u{fa94}', 2663, 1), ('\u{fa95}', 2664, 1), ('\u{fa96}', 2665, 1), ('\u{fa97}', 2666, 1),
+        ('\u{fa98}', 2667, 1), ('\u{fa99}', 2668, 1), ('\u{fa9a}', 2669, 1), ('\u{fa9b}', 2670, 1),
+        ('\u{fa9c}', 2671, 1), ('\u{fa9d}', 2672, 1), ('\u{fa9e}', 2673, 1), ('\u{fa9f}', 2674, 1),
+        ('\u{faa0}', 2675, 1), ('\u{faa1}', 2676, 1), ('\u{faa2}', 2677, 1), ('\u{faa3}', 2678, 1),
+        ('\u{faa4}', 2679, 1), ('\u{faa5}', 2680, 1), ('\u{faa6}', 2681, 1), ('\u{faa7}', 2682, 1),
+        ('\u{faa8}', 2683, 1), ('\u{faa9}', 2684, 1), ('\u{faaa}', 2685, 1), ('\u{faab}', 2686, 1),
+        ('\u{faac}', 2687, 1), ('\u{faad}', 2688, 1), ('\u{faae}', 2689, 1), ('\u{faaf}', 2690, 1),
+        ('\u{fab0}', 2691, 1), ('\u{fab1}', 2692, 1), ('\u{fab2}', 2693, 1), ('\u{fab3}', 2694, 1),
+        ('\u{fab4}', 2695, 1), ('\u{fab5}', 2696, 1), ('\u{fab6}', 2697, 1), ('\u{fab7}', 2698, 1),
+        ('\u{fab8}', 2699, 1), ('\u{fab9}', 2700, 1), ('\u{faba}', 2701, 1), ('\u{fabb}', 2702, 1),
+        ('\u{fabc}', 2703, 1), ('\u{fabd}', 2704, 1), ('\u{fabe}', 2705, 1), ('\u{fabf}', 2706, 1),
+        ('\u{fac0}', 2707, 1), ('\u{fac1}', 2708, 1), ('\u{fac2}', 2709, 1), ('\u{fac3}', 2710, 1),
+        ('\u{fac4}', 2711, 1), ('\u{fac5}', 2712, 1), ('\u{fac6}', 2713, 1), ('\u{fac7}', 2714, 1),
+        ('\u{fac8}', 2715, 1), ('\u{fac9}', 2716, 1), ('\u{faca}', 2717, 1), ('\u{facb}', 2718, 1),
+        ('\u{facc}', 2719, 1), ('\u{facd}', 2720, 1), ('\u{face}', 2721, 1), ('\u{facf}', 2722, 1),
+        ('\u{fad0}', 2723, 1), ('\u{fad1}', 2724, 1), ('\u{fad2}', 2725, 1), ('\u{fad3}', 2726, 1),
+        ('\u{fad4}', 2727, 1), ('\u{fad5}', 2728, 1), ('\u{fad6}', 2729, 1), ('\u{fad7}', 2730, 1),
+        ('\u{fad8}', 2731, 1), ('\u{fad9}', 2732, 1), ('\u{fb1d}', 2733, 2), ('\u{fb1f}', 2735, 2),
+        ('\u{fb2a}', 2737, 2), ('\u{fb2b}', 2739, 2), ('\u{fb2c}', 2741, 3), ('\u{fb2d}', 2744, 3),
+        ('\u{fb2e}', 2747, 2), ('\u{fb2f}', 2749, 2), ('\u{fb30}', 2751, 2), ('\u{fb31}', 2753, 2),
+        ('\u{fb32}', 2755, 2), ('\u{fb33}', 2757, 2), ('\u{fb34}', 2759, 2), ('\u{fb35}', 2761, 2),
+        ('\u{fb36}', 2763, 2), ('\u{fb38}', 2765, 2), ('\u{fb39}', 2767, 2), ('\u{fb3a}', 2769, 2),
+        ('\u{fb3b}', 2771, 2), ('\u{fb3c}', 2773, 2), ('\u{fb3e}', 2775, 2), ('\u{fb40}', 2777, 2),
+        ('\u{fb41}', 2779, 2), ('\u{fb43}', 2781, 2), ('\u{fb44}', 2783, 2), ('\u{fb46}', 2785, 2),
+        ('\u{fb47}', 2787, 2), ('\u{fb48}', 2789, 2), ('\u{fb49}', 2791, 2), ('\u{fb4a}', 2793, 2),
+        ('\u{fb4b}', 2795, 2), ('\u{fb4c}', 2797, 2), ('\u{fb4d}', 2799, 2), ('\u{fb4e}', 2801, 2),
+        ('\u{105c9}', 2803, 2), ('\u{105e4}', 2805, 2), ('\u{1109a}', 2807, 2),
+        ('\u{1109c}', 2809, 2), ('\u{110ab}', 2811, 2), ('\u{1112e}', 2813, 2),
+        ('\u{1112f}', 2815, 2), ('\u{1134b}', 2817, 2), ('\u{1134c}', 2819, 2),
+        ('\u{11383}', 2821, 2), ('\u{11385}', 2823, 2), ('\u{1138e}', 2825, 2),
+        ('\u{11391}', 2827, 2), ('\u{113c5}', 2829, 2), ('\u{113c7}', 2831, 2),
+        ('\u{113c8}', 2833, 2), ('\u{114bb}', 2835, 2), ('\u{114bc}', 2837, 2),
+        ('\u{114be}', 2839, 2), ('\u{115ba}', 2841, 2), ('\u{115bb}', 2843, 2),
+        ('\u{11938}', 2845, 2), ('\u{16121}', 2847, 2), ('\u{16122}', 2849, 2),
+        ('\u{16123}', 2851, 2), ('\u{16124}', 2853, 2), ('\u{16125}', 2855, 2),
+        ('\u{16126}', 2857, 3), ('\u{16127}', 2860, 3), ('\u{16128}', 2863, 3),
+        ('\u{16d68}', 2866, 2), ('\u{16d69}', 2868, 2), ('\u{16d6a}', 2870, 3),
+        ('\u{1d15e}', 2873, 2), ('\u{1d15f}', 2875, 2), ('\u{1d160}', 2877, 3),
+        ('\u{1d161}', 2880, 3), ('\u{1d162}', 2883, 3), ('\u{1d163}', 2886, 3),
+        ('\u{1d164}', 2889, 3), ('\u{1d1bb}', 2892, 2), ('\u{1d1bc}', 2894, 2),
+        ('\u{1d1bd}', 2896, 3), ('\u{1d1be}', 2899, 3), ('\u{1d1bf}', 2902, 3),
+        ('\u{1d1c0}', 2905, 3), ('\u{2f800}', 2908, 1), ('\u{2f801}', 2909, 1),
+        ('\u{2f802}', 2910, 1), ('\u{2f803}', 2911, 1), ('\u{2f804}', 2912, 1),
+        ('\u{2f805}', 2913, 1), ('\u{2f806}', 2914, 1), ('\u{2f807}', 2915, 1),
+        ('\u{2f808}', 2916, 1), ('\u{2f809}', 2917, 1), ('\u{2f80a}', 2918, 1),
+        ('\u{2f80b}', 2919, 1), ('\u{2f80c}', 2920, 1), ('\u{2f80d}', 2921, 1),
+        ('\u{2f80e}', 2922, 1), ('\u{2f80f}', 2923, 1), ('\u{2f810}', 2924, 1),
+        ('\u{2f811}', 2925, 1), ('\u{2f812}', 2926, 1), ('\u{2f813}', 2927, 1),
+        ('\u{2f814}', 2928, 1), ('\u{2f815}', 2929, 1), ('\u{2f816}', 2930, 1),
+        ('\u{2f817}', 2931, 1), ('\u{2f818}', 2932, 1), ('\u{2f819}', 2933, 1),
+        ('\u{2f81a}', 2934, 1), ('\u{2f81b}', 2935, 1), ('\u{2f81c}', 2936, 1),
+        ('\u{2f81d}', 2937, 1), ('\u{2f81e}', 2938, 1), ('\u{2f81f}', 2939, 1),
+        ('\u{2f820}', 2940, 1), ('\u{2f821}', 2941, 1), ('\u{2f822}', 2942, 1),
+        ('\u{2f823}', 2943, 1), ('\u{2f824}', 2944, 1), ('\u{2f825}', 2945, 1),
+        ('\u{2f826}', 2946, 1), ('\u{2f827}', 2947, 1), ('\u{2f828}', 2948, 1),
+        ('\u{2f829}', 2949, 1), ('\u{2f82a}', 2950, 1), ('\u{2f82b}', 2951, 1),
+        ('\u{2f82c}', 2952, 1), ('\u{2f82d}', 2953, 1), ('\u{2f82e}', 2954, 1),
+        ('\u{2f82f}', 2955, 1), ('\u{2f830}', 2956, 1), ('\u{2f831}', 2957, 1),
+        ('\u{2f832}', 2958, 1), ('\u{2f833}', 2959, 1), ('\u{2f834}', 2960, 1),
+        ('\u{2f835}', 2961, 1), ('\u{2f836}', 2962, 1), ('\u{2f837}', 2963, 1),
+        ('\u{2f838}', 2964, 1), ('\u{2f839}', 2965, 1), ('\u{2f83a}', 2966, 1),
+        ('\u{2f83b}', 2967, 1), ('\u{2f83c}', 2968, 1), ('\u{2f83d}', 2969, 1),
+        ('\u{2f83e}', 2970, 1), ('\u{2f83f}', 2971, 1), ('\u{2f840}', 2972, 1),
+        ('\u{2f841}', 2973, 1), ('\u{2f842}', 2974, 1), ('\u{2f843}', 2975, 1),
+        ('\u{2f844}', 2976, 1), ('\u{2f845}', 2977, 1), ('\u{2f846}', 2978, 1),
+        ('\u{2f847}', 2979, 1), ('\u{2f848}', 2980, 1), ('\u{2f849}', 2981, 1),
+        ('\u{2f84a}', 2982, 1), ('\u{2f84b}', 2983, 1), ('\u{2f84c}', 2984, 1),
+        ('\u{2f84d}', 2985, 1), ('\u{2f84e}', 2986, 1), ('\u{2f84f}', 2987, 1),
+        ('\u{2f850}', 2988, 1), ('\u{2f851}', 2989, 1), ('\u{2f852}', 2990, 1),
+        ('\u{2f853}', 2991, 1), ('\u{2f854}', 2992, 1), ('\u{2f855}', 2993, 1),
+        ('\u{2f856}', 2994, 1), ('\u{2f857}', 2995, 1), ('\u{2f858}', 2996, 1),
+        ('\u{2f859}', 2997, 1), ('\u{2f85a}', 2998, 1), ('\u{2f85b}', 2999, 1),
+        ('\u{2f85c}', 3000, 1), ('\u{2f85d}', 3001, 1), ('\u{2f85e}', 3002, 1),
+        ('\u{2f85f}', 3003, 1), ('\u{2f860}', 3004, 1), ('\u{2f861}', 3005, 1),
+        ('\u{2f862}', 3006, 1), ('\u{2f863}', 3007, 1), ('\u{2f864}', 3008, 1),
+        ('\u{2f865}', 3009, 1), ('\u{2f866}', 3010, 1), ('\u{2f867}', 3011, 1),
+        ('\u{2f868}', 3012, 1), ('\u{2f869}', 3013, 1), ('\u{2f86a}', 3014, 1),
+        ('\u{2f86b}', 3015, 1), ('\u{2f86c}', 3016, 1), ('\u{2f86d}', 3017, 1),
+        ('\u{2f86e}', 3018, 1), ('\u{2f86f}', 3019, 1), ('\u{2f870}', 3020, 1),
+        ('\u{2f871}', 3021, 1), ('\u{2f872}', 3022, 1), ('\u{2f873}', 3023, 1),
+        ('\u{2f874}', 3024, 1), ('\u{2f875}', 3025, 1), ('\u{2f876}', 3026, 1),
+        ('\u{2f877}', 3027, 1), ('\u{2f878}', 3028, 1), ('\u{2f879}', 3029, 1),
+        ('\u{2f87a}', 3030, 1), ('\u{2f87b}', 3031, 1), ('\u{2f87c}', 3032, 1),
+        ('\u{2f87d}', 3033, 1), ('\u{2f87e}', 3034, 1), ('\u{2f87f}', 3035, 1),
+        ('\u{2f880}', 3036, 1), ('\u{2f881}', 3037, 1), ('\u{2f882}', 3038, 1),
+        ('\u{2f883}', 3039, 1), ('\u{2f884}', 3040, 1), ('\u{2f885}', 3041, 1),
+        ('\u{2f886}', 3042, 1), ('\u{2f887}', 3043, 1), ('\u{2f888}', 3044, 1),
+        ('\u{2f889}', 3045, 1), ('\u{2f88a}', 3046, 1), ('\u{2f88b}', 3047, 1),
+        ('\u{2f88c}', 3048, 1), ('\u{2f88d}', 3049, 1), ('\u{2f88e}', 3050, 1),
+        ('\u{2f88f}', 3051, 1), ('\u{2f890}', 3052, 1), ('\u{2f891}', 3053, 1),
+        ('\u{2f892}', 3054, 1), ('\u{2f893}', 3055, 1), ('\u{2f894}', 3056, 1),
+        ('\u{2f895}', 3057, 1), ('\u{2f896}', 3058, 1), ('\u{2f897}', 3059, 1),
+        ('\u{2f898}', 3060, 1), ('\u{2f899}', 3061, 1), ('\u{2f89a}', 3062, 1),
+        ('\u{2f89b}', 3063, 1), ('\u{2f89c}', 3064, 1), ('\u{2f89d}', 3065, 1),
+        ('\u{2f89e}', 3066, 1), ('\u{2f89f}', 3067, 1), ('\u{2f8a0}', 3068, 1),
+        ('\u{2f8a1}', 3069, 1), ('\u{2f8a2}', 3070, 1), ('\u{2f8a3}', 3071, 1),
+        ('\u{2f8a4}', 3072, 1), ('\u{2f8a5}', 3073, 1), ('\u{2f8a6}', 3074, 1),
+        ('\u{2f8a7}', 3075, 1), ('\u{2f8a8}', 3076, 1), ('\u{2f8a9}', 3077, 1),
+        ('\u{2f8aa}', 3078, 1), ('\u{2f8ab}', 3079, 1), ('\u{2f8ac}', 3080, 1),
+        ('\u{2f8ad}', 3081, 1), ('\u{2f8ae}', 3082, 1), ('\u{2f8af}', 3083, 1),
+        ('\u{2f8b0}', 3084, 1), ('\u{2f8b1}', 3085, 1), ('\u{2f8b2}', 3086, 1),
+        ('\u{2f8b3}', 3087, 1), ('\u{2f8b4}', 3088, 1), ('\u{2f8b5}', 3089, 1),
+        ('\u{2f8b6}', 3090, 1), ('\u{2f8b7}', 3091, 1), ('\u{2f8b8}', 3092, 1),
+        ('\u{2f8b9}', 3093, 1), ('\u{2f8ba}', 3094, 1), ('\u{2f8bb}', 3095, 1),
+        ('\u{2f8bc}', 3096, 1), ('\u{2f8bd}', 3097, 1), ('\u{2f8be}', 3098, 1),
+        ('\u{2f8bf}', 3099, 1), ('\u{2f8c0}', 3100, 1), ('\u{2f8c1}', 3101, 1),
+        ('\u{2f8c2}', 3102, 1), ('\u{2f8c3}', 3103, 1), ('\u{2f8c4}', 3104, 1),
+        ('\u{2f8c5}', 3105, 1), ('\u{2f8c6}', 3106, 1), ('\u{2f8c7}', 3107, 1),
+        ('\u{2f8c8}', 3108, 1), ('\u{2f8c9}', 3109, 1), ('\u{2f8ca}', 3110, 1),
+        ('\u{2f8cb}', 3111, 1), ('\u{2f8cc}', 3112, 1), ('\u{2f8cd}', 3113, 1),
+        ('\u{2f8ce}', 3114, 1), ('\u{2f8cf}', 3115, 1), ('\u{2f8d0}', 3116, 1),
+        ('\u{2f8d1}', 3117, 1), ('\u{2f8d2}', 3118, 1), ('\u{2f8d3}', 3119, 1),
+        ('\u{2f8d4}', 3120, 1), ('\u{2f8d5}', 3121, 1), ('\u{2f8d6}', 3122, 1),
+        ('\u{2f8d7}', 3123, 1), ('\u{2f8d8}', 3124, 1), ('\u{2f8d9}', 3125, 1),
+        ('\u{2f8da}', 3126, 1), ('\u{2f8db}', 3127, 1), ('\u{2f8dc}', 3128, 1),
+        ('\u{2f8dd}', 3129, 1), ('\u{2f8de}', 3130, 1), ('\u{2f8df}', 3131, 1),
+        ('\u{2f8e0}', 3132, 1), ('\u{2f8e1}', 3133, 1), ('\u{2f8e2}', 3134, 1),
+        ('\u{2f8e3}', 3135, 1), ('\u{2f8e4}', 3136, 1), ('\u{2f8e5}', 3137, 1),
+        ('\u{2f8e6}', 3138, 1), ('\u{2f8e7}', 3139, 1), ('\u{2f8e8}', 3140, 1),
+        ('\u{2f8e9}', 3141, 1), ('\u{2f8ea}', 3142, 1), ('\u{2f8eb}', 3143, 1),
+        ('\u{2f8ec}', 3144, 1), ('\u{2f8ed}', 3145, 1), ('\u{2f8ee}', 3146, 1),
+        ('\u{2f8ef}', 3147, 1), ('\u{2f8f0}', 3148, 1), ('\u{2f8f1}', 3149, 1),
+        ('\u{2f8f2}', 3150, 1), ('\u{2f8f3}', 3151, 1), ('\u{2f8f4}', 3152, 1),
+        ('\u{2f8f5}', 3153, 1), ('\u{2f8f6}', 3154, 1), ('\u{2f8f7}', 3155, 1),
+        ('\u{2f8f8}', 3156, 1), ('\u{2f8f9}', 3157, 1), ('\u{2f8fa}', 3158, 1),
+        ('\u{2f8fb}', 3159, 1), ('\u{2f8fc}', 3160, 1), ('\u{2f8fd}', 3161, 1),
+        ('\u{2f8fe}', 3162, 1), ('\u{2f8ff}', 3163, 1), ('\u{2f900}', 3164, 1),
+        ('\u{2f901}', 3165, 1), ('\u{2f902}', 3166, 1), ('\u{2f903}', 3167, 1),
+        ('\u{2f904}', 3168, 1), ('\u{2f905}', 3169, 1), ('\u{2f906}', 3170, 1),
+        ('\u{2f907}', 3171, 1), ('\u{2f908}', 3172, 1), ('\u{2f909}', 3173, 1),
+        ('\u{2f90a}', 3174, 1), ('\u{2f90b}', 3175, 1), ('\u{2f90c}', 3176, 1),
+        ('\u{2f90d}', 3177, 1), ('\u{2f90e}', 3178, 1), ('\u{2f90f}', 3179, 1),
+        ('\u{2f910}', 3180, 1), ('\u{2f911}', 3181, 1), ('\u{2f912}', 3182, 1),
+        ('\u{2f913}', 3183, 1), ('\u{2f914}', 3184, 1), ('\u{2f915}', 3185, 1),
+        ('\u{2f916}', 3186, 1), ('\u{2f917}', 3187, 1), ('\u{2f918}', 3188, 1),
+        ('\u{2f919}', 3189, 1), ('\u{2f91a}', 3190, 1), ('\u{2f91b}', 3191, 1),
+        ('\u{2f91c}', 3192, 1), ('\u{2f91d}', 3193, 1), ('\u{2f91e}', 3194, 1),
+        ('\u{2f91f}', 3195, 1), ('\u{2f920}', 3196, 1), ('\u{2f921}', 3197, 1),
+        ('\u{2f922}', 3198, 1), ('\u{2f923}', 3199, 1), ('\u{2f924}', 3200, 1),
+        ('\u{2f925}', 3201, 1), ('\u{2f926}', 3202, 1), ('\u{2f927}', 3203, 1),
+        ('\u{2f928}', 3204, 1), ('\u{2f929}', 3205, 1), ('\u{2f92a}', 3206, 1),
+        ('\u{2f92b}', 3207, 1), ('\u{2f92c}', 3208, 1), ('\u{2f92d}', 3209, 1),
+        ('\u{2f92e}', 3210, 1), ('\u{2f92f}', 3211, 1), ('\u{2f930}', 3212, 1),
+        ('\u{2f931}', 3213, 1), ('\u{2f932}', 3214, 1), ('\u{2f933}', 3215, 1),
+        ('\u{2f934}', 3216, 1), ('\u{2f935}', 3217, 1), ('\u{2f936}', 3218, 1),
+        ('\u{2f937}', 3219, 1), ('\u{2f938}', 3220, 1), ('\u{2f939}', 3221, 1),
+        ('\u{2f93a}', 3222, 1), ('\u{2f93b}', 3223, 1), ('\u{2f93c}', 3224, 1),
+        ('\u{2f93d}', 3225, 1), ('\u{2f93e}', 3226, 1), ('\u{2f93f}', 3227, 1),
+        ('\u{2f940}', 3228, 1), ('\u{2f941}', 3229, 1), ('\u{2f942}', 3230, 1),
+        ('\u{2f943}', 3231, 1), ('\u{2f944}', 3232, 1), ('\u{2f945}', 3233, 1),
+        ('\u{2f946}', 3234, 1), ('\u{2f947}', 3235, 1), ('\u{2f948}', 3236, 1),
+        ('\u{2f949}', 3237, 1), ('\u{2f94a}', 3238, 1), ('\u{2f94b}', 3239, 1),
+        ('\u{2f94c}', 3240, 1), ('\u{2f94d}', 3241, 1), ('\u{2f94e}', 3242, 1),
+        ('\u{2f94f}', 3243, 1), ('\u{2f950}', 3244, 1), ('\u{2f951}', 3245, 1),
+        ('\u{2f952}', 3246, 1), ('\u{2f953}', 3247, 1), ('\u{2f954}', 3248, 1),
+        ('\u{2f955}', 3249, 1), ('\u{2f956}', 3250, 1), ('\u{2f957}', 3251, 1),
+        ('\u{2f958}', 3252, 1), ('\u{2f959}', 3253, 1), ('\u{2f95a}', 3254, 1),
+        ('\u{2f95b}', 3255, 1), ('\u{2f95c}', 3256, 1), ('\u{2f95d}', 3257, 1),
+        ('\u{2f95e}', 3258, 1), ('\u{2f95f}', 3259, 1), ('\u{2f960}', 3260, 1),
+        ('\u{2f961}', 3261, 1), ('\u{2f962}', 3262, 1), ('\u{2f963}', 3263, 1),
+        ('\u{2f964}', 3264, 1), ('\u{2f965}', 3265, 1), ('\u{2f966}', 3266, 1),
+        ('\u{2f967}', 3267, 1), ('\u{2f968}', 3268, 1), ('\u{2f969}', 3269, 1),
+        ('\u{2f96a}', 3270, 1), ('\u{2f96b}', 3271, 1), ('\u{2f96c}', 3272, 1),
+        ('\u{2f96d}', 3273, 1), ('\u{2f96e}', 3274, 1), ('\u{2f96f}', 3275, 1),
+        ('\u{2f970}', 3276, 1), ('\u{2f971}', 3277, 1), ('\u{2f972}', 3278, 1),
+        ('\u{2f973}', 3279, 1), ('\u{2f974}', 3280, 1), ('\u{2f975}', 3281, 1),
+        ('\u{2f976}', 3282, 1), ('\u{2f977}', 3283, 1), ('\u{2f978}', 3284, 1),
+        ('\u{2f979}', 3285, 1), ('\u{2f97a}', 3286, 1), ('\u{2f97b}', 3287, 1),
+        ('\u{2f97c}', 3288, 1), ('\u{2f97d}', 3289, 1), ('\u{2f97e}', 3290, 1),
+        ('\u{2f97f}', 3291, 1), ('\u{2f980}', 3292, 1), ('\u{2f981}', 3293, 1),
+        ('\u{2f982}', 3294, 1), ('\u{2f983}', 3295, 1), ('\u{2f984}', 3296, 1),
+        ('\u{2f985}', 3297, 1), ('\u{2f986}', 3298, 1), ('\u{2f987}', 3299, 1),
+        ('\u{2f988}', 3300, 1), ('\u{2f989}', 3301, 1), ('\u{2f98a}', 3302, 1),
+        ('\u{2f98b}', 3303, 1), ('\u{2f98c}', 3304, 1), ('\u{2f98d}', 3305, 1),
+        ('\u{2f98e}', 3306, 1), ('\u{2f98f}', 3307, 1), ('\u{2f990}', 3308, 1),
+        ('\u{2f991}', 3309, 1), ('\u{2f992}', 3310, 1), ('\u{2f993}', 3311, 1),
+        ('\u{2f994}', 3312, 1), ('\u{2f995}', 3313, 1), ('\u{2f996}', 3314, 1),
+        ('\u{2f997}', 3315, 1), ('\u{2f998}', 3316, 1), ('\u{2f999}', 3317, 1),
+        ('\u{2f99a}', 3318, 1), ('\u{2f99b}', 3319, 1), ('\u{2f99c}', 3320, 1),
+        ('\u{2f99d}', 3321, 1), ('\u{2f99e}', 3322, 1), ('\u{2f99f}', 3323, 1),
+        ('\u{2f9a0}', 3324, 1), ('\u{2f9a1}', 3325, 1), ('\u{2f9a2}', 3326, 1),
+        ('\u{2f9a3}', 3327, 1), ('\u{2f9a4}', 3328, 1), ('\u{2f9a5}', 3329, 1),
+        ('\u{2f9a6}', 3330, 1), ('\u{2f9a7}', 3331, 1), ('\u{2f9a8}', 3332, 1),
+        ('\u{2f9a9}', 3333, 1), ('\u{2f9aa}', 3334, 1), ('\u{2f9ab}', 3335, 1),
+        ('\u{2f9ac}', 3336, 1), ('\u{2f9ad}', 3337, 1), ('\u{2f9ae}', 3338, 1),
+        ('\u{2f9af}', 3339, 1), ('\u{2f9b0}', 3340, 1), ('\u{2f9b1}', 3341, 1),
+        ('\u{2f9b2}', 3342, 1), ('\u{2f9b3}', 3343, 1), ('\u{2f9b4}', 3344, 1),
+        ('\u{2f9b5}', 3345, 1), ('\u{2f9b6}', 3346, 1), ('\u{2f9b7}', 3347, 1),
+        ('\u{2f9b8}', 3348, 1), ('\u{2f9b9}', 3349, 1), ('\u{2f9ba}', 3350, 1),
+        ('\u{2f9bb}', 3351, 1), ('\u{2f9bc}', 3352, 1), ('\u{2f9bd}', 3353, 1),
+        ('\u{2f9be}', 3354, 1), ('\u{2f9bf}', 3355, 1), ('\u{2f9c0}', 3356, 1),
+        ('\u{2f9c1}', 3357, 1), ('\u{2f9c2}', 3358, 1), ('\u{2f9c3}', 3359, 1),
+        ('\u{2f9c4}', 3360, 1), ('\u{2f9c5}', 3361, 1), ('\u{2f9c6}', 3362, 1),
+        ('\u{2f9c7}', 3363, 1), ('\u{2f9c8}', 3364, 1), ('\u{2f9c9}', 3365, 1),
+        ('\u{2f9ca}', 3366, 1), ('\u{2f9cb}', 3367, 1), ('\u{2f9cc}', 3368, 1),
+        ('\u{2f9cd}', 3369, 1), ('\u{2f9ce}', 3370, 1), ('\u{2f9cf}', 3371, 1),
+        ('\u{2f9d0}', 3372, 1), ('\u{2f9d1}', 3373, 1), ('\u{2f9d2}', 3374, 1),
+        ('\u{2f9d3}', 3375, 1), ('\u{2f9d4}', 3376, 1), ('\u{2f9d5}', 3377, 1),
+        ('\u{2f9d6}', 3378, 1), ('\u{2f9d7}', 3379, 1), ('\u{2f9d8}', 3380, 1),
+        ('\u{2f9d9}', 3381, 1), ('\u{2f9da}', 3382, 1), ('\u{2f9db}', 3383, 1),
+        ('\u{2f9dc}', 3384, 1), ('\u{2f9dd}', 3385, 1), ('\u{2f9de}', 3386, 1),
+        ('\u{2f9df}', 3387, 1), ('\u{2f9e0}', 3388, 1), ('\u{2f9e1}', 3389, 1),
+        ('\u{2f9e2}', 3390, 1), ('\u{2f9e3}', 3391, 1), ('\u{2f9e4}', 3392, 1),
+        ('\u{2f9e5}', 3393, 1), ('\u{2f9e6}', 3394, 1), ('\u{2f9e7}', 3395, 1),
+        ('\u{2f9e8}', 3396, 1), ('\u{2f9e9}', 3397, 1), ('\u{2f9ea}', 3398, 1),
+        ('\u{2f9eb}', 3399, 1), ('\u{2f9ec}', 3400, 1), ('\u{2f9ed}', 3401, 1),
+        ('\u{2f9ee}', 3402, 1), ('\u{2f9ef}', 3403, 1), ('\u{2f9f0}', 3404, 1),
+        ('\u{2f9f1}', 3405, 1), ('\u{2f9f2}', 3406, 1), ('\u{2f9f3}', 3407, 1),
+        ('\u{2f9f4}', 3408, 1), ('\u{2f9f5}', 3409, 1), ('\u{2f9f6}', 3410, 1),
+        ('\u{2f9f7}', 3411, 1), ('\u{2f9f8}', 3412, 1), ('\u{2f9f9}', 3413, 1),
+        ('\u{2f9fa}', 3414, 1), ('\u{2f9fb}', 3415, 1), ('\u{2f9fc}', 3416, 1),
+        ('\u{2f9fd}', 3417, 1), ('\u{2f9fe}', 3418, 1), ('\u{2f9ff}', 3419, 1),
+        ('\u{2fa00}', 3420, 1), ('\u{2fa01}', 3421, 1), ('\u{2fa02}', 3422, 1),
+        ('\u{2fa03}', 3423, 1), ('\u{2fa04}', 3424, 1), ('\u{2fa05}', 3425, 1),
+        ('\u{2fa06}', 3426, 1), ('\u{2fa07}', 3427, 1), ('\u{2fa08}', 3428, 1),
+        ('\u{2fa09}', 3429, 1), ('\u{2fa0a}', 3430, 1), ('\u{2fa0b}', 3431, 1),
+        ('\u{2fa0c}', 3432, 1), ('\u{2fa0d}', 3433, 1), ('\u{2fa0e}', 3434, 1),
+        ('\u{2fa0f}', 3435, 1), ('\u{2fa10}', 3436, 1), ('\u{2fa11}', 3437, 1),
+        ('\u{2fa12}', 3438, 1), ('\u{2fa13}', 3439, 1), ('\u{2fa14}', 3440, 1),
+        ('\u{2fa15}', 3441, 1), ('\u{2fa16}', 3442, 1), ('\u{2fa17}', 3443, 1),
+        ('\u{2fa18}', 3444, 1), ('\u{2fa19}', 3445, 1), ('\u{2fa1a}', 3446, 1),
+        ('\u{2fa1b}', 3447, 1), ('\u{2fa1c}', 3448, 1), ('\u{2fa1d}', 3449, 1),
+    ];
+
+    static CANONICAL_DECOMPOSED_CHARS: &[char] = &[
+        'A', '\u{300}', 'A', '\u{301}', 'A', '\u{302}', 'A', '\u{303}', 'A', '\u{308}', 'A',
+        '\u{30a}', 'C', '\u{327}', 'E', '\u{300}', 'E', '\u{301}', 'E', '\u{302}', 'E', '\u{308}',
+        'I', '\u{300}', 'I', '\u{301}', 'I', '\u{302}', 'I', '\u{308}', 'N', '\u{303}', 'O',
+        '\u{300}', 'O', '\u{301}', 'O', '\u{302}', 'O', '\u{303}', 'O', '\u{308}', 'U', '\u{300}',
+        'U', '\u{301}', 'U', '\u{302}', 'U', '\u{308}', 'Y', '\u{301}', 'a', '\u{300}', 'a',
+        '\u{301}', 'a', '\u{302}', 'a', '\u{303}', 'a', '\u{308}', 'a', '\u{30a}', 'c', '\u{327}',
+        'e', '\u{300}', 'e', '\u{301}', 'e', '\u{302}', 'e', '\u{308}', 'i', '\u{300}', 'i',
+        '\u{301}', 'i', '\u{302}', 'i', '\u{308}', 'n', '\u{303}', 'o', '\u{300}', 'o', '\u{301}',
+        'o', '\u{302}', 'o', '\u{303}', 'o', '\u{308}', 'u', '\u{300}', 'u', '\u{301}', 'u',
+        '\u{302}', 'u', '\u{308}', 'y', '\u{301}', 'y', '\u{308}', 'A', '\u{304}', 'a', '\u{304}',
+        'A', '\u{306}', 'a', '\u{306}', 'A', '\u{328}', 'a', '\u{328}', 'C', '\u{301}', 'c',
+        '\u{301}', 'C', '\u{302}', 'c', '\u{302}', 'C', '\u{307}', 'c', '\u{307}', 'C', '\u{30c}',
+        'c', '\u{30c}', 'D', '\u{30c}', 'd', '\u{30c}', 'E', '\u{304}', 'e', '\u{304}', 'E',
+        '\u{306}', 'e', '\u{306}', 'E', '\u{307}', 'e', '\u{307}', 'E', '\u{328}', 'e', '\u{328}',
+        'E', '\u{30c}', 'e', '\u{30c}', 'G', '\u{302}', 'g', '\u{302}', 'G', '\u{306}', 'g',
+        '\u{306}', 'G', '\u{307}', 'g', '\u{307}', 'G', '\u{327}', 'g', '\u{327}', 'H', '\u{302}',
+        'h', '\u{302}', 'I', '\u{303}', 'i', '\u{303}', 'I', '\u{304}', 'i', '\u{304}', 'I',
+        '\u{306}', 'i', '\u{306}', 'I', '\u{328}', 'i', '\u{328}', 'I', '\u{307}', 'J', '\u{302}',
+        'j', '\u{302}', 'K', '\u{327}', 'k', '\u{327}', 'L', '\u{301}', 'l', '\u{301}', 'L',
+        '\u{327}', 'l', '\u{327}', 'L', '\u{30c}', 'l', '\u{30c}', 'N', '\u{301}', 'n', '\u{301}',
+        'N', '\u{327}', 'n', '\u{327}', 'N', '\u{30c}', 'n', '\u{30c}', 'O', '\u{304}', 'o',
+        '\u{304}', 'O', '\u{306}', 'o', '\u{306}', 'O', '\u{30b}', 'o', '\u{30b}', 'R', '\u{301}',
+        'r', '\u{301}', 'R', '\u{327}', 'r', '\u{327}', 'R', '\u{30c}', 'r', '\u{30c}', 'S',
+        '\u{301}', 's', '\u{301}', 'S', '\u{302}', 's', '\u{302}', 'S', '\u{327}', 's', '\u{327}',
+        'S', '\u{30c}', 's', '\u{30c}', 'T', '\u{327}', 't', '\u{327}', 'T', '\u{30c}', 't',
+        '\u{30c}', 'U', '\u{303}', 'u', '\u{303}', 'U', '\u{304}', 'u', '\u{304}', 'U', '\u{306}',
+        'u', '\u{306}', 'U', '\u{30a}', 'u', '\u{30a}', 'U', '\u{30b}', 'u', '\u{30b}', 'U',
+        '\u{328}', 'u', '\u{328}', 'W', '\u{302}', 'w', '\u{302}', 'Y', '\u{302}', 'y', '\u{302}',
+        'Y', '\u{308}', 'Z', '\u{301}', 'z', '\u{301}', 'Z', '\u{307}', 'z', '\u{307}', 'Z',
+        '\u{30c}', 'z', '\u{30c}', 'O', '\u{31b}', 'o', '\u{31b}', 'U', '\u{31b}', 'u', '\u{31b}',
+        'A', '\u{30c}', 'a', '\u{30c}', 'I', '\u{30c}', 'i', '\u{30c}', 'O', '\u{30c}', 'o',
+        '\u{30c}', 'U', '\u{30c}', 'u', '\u{30c}', 'U', '\u{308}', '\u{304}', 'u', '\u{308}',
+        '\u{304}', 'U', '\u{308}', '\u{301}', 'u', '\u{308}', '\u{301}', 'U', '\u{308}', '\u{30c}',
+        'u', '\u{308}', '\u{30c}', 'U', '\u{308}', '\u{300}', 'u', '\u{308}', '\u{300}', 'A',
+        '\u{308}', '\u{304}', 'a', '\u{308}', '\u{304}', 'A', '\u{307}', '\u{304}', 'a', '\u{307}',
+        '\u{304}', '\u{c6}', '\u{304}', '\u{e6}', '\u{304}', 'G', '\u{30c}', 'g', '\u{30c}', 'K',
+        '\u{30c}', 'k', '\u{30c}', 'O', '\u{328}', 'o', '\u{328}', 'O', '\u{328}', '\u{304}', 'o',
+        '\u{328}', '\u{304}', '\u{1b7}', '\u{30c}', '\u{292}', '\u{30c}', 'j', '\u{30c}', 'G',
+        '\u{301}', 'g', '\u{301}', 'N', '\u{300}', 'n', '\u{300}', 'A', '\u{30a}', '\u{301}', 'a',
+        '\u{30a}', '\u{301}', '\u{c6}', '\u{301}', '\u{e6}', '\u{301}', '\u{d8}', '\u{301}',
+        '\u{f8}', '\u{301}', 'A', '\u{30f}', 'a', '\u{30f}', 'A', '\u{311}', 'a', '\u{311}', 'E',
+        '\u{30f}', 'e', '\u{30f}', 'E', '\u{311}', 'e', '\u{311}', 'I', '\u{30f}', 'i', '\u{30f}',
+        'I', '\u{311}', 'i', '\u{311}', 'O', '\u{30f}', 'o', '\u{30f}', 'O', '\u{311}', 'o',
+        '\u{311}', 'R', '\u{30f}', 'r', '\u{30f}', 'R', '\u{311}', 'r', '\u{311}', 'U', '\u{30f}',
+        'u', '\u{30f}', 'U', '\u{311}', 'u', '\u{311}', 'S', '\u{326}', 's', '\u{326}', 'T',
+        '\u{326}', 't', '\u{326}', 'H', '\u{30c}', 'h', '\u{30c}', 'A', '\u{307}', 'a', '\u{307}',
+        'E', '\u{327}', 'e', '\u{327}', 'O', '\u{308}', '\u{304}', 'o', '\u{308}', '\u{304}', 'O',
+        '\u{303}', '\u{304}', 'o', '\u{303}', '\u{304}', 'O', '\u{307}', 'o', '\u{307}', 'O',
+        '\u{307}', '\u{304}', 'o', '\u{307}', '\u{304}', 'Y', '\u{304}', 'y', '\u{304}', '\u{300}',
+        '\u{301}', '\u{313}', '\u{308}', '\u{301}', '\u{2b9}', ';', '\u{a8}', '\u{301}', '\u{391}',
+        '\u{301}', '\u{b7}', '\u{395}', '\u{301}', '\u{397}', '\u{301}', '\u{399}', '\u{301}',
+        '\u{39f}', '\u{301}', '\u{3a5}', '\u{301}', '\u{3a9}', '\u{301}', '\u{3b9}', '\u{308}',
+        '\u{301}', '\u{399}', '\u{308}', '\u{3a5}', '\u{308}', '\u{3b1}', '\u{301}', '\u{3b5}',
+        '\u{301}', '\u{3b7}', '\u{301}', '\u{3b9}', '\u{301}', '\u{3c5}', '\u{308}', '\u{301}',
+        '\u{3b9}', '\u{308}', '\u{3c5}', '\u{308}', '\u{3bf}', '\u{301}', '\u{3c5}', '\u{301}',
+        '\u{3c9}', '\u{301}', '\u{3d2}', '\u{301}', '\u{3d2}', '\u{308}', '\u{415}', '\u{300}',
+        '\u{415}', '\u{308}', '\u{413}', '\u{301}', '\u{406}', '\u{308}', '\u{41a}', '\u{301}',
+        '\u{418}', '\u{300}', '\u{423}', '\u{306}', '\u{418}', '\u{306}', '\u{438}', '\u{306}',
+        '\u{435}', '\u{300}', '\u{435}', '\u{308}', '\u{433}', '\u{301}', '\u{456}', '\u{308}',
+        '\u{43a}', '\u{301}', '\u{438}', '\u{300}', '\u{443}', '\u{306}', '\u{474}', '\u{30f}',
+        '\u{475}', '\u{30f}', '\u{416}', '\u{306}', '\u{436}', '\u{306}', '\u{410}', '\u{306}',
+        '\u{430}', '\u{306}', '\u{410}', '\u{308}', '\u{430}', '\u{308}', '\u{415}', '\u{306}',
+        '\u{435}', '\u{306}', '\u{4d8}', '\u{308}', '\u{4d9}', '\u{308}', '\u{416}', '\u{308}',
+        '\u{436}', '\u{308}', '\u{417}', '\u{308}', '\u{437}', '\u{308}', '\u{418}', '\u{304}',
+        '\u{438}', '\u{304}', '\u{418}', '\u{308}', '\u{438}', '\u{308}', '\u{41e}', '\u{308}',
+        '\u{43e}', '\u{308}', '\u{4e8}', '\u{308}', '\u{4e9}', '\u{308}', '\u{42d}', '\u{308}',
+        '\u{44d}', '\u{308}', '\u{423}', '\u{304}', '\u{443}', '\u{304}', '\u{423}', '\u{308}',
+        '\u{443}', '\u{308}', '\u{423}', '\u{30b}', '\u{443}', '\u{30b}', '\u{427}', '\u{308}',
+        '\u{447}', '\u{308}', '\u{42b}', '\u{308}', '\u{44b}', '\u{308}', '\u{627}', '\u{653}',
+        '\u{627}', '\u{654}', '\u{648}', '\u{654}', '\u{627}', '\u{655}', '\u{64a}', '\u{654}',
+        '\u{6d5}', '\u{654}', '\u{6c1}', '\u{654}', '\u{6d2}', '\u{654}', '\u{928}', '\u{93c}',
+        '\u{930}', '\u{93c}', '\u{933}', '\u{93c}', '\u{915}', '\u{93c}', '\u{916}', '\u{93c}',
+        '\u{917}', '\u{93c}', '\u{91c}', '\u{93c}', '\u{921}', '\u{93c}', '\u{922}', '\u{93c}',
+        '\u{92b}', '\u{93c}', '\u{92f}', '\u{93c}', '\u{9c7}', '\u{9be}', '\u{9c7}', '\u{9d7}',
+        '\u{9a1}', '\u{9bc}', '\u{9a2}', '\u{9bc}', '\u{9af}', '\u{9bc}', '\u{a32}', '\u{a3c}',
+        '\u{a38}', '\u{a3c}', '\u{a16}', '\u{a3c}', '\u{a17}', '\u{a3c}', '\u{a1c}', '\u{a3c}',
+        '\u{a2b}', '\u{a3c}', '\u{b47}', '\u{b56}', '\u{b47}', '\u{b3e}', '\u{b47}', '\u{b57}',
+        '\u{b21}', '\u{b3c}', '\u{b22}', '\u{b3c}', '\u{b92}', '\u{bd7}', '\u{bc6}', '\u{bbe}',
+        '\u{bc7}', '\u{bbe}', '\u{bc6}', '\u{bd7}', '\u{c46}', '\u{c56}', '\u{cbf}', '\u{cd5}',
+        '\u{cc6}', '\u{cd5}', '\u{cc6}', '\u{cd6}', '\u{cc6}', '\u{cc2}', '\u{cc6}', '\u{cc2}',
+        '\u{cd5}', '\u{d46}', '\u{d3e}', '\u{d47}', '\u{d3e}', '\u{d46}', '\u{d57}', '\u{dd9}',
+        '\u{dca}', '\u{dd9}', '\u{dcf}', '\u{dd9}', '\u{dcf}', '\u{dca}', '\u{dd9}', '\u{ddf}',
+        '\u{f42}', '\u{fb7}', '\u{f4c}', '\u{fb7}', '\u{f51}', '\u{fb7}', '\u{f56}', '\u{fb7}',
+        '\u{f5b}', '\u{fb7}', '\u{f40}', '\u{fb5}', '\u{f71}', '\u{f72}', '\u{f71}', '\u{f74}',
+        '\u{fb2}', '\u{f80}', '\u{fb3}', '\u{f80}', '\u{f71}', '\u{f80}', '\u{f92}', '\u{fb7}',
+        '\u{f9c}', '\u{fb7}', '\u{fa1}', '\u{fb7}', '\u{fa6}', '\u{fb7}', '\u{fab}', '\u{fb7}',
+        '\u{f90}', '\u{fb5}', '\u{1025}', '\u{102e}', '\u{1b05}', '\u{1b35}', '\u{1b07}',
+        '\u{1b35}', '\u{1b09}', '\u{1b35}', '\u{1b0b}', '\u{1b35}', '\u{1b0d}', '\u{1b35}',
+        '\u{1b11}', '\u{1b35}', '\u{1b3a}', '\u{1b35}', '\u{1b3c}', '\u{1b35}', '\u{1b3e}',
+        '\u{1b35}', '\u{1b3f}', '\u{1b35}', '\u{1b42}', '\u{1b35}', 'A', '\u{325}', 'a', '\u{325}',
+        'B', '\u{307}', 'b', '\u{307}', 'B', '\u{323}', 'b', '\u{323}', 'B', '\u{331}', 'b',
+        '\u{331}', 'C', '\u{327}', '\u{301}', 'c', '\u{327}', '\u{301}', 'D', '\u{307}', 'd',
+        '\u{307}', 'D', '\u{323}', 'd', '\u{323}', 'D', '\u{331}', 'd', '\u{331}', 'D', '\u{327}',
+        'd', '\u{327}', 'D', '\u{32d}', 'd', '\u{32d}', 'E', '\u{304}', '\u{300}', 'e', '\u{304}',
+        '\u{300}', 'E', '\u{304}', '\u{301}', 'e', '\u{304}', '\u{301}', 'E', '\u{32d}', 'e',
+        '\u{32d}', 'E', '\u{330}', 'e', '\u{330}', 'E', '\u{327}', '\u{306}', 'e', '\u{327}',
+        '\u{306}', 'F', '\u{307}', 'f', '\u{307}', 'G', '\u{304}', 'g', '\u{304}', 'H', '\u{307}',
+        'h', '\u{307}', 'H', '\u{323}', 'h', '\u{323}', 'H', '\u{308}', 'h', '\u{308}', 'H',
+        '\u{327}', 'h', '\u{327}', 'H', '\u{32e}', 'h', '\u{32e}', 'I', '\u{330}', 'i', '\u{330}',
+        'I', '\u{308}', '\u{301}', 'i', '\u{308}', '\u{301}', 'K', '\u{301}', 'k', '\u{301}', 'K',
+        '\u{323}', 'k', '\u{323}', 'K', '\u{331}', 'k', '\u{331}', 'L', '\u{323}', 'l', '\u{323}',
+        'L', '\u{323}', '\u{304}', 'l', '\u{323}', '\u{304}', 'L', '\u{331}', 'l', '\u{331}', 'L',
+        '\u{32d}', 'l', '\u{32d}', 'M', '\u{301}', 'm', '\u{301}', 'M', '\u{307}', 'm', '\u{307}',
+        'M', '\u{323}', 'm', '\u{323}', 'N', '\u{307}', 'n', '\u{307}', 'N', '\u{323}', 'n',
+        '\u{323}', 'N', '\u{331}', 'n', '\u{331}', 'N', '\u{32d}', 'n', '\u{32d}', 'O', '\u{303}',
+        '\u{301}', 'o', '\u{303}', '\u{301}', 'O', '\u{303}', '\u{308}', 'o', '\u{303}', '\u{308}',
+        'O', '\u{304}', '\u{300}', 'o', '\u{304}', '\u{300}', 'O', '\u{304}', '\u{301}', 'o',
+        '\u{304}', '\u{301}', 'P', '\u{301}', 'p', '\u{301}', 'P', '\u{307}', 'p', '\u{307}', 'R',
+        '\u{307}', 'r', '\u{307}', 'R', '\u{323}', 'r', '\u{323}', 'R', '\u{323}', '\u{304}', 'r',
+        '\u{323}', '\u{304}', 'R', '\u{331}', 'r', '\u{331}', 'S', '\u{307}', 's', '\u{307}', 'S',
+        '\u{323}', 's', '\u{323}', 'S', '\u{301}', '\u{307}', 's', '\u{301}', '\u{307}', 'S',
+        '\u{30c}', '\u{307}', 's', '\u{30c}', '\u{307}', 'S', '\u{323}', '\u{307}', 's', '\u{323}',
+        '\u{307}', 'T', '\u{307}', 't', '\u{307}', 'T', '\u{323}', 't', '\u{323}', 'T', '\u{331}',
+        't', '\u{331}', 'T', '\u{32d}', 't', '\u{32d}', 'U', '\u{324}', 'u', '\u{324}', 'U',
+        '\u{330}', 'u', '\u{330}', 'U', '\u{32d}', 'u', '\u{32d}', 'U', '\u{303}', '\u{301}', 'u',
+        '\u{303}', '\u{301}', 'U', '\u{304}', '\u{308}', 'u', '\u{304}', '\u{308}', 'V', '\u{303}',
+        'v', '\u{303}', 'V', '\u{323}', 'v', '\u{323}', 'W', '\u{300}', 'w', '\u{300}', 'W',
+        '\u{301}', 'w', '\u{301}', 'W', '\u{308}', 'w', '\u{308}', 'W', '\u{307}', 'w', '\u{307}',
+        'W', '\u{323}', 'w', '\u{323}', 'X', '\u{307}', 'x', '\u{307}', 'X', '\u{308}', 'x',
+        '\u{308}', 'Y', '\u{307}', 'y', '\u{307}', 'Z', '\u{302}', 'z', '\u{302}', 'Z', '\u{323}',
+        'z', '\u{323}', 'Z', '\u{331}', 'z', '\u{331}', 'h', '\u{331}', 't', '\u{308}', 'w',
+        '\u{30a}', 'y', '\u{30a}', '\u{17f}', '\u{307}', 'A', '\u{323}', 'a', '\u{323}', 'A',
+        '\u{309}', 'a', '\u{309}', 'A', '\u{302}', '\u{301}', 'a', '\u{302}', '\u{301}', 'A',
+        '\u{302}', '\u{300}', 'a', '\u{302}', '\u{300}', 'A', '\u{302}', '\u{309}', 'a', '\u{302}',
+        '\u{309}', 'A', '\u{302}', '\u{303}', 'a', '\u{302}', '\u{303}', 'A', '\u{323}', '\u{302}',
+        'a', '\u{323}', '\u{302}', 'A', '\u{306}', '\u{301}', 'a', '\u{306}', '\u{301}', 'A',
+        '\u{306}', '\u{300}', 'a', '\u{306}', '\u{300}', 'A', '\u{306}', '\u{309}', 'a', '\u{306}',
+        '\u{309}', 'A', '\u{306}', '\u{303}', 'a', '\u{306}', '\u{303}', 'A', '\u{323}', '\u{306}',
+        'a', '\u{323}', '\u{306}', 'E', '\u{323}', 'e', '\u{323}', 'E', '\u{309}', 'e', '\u{309}',
+        'E', '\u{303}', 'e', '\u{303}', 'E', '\u{302}', '\u{301}', 'e', '\u{302}', '\u{301}', 'E',
+        '\u{302}', '\u{300}', 'e', '\u{302}', '\u{300}', 'E', '\u{302}', '\u{309}', 'e', '\u{302}',
+        '\u{309}', 'E', '\u{302}', '\u{303}', 'e', '\u{302}', '\u{303}', 'E', '\u{323}', '\u{302}',
+        'e', '\u{323}', '\u{302}', 'I', '\u{309}', 'i', '\u{309}', 'I', '\u{323}', 'i', '\u{323}',
+        'O', '\u{323}', 'o', '\u{323}', 'O', '\u{309}', 'o', '\u{309}', 'O', '\u{302}', '\u{301}',
+        'o', '\u{302}', '\u{301}', 'O', '\u{302}', '\u{300}', 'o', '\u{302}', '\u{300}', 'O',
+        '\u{302}', '\u{309}', 'o', '\u{302}', '\u{309}', 'O', '\u{302}', '\u{303}', 'o', '\u{302}',
+        '\u{303}', 'O', '\u{323}', '\u{302}', 'o', '\u{323}', '\u{302}', 'O', '\u{31b}', '\u{301}',
+        'o', '\u{31b}', '\u{301}', 'O', '\u{31b}', '\u{300}', 'o', '\u{31b}', '\u{300}', 'O',
+        '\u{31b}', '\u{309}', 'o', '\u{31b}', '\u{309}', 'O', '\u{31b}', '\u{303}', 'o', '\u{31b}',
+        '\u{303}', 'O', '\u{31b}', '\u{323}', 'o', '\u{31b}', '\u{323}', 'U', '\u{323}', 'u',
+        '\u{323}', 'U', '\u{309}', 'u', '\u{309}', 'U', '\u{31b}', '\u{301}', 'u', '\u{31b}',
+        '\u{301}', 'U', '\u{31b}', '\u{300}', 'u', '\u{31b}', '\u{300}', 'U', '\u{31b}', '\u{309}',
+        'u', '\u{31b}', '\u{309}', 'U', '\u{31b}', '\u{303}', 'u', '\u{31b}', '\u{303}', 'U',
+        '\u{31b}', '\u{323}', 'u', '\u{31b}', '\u{323}', 'Y', '\u{300}', 'y', '\u{300}', 'Y',
+        '\u{323}', 'y', '\u{323}', 'Y', '\u{309}', 'y', '\u{309}', 'Y', '\u{303}', 'y', '\u{303}',
+        '\u{3b1}', '\u{313}', '\u{3b1}', '\u{314}', '\u{3b1}', '\u{313}', '\u{300}', '\u{3b1}',
+        '\u{314}', '\u{300}', '\u{3b1}', '\u{313}', '\u{301}', '\u{3b1}', '\u{314}', '\u{301}',
+        '\u{3b1}', '\u{313}', '\u{342}', '\u{3b1}', '\u{314}', '\u{342}', '\u{391}', '\u{313}',
+        '\u{391}', '\u{314}', '\u{391}', '\u{313}', '\u{300}', '\u{391}', '\u{314}', '\u{300}',
+        '\u{391}', '\u{313}', '\u{301}', '\u{391}', '\u{314}', '\u{301}', '\u{391}', '\u{313}',
+        '\u{342}', '\u{391}', '\u{314}', '\u{342}', '\u{3b5}', '\u{313}', '\u{3b5}', '\u{314}',
+        '\u{3b5}', '\u{313}', '\u{300}', '\u{3b5}', '\u{314}', '\u{300}', '\u{3b5}', '\u{313}',
+        '\u{301}', '\u{3b5}', '\u{314}', '\u{301}', '\u{395}', '\u{313}', '\u{395}', '\u{314}',
+        '\u{395}', '\u{313}', '\u{300}', '\u{395}', '\u{314}', '\u{300}', '\u{395}', '\u{313}',
+        '\u{301}', '\u{395}', '\u{314}', '\u{301}', '\u{3b7}', '\u{313}', '\u{3b7}', '\u{314}',
+        '\u{3b7}', '\u{313}', '\u{300}', '\u{3b7}', '\u{314}', '\u{300}', '\u{3b7}', '\u{313}',
+        '\u{301}', '\u{3b7}', '\u{314}', '\u{301}', '\u{3b7}', '\u{313}', '\u{342}', '\u{3b7}',
+        '\u{314}', '\u{342}', '\u{397}', '\u{313}', '\u{397}', '\u{314}', '\u{397}', '\u{313}',
+        '\u{300}', '\u{397}', '\u{314}', '\u{300}', '\u{397}', '\u{313}', '\u{301}', '\u{397}',
+        '\u{314}', '\u{301}', '\u{397}', '\u{313}', '\u{342}', '\u{397}', '\u{314}', '\u{342}',
+        '\u{3b9}', '\u{313}', '\u{3b9}', '\u{314}', '\u{3b9}', '\u{313}', '\u{300}', '\u{3b9}',
+        '\u{314}', '\u{300}', '\u{3b9}', '\u{313}', '\u{301}', '\u{3b9}', '\u{314}', '\u{301}',
+        '\u{3b9}', '\u{313}', '\u{342}', '\u{3b9}', '\u{314}', '\u{342}', '\u{399}', '\u{313}',
+        '\u{399}', '\u{314}', '\u{399}', '\u{313}', '\u{300}', '\u{399}', '\u{314}', '\u{300}',
+        '\u{399}', '\u{313}', '\u{301}', '\u{399}', '\u{314}', '\u{301}', '\u{399}', '\u{313}',
+        '\u{342}', '\u{399}', '\u{314}', '\u{342}', '\u{3bf}', '\u{313}', '\u{3bf}', '\u{314}',
+        '\u{3bf}', '\u{313}', '\u{300}', '\u{3bf}', '\u{314}', '\u{300}', '\u{3bf}', '\u{313}',
+        '\u{301}', '\u{3bf}', '\u{314}', '\u{301}', '\u{39f}', '\u{313}', '\u{39f}', '\u{314}',
+        '\u{39f}', '\u{313}', '\u{300}', '\u{39f}', '\u{314}', '\u{300}', '\u{39f}', '\u{313}',
+        '\u{301}', '\u{39f}', '\u{314}', '\u{301}', '\u{3c5}', '\u{313}', '\u{3c5}', '\u{314}',
+        '\u{3c5}', '\u{313}', '\u{300}', '\u{3c5}', '\u{314}', '\u{300}', '\u{3c5}', '\u{313}',
+        '\u{301}', '\u{3c5}', '\u{314}', '\u{301}', '\u{3c5}', '\u{313}', '\u{342}', '\u{3c5}',
+        '\u{314}', '\u{342}', '\u{3a5}', '\u{314}', '\u{3a5}', '\u{314}', '\u{300}', '\u{3a5}',
+        '\u{314}', '\u{301}', '\u{3a5}', '\u{314}', '\u{342}', '\u{3c9}', '\u{313}', '\u{3c9}',
+        '\u{314}', '\u{3c9}', '\u{313}', '\u{300}', '\u{3c9}', '\u{314}', '\u{300}', '\u{3c9}',
+        '\u{313}', '\u{301}', '\u{3c9}', '\u{314}', '\u{301}', '\u{3c9}', '\u{313}', '\u{342}',
+        '\u{3c9}', '\u{314}', '\u{342}', '\u{3a9}', '\u{313}', '\u{3a9}', '\u{314}', '\u{3a9}',
+        '\u{313}', '\u{300}', '\u{3a9}', '\u{314}', '\u{300}', '\u{3a9}', '\u{313}', '\u{301}',
+        '\u{3a9}', '\u{314}', '\u{301}', '\u{3a9}', '\u{313}', '\u{342}', '\u{3a9}', '\u{314}',
+        '\u{342}', '\u{3b1}', '\u{300}', '\u{3b1}', '\u{301}', '\u{3b5}', '\u{300}', '\u{3b5}',
+        '\u{301}', '\u{3b7}', '\u{300}', '\u{3b7}', '\u{301}', '\u{3b9}', '\u{300}', '\u{3b9}',
+        '\u{301}', '\u{3bf}', '\u{300}', '\u{3bf}', '\u{301}', '\u{3c5}', '\u{300}', '\u{3c5}',
+        '\u{301}', '\u{3c9}', '\u{300}', '\u{3c9}', '\u{301}', '\u{3b1}', '\u{313}', '\u{345}',
+        '\u{3b1}', '\u{314}', '\u{345}', '\u{3b1}', '\u{313}', '\u{300}', '\u{345}', '\u{3b1}',
+        '\u{314}', '\u{300}', '\u{345}', '\u{3b1}', '\u{313}', '\u{301}', '\u{345}', '\u{3b1}',
+        '\u{314}', '\u{301}', '\u{345}', '\u{3b1}', '\u{313}', '\u{342}', '\u{345}', '\u{3b1}',
+        '\u{314}', '\u{342}', '\u{345}', '\u{391}', '\u{313}', '\u{345}', '\u{391}', '\u{314}',
+        '\u{345}', '\u{391}', '\u{313}', '\u{300}', '\u{345}', '\u{391}', '\u{314}', '\u{300}',
+        '\u{345}', '\u{391}', '\u{313}', '\u{301}', '\u{345}', '\u{391}', '\u{314}', '\u{301}',
+        '\u{345}', '\u{391}', '\u{313}', '\u{342}', '\u{345}', '\u{391}', '\u{314}', '\u{342}',
+        '\u{345}', '\u{3b7}', '\u{313}', '\u{345}', '\u{3b7}', '\u{314}', '\u{345}', '\u{3b7}',
+        '\u{313}', '\u{300}', '\u{345}', '\u{3b7}', '\u{314}', '\u{300}', '\u{345}', '\u{3b7}',
+        '\u{313}', '\u{301}', '\u{345}', '\u{3b7}', '\u{314}', '\u{301}', '\u{345}', '\u{3b7}',
+        '\u{313}', '\u{342}', '\u{345}', '\u{3b7}', '\u{314}', '\u{342}', '\u{345}', '\u{397}',
+        '\u{313}', '\u{345}', '\u{397}', '\u{314}', '\u{345}', '\u{397}', '\u{313}', '\u{300}',
+        '\u{345}', '\u{397}', '\u{314}', '\u{300}', '\u{345}', '\u{397}', '\u{313}', '\u{301}',
+        '\u{345}', '\u{397}', '\u{314}', '\u{301}', '\u{345}', '\u{397}', '\u{313}', '\u{342}',
+        '\u{345}', '\u{397}', '\u{314}', '\u{342}', '\u{345}', '\u{3c9}', '\u{313}', '\u{345}',
+        '\u{3c9}', '\u{314}', '\u{345}', '\u{3c9}', '\u{313}', '\u{300}', '\u{345}', '\u{3c9}',
+        '\u{314}', '\u{300}', '\u{345}', '\u{3c9}', '\u{313}', '\u{301}', '\u{345}', '\u{3c9}',
+        '\u{314}', '\u{301}', '\u{345}', '\u{3c9}', '\u{313}', '\u{342}', '\u{345}', '\u{3c9}',
+        '\u{314}', '\u{342}', '\u{345}', '\u{3a9}', '\u{313}', '\u{345}', '\u{3a9}', '\u{314}',
+        '\u{345}', '\u{3a9}', '\u{313}', '\u{300}', '\u{345}', '\u{3a9}', '\u{314}', '\u{300}',
+        '\u{345}', '\u{3a9}', '\u{313}', '\u{301}', '\u{345}', '\u{3a9}', '\u{314}', '\u{301}',
+        '\u{345}', '\u{3a9}', '\u{313}', '\u{342}', '\u{345}', '\u{3a9}', '\u{314}', '\u{342}',
+        '\u{345}', '\u{3b1}', '\u{306}', '\u{3b1}', '\u{304}', '\u{3b1}', '\u{300}', '\u{345}',
+        '\u{3b1}', '\u{345}', '\u{3b1}', '\u{301}', '\u{345}', '\u{3b1}', '\u{342}', '\u{3b1}',
+        '\u{342}', '\u{345}', '\u{391}', '\u{306}', '\u{391}', '\u{304}', '\u{391}', '\u{300}',
+        '\u{391}', '\u{301}', '\u{391}', '\u{345}', '\u{3b9}', '\u{a8}', '\u{342}', '\u{3b7}',
+        '\u{300}', '\u{345}', '\u{3b7}', '\u{345}', '\u{3b7}', '\u{301}', '\u{345}', '\u{3b7}',
+        '\u{342}', '\u{3b7}', '\u{342}', '\u{345}', '\u{395}', '\u{300}', '\u{395}', '\u{301}',
+        '\u{397}', '\u{300}', '\u{397}', '\u{301}', '\u{397}', '\u{345}', '\u{1fbf}', '\u{300}',
+        '\u{1fbf}', '\u{301}', '\u{1fbf}', '\u{342}', '\u{3b9}', '\u{306}', '\u{3b9}', '\u{304}',
+        '\u{3b9}', '\u{308}', '\u{300}', '\u{3b9}', '\u{308}', '\u{301}', '\u{3b9}', '\u{342}',
+        '\u{3b9}', '\u{308}', '\u{342}', '\u{399}', '\u{306}', '\u{399}', '\u{304}', '\u{399}',
+        '\u{300}', '\u{399}', '\u{301}', '\u{1ffe}', '\u{300}', '\u{1ffe}', '\u{301}', '\u{1ffe}',
+        '\u{342}', '\u{3c5}', '\u{306}', '\u{3c5}', '\u{304}', '\u{3c5}', '\u{308}', '\u{300}',
+        '\u{3c5}', '\u{308}', '\u{301}', '\u{3c1}', '\u{313}', '\u{3c1}', '\u{314}', '\u{3c5}',
+        '\u{342}', '\u{3c5}', '\u{308}', '\u{342}', '\u{3a5}', '\u{306}', '\u{3a5}', '\u{304}',
+        '\u{3a5}', '\u{300}', '\u{3a5}', '\u{301}', '\u{3a1}', '\u{314}', '\u{a8}', '\u{300}',
+        '\u{a8}', '\u{301}', '`', '\u{3c9}', '\u{300}', '\u{345}', '\u{3c9}', '\u{345}', '\u{3c9}',
+        '\u{301}', '\u{345}', '\u{3c9}', '\u{342}', '\u{3c9}', '\u{342}', '\u{345}', '\u{39f}',
+        '\u{300}', '\u{39f}', '\u{301}', '\u{3a9}', '\u{300}', '\u{3a9}', '\u{301}', '\u{3a9}',
+        '\u{345}', '\u{b4}', '\u{2002}', '\u{2003}', '\u{3a9}', 'K', 'A', '\u{30a}', '\u{2190}',
+        '\u{338}', '\u{2192}', '\u{338}', '\u{2194}', '\u{338}', '\u{21d0}', '\u{338}', '\u{21d4}',
+        '\u{338}', '\u{21d2}', '\u{338}', '\u{2203}', '\u{338}', '\u{2208}', '\u{338}', '\u{220b}',
+        '\u{338}', '\u{2223}', '\u{338}', '\u{2225}', '\u{338}', '\u{223c}', '\u{338}', '\u{2243}',
+        '\u{338}', '\u{2245}', '\u{338}', '\u{2248}', '\u{338}', '=', '\u{338}', '\u{2261}',
+        '\u{338}', '\u{224d}', '\u{338}', '<', '\u{338}', '>', '\u{338}', '\u{2264}', '\u{338}',
+        '\u{2265}', '\u{338}', '\u{2272}', '\u{338}', '\u{2273}', '\u{338}', '\u{2276}', '\u{338}',
+        '\u{2277}', '\u{338}', '\u{227a}', '\u{338}', '\u{227b}', '\u{338}', '\u{2282}', '\u{338}',
+        '\u{2283}', '\u{338}', '\u{2286}', '\u{338}', '\u{2287}', '\u{338}', '\u{22a2}', '\u{338}',
+        '\u{22a8}', '\u{338}', '\u{22a9}', '\u{338}', '\u{22ab}', '\u{338}', '\u{227c}', '\u{338}',
+        '\u{227d}', '\u{338}', '\u{2291}', '\u{338}', '\u{2292}', '\u{338}', '\u{22b2}', '\u{338}',
+        '\u{22b3}', '\u{338}', '\u{22b4}', '\u{338}', '\u{22b5}', '\u{338}', '\u{3008}', '\u{3009}',
+        '\u{2add}', '\u{338}', '\u{304b}', '\u{3099}', '\u{304d}', '\u{3099}', '\u{304f}',
+        '\u{3099}', '\u{3051}', '\u{3099}', '\u{3053}', '\u{3099}', '\u{3055}', '\u{3099}',
+        '\u{3057}', '\u{3099}', '\u{3059}', '\u{3099}', '\u{305b}', '\u{3099}', '\u{305d}',
+        '\u{3099}', '\u{305f}', '\u{3099}', '\u{3061}', '\u{3099}', '\u{3064}', '\u{3099}',
+        '\u{3066}', '\u{3099}', '\u{3068}', '\u{3099}', '\u{306f}', '\u{3099}', '\u{306f}',
+        '\u{309a}', '\u{3072}', '\u{3099}', '\u{3072}', '\u{309a}', '\u{3075}', '\u{3099}',
+        '\u{3075}', '\u{309a}', '\u{3078}', '\u{3099}', '\u{3078}', '\u{309a}', '\u{307b}',
+        '\u{3099}', '\u{307b}', '\u{309a}', '\u{3046}', '\u{3099}', '\u{309d}', '\u{3099}',
+        '\u{30ab}', '\u{3099}', '\u{30ad}', '\u{3099}', '\u{30af}', '\u{3099}', '\u{30b1}',
+        '\u{3099}', '\u{30b3}', '\u{3099}', '\u{30b5}', '\u{3099}', '\u{30b7}', '\u{3099}',
+        '\u{30b9}', '\u{3099}', '\u{30bb}', '\u{3099}', '\u{30bd}', '\u{3099}', '\u{30bf}',
+        '\u{3099}', '\u{30c1}', '\u{3099}', '\u{30c4}', '\u{3099}', '\u{30c6}', '\u{3099}',
+        '\u{30c8}', '\u{3099}', '\u{30cf}', '\u{3099}', '\u{30cf}', '\u{309a}', '\u{30d2}',
+        '\u{3099}', '\u{30d2}', '\u{309a}', '\u{30d5}', '\u{3099}', '\u{30d5}', '\u{309a}',
+        '\u{30d8}', '\u{3099}', '\u{30d8}', '\u{309a}', '\u{30db}', '\u{3099}', '\u{30db}',
+        '\u{309a}', '\u{30a6}', '\u{3099}', '\u{30ef}', '\u{3099}', '\u{30f0}', '\u{3099}',
+        '\u{30f1}', '\u{3099}', '\u{30f2}', '\u{3099}', '\u{30fd}', '\u{3099}', '\u{8c48}',
+        '\u{66f4}', '\u{8eca}', '\u{8cc8}', '\u{6ed1}', '\u{4e32}', '\u{53e5}', '\u{9f9c}',
+        '\u{9f9c}', '\u{5951}', '\u{91d1}', '\u{5587}', '\u{5948}', '\u{61f6}', '\u{7669}',
+        '\u{7f85}', '\u{863f}', '\u{87ba}', '\u{88f8}', '\u{908f}', '\u{6a02}', '\u{6d1b}',
+        '\u{70d9}', '\u{73de}', '\u{843d}', '\u{916a}', '\u{99f1}', '\u{4e82}', '\u{5375}',
+        '\u{6b04}', '\u{721b}', '\u{862d}', '\u{9e1e}', '\u{5d50}', '\u{6feb}', '\u{85cd}',
+        '\u{8964}', '\u{62c9}', '\u{81d8}', '\u{881f}', '\u{5eca}', '\u{6717}', '\u{6d6a}',
+        '\u{72fc}', '\u{90ce}', '\u{4f86}', '\u{51b7}', '\u{52de}', '\u{64c4}', '\u{6ad3}',
+        '\u{7210}', '\u{76e7}', '\u{8001}', '\u{8606}', '\u{865c}', '\u{8def}', '\u{9732}',
+        '\u{9b6f}', '\u{9dfa}', '\u{788c}', '\u{797f}', '\u{7da0}', '\u{83c9}', '\u{9304}',
+        '\u{9e7f}', '\u{8ad6}', '\u{58df}', '\u{5f04}', '\u{7c60}', '\u{807e}', '\u{7262}',
+        '\u{78ca}', '\u{8cc2}', '\u{96f7}', '\u{58d8}', '\u{5c62}', '\u{6a13}', '\u{6dda}',
+        '\u{6f0f}', '\u{7d2f}', '\u{7e37}', '\u{964b}', '\u{52d2}', '\u{808b}', '\u{51dc}',
+        '\u{51cc}', '\u{7a1c}', '\u{7dbe}', '\u{83f1}', '\u{9675}', '\u{8b80}', '\u{62cf}',
+        '\u{6a02}', '\u{8afe}', '\u{4e39}', '\u{5be7}', '\u{6012}', '\u{7387}', '\u{7570}',
+        '\u{5317}', '\u{78fb}', '\u{4fbf}', '\u{5fa9}', '\u{4e0d}', '\u{6ccc}', '\u{6578}',
+        '\u{7d22}', '\u{53c3}', '\u{585e}', '\u{7701}', '\u{8449}', '\u{8aaa}', '\u{6bba}',
+        '\u{8fb0}', '\u{6c88}', '\u{62fe}', '\u{82e5}', '\u{63a0}', '\u{7565}', '\u{4eae}',
+        '\u{5169}', '\u{51c9}', '\u{6881}', '\u{7ce7}', '\u{826f}', '\u{8ad2}', '\u{91cf}',
+        '\u{52f5}', '\u{5442}', '\u{5973}', '\u{5eec}', '\u{65c5}', '\u{6ffe}', '\u{792a}',
+        '\u{95ad}', '\u{9a6a}', '\u{9e97}', '\u{9ece}', '\u{529b}', '\u{66c6}', '\u{6b77}',
+        '\u{8f62}', '\u{5e74}', '\u{6190}', '\u{6200}', '\u{649a}', '\u{6f23}', '\u{7149}',
+        '\u{7489}', '\u{79ca}', '\u{7df4}', '\u{806f}', '\u{8f26}', '\u{84ee}', '\u{9023}',
+        '\u{934a}', '\u{5217}', '\u{52a3}', '\u{54bd}', '\u{70c8}', '\u{88c2}', '\u{8aaa}',
+        '\u{5ec9}', '\u{5ff5}', '\u{637b}', '\u{6bae}', '\u{7c3e}', '\u{7375}', '\u{4ee4}',
+        '\u{56f9}', '\u{5be7}', '\u{5dba}', '\u{601c}', '\u{73b2}', '\u{7469}', '\u{7f9a}',
+        '\u{8046}', '\u{9234}', '\u{96f6}', '\u{9748}', '\u{9818}', '\u{4f8b}', '\u{79ae}',
+        '\u{91b4}', '\u{96b8}', '\u{60e1}', '\u{4e86}', '\u{50da}', '\u{5bee}', '\u{5c3f}',
+        '\u{6599}', '\u{6a02}', '\u{71ce}', '\u{7642}', '\u{84fc}', '\u{907c}', '\u{9f8d}',
+        '\u{6688}', '\u{962e}', '\u{5289}', '\u{677b}', '\u{67f3}', '\u{6d41}', '\u{6e9c}',
+        '\u{7409}', '\u{7559}', '\u{786b}', '\u{7d10}', '\u{985e}', '\u{516d}', '\u{622e}',
+        '\u{9678}', '\u{502b}', '\u{5d19}', '\u{6dea}', '\u{8f2a}', '\u{5f8b}', '\u{6144}',
+        '\u{6817}', '\u{7387}', '\u{9686}', '\u{5229}', '\u{540f}', '\u{5c65}', '\u{6613}',
+        '\u{674e}', '\u{68a8}', '\u{6ce5}', '\u{7406}', '\u{75e2}', '\u{7f79}', '\u{88cf}',
+        '\u{88e1}', '\u{91cc}', '\u{96e2}', '\u{533f}', '\u{6eba}', '\u{541d}', '\u{71d0}',
+        '\u{7498}', '\u{85fa}', '\u{96a3}', '\u{9c57}', '\u{9e9f}', '\u{6797}', '\u{6dcb}',
+        '\u{81e8}', '\u{7acb}', '\u{7b20}', '\u{7c92}', '\u{72c0}', '\u{7099}', '\u{8b58}',
+        '\u{4ec0}', '\u{8336}', '\u{523a}', '\u{5207}', '\u{5ea6}', '\u{62d3}', '\u{7cd6}',
+        '\u{5b85}', '\u{6d1e}', '\u{66b4}', '\u{8f3b}', '\u{884c}', '\u{964d}', '\u{898b}',
+        '\u{5ed3}', '\u{5140}', '\u{55c0}', '\u{585a}', '\u{6674}', '\u{51de}', '\u{732a}',
+        '\u{76ca}', '\u{793c}', '\u{795e}', '\u{7965}', '\u{798f}', '\u{9756}', '\u{7cbe}',
+        '\u{7fbd}', '\u{8612}', '\u{8af8}', '\u{9038}', '\u{90fd}', '\u{98ef}', '\u{98fc}',
+        '\u{9928}', '\u{9db4}', '\u{90de}', '\u{96b7}', '\u{4fae}', '\u{50e7}', '\u{514d}',
+        '\u{52c9}', '\u{52e4}', '\u{5351}', '\u{559d}', '\u{5606}', '\u{5668}', '\u{5840}',
+        '\u{58a8}', '\u{5c64}', '\u{5c6e}', '\u{6094}', '\u{6168}', '\u{618e}', '\u{61f2}',
+        '\u{654f}', '\u{65e2}', '\u{6691}', '\u{6885}', '\u{6d77}', '\u{6e1a}', '\u{6f22}',
+        '\u{716e}', '\u{722b}', '\u{7422}', '\u{7891}', '\u{793e}', '\u{7949}', '\u{7948}',
+        '\u{7950}', '\u{7956}', '\u{795d}', '\u{798d}', '\u{798e}', '\u{7a40}', '\u{7a81}',
+        '\u{7bc0}', '\u{7df4}', '\u{7e09}', '\u{7e41}', '\u{7f72}', '\u{8005}', '\u{81ed}',
+        '\u{8279}', '\u{8279}', '\u{8457}', '\u{8910}', '\u{8996}', '\u{8b01}', '\u{8b39}',
+        '\u{8cd3}', '\u{8d08}', '\u{8fb6}', '\u{9038}', '\u{96e3}', '\u{97ff}', '\u{983b}',
+        '\u{6075}', '\u{242ee}', '\u{8218}', '\u{4e26}', '\u{51b5}', '\u{5168}', '\u{4f80}',
+        '\u{5145}', '\u{5180}', '\u{52c7}', '\u{52fa}', '\u{559d}', '\u{5555}', '\u{5599}',
+        '\u{55e2}', '\u{585a}', '\u{58b3}', '\u{5944}', '\u{5954}', '\u{5a62}', '\u{5b28}',
+        '\u{5ed2}', '\u{5ed9}', '\u{5f69}', '\u{5fad}', '\u{60d8}', '\u{614e}', '\u{6108}',
+        '\u{618e}', '\u{6160}', '\u{61f2}', '\u{6234}', '\u{63c4}', '\u{641c}', '\u{6452}',
+        '\u{6556}', '\u{6674}', '\u{6717}', '\u{671b}', '\u{6756}', '\u{6b79}', '\u{6bba}',
+        '\u{6d41}', '\u{6edb}', '\u{6ecb}', '\u{6f22}', '\u{701e}', '\u{716e}', '\u{77a7}',
+        '\u{7235}', '\u{72af}', '\u{732a}', '\u{7471}', '\u{7506}', '\u{753b}', '\u{761d}',
+        '\u{761f}', '\u{76ca}', '\u{76db}', '\u{76f4}', '\u{774a}', '\u{7740}', '\u{78cc}',
+        '\u{7ab1}', '\u{7bc0}', '\u{7c7b}', '\u{7d5b}', '\u{7df4}', '\u{7f3e}', '\u{8005}',
+        '\u{8352}', '\u{83ef}', '\u{8779}', '\u{8941}', '\u{8986}', '\u{8996}', '\u{8abf}',
+        '\u{8af8}', '\u{8acb}', '\u{8b01}', '\u{8afe}', '\u{8aed}', '\u{8b39}', '\u{8b8a}',
+        '\u{8d08}', '\u{8f38}', '\u{9072}', '\u{9199}', '\u{9276}', '\u{967c}', '\u{96e3}',
+        '\u{9756}', '\u{97db}', '\u{97ff}', '\u{980b}', '\u{983b}', '\u{9b12}', '\u{9f9c}',
+        '\u{2284a}', '\u{22844}', '\u{233d5}', '\u{3b9d}', '\u{4018}', '\u{4039}', '\u{25249}',
+        '\u{25cd0}', '\u{27ed3}', '\u{9f43}', '\u{9f8e}', '\u{5d9}', '\u{5b4}', '\u{5f2}',
+        '\u{5b7}', '\u{5e9}', '\u{5c1}', '\u{5e9}', '\u{5c2}', '\u{5e9}', '\u{5bc}', '\u{5c1}',
+        '\u{5e9}', '\u{5bc}', '\u{5c2}', '\u{5d0}', '\u{5b7}', '\u{5d0}', '\u{5b8}', '\u{5d0}',
+        '\u{5bc}', '\u{5d1}', '\u{5bc}', '\u{5d2}', '\u{5bc}', '\u{5d3}', '\u{5bc}', '\u{5d4}',
+        '\u{5bc}', '\u{5d5}', '\u{5bc}', '\u{5d6}', '\u{5bc}', '\u{5d8}', '\u{5bc}', '\u{5d9}',
+        '\u{5bc}', '\u{5da}', '\u{5bc}', '\u{5db}', '\u{5bc}', '\u{5dc}', '\u{5bc}', '\u{5de}',
+        '\u{5bc}', '\u{5e0}', '\u{5bc}', '\u{5e1}', '\u{5bc}', '\u{5e3}', '\u{5bc}', '\u{5e4}',
+        '\u{5bc}', '\u{5e6}', '\u{5bc}', '\u{5e7}', '\u{5bc}', '\u{5e8}', '\u{5bc}', '\u{5e9}',
+        '\u{5bc}', '\u{5ea}', '\u{5bc}', '\u{5d5}', '\u{5b9}', '\u{5d1}', '\u{5bf}', '\u{5db}',
+        '\u{5bf}', '\u{5e4}', '\u{5bf}', '\u{105d2}', '\u{307}', '\u{105da}', '\u{307}',
+        '\u{11099}', '\u{110ba}', '\u{1109b}', '\u{110ba}', '\u{110a5}', '\u{110ba}', '\u{11131}',
+        '\u{11127}', '\u{11132}', '\u{11127}', '\u{11347}', '\u{1133e}', '\u{11347}', '\u{11357}',
+        '\u{11382}', '\u{113c9}', '\u{11384}', '\u{113bb}', '\u{1138b}', '\u{113c2}', '\u{11390}',
+        '\u{113c9}', '\u{113c2}', '\u{113c2}', '\u{113c2}', '\u{113b8}', '\u{113c2}', '\u{113c9}',
+        '\u{114b9}', '\u{114ba}', '\u{114b9}', '\u{114b0}', '\u{114b9}', '\u{114bd}', '\u{115b8}',
+        '\u{115af}', '\u{115b9}', '\u{115af}', '\u{11935}', '\u{11930}', '\u{1611e}', '\u{1611e}',
+        '\u{1611e}', '\u{16129}', '\u{1611e}', '\u{1611f}', '\u{16129}', '\u{1611f}', '\u{1611e}',
+        '\u{16120}', '\u{1611e}', '\u{1611e}', '\u{1611f}', '\u{1611e}', '\u{16129}', '\u{1611f}',
+        '\u{1611e}', '\u{1611e}', '\u{16120}', '\u{16d67}', '\u{16d67}', '\u{16d63}', '\u{16d67}',
+        '\u{16d63}', '\u{16d67}', '\u{16d67}', '\u{1d157}', '\u{1d165}', '\u{1d158}', '\u{1d165}',
+        '\u{1d158}', '\u{1d165}', '\u{1d16e}', '\u{1d158}', '\u{1d165}', '\u{1d16f}', '\u{1d158}',
+        '\u{1d165}', '\u{1d170}', '\u{1d158}', '\u{1d165}', '\u{1d171}', '\u{1d158}', '\u{1d165}',
+        '\u{1d172}', '\u{1d1b9}', '\u{1d165}', '\u{1d1ba}', '\u{1d165}', '\u{1d1b9}', '\u{1d165}',
+        '\u{1d16e}', '\u{1d1ba}', '\u{1d165}', '\u{1d16e}', '\u{1d1b9}', '\u{1d165}', '\u{1d16f}',
+        '\u{1d1ba}', '\u{1d165}', '\u{1d16f}', '\u{4e3d}', '\u{4e38}', '\u{4e41}', '\u{20122}',
+        '\u{4f60}', '\u{4fae}', '\u{4fbb}', '\u{5002}', '\u{507a}', '\u{5099}', '\u{50e7}',
+        '\u{50cf}', '\u{349e}', '\u{2063a}', '\u{514d}', '\u{5154}', '\u{5164}', '\u{5177}',
+        '\u{2051c}', '\u{34b9}', '\u{5167}', '\u{518d}', '\u{2054b}', '\u{5197}', '\u{51a4}',
+        '\u{4ecc}', '\u{51ac}', '\u{51b5}', '\u{291df}', '\u{51f5}', '\u{5203}', '\u{34df}',
+        '\u{523b}', '\u{5246}', '\u{5272}', '\u{5277}', '\u{3515}', '\u{52c7}', '\u{52c9}',
+        '\u{52e4}', '\u{52fa}', '\u{5305}', '\u{5306}', '\u{5317}', '\u{5349}', '\u{5351}',
+        '\u{535a}', '\u{5373}', '\u{537d}', '\u{537f}', '\u{537f}', '\u{537f}', '\u{20a2c}',
+        '\u{7070}', '\u{53ca}', '\u{53df}', '\u{20b63}', '\u{53eb}', '\u{53f1}', '\u{5406}',
+        '\u{549e}', '\u{5438}', '\u{5448}', '\u{5468}', '\u{54a2}', '\u{54f6}', '\u{5510}',
+        '\u{5553}', '\u{5563}', '\u{5584}', '\u{5584}', '\u{5599}', '\u{55ab}', '\u{55b3}',
+        '\u{55c2}', '\u{5716}', '\u{5606}', '\u{5717}', '\u{5651}', '\u{5674}', '\u{5207}',
+        '\u{58ee}', '\u{57ce}', '\u{57f4}', '\u{580d}', '\u{578b}', '\u{5832}', '\u{5831}',
+        '\u{58ac}', '\u{214e4}', '\u{58f2}', '\u{58f7}', '\u{5906}', '\u{591a}', '\u{5922}',
+        '\u{5962}', '\u{216a8}', '\u{216ea}', '\u{59ec}', '\u{5a1b}', '\u{5a27}', '\u{59d8}',
+        '\u{5a66}', '\u{36ee}', '\u{36fc}', '\u{5b08}', '\u{5b3e}', '\u{5b3e}', '\u{219c8}',
+        '\u{5bc3}', '\u{5bd8}', '\u{5be7}', '\u{5bf3}', '\u{21b18}', '\u{5bff}', '\u{5c06}',
+        '\u{5f53}', '\u{5c22}', '\u{3781}', '\u{5c60}', '\u{5c6e}', '\u{5cc0}', '\u{5c8d}',
+        '\u{21de4}', '\u{5d43}', '\u{21de6}', '\u{5d6e}', '\u{5d6b}', '\u{5d7c}', '\u{5de1}',
+        '\u{5de2}', '\u{382f}', '\u{5dfd}', '\u{5e28}', '\u{5e3d}', '\u{5e69}', '\u{3862}',
+        '\u{22183}', '\u{387c}', '\u{5eb0}', '\u{5eb3}', '\u{5eb6}', '\u{5eca}', '\u{2a392}',
+        '\u{5efe}', '\u{22331}', '\u{22331}', '\u{8201}', '\u{5f22}', '\u{5f22}', '\u{38c7}',
+        '\u{232b8}', '\u{261da}', '\u{5f62}', '\u{5f6b}', '\u{38e3}', '\u{5f9a}', '\u{5fcd}',
+        '\u{5fd7}', '\u{5ff9}', '\u{6081}', '\u{393a}', '\u{391c}', '\u{6094}', '\u{226d4}',
+        '\u{60c7}', '\u{6148}', '\u{614c}', '\u{614e}', '\u{614c}', '\u{617a}', '\u{618e}',
+        '\u{61b2}', '\u{61a4}', '\u{61af}', '\u{61de}', '\u{61f2}', '\u{61f6}', '\u{6210}',
+        '\u{621b}', '\u{625d}', '\u{62b1}', '\u{62d4}', '\u{6350}', '\u{22b0c}', '\u{633d}',
+        '\u{62fc}', '\u{6368}', '\u{6383}', '\u{63e4}', '\u{22bf1}', '\u{6422}', '\u{63c5}',
+        '\u{63a9}', '\u{3a2e}', '\u{6469}', '\u{647e}', '\u{649d}', '\u{6477}', '\u{3a6c}',
+        '\u{654f}', '\u{656c}', '\u{2300a}', '\u{65e3}', '\u{66f8}', '\u{6649}', '\u{3b19}',
+        '\u{6691}', '\u{3b08}', '\u{3ae4}', '\u{5192}', '\u{5195}', '\u{6700}', '\u{669c}',
+        '\u{80ad}', '\u{43d9}', '\u{6717}', '\u{671b}', '\u{6721}', '\u{675e}', '\u{6753}',
+        '\u{233c3}', '\u{3b49}', '\u{67fa}', '\u{6785}', '\u{6852}', '\u{6885}', '\u{2346d}',
+        '\u{688e}', '\u{681f}', '\u{6914}', '\u{3b9d}', '\u{6942}', '\u{69a3}', '\u{69ea}',
+        '\u{6aa8}', '\u{236a3}', '\u{6adb}', '\u{3c18}', '\u{6b21}', '\u{238a7}', '\u{6b54}',
+        '\u{3c4e}', '\u{6b72}', '\u{6b9f}', '\u{6bba}', '\u{6bbb}', '\u{23a8d}', '\u{21d0b}',
+        '\u{23afa}', '\u{6c4e}', '\u{23cbc}', '\u{6cbf}', '\u{6ccd}', '\u{6c67}', '\u{6d16}',
+        '\u{6d3e}', '\u{6d77}', '\u{6d41}', '\u{6d69}', '\u{6d78}', '\u{6d85}', '\u{23d1e}',
+        '\u{6d34}', '\u{6e2f}', '\u{6e6e}', '\u{3d33}', '\u{6ecb}', '\u{6ec7}', '\u{23ed1}',
+        '\u{6df9}', '\u{6f6e}', '\u{23f5e}', '\u{23f8e}', '\u{6fc6}', '\u{7039}', '\u{701e}',
+        '\u{701b}', '\u{3d96}', '\u{704a}', '\u{707d}', '\u{7077}', '\u{70ad}', '\u{20525}',
+        '\u{7145}', '\u{24263}', '\u{719c}', '\u{243ab}', '\u{7228}', '\u{7235}', '\u{7250}',
+        '\u{24608}', '\u{7280}', '\u{7295}', '\u{24735}', '\u{24814}', '\u{737a}', '\u{738b}',
+        '\u{3eac}', '\u{73a5}', '\u{3eb8}', '\u{3eb8}', '\u{7447}', '\u{745c}', '\u{7471}',
+        '\u{7485}', '\u{74ca}', '\u{3f1b}', '\u{7524}', '\u{24c36}', '\u{753e}', '\u{24c92}',
+        '\u{7570}', '\u{2219f}', '\u{7610}', '\u{24fa1}', '\u{24fb8}', '\u{25044}', '\u{3ffc}',
+        '\u{4008}', '\u{76f4}', '\u{250f3}', '\u{250f2}', '\u{25119}', '\u{25133}', '\u{771e}',
+        '\u{771f}', '\u{771f}', '\u{774a}', '\u{4039}', '\u{778b}', '\u{4046}', '\u{4096}',
+        '\u{2541d}', '\u{784e}', '\u{788c}', '\u{78cc}', '\u{40e3}', '\u{25626}', '\u{7956}',
+        '\u{2569a}', '\u{256c5}', '\u{798f}', '\u{79eb}', '\u{412f}', '\u{7a40}', '\u{7a4a}',
+        '\u{7a4f}', '\u{2597c}', '\u{25aa7}', '\u{25aa7}', '\u{7aee}', '\u{4202}', '\u{25bab}',
+        '\u{7bc6}', '\u{7bc9}', '\u{4227}', '\u{25c80}', '\u{7cd2}', '\u{42a0}', '\u{7ce8}',
+        '\u{7ce3}', '\u{7d00}', '\u{25f86}', '\u{7d63}', '\u{4301}', '\u{7dc7}', '\u{7e02}',
+        '\u{7e45}', '\u{4334}', '\u{26228}', '\u{26247}', '\u{4359}', '\u{262d9}', '\u{7f7a}',
+        '\u{2633e}', '\u{7f95}', '\u{7ffa}', '\u{8005}', '\u{264da}', '\u{26523}', '\u{8060}',
+        '\u{265a8}', '\u{8070}', '\u{2335f}', '\u{43d5}', '\u{80b2}', '\u{8103}', '\u{440b}',
+        '\u{813e}', '\u{5ab5}', '\u{267a7}', '\u{267b5}', '\u{23393}', '\u{2339c}', '\u{8201}',
+        '\u{8204}', '\u{8f9e}', '\u{446b}', '\u{8291}', '\u{828b}', '\u{829d}', '\u{52b3}',
+        '\u{82b1}', '\u{82b3}', '\u{82bd}', '\u{82e6}', '\u{26b3c}', '\u{82e5}', '\u{831d}',
+        '\u{8363}', '\u{83ad}', '\u{8323}', '\u{83bd}', '\u{83e7}', '\u{8457}', '\u{8353}',
+        '\u{83ca}', '\u{83cc}', '\u{83dc}', '\u{26c36}', '\u{26d6b}', '\u{26cd5}', '\u{452b}',
+        '\u{84f1}', '\u{84f3}', '\u{8516}', '\u{273ca}', '\u{8564}', '\u{26f2c}', '\u{455d}',
+        '\u{4561}', '\u{26fb1}', '\u{270d2}', '\u{456b}', '\u{8650}', '\u{865c}', '\u{8667}',
+        '\u{8669}', '\u{86a9}', '\u{8688}', '\u{870e}', '\u{86e2}', '\u{8779}', '\u{8728}',
+        '\u{876b}', '\u{8786}', '\u{45d7}', '\u{87e1}', '\u{8801}', '\u{45f9}', '\u{8860}',
+        '\u{8863}', '\u{27667}', '\u{88d7}', '\u{88de}', '\u{4635}', '\u{88fa}', '\u{34bb}',
+        '\u{278ae}', '\u{27966}', '\u{46be}', '\u{46c7}', '\u{8aa0}', '\u{8aed}', '\u{8b8a}',
+        '\u{8c55}', '\u{27ca8}', '\u{8cab}', '\u{8cc1}', '\u{8d1b}', '\u{8d77}', '\u{27f2f}',
+        '\u{20804}', '\u{8dcb}', '\u{8dbc}', '\u{8df0}', '\u{208de}', '\u{8ed4}', '\u{8f38}',
+        '\u{285d2}', '\u{285ed}', '\u{9094}', '\u{90f1}', '\u{9111}', '\u{2872e}', '\u{911b}',
+        '\u{9238}', '\u{92d7}', '\u{92d8}', '\u{927c}', '\u{93f9}', '\u{9415}', '\u{28bfa}',
+        '\u{958b}', '\u{4995}', '\u{95b7}', '\u{28d77}', '\u{49e6}', '\u{96c3}', '\u{5db2}',
+        '\u{9723}', '\u{29145}', '\u{2921a}', '\u{4a6e}', '\u{4a76}', '\u{97e0}', '\u{2940a}',
+        '\u{4ab2}', '\u{29496}', '\u{980b}', '\u{980b}', '\u{9829}', '\u{295b6}', '\u{98e2}',
+        '\u{4b33}', '\u{9929}', '\u{99a7}', '\u{99c2}', '\u{99fe}', '\u{4bce}', '\u{29b30}',
+        '\u{9b12}', '\u{9c40}', '\u{9cfd}', '\u{4cce}', '\u{4ced}', '\u{9d67}', '\u{2a0ce}',
+        '\u{4cf8}', '\u{2a105}', '\u{2a20e}', '\u{2a291}', '\u{9ebb}', '\u{4d56}', '\u{9ef9}',
+        '\u{9efe}', '\u{9f05}', '\u{9f0f}', '\u{9f16}', '\u{9f3b}', '\u{2a600}',
+    ];
+
+    static COMPATIBILITY_DECOMPOSED_TABLE: &[(char, u16, u8)] = &[
+        ('\u{a0}', 0, 1), ('\u{a8}', 1, 2), ('\u{aa}', 3, 1), ('\u{af}', 4, 2), ('\u{b2}', 6, 1),
+        ('\u{b3}', 7, 1), ('\u{b4}', 8, 2), ('\u{b5}', 10, 1), ('\u{b8}', 11, 2), ('\u{b9}', 13, 1),
+        ('\u{ba}', 14, 1), ('\u{bc}', 15, 3), ('\u{bd}', 18, 3), ('\u{be}', 21, 3),
+        ('\u{132}', 24, 2), ('\u{133}', 26, 2), ('\u{13f}', 28, 2), ('\u{140}', 30, 2),
+        ('\u{149}', 32, 2), ('\u{17f}', 34, 1), ('\u{1c4}', 35, 3), ('\u{1c5}', 38, 3),
+        ('\u{1c6}', 41, 3), ('\u{1c7}', 44, 2), ('\u{1c8}', 46, 2), ('\u{1c9}', 48, 2),
+        ('\u{1ca}', 50, 2), ('\u{1cb}', 52, 2), ('\u{1cc}', 54, 2), ('\u{1f1}', 56, 2),
+        ('\u{1f2}', 58, 2), ('\u{1f3}', 60, 2), ('\u{2b0}', 62, 1), ('\u{2b1}', 63, 1),
+        ('\u{2b2}', 64, 1), ('\u{2b3}', 65, 1), ('\u{2b4}', 66, 1), ('\u{2b5}', 67, 1),
+        ('\u{2b6}', 68, 1), ('\u{2b7}', 69, 1), ('\u{2b8}', 70, 1), ('\u{2d8}', 71, 2),
+        ('\u{2d9}', 73, 2), ('\u{2da}', 75, 2), ('\u{2db}', 77, 2), ('\u{2dc}', 79, 2),
+        ('\u{2dd}', 81, 2), ('\u{2e0}', 83, 1), ('\u{2e1}', 84, 1), ('\u{2e2}', 85, 1),
+        ('\u{2e3}', 86, 1), ('\u{2e4}', 87, 1), ('\u{37a}', 88, 2), ('\u{384}', 90, 2),
+        ('\u{385}', 92, 3), ('\u{3d0}', 95, 1), ('\u{3d1}', 96, 1), ('\u{3d2}', 97, 1),
+        ('\u{3d3}', 98, 2), ('\u{3d4}', 100, 2), ('\u{3d5}', 102, 1), ('\u{3d6}', 103, 1),
+        ('\u{3f0}', 104, 1), ('\u{3f1}', 105, 1), ('\u{3f2}', 106, 1), ('\u{3f4}', 107, 1),
+        ('\u{3f5}', 108, 1), ('\u{3f9}', 109, 1), ('\u{587}', 110, 2), ('\u{675}', 112, 2),
+        ('\u{676}', 114, 2), ('\u{677}', 116, 2), ('\u{678}', 118, 2), ('\u{e33}', 120, 2),
+        ('\u{eb3}', 122, 2), ('\u{edc}', 124, 2), ('\u{edd}', 126, 2), ('\u{f0c}', 128, 1),
+        ('\u{f77}', 129, 3), ('\u{f79}', 132, 3), ('\u{10fc}', 135, 1), ('\u{1d2c}', 136, 1),
+        ('\u{1d2d}', 137, 1), ('\u{1d2e}', 138, 1), ('\u{1d30}', 139, 1), ('\u{1d31}', 140, 1),
+        ('\u{1d32}', 141, 1), ('\u{1d33}', 142, 1), ('\u{1d34}', 143, 1), ('\u{1d35}', 144, 1),
+        ('\u{1d36}', 145, 1), ('\u{1d37}', 146, 1), ('\u{1d38}', 147, 1), ('\u{1d39}', 148, 1),
+        ('\u{1d3a}', 149, 1), ('\u{1d3c}', 150, 1), ('\u{1d3d}', 151, 1), ('\u{1d3e}', 152, 1),
+        ('\u{1d3f}', 153, 1), ('\u{1d40}', 154, 1), ('\u{1d41}', 155, 1), ('\u{1d42}', 156, 1),
+        ('\u{1d43}', 157, 1), ('\u{1d44}', 158, 1), ('\u{1d45}', 159, 1), ('\u{1d46}', 160, 1),
+        ('\u{1d47}', 161, 1), ('\u{1d48}', 162, 1), ('\u{1d49}', 163, 1), ('\u{1d4a}', 164, 1),
+        ('\u{1d4b}', 165, 1), ('\u{1d4c}', 166, 1), ('\u{1d4d}', 167, 1), ('\u{1d4f}', 168, 1),
+        ('\u{1d50}', 169, 1), ('\u{1d51}', 170, 1), ('\u{1d52}', 171, 1), ('\u{1d53}', 172, 1),
+        ('\u{1d54}', 173, 1), ('\u{1d55}', 174, 1), ('\u{1d56}', 175, 1), ('\u{1d57}', 176, 1),
+        ('\u{1d58}', 177, 1), ('\u{1d59}', 178, 1), ('\u{1d5a}', 179, 1), ('\u{1d5b}', 180, 1),
+        ('\u{1d5c}', 181, 1), ('\u{1d5d}', 182, 1), ('\u{1d5e}', 183, 1), ('\u{1d5f}', 184, 1),
+        ('\u{1d60}', 185, 1), ('\u{1d61}', 186, 1), ('\u{1d62}', 187, 1), ('\u{1d63}', 188, 1),
+        ('\u{1d64}', 189, 1), ('\u{1d65}', 190, 1), ('\u{1d66}', 191, 1), ('\u{1d67}', 192, 1),
+        ('\u{1d68}', 193, 1), ('\u{1d69}', 194, 1), ('\u{1d6a}', 195, 1), ('\u{1d78}', 196, 1),
+        ('\u{1d9b}', 197, 1), ('\u{1d9c}', 198, 1), ('\u{1d9d}', 199, 1), ('\u{1d9e}', 200, 1),
+        ('\u{1d9f}', 201, 1), ('\u{1da0}', 202, 1), ('\u{1da1}', 203, 1), ('\u{1da2}', 204, 1),
+        ('\u{1da3}', 205, 1), ('\u{1da4}', 206, 1), ('\u{1da5}', 207, 1), ('\u{1da6}', 208, 1),
+        ('\u{1da7}', 209, 1), ('\u{1da8}', 210, 1), ('\u{1da9}', 211, 1), ('\u{1daa}', 212, 1),
+        ('\u{1dab}', 213, 1), ('\u{1dac}', 214, 1), ('\u{1dad}', 215, 1), ('\u{1dae}', 216, 1),
+        ('\u{1daf}', 217, 1), ('\u{1db0}', 218, 1), ('\u{1db1}', 219, 1), ('\u{1db2}', 220, 1),
+        ('\u{1db3}', 221, 1), ('\u{1db4}', 222, 1), ('\u{1db5}', 223, 1), ('\u{1db6}', 224, 1),
+        ('\u{1db7}', 225, 1), ('\u{1db8}', 226, 1), ('\u{1db9}', 227, 1), ('\u{1dba}', 228, 1),
+        ('\u{1dbb}', 229, 1), ('\u{1dbc}', 230, 1), ('\u{1dbd}', 231, 1), ('\u{1dbe}', 232, 1),
+        ('\u{1dbf}', 233, 1), ('\u{1e9a}', 234, 2), ('\u{1e9b}', 236, 2), ('\u{1fbd}', 238, 2),
+        ('\u{1fbf}', 240, 2), ('\u{1fc0}', 242, 2), ('\u{1fc1}', 244, 3), ('\u{1fcd}', 247, 3),
+        ('\u{1fce}', 250, 3), ('\u{1fcf}', 253, 3), ('\u{1fdd}', 256, 3), ('\u{1fde}', 259, 3),
+        ('\u{1fdf}', 262, 3), ('\u{1fed}', 265, 3), ('\u{1fee}', 268, 3), ('\u{1ffd}', 271, 2),
+        ('\u{1ffe}', 273, 2), ('\u{2000}', 275, 1), ('\u{2001}', 276, 1), ('\u{2002}', 277, 1),
+        ('\u{2003}', 278, 1), ('\u{2004}', 279, 1), ('\u{2005}', 280, 1), ('\u{2006}', 281, 1),
+        ('\u{2007}', 282, 1), ('\u{2008}', 283, 1), ('\u{2009}', 284, 1), ('\u{200a}', 285, 1),
+        ('\u{2011}', 286, 1), ('\u{2017}', 287, 2), ('\u{2024}', 289, 1), ('\u{2025}', 290, 2),
+        ('\u{2026}', 292, 3), ('\u{202f}', 295, 1), ('\u{2033}', 296, 2), ('\u{2034}', 298, 3),
+        ('\u{2036}', 301, 2), ('\u{2037}', 303, 3), ('\u{203c}', 306, 2), ('\u{203e}', 308, 2),
+        ('\u{2047}', 310, 2), ('\u{2048}', 312, 2), ('\u{2049}', 314, 2), ('\u{2057}', 316, 4),
+        ('\u{205f}', 320, 1), ('\u{2070}', 321, 1), ('\u{2071}', 322, 1), ('\u{2074}', 323, 1),
+        ('\u{2075}', 324, 1), ('\u{2076}', 325, 1), ('\u{2077}', 326, 1), ('\u{2078}', 327, 1),
+        ('\u{2079}', 328, 1), ('\u{207a}', 329, 1), ('\u{207b}', 330, 1), ('\u{207c}', 331, 1),
+        ('\u{207d}', 332, 1), ('\u{207e}', 333, 1), ('\u{207f}', 334, 1), ('\u{2080}', 335, 1),
+        ('\u{2081}', 336, 1), ('\u{2082}', 337, 1), ('\u{2083}', 338, 1), ('\u{2084}', 339, 1),
+        ('\u{2085}', 340, 1), ('\u{2086}', 341, 1), ('\u{2087}', 342, 1), ('\u{2088}', 343, 1),
+        ('\u{2089}', 344, 1), ('\u{208a}', 345, 1), ('\u{208b}', 346, 1), ('\u{208c}', 347, 1),
+        ('\u{208d}', 348, 1), ('\u{208e}', 349, 1), ('\u{2090}', 350, 1), ('\u{2091}', 351, 1),
+        ('\u{2092}', 352, 1), ('\u{2093}', 353, 1), ('\u{2094}', 354, 1), ('\u{2095}', 355, 1),
+        ('\u{2096}', 356, 1), ('\u{2097}', 357, 1), ('\u{2098}', 358, 1), ('\u{2099}', 359, 1),
+        ('\u{209a}', 360, 1), ('\u{209b}', 361, 1), ('\u{209c}', 362, 1), ('\u{20a8}', 363, 2),
+        ('\u{2100}', 365, 3), ('\u{2101}', 368, 3), ('\u{2102}', 371, 1), ('\u{2103}', 372, 2),
+        ('\u{2105}', 374, 3), ('\u{2106}', 377, 3), ('\u{2107}', 380, 1), ('\u{2109}', 381, 2),
+        ('\u{210a}', 383, 1), ('\u{210b}', 384, 1), ('\u{210c}', 385, 1), ('\u{210d}', 386, 1),
+        ('\u{210e}', 387, 1), ('\u{210f}', 388, 1), ('\u{2110}', 389, 1), ('\u{2111}', 390, 1),
+        ('\u{2112}', 391, 1), ('\u{2113}', 392, 1), ('\u{2115}', 393, 1), ('\u{2116}', 394, 2),
+        ('\u{2119}', 396, 1), ('\u{211a}', 397, 1), ('\u{211b}', 398, 1), ('\u{211c}', 399, 1),
+        ('\u{211d}', 400, 1), ('\u{2120}', 401, 2), ('\u{2121}', 403, 3), ('\u{2122}', 406, 2),
+        ('\u{2124}', 408, 1), ('\u{2128}', 409, 1), ('\u{212c}', 410, 1), ('\u{212d}', 411, 1),
+        ('\u{212f}', 412, 1), ('\u{2130}', 413, 1), ('\u{2131}', 414, 1), ('\u{2133}', 415, 1),
+        ('\u{2134}', 416, 1), ('\u{2135}', 417, 1), ('\u{2136}', 418, 1), ('\u{2137}', 419, 1),
+        ('\u{2138}', 420, 1), ('\u{2139}', 421, 1), ('\u{213b}', 422, 3), ('\u{213c}', 425, 1),
+        ('\u{213d}', 426, 1), ('\u{213e}', 427, 1), ('\u{213f}', 428, 1), ('\u{2140}', 429, 1),
+        ('\u{2145}', 430, 1), ('\u{2146}', 431, 1), ('\u{2147}', 432, 1), ('\u{2148}', 433, 1),
+        ('\u{2149}', 434, 1), ('\u{2150}', 435, 3), ('\u{2151}', 438, 3), ('\u{2152}', 441, 4),
+        ('\u{2153}', 445, 3), ('\u{2154}', 448, 3), ('\u{2155}', 451, 3), ('\u{2156}', 454, 3),
+        ('\u{2157}', 457, 3), ('\u{2158}', 460, 3), ('\u{2159}', 463, 3), ('\u{215a}', 466, 3),
+        ('\u{215b}', 469, 3), ('\u{215c}', 472, 3), ('\u{215d}', 475, 3), ('\u{215e}', 478, 3),
+        ('\u{215f}', 481, 2), ('\u{2160}', 483, 1), ('\u{2161}', 484, 2), ('\u{2162}', 486, 3),
+        ('\u{2163}', 489, 2), ('\u{2164}', 491, 1), ('\u{2165}', 492, 2), ('\u{2166}', 494, 3),
+        ('\u{2167}', 497, 4), ('\u{2168}', 501, 2), ('\u{2169}', 503, 1), ('\u{216a}', 504, 2),
+        ('\u{216b}', 506, 3), ('\u{216c}', 509, 1), ('\u{216d}', 510, 1), ('\u{216e}', 511, 1),
+        ('\u{216f}', 512, 1), ('\u{2170}', 513, 1), ('\u{2171}', 514, 2), ('\u{2172}', 516, 3),
+        ('\u{2173}', 519, 2), ('\u{2174}', 521, 1), ('\u{2175}', 522, 2), ('\u{2176}', 524, 3),
+        ('\u{2177}', 527, 4), ('\u{2178}', 531, 2), ('\u{2179}', 533, 1), ('\u{217a}', 534, 2),
+        ('\u{217b}', 536, 3), ('\u{217c}', 539, 1), ('\u{217d}', 540, 1), ('\u{217e}', 541, 1),
+        ('\u{217f}', 542, 1), ('\u{2189}', 543, 3), ('\u{222c}', 546, 2), ('\u{222d}', 548, 3),
+        ('\u{222f}', 551, 2), ('\u{2230}', 553, 3), ('\u{2460}', 556, 1), ('\u{2461}', 557, 1),
+        ('\u{2462}', 558, 1), ('\u{2463}', 559, 1), ('\u{2464}', 560, 1), ('\u{2465}', 561, 1),
+        ('\u{2466}', 562, 1), ('\u{2467}', 563, 1), ('\u{2468}', 564, 1), ('\u{2469}', 565, 2),
+        ('\u{246a}', 567, 2), ('\u{246b}', 569, 2), ('\u{246c}', 571, 2), ('\u{246d}', 573, 2),
+        ('\u{246e}', 575, 2), ('\u{246f}', 577, 2), ('\u{2470}', 579, 2), ('\u{2471}', 581, 2),
+        ('\u{2472}', 583, 2), ('\u{2473}', 585, 2), ('\u{2474}', 587, 3), ('\u{2475}', 590, 3),
+        ('\u{2476}', 593, 3), ('\u{2477}', 596, 3), ('\u{2478}', 599, 3), ('\u{2479}', 602, 3),
+        ('\u{247a}', 605, 3), ('\u{247b}', 608, 3), ('\u{247c}', 611, 3), ('\u{247d}', 614, 4),
+        ('\u{247e}', 618, 4), ('\u{247f}', 622, 4), ('\u{2480}', 626, 4), ('\u{2481}', 630, 4),
+        ('\u{2482}', 634, 4), ('\u{2483}', 638, 4), ('\u{2484}', 642, 4), ('\u{2485}', 646, 4),
+        ('\u{2486}', 650, 4), ('\u{2487}', 654, 4), ('\u{2488}', 658, 2), ('\u{2489}', 660, 2),
+        ('\u{248a}', 662, 2), ('\u{248b}', 664, 2), ('\u{248c}', 666, 2), ('\u{248d}', 668, 2),
+        ('\u{248e}', 670, 2), ('\u{248f}', 672, 2), ('\u{2490}', 674, 2), ('\u{2491}', 676, 3),
+        ('\u{2492}', 679, 3), ('\u{2493}', 682, 3), ('\u{2494}', 685, 3), ('\u{2495}', 688, 3),
+        ('\u{2496}', 691, 3), ('\u{2497}', 694, 3), ('\u{2498}', 697, 3), ('\u{2499}', 700, 3),
+        ('\u{249a}', 703, 3), ('\u{249b}', 706, 3), ('\u{249c}', 709, 3), ('\u{249d}', 712, 3),
+        ('\u{249e}', 715, 3), ('\u{249f}', 718, 3), ('\u{24a0}', 721, 3), ('\u{24a1}', 724, 3),
+        ('\u{24a2}', 727, 3), ('\u{24a3}', 730, 3), ('\u{24a4}', 733, 3), ('\u{24a5}', 736, 3),
+        ('\u{24a6}', 739, 3), ('\u{24a7}', 742, 3), ('\u{24a8}', 745, 3), ('\u{24a9}', 748, 3),
+        ('\u{24aa}', 751, 3), ('\u{24ab}', 754, 3), ('\u{24ac}', 757, 3), ('\u{24ad}', 760, 3),
+        ('\u{24ae}', 763, 3), ('\u{24af}', 766, 3), ('\u{24b0}', 769, 3), ('\u{24b1}', 772, 3),
+        ('\u{24b2}', 775, 3), ('\u{24b3}', 778, 3), ('\u{24b4}', 781, 3), ('\u{24b5}', 784, 3),
+        ('\u{24b6}', 787, 1), ('\u{24b7}', 788, 1), ('\u{24b8}', 789, 1), ('\u{24b9}', 790, 1),
+        ('\u{24ba}', 791, 1), ('\u{24bb}', 792, 1), ('\u{24bc}', 793, 1), ('\u{24bd}', 794, 1),
+        ('\u{24be}', 795, 1), ('\u{24bf}', 796, 1), ('\u{24c0}', 797, 1), ('\u{24c1}', 798, 1),
+        ('\u{24c2}', 799, 1), ('\u{24c3}', 800, 1), ('\u{24c4}', 801, 1), ('\u{24c5}', 802, 1),
+        ('\u{24c6}', 803, 1), ('\u{24c7}', 804, 1), ('\u{24c8}', 805, 1), ('\u{24c9}', 806, 1),
+        ('\u{24ca}', 807, 1), ('\u{24cb}', 808, 1), ('\u{24cc}', 809, 1), ('\u{24cd}', 810, 1),
+        ('\u{24ce}', 811, 1), ('\u{24cf}', 812, 1), ('\u{24d0}', 813, 1), ('\u{24d1}', 814, 1),
+        ('\u{24d2}', 815, 1), ('\u{24d3}', 816, 1), ('\u{24d4}', 817, 1), ('\u{24d5}', 818, 1),
+        ('\u{24d6}', 819, 1), ('\u{24d7}', 820, 1), ('\u{24d8}', 821, 1), ('\u{24d9}', 822, 1),
+        ('\u{24da}', 823, 1), ('\u{24db}', 824, 1), ('\u{24dc}', 825, 1), ('\u{24dd}', 826, 1),
+        ('\u{24de}', 827, 1), ('\u{24df}', 828, 1), ('\u{24e0}', 829, 1), ('\u{24e1}', 830, 1),
+        ('\u{24e2}', 831, 1), ('\u{24e3}', 832, 1), ('\u{24e4}', 833, 1), ('\u{24e5}', 834, 1),
+        ('\u{24e6}', 835, 1), ('\u{24e7}', 836, 1), ('\u{24e8}', 837, 1), ('\u{24e9}', 838, 1),
+        ('\u{24ea}', 839, 1), ('\u{2a0c}', 840, 4), ('\u{2a74}', 844, 3), ('\u{2a75}', 847, 2),
+        ('\u{2a76}', 849, 3), ('\u{2c7c}', 852, 1), ('\u{2c7d}', 853, 1), ('\u{2d6f}', 854, 1),
+        ('\u{2e9f}', 855, 1), ('\u{2ef3}', 856, 1), ('\u{2f00}', 857, 1), ('\u{2f01}', 858, 1),
+        ('\u{2f02}', 859, 1), ('\u{2f03}', 860, 1), ('\u{2f04}', 861, 1), ('\u{2f05}', 862, 1),
+        ('\u{2f06}', 863, 1), ('\u{2f07}', 864, 1), ('\u{2f08}', 865, 1), ('\u{2f09}', 866, 1),
+        ('\u{2f0a}', 867, 1), ('\u{2f0b}', 868, 1), ('\u{2f0c}', 869, 1), ('\u{2f0d}', 870, 1),
+        ('\u{2f0e}', 871, 1), ('\u{2f0f}', 872, 1), ('\u{2f10}', 873, 1), ('\u{2f11}', 874, 1),
+        ('\u{2f12}', 875, 1), ('\u{2f13}', 876, 1), ('\u{2f14}', 877, 1), ('\u{2f15}', 878, 1),
+        ('\u{2f16}', 879, 1), ('\u{2f17}', 880, 1), ('\u{2f18}', 881, 1), ('\u{2f19}', 882, 1),
+        ('\u{2f1a}', 883, 1), ('\u{2f1b}', 884, 1), ('\u{2f1c}', 885, 1), ('\u{2f1d}', 886, 1),
+        ('\u{2f1e}', 887, 1), ('\u{2f1f}', 888, 1), ('\u{2f20}', 889, 1), ('\u{2f21}', 890, 1),
+        ('\u{2f22}', 891, 1), ('\u{2f23}', 892, 1), ('\u{2f24}', 893, 1), ('\u{2f25}', 894, 1),
+        ('\u{2f26}', 895, 1), ('\u{2f27}', 896, 1), ('\u{2f28}', 897, 1), ('\u{2f29}', 898, 1),
+        ('\u{2f2a}', 899, 1), ('\u{2f2b}', 900, 1), ('\u{2f2c}', 901, 1), ('\u{2f2d}', 902, 1),
+        ('\u{2f2e}', 903, 1), ('\u{2f2f}', 904, 1), ('\u{2f30}', 905, 1), ('\u{2f31}', 906, 1),
+        ('\u{2f32}', 907, 1), ('\u{2f33}', 908, 1), ('\u{2f34}', 909, 1), ('\u{2f35}', 910, 1),
+        ('\u{2f36}', 911, 1), ('\u{2f37}', 912, 1), ('\u{2f38}', 913, 1), ('\u{2f39}', 914, 1),
+        ('\u{2f3a}', 915, 1), ('\u{2f3b}', 916, 1), ('\u{2f3c}', 917, 1), ('\u{2f3d}', 918, 1),
+        ('\u{2f3e}', 919, 1), ('\u{2f3f}', 920, 1), ('\u{2f40}', 921, 1), ('\u{2f41}', 922, 1),
+        ('\u{2f42}', 923, 1), ('\u{2f43}', 924, 1), ('\u{2f44}', 925, 1), ('\u{2f45}', 926, 1),
+        ('\u{2f46}', 927, 1), ('\u{2f47}', 928, 1), ('\u{2f48}', 929, 1), ('\u{2f49}', 930, 1),
+        ('\u{2f4a}', 931, 1), ('\u{2f4b}', 932, 1), ('\u{2f4c}', 933, 1), ('\u{2f4d}', 934, 1),
+        ('\u{2f4e}', 935, 1), ('\u{2f4f}', 936, 1), ('\u{2f50}', 937, 1), ('\u{2f51}', 938, 1),
+        ('\u{2f52}', 939, 1), ('\u{2f53}', 940, 1), ('\u{2f54}', 941, 1), ('\u{2f55}', 942, 1),
+        ('\u{2f56}', 943, 1), ('\u{2f57}', 944, 1), ('\u{2f58}', 945, 1), ('\u{2f59}', 946, 1),
+        ('\u{2f5a}', 947, 1), ('\u{2f5b}', 948, 1), ('\u{2f5c}', 949, 1), ('\u{2f5d}', 950, 1),
+        ('\u{2f5e}', 951, 1), ('\u{2f5f}', 952, 1), ('\u{2f60}', 953, 1), ('\u{2f61}', 954, 1),
+        ('\u{2f62}', 955, 1), ('\u{2f63}', 956, 1), ('\u{2f64}', 957, 1), ('\u{2f65}', 958, 1),
+        ('\u{2f66}', 959, 1), ('\u{2f67}', 960, 1), ('\u{2f68}', 961, 1), ('\u{2f69}', 962, 1),
+        ('\u{2f6a}', 963, 1), ('\u{2f6b}', 964, 1), ('\u{2f6c}', 965, 1), ('\u{2f6d}', 966, 1),
+        ('\u{2f6e}', 967, 1), ('\u{2f6f}', 968, 1), ('\u{2f70}', 969, 1), ('\u{2f71}', 970, 1),
+        ('\u{2f72}', 971, 1), ('\u{2f73}', 972, 1), ('\u{2f74}', 973, 1), ('\u{2f75}', 974, 1),
+        ('\u{2f76}', 975, 1), ('\u{2f77}', 976, 1), ('\u{2f78}', 977, 1), ('\u{2f79}', 978, 1),
+        ('\u{2f7a}', 979, 1), ('\u{2f7b}', 980, 1), ('\u{2f7c}', 981, 1), ('\u{2f7d}', 982, 1),
+        ('\u{2f7e}', 983, 1), ('\u{2f7f}', 984, 1), ('\u{2f80}', 985, 1), ('\u{2f81}', 986, 1),
+        ('\u{2f82}', 987, 1), ('\u{2f83}', 988, 1), ('\u{2f84}', 989, 1), ('\u{2f85}', 990, 1),
+        ('\u{2f86}', 991, 1), ('\u{2f87}', 992, 1), ('\u{2f88}', 993, 1), ('\u{2f89}', 994, 1),
+        ('\u{2f8a}', 995, 1), ('\u{2f8b}', 996, 1), ('\u{2f8c}', 997, 1), ('\u{2f8d}', 998, 1),
+        ('\u{2f8e}', 999, 1), ('\u{2f8f}', 1000, 1), ('\u{2f90}', 1001, 1), ('\u{2f91}', 1002, 1),
+        ('\u{2f92}', 1003, 1), ('\u{2f93}', 1004, 1), ('\u{2f94}', 1005, 1), ('\u{2f95}', 1006, 1),
+        ('\u{2f96}', 1007, 1), ('\u{2f97}', 1008, 1), ('\u{2f98}', 1009, 1), ('\u{2f99}', 1010, 1),
+        ('\u{2f9a}', 1011, 1), ('\u{2f9b}', 1012, 1), ('\u{2f9c}', 1013, 1), ('\u{2f9d}', 1014, 1),
+        ('\u{2f9e}', 1015, 1), ('\u{2f9f}', 1016, 1), ('\u{2fa0}', 1017, 1), ('\u{2fa1}', 1018, 1),
+        ('\u{2fa2}', 1019, 1), ('\u{2fa3}', 1020, 1), ('\u{2fa4}', 1021, 1), ('\u{2fa5}', 1022, 1),
+        ('\u{2fa6}', 1023, 1), ('\u{2fa7}', 1024, 1), ('\u{2fa8}', 1025, 1), ('\u{2fa9}', 1026, 1),
+        ('\u{2faa}', 1027, 1), ('\u{2fab}', 1028, 1), ('\u{2fac}', 1029, 1), ('\u{2fad}', 1030, 1),
+        ('\u{2fae}', 1031, 1), ('\u{2faf}', 1032, 1), ('\u{2fb0}', 1033, 1), ('\u{2fb1}', 1034, 1),
+        ('\u{2fb2}', 1035, 1), ('\u{2fb3}', 1036, 1), ('\u{2fb4}', 1037, 1), ('\u{2fb5}', 1038, 1),
+        ('\u{2fb6}', 1039, 1), ('\u{2fb7}', 1040, 1), ('\u{2fb8}', 1041, 1), ('\u{2fb9}', 1042, 1),
+        ('\u{2fba}', 1043, 1), ('\u{2fbb}', 1044, 1), ('\u{2fbc}', 1045, 1), ('\u{2fbd}', 1046, 1),
+        ('\u{2fbe}', 1047, 1), ('\u{2fbf}', 1048, 1), ('\u{2fc0}', 1049, 1), ('\u{2fc1}', 1050, 1),
+        ('\u{2fc2}', 1051, 1), ('\u{2fc3}', 1052, 1), ('\u{2fc4}', 1053, 1), ('\u{2fc5}', 1054, 1),
+        ('\u{2fc6}', 1055, 1), ('\u{2fc7}', 1056, 1), ('\u{2fc8}', 1057, 1), ('\u{2fc9}', 1058, 1),
+        ('\u{2fca}', 1059, 1), ('\u{2fcb}', 1060, 1), ('\u{2fcc}', 1061, 1), ('\u{2fcd}', 1062, 1),
+        ('\u{2fce}', 1063, 1), ('\u{2fcf}', 1064, 1), ('\u{2fd0}', 1065, 1), ('\u{2fd1}', 1066, 1),
+        ('\u{2fd2}', 1067, 1), ('\u{2fd3}', 1068, 1), ('\u{2fd4}', 1069, 1), ('\u{2fd5}', 1070, 1),
+        ('\u{3000}', 1071, 1), ('\u{3036}', 1072, 1), ('\u{3038}', 1073, 1), ('\u{3039}', 1074, 1),
+        ('\u{303a}', 1075, 1), ('\u{309b}', 1076, 2), ('\u{309c}', 1078, 2), ('\u{309f}', 1080, 2),
+        ('\u{30ff}', 1082, 2), ('\u{3131}', 1084, 1), ('\u{3132}', 1085, 1), ('\u{3133}', 1086, 1),
+        ('\u{3134}', 1087, 1), ('\u{3135}', 1088, 1), ('\u{3136}', 1089, 1), ('\u{3137}', 1090, 1),
+        ('\u{3138}', 1091, 1), ('\u{3139}', 1092, 1), ('\u{313a}', 1093, 1), ('\u{313b}', 1094, 1),
+        ('\u{313c}', 1095, 1), ('\u{313d}', 1096, 1), ('\u{313e}', 1097, 1), ('\u{313f}', 1098, 1),
+        ('\u{3140}', 1099, 1), ('\u{3141}', 1100, 1), ('\u{3142}', 1101, 1), ('\u{3143}', 1102, 1),
+        ('\u{3144}', 1103, 1), ('\u{3145}', 1104, 1), ('\u{3146}', 1105, 1), ('\u{3147}', 1106, 1),
+        ('\u{3148}', 1107, 1), ('\u{3149}', 1108, 1), ('\u{314a}', 1109, 1), ('\u{314b}', 1110, 1),
+        ('\u{314c}', 1111, 1), ('\u{314d}', 1112, 1), ('\u{314e}', 1113, 1), ('\u{314f}', 1114, 1),
+        ('\u{3150}', 1115, 1), ('\u{3151}', 1116, 1), ('\u{3152}', 1117, 1), ('\u{3153}', 1118, 1),
+        ('\u{3154}', 1119, 1), ('\u{3155}', 1120, 1), ('\u{3156}', 1121, 1), ('\u{3157}', 1122, 1),
+        ('\u{3158}', 1123, 1), ('\u{3159}', 1124, 1), ('\u{315a}', 1125, 1), ('\u{315b}', 1126, 1),
+        ('\u{315c}', 1127, 1), ('\u{315d}', 1128, 1), ('\u{315e}', 1129, 1), ('\u{315f}', 1130, 1),
+        ('\u{3160}', 1131, 1), ('\u{3161}', 1132, 1), ('\u{3162}', 1133, 1), ('\u{3163}', 1134, 1),
+        ('\u{3164}', 1135, 1), ('\u{3165}', 1136, 1), ('\u{3166}', 1137, 1), ('\u{3167}', 1138, 1),
+        ('\u{3168}', 1139, 1), ('\u{3169}', 1140, 1), ('\u{316a}', 1141, 1), ('\u{316b}', 1142, 1),
+        ('\u{316c}', 1143, 1), ('\u{316d}', 1144, 1), ('\u{316e}', 1145, 1), ('\u{316f}', 1146, 1),
+        ('\u{3170}', 1147, 1), ('\u{3171}', 1148, 1), ('\u{3172}', 1149, 1), ('\u{3173}', 1150, 1),
+        ('\u{3174}', 1151, 1), ('\u{3175}', 1152, 1), ('\u{3176}', 1153, 1), ('\u{3177}', 1154, 1),
+        ('\u{3178}', 1155, 1), ('\u{3179}', 1156, 1), ('\u{317a}', 1157, 1), ('\u{317b}', 1158, 1),
+        ('\u{317c}', 1159, 1), ('\u{317d}', 1160, 1), ('\u{317e}', 1161, 1), ('\u{317f}', 1162, 1),
+        ('\u{3180}', 1163, 1), ('\u{3181}', 1164, 1), ('\u{3182}', 1165, 1), ('\u{3183}', 1166, 1),
+        ('\u{3184}', 1167, 1), ('\u{3185}', 1168, 1), ('\u{3186}', 1169, 1), ('\u{3187}', 1170, 1),
+        ('\u{3188}', 1171, 1), ('\u{3189}', 1172, 1), ('\u{318a}', 1173, 1), ('\u{318b}', 1174, 1),
+        ('\u{318c}', 1175, 1), ('\u{318d}', 1176, 1), ('\u{318e}', 1177, 1), ('\u{3192}', 1178, 1),
+        ('\u{3193}', 1179, 1), ('\u{3194}', 1180, 1), ('\u{3195}', 1181, 1), ('\u{3196}', 1182, 1),
+        ('\u{3197}', 1183, 1), ('\u{3198}', 1184, 1), ('\u{3199}', 1185, 1), ('\u{319a}', 1186, 1),
+        ('\u{319b}', 1187, 1), ('\u{319c}', 1188, 1), ('\u{319d}', 1189, 1), ('\u{319e}', 1190, 1),
+        ('\u{319f}', 1191, 1), ('\u{3200}', 1192, 3), ('\u{3201}', 1195, 3), ('\u{3202}', 1198, 3),
+        ('\u{3203}', 1201, 3), ('\u{3204}', 1204, 3), ('\u{3205}', 1207, 3), ('\u{3206}', 1210, 3),
+        ('\u{3207}', 1213, 3), ('\u{3208}', 1216, 3), ('\u{3209}', 1219, 3), ('\u{320a}', 1222, 3),
+        ('\u{320b}', 1225, 3), ('\u{320c}', 1228, 3), ('\u{320d}', 1231, 3), ('\u{320e}', 1234, 4),
+        ('\u{320f}', 1238, 4), ('\u{3210}', 1242, 4), ('\u{3211}', 1246, 4), ('\u{3212}', 1250, 4),
+        ('\u{3213}', 1254, 4), ('\u{3214}', 1258, 4), ('\u{3215}', 1262, 4), ('\u{3216}', 1266, 4),
+        ('\u{3217}', 1270, 4), ('\u{3218}', 1274, 4), ('\u{3219}', 1278, 4), ('\u{321a}', 1282, 4),
+        ('\u{321b}', 1286, 4), ('\u{321c}', 1290, 4), ('\u{321d}', 1294, 7), ('\u{321e}', 1301, 6),
+        ('\u{3220}', 1307, 3), ('\u{3221}', 1310, 3), ('\u{3222}', 1313, 3), ('\u{3223}', 1316, 3),
+        ('\u{3224}', 1319, 3), ('\u{3225}', 1322, 3), ('\u{3226}', 1325, 3), ('\u{3227}', 1328, 3),
+        ('\u{3228}', 1331, 3), ('\u{3229}', 1334, 3), ('\u{322a}', 1337, 3), ('\u{322b}', 1340, 3),
+        ('\u{322c}', 1343, 3), ('\u{322d}', 1346, 3), ('\u{322e}', 1349, 3), ('\u{322f}', 1352, 3),
+        ('\u{3230}', 1355, 3), ('\u{3231}', 1358, 3), ('\u{3232}', 1361, 3), ('\u{3233}', 1364, 3),
+        ('\u{3234}', 1367, 3), ('\u{3235}', 1370, 3), ('\u{3236}', 1373, 3), ('\u{3237}', 1376, 3),
+        ('\u{3238}', 1379, 3), ('\u{3239}', 1382, 3), ('\u{323a}', 1385, 3), ('\u{323b}', 1388, 3),
+        ('\u{323c}', 1391, 3), ('\u{323d}', 1394, 3), ('\u{323e}', 1397, 3), ('\u{323f}', 1400, 3),
+        ('\u{3240}', 1403, 3), ('\u{3241}', 1406, 3), ('\u{3242}', 1409, 3), ('\u{3243}', 1412, 3),
+        ('\u{3244}', 1415, 1), ('\u{3245}', 1416, 1), ('\u{3246}', 1417, 1), ('\u{3247}', 1418, 1),
+        ('\u{3250}', 1419, 3), ('\u{3251}', 1422, 2), ('\u{3252}', 1424, 2), ('\u{3253}', 1426, 2),
+        ('\u{3254}', 1428, 2), ('\u{3255}', 1430, 2), ('\u{3256}', 1432, 2), ('\u{3257}', 1434, 2),
+        ('\u{3258}', 1436, 2), ('\u{3259}', 1438, 2), ('\u{325a}', 1440, 2), ('\u{325b}', 1442, 2),
+        ('\u{325c}', 1444, 2), ('\u{325d}', 1446, 2), ('\u{325e}', 1448, 2), ('\u{325f}', 1450, 2),
+        ('\u{3260}', 1452, 1), ('\u{3261}', 1453, 1), ('\u{3262}', 1454, 1), ('\u{3263}', 1455, 1),
+        ('\u{3264}', 1456, 1), ('\u{3265}', 1457, 1), ('\u{3266}', 1458, 1), ('\u{3267}', 1459, 1),
+        ('\u{3268}', 1460, 1), ('\u{3269}', 1461, 1), ('\u{326a}', 1462, 1), ('\u{326b}', 1463, 1),
+        ('\u{326c}', 1464, 1), ('\u{326d}', 1465, 1), ('\u{326e}', 1466, 2), ('\u{326f}', 1468, 2),
+        ('\u{3270}', 1470, 2), ('\u{3271}', 1472, 2), ('\u{3272}', 1474, 2), ('\u{3273}', 1476, 2),
+        ('\u{3274}', 1478, 2), ('\u{3275}', 1480, 2), ('\u{3276}', 1482, 2), ('\u{3277}', 1484, 2),
+        ('\u{3278}', 1486, 2), ('\u{3279}', 1488, 2), ('\u{327a}', 1490, 2), ('\u{327b}', 1492, 2),
+        ('\u{327c}', 1494, 5), ('\u{327d}', 1499, 4), ('\u{327e}', 1503, 2), ('\u{3280}', 1505, 1),
+        ('\u{3281}', 1506, 1), ('\u{3282}', 1507, 1), ('\u{3283}', 1508, 1), ('\u{3284}', 1509, 1),
+        ('\u{3285}', 1510, 1), ('\u{3286}', 1511, 1), ('\u{3287}', 1512, 1), ('\u{3288}', 1513, 1),
+        ('\u{3289}', 1514, 1), ('\u{328a}', 1515, 1), ('\u{328b}', 1516, 1), ('\u{328c}', 1517, 1),
+        ('\u{328d}', 1518, 1), ('\u{328e}', 1519, 1), ('\u{328f}', 1520, 1), ('\u{3290}', 1521, 1),
+        ('\u{3291}', 1522, 1), ('\u{3292}', 1523, 1), ('\u{3293}', 1524, 1), ('\u{3294}', 1525, 1),
+        ('\u{3295}', 1526, 1), ('\u{3296}', 1527, 1), ('\u{3297}', 1528, 1), ('\u{3298}', 1529, 1),
+        ('\u{3299}', 1530, 1), ('\u{329a}', 1531, 1), ('\u{329b}', 1532, 1), ('\u{329c}', 1533, 1),
+        ('\u{329d}', 1534, 1), ('\u{329e}', 1535, 1), ('\u{329f}', 1536, 1), ('\u{32a0}', 1537, 1),
+        ('\u{32a1}', 1538, 1), ('\u{32a2}', 1539, 1), ('\u{32a3}', 1540, 1), ('\u{32a4}', 1541, 1),
+        ('\u{32a5}', 1542, 1), ('\u{32a6}', 1543, 1), ('\u{32a7}', 1544, 1), ('\u{32a8}', 1545, 1),
+        ('\u{32a9}', 1546, 1), ('\u{32aa}', 1547, 1), ('\u{32ab}', 1548, 1), ('\u{32ac}', 1549, 1),
+        ('\u{32ad}', 1550, 1), ('\u{32ae}', 1551, 1), ('\u{32af}', 1552, 1), ('\u{32b0}', 1553, 1),
+        ('\u{32b1}', 1554, 2), ('\u{32b2}', 1556, 2), ('\u{32b3}', 1558, 2), ('\u{32b4}', 1560, 2),
+        ('\u{32b5}', 1562, 2), ('\u{32b6}', 1564, 2), ('\u{32b7}', 1566, 2), ('\u{32b8}', 1568, 2),
+        ('\u{32b9}', 1570, 2), ('\u{32ba}', 1572, 2), ('\u{32bb}', 1574, 2), ('\u{32bc}', 1576, 2),
+        ('\u{32bd}', 1578, 2), ('\u{32be}', 1580, 2), ('\u{32bf}', 1582, 2), ('\u{32c0}', 1584, 2),
+        ('\u{32c1}', 1586, 2), ('\u{32c2}', 1588, 2), ('\u{32c3}', 1590, 2), ('\u{32c4}', 1592, 2),
+        ('\u{32c5}', 1594, 2), ('\u{32c6}', 1596, 2), ('\u{32c7}', 1598, 2), ('\u{32c8}', 1600, 2),
+        ('\u{32c9}', 1602, 3), ('\u{32ca}', 1605, 3), ('\u{32cb}', 1608, 3), ('\u{32cc}', 1611, 2),
+        ('\u{32cd}', 1613, 3), ('\u{32ce}', 1616, 2), ('\u{32cf}', 1618, 3), ('\u{32d0}', 1621, 1),
+        ('\u{32d1}', 1622, 1), ('\u{32d2}', 1623, 1), ('\u{32d3}', 1624, 1), ('\u{32d4}', 1625, 1),
+        ('\u{32d5}', 1626, 1), ('\u{32d6}', 1627, 1), ('\u{32d7}', 1628, 1), ('\u{32d8}', 1629, 1),
+        ('\u{32d9}', 1630, 1), ('\u{32da}', 1631, 1), ('\u{32db}', 1632, 1), ('\u{32dc}', 1633, 1),
+        ('\u{32dd}', 1634, 1), ('\u{32de}', 1635, 1), ('\u{32df}', 1636, 1), ('\u{32e0}', 1637, 1),
+        ('\u{32e1}', 1638, 1), ('\u{32e2}', 1639, 1), ('\u{32e3}', 1640, 1), ('\u{32e4}', 1641, 1),
+        ('\u{32e5}', 1642, 1), ('\u{32e6}', 1643, 1), ('\u{32e7}', 1644, 1), ('\u{32e8}', 1645, 1),
+        ('\u{32e9}', 1646, 1), ('\u{32ea}', 1647, 1), ('\u{32eb}', 1648, 1), ('\u{32ec}', 1649, 1),
+        ('\u{32ed}', 1650, 1), ('\u{32ee}', 1651, 1), ('\u{32ef}', 1652, 1), ('\u{32f0}', 1653, 1),
+        ('\u{32f1}', 1654, 1), ('\u{32f2}', 1655, 1), ('\u{32f3}', 1656, 1), ('\u{32f4}', 1657, 1),
+        ('\u{32f5}', 1658, 1), ('\u{32f6}', 1659, 1), ('\u{32f7}', 1660, 1), ('\u{32f8}', 1661, 1),
+        ('\u{32f9}', 1662, 1), ('\u{32fa}', 1663, 1), ('\u{32fb}', 1664, 1), ('\u{32fc}', 1665, 1),
+        ('\u{32fd}', 1666, 1), ('\u{32fe}', 1667, 1), ('\u{32ff}', 1668, 2), ('\u{3300}', 1670, 5),
+        ('\u{3301}', 1675, 4), ('\u{3302}', 1679, 5), ('\u{3303}', 1684, 3), ('\u{3304}', 1687, 5),
+        ('\u{3305}', 1692, 3), ('\u{3306}', 1695, 3), ('\u{3307}', 1698, 6), ('\u{3308}', 1704, 4),
+        ('\u{3309}', 1708, 3), ('\u{330a}', 1711, 3), ('\u{330b}', 1714, 3), ('\u{330c}', 1717, 4),
+        ('\u{330d}', 1721, 4), ('\u{330e}', 1725, 4), ('\u{330f}', 1729, 4), ('\u{3310}', 1733, 4),
+        ('\u{3311}', 1737, 4), ('\u{3312}', 1741, 4), ('\u{3313}', 1745, 6), ('\u{3314}', 1751, 2),
+        ('\u{3315}', 1753, 6), ('\u{3316}', 1759, 6), ('\u{3317}', 1765, 5), ('\u{3318}', 1770, 4),
+        ('\u{3319}', 1774, 6), ('\u{331a}', 1780, 6), ('\u{331b}', 1786, 4), ('\u{331c}', 1790, 3),
+        ('\u{331d}', 1793, 3), ('\u{331e}', 1796, 4), ('\u{331f}', 1800, 4), ('\u{3320}', 1804, 5),
+        ('\u{3321}', 1809, 5), ('\u{3322}', 1814, 3), ('\u{3323}', 1817, 3), ('\u{3324}', 1820, 4),
+        ('\u{3325}', 1824, 3), ('\u{3326}', 1827, 3), ('\u{3327}', 1830, 2), ('\u{3328}', 1832, 2),
+        ('\u{3329}', 1834, 3), ('\u{332a}', 1837, 3), ('\u{332b}', 1840, 6), ('\u{332c}', 1846, 4),
+        ('\u{332d}', 1850, 5), ('\u{332e}', 1855, 6), ('\u{332f}', 1861, 4), ('\u{3330}', 1865, 3),
+        ('\u{3331}', 1868, 3), ('\u{3332}', 1871, 6), ('\u{3333}', 1877, 4), ('\u{3334}', 1881, 6),
+        ('\u{3335}', 1887, 3), ('\u{3336}', 1890, 5), ('\u{3337}', 1895, 3), ('\u{3338}', 1898, 4),
+        ('\u{3339}', 1902, 3), ('\u{333a}', 1905, 4), ('\u{333b}', 1909, 5), ('\u{333c}', 1914, 4),
+        ('\u{333d}', 1918, 5), ('\u{333e}', 1923, 4), ('\u{333f}', 1927, 2), ('\u{3340}', 1929, 5),
+        ('\u{3341}', 1934, 3), ('\u{3342}', 1937, 3), ('\u{3343}', 1940, 4), ('\u{3344}', 1944, 3),
+        ('\u{3345}', 1947, 3), ('\u{3346}', 1950, 3), ('\u{3347}', 1953, 5), ('\u{3348}', 1958, 4),
+        ('\u{3349}', 1962, 2), ('\u{334a}', 1964, 6), ('\u{334b}', 1970, 3), ('\u{334c}', 1973, 5),
+        ('\u{334d}', 1978, 4), ('\u{334e}', 1982, 4), ('\u{334f}', 1986, 3), ('\u{3350}', 1989, 3),
+        ('\u{3351}', 1992, 4), ('\u{3352}', 1996, 2), ('\u{3353}', 1998, 4), ('\u{3354}', 2002, 5),
+        ('\u{3355}', 2007, 2), ('\u{3356}', 2009, 6), ('\u{3357}', 2015, 3), ('\u{3358}', 2018, 2),
+        ('\u{3359}', 2020, 2), ('\u{335a}', 2022, 2), ('\u{335b}', 2024, 2), ('\u{335c}', 2026, 2),
+        ('\u{335d}', 2028, 2), ('\u{335e}', 2030, 2), ('\u{335f}', 2032, 2), ('\u{3360}', 2034, 2),
+        ('\u{3361}', 2036, 2), ('\u{3362}', 2038, 3), ('\u{3363}', 2041, 3), ('\u{3364}', 2044, 3),
+        ('\u{3365}', 2047, 3), ('\u{3366}', 2050, 3), ('\u{3367}', 2053, 3), ('\u{3368}', 2056, 3),
+        ('\u{3369}', 2059, 3), ('\u{336a}', 2062, 3), ('\u{336b}', 2065, 3), ('\u{336c}', 2068, 3),
+        ('\u{336d}', 2071, 3), ('\u{336e}', 2074, 3), ('\u{336f}', 2077, 3), ('\u{3370}', 2080, 3),
+        ('\u{3371}', 2083, 3), ('\u{3372}', 2086, 2), ('\u{3373}', 2088, 2), ('\u{3374}', 2090, 3),
+        ('\u{3375}', 2093, 2), ('\u{3376}', 2095, 2), ('\u{3377}', 2097, 2), ('\u{3378}', 2099, 3),
+        ('\u{3379}', 2102, 3), ('\u{337a}', 2105, 2), ('\u{337b}', 2107, 2), ('\u{337c}', 2109, 2),
+        ('\u{337d}', 2111, 2), ('\u{337e}', 2113, 2), ('\u{337f}', 2115, 4), ('\u{3380}', 2119, 2),
+        ('\u{3381}', 2121, 2), ('\u{3382}', 2123, 2), ('\u{3383}', 2125, 2), ('\u{3384}', 2127, 2),
+        ('\u{3385}', 2129, 2), ('\u{3386}', 2131, 2), ('\u{3387}', 2133, 2), ('\u{3388}', 2135, 3),
+        ('\u{3389}', 2138, 4), ('\u{338a}', 2142, 2), ('\u{338b}', 2144, 2), ('\u{338c}', 2146, 2),
+        ('\u{338d}', 2148, 2), ('\u{338e}', 2150, 2), ('\u{338f}', 2152, 2), ('\u{3390}', 2154, 2),
+        ('\u{3391}', 2156, 3), ('\u{3392}', 2159, 3), ('\u{3393}', 2162, 3), ('\u{3394}', 2165, 3),
+        ('\u{3395}', 2168, 2), ('\u{3396}', 2170, 2), ('\u{3397}', 2172, 2), ('\u{3398}', 2174, 2),
+        ('\u{3399}', 2176, 2), ('\u{339a}', 2178, 2), ('\u{339b}', 2180, 2), ('\u{339c}', 2182, 2),
+        ('\u{339d}', 2184, 2), ('\u{339e}', 2186, 2), ('\u{339f}', 2188, 3), ('\u{33a0}', 2191, 3),
+        ('\u{33a1}', 2194, 2), ('\u{33a2}', 2196, 3), ('\u{33a3}', 2199, 3), ('\u{33a4}', 2202, 3),
+        ('\u{33a5}', 2205, 2), ('\u{33a6}', 2207, 3), ('\u{33a7}', 2210, 3), ('\u{33a8}', 2213, 4),
+        ('\u{33a9}', 2217, 2), ('\u{33aa}', 2219, 3), ('\u{33ab}', 2222, 3), ('\u{33ac}', 2225, 3),
+        ('\u{33ad}', 2228, 3), ('\u{33ae}', 2231, 5), ('\u{33af}', 2236, 6), ('\u{33b0}', 2242, 2),
+        ('\u{33b1}', 2244, 2), ('\u{33b2}', 2246, 2), ('\u{33b3}', 2248, 2), ('\u{33b4}', 2250, 2),
+        ('\u{33b5}', 2252, 2), ('\u{33b6}', 2254, 2), ('\u{33b7}', 2256, 2), ('\u{33b8}', 2258, 2),
+        ('\u{33b9}', 2260, 2), ('\u{33ba}', 2262, 2), ('\u{33bb}', 2264, 2), ('\u{33bc}', 2266, 2),
+        ('\u{33bd}', 2268, 2), ('\u{33be}', 2270, 2), ('\u{33bf}', 2272, 2), ('\u{33c0}', 2274, 2),
+        ('\u{33c1}', 2276, 2), ('\u{33c2}', 2278, 4), ('\u{33c3}', 2282, 2), ('\u{33c4}', 2284, 2),
+        ('\u{33c5}', 2286, 2), ('\u{33c6}', 2288, 4), ('\u{33c7}', 2292, 3), ('\u{33c8}', 2295, 2),
+        ('\u{33c9}', 2297, 2), ('\u{33ca}', 2299, 2), ('\u{33cb}', 2301, 2), ('\u{33cc}', 2303, 2),
+        ('\u{33cd}', 2305, 2), ('\u{33ce}', 2307, 2), ('\u{33cf}', 2309, 2), ('\u{33d0}', 2311, 2),
+        ('\u{33d1}', 2313, 2), ('\u{33d2}', 2315, 3), ('\u{33d3}', 2318, 2), ('\u{33d4}', 2320, 2),
+        ('\u{33d5}', 2322, 3), ('\u{33d6}', 2325, 3), ('\u{33d7}', 2328, 2), ('\u{33d8}', 2330, 4),
+        ('\u{33d9}', 2334, 3), ('\u{33da}', 2337, 2), ('\u{33db}', 2339, 2), ('\u{33dc}', 2341, 2),
+        ('\u{33dd}', 2343, 2), ('\u{33de}', 2345, 3), ('\u{33df}', 2348, 3), ('\u{33e0}', 2351, 2),
+        ('\u{33e1}', 2353, 2), ('\u{33e2}', 2355, 2), ('\u{33e3}', 2357, 2), ('\u{33e4}', 2359, 2),
+        ('\u{33e5}', 2361, 2), ('\u{33e6}', 2363, 2), ('\u{33e7}', 2365, 2), ('\u{33e8}', 2367, 2),
+        ('\u{33e9}', 2369, 3), ('\u{33ea}', 2372, 3), ('\u{33eb}', 2375, 3), ('\u{33ec}', 2378, 3),
+        ('\u{33ed}', 2381, 3), ('\u{33ee}', 2384, 3), ('\u{33ef}', 2387, 3), ('\u{33f0}', 2390, 3),
+        ('\u{33f1}', 2393, 3), ('\u{33f2}', 2396, 3), ('\u{33f3}', 2399, 3), ('\u{33f4}', 2402, 3),
+        ('\u{33f5}', 2405, 3), ('\u{33f6}', 2408, 3), ('\u{33f7}', 2411, 3), ('\u{33f8}', 2414, 3),
+        ('\u{33f9}', 2417, 3), ('\u{33fa}', 2420, 3), ('\u{33fb}', 2423, 3), ('\u{33fc}', 2426, 3),
+        ('\u{33fd}', 2429, 3), ('\u{33fe}', 2432, 3), ('\u{33ff}', 2435, 3), ('\u{a69c}', 2438, 1),
+        ('\u{a69d}', 2439, 1), ('\u{a770}', 2440, 1), ('\u{a7f2}', 2441, 1), ('\u{a7f3}', 2442, 1),
+        ('\u{a7f4}', 2443, 1), ('\u{a7f8}', 2444, 1), ('\u{a7f9}', 2445, 1), ('\u{ab5c}', 2446, 1),
+        ('\u{ab5d}', 2447, 1), ('\u{ab5e}', 2448, 1), ('\u{ab5f}', 2449, 1), ('\u{ab69}', 2450, 1),
+        ('\u{fb00}', 2451, 2), ('\u{fb01}', 2453, 2), ('\u{fb02}', 2455, 2), ('\u{fb03}', 2457, 3),
+        ('\u{fb04}', 2460, 3), ('\u{fb05}', 2463, 2), ('\u{fb06}', 2465, 2), ('\u{fb13}', 2467, 2),
+        ('\u{fb14}', 2469, 2), ('\u{fb15}', 2471, 2), ('\u{fb16}', 2473, 2), ('\u{fb17}', 2475, 2),
+        ('\u{fb20}', 2477, 1), ('\u{fb21}', 2478, 1), ('\u{fb22}', 2479, 1), ('\u{fb23}', 2480, 1),
+        ('\u{fb24}', 2481, 1), ('\u{fb25}', 2482, 1), ('\u{fb26}', 2483, 1), ('\u{fb27}', 2484, 1),
+        ('\u{fb28}', 2485, 1), ('\u{fb29}', 2486, 1), ('\u{fb4f}', 2487, 2), ('\u{fb50}', 2489, 1),
+        ('\u{fb51}', 2490, 1), ('\u{fb52}', 2491, 1), ('\u{fb53}', 2492, 1), ('\u{fb54}', 2493, 1),
+        ('\u{fb55}', 2494, 1), ('\u{fb56}', 2495, 1), ('\u{fb57}', 2496, 1), ('\u{fb58}', 2497, 1),
+        ('\u{fb59}', 2498, 1), ('\u{fb5a}', 2499, 1), ('\u{fb5b}', 2500, 1), ('\u{fb5c}', 2501, 1),
+        ('\u{fb5d}', 2502, 1), ('\u{fb5e}', 2503, 1), ('\u{fb5f}', 2504, 1), ('\u{fb60}', 2505, 1),
+        ('\u{fb61}', 2506, 1), ('\u{fb62}', 2507, 1), ('\u{fb63}', 2508, 1), ('\u{fb64}', 2509, 1),
+        ('\u{fb65}', 2510, 1), ('\u{fb66}', 2511, 1), ('\u{fb67}', 2512, 1), ('\u{fb68}', 2513, 1),
+        ('\u{fb69}', 2514, 1), ('\u{fb6a}', 2515, 1), ('\u{fb6b}', 2516, 1), ('\u{fb6c}', 2517, 1),
+        ('\u{fb6d}', 2518, 1), ('\u{fb6e}', 2519, 1), ('\u{fb6f}', 2520, 1), ('\u{fb70}', 2521, 1),
+        ('\u{fb71}', 2522, 1), ('\u{fb72}', 2523, 1), ('\u{fb73}', 2524, 1), ('\u{fb74}', 2525, 1),
+        ('\u{fb75}', 2526, 1), ('\u{fb76}', 2527, 1), ('\u{fb77}', 2528, 1), ('\u{fb78}', 2529, 1),
+        ('\u{fb79}', 2530, 1), ('\u{fb7a}', 2531, 1), ('\u{fb7b}', 2532, 1), ('\u{fb7c}', 2533, 1),
+        ('\u{fb7d}', 2534, 1), ('\u{fb7e}', 2535, 1), ('\u{fb7f}', 2536, 1), ('\u{fb80}', 2537, 1),
+        ('\u{fb81}', 2538, 1), ('\u{fb82}', 2539, 1), ('\u{fb83}', 2540, 1), ('\u{fb84}', 2541, 1),
+        ('\u{fb85}', 2542, 1), ('\u{fb86}', 2543, 1), ('\u{fb87}', 2544, 1), ('\u{fb88}', 2545, 1),
+        ('\u{fb89}', 2546, 1), ('\u{fb8a}', 2547, 1), ('\u{fb8b}', 2548, 1), ('\u{fb8c}', 2549, 1),
+        ('\u{fb8d}', 2550, 1), ('\u{fb8e}', 2551, 1), ('\u{fb8f}', 2552, 1), ('\u{fb90}', 2553, 1),
+        ('\u{fb91}', 2554, 1), ('\u{fb92}', 2555, 1), ('\u{fb93}', 2556, 1), ('\u{fb94}', 2557, 1),
+        ('\u{fb95}', 2558, 1), ('\u{fb96}', 2559, 1), ('\u{fb97}', 2560, 1), ('\u{fb98}', 2561, 1),
+        ('\u{fb99}', 2562, 1), ('\u{fb9a}', 2563, 1), ('\u{fb9b}', 2564, 1), ('\u{fb9c}', 2565, 1),
+        ('\u{fb9d}', 2566, 1), ('\u{fb9e}', 2567, 1), ('\u{fb9f}', 2568, 1), ('\u{fba0}', 2569, 1),
+        ('\u{fba1}', 2570, 1), ('\u{fba2}', 2571, 1), ('\u{fba3}', 2572, 1), ('\u{fba4}', 2573, 2),
+        ('\u{fba5}', 2575, 2), ('\u{fba6}', 2577, 1), ('\u{fba7}', 2578, 1), ('\u{fba8}', 2579, 1),
+        ('\u{fba9}', 2580, 1), ('\u{fbaa}', 2581, 1), ('\u{fbab}', 2582, 1), ('\u{fbac}', 2583, 1),
+        ('\u{fbad}', 2584, 1), ('\u{fbae}', 2585, 1), ('\u{fbaf}', 2586, 1), ('\u{fbb0}', 2587, 2),
+        ('\u{fbb1}', 2589, 2), ('\u{fbd3}', 2591, 1), ('\u{fbd4}', 2592, 1), ('\u{fbd5}', 2593, 1),
+        ('\u{fbd6}', 2594, 1), ('\u{fbd7}', 2595, 1), ('\u{fbd8}', 2596, 1), ('\u{fbd9}', 2597, 1),
+        ('\u{fbda}', 2598, 1), ('\u{fbdb}', 2599, 1), ('\u{fbdc}', 2600, 1), ('\u{fbdd}', 2601, 2),
+        ('\u{fbde}', 2603, 1), ('\u{fbdf}', 2604, 1), ('\u{fbe0}', 2605, 1), ('\u{fbe1}', 2606, 1),
+        ('\u{fbe2}', 2607, 1), ('\u{fbe3}', 2608, 1), ('\u{fbe4}', 2609, 1), ('\u{fbe5}', 2610, 1),
+        ('\u{fbe6}', 2611, 1), ('\u{fbe7}', 2612, 1), ('\u{fbe8}', 2613, 1), ('\u{fbe9}', 2614, 1),
+        ('\u{fbea}', 2615, 3), ('\u{fbeb}', 2618, 3), ('\u{fbec}', 2621, 3), ('\u{fbed}', 2624, 3),
+        ('\u{fbee}', 2627, 3), ('\u{fbef}', 2630, 3), ('\u{fbf0}', 2633, 3), ('\u{fbf1}', 2636, 3),
+        ('\u{fbf2}', 2639, 3), ('\u{fbf3}', 2642, 3), ('\u{fbf4}', 2645, 3), ('\u{fbf5}', 2648, 3),
+        ('\u{fbf6}', 2651, 3), ('\u{fbf7}', 2654, 3), ('\u{fbf8}', 2657, 3), ('\u{fbf9}', 2660, 3),
+        ('\u{fbfa}', 2663, 3), ('\u{fbfb}', 2666, 3), ('\u{fbfc}', 2669, 1), ('\u{fbfd}', 2670, 1),
+        ('\u{fbfe}', 2671, 1), ('\u{fbff}', 2672, 1), ('\u{fc00}', 2673, 3), ('\u{fc01}', 2676, 3),
+        ('\u{fc02}', 2679, 3), ('\u{fc03}', 2682, 3), ('\u{fc04}', 2685, 3), ('\u{fc05}', 2688, 2),
+        ('\u{fc06}', 2690, 2), ('\u{fc07}', 2692, 2), ('\u{fc08}', 2694, 2), ('\u{fc09}', 2696, 2),
+        ('\u{fc0a}', 2698, 2), ('\u{fc0b}', 2700, 2), ('\u{fc0c}', 2702, 2), ('\u{fc0d}', 2704, 2),
+        ('\u{fc0e}', 2706, 2), ('\u{fc0f}', 2708, 2), ('\u{fc10}', 2710, 2), ('\u{fc11}', 2712, 2),
+        ('\u{fc12}', 2714, 2), ('\u{fc13}', 2716, 2), ('\u{fc14}', 2718, 2), ('\u{fc15}', 2720, 2),
+        ('\u{fc16}', 2722, 2), ('\u{fc17}', 2724, 2), ('\u{fc18}', 2726, 2), ('\u{fc19}', 2728, 2),
+        ('\u{fc1a}', 2730, 2), ('\u{fc1b}', 2732, 2), ('\u{fc1c}', 2734, 2), ('\u{fc1d}', 2736, 2),
+        ('\u{fc1e}', 2738, 2), ('\u{fc1f}', 2740, 2), ('\u{fc20}', 2742, 2), ('\u{fc21}', 2744, 2),
+        ('\u{fc22}', 2746, 2), ('\u{fc23}', 2748, 2), ('\u{fc24}', 2750, 2), ('\u{fc25}', 2752, 2),
+        ('\u{fc26}', 2754, 2), ('\u{fc27}', 2756, 2), ('\u{fc28}', 2758, 2), ('\u{fc29}', 2760, 2),
+        ('\u{fc2a}', 2762, 2), ('\u{fc2b}', 2764, 2), ('\u{fc2c}', 2766, 2), ('\u{fc2d}', 2768, 2),
+        ('\u{fc2e}', 2770, 2), ('\u{fc2f}', 2772, 2), ('\u{fc30}', 2774, 2), ('\u{fc31}', 2776, 2),
+        ('\u{fc32}', 2778, 2), ('\u{fc33}', 2780, 2), ('\u{fc34}', 2782, 2), ('\u{fc35}', 2784, 2),
+        ('\u{fc36}', 2786, 2), ('\u{fc37}', 2788, 2), ('\u{fc38}', 2790, 2), ('\u{fc39}', 2792, 2),
+        ('\u{fc3a}', 2794, 2), ('\u{fc3b}', 2796, 2), ('\u{fc3c}', 2798, 2), ('\u{fc3d}', 2800, 2),
+        ('\u{fc3e}', 2802, 2), ('\u{fc3f}', 2804, 2), ('\u{fc40}', 2806, 2), ('\u{fc41}', 2808, 2),
+        ('\u{fc42}', 2810, 2), ('\u{fc43}', 2812, 2), ('\u{fc44}', 2814, 2), ('\u{fc45}', 2816, 2),
+        ('\u{fc46}', 2818, 2), ('\u{fc47}', 2820, 2), ('\u{fc48}', 2822, 2), ('\u{fc49}', 2824, 2),
+        ('\u{fc4a}', 2826, 2), ('\u{fc4b}', 2828, 2), ('\u{fc4c}', 2830, 2), ('\u{fc4d}', 2832, 2),
+        ('\u{fc4e}', 2834, 2), ('\u{fc4f}', 2836, 2), ('\u{fc50}', 2838, 2), ('\u{fc51}', 2840, 2),
+        ('\u{fc52}', 2842, 2), ('\u{fc53}', 2844, 2), ('\u{fc54}', 2846, 2), ('\u{fc55}', 2848, 2),
+        ('\u{fc56}', 2850, 2), ('\u{fc57}', 2852, 2), ('\u{fc58}', 2854, 2), ('\u{fc59}', 2856, 2),
+        ('\u{fc5a}', 2858, 2), ('\u{fc5b}', 2860, 2), ('\u{fc5c}', 2862, 2), ('\u{fc5d}', 2864, 2),
+        ('\u{fc5e}', 2866, 3), ('\u{fc5f}', 2869, 3), ('\u{fc60}', 2872, 3), ('\u{fc61}', 2875, 3),
+        ('\u{fc62}', 2878, 3), ('\u{fc63}', 2881, 3), ('\u{fc64}', 2884, 3), ('\u{fc65}', 2887, 3),
+        ('\u{fc66}', 2890, 3), ('\u{fc67}', 2893, 3), ('\u{fc68}', 2896, 3), ('\u{fc69}', 2899, 3),
+        ('\u{fc6a}', 2902, 2), ('\u{fc6b}', 2904, 2), ('\u{fc6c}', 2906, 2), ('\u{fc6d}', 2908, 2),
+        ('\u{fc6e}', 2910, 2), ('\u{fc6f}', 2912, 2), ('\u{fc70}', 2914, 2), ('\u{fc71}', 2916, 2),
+        ('\u{fc72}', 2918, 2), ('\u{fc73}', 2920, 2), ('\u{fc74}', 2922, 2), ('\u{fc75}', 2924, 2),
+        ('\u{fc76}', 2926, 2), ('\u{fc77}', 2928, 2), ('\u{fc78}', 2930, 2), ('\u{fc79}', 2932, 2),
+        ('\u{fc7a}', 2934, 2), ('\u{fc7b}', 2936, 2), ('\u{fc7c}', 2938, 2), ('\u{fc7d}', 2940, 2),
+        ('\u{fc7e}', 2942, 2), ('\u{fc7f}', 2944, 2), ('\u{fc80}', 2946, 2), ('\u{fc81}', 2948, 2),
+        ('\u{fc82}', 2950, 2), ('\u{fc83}', 2952, 2), ('\u{fc84}', 2954, 2), ('\u{fc85}', 2956, 2),
+        ('\u{fc86}', 2958, 2), ('\u{fc87}', 2960, 2), ('\u{fc88}', 2962, 2), ('\u{fc89}', 2964, 2),
+        ('\u{fc8a}', 2966, 2), ('\u{fc8b}', 2968, 2), ('\u{fc8c}', 2970, 2), ('\u{fc8d}', 2972, 2),
+        ('\u{fc8e}', 2974, 2), ('\u{fc8f}', 2976, 2), ('\u{fc90}', 2978, 2), ('\u{fc91}', 2980, 2),
+        ('\u{fc92}', 2982, 2), ('\u{fc93}', 2984, 2), ('\u{fc94}', 2986, 2), ('\u{fc95}', 2988, 2),
+        ('\u{fc96}', 2990, 2), ('\u{fc97}', 2992, 3), ('\u{fc98}', 2995, 3), ('\u{fc99}', 2998, 3),
+        ('\u{fc9a}', 3001, 3), ('\u{fc9b}', 3004, 3), ('\u{fc9c}', 3007, 2), ('\u{fc9d}', 3009, 2),
+        ('\u{fc9e}', 3011, 2), ('\u{fc9f}', 3013, 2), ('\u{fca0}', 3015, 2), ('\u{fca1}', 3017, 2),
+        ('\u{fca2}', 3019, 2), ('\u{fca3}', 3021, 2), ('\u{fca4}', 3023, 2), ('\u{fca5}', 3025, 2),
+        ('\u{fca6}', 3027, 2), ('\u{fca7}', 3029, 2), ('\u{fca8}', 3031, 2), ('\u{fca9}', 3033, 2),
+        ('\u{fcaa}', 3035, 2), ('\u{fcab}', 3037, 2), ('\u{fcac}', 3039, 2), ('\u{fcad}', 3041, 2),
+        ('\u{fcae}', 3043, 2), ('\u{fcaf}', 3045, 2), ('\u{fcb0}', 3047, 2), ('\u{fcb1}', 3049, 2),
+        ('\u{fcb2}', 3051, 2), ('\u{fcb3}', 3053, 2), ('\u{fcb4}', 3055, 2), ('\u{fcb5}', 3057, 2),
+        ('\u{fcb6}', 3059, 2), ('\u{fcb7}', 3061, 2), ('\u{fcb8}', 3063, 2), ('\u{fcb9}', 3065, 2),
+        ('\u{fcba}', 3067, 2), ('\u{fcbb}', 3069, 2), ('\u{fcbc}', 3071, 2), ('\u{fcbd}', 3073, 2),
+        ('\u{fcbe}', 3075, 2), ('\u{fcbf}', 3077, 2), ('\u{fcc0}', 3079, 2), ('\u{fcc1}', 3081, 2),
+        ('\u{fcc2}', 3083, 2), ('\u{fcc3}', 3085, 2), ('\u{fcc4}', 3087, 2), ('\u{fcc5}', 3089, 2),
+        ('\u{fcc6}', 3091, 2), ('\u{fcc7}', 3093, 2), ('\u{fcc8}', 3095, 2), ('\u{fcc9}', 3097, 2),
+        ('\u{fcca}', 3099, 2), ('\u{fccb}', 3101, 2), ('\u{fccc}', 3103, 2), ('\u{fccd}', 3105, 2),
+        ('\u{fcce}', 3107, 2), ('\u{fccf}', 3109, 2), ('\u{fcd0}', 3111, 2), ('\u{fcd1}', 3113, 2),
+        ('\u{fcd2}', 3115, 2), ('\u{fcd3}', 3117, 2), ('\u{fcd4}', 3119, 2), ('\u{fcd5}', 3121, 2),
+        ('\u{fcd6}', 3123, 2), ('\u{fcd7}', 3125, 2), ('\u{fcd8}', 3127, 2), ('\u{fcd9}', 3129, 2),
+        ('\u{fcda}', 3131, 2), ('\u{fcdb}', 3133, 2), ('\u{fcdc}', 3135, 2), ('\u{fcdd}', 3137, 2),
+        ('\u{fcde}', 3139, 2), ('\u{fcdf}', 3141, 3), ('\u{fce0}', 3144, 3), ('\u{fce1}', 3147, 2),
+        ('\u{fce2}', 3149, 2), ('\u{fce3}', 3151, 2), ('\u{fce4}', 3153, 2), ('\u{fce5}', 3155, 2),
+        ('\u{fce6}', 3157, 2), ('\u{fce7}', 3159, 2), ('\u{fce8}', 3161, 2), ('\u{fce9}', 3163, 2),
+        ('\u{fcea}', 3165, 2), ('\u{fceb}', 3167, 2), ('\u{fcec}', 3169, 2), ('\u{fced}', 3171, 2),
+        ('\u{fcee}', 3173, 2), ('\u{fcef}', 3175, 2), ('\u{fcf0}', 3177, 2), ('\u{fcf1}', 3179, 2),
+        ('\u{fcf2}', 3181, 3), ('\u{fcf3}', 3184, 3), ('\u{fcf4}', 3187, 3), ('\u{fcf5}', 3190, 2),
+        ('\u{fcf6}', 3192, 2), ('\u{fcf7}', 3194, 2), ('\u{fcf8}', 3196, 2), ('\u{fcf9}', 3198, 2),
+        ('\u{fcfa}', 3200, 2), ('\u{fcfb}', 3202, 2), ('\u{fcfc}', 3204, 2), ('\u{fcfd}', 3206, 2),
+        ('\u{fcfe}', 3208, 2), ('\u{fcff}', 3210, 2), ('\u{fd00}', 3212, 2), ('\u{fd01}', 3214, 2),
+        ('\u{fd02}', 3216, 2), ('\u{fd03}', 3218, 2), ('\u{fd04}', 3220, 2), ('\u{fd05}', 3222, 2),
+        ('\u{fd06}', 3224, 2), ('\u{fd07}', 3226, 2), ('\u{fd08}', 3228, 2), ('\u{fd09}', 3230, 2),
+        ('\u{fd0a}', 3232, 2), ('\u{fd0b}', 3234, 2), ('\u{fd0c}', 3236, 2), ('\u{fd0d}', 3238, 2),
+        ('\u{fd0e}', 3240, 2), ('\u{fd0f}', 3242, 2), ('\u{fd10}', 3244, 2), ('\u{fd11}', 3246, 2),
+        ('\u{fd12}', 3248, 2), ('\u{fd13}', 3250, 2), ('\u{fd14}', 3252, 2), ('\u{fd15}', 3254, 2),
+        ('\u{fd16}', 3256, 2), ('\u{fd17}', 3258, 2), ('\u{fd18}', 3260, 2), ('\u{fd19}', 3262, 2),
+        ('\u{fd1a}', 3264, 2), ('\u{fd1b}', 3266, 2), ('\u{fd1c}', 3268, 2), ('\u{fd1d}', 3270, 2),
+        ('\u{fd1e}', 3272, 2), ('\u{fd1f}', 3274, 2), ('\u{fd20}', 3276, 2), ('\u{fd21}', 3278, 2),
+        ('\u{fd22}', 3280, 2), ('\u{fd23}', 3282, 2), ('\u{fd24}', 3284, 2), ('\u{fd25}', 3286, 2),
+        ('\u{fd26}', 3288, 2), ('\u{fd27}', 3290, 2), ('\u{fd28}', 3292, 2), ('\u{fd29}', 3294, 2),
+        ('\u{fd2a}', 3296, 2), ('\u{fd2b}', 3298, 2), ('\u{fd2c}', 3300, 2), ('\u{fd2d}', 3302, 2),
+        ('\u{fd2e}', 3304, 2), ('\u{fd2f}', 3306, 2), ('\u{fd30}', 3308, 2), ('\u{fd31}', 3310, 2),
+        ('\u{fd32}', 3312, 2), ('\u{fd33}', 3314, 2), ('\u{fd34}', 3316, 2), ('\u{fd35}', 3318, 2),
+        ('\u{fd36}', 3320, 2), ('\u{fd37}', 3322, 2), ('\u{fd38}', 3324, 2), ('\u{fd39}', 3326, 2),
+        ('\u{fd3a}', 3328, 2), ('\u{fd3b}', 3330, 2), ('\u{fd3c}', 3332, 2), ('\u{fd3d}', 3334, 2),
+        ('\u{fd50}', 3336, 3), ('\u{fd51}', 3339, 3), ('\u{fd52}', 3342, 3), ('\u{fd53}', 3345, 3),
+        ('\u{fd54}', 3348, 3), ('\u{fd55}', 3351, 3), ('\u{fd56}', 3354, 3), ('\u{fd57}', 3357, 3),
+        ('\u{fd58}', 3360, 3), ('\u{fd59}', 3363, 3), ('\u{fd5a}', 3366, 3), ('\u{fd5b}', 3369, 3),
+        ('\u{fd5c}', 3372, 3), ('\u{fd5d}', 3375, 3), ('\u{fd5e}', 3378, 3), ('\u{fd5f}', 3381, 3),
+        ('\u{fd60}', 3384, 3), ('\u{fd61}', 3387, 3), ('\u{fd62}', 3390, 3), ('\u{fd63}', 3393, 3),
+        ('\u{fd64}', 3396, 3), ('\u{fd65}', 3399, 3), ('\u{fd66}', 3402, 3), ('\u{fd67}', 3405, 3),
+        ('\u{fd68}', 3408, 3), ('\u{fd69}', 3411, 3), ('\u{fd6a}', 3414, 3), ('\u{fd6b}', 3417, 3),
+        ('\u{fd6c}', 3420, 3), ('\u{fd6d}', 3423, 3), ('\u{fd6e}', 3426, 3), ('\u{fd6f}', 3429, 3),
+        ('\u{fd70}', 3432, 3), ('\u{fd71}', 3435, 3), ('\u{fd72}', 3438, 3), ('\u{fd73}', 3441, 3),
+        ('\u{fd74}', 3444, 3), ('\u{fd75}', 3447, 3), ('\u{fd76}', 3450, 3), ('\u{fd77}', 3453, 3),
+        ('\u{fd78}', 3456, 3), ('\u{fd79}', 3459, 3), ('\u{fd7a}', 3462, 3), ('\u{fd7b}', 3465, 3),
+        ('\u{fd7c}', 3468, 3), ('\u{fd7d}', 3471, 3), ('\u{fd7e}', 3474, 3), ('\u{fd7f}', 3477, 3),
+        ('\u{fd80}', 3480, 3), ('\u{fd81}', 3483, 3), ('\u{fd82}', 3486, 3), ('\u{fd83}', 3489, 3),
+        ('\u{fd84}', 3492, 3), ('\u{fd85}', 3495, 3), ('\u{fd86}', 3498, 3), ('\u{fd87}', 3501, 3),
+        ('\u{fd88}', 3504, 3), ('\u{fd89}', 3507, 3), ('\u{fd8a}', 3510, 3), ('\u{fd8b}', 3513, 3),
+        ('\u{fd8c}', 3516, 3), ('\u{fd8d}', 3519, 3), ('\u{fd8e}', 3522, 3), ('\u{fd8f}', 3525, 3),
+        ('\u{fd92}', 3528, 3), ('\u{fd93}', 3531, 3), ('\u{fd94}', 3534, 3), ('\u{fd95}', 3537, 3),
+        ('\u{fd96}', 3540, 3), ('\u{fd97}', 3543, 3), ('\u{fd98}', 3546, 3), ('\u{fd99}', 3549, 3),
+        ('\u{fd9a}', 3552, 3), ('\u{fd9b}', 3555, 3), ('\u{fd9c}', 3558, 3), ('\u{fd9d}', 3561, 3),
+        ('\u{fd9e}', 3564, 3), ('\u{fd9f}', 3567, 3), ('\u{fda0}', 3570, 3), ('\u{fda1}', 3573, 3),
+        ('\u{fda2}', 3576, 3), ('\u{fda3}', 3579, 3), ('\u{fda4}', 3582, 3), ('\u{fda5}', 3585, 3),
+        ('\u{fda6}', 3588, 3), ('\u{fda7}', 3591, 3), ('\u{fda8}', 3594, 3), ('\u{fda9}', 3597, 3),
+        ('\u{fdaa}', 3600, 3), ('\u{fdab}', 3603, 3), ('\u{fdac}', 3606, 3), ('\u{fdad}', 3609, 3),
+        ('\u{fdae}', 3612, 3), ('\u{fdaf}', 3615, 3), ('\u{fdb0}', 3618, 3), ('\u{fdb1}', 3621, 3),
+        ('\u{fdb2}', 3624, 3), ('\u{fdb3}', 3627, 3), ('\u{fdb4}', 3630, 3), ('\u{fdb5}', 3633, 3),
+        ('\u{fdb6}', 3636, 3), ('\u{fdb7}', 3639, 3), ('\u{fdb8}', 3642, 3), ('\u{fdb9}', 3645, 3),
+        ('\u{fdba}', 3648, 3), ('\u{fdbb}', 3651, 3), ('\u{fdbc}', 3654, 3), ('\u{fdbd}', 3657, 3),
+        ('\u{fdbe}', 3660, 3), ('\u{fdbf}', 3663, 3), ('\u{fdc0}', 3666, 3), ('\u{fdc1}', 3669, 3),
+        ('\u{fdc2}', 3672, 3), ('\u{fdc3}', 3675, 3), ('\u{fdc4}', 3678, 3), ('\u{fdc5}', 3681, 3),
+        ('\u{fdc6}', 3684, 3), ('\u{fdc7}', 3687, 3), ('\u{fdf0}', 3690, 3), ('\u{fdf1}', 3693, 3),
+        ('\u{fdf2}', 3696, 4), ('\u{fdf3}', 3700, 4), ('\u{fdf4}', 3704, 4), ('\u{fdf5}', 3708, 4),
+        ('\u{fdf6}', 3712, 4), ('\u{fdf7}', 3716, 4), ('\u{fdf8}', 3720, 4), ('\u{fdf9}', 3724, 3),
+        ('\u{fdfa}', 3727, 18), ('\u{fdfb}', 3745, 8), ('\u{fdfc}', 3753, 4), ('\u{fe10}', 3757, 1),
+        ('\u{fe11}', 3758, 1), ('\u{fe12}', 3759, 1), ('\u{fe13}', 3760, 1), ('\u{fe14}', 3761, 1),
+        ('\u{fe15}', 3762, 1), ('\u{fe16}', 3763, 1), ('\u{fe17}', 3764, 1), ('\u{fe18}', 3765, 1),
+        ('\u{fe19}', 3766, 3), ('\u{fe30}', 3769, 2), ('\u{fe31}', 3771, 1), ('\u{fe32}', 3772, 1),
+        ('\u{fe33}', 3773, 1), ('\u{fe34}', 3774, 1), ('\u{fe35}', 3775, 1), ('\u{fe36}', 3776, 1),
+        ('\u{fe37}', 3777, 1), ('\u{fe38}', 3778, 1), ('\u{fe39}', 3779, 1), ('\u{fe3a}', 3780, 1),
+        ('\u{fe3b}', 3781, 1), ('\u{fe3c}', 3782, 1), ('\u{fe3d}', 3783, 1), ('\u{fe3e}', 3784, 1),
+        ('\u{fe3f}', 3785, 1), ('\u{fe40}', 3786, 1), ('\u{fe41}', 3787, 1), ('\u{fe42}', 3788, 1),
+        ('\u{fe43}', 3789, 1), ('\u{fe44}', 3790, 1), ('\u{fe47}', 3791, 1), ('\u{fe48}', 3792, 1),
+        ('\u{fe49}', 3793, 2), ('\u{fe4a}', 3795, 2), ('\u{fe4b}', 3797, 2), ('\u{fe4c}', 3799, 2),
+        ('\u{fe4d}', 3801, 1), ('\u{fe4e}', 3802, 1), ('\u{fe4f}', 3803, 1), ('\u{fe50}', 3804, 1),
+        ('\u{fe51}', 3805, 1), ('\u{fe52}', 3806, 1), ('\u{fe54}', 3807, 1), ('\u{fe55}', 3808, 1),
+        ('\u{fe56}', 3809, 1), ('\u{fe57}', 3810, 1), ('\u{fe58}', 3811, 1), ('\u{fe59}', 3812, 1),
+        ('\u{fe5a}', 3813, 1), ('\u{fe5b}', 3814, 1), ('\u{fe5c}', 3815, 1), ('\u{fe5d}', 3816, 1),
+        ('\u{fe5e}', 3817, 1), ('\u{fe5f}', 3818, 1), ('\u{fe60}', 3819, 1), ('\u{fe61}', 3820, 1),
+        ('\u{fe62}', 3821, 1), ('\u{fe63}', 3822, 1), ('\u{fe64}', 3823, 1), ('\u{fe65}', 3824, 1),
+        ('\u{fe66}', 3825, 1), ('\u{fe68}', 3826, 1), ('\u{fe69}', 3827, 1), ('\u{fe6a}', 3828, 1),
+        ('\u{fe6b}', 3829, 1), ('\u{fe70}', 3830, 2), ('\u{fe71}', 3832, 2), ('\u{fe72}', 3834, 2),
+        ('\u{fe74}', 3836, 2), ('\u{fe76}', 3838, 2), ('\u{fe77}', 3840, 2), ('\u{fe78}', 3842, 2),
+        ('\u{fe79}', 3844, 2), ('\u{fe7a}', 3846, 2), ('\u{fe7b}', 3848, 2), ('\u{fe7c}', 3850, 2),
+        ('\u{fe7d}', 3852, 2), ('\u{fe7e}', 3854, 2), ('\u{fe7f}', 3856, 2), ('\u{fe80}', 3858, 1),
+        ('\u{fe81}', 3859, 2), ('\u{fe82}', 3861, 2), ('\u{fe83}', 3863, 2), ('\u{fe84}', 3865, 2),
+        ('\u{fe85}', 3867, 2), ('\u{fe86}', 3869, 2), ('\u{fe87}', 3871, 2), ('\u{fe88}', 3873, 2),
+        ('\u{fe89}', 3875, 2), ('\u{fe8a}', 3877, 2), ('\u{fe8b}', 3879, 2), ('\u{fe8c}', 3881, 2),
+        ('\u{fe8d}', 3883, 1), ('\u{fe8e}', 3884, 1), ('\u{fe8f}', 3885, 1), ('\u{fe90}', 3886, 1),
+        ('\u{fe91}', 3887, 1), ('\u{fe92}', 3888, 1), ('\u{fe93}', 3889, 1), ('\u{fe94}', 3890, 1),
+        ('\u{fe95}', 3891, 1), ('\u{fe96}', 3892, 1), ('\u{fe97}', 3893, 1), ('\u{fe98}', 3894, 1),
+        ('\u{fe99}', 3895, 1), ('\u{fe9a}', 3896, 1), ('\u{fe9b}', 3897, 1), ('\u{fe9c}', 3898, 1),
+        ('\u{fe9d}', 3899, 1), ('\u{fe9e}', 3900, 1), ('\u{fe9f}', 3901, 1), ('\u{fea0}', 3902, 1),
+        ('\u{fea1}', 3903, 1), ('\u{fea2}', 3904, 1), ('\u{fea3}', 3905, 1), ('\u{fea4}', 3906, 1),
+        ('\u{fea5}', 3907, 1), ('\u{fea6}', 3908, 1), ('\u{fea7}', 3909, 1), ('\u{fea8}', 3910, 1),
+        ('\u{fea9}', 3911, 1), ('\u{feaa}', 3912, 1), ('\u{feab}', 3913, 1), ('\u{feac}', 3914, 1),
+        ('\u{fead}', 3915, 1), ('\u{feae}', 3916, 1), ('\u{feaf}', 3917, 1), ('\u{feb0}', 3918, 1),
+        ('\u{feb1}', 3919, 1), ('\u{feb2}', 3920, 1), ('\u{feb3}', 3921, 1), ('\u{feb4}', 3922, 1),
+        ('\u{feb5}', 3923, 1), ('\u{feb6}', 3924, 1), ('\u{feb7}', 3925, 1), ('\u{feb8}', 3926, 1),
+        ('\u{feb9}', 3927, 1), ('\u{feba}', 3928, 1), ('\u{febb}', 3929, 1), ('\u{febc}', 3930, 1),
+        ('\u{febd}', 3931, 1), ('\u{febe}', 3932, 1), ('\u{febf}', 3933, 1), ('\u{fec0}', 3934, 1),
+        ('\u{fec1}', 3935, 1), ('\u{fec2}', 3936, 1), ('\u{fec3}', 3937, 1), ('\u{fec4}', 3938, 1),
+        ('\u{fec5}', 3939, 1), ('\u{fec6}', 3940, 1), ('\u{fec7}', 3941, 1), ('\u{fec8}', 3942, 1),
+        ('\u{fec9}', 3943, 1), ('\u{feca}', 3944, 1), ('\u{fecb}', 3945, 1), ('\u{fecc}', 3946, 1),
+        ('\u{fecd}', 3947, 1), ('\u{fece}', 3948, 1), ('\u{fecf}', 3949, 1), ('\u{fed0}', 3950, 1),
+        ('\u{fed1}', 3951, 1), ('\u{fed2}', 3952, 1), ('\u{fed3}', 3953, 1), ('\u{fed4}', 3954, 1),
+        ('\u{fed5}', 3955, 1), ('\u{fed6}', 3956, 1), ('\u{fed7}', 3957, 1), ('\u{fed8}', 3958, 1),
+        ('\u{fed9}', 3959, 1), ('\u{feda}', 3960, 1), ('\u{fedb}', 3961, 1), ('\u{fedc}', 3962, 1),
+        ('\u{fedd}', 3963, 1), ('\u{fede}', 3964, 1), ('\u{fedf}', 3965, 1), ('\u{fee0}', 3966, 1),
+        ('\u{fee1}', 3967, 1), ('\u{fee2}', 3968, 1), ('\u{fee3}', 3969, 1), ('\u{fee4}', 3970, 1),
+        ('\u{fee5}', 3971, 1), ('\u{fee6}', 3972, 1), ('\u{fee7}', 3973, 1), ('\u{fee8}', 3974, 1),
+        ('\u{fee9}', 3975, 1), ('\u{feea}', 3976, 1), ('\u{feeb}', 3977, 1), ('\u{feec}', 3978, 1),
+        ('\u{feed}', 3979, 1), ('\u{feee}', 3980, 1), ('\u{feef}', 3981, 1), ('\u{fef0}', 3982, 1),
+        ('\u{fef1}', 3983, 1), ('\u{fef2}', 3984, 1), ('\u{fef3}', 3985, 1), ('\u{fef4}', 3986, 1),
+        ('\u{fef5}', 3987, 3), ('\u{fef6}', 3990, 3), ('\u{fef7}', 3993, 3), ('\u{fef8}', 3996, 3),
+        ('\u{fef9}', 3999, 3), ('\u{fefa}', 4002, 3), ('\u{fefb}', 4005, 2), ('\u{fefc}', 4007, 2),
+        ('\u{ff01}', 4009, 1), ('\u{ff02}', 4010, 1), ('\u{ff03}', 4011, 1), ('\u{ff04}', 4012, 1),
+        ('\u{ff05}', 4013, 1), ('\u{ff06}', 4014, 1), ('\u{ff07}', 4015, 1), ('\u{ff08}', 4016, 1),
+        ('\u{ff09}', 4017, 1), ('\u{ff0a}', 4018, 1), ('\u{ff0b}', 4019, 1), ('\u{ff0c}', 4020, 1),
+        ('\u{ff0d}', 4021, 1), ('\u{ff0e}', 4022, 1), ('\u{ff0f}', 4023, 1), ('\u{ff10}', 4024, 1),
+        ('\u{ff11}', 4025, 1), ('\u{ff12}', 4026, 1), ('\u{ff13}', 4027, 1), ('\u{ff14}', 4028, 1),
+        ('\u{ff15}', 4029, 1), ('\u{ff16}', 4030, 1), ('\u{ff17}', 4031, 1), ('\u{ff18}', 4032, 1),
+        ('\u{ff19}', 4033, 1), ('\u{ff1a}', 4034, 1), ('\u{ff1b}', 4035, 1), ('\u{ff1c}', 4036, 1),
+        ('\u{ff1d}', 4037, 1), ('\u{ff1e}', 4038, 1), ('\u{ff1f}', 4039, 1), ('\u{ff20}', 4040, 1),
+        ('\u{ff21}', 4041, 1), ('\u{ff22}', 4042, 1), ('\u{ff23}', 4043, 1), ('\u{ff24}', 4044, 1),
+        ('\u{ff25}', 4045, 1), ('\u{ff26}', 4046, 1), ('\u{ff27}', 4047, 1), ('\u{ff28}', 4048, 1),
+        ('\u{ff29}', 4049, 1), ('\u{ff2a}', 4050, 1), ('\u{ff2b}', 4051, 1), ('\u{ff2c}', 4052, 1),
+        ('\u{ff2d}', 4053, 1), ('\u{ff2e}', 4054, 1), ('\u{ff2f}', 4055, 1), ('\u{ff30}', 4056, 1),
+        ('\u{ff31}', 4057, 1), ('\u{ff32}', 4058, 1), ('\u{ff33}', 4059, 1), ('\u{ff34}', 4060, 1),
+        ('\u{ff35}', 4061, 1), ('\u{ff36}', 4062, 1), ('\u{ff37}', 4063, 1), ('\u{ff38}', 4064, 1),
+        ('\u{ff39}', 4065, 1), ('\u{ff3a}', 4066, 1), ('\u{ff3b}', 4067, 1), ('\u{ff3c}', 4068, 1),
+        ('\u{ff3d}', 4069, 1), ('\u{ff3e}', 4070, 1), ('\u{ff3f}', 4071, 1), ('\u{ff40}', 4072, 1),
+        ('\u{ff41}', 4073, 1), ('\u{ff42}', 4074, 1), ('\u{ff43}', 4075, 1), ('\u{ff44}', 4076, 1),
+        ('\u{ff45}', 4077, 1), ('\u{ff46}', 4078, 1), ('\u{ff47}', 4079, 1), ('\u{ff48}', 4080, 1),
+        ('\u{ff49}', 4081, 1), ('\u{ff4a}', 4082, 1), ('\u{ff4b}', 4083, 1), ('\u{ff4c}', 4084, 1),
+        ('\u{ff4d}', 4085, 1), ('\u{ff4e}', 4086, 1), ('\u{ff4f}', 4087, 1), ('\u{ff50}', 4088, 1),
+        ('\u{ff51}', 4089, 1), ('\u{ff52}', 4090, 1), ('\u{ff53}', 4091, 1), ('\u{ff54}', 4092, 1),
+        ('\u{ff55}', 4093, 1), ('\u{ff56}', 4094, 1), ('\u{ff57}', 4095, 1), ('\u{ff58}', 4096, 1),
+        ('\u{ff59}', 4097, 1), ('\u{ff5a}', 4098, 1), ('\u{ff5b}', 4099, 1), ('\u{ff5c}', 4100, 1),
+        ('\u{ff5d}', 4101, 1), ('\u{ff5e}', 4102, 1), ('\u{ff5f}', 4103, 1), ('\u{ff60}', 4104, 1),
+        ('\u{ff61}', 4105, 1), ('\u{ff62}', 4106, 1), ('\u{ff63}', 4107, 1), ('\u{ff64}', 4108, 1),
+        ('\u{ff65}', 4109, 1), ('\u{ff66}', 4110, 1), ('\u{ff67}', 4111, 1), ('\u{ff68}', 4112, 1),
+        ('\u{ff69}', 4113, 1), ('\u{ff6a}', 4114, 1), ('\u{ff6b}', 4115, 1), ('\u{ff6c}', 4116, 1),
+        ('\u{ff6d}', 4117, 1), ('\u{ff6e}', 4118, 1), ('\u{ff6f}', 4119, 1), ('\u{ff70}', 4120, 1),
+        ('\u{ff71}', 4121, 1), ('\u{ff72}', 4122, 1), ('\u{ff73}', 4123, 1), ('\u{ff74}', 4124, 1),
+        ('\u{ff75}', 4125, 1), ('\u{ff76}', 4126, 1), ('\u{ff77}', 4127, 1), ('\u{ff78}', 4128, 1),
+        ('\u{ff79}', 4129, 1), ('\u{ff7a}', 4130, 1), ('\u{ff7b}', 4131, 1), ('\u{ff7c}', 4132, 1),
+        ('\u{ff7d}', 4133, 1), ('\u{ff7e}', 4134, 1), ('\u{ff7f}', 4135, 1), ('\u{ff80}', 4136, 1),
+        ('\u{ff81}', 4137, 1), ('\u{ff82}', 4138, 1), ('\u{ff83}', 4139, 1), ('\u{ff84}', 4140, 1),
+        ('\u{ff85}', 4141, 1), ('\u{ff86}', 4142, 1), ('\u{ff87}', 4143, 1), ('\u{ff88}', 4144, 1),
+        ('\u{ff89}', 4145, 1), ('\u{ff8a}', 4146, 1), ('\u{ff8b}', 4147, 1), ('\u{ff8c}', 4148, 1),
+        ('\u{ff8d}', 4149, 1), ('\u{ff8e}', 4150, 1), ('\u{ff8f}', 4151, 1), ('\u{ff90}', 4152, 1),
+        ('\u{ff91}', 4153, 1), ('\u{ff92}', 4154, 1), ('\u{ff93}', 4155, 1), ('\u{ff94}', 4156, 1),
+        ('\u{ff95}', 4157, 1), ('\u{ff96}', 4158, 1), ('\u{ff97}', 4159, 1), ('\u{ff98}', 4160, 1),
+        ('\u{ff99}', 4161, 1), ('\u{ff9a}', 4162, 1), ('\u{ff9b}', 4163, 1), ('\u{ff9c}', 4164, 1),
+        ('\u{ff9d}', 4165, 1), ('\u{ff9e}', 4166, 1), ('\u{ff9f}', 4167, 1), ('\u{ffa0}', 4168, 1),
+        ('\u{ffa1}', 4169, 1), ('\u{ffa2}', 4170, 1), ('\u{ffa3}', 4171, 1), ('\u{ffa4}', 4172, 1),
+        ('\u{ffa5}', 4173, 1), ('\u{ffa6}', 4174, 1), ('\u{ffa7}', 4175, 1), ('\u{ffa8}', 4176, 1),
+        ('\u{ffa9}', 4177, 1), ('\u{ffaa}', 4178, 1), ('\u{ffab}', 4179, 1), ('\u{ffac}', 4180, 1),
+        ('\u{ffad}', 4181, 1), ('\u{ffae}', 4182, 1), ('\u{ffaf}', 4183, 1), ('\u{ffb0}', 4184, 1),
+        ('\u{ffb1}', 4185, 1), ('\u{ffb2}', 4186, 1), ('\u{ffb3}', 4187, 1), ('\u{ffb4}', 4188, 1),
+        ('\u{ffb5}', 4189, 1), ('\u{ffb6}', 4190, 1), ('\u{ffb7}', 4191, 1), ('\u{ffb8}', 4192, 1),
+        ('\u{ffb9}', 4193, 1), ('\u{ffba}', 4194, 1), ('\u{ffbb}', 4195, 1), ('\u{ffbc}', 4196, 1),
+        ('\u{ffbd}', 4197, 1), ('\u{ffbe}', 4198, 1), ('\u{ffc2}', 4199, 1), ('\u{ffc3}', 4200, 1),
+        ('\u{ffc4}', 4201, 1), ('\u{ffc5}', 4202, 1), ('\u{ffc6}', 4203, 1), ('\u{ffc7}', 4204, 1),
+        ('\u{ffca}', 4205, 1), ('\u{ffcb}', 4206, 1), ('\u{ffcc}', 4207, 1), ('\u{ffcd}', 4208, 1),
+        ('\u{ffce}', 4209, 1), ('\u{ffcf}', 4210, 1), ('\u{ffd2}', 4211, 1), ('\u{ffd3}', 4212, 1),
+        ('\u{ffd4}', 4213, 1), ('\u{ffd5}', 4214, 1), ('\u{ffd6}', 4215, 1), ('\u{ffd7}', 4216, 1),
+        ('\u{ffda}', 4217, 1), ('\u{ffdb}', 4218, 1), ('\u{ffdc}', 4219, 1), ('\u{ffe0}', 4220, 1),
+        ('\u{ffe1}', 4221, 1), ('\u{ffe2}', 4222, 1), ('\u{ffe3}', 4223, 2), ('\u{ffe4}', 4225, 1),
+        ('\u{ffe5}', 4226, 1), ('\u{ffe6}', 4227, 1), ('\u{ffe8}', 4228, 1), ('\u{ffe9}', 4229, 1),
+        ('\u{ffea}', 4230, 1), ('\u{ffeb}', 4231, 1), ('\u{ffec}', 4232, 1), ('\u{ffed}', 4233, 1),
+        ('\u{ffee}', 4234, 1), ('\u{10781}', 4235, 1), ('\u{10782}', 4236, 1),
+        ('\u{10783}', 4237, 1), ('\u{10784}', 4238, 1), ('\u{10785}', 4239, 1),
+        ('\u{10787}', 4240, 1), ('\u{10788}', 4241, 1), ('\u{10789}', 4242, 1),
+        ('\u{1078a}', 4243, 1), ('\u{1078b}', 4244, 1), ('\u{1078c}', 4245, 1),
+        ('\u{1078d}', 4246, 1), ('\u{1078e}', 4247, 1), ('\u{1078f}', 4248, 1),
+        ('\u{10790}', 4249, 1), ('\u{10791}', 4250, 1), ('\u{10792}', 4251, 1),
+        ('\u{10793}', 4252, 1), ('\u{10794}', 4253, 1), ('\u{10795}', 4254, 1),
+        ('\u{10796}', 4255, 1), ('\u{10797}', 4256, 1), ('\u{10798}', 4257, 1),
+        ('\u{10799}', 4258, 1), ('\u{1079a}', 4259, 1), ('\u{1079b}', 4260, 1),
+        ('\u{1079c}', 4261, 1), ('\u{1079d}', 4262, 1), ('\u{1079e}', 4263, 1),
+        ('\u{1079f}', 4264, 1), ('\u{107a0}', 4265, 1), ('\u{107a1}', 4266, 1),
+        ('\u{107a2}', 4267, 1), ('\u{107a3}', 4268, 1), ('\u{107a4}', 4269, 1),
+        ('\u{107a5}', 4270, 1), ('\u{107a6}', 4271, 1), ('\u{107a7}', 4272, 1),
+        ('\u{107a8}', 4273, 1), ('\u{107a9}', 4274, 1), ('\u{107aa}', 4275, 1),
+        ('\u{107ab}', 4276, 1), ('\u{107ac}', 4277, 1), ('\u{107ad}', 4278, 1),
+        ('\u{107ae}', 4279, 1), ('\u{107af}', 4280, 1), ('\u{107b0}', 4281, 1),
+        ('\u{107b2}', 4282, 1), ('\u{107b3}', 4283, 1), ('\u{107b4}', 4284, 1),
+        ('\u{107b5}', 4285, 1), ('\u{107b6}', 4286, 1), ('\u{107b7}', 4287, 1),
+        ('\u{107b8}', 4288, 1), ('\u{107b9}', 4289, 1), ('\u{107ba}', 4290, 1),
+        ('\u{1ccd6}', 4291, 1), ('\u{1ccd7}', 4292, 1), ('\u{1ccd8}', 4293, 1),
+        ('\u{1ccd9}', 4294, 1), ('\u{1ccda}', 4295, 1), ('\u{1ccdb}', 4296, 1),
+        ('\u{1ccdc}', 4297, 1), ('\u{1ccdd}', 4298, 1), ('\u{1ccde}', 4299, 1),
+        ('\u{1ccdf}', 4300, 1), ('\u{1cce0}', 4301, 1), ('\u{1cce1}', 4302, 1),
+        ('\u{1cce2}', 4303, 1), ('\u{1cce3}', 4304, 1), ('\u{1cce4}', 4305, 1),
+        ('\u{1cce5}', 4306, 1), ('\u{1cce6}', 4307, 1), ('\u{1cce7}', 4308, 1),
+        ('\u{1cce8}', 4309, 1), ('\u{1cce9}', 4310, 1), ('\u{1ccea}', 4311, 1),
+        ('\u{1cceb}', 4312, 1), ('\u{1ccec}', 4313, 1), ('\u{1cced}', 4314, 1),
+        ('\u{1ccee}', 4315, 1), ('\u{1ccef}', 4316, 1), ('\u{1ccf0}', 4317, 1),
+        ('\u{1ccf1}', 4318, 1), ('\u{1ccf2}', 4319, 1), ('\u{1ccf3}', 4320, 1),
+        ('\u{1ccf4}', 4321, 1), ('\u{1ccf5}', 4322, 1), ('\u{1ccf6}', 4323, 1),
+        ('\u{1ccf7}', 4324, 1), ('\u{1ccf8}', 4325, 1), ('\u{1ccf9}', 4326, 1),
+        ('\u{1d400}', 4327, 1), ('\u{1d401}', 4328, 1), ('\u{1d402}', 4329, 1),
+        ('\u{1d403}', 4330, 1), ('\u{1d404}', 4331, 1), ('\u{1d405}', 4332, 1),
+        ('\u{1d406}', 4333, 1), ('\u{1d407}', 4334, 1), ('\u{1d408}', 4335, 1),
+        ('\u{1d409}', 4336, 1), ('\u{1d40a}', 4337, 1), ('\u{1d40b}', 4338, 1),
+        ('\u{1d40c}', 4339, 1), ('\u{1d40d}', 4340, 1), ('\u{1d40e}', 4341, 1),
+        ('\u{1d40f}', 4342, 1), ('\u{1d410}', 4343, 1), ('\u{1d411}', 4344, 1),
+        ('\u{1d412}', 4345, 1), ('\u{1d413}', 4346, 1), ('\u{1d414}', 4347, 1),
+        ('\u{1d415}', 4348, 1), ('\u{1d416}', 4349, 1), ('\u{1d417}', 4350, 1),
+        ('\u{1d418}', 4351, 1), ('\u{1d419}', 4352, 1), ('\u{1d41a}', 4353, 1),
+        ('\u{1d41b}', 4354, 1), ('\u{1d41c}', 4355, 1), ('\u{1d41d}', 4356, 1),
+        ('\u{1d41e}', 4357, 1), ('\u{1d41f}', 4358, 1), ('\u{1d420}', 4359, 1),
+        ('\u{1d421}', 4360, 1), ('\u{1d422}', 4361, 1), ('\u{1d423}', 4362, 1),
+        ('\u{1d424}', 4363, 1), ('\u{1d425}', 4364, 1), ('\u{1d426}', 4365, 1),
+        ('\u{1d427}', 4366, 1), ('\u{1d428}', 4367, 1), ('\u{1d429}', 4368, 1),
+        ('\u{1d42a}', 4369, 1), ('\u{1d42b}', 4370, 1), ('\u{1d42c}', 4371, 1),
+        ('\u{1d42d}', 4372, 1), ('\u{1d42e}', 4373, 1), ('\u{1d42f}', 4374, 1),
+        ('\u{1d430}', 4375, 1), ('\u{1d431}', 4376, 1), ('\u{1d432}', 4377, 1),
+        ('\u{1d433}', 4378, 1), ('\u{1d434}', 4379, 1), ('\u{1d435}', 4380, 1),
+        ('\u{1d436}', 4381, 1), ('\u{1d437}', 4382, 1), ('\u{1d438}', 4383, 1),
+        ('\u{1d439}', 4384, 1), ('\u{1d43a}', 4385, 1), ('\u{1d43b}', 4386, 1),
+        ('\u{1d43c}', 4387, 1), ('\u{1d43d}', 4388, 1), ('\u{1d43e}', 4389, 1),
+        ('\u{1d43f}', 4390, 1), ('\u{1d440}', 4391, 1), ('\u{1d441}', 4392, 1),
+        ('\u{1d442}', 4393, 1), ('\u{1d443}', 4394, 1), ('\u{1d444}', 4395, 1),
+        ('\u{1d445}', 4396, 1), ('\u{1d446}', 4397, 1), ('\u{1d447}', 4398, 1),
+        ('\u{1d448}', 4399, 1), ('\u{1d449}', 4400, 1), ('\u{1d44a}', 4401, 1),
+        ('\u{1d44b}', 4402, 1), ('\u{1d44c}', 4403, 1), ('\u{1d44d}', 4404, 1),
+        ('\u{1d44e}', 4405, 1), ('\u{1d44f}', 4406, 1), ('\u{1d450}', 4407, 1),
+        ('\u{1d451}', 4408, 1), ('\u{1d452}', 4409, 1), ('\u{1d453}', 4410, 1),
+        ('\u{1d454}', 4411, 1), ('\u{1d456}', 4412, 1), ('\u{1d457}', 4413, 1),
+        ('\u{1d458}', 4414, 1), ('\u{1d459}', 4415, 1), ('\u{1d45a}', 4416, 1),
+        ('\u{1d45b}', 4417, 1), ('\u{1d45c}', 4418, 1), ('\u{1d45d}', 4419, 1),
+        ('\u{1d45e}', 4420, 1), ('\u{1d45f}', 4421, 1), ('\u{1d460}', 4422, 1),
+        ('\u{1d461}', 4423, 1), ('\u{1d462}', 4424, 1), ('\u{1d463}', 4425, 1),
+        ('\u{1d464}', 4426, 1), ('\u{1d465}', 4427, 1), ('\u{1d466}', 4428, 1),
+        ('\u{1d467}', 4429, 1), ('\u{1d468}', 4430, 1), ('\u{1d469}', 4431, 1),
+        ('\u{1d46a}', 4432, 1), ('\u{1d46b}', 4433, 1), ('\u{1d46c}', 4434, 1),
+        ('\u{1d46d}', 4435, 1), ('\u{1d46e}', 4436, 1), ('\u{1d46f}', 4437, 1),
+        ('\u{1d470}', 4438, 1), ('\u{1d471}', 4439, 1), ('\u{1d472}', 4440, 1),
+        ('\u{1d473}', 4441, 1), ('\u{1d474}', 4442, 1), ('\u{1d475}', 4443, 1),
+        ('\u{1d476}', 4444, 1), ('\u{1d477}', 4445, 1), ('\u{1d478}', 4446, 1),
+        ('\u{1d479}', 4447, 1), ('\u{1d47a}', 4448, 1), ('\u{1d47b}', 4449, 1),
+        ('\u{1d47c}', 4450, 1), ('\u{1d47d}', 4451, 1), ('\u{1d47e}', 4452, 1),
+        ('\u{1d47f}', 4453, 1), ('\u{1d480}', 4454, 1), ('\u{1d481}', 4455, 1),
+        ('\u{1d482}', 4456, 1), ('\u{1d483}', 4457, 1), ('\u{1d484}', 4458, 1),
+        ('\u{1d485}', 4459, 1), ('\u{1d486}', 4460, 1), ('\u{1d487}', 4461, 1),
+        ('\u{1d488}', 4462, 1), ('\u{1d489}', 4463, 1), ('\u{1d48a}', 4464, 1),
+        ('\u{1d48b}', 4465, 1), ('\u{1d48c}', 4466, 1), ('\u{1d48d}', 4467, 1),
+        ('\u{1d48e}', 4468, 1), ('\u{1d48f}', 4469, 1), ('\u{1d490}', 4470, 1),
+        ('\u{1d491}', 4471, 1), ('\u{1d492}', 4472, 1), ('\u{1d493}', 4473, 1),
+        ('\u{1d494}', 4474, 1), ('\u{1d495}', 4475, 1), ('\u{1d496}', 4476, 1),
+        ('\u{1d497}', 4477, 1), ('\u{1d498}', 4478, 1), ('\u{1d499}', 4479, 1),
+        ('\u{1d49a}', 4480, 1), ('\u{1d49b}', 4481, 1), ('\u{1d49c}', 4482, 1),
+        ('\u{1d49e}', 4483, 1), ('\u{1d49f}', 4484, 1), ('\u{1d4a2}', 4485, 1),
+        ('\u{1d4a5}', 4486, 1), ('\u{1d4a6}', 4487, 1), ('\u{1d4a9}', 4488, 1),
+        ('\u{1d4aa}', 4489, 1), ('\u{1d4ab}', 4490, 1), ('\u{1d4ac}', 4491, 1),
+        ('\u{1d4ae}', 4492, 1), ('\u{1d4af}', 4493, 1), ('\u{1d4b0}', 4494, 1),
+        ('\u{1d4b1}', 4495, 1), ('\u{1d4b2}', 4496, 1), ('\u{1d4b3}', 4497, 1),
+        ('\u{1d4b4}', 4498, 1), ('\u{1d4b5}', 4499, 1), ('\u{1d4b6}', 4500, 1),
+        ('\u{1d4b7}', 4501, 1), ('\u{1d4b8}', 4502, 1), ('\u{1d4b9}', 4503, 1),
+        ('\u{1d4bb}', 4504, 1), ('\u{1d4bd}', 4505, 1), ('\u{1d4be}', 4506, 1),
+        ('\u{1d4bf}', 4507, 1), ('\u{1d4c0}', 4508, 1), ('\u{1d4c1}', 4509, 1),
+        ('\u{1d4c2}', 4510, 1), ('\u{1d4c3}', 4511, 1), ('\u{1d4c5}', 4512, 1),
+        ('\u{1d4c6}', 4513, 1), ('\u{1d4c7}', 4514, 1), ('\u{1d4c8}', 4515, 1),
+        ('\u{1d4c9}', 4516, 1), ('\u{1d4ca}', 4517, 1), ('\u{1d4cb}', 4518, 1),
+        ('\u{1d4cc}', 4519, 1), ('\u{1d4cd}', 4520, 1), ('\u{1d4ce}', 4521, 1),
+        ('\u{1d4cf}', 4522, 1), ('\u{1d4d0}', 4523, 1), ('\u{1d4d1}', 4524, 1),
+        ('\u{1d4d2}', 4525, 1), ('\u{1d4d3}', 4526, 1), ('\u{1d4d4}', 4527, 1),
+        ('\u{1d4d5}', 4528, 1), ('\u{1d4d6}', 4529, 1), ('\u{1d4d7}', 4530, 1),
+        ('\u{1d4d8}', 4531, 1), ('\u{1d4d9}', 4532, 1), ('\u{1d4da}', 4533, 1),
+        ('\u{1d4db}', 4534, 1), ('\u{1d4dc}', 4535, 1), ('\u{1d4dd}', 4536, 1),
+        ('\u{1d4de}', 4537, 1), ('\u{1d4df}', 4538, 1), ('\u{1d4e0}', 4539, 1),
+        ('\u{1d4e1}', 4540, 1), ('\u{1d4e2}', 4541, 1), ('\u{1d4e3}', 4542, 1),
+        ('\u{1d4e4}', 4543, 1), ('\u{1d4e5}', 4544, 1), ('\u{1d4e6}', 4545, 1),
+        ('\u{1d4e7}', 4546, 1), ('\u{1d4e8}', 4547, 1), ('\u{1d4e9}', 4548, 1),
+        ('\u{1d4ea}', 4549, 1), ('\u{1d4eb}', 4550, 1), ('\u{1d4ec}', 4551, 1),
+        ('\u{1d4ed}', 4552, 1), ('\u{1d4ee}', 4553, 1), ('\u{1d4ef}', 4554, 1),
+        ('\u{1d4f0}', 4555, 1), ('\u{1d4f1}', 4556, 1), ('\u{1d4f2}', 4557, 1),
+        ('\u{1d4f3}', 4558, 1), ('\u{1d4f4}', 4559, 1), ('\u{1d4f5}', 4560, 1),
+        ('\u{1d4f6}', 4561, 1), ('\u{1d4f7}', 4562, 1), ('\u{1d4f8}', 4563, 1),
+        ('\u{1d4f9}', 4564, 1), ('\u{1d4fa}', 4565, 1), ('\u{1d4fb}', 4566, 1),
+        ('\u{1d4fc}', 4567, 1), ('\u{1d4fd}', 4568, 1), ('\u{1d4fe}', 4569, 1),
+        ('\u{1d4ff}', 4570, 1), ('\u{1d500}', 4571, 1), ('\u{1d501}', 4572, 1),
+        ('\u{1d502}', 4573, 1), ('\u{1d503}', 4574, 1), ('\u{1d504}', 4575, 1),
+        ('\u{1d505}', 4576, 1), ('\u{1d507}', 4577, 1), ('\u{1d508}', 4578, 1),
+        ('\u{1d509}', 4579, 1), ('\u{1d50a}', 4580, 1), ('\u{1d50d}', 4581, 1),
+        ('\u{1d50e}', 4582, 1), ('\u{1d50f}', 4583, 1), ('\u{1d510}', 4584, 1),
+        ('\u{1d511}', 4585, 1), ('\u{1d512}', 4586, 1), ('\u{1d513}', 4587, 1),
+        ('\u{1d514}', 4588, 1), ('\u{1d516}', 4589, 1), ('\u{1d517}', 4590, 1),
+        ('\u{1d518}', 4591, 1), ('\u{1d519}', 4592, 1), ('\u{1d51a}', 4593, 1),
+        ('\u{1d51b}', 4594, 1), ('\u{1d51c}', 4595, 1), ('\u{1d51e}', 4596, 1),
+        ('\u{1d51f}', 4597, 1), ('\u{1d520}', 4598, 1), ('\u{1d521}', 4599, 1),
+        ('\u{1d522}', 4600, 1), ('\u{1d523}', 4601, 1), ('\u{1d524}', 4602, 1),
+        ('\u{1d525}', 4603, 1), ('\u{1d526}', 4604, 1), ('\u{1d527}', 4605, 1),
+        ('\u{1d528}', 4606, 1), ('\u{1d529}', 4607, 1), ('\u{1d52a}', 4608, 1),
+        ('\u{1d52b}', 4609, 1), ('\u{1d52c}', 4610, 1), ('\u{1d52d}', 4611, 1),
+        ('\u{1d52e}', 4612, 1), ('\u{1d52f}', 4613, 1), ('\u{1d530}', 4614, 1),
+        ('\u{1d531}', 4615, 1), ('\u{1d532}', 4616, 1), ('\u{1d533}', 4617, 1),
+        ('\u{1d534}', 4618, 1), ('\u{1d535}', 4619, 1), ('\u{1d536}', 4620, 1),
+        ('\u{1d537}', 4621, 1), ('\u{1d538}', 4622, 1), ('\u{1d539}', 4623, 1),
+        ('\u{1d53b}', 4624, 1), ('\u{1d53c}', 4625, 1), ('\u{1d53d}', 4626, 1),
+        ('\u{1d53e}', 4627, 1), ('\u{1d540}', 4628, 1), ('\u{1d541}', 4629, 1),
+        ('\u{1d542}', 4630, 1), ('\u{1d543}', 4631, 1), ('\u{1d544}', 4632, 1),
+        ('\u{1d546}', 4633, 1), ('\u{1d54a}', 4634, 1), ('\u{1d54b}', 4635, 1),
+        ('\u{1d54c}', 4636, 1), ('\u{1d54d}', 4637, 1), ('\u{1d54e}', 4638, 1),
+        ('\u{1d54f}', 4639, 1), ('\u{1d550}', 4640, 1), ('\u{1d552}', 4641, 1),
+        ('\u{1d553}', 4642, 1), ('\u{1d554}', 4643, 1), ('\u{1d555}', 4644, 1),
+        ('\u{1d556}', 4645, 1), ('\u{1d557}', 4646, 1), ('\u{1d558}', 4647, 1),
+        ('\u{1d559}', 4648, 1), ('\u{1d55a}', 4649, 1), ('\u{1d55b}', 4650, 1),
+        ('\u{1d55c}', 4651, 1), ('\u{1d55d}', 4652, 1), ('\u{1d55e}', 4653, 1),
+        ('\u{1d55f}', 4654, 1), ('\u{1d560}', 4655, 1), ('\u{1d561}', 4656, 1),
+        ('\u{1d562}', 4657, 1), ('\u{1d563}', 4658, 1), ('\u{1d564}', 4659, 1),
+        ('\u{1d565}', 4660, 1), ('\u{1d566}', 4661, 1), ('\u{1d567}', 4662, 1),
+        ('\u{1d568}', 4663, 1), ('\u{1d569}', 4664, 1), ('\u{1d56a}', 4665, 1),
+        ('\u{1d56b}', 4666, 1), ('\u{1d56c}', 4667, 1), ('\u{1d56d}', 4668, 1),
+        ('\u{1d56e}', 4669, 1), ('\u{1d56f}', 4670, 1), ('\u{1d570}', 4671, 1),
+        ('\u{1d571}', 4672, 1), ('\u{1d572}', 4673, 1), ('\u{1d573}', 4674, 1),
+        ('\u{1d574}', 4675, 1), ('\u{1d575}', 4676, 1), ('\u{1d576}', 4677, 1),
+        ('\u{1d577}', 4678, 1), ('\u{1d578}', 4679, 1), ('\u{1d579}', 4680, 1),
+        ('\u{1d57a}', 4681, 1), ('\u{1d57b}', 4682, 1), ('\u{1d57c}', 4683, 1),
+        ('\u{1d57d}', 4684, 1), ('\u{1d57e}', 4685, 1), ('\u{1d57f}', 4686, 1),
+        ('\u{1d580}', 4687, 1), ('\u{1d581}', 4688, 1), ('\u{1d582}', 4689, 1),
+        ('\u{1d583}', 4690, 1), ('\u{1d584}', 4691, 1), ('\u{1d585}', 4692, 1),
+        ('\u{1d586}', 4693, 1), ('\u{1d587}', 4694, 1), ('\u{1d588}', 4695, 1),
+        ('\u{1d589}', 4696, 1), ('\u{1d58a}', 4697, 1), ('\u{1d58b}', 4698, 1),
+        ('\u{1d58c}', 4699, 1), ('\u{1d58d}', 4700, 1), ('\u{1d58e}', 4701, 1),
+        ('\u{1d58f}', 4702, 1), ('\u{1d590}', 4703, 1), ('\u{1d591}', 4704, 1),
+        ('\u{1d592}', 4705, 1), ('\u{1d593}', 4706, 1), ('\u{1d594}', 4707, 1),
+        ('\u{1d595}', 4708, 1), ('\u{1d596}', 4709, 1), ('\u{1d597}', 4710, 1),
+        ('\u{1d598}', 4711, 1), ('\u{1d599}', 4712, 1), ('\u{1d59a}', 4713, 1),
+        ('\u{1d59b}', 4714, 1), ('\u{1d59c}', 4715, 1), ('\u{1d59d}', 4716, 1),
+        ('\u{1d59e}', 4717, 1), ('\u{1d59f}', 4718, 1), ('\u{1d5a0}', 4719, 1),
+        ('\u{1d5a1}', 4720, 1), ('\u{1d5a2}', 4721, 1), ('\u{1d5a3}', 4722, 1),
+        ('\u{1d5a4}', 4723, 1), ('\u{1d5a5}', 4724, 1), ('\u{1d5a6}', 4725, 1),
+        ('\u{1d5a7}', 4726, 1), ('\u{1d5a8}', 4727, 1), ('\u{1d5a9}', 4728, 1),
+        ('\u{1d5aa}', 4729, 1), ('\u{1d5ab}', 4730, 1), ('\u{1d5ac}', 4731, 1),
+        ('\u{1d5ad}', 4732, 1), ('\u{1d5ae}', 4733, 1), ('\u{1d5af}', 4734, 1),
+        ('\u{1d5b0}', 4735, 1), ('\u{1d5b1}', 4736, 1), ('\u{1d5b2}', 4737, 1),
+        ('\u{1d5b3}', 4738, 1), ('\u{1d5b4}', 4739, 1), ('\u{1d5b5}', 4740, 1),
+        ('\u{1d5b6}', 4741, 1), ('\u{1d5b7}', 4742, 1), ('\u{1d5b8}', 4743, 1),
+        ('\u{1d5b9}', 4744, 1), ('\u{1d5ba}', 4745, 1), ('\u{1d5bb}', 4746, 1),
+        ('\u{1d5bc}', 4747, 1), ('\u{1d5bd}', 4748, 1), ('\u{1d5be}', 4749, 1),
+        ('\u{1d5bf}', 4750, 1), ('\u{1d5c0}', 4751, 1), ('\u{1d5c1}', 4752, 1),
+        ('\u{1d5c2}', 4753, 1), ('\u{1d5c3}', 4754, 1), ('\u{1d5c4}', 4755, 1),
+        ('\u{1d5c5}', 4756, 1), ('\u{1d5c6}', 4757, 1), ('\u{1d5c7}', 4758, 1),
+        ('\u{1d5c8}', 4759, 1), ('\u{1d5c9}', 4760, 1), ('\u{1d5ca}', 4761, 1),
+        ('\u{1d5cb}', 4762, 1), ('\u{1d5cc}', 4763, 1), ('\u{1d5cd}', 4764, 1),
+        ('\u{1d5ce}', 4765, 1), ('\u{1d5cf}', 4766, 1), ('\u{1d5d0}', 4767, 1),
+        ('\u{1d5d1}', 4768, 1), ('\u{1d5d2}', 4769, 1), ('\u{1d5d3}', 4770, 1),
+        ('\u{1d5d4}', 4771, 1), ('\u{1d5d5}', 4772, 1), ('\u{1d5d6}', 4773, 1),
+        ('\u{1d5d7}', 4774, 1), ('\u{1d5d8}', 4775, 1), ('\u{1d5d9}', 4776, 1),
+        ('\u{1d5da}', 4777, 1), ('\u{1d5db}', 4778, 1), ('\u{1d5dc}', 4779, 1),
+        ('\u{1d5dd}', 4780, 1), ('\u{1d5de}', 4781, 1), ('\u{1d5df}', 4782, 1),
+        ('\u{1d5e0}', 4783, 1), ('\u{1d5e1}', 4784, 1), ('\u{1d5e2}', 4785, 1),
+        ('\u{1d5e3}', 4786, 1), ('\u{1d5e4}', 4787, 1), ('\u{1d5e5}', 4788, 1),
+        ('\u{1d5e6}', 4789, 1), ('\u{1d5e7}', 4790, 1), ('\u{1d5e8}', 4791, 1),
+        ('\u{1d5e9}', 4792, 1), ('\u{1d5ea}', 4793, 1), ('\u{1d5eb}', 4794, 1),
+        ('\u{1d5ec}', 4795, 1), ('\u{1d5ed}', 4796, 1), ('\u{1d5ee}', 4797, 1),
+        ('\u{1d5ef}', 4798, 1), ('\u{1d5f0}', 4799, 1), ('\u{1d5f1}', 4800, 1),
+        ('\u{1d5f2}', 4801, 1), ('\u{1d5f3}', 4802, 1), ('\u{1d5f4}', 4803, 1),
+        ('\u{1d5f5}', 4804, 1), ('\u{1d5f6}', 4805, 1), ('\u{1d5f7}', 4806, 1),
+        ('\u{1d5f8}', 4807, 1), ('\u{1d5f9}', 4808, 1), ('\u{1d5fa}', 4809, 1),
+        ('\u{1d5fb}', 4810, 1), ('\u{1d5fc}', 4811, 1), ('\u{1d5fd}', 4812, 1),
+        ('\u{1d5fe}', 4813, 1), ('\u{1d5ff}', 4814, 1), ('\u{1d600}', 4815, 1),
+        ('\u{1d601}', 4816, 1), ('\u{1d602}', 4817, 1), ('\u{1d603}', 4818, 1),
+        ('\u{1d604}', 4819, 1), ('\u{1d605}', 4820, 1), ('\u{1d606}', 4821, 1),
+        ('\u{1d607}', 4822, 1), ('\u{1d608}', 4823, 1), ('\u{1d609}', 4824, 1),
+        ('\u{1d60a}', 4825, 1), ('\u{1d60b}', 4826, 1), ('\u{1d60c}', 4827, 1),
+        ('\u{1d60d}', 4828, 1), ('\u{1d60e}', 4829, 1), ('\u{1d60f}', 4830, 1),
+        ('\u{1d610}', 4831, 1), ('\u{1d611}', 4832, 1), ('\u{1d612}', 4833, 1),
+        ('\u{1d613}', 4834, 1), ('\u{1d614}', 4835, 1), ('\u{1d615}', 4836, 1),
+        ('\u{1d616}', 4837, 1), ('\u{1d617}', 4838, 1), ('\u{1d618}', 4839, 1),
+        ('\u{1d619}', 4840, 1), ('\u{1d61a}', 4841, 1), ('\u{1d61b}', 4842, 1),
+        ('\u{1d61c}', 4843, 1), ('\u{1d61d}', 4844, 1), ('\u{1d61e}', 4845, 1),
+        ('\u{1d61f}', 4846, 1), ('\u{1d620}', 4847, 1), ('\u{1d621}', 4848, 1),
+        ('\u{1d622}', 4849, 1), ('\u{1d623}', 4850, 1), ('\u{1d624}', 4851, 1),
+        ('\u{1d625}', 4852, 1), ('\u{1d626}', 4853, 1), ('\u{1d627}', 4854, 1),
+        ('\u{1d628}', 4855, 1), ('\u{1d629}', 4856, 1), ('\u{1d62a}', 4857, 1),
+        ('\u{1d62b}', 4858, 1), ('\u{1d62c}', 4859, 1), ('\u{1d62d}', 4860, 1),
+        ('\u{1d62e}', 4861, 1), ('\u{1d62f}', 4862, 1), ('\u{1d630}', 4863, 1),
+        ('\u{1d631}', 4864, 1), ('\u{1d632}', 4865, 1), ('\u{1d633}', 4866, 1),
+        ('\u{1d634}', 4867, 1), ('\u{1d635}', 4868, 1), ('\u{1d636}', 4869, 1),
+        ('\u{1d637}', 4870, 1), ('\u{1d638}', 4871, 1), ('\u{1d639}', 4872, 1),
+        ('\u{1d63a}', 4873, 1), ('\u{1d63b}', 4874, 1), ('\u{1d63c}', 4875, 1),
+        ('\u{1d63d}', 4876, 1), ('\u{1d63e}', 4877, 1), ('\u{1d63f}', 4878, 1),
+        ('\u{1d640}', 4879, 1), ('\u{1d641}', 4880, 1), ('\u{1d642}', 4881, 1),
+        ('\u{1d643}', 4882, 1), ('\u{1d644}', 4883, 1), ('\u{1d645}', 4884, 1),
+        ('\u{1d646}', 4885, 1), ('\u{1d647}', 4886, 1), ('\u{1d648}', 4887, 1),
+        ('\u{1d649}', 4888, 1), ('\u{1d64a}', 4889, 1), ('\u{1d64b}', 4890, 1),
+        ('\u{1d64c}', 4891, 1), ('\u{1d64d}', 4892, 1), ('\u{1d64e}', 4893, 1),
+        ('\u{1d64f}', 4894, 1), ('\u{1d650}', 4895, 1), ('\u{1d651}', 4896, 1),
+        ('\u{1d652}', 4897, 1), ('\u{1d653}', 4898, 1), ('\u{1d654}', 4899, 1),
+        ('\u{1d655}', 4900, 1), ('\u{1d656}', 4901, 1), ('\u{1d657}', 4902, 1),
+        ('\u{1d658}', 4903, 1), ('\u{1d659}', 4904, 1), ('\u{1d65a}', 4905, 1),
+        ('\u{1d65b}', 4906, 1), ('\u{1d65c}', 4907, 1), ('\u{1d65d}', 4908, 1),
+        ('\u{1d65e}', 4909, 1), ('\u{1d65f}', 4910, 1), ('\u{1d660}', 4911, 1),
+        ('\u{1d661}', 4912, 1), ('\u{1d662}', 4913, 1), ('\u{1d663}', 4914, 1),
+        ('\u{1d664}', 4915, 1), ('\u{1d665}', 4916, 1), ('\u{1d666}', 4917, 1),
+        ('\u{1d667}', 4918, 1), ('\u{1d668}', 4919, 1), ('\u{1d669}', 4920, 1),
+        ('\u{1d66a}', 4921, 1), ('\u{1d66b}', 4922, 1), ('\u{1d66c}', 4923, 1),
+        ('\u{1d66d}', 4924, 1), ('\u{1d66e}', 4925, 1), ('\u{1d66f}', 4926, 1),
+        ('\u{1d670}', 4927, 1), ('\u{1d671}', 4928, 1), ('\u{1d672}', 4929, 1),
+        ('\u{1d673}', 4930, 1), ('\u{1d674}', 4931, 1), ('\u{1d675}', 4932, 1),
+        ('\u{1d676}', 4933, 1), ('\u{1d677}', 4934, 1), ('\u{1d678}', 4935, 1),
+        ('\u{1d679}', 4936, 1), ('\u{1d67a}', 4937, 1), ('\u{1d67b}', 4938, 1),
+        ('\u{1d67c}', 4939, 1), ('\u{1d67d}', 4940, 1), ('\u{1d67e}', 4941, 1),
+        ('\u{1d67f}', 4942, 1), ('\u{1d680}', 4943, 1), ('\u{1d681}', 4944, 1),
+        ('\u{1d682}', 4945, 1), ('\u{1d683}', 4946, 1), ('\u{1d684}', 4947, 1),
+        ('\u{1d685}', 4948, 1), ('\u{1d686}', 4949, 1), ('\u{1d687}', 4950, 1),
+        ('\u{1d688}', 4951, 1), ('\u{1d689}', 4952, 1), ('\u{1d68a}', 4953, 1),
+        ('\u{1d68b}', 4954, 1), ('\u{1d68c}', 4955, 1), ('\u{1d68d}', 4956, 1),
+        ('\u{1d68e}', 4957, 1), ('\u{1d68f}', 4958, 1), ('\u{1d690}', 4959, 1),
+        ('\u{1d691}', 4960, 1), ('\u{1d692}', 4961, 1), ('\u{1d693}', 4962, 1),
+        ('\u{1d694}', 4963, 1), ('\u{1d695}', 4964, 1), ('\u{1d696}', 4965, 1),
+        ('\u{1d697}', 4966, 1), ('\u{1d698}', 4967, 1), ('\u{1d699}', 4968, 1),
+        ('\u{1d69a}', 4969, 1), ('\u{1d69b}', 4970, 1), ('\u{1d69c}', 4971, 1),
+        ('\u{1d69d}', 4972, 1), ('\u{1d69e}', 4973, 1), ('\u{1d69f}', 4974, 1),
+        ('\u{1d6a0}', 4975, 1), ('\u{1d6a1}', 4976, 1), ('\u{1d6a2}', 4977, 1),
+        ('\u{1d6a3}', 4978, 1), ('\u{1d6a4}', 4979, 1), ('\u{1d6a5}', 4980, 1),
+        ('\u{1d6a8}', 4981, 1), ('\u{1d6a9}', 4982, 1), ('\u{1d6aa}', 4983, 1),
+        ('\u{1d6ab}', 4984, 1), ('\u{1d6ac}', 4985, 1), ('\u{1d6ad}', 4986, 1),
+        ('\u{1d6ae}', 4987, 1), ('\u{1d6af}', 4988, 1), ('\u{1d6b0}', 4989, 1),
+        ('\u{1d6b1}', 4990, 1), ('\u{1d6b2}', 4991, 1), ('\u{1d6b3}', 4992, 1),
+        ('\u{1d6b4}', 4993, 1), ('\u{1d6b5}', 4994, 1), ('\u{1d6b6}', 4995, 1),
+        ('\u{1d6b7}', 4996, 1), ('\u{1d6b8}', 4997, 1), ('\u{1d6b9}', 4998, 1),
+        ('\u{1d6ba}', 4999, 1), ('\u{1d6bb}', 5000, 1), ('\u{1d6bc}', 5001, 1),
+        ('\u{1d6bd}', 5002, 1), ('\u{1d6be}', 5003, 1), ('\u{1d6bf}', 5004, 1),
+        ('\u{1d6c0}', 5005, 1), ('\u{1d6c1}', 5006, 1), ('\u{1d6c2}', 5007, 1),
+        ('\u{1d6c3}', 5008, 1), ('\u{1d6c4}', 5009, 1), ('\u{1d6c5}', 5010, 1),
+        ('\u{1d6c6}', 5011, 1), ('\u{1d6c7}', 5012, 1), ('\u{1d6c8}', 5013, 1),
+        ('\u{1d6c9}', 5014, 1), ('\u{1d6ca}', 5015, 1), ('\u{1d6cb}', 5016, 1),
+        ('\u{1d6cc}', 5017, 1), ('\u{1d6cd}', 5018, 1), ('\u{1d6ce}', 5019, 1),
+        ('\u{1d6cf}', 5020, 1), ('\u{1d6d0}', 5021, 1), ('\u{1d6d1}', 5022, 1),
+        ('\u{1d6d2}', 5023, 1), ('\u{1d6d3}', 5024, 1), ('\u{1d6d4}', 5025, 1),
+        ('\u{1d6d5}', 5026, 1), ('\u{1d6d6}', 5027, 1), ('\u{1d6d7}', 5028, 1),
+        ('\u{1d6d8}', 5029, 1), ('\u{1d6d9}', 5030, 1), ('\u{1d6da}', 5031, 1),
+        ('\u{1d6db}', 5032, 1), ('\u{1d6dc}', 5033, 1), ('\u{1d6dd}', 5034, 1),
+        ('\u{1d6de}', 5035, 1), ('\u{1d6df}', 5036, 1), ('\u{1d6e0}', 5037, 1),
+        ('\u{1d6e1}', 5038, 1), ('\u{1d6e2}', 5039, 1), ('\u{1d6e3}', 5040, 1),
+        ('\u{1d6e4}', 5041, 1), ('\u{1d6e5}', 5042, 1), ('\u{1d6e6}', 5043, 1),
+        ('\u{1d6e7}', 5044, 1), ('\u{1d6e8}', 5045, 1), ('\u{1d6e9}', 5046, 1),
+        ('\u{1d6ea}', 5047, 1), ('\u{1d6eb}', 5048, 1), ('\u{1d6ec}', 5049, 1),
+        ('\u{1d6ed}', 5050, 1), ('\u{1d6ee}', 5051, 1), ('\u{1d6ef}', 5052, 1),
+        ('\u{1d6f0}', 5053, 1), ('\u{1d6f1}', 5054, 1), ('\u{1d6f2}', 5055, 1),
+        ('\u{1d6f3}', 5056, 1), ('\u{1d6f4}', 5057, 1), ('\u{1d6f5}', 5058, 1),
+        ('\u{1d6f6}', 5059, 1), ('\u{1d6f7}', 5060, 1), ('\u{1d6f8}', 5061, 1),
+        ('\u{1d6f9}', 5062, 1), ('\u{1d6fa}', 5063, 1), ('\u{1d6fb}', 5064, 1),
+        ('\u{1d6fc}', 5065, 1), ('\u{1d6fd}', 5066, 1), ('\u{1d6fe}', 5067, 1),
+        ('\u{1d6ff}', 5068, 1), ('\u{1d700}', 5069, 1), ('\u{1d701}', 5070, 1),
+        ('\u{1d702}', 5071, 1), ('\u{1d703}', 5072, 1), ('\u{1d704}', 5073, 1),
+        ('\u{1d705}', 5074, 1), ('\u{1d706}', 5075, 1), ('\u{1d707}', 5076, 1),
+        ('\u{1d708}', 5077, 1), ('\u{1d709}', 5078, 1), ('\u{1d70a}', 5079, 1),
+        ('\u{1d70b}', 5080, 1), ('\u{1d70c}', 5081, 1), ('\u{1d70d}', 5082, 1),
+        ('\u{1d70e}', 5083, 1), ('\u{1d70f}', 5084, 1), ('\u{1d710}', 5085, 1),
+        ('\u{1d711}', 5086, 1), ('\u{1d712}', 5087, 1), ('\u{1d713}', 5088, 1),
+        ('\u{1d714}', 5089, 1), ('\u{1d715}', 5090, 1), ('\u{1d716}', 5091, 1),
+        ('\u{1d717}', 5092, 1), ('\u{1d718}', 5093, 1), ('\u{1d719}', 5094, 1),
+        ('\u{1d71a}', 5095, 1), ('\u{1d71b}', 5096, 1), ('\u{1d71c}', 5097, 1),
+        ('\u{1d71d}', 5098, 1), ('\u{1d71e}', 5099, 1), ('\u{1d71f}', 5100, 1),
+        ('\u{1d720}', 5101, 1), ('\u{1d721}', 5102, 1), ('\u{1d722}', 5103, 1),
+        ('\u{1d723}', 5104, 1), ('\u{1d724}', 5105, 1), ('\u{1d725}', 5106, 1),
+        ('\u{1d726}', 5107, 1), ('\u{1d727}', 5108, 1), ('\u{1d728}', 5109, 1),
+        ('\u{1d729}', 5110, 1), ('\u{1d72a}', 5111, 1), ('\u{1d72b}', 5112, 1),
+        ('\u{1d72c}', 5113, 1), ('\u{1d72d}', 5114, 1), ('\u{1d72e}', 5115, 1),
+        ('\u{1d72f}', 5116, 1), ('\u{1d730}', 5117, 1), ('\u{1d731}', 5118, 1),
+        ('\u{1d732}', 5119, 1), ('\u{1d733}', 5120, 1), ('\u{1d734}', 5121, 1),
+        ('\u{1d735}', 5122, 1), ('\u{1d736}', 5123, 1), ('\u{1d737}', 5124, 1),
+        ('\u{1d738}', 5125, 1), ('\u{1d739}', 5126, 1), ('\u{1d73a}', 5127, 1),
+        ('\u{1d73b}', 5128, 1), ('\u{1d73c}', 5129, 1), ('\u{1d73d}', 5130, 1),
+        ('\u{1d73e}', 5131, 1), ('\u{1d73f}', 5132, 1), ('\u{1d740}', 5133, 1),
+        ('\u{1d741}', 5134, 1), ('\u{1d742}', 5135, 1), ('\u{1d743}', 5136, 1),
+        ('\u{1d744}', 5137, 1), ('\u{1d745}', 5138, 1), ('\u{1d746}', 5139, 1),
+        ('\u{1d747}', 5140, 1), ('\u{1d748}', 5141, 1), ('\u{1d749}', 5142, 1),
+        ('\u{1d74a}', 5143, 1), ('\u{1d74b}', 5144, 1), ('\u{1d74c}', 5145, 1),
+        ('\u{1d74d}', 5146, 1), ('\u{1d74e}', 5147, 1), ('\u{1d74f}', 5148, 1),
+        ('\u{1d750}', 5149, 1), ('\u{1d751}', 5150, 1), ('\u{1d752}', 5151, 1),
+        ('\u{1d753}', 5152, 1), ('\u{1d754}', 5153, 1), ('\u{1d755}', 5154, 1),
+        ('\u{1d756}', 5155, 1), ('\u{1d757}', 5156, 1), ('\u{1d758}', 5157, 1),
+        ('\u{1d759}', 5158, 1), ('\u{1d75a}', 5159, 1), ('\u{1d75b}', 5160, 1),
+        ('\u{1d75c}', 5161, 1), ('\u{1d75d}', 5162, 1), ('\u{1d75e}', 5163, 1),
+        ('\u{1d75f}', 5164, 1), ('\u{1d760}', 5165, 1), ('\u{1d761}', 5166, 1),
+        ('\u{1d762}', 5167, 1), ('\u{1d763}', 5168, 1), ('\u{1d764}', 5169, 1),
+        ('\u{1d765}', 5170, 1), ('\u{1d766}', 5171, 1), ('\u{1d767}', 5172, 1),
+        ('\u{1d768}', 5173, 1), ('\u{1d769}', 5174, 1), ('\u{1d76a}', 5175, 1),
+        ('\u{1d76b}', 5176, 1), ('\u{1d76c}', 5177, 1), ('\u{1d76d}', 5178, 1),
+        ('\u{1d76e}', 5179, 1), ('\u{1d76f}', 5180, 1), ('\u{1d770}', 5181, 1),
+        ('\u{1d771}', 5182, 1), ('\u{1d772}', 5183, 1), ('\u{1d773}', 5184, 1),
+        ('\u{1d774}', 5185, 1), ('\u{1d775}', 5186, 1), ('\u{1d776}', 5187, 1),
+        ('\u{1d777}', 5188, 1), ('\u{1d778}', 5189, 1), ('\u{1d779}', 5190, 1),
+        ('\u{1d77a}', 5191, 1), ('\u{1d77b}', 5192, 1), ('\u{1d77c}', 5193, 1),
+        ('\u{1d77d}', 5194, 1), ('\u{1d77e}', 5195, 1), ('\u{1d77f}', 5196, 1),
+        ('\u{1d780}', 5197, 1), ('\u{1d781}', 5198, 1), ('\u{1d782}', 5199, 1),
+        ('\u{1d783}', 5200, 1), ('\u{1d784}', 5201, 1), ('\u{1d785}', 5202, 1),
+        ('\u{1d786}', 5203, 1), ('\u{1d787}', 5204, 1), ('\u{1d788}', 5205, 1),
+        ('\u{1d789}', 5206, 1), ('\u{1d78a}', 5207, 1), ('\u{1d78b}', 5208, 1),
+        ('\u{1d78c}', 5209, 1), ('\u{1d78d}', 5210, 1), ('\u{1d78e}', 5211, 1),
+        ('\u{1d78f}', 5212, 1), ('\u{1d790}', 5213, 1), ('\u{1d791}', 5214, 1),
+        ('\u{1d792}', 5215, 1), ('\u{1d793}', 5216, 1), ('\u{1d794}', 5217, 1),
+        ('\u{1d795}', 5218, 1), ('\u{1d796}', 5219, 1), ('\u{1d797}', 5220, 1),
+        ('\u{1d798}', 5221, 1), ('\u{1d799}', 5222, 1), ('\u{1d79a}', 5223, 1),
+        ('\u{1d79b}', 5224, 1), ('\u{1d79c}', 5225, 1), ('\u{1d79d}', 5226, 1),
+        ('\u{1d79e}', 5227, 1), ('\u{1d79f}', 5228, 1), ('\u{1d7a0}', 5229, 1),
+        ('\u{1d7a1}', 5230, 1), ('\u{1d7a2}', 5231, 1), ('\u{1d7a3}', 5232, 1),
+        ('\u{1d7a4}', 5233, 1), ('\u{1d7a5}', 5234, 1), ('\u{1d7a6}', 5235, 1),
+        ('\u{1d7a7}', 5236, 1), ('\u{1d7a8}', 5237, 1), ('\u{1d7a9}', 5238, 1),
+        ('\u{1d7aa}', 5239, 1), ('\u{1d7ab}', 5240, 1), ('\u{1d7ac}', 5241, 1),
+        ('\u{1d7ad}', 5242, 1), ('\u{1d7ae}', 5243, 1), ('\u{1d7af}', 5244, 1),
+        ('\u{1d7b0}', 5245, 1), ('\u{1d7b1}', 5246, 1), ('\u{1d7b2}', 5247, 1),
+        ('\u{1d7b3}', 5248, 1), ('\u{1d7b4}', 5249, 1), ('\u{1d7b5}', 5250, 1),
+        ('\u{1d7b6}', 5251, 1), ('\u{1d7b7}', 5252, 1), ('\u{1d7b8}', 5253, 1),
+        ('\u{1d7b9}', 5254, 1), ('\u{1d7ba}', 5255, 1), ('\u{1d7bb}', 5256, 1),
+        ('\u{1d7bc}', 5257, 1), ('\u{1d7bd}', 5258, 1), ('\u{1d7be}', 5259, 1),
+        ('\u{1d7bf}', 5260, 1), ('\u{1d7c0}', 5261, 1), ('\u{1d7c1}', 5262, 1),
+        ('\u{1d7c2}', 5263, 1), ('\u{1d7c3}', 5264, 1), ('\u{1d7c4}', 5265, 1),
+        ('\u{1d7c5}', 5266, 1), ('\u{1d7c6}', 5267, 1), ('\u{1d7c7}', 5268, 1),
+        ('\u{1d7c8}', 5269, 1), ('\u{1d7c9}', 5270, 1), ('\u{1d7ca}', 5271, 1),
+        ('\u{1d7cb}', 5272, 1), ('\u{1d7ce}', 5273, 1), ('\u{1d7cf}', 5274, 1),
+        ('\u{1d7d0}', 5275, 1), ('\u{1d7d1}', 5276, 1), ('\u{1d7d2}', 5277, 1),
+        ('\u{1d7d3}', 5278, 1), ('\u{1d7d4}', 5279, 1), ('\u{1d7d5}', 5280, 1),
+        ('\u{1d7d6}', 5281, 1), ('\u{1d7d7}', 5282, 1), ('\u{1d7d8}', 5283, 1),
+        ('\u{1d7d9}', 5284, 1), ('\u{1d7da}', 5285, 1), ('\u{1d7db}', 5286, 1),
+        ('\u{1d7dc}', 5287, 1), ('\u{1d7dd}', 5288, 1), ('\u{1d7de}', 5289, 1),
+        ('\u{1d7df}', 5290, 1), ('\u{1d7e0}', 5291, 1), ('\u{1d7e1}', 5292, 1),
+        ('\u{1d7e2}', 5293, 1), ('\u{1d7e3}', 5294, 1), ('\u{1d7e4}', 5295, 1),
+        ('\u{1d7e5}', 5296, 1), ('\u{1d7e6}', 5297, 1), ('\u{1d7e7}', 5298, 1),
+        ('\u{1d7e8}', 5299, 1), ('\u{1d7e9}', 5300, 1), ('\u{1d7ea}', 5301, 1),
+        ('\u{1d7eb}', 5302, 1), ('\u{1d7ec}', 5303, 1), ('\u{1d7ed}', 5304, 1),
+        ('\u{1d7ee}', 5305, 1), ('\u{1d7ef}', 5306, 1), ('\u{1d7f0}', 5307, 1),
+        ('\u{1d7f1}', 5308, 1), ('\u{1d7f2}', 5309, 1), ('\u{1d7f3}', 5310, 1),
+        ('\u{1d7f4}', 5311, 1), ('\u{1d7f5}', 5312, 1), ('\u{1d7f6}', 5313, 1),
+        ('\u{1d7f7}', 5314, 1), ('\u{1d7f8}', 5315, 1), ('\u{1d7f9}', 5316, 1),
+        ('\u{1d7fa}', 5317, 1), ('\u{1d7fb}', 5318, 1), ('\u{1d7fc}', 5319, 1),
+        ('\u{1d7fd}', 5320, 1), ('\u{1d7fe}', 5321, 1), ('\u{1d7ff}', 5322, 1),
+        ('\u{1e030}', 5323, 1), ('\u{1e031}', 5324, 1), ('\u{1e032}', 5325, 1),
+        ('\u{1e033}', 5326, 1), ('\u{1e034}', 5327, 1), ('\u{1e035}', 5328, 1),
+        ('\u{1e036}', 5329, 1), ('\u{1e037}', 5330, 1), ('\u{1e038}', 5331, 1),
+        ('\u{1e039}', 5332, 1), ('\u{1e03a}', 5333, 1), ('\u{1e03b}', 5334, 1),
+        ('\u{1e03c}', 5335, 1), ('\u{1e03d}', 5336, 1), ('\u{1e03e}', 5337, 1),
+        ('\u{1e03f}', 5338, 1), ('\u{1e040}', 5339, 1), ('\u{1e041}', 5340, 1),
+        ('\u{1e042}', 5341, 1), ('\u{1e043}', 5342, 1), ('\u{1e044}', 5343, 1),
+        ('\u{1e045}', 5344, 1), ('\u{1e046}', 5345, 1), ('\u{1e047}', 5346, 1),
+        ('\u{1e048}', 5347, 1), ('\u{1e049}', 5348, 1), ('\u{1e04a}', 5349, 1),
+        ('\u{1e04b}', 5350, 1), ('\u{1e04c}', 5351, 1), ('\u{1e04d}', 5352, 1),
+        ('\u{1e04e}', 5353, 1), ('\u{1e04f}', 5354, 1), ('\u{1e050}', 5355, 1),
+        ('\u{1e051}', 5356, 1), ('\u{1e052}', 5357, 1), ('\u{1e053}', 5358, 1),
+        ('\u{1e054}', 5359, 1), ('\u{1e055}', 5360, 1), ('\u{1e056}', 5361, 1),
+        ('\u{1e057}', 5362, 1), ('\u{1e058}', 5363, 1), ('\u{1e059}', 5364, 1),
+        ('\u{1e05a}', 5365, 1), ('\u{1e05b}', 5366, 1), ('\u{1e05c}', 5367, 1),
+        ('\u{1e05d}', 5368, 1), ('\u{1e05e}', 5369, 1), ('\u{1e05f}', 5370, 1),
+        ('\u{1e060}', 5371, 1), ('\u{1e061}', 5372, 1), ('\u{1e062}', 5373, 1),
+        ('\u{1e063}', 5374, 1), ('\u{1e064}', 5375, 1), ('\u{1e065}', 5376, 1),
+        ('\u{1e066}', 5377, 1), ('\u{1e067}', 5378, 1), ('\u{1e068}', 5379, 1),
+        ('\u{1e069}', 5380, 1), ('\u{1e06a}', 5381, 1), ('\u{1e06b}', 5382, 1),
+        ('\u{1e06c}', 5383, 1), ('\u{1e06d}', 5384, 1), ('\u{1ee00}', 5385, 1),
+        ('\u{1ee01}', 5386, 1), ('\u{1ee02}', 5387, 1), ('\u{1ee03}', 5388, 1),
+        ('\u{1ee05}', 5389, 1), ('\u{1ee06}', 5390, 1), ('\u{1ee07}', 5391, 1),
+        ('\u{1ee08}', 5392, 1), ('\u{1ee09}', 5393, 1), ('\u{1ee0a}', 5394, 1),
+        ('\u{1ee0b}', 5395, 1), ('\u{1ee0c}', 5396, 1), ('\u{1ee0d}', 5397, 1),
+        ('\u{1ee0e}', 5398, 1), ('\u{1ee0f}', 5399, 1), ('\u{1ee10}', 5400, 1),
+        ('\u{1ee11}', 5401, 1), ('\u{1ee12}', 5402, 1), ('\u{1ee13}', 5403, 1),
+        ('\u{1ee14}', 5404, 1), ('\u{1ee15}', 5405, 1), ('\u{1ee16}', 5406, 1),
+        ('\u{1ee17}', 5407, 1), ('\u{1ee18}', 5408, 1), ('\u{1ee19}', 5409, 1),
+        ('\u{1ee1a}', 5410, 1), ('\u{1ee1b}', 5411, 1), ('\u{1ee1c}', 5412, 1),
+        ('\u{1ee1d}', 5413, 1), ('\u{1ee1e}', 5414, 1), ('\u{1ee1f}', 5415, 1),
+        ('\u{1ee21}', 5416, 1), ('\u{1ee22}', 5417, 1), ('\u{1ee24}', 5418, 1),
+        ('\u{1ee27}', 5419, 1), ('\u{1ee29}', 5420, 1), ('\u{1ee2a}', 5421, 1),
+        ('\u{1ee2b}', 5422, 1), ('\u{1ee2c}', 5423, 1), ('\u{1ee2d}', 5424, 1),
+        ('\u{1ee2e}', 5425, 1), ('\u{1ee2f}', 5426, 1), ('\u{1ee30}', 5427, 1),
+        ('\u{1ee31}', 5428, 1), ('\u{1ee32}', 5429, 1), ('\u{1ee34}', 5430, 1),
+        ('\u{1ee35}', 5431, 1), ('\u{1ee36}', 5432, 1), ('\u{1ee37}', 5433, 1),
+        ('\u{1ee39}', 5434, 1), ('\u{1ee3b}', 5435, 1), ('\u{1ee42}', 5436, 1),
+        ('\u{1ee47}', 5437, 1), ('\u{1ee49}', 5438, 1), ('\u{1ee4b}', 5439, 1),
+        ('\u{1ee4d}', 5440, 1), ('\u{1ee4e}', 5441, 1), ('\u{1ee4f}', 5442, 1),
+        ('\u{1ee51}', 5443, 1), ('\u{1ee52}', 5444, 1), ('\u{1ee54}', 5445, 1),
+        ('\u{1ee57}', 5446, 1), ('\u{1ee59}', 5447, 1), ('\u{1ee5b}', 5448, 1),
+        ('\u{1ee5d}', 5449, 1), ('\u{1ee5f}', 5450, 1), ('\u{1ee61}', 5451, 1),
+        ('\u{1ee62}', 5452, 1), ('\u{1ee64}', 5453, 1), ('\u{1ee67}', 5454, 1),
+        ('\u{1ee68}', 5455, 1), ('\u{1ee69}', 5456, 1), ('\u{1ee6a}', 5457, 1),
+        ('\u{1ee6c}', 5458, 1), ('\u{1ee6d}', 5459, 1), ('\u{1ee6e}', 5460, 1),
+        ('\u{1ee6f}', 5461, 1), ('\u{1ee70}', 5462, 1), ('\u{1ee71}', 5463, 1),
+        ('\u{1ee72}', 5464, 1), ('\u{1ee74}', 5465, 1), ('\u{1ee75}', 5466, 1),
+        ('\u{1ee76}', 5467, 1), ('\u{1ee77}', 5468, 1), ('\u{1ee79}', 5469, 1),
+        ('\u{1ee7a}', 5470, 1), ('\u{1ee7b}', 5471, 1), ('\u{1ee7c}', 5472, 1),
+        ('\u{1ee7e}', 5473, 1), ('\u{1ee80}', 5474, 1), ('\u{1ee81}', 5475, 1),
+        ('\u{1ee82}', 5476, 1), ('\u{1ee83}', 5477, 1), ('\u{1ee84}', 5478, 1),
+        ('\u{1ee85}', 5479, 1), ('\u{1ee86}', 5480, 1), ('\u{1ee87}', 5481, 1),
+        ('\u{1ee88}', 5482, 1), ('\u{1ee89}', 5483, 1), ('\u{1ee8b}', 5484, 1),
+        ('\u{1ee8c}', 5485, 1), ('\u{1ee8d}', 5486, 1), ('\u{1ee8e}', 5487, 1),
+        ('\u{1ee8f}', 5488, 1), ('\u{1ee90}', 5489, 1), ('\u{1ee91}', 5490, 1),
+        ('\u{1ee92}', 5491, 1), ('\u{1ee93}', 5492, 1), ('\u{1ee94}', 5493, 1),
+        ('\u{1ee95}', 5494, 1), ('\u{1ee96}', 5495, 1), ('\u{1ee97}', 5496, 1),
+        ('\u{1ee98}', 5497, 1), ('\u{1ee99}', 5498, 1), ('\u{1ee9a}', 5499, 1),
+        ('\u{1ee9b}', 5500, 1), ('\u{1eea1}', 5501, 1), ('\u{1eea2}', 5502, 1),
+        ('\u{1eea3}', 5503, 1), ('\u{1eea5}', 5504, 1), ('\u{1eea6}', 5505, 1),
+        ('\u{1eea7}', 5506, 1), ('\u{1eea8}', 5507, 1), ('\u{1eea9}', 5508, 1),
+        ('\u{1eeab}', 5509, 1), ('\u{1eeac}', 5510, 1), ('\u{1eead}', 5511, 1),
+        ('\u{1eeae}', 5512, 1), ('\u{1eeaf}', 5513, 1), ('\u{1eeb0}', 5514, 1),
+        ('\u{1eeb1}', 5515, 1), ('\u{1eeb2}', 5516, 1), ('\u{1eeb3}', 5517, 1),
+        ('\u{1eeb4}', 5518, 1), ('\u{1eeb5}', 5519, 1), ('\u{1eeb6}', 5520, 1),
+        ('\u{1eeb7}', 5521, 1), ('\u{1eeb8}', 5522, 1), ('\u{1eeb9}', 5523, 1),
+        ('\u{1eeba}', 5524, 1), ('\u{1eebb}', 5525, 1), ('\u{1f100}', 5526, 2),
+        ('\u{1f101}', 5528, 2), ('\u{1f102}', 5530, 2), ('\u{1f103}', 5532, 2),
+        ('\u{1f104}', 5534, 2), ('\u{1f105}', 5536, 2), ('\u{1f106}', 5538, 2),
+        ('\u{1f107}', 5540, 2), ('\u{1f108}', 5542, 2), ('\u{1f109}', 5544, 2),
+        ('\u{1f10a}', 5546, 2), ('\u{1f110}', 5548, 3), ('\u{1f111}', 5551, 3),
+        ('\u{1f112}', 5554, 3), ('\u{1f113}', 5557, 3), ('\u{1f114}', 5560, 3),
+        ('\u{1f115}', 5563, 3), ('\u{1f116}', 5566, 3), ('\u{1f117}', 5569, 3),
+        ('\u{1f118}', 5572, 3), ('\u{1f119}', 5575, 3), ('\u{1f11a}', 5578, 3),
+        ('\u{1f11b}', 5581, 3), ('\u{1f11c}', 5584, 3), ('\u{1f11d}', 5587, 3),
+        ('\u{1f11e}', 5590, 3), ('\u{1f11f}', 5593, 3), ('\u{1f120}', 5596, 3),
+        ('\u{1f121}', 5599, 3), ('\u{1f122}', 5602, 3), ('\u{1f123}', 5605, 3),
+        ('\u{1f124}', 5608, 3), ('\u{1f125}', 5611, 3), ('\u{1f126}', 5614, 3),
+        ('\u{1f127}', 5617, 3), ('\u{1f128}', 5620, 3), ('\u{1f129}', 5623, 3),
+        ('\u{1f12a}', 5626, 3), ('\u{1f12b}', 5629, 1), ('\u{1f12c}', 5630, 1),
+        ('\u{1f12d}', 5631, 2), ('\u{1f12e}', 5633, 2), ('\u{1f130}', 5635, 1),
+        ('\u{1f131}', 5636, 1), ('\u{1f132}', 5637, 1), ('\u{1f133}', 5638, 1),
+        ('\u{1f134}', 5639, 1), ('\u{1f135}', 5640, 1), ('\u{1f136}', 5641, 1),
+        ('\u{1f137}', 5642, 1), ('\u{1f138}', 5643, 1), ('\u{1f139}', 5644, 1),
+        ('\u{1f13a}', 5645, 1), ('\u{1f13b}', 5646, 1), ('\u{1f13c}', 5647, 1),
+        ('\u{1f13d}', 5648, 1), ('\u{1f13e}', 5649, 1), ('\u{1f13f}', 5650, 1),
+        ('\u{1f140}', 5651, 1), ('\u{1f141}', 5652, 1), ('\u{1f142}', 5653, 1),
+        ('\u{1f143}', 5654, 1), ('\u{1f144}', 5655, 1), ('\u{1f145}', 5656, 1),
+        ('\u{1f146}', 5657, 1), ('\u{1f147}', 5658, 1), ('\u{1f148}', 5659, 1),
+        ('\u{1f149}', 5660, 1), ('\u{1f14a}', 5661, 2), ('\u{1f14b}', 5663, 2),
+        ('\u{1f14c}', 5665, 2), ('\u{1f14d}', 5667, 2), ('\u{1f14e}', 5669, 3),
+        ('\u{1f14f}', 5672, 2), ('\u{1f16a}', 5674, 2), ('\u{1f16b}', 5676, 2),
+        ('\u{1f16c}', 5678, 2), ('\u{1f190}', 5680, 2), ('\u{1f200}', 5682, 2),
+        ('\u{1f201}', 5684, 2), ('\u{1f202}', 5686, 1), ('\u{1f210}', 5687, 1),
+        ('\u{1f211}', 5688, 1), ('\u{1f212}', 5689, 1), ('\u{1f213}', 5690, 2),
+        ('\u{1f214}', 5692, 1), ('\u{1f215}', 5693, 1), ('\u{1f216}', 5694, 1),
+        ('\u{1f217}', 5695, 1), ('\u{1f218}', 5696, 1), ('\u{1f219}', 5697, 1),
+        ('\u{1f21a}', 5698, 1), ('\u{1f21b}', 5699, 1), ('\u{1f21c}', 5700, 1),
+        ('\u{1f21d}', 5701, 1), ('\u{1f21e}', 5702, 1), ('\u{1f21f}', 5703, 1),
+        ('\u{1f220}', 5704, 1), ('\u{1f221}', 5705, 1), ('\u{1f222}', 5706, 1),
+        ('\u{1f223}', 5707, 1), ('\u{1f224}', 5708, 1), ('\u{1f225}', 5709, 1),
+        ('\u{1f226}', 5710, 1), ('\u{1f227}', 5711, 1), ('\u{1f228}', 5712, 1),
+        ('\u{1f229}', 5713, 1), ('\u{1f22a}', 5714, 1), ('\u{1f22b}', 5715, 1),
+        ('\u{1f22c}', 5716, 1), ('\u{1f22d}', 5717, 1), ('\u{1f22e}', 5718, 1),
+        ('\u{1f22f}', 5719, 1), ('\u{1f230}', 5720, 1), ('\u{1f231}', 5721, 1),
+        ('\u{1f232}', 5722, 1), ('\u{1f233}', 5723, 1), ('\u{1f234}', 5724, 1),
+        ('\u{1f235}', 5725, 1), ('\u{1f236}', 5726, 1), ('\u{1f237}', 5727, 1),
+        ('\u{1f238}', 5728, 1), ('\u{1f239}', 5729, 1), ('\u{1f23a}', 5730, 1),
+        ('\u{1f23b}', 5731, 1), ('\u{1f240}', 5732, 3), ('\u{1f241}', 5735, 3),
+        ('\u{1f242}', 5738, 3), ('\u{1f243}', 5741, 3), ('\u{1f244}', 5744, 3),
+        ('\u{1f245}', 5747, 3), ('\u{1f246}', 5750, 3), ('\u{1f247}', 5753, 3),
+        ('\u{1f248}', 5756, 3), ('\u{1f250}', 5759, 1), ('\u{1f251}', 5760, 1),
+        ('\u{1fbf0}', 5761, 1), ('\u{1fbf1}', 5762, 1), ('\u{1fbf2}', 5763, 1),
+        ('\u{1fbf3}', 5764, 1), ('\u{1fbf4}', 5765, 1), ('\u{1fbf5}', 5766, 1),
+        ('\u{1fbf6}', 5767, 1), ('\u{1fbf7}', 5768, 1), ('\u{1fbf8}', 5769, 1),
+        ('\u{1fbf9}', 5770, 1),
+    ];
+
+    static COMPATIBILITY_DECOMPOSED_CHARS: &[char] = &[
+        ' ', ' ', '\u{308}', 'a', ' ', '\u{304}', '2', '3', ' ', '\u{301}', '\u{3bc}', ' ',
+        '\u{327}', '1', 'o', '1', '\u{2044}', '4', '1', '\u{2044}', '2', '3', '\u{2044}', '4', 'I',
+        'J', 'i', 'j', 'L', '\u{b7}', 'l', '\u{b7}', '\u{2bc}', 'n', 's', 'D', 'Z', '\u{30c}', 'D',
+        'z', '\u{30c}', 'd', 'z', '\u{30c}', 'L', 'J', 'L', 'j', 'l', 'j', 'N', 'J', 'N', 'j', 'n',
+        'j', 'D', 'Z', 'D', 'z', 'd', 'z', 'h', '\u{266}', 'j', 'r', '\u{279}', '\u{27b}',
+        '\u{281}', 'w', 'y', ' ', '\u{306}', ' ', '\u{307}', ' ', '\u{30a}', ' ', '\u{328}', ' ',
+        '\u{303}', ' ', '\u{30b}', '\u{263}', 'l', 's', 'x', '\u{295}', ' ', '\u{345}', ' ',
+        '\u{301}', ' ', '\u{308}', '\u{301}', '\u{3b2}', '\u{3b8}', '\u{3a5}', '\u{3a5}', '\u{301}',
+        '\u{3a5}', '\u{308}', '\u{3c6}', '\u{3c0}', '\u{3ba}', '\u{3c1}', '\u{3c2}', '\u{398}',
+        '\u{3b5}', '\u{3a3}', '\u{565}', '\u{582}', '\u{627}', '\u{674}', '\u{648}', '\u{674}',
+        '\u{6c7}', '\u{674}', '\u{64a}', '\u{674}', '\u{e4d}', '\u{e32}', '\u{ecd}', '\u{eb2}',
+        '\u{eab}', '\u{e99}', '\u{eab}', '\u{ea1}', '\u{f0b}', '\u{fb2}', '\u{f71}', '\u{f80}',
+        '\u{fb3}', '\u{f71}', '\u{f80}', '\u{10dc}', 'A', '\u{c6}', 'B', 'D', 'E', '\u{18e}', 'G',
+        'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', '\u{222}', 'P', 'R', 'T', 'U', 'W', 'a', '\u{250}',
+        '\u{251}', '\u{1d02}', 'b', 'd', 'e', '\u{259}', '\u{25b}', '\u{25c}', 'g', 'k', 'm',
+        '\u{14b}', 'o', '\u{254}', '\u{1d16}', '\u{1d17}', 'p', 't', 'u', '\u{1d1d}', '\u{26f}',
+        'v', '\u{1d25}', '\u{3b2}', '\u{3b3}', '\u{3b4}', '\u{3c6}', '\u{3c7}', 'i', 'r', 'u', 'v',
+        '\u{3b2}', '\u{3b3}', '\u{3c1}', '\u{3c6}', '\u{3c7}', '\u{43d}', '\u{252}', 'c', '\u{255}',
+        '\u{f0}', '\u{25c}', 'f', '\u{25f}', '\u{261}', '\u{265}', '\u{268}', '\u{269}', '\u{26a}',
+        '\u{1d7b}', '\u{29d}', '\u{26d}', '\u{1d85}', '\u{29f}', '\u{271}', '\u{270}', '\u{272}',
+        '\u{273}', '\u{274}', '\u{275}', '\u{278}', '\u{282}', '\u{283}', '\u{1ab}', '\u{289}',
+        '\u{28a}', '\u{1d1c}', '\u{28b}', '\u{28c}', 'z', '\u{290}', '\u{291}', '\u{292}',
+        '\u{3b8}', 'a', '\u{2be}', 's', '\u{307}', ' ', '\u{313}', ' ', '\u{313}', ' ', '\u{342}',
+        ' ', '\u{308}', '\u{342}', ' ', '\u{313}', '\u{300}', ' ', '\u{313}', '\u{301}', ' ',
+        '\u{313}', '\u{342}', ' ', '\u{314}', '\u{300}', ' ', '\u{314}', '\u{301}', ' ', '\u{314}',
+        '\u{342}', ' ', '\u{308}', '\u{300}', ' ', '\u{308}', '\u{301}', ' ', '\u{301}', ' ',
+        '\u{314}', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '\u{2010}', ' ',
+        '\u{333}', '.', '.', '.', '.', '.', '.', ' ', '\u{2032}', '\u{2032}', '\u{2032}',
+        '\u{2032}', '\u{2032}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}', '!',
+        '!', ' ', '\u{305}', '?', '?', '?', '!', '!', '?', '\u{2032}', '\u{2032}', '\u{2032}',
+        '\u{2032}', ' ', '0', 'i', '4', '5', '6', '7', '8', '9', '+', '\u{2212}', '=', '(', ')',
+        'n', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '+', '\u{2212}', '=', '(', ')', 'a',
+        'e', 'o', 'x', '\u{259}', 'h', 'k', 'l', 'm', 'n', 'p', 's', 't', 'R', 's', 'a', '/', 'c',
+        'a', '/', 's', 'C', '\u{b0}', 'C', 'c', '/', 'o', 'c', '/', 'u', '\u{190}', '\u{b0}', 'F',
+        'g', 'H', 'H', 'H', 'h', '\u{127}', 'I', 'I', 'L', 'l', 'N', 'N', 'o', 'P', 'Q', 'R', 'R',
+        'R', 'S', 'M', 'T', 'E', 'L', 'T', 'M', 'Z', 'Z', 'B', 'C', 'e', 'E', 'F', 'M', 'o',
+        '\u{5d0}', '\u{5d1}', '\u{5d2}', '\u{5d3}', 'i', 'F', 'A', 'X', '\u{3c0}', '\u{3b3}',
+        '\u{393}', '\u{3a0}', '\u{2211}', 'D', 'd', 'e', 'i', 'j', '1', '\u{2044}', '7', '1',
+        '\u{2044}', '9', '1', '\u{2044}', '1', '0', '1', '\u{2044}', '3', '2', '\u{2044}', '3', '1',
+        '\u{2044}', '5', '2', '\u{2044}', '5', '3', '\u{2044}', '5', '4', '\u{2044}', '5', '1',
+        '\u{2044}', '6', '5', '\u{2044}', '6', '1', '\u{2044}', '8', '3', '\u{2044}', '8', '5',
+        '\u{2044}', '8', '7', '\u{2044}', '8', '1', '\u{2044}', 'I', 'I', 'I', 'I', 'I', 'I', 'I',
+        'V', 'V', 'V', 'I', 'V', 'I', 'I', 'V', 'I', 'I', 'I', 'I', 'X', 'X', 'X', 'I', 'X', 'I',
+        'I', 'L', 'C', 'D', 'M', 'i', 'i', 'i', 'i', 'i', 'i', 'i', 'v', 'v', 'v', 'i', 'v', 'i',
+        'i', 'v', 'i', 'i', 'i', 'i', 'x', 'x', 'x', 'i', 'x', 'i', 'i', 'l', 'c', 'd', 'm', '0',
+        '\u{2044}', '3', '\u{222b}', '\u{222b}', '\u{222b}', '\u{222b}', '\u{222b}', '\u{222e}',
+        '\u{222e}', '\u{222e}', '\u{222e}', '\u{222e}', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+        '1', '0', '1', '1', '1', '2', '1', '3', '1', '4', '1', '5', '1', '6', '1', '7', '1', '8',
+        '1', '9', '2', '0', '(', '1', ')', '(', '2', ')', '(', '3', ')', '(', '4', ')', '(', '5',
+        ')', '(', '6', ')', '(', '7', ')', '(', '8', ')', '(', '9', ')', '(', '1', '0', ')', '(',
+        '1', '1', ')', '(', '1', '2', ')', '(', '1', '3', ')', '(', '1', '4', ')', '(', '1', '5',
+        ')', '(', '1', '6', ')', '(', '1', '7', ')', '(', '1', '8', ')', '(', '1', '9', ')', '(',
+        '2', '0', ')', '1', '.', '2', '.', '3', '.', '4', '.', '5', '.', '6', '.', '7', '.', '8',
+        '.', '9', '.', '1', '0', '.', '1', '1', '.', '1', '2', '.', '1', '3', '.', '1', '4', '.',
+        '1', '5', '.', '1', '6', '.', '1', '7', '.', '1', '8', '.', '1', '9', '.', '2', '0', '.',
+        '(', 'a', ')', '(', 'b', ')', '(', 'c', ')', '(', 'd', ')', '(', 'e', ')', '(', 'f', ')',
+        '(', 'g', ')', '(', 'h', ')', '(', 'i', ')', '(', 'j', ')', '(', 'k', ')', '(', 'l', ')',
+        '(', 'm', ')', '(', 'n', ')', '(', 'o', ')', '(', 'p', ')', '(', 'q', ')', '(', 'r', ')',
+        '(', 's', ')', '(', 't', ')', '(', 'u', ')', '(', 'v', ')', '(', 'w', ')', '(', 'x', ')',
+        '(', 'y', ')', '(', 'z', ')', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L',
+        'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd',
+        'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v',
+        'w', 'x', 'y', 'z', '0', '\u{222b}', '\u{222b}', '\u{222b}', '\u{222b}', ':', ':', '=', '=',
+        '=', '=', '=', '=', 'j', 'V', '\u{2d61}', '\u{6bcd}', '\u{9f9f}', '\u{4e00}', '\u{4e28}',
+        '\u{4e36}', '\u{4e3f}', '\u{4e59}', '\u{4e85}', '\u{4e8c}', '\u{4ea0}', '\u{4eba}',
+        '\u{513f}', '\u{5165}', '\u{516b}', '\u{5182}', '\u{5196}', '\u{51ab}', '\u{51e0}',
+        '\u{51f5}', '\u{5200}', '\u{529b}', '\u{52f9}', '\u{5315}', '\u{531a}', '\u{5338}',
+        '\u{5341}', '\u{535c}', '\u{5369}', '\u{5382}', '\u{53b6}', '\u{53c8}', '\u{53e3}',
+        '\u{56d7}', '\u{571f}', '\u{58eb}', '\u{5902}', '\u{590a}', '\u{5915}', '\u{5927}',
+        '\u{5973}', '\u{5b50}', '\u{5b80}', '\u{5bf8}', '\u{5c0f}', '\u{5c22}', '\u{5c38}',
+        '\u{5c6e}', '\u{5c71}', '\u{5ddb}', '\u{5de5}', '\u{5df1}', '\u{5dfe}', '\u{5e72}',
+        '\u{5e7a}', '\u{5e7f}', '\u{5ef4}', '\u{5efe}', '\u{5f0b}', '\u{5f13}', '\u{5f50}',
+        '\u{5f61}', '\u{5f73}', '\u{5fc3}', '\u{6208}', '\u{6236}', '\u{624b}', '\u{652f}',
+        '\u{6534}', '\u{6587}', '\u{6597}', '\u{65a4}', '\u{65b9}', '\u{65e0}', '\u{65e5}',
+        '\u{66f0}', '\u{6708}', '\u{6728}', '\u{6b20}', '\u{6b62}', '\u{6b79}', '\u{6bb3}',
+        '\u{6bcb}', '\u{6bd4}', '\u{6bdb}', '\u{6c0f}', '\u{6c14}', '\u{6c34}', '\u{706b}',
+        '\u{722a}', '\u{7236}', '\u{723b}', '\u{723f}', '\u{7247}', '\u{7259}', '\u{725b}',
+        '\u{72ac}', '\u{7384}', '\u{7389}', '\u{74dc}', '\u{74e6}', '\u{7518}', '\u{751f}',
+        '\u{7528}', '\u{7530}', '\u{758b}', '\u{7592}', '\u{7676}', '\u{767d}', '\u{76ae}',
+        '\u{76bf}', '\u{76ee}', '\u{77db}', '\u{77e2}', '\u{77f3}', '\u{793a}', '\u{79b8}',
+        '\u{79be}', '\u{7a74}', '\u{7acb}', '\u{7af9}', '\u{7c73}', '\u{7cf8}', '\u{7f36}',
+        '\u{7f51}', '\u{7f8a}', '\u{7fbd}', '\u{8001}', '\u{800c}', '\u{8012}', '\u{8033}',
+        '\u{807f}', '\u{8089}', '\u{81e3}', '\u{81ea}', '\u{81f3}', '\u{81fc}', '\u{820c}',
+        '\u{821b}', '\u{821f}', '\u{826e}', '\u{8272}', '\u{8278}', '\u{864d}', '\u{866b}',
+        '\u{8840}', '\u{884c}', '\u{8863}', '\u{897e}', '\u{898b}', '\u{89d2}', '\u{8a00}',
+        '\u{8c37}', '\u{8c46}', '\u{8c55}', '\u{8c78}', '\u{8c9d}', '\u{8d64}', '\u{8d70}',
+        '\u{8db3}', '\u{8eab}', '\u{8eca}', '\u{8f9b}', '\u{8fb0}', '\u{8fb5}', '\u{9091}',
+        '\u{9149}', '\u{91c6}', '\u{91cc}', '\u{91d1}', '\u{9577}', '\u{9580}', '\u{961c}',
+        '\u{96b6}', '\u{96b9}', '\u{96e8}', '\u{9751}', '\u{975e}', '\u{9762}', '\u{9769}',
+        '\u{97cb}', '\u{97ed}', '\u{97f3}', '\u{9801}', '\u{98a8}', '\u{98db}', '\u{98df}',
+        '\u{9996}', '\u{9999}', '\u{99ac}', '\u{9aa8}', '\u{9ad8}', '\u{9adf}', '\u{9b25}',
+        '\u{9b2f}', '\u{9b32}', '\u{9b3c}', '\u{9b5a}', '\u{9ce5}', '\u{9e75}', '\u{9e7f}',
+        '\u{9ea5}', '\u{9ebb}', '\u{9ec3}', '\u{9ecd}', '\u{9ed1}', '\u{9ef9}', '\u{9efd}',
+        '\u{9f0e}', '\u{9f13}', '\u{9f20}', '\u{9f3b}', '\u{9f4a}', '\u{9f52}', '\u{9f8d}',
+        '\u{9f9c}', '\u{9fa0}', ' ', '\u{3012}', '\u{5341}', '\u{5344}', '\u{5345}', ' ',
+        '\u{3099}', ' ', '\u{309a}', '\u{3088}', '\u{308a}', '\u{30b3}', '\u{30c8}', '\u{1100}',
+        '\u{1101}', '\u{11aa}', '\u{1102}', '\u{11ac}', '\u{11ad}', '\u{1103}', '\u{1104}',
+        '\u{1105}', '\u{11b0}', '\u{11b1}', '\u{11b2}', '\u{11b3}', '\u{11b4}', '\u{11b5}',
+        '\u{111a}', '\u{1106}', '\u{1107}', '\u{1108}', '\u{1121}', '\u{1109}', '\u{110a}',
+        '\u{110b}', '\u{110c}', '\u{110d}', '\u{110e}', '\u{110f}', '\u{1110}', '\u{1111}',
+        '\u{1112}', '\u{1161}', '\u{1162}', '\u{1163}', '\u{1164}', '\u{1165}', '\u{1166}',
+        '\u{1167}', '\u{1168}', '\u{1169}', '\u{116a}', '\u{116b}', '\u{116c}', '\u{116d}',
+        '\u{116e}', '\u{116f}', '\u{1170}', '\u{1171}', '\u{1172}', '\u{1173}', '\u{1174}',
+        '\u{1175}', '\u{1160}', '\u{1114}', '\u{1115}', '\u{11c7}', '\u{11c8}', '\u{11cc}',
+        '\u{11ce}', '\u{11d3}', '\u{11d7}', '\u{11d9}', '\u{111c}', '\u{11dd}', '\u{11df}',
+        '\u{111d}', '\u{111e}', '\u{1120}', '\u{1122}', '\u{1123}', '\u{1127}', '\u{1129}',
+        '\u{112b}', '\u{112c}', '\u{112d}', '\u{112e}', '\u{112f}', '\u{1132}', '\u{1136}',
+        '\u{1140}', '\u{1147}', '\u{114c}', '\u{11f1}', '\u{11f2}', '\u{1157}', '\u{1158}',
+        '\u{1159}', '\u{1184}', '\u{1185}', '\u{1188}', '\u{1191}', '\u{1192}', '\u{1194}',
+        '\u{119e}', '\u{11a1}', '\u{4e00}', '\u{4e8c}', '\u{4e09}', '\u{56db}', '\u{4e0a}',
+        '\u{4e2d}', '\u{4e0b}', '\u{7532}', '\u{4e59}', '\u{4e19}', '\u{4e01}', '\u{5929}',
+        '\u{5730}', '\u{4eba}', '(', '\u{1100}', ')', '(', '\u{1102}', ')', '(', '\u{1103}', ')',
+        '(', '\u{1105}', ')', '(', '\u{1106}', ')', '(', '\u{1107}', ')', '(', '\u{1109}', ')', '(',
+        '\u{110b}', ')', '(', '\u{110c}', ')', '(', '\u{110e}', ')', '(', '\u{110f}', ')', '(',
+        '\u{1110}', ')', '(', '\u{1111}', ')', '(', '\u{1112}', ')', '(', '\u{1100}', '\u{1161}',
+        ')', '(', '\u{1102}', '\u{1161}', ')', '(', '\u{1103}', '\u{1161}', ')', '(', '\u{1105}',
+        '\u{1161}', ')', '(', '\u{1106}', '\u{1161}', ')', '(', '\u{1107}', '\u{1161}', ')', '(',
+        '\u{1109}', '\u{1161}', ')', '(', '\u{110b}', '\u{1161}', ')', '(', '\u{110c}', '\u{1161}',
+        ')', '(', '\u{110e}', '\u{1161}', ')', '(', '\u{110f}', '\u{1161}', ')', '(', '\u{1110}',
+        '\u{1161}', ')', '(', '\u{1111}', '\u{1161}', ')', '(', '\u{1112}', '\u{1161}', ')', '(',
+        '\u{110c}', '\u{116e}', ')', '(', '\u{110b}', '\u{1169}', '\u{110c}', '\u{1165}',
+        '\u{11ab}', ')', '(', '\u{110b}', '\u{1169}', '\u{1112}', '\u{116e}', ')', '(', '\u{4e00}',
+        ')', '(', '\u{4e8c}', ')', '(', '\u{4e09}', ')', '(', '\u{56db}', ')', '(', '\u{4e94}', ')',
+        '(', '\u{516d}', ')', '(', '\u{4e03}', ')', '(', '\u{516b}', ')', '(', '\u{4e5d}', ')', '(',
+        '\u{5341}', ')', '(', '\u{6708}', ')', '(', '\u{706b}', ')', '(', '\u{6c34}', ')', '(',
+        '\u{6728}', ')', '(', '\u{91d1}', ')', '(', '\u{571f}', ')', '(', '\u{65e5}', ')', '(',
+        '\u{682a}', ')', '(', '\u{6709}', ')', '(', '\u{793e}', ')', '(', '\u{540d}', ')', '(',
+        '\u{7279}', ')', '(', '\u{8ca1}', ')', '(', '\u{795d}', ')', '(', '\u{52b4}', ')', '(',
+        '\u{4ee3}', ')', '(', '\u{547c}', ')', '(', '\u{5b66}', ')', '(', '\u{76e3}', ')', '(',
+        '\u{4f01}', ')', '(', '\u{8cc7}', ')', '(', '\u{5354}', ')', '(', '\u{796d}', ')', '(',
+        '\u{4f11}', ')', '(', '\u{81ea}', ')', '(', '\u{81f3}', ')', '\u{554f}', '\u{5e7c}',
+        '\u{6587}', '\u{7b8f}', 'P', 'T', 'E', '2', '1', '2', '2', '2', '3', '2', '4', '2', '5',
+        '2', '6', '2', '7', '2', '8', '2', '9', '3', '0', '3', '1', '3', '2', '3', '3', '3', '4',
+        '3', '5', '\u{1100}', '\u{1102}', '\u{1103}', '\u{1105}', '\u{1106}', '\u{1107}',
+        '\u{1109}', '\u{110b}', '\u{110c}', '\u{110e}', '\u{110f}', '\u{1110}', '\u{1111}',
+        '\u{1112}', '\u{1100}', '\u{1161}', '\u{1102}', '\u{1161}', '\u{1103}', '\u{1161}',
+        '\u{1105}', '\u{1161}', '\u{1106}', '\u{1161}', '\u{1107}', '\u{1161}', '\u{1109}',
+        '\u{1161}', '\u{110b}', '\u{1161}', '\u{110c}', '\u{1161}', '\u{110e}', '\u{1161}',
+        '\u{110f}', '\u{1161}', '\u{1110}', '\u{1161}', '\u{1111}', '\u{1161}', '\u{1112}',
+        '\u{1161}', '\u{110e}', '\u{1161}', '\u{11b7}', '\u{1100}', '\u{1169}', '\u{110c}',
+        '\u{116e}', '\u{110b}', '\u{1174}', '\u{110b}', '\u{116e}', '\u{4e00}', '\u{4e8c}',
+        '\u{4e09}', '\u{56db}', '\u{4e94}', '\u{516d}', '\u{4e03}', '\u{516b}', '\u{4e5d}',
+        '\u{5341}', '\u{6708}', '\u{706b}', '\u{6c34}', '\u{6728}', '\u{91d1}', '\u{571f}',
+        '\u{65e5}', '\u{682a}', '\u{6709}', '\u{793e}', '\u{540d}', '\u{7279}', '\u{8ca1}',
+        '\u{795d}', '\u{52b4}', '\u{79d8}', '\u{7537}', '\u{5973}', '\u{9069}', '\u{512a}',
+        '\u{5370}', '\u{6ce8}', '\u{9805}', '\u{4f11}', '\u{5199}', '\u{6b63}', '\u{4e0a}',
+        '\u{4e2d}', '\u{4e0b}', '\u{5de6}', '\u{53f3}', '\u{533b}', '\u{5b97}', '\u{5b66}',
+        '\u{76e3}', '\u{4f01}', '\u{8cc7}', '\u{5354}', '\u{591c}', '3', '6', '3', '7', '3', '8',
+        '3', '9', '4', '0', '4', '1', '4', '2', '4', '3', '4', '4', '4', '5', '4', '6', '4', '7',
+        '4', '8', '4', '9', '5', '0', '1', '\u{6708}', '2', '\u{6708}', '3', '\u{6708}', '4',
+        '\u{6708}', '5', '\u{6708}', '6', '\u{6708}', '7', '\u{6708}', '8', '\u{6708}', '9',
+        '\u{6708}', '1', '0', '\u{6708}', '1', '1', '\u{6708}', '1', '2', '\u{6708}', 'H', 'g', 'e',
+        'r', 'g', 'e', 'V', 'L', 'T', 'D', '\u{30a2}', '\u{30a4}', '\u{30a6}', '\u{30a8}',
+        '\u{30aa}', '\u{30ab}', '\u{30ad}', '\u{30af}', '\u{30b1}', '\u{30b3}', '\u{30b5}',
+        '\u{30b7}', '\u{30b9}', '\u{30bb}', '\u{30bd}', '\u{30bf}', '\u{30c1}', '\u{30c4}',
+        '\u{30c6}', '\u{30c8}', '\u{30ca}', '\u{30cb}', '\u{30cc}', '\u{30cd}', '\u{30ce}',
+        '\u{30cf}', '\u{30d2}', '\u{30d5}', '\u{30d8}', '\u{30db}', '\u{30de}', '\u{30df}',
+        '\u{30e0}', '\u{30e1}', '\u{30e2}', '\u{30e4}', '\u{30e6}', '\u{30e8}', '\u{30e9}',
+        '\u{30ea}', '\u{30eb}', '\u{30ec}', '\u{30ed}', '\u{30ef}', '\u{30f0}', '\u{30f1}',
+        '\u{30f2}', '\u{4ee4}', '\u{548c}', '\u{30a2}', '\u{30cf}', '\u{309a}', '\u{30fc}',
+        '\u{30c8}', '\u{30a2}', '\u{30eb}', '\u{30d5}', '\u{30a1}', '\u{30a2}', '\u{30f3}',
+        '\u{30d8}', '\u{309a}', '\u{30a2}', '\u{30a2}', '\u{30fc}', '\u{30eb}', '\u{30a4}',
+        '\u{30cb}', '\u{30f3}', '\u{30af}', '\u{3099}', '\u{30a4}', '\u{30f3}', '\u{30c1}',
+        '\u{30a6}', '\u{30a9}', '\u{30f3}', '\u{30a8}', '\u{30b9}', '\u{30af}', '\u{30fc}',
+        '\u{30c8}', '\u{3099}', '\u{30a8}', '\u{30fc}', '\u{30ab}', '\u{30fc}', '\u{30aa}',
+        '\u{30f3}', '\u{30b9}', '\u{30aa}', '\u{30fc}', '\u{30e0}', '\u{30ab}', '\u{30a4}',
+        '\u{30ea}', '\u{30ab}', '\u{30e9}', '\u{30c3}', '\u{30c8}', '\u{30ab}', '\u{30ed}',
+        '\u{30ea}', '\u{30fc}', '\u{30ab}', '\u{3099}', '\u{30ed}', '\u{30f3}', '\u{30ab}',
+        '\u{3099}', '\u{30f3}', '\u{30de}', '\u{30ad}', '\u{3099}', '\u{30ab}', '\u{3099}',
+        '\u{30ad}', '\u{3099}', '\u{30cb}', '\u{30fc}', '\u{30ad}', '\u{30e5}', '\u{30ea}',
+        '\u{30fc}', '\u{30ad}', '\u{3099}', '\u{30eb}', '\u{30bf}', '\u{3099}', '\u{30fc}',
+        '\u{30ad}', '\u{30ed}', '\u{30ad}', '\u{30ed}', '\u{30af}', '\u{3099}', '\u{30e9}',
+        '\u{30e0}', '\u{30ad}', '\u{30ed}', '\u{30e1}', '\u{30fc}', '\u{30c8}', '\u{30eb}',
+        '\u{30ad}', '\u{30ed}', '\u{30ef}', '\u{30c3}', '\u{30c8}', '\u{30af}', '\u{3099}',
+        '\u{30e9}', '\u{30e0}', '\u{30af}', '\u{3099}', '\u{30e9}', '\u{30e0}', '\u{30c8}',
+        '\u{30f3}', '\u{30af}', '\u{30eb}', '\u{30bb}', '\u{3099}', '\u{30a4}', '\u{30ed}',
+        '\u{30af}', '\u{30ed}', '\u{30fc}', '\u{30cd}', '\u{30b1}', '\u{30fc}', '\u{30b9}',
+        '\u{30b3}', '\u{30eb}', '\u{30ca}', '\u{30b3}', '\u{30fc}', '\u{30db}', '\u{309a}',
+        '\u{30b5}', '\u{30a4}', '\u{30af}', '\u{30eb}', '\u{30b5}', '\u{30f3}', '\u{30c1}',
+        '\u{30fc}', '\u{30e0}', '\u{30b7}', '\u{30ea}', '\u{30f3}', '\u{30af}', '\u{3099}',
+        '\u{30bb}', '\u{30f3}', '\u{30c1}', '\u{30bb}', '\u{30f3}', '\u{30c8}', '\u{30bf}',
+        '\u{3099}', '\u{30fc}', '\u{30b9}', '\u{30c6}', '\u{3099}', '\u{30b7}', '\u{30c8}',
+        '\u{3099}', '\u{30eb}', '\u{30c8}', '\u{30f3}', '\u{30ca}', '\u{30ce}', '\u{30ce}',
+        '\u{30c3}', '\u{30c8}', '\u{30cf}', '\u{30a4}', '\u{30c4}', '\u{30cf}', '\u{309a}',
+        '\u{30fc}', '\u{30bb}', '\u{30f3}', '\u{30c8}', '\u{30cf}', '\u{309a}', '\u{30fc}',
+        '\u{30c4}', '\u{30cf}', '\u{3099}', '\u{30fc}', '\u{30ec}', '\u{30eb}', '\u{30d2}',
+        '\u{309a}', '\u{30a2}', '\u{30b9}', '\u{30c8}', '\u{30eb}', '\u{30d2}', '\u{309a}',
+        '\u{30af}', '\u{30eb}', '\u{30d2}', '\u{309a}', '\u{30b3}', '\u{30d2}', '\u{3099}',
+        '\u{30eb}', '\u{30d5}', '\u{30a1}', '\u{30e9}', '\u{30c3}', '\u{30c8}', '\u{3099}',
+        '\u{30d5}', '\u{30a3}', '\u{30fc}', '\u{30c8}', '\u{30d5}', '\u{3099}', '\u{30c3}',
+        '\u{30b7}', '\u{30a7}', '\u{30eb}', '\u{30d5}', '\u{30e9}', '\u{30f3}', '\u{30d8}',
+        '\u{30af}', '\u{30bf}', '\u{30fc}', '\u{30eb}', '\u{30d8}', '\u{309a}', '\u{30bd}',
+        '\u{30d8}', '\u{309a}', '\u{30cb}', '\u{30d2}', '\u{30d8}', '\u{30eb}', '\u{30c4}',
+        '\u{30d8}', '\u{309a}', '\u{30f3}', '\u{30b9}', '\u{30d8}', '\u{309a}', '\u{30fc}',
+        '\u{30b7}', '\u{3099}', '\u{30d8}', '\u{3099}', '\u{30fc}', '\u{30bf}', '\u{30db}',
+        '\u{309a}', '\u{30a4}', '\u{30f3}', '\u{30c8}', '\u{30db}', '\u{3099}', '\u{30eb}',
+        '\u{30c8}', '\u{30db}', '\u{30f3}', '\u{30db}', '\u{309a}', '\u{30f3}', '\u{30c8}',
+        '\u{3099}', '\u{30db}', '\u{30fc}', '\u{30eb}', '\u{30db}', '\u{30fc}', '\u{30f3}',
+        '\u{30de}', '\u{30a4}', '\u{30af}', '\u{30ed}', '\u{30de}', '\u{30a4}', '\u{30eb}',
+        '\u{30de}', '\u{30c3}', '\u{30cf}', '\u{30de}', '\u{30eb}', '\u{30af}', '\u{30de}',
+        '\u{30f3}', '\u{30b7}', '\u{30e7}', '\u{30f3}', '\u{30df}', '\u{30af}', '\u{30ed}',
+        '\u{30f3}', '\u{30df}', '\u{30ea}', '\u{30df}', '\u{30ea}', '\u{30cf}', '\u{3099}',
+        '\u{30fc}', '\u{30eb}', '\u{30e1}', '\u{30ab}', '\u{3099}', '\u{30e1}', '\u{30ab}',
+        '\u{3099}', '\u{30c8}', '\u{30f3}', '\u{30e1}', '\u{30fc}', '\u{30c8}', '\u{30eb}',
+        '\u{30e4}', '\u{30fc}', '\u{30c8}', '\u{3099}', '\u{30e4}', '\u{30fc}', '\u{30eb}',
+        '\u{30e6}', '\u{30a2}', '\u{30f3}', '\u{30ea}', '\u{30c3}', '\u{30c8}', '\u{30eb}',
+        '\u{30ea}', '\u{30e9}', '\u{30eb}', '\u{30d2}', '\u{309a}', '\u{30fc}', '\u{30eb}',
+        '\u{30fc}', '\u{30d5}', '\u{3099}', '\u{30eb}', '\u{30ec}', '\u{30e0}', '\u{30ec}',
+        '\u{30f3}', '\u{30c8}', '\u{30b1}', '\u{3099}', '\u{30f3}', '\u{30ef}', '\u{30c3}',
+        '\u{30c8}', '0', '\u{70b9}', '1', '\u{70b9}', '2', '\u{70b9}', '3', '\u{70b9}', '4',
+        '\u{70b9}', '5', '\u{70b9}', '6', '\u{70b9}', '7', '\u{70b9}', '8', '\u{70b9}', '9',
+        '\u{70b9}', '1', '0', '\u{70b9}', '1', '1', '\u{70b9}', '1', '2', '\u{70b9}', '1', '3',
+        '\u{70b9}', '1', '4', '\u{70b9}', '1', '5', '\u{70b9}', '1', '6', '\u{70b9}', '1', '7',
+        '\u{70b9}', '1', '8', '\u{70b9}', '1', '9', '\u{70b9}', '2', '0', '\u{70b9}', '2', '1',
+        '\u{70b9}', '2', '2', '\u{70b9}', '2', '3', '\u{70b9}', '2', '4', '\u{70b9}', 'h', 'P', 'a',
+        'd', 'a', 'A', 'U', 'b', 'a', 'r', 'o', 'V', 'p', 'c', 'd', 'm', 'd', 'm', '2', 'd', 'm',
+        '3', 'I', 'U', '\u{5e73}', '\u{6210}', '\u{662d}', '\u{548c}', '\u{5927}', '\u{6b63}',
+        '\u{660e}', '\u{6cbb}', '\u{682a}', '\u{5f0f}', '\u{4f1a}', '\u{793e}', 'p', 'A', 'n', 'A',
+        '\u{3bc}', 'A', 'm', 'A', 'k', 'A', 'K', 'B', 'M', 'B', 'G', 'B', 'c', 'a', 'l', 'k', 'c',
+        'a', 'l', 'p', 'F', 'n', 'F', '\u{3bc}', 'F', '\u{3bc}', 'g', 'm', 'g', 'k', 'g', 'H', 'z',
+        'k', 'H', 'z', 'M', 'H', 'z', 'G', 'H', 'z', 'T', 'H', 'z', '\u{3bc}', 'l', 'm', 'l', 'd',
+        'l', 'k', 'l', 'f', 'm', 'n', 'm', '\u{3bc}', 'm', 'm', 'm', 'c', 'm', 'k', 'm', 'm', 'm',
+        '2', 'c', 'm', '2', 'm', '2', 'k', 'm', '2', 'm', 'm', '3', 'c', 'm', '3', 'm', '3', 'k',
+        'm', '3', 'm', '\u{2215}', 's', 'm', '\u{2215}', 's', '2', 'P', 'a', 'k', 'P', 'a', 'M',
+        'P', 'a', 'G', 'P', 'a', 'r', 'a', 'd', 'r', 'a', 'd', '\u{2215}', 's', 'r', 'a', 'd',
+        '\u{2215}', 's', '2', 'p', 's', 'n', 's', '\u{3bc}', 's', 'm', 's', 'p', 'V', 'n', 'V',
+        '\u{3bc}', 'V', 'm', 'V', 'k', 'V', 'M', 'V', 'p', 'W', 'n', 'W', '\u{3bc}', 'W', 'm', 'W',
+        'k', 'W', 'M', 'W', 'k', '\u{3a9}', 'M', '\u{3a9}', 'a', '.', 'm', '.', 'B', 'q', 'c', 'c',
+        'c', 'd', 'C', '\u{2215}', 'k', 'g', 'C', 'o', '.', 'd', 'B', 'G', 'y', 'h', 'a', 'H', 'P',
+        'i', 'n', 'K', 'K', 'K', 'M', 'k', 't', 'l', 'm', 'l', 'n', 'l', 'o', 'g', 'l', 'x', 'm',
+        'b', 'm', 'i', 'l', 'm', 'o', 'l', 'P', 'H', 'p', '.', 'm', '.', 'P', 'P', 'M', 'P', 'R',
+        's', 'r', 'S', 'v', 'W', 'b', 'V', '\u{2215}', 'm', 'A', '\u{2215}', 'm', '1', '\u{65e5}',
+        '2', '\u{65e5}', '3', '\u{65e5}', '4', '\u{65e5}', '5', '\u{65e5}', '6', '\u{65e5}', '7',
+        '\u{65e5}', '8', '\u{65e5}', '9', '\u{65e5}', '1', '0', '\u{65e5}', '1', '1', '\u{65e5}',
+        '1', '2', '\u{65e5}', '1', '3', '\u{65e5}', '1', '4', '\u{65e5}', '1', '5', '\u{65e5}', '1',
+        '6', '\u{65e5}', '1', '7', '\u{65e5}', '1', '8', '\u{65e5}', '1', '9', '\u{65e5}', '2', '0',
+        '\u{65e5}', '2', '1', '\u{65e5}', '2', '2', '\u{65e5}', '2', '3', '\u{65e5}', '2', '4',
+        '\u{65e5}', '2', '5', '\u{65e5}', '2', '6', '\u{65e5}', '2', '7', '\u{65e5}', '2', '8',
+        '\u{65e5}', '2', '9', '\u{65e5}', '3', '0', '\u{65e5}', '3', '1', '\u{65e5}', 'g', 'a', 'l',
+        '\u{44a}', '\u{44c}', '\u{a76f}', 'C', 'F', 'Q', '\u{126}', '\u{153}', '\u{a727}',
+        '\u{ab37}', '\u{26b}', '\u{ab52}', '\u{28d}', 'f', 'f', 'f', 'i', 'f', 'l', 'f', 'f', 'i',
+        'f', 'f', 'l', 's', 't', 's', 't', '\u{574}', '\u{576}', '\u{574}', '\u{565}', '\u{574}',
+        '\u{56b}', '\u{57e}', '\u{576}', '\u{574}', '\u{56d}', '\u{5e2}', '\u{5d0}', '\u{5d3}',
+        '\u{5d4}', '\u{5db}', '\u{5dc}', '\u{5dd}', '\u{5e8}', '\u{5ea}', '+', '\u{5d0}', '\u{5dc}',
+        '\u{671}', '\u{671}', '\u{67b}', '\u{67b}', '\u{67b}', '\u{67b}', '\u{67e}', '\u{67e}',
+        '\u{67e}', '\u{67e}', '\u{680}', '\u{680}', '\u{680}', '\u{680}', '\u{67a}', '\u{67a}',
+        '\u{67a}', '\u{67a}', '\u{67f}', '\u{67f}', '\u{67f}', '\u{67f}', '\u{679}', '\u{679}',
+        '\u{679}', '\u{679}', '\u{6a4}', '\u{6a4}', '\u{6a4}', '\u{6a4}', '\u{6a6}', '\u{6a6}',
+        '\u{6a6}', '\u{6a6}', '\u{684}', '\u{684}', '\u{684}', '\u{684}', '\u{683}', '\u{683}',
+        '\u{683}', '\u{683}', '\u{686}', '\u{686}', '\u{686}', '\u{686}', '\u{687}', '\u{687}',
+        '\u{687}', '\u{687}', '\u{68d}', '\u{68d}', '\u{68c}', '\u{68c}', '\u{68e}', '\u{68e}',
+        '\u{688}', '\u{688}', '\u{698}', '\u{698}', '\u{691}', '\u{691}', '\u{6a9}', '\u{6a9}',
+        '\u{6a9}', '\u{6a9}', '\u{6af}', '\u{6af}', '\u{6af}', '\u{6af}', '\u{6b3}', '\u{6b3}',
+        '\u{6b3}', '\u{6b3}', '\u{6b1}', '\u{6b1}', '\u{6b1}', '\u{6b1}', '\u{6ba}', '\u{6ba}',
+        '\u{6bb}', '\u{6bb}', '\u{6bb}', '\u{6bb}', '\u{6d5}', '\u{654}', '\u{6d5}', '\u{654}',
+        '\u{6c1}', '\u{6c1}', '\u{6c1}', '\u{6c1}', '\u{6be}', '\u{6be}', '\u{6be}', '\u{6be}',
+        '\u{6d2}', '\u{6d2}', '\u{6d2}', '\u{654}', '\u{6d2}', '\u{654}', '\u{6ad}', '\u{6ad}',
+        '\u{6ad}', '\u{6ad}', '\u{6c7}', '\u{6c7}', '\u{6c6}', '\u{6c6}', '\u{6c8}', '\u{6c8}',
+        '\u{6c7}', '\u{674}', '\u{6cb}', '\u{6cb}', '\u{6c5}', '\u{6c5}', '\u{6c9}', '\u{6c9}',
+        '\u{6d0}', '\u{6d0}', '\u{6d0}', '\u{6d0}', '\u{649}', '\u{649}', '\u{64a}', '\u{654}',
+        '\u{627}', '\u{64a}', '\u{654}', '\u{627}', '\u{64a}', '\u{654}', '\u{6d5}', '\u{64a}',
+        '\u{654}', '\u{6d5}', '\u{64a}', '\u{654}', '\u{648}', '\u{64a}', '\u{654}', '\u{648}',
+        '\u{64a}', '\u{654}', '\u{6c7}', '\u{64a}', '\u{654}', '\u{6c7}', '\u{64a}', '\u{654}',
+        '\u{6c6}', '\u{64a}', '\u{654}', '\u{6c6}', '\u{64a}', '\u{654}', '\u{6c8}', '\u{64a}',
+        '\u{654}', '\u{6c8}', '\u{64a}', '\u{654}', '\u{6d0}', '\u{64a}', '\u{654}', '\u{6d0}',
+        '\u{64a}', '\u{654}', '\u{6d0}', '\u{64a}', '\u{654}', '\u{649}', '\u{64a}', '\u{654}',
+        '\u{649}', '\u{64a}', '\u{654}', '\u{649}', '\u{6cc}', '\u{6cc}', '\u{6cc}', '\u{6cc}',
+        '\u{64a}', '\u{654}', '\u{62c}', '\u{64a}', '\u{654}', '\u{62d}', '\u{64a}', '\u{654}',
+        '\u{645}', '\u{64a}', '\u{654}', '\u{649}', '\u{64a}', '\u{654}', '\u{64a}', '\u{628}',
+        '\u{62c}', '\u{628}', '\u{62d}', '\u{628}', '\u{62e}', '\u{628}', '\u{645}', '\u{628}',
+        '\u{649}', '\u{628}', '\u{64a}', '\u{62a}', '\u{62c}', '\u{62a}', '\u{62d}', '\u{62a}',
+        '\u{62e}', '\u{62a}', '\u{645}', '\u{62a}', '\u{649}', '\u{62a}', '\u{64a}', '\u{62b}',
+        '\u{62c}', '\u{62b}', '\u{645}', '\u{62b}', '\u{649}', '\u{62b}', '\u{64a}', '\u{62c}',
+        '\u{62d}', '\u{62c}', '\u{645}', '\u{62d}', '\u{62c}', '\u{62d}', '\u{645}', '\u{62e}',
+        '\u{62c}', '\u{62e}', '\u{62d}', '\u{62e}', '\u{645}', '\u{633}', '\u{62c}', '\u{633}',
+        '\u{62d}', '\u{633}', '\u{62e}', '\u{633}', '\u{645}', '\u{635}', '\u{62d}', '\u{635}',
+        '\u{645}', '\u{636}', '\u{62c}', '\u{636}', '\u{62d}', '\u{636}', '\u{62e}', '\u{636}',
+        '\u{645}', '\u{637}', '\u{62d}', '\u{637}', '\u{645}', '\u{638}', '\u{645}', '\u{639}',
+        '\u{62c}', '\u{639}', '\u{645}', '\u{63a}', '\u{62c}', '\u{63a}', '\u{645}', '\u{641}',
+        '\u{62c}', '\u{641}', '\u{62d}', '\u{641}', '\u{62e}', '\u{641}', '\u{645}', '\u{641}',
+        '\u{649}', '\u{641}', '\u{64a}', '\u{642}', '\u{62d}', '\u{642}', '\u{645}', '\u{642}',
+        '\u{649}', '\u{642}', '\u{64a}', '\u{643}', '\u{627}', '\u{643}', '\u{62c}', '\u{643}',
+        '\u{62d}', '\u{643}', '\u{62e}', '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{643}',
+        '\u{649}', '\u{643}', '\u{64a}', '\u{644}', '\u{62c}', '\u{644}', '\u{62d}', '\u{644}',
+        '\u{62e}', '\u{644}', '\u{645}', '\u{644}', '\u{649}', '\u{644}', '\u{64a}', '\u{645}',
+        '\u{62c}', '\u{645}', '\u{62d}', '\u{645}', '\u{62e}', '\u{645}', '\u{645}', '\u{645}',
+        '\u{649}', '\u{645}', '\u{64a}', '\u{646}', '\u{62c}', '\u{646}', '\u{62d}', '\u{646}',
+        '\u{62e}', '\u{646}', '\u{645}', '\u{646}', '\u{649}', '\u{646}', '\u{64a}', '\u{647}',
+        '\u{62c}', '\u{647}', '\u{645}', '\u{647}', '\u{649}', '\u{647}', '\u{64a}', '\u{64a}',
+        '\u{62c}', '\u{64a}', '\u{62d}', '\u{64a}', '\u{62e}', '\u{64a}', '\u{645}', '\u{64a}',
+        '\u{649}', '\u{64a}', '\u{64a}', '\u{630}', '\u{670}', '\u{631}', '\u{670}', '\u{649}',
+        '\u{670}', ' ', '\u{64c}', '\u{651}', ' ', '\u{64d}', '\u{651}', ' ', '\u{64e}', '\u{651}',
+        ' ', '\u{64f}', '\u{651}', ' ', '\u{650}', '\u{651}', ' ', '\u{651}', '\u{670}', '\u{64a}',
+        '\u{654}', '\u{631}', '\u{64a}', '\u{654}', '\u{632}', '\u{64a}', '\u{654}', '\u{645}',
+        '\u{64a}', '\u{654}', '\u{646}', '\u{64a}', '\u{654}', '\u{649}', '\u{64a}', '\u{654}',
+        '\u{64a}', '\u{628}', '\u{631}', '\u{628}', '\u{632}', '\u{628}', '\u{645}', '\u{628}',
+        '\u{646}', '\u{628}', '\u{649}', '\u{628}', '\u{64a}', '\u{62a}', '\u{631}', '\u{62a}',
+        '\u{632}', '\u{62a}', '\u{645}', '\u{62a}', '\u{646}', '\u{62a}', '\u{649}', '\u{62a}',
+        '\u{64a}', '\u{62b}', '\u{631}', '\u{62b}', '\u{632}', '\u{62b}', '\u{645}', '\u{62b}',
+        '\u{646}', '\u{62b}', '\u{649}', '\u{62b}', '\u{64a}', '\u{641}', '\u{649}', '\u{641}',
+        '\u{64a}', '\u{642}', '\u{649}', '\u{642}', '\u{64a}', '\u{643}', '\u{627}', '\u{643}',
+        '\u{644}', '\u{643}', '\u{645}', '\u{643}', '\u{649}', '\u{643}', '\u{64a}', '\u{644}',
+        '\u{645}', '\u{644}', '\u{649}', '\u{644}', '\u{64a}', '\u{645}', '\u{627}', '\u{645}',
+        '\u{645}', '\u{646}', '\u{631}', '\u{646}', '\u{632}', '\u{646}', '\u{645}', '\u{646}',
+        '\u{646}', '\u{646}', '\u{649}', '\u{646}', '\u{64a}', '\u{649}', '\u{670}', '\u{64a}',
+        '\u{631}', '\u{64a}', '\u{632}', '\u{64a}', '\u{645}', '\u{64a}', '\u{646}', '\u{64a}',
+        '\u{649}', '\u{64a}', '\u{64a}', '\u{64a}', '\u{654}', '\u{62c}', '\u{64a}', '\u{654}',
+        '\u{62d}', '\u{64a}', '\u{654}', '\u{62e}', '\u{64a}', '\u{654}', '\u{645}', '\u{64a}',
+        '\u{654}', '\u{647}', '\u{628}', '\u{62c}', '\u{628}', '\u{62d}', '\u{628}', '\u{62e}',
+        '\u{628}', '\u{645}', '\u{628}', '\u{647}', '\u{62a}', '\u{62c}', '\u{62a}', '\u{62d}',
+        '\u{62a}', '\u{62e}', '\u{62a}', '\u{645}', '\u{62a}', '\u{647}', '\u{62b}', '\u{645}',
+        '\u{62c}', '\u{62d}', '\u{62c}', '\u{645}', '\u{62d}', '\u{62c}', '\u{62d}', '\u{645}',
+        '\u{62e}', '\u{62c}', '\u{62e}', '\u{645}', '\u{633}', '\u{62c}', '\u{633}', '\u{62d}',
+        '\u{633}', '\u{62e}', '\u{633}', '\u{645}', '\u{635}', '\u{62d}', '\u{635}', '\u{62e}',
+        '\u{635}', '\u{645}', '\u{636}', '\u{62c}', '\u{636}', '\u{62d}', '\u{636}', '\u{62e}',
+        '\u{636}', '\u{645}', '\u{637}', '\u{62d}', '\u{638}', '\u{645}', '\u{639}', '\u{62c}',
+        '\u{639}', '\u{645}', '\u{63a}', '\u{62c}', '\u{63a}', '\u{645}', '\u{641}', '\u{62c}',
+        '\u{641}', '\u{62d}', '\u{641}', '\u{62e}', '\u{641}', '\u{645}', '\u{642}', '\u{62d}',
+        '\u{642}', '\u{645}', '\u{643}', '\u{62c}', '\u{643}', '\u{62d}', '\u{643}', '\u{62e}',
+        '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{644}', '\u{62c}', '\u{644}', '\u{62d}',
+        '\u{644}', '\u{62e}', '\u{644}', '\u{645}', '\u{644}', '\u{647}', '\u{645}', '\u{62c}',
+        '\u{645}', '\u{62d}', '\u{645}', '\u{62e}', '\u{645}', '\u{645}', '\u{646}', '\u{62c}',
+        '\u{646}', '\u{62d}', '\u{646}', '\u{62e}', '\u{646}', '\u{645}', '\u{646}', '\u{647}',
+        '\u{647}', '\u{62c}', '\u{647}', '\u{645}', '\u{647}', '\u{670}', '\u{64a}', '\u{62c}',
+        '\u{64a}', '\u{62d}', '\u{64a}', '\u{62e}', '\u{64a}', '\u{645}', '\u{64a}', '\u{647}',
+        '\u{64a}', '\u{654}', '\u{645}', '\u{64a}', '\u{654}', '\u{647}', '\u{628}', '\u{645}',
+        '\u{628}', '\u{647}', '\u{62a}', '\u{645}', '\u{62a}', '\u{647}', '\u{62b}', '\u{645}',
+        '\u{62b}', '\u{647}', '\u{633}', '\u{645}', '\u{633}', '\u{647}', '\u{634}', '\u{645}',
+        '\u{634}', '\u{647}', '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{644}', '\u{645}',
+        '\u{646}', '\u{645}', '\u{646}', '\u{647}', '\u{64a}', '\u{645}', '\u{64a}', '\u{647}',
+        '\u{640}', '\u{64e}', '\u{651}', '\u{640}', '\u{64f}', '\u{651}', '\u{640}', '\u{650}',
+        '\u{651}', '\u{637}', '\u{649}', '\u{637}', '\u{64a}', '\u{639}', '\u{649}', '\u{639}',
+        '\u{64a}', '\u{63a}', '\u{649}', '\u{63a}', '\u{64a}', '\u{633}', '\u{649}', '\u{633}',
+        '\u{64a}', '\u{634}', '\u{649}', '\u{634}', '\u{64a}', '\u{62d}', '\u{649}', '\u{62d}',
+        '\u{64a}', '\u{62c}', '\u{649}', '\u{62c}', '\u{64a}', '\u{62e}', '\u{649}', '\u{62e}',
+        '\u{64a}', '\u{635}', '\u{649}', '\u{635}', '\u{64a}', '\u{636}', '\u{649}', '\u{636}',
+        '\u{64a}', '\u{634}', '\u{62c}', '\u{634}', '\u{62d}', '\u{634}', '\u{62e}', '\u{634}',
+        '\u{645}', '\u{634}', '\u{631}', '\u{633}', '\u{631}', '\u{635}', '\u{631}', '\u{636}',
+        '\u{631}', '\u{637}', '\u{649}', '\u{637}', '\u{64a}', '\u{639}', '\u{649}', '\u{639}',
+        '\u{64a}', '\u{63a}', '\u{649}', '\u{63a}', '\u{64a}', '\u{633}', '\u{649}', '\u{633}',
+        '\u{64a}', '\u{634}', '\u{649}', '\u{634}', '\u{64a}', '\u{62d}', '\u{649}', '\u{62d}',
+        '\u{64a}', '\u{62c}', '\u{649}', '\u{62c}', '\u{64a}', '\u{62e}', '\u{649}', '\u{62e}',
+        '\u{64a}', '\u{635}', '\u{649}', '\u{635}', '\u{64a}', '\u{636}', '\u{649}', '\u{636}',
+        '\u{64a}', '\u{634}', '\u{62c}', '\u{634}', '\u{62d}', '\u{634}', '\u{62e}', '\u{634}',
+        '\u{645}', '\u{634}', '\u{631}', '\u{633}', '\u{631}', '\u{635}', '\u{631}', '\u{636}',
+        '\u{631}', '\u{634}', '\u{62c}', '\u{634}', '\u{62d}', '\u{634}', '\u{62e}', '\u{634}',
+        '\u{645}', '\u{633}', '\u{647}', '\u{634}', '\u{647}', '\u{637}', '\u{645}', '\u{633}',
+        '\u{62c}', '\u{633}', '\u{62d}', '\u{633}', '\u{62e}', '\u{634}', '\u{62c}', '\u{634}',
+        '\u{62d}', '\u{634}', '\u{62e}', '\u{637}', '\u{645}', '\u{638}', '\u{645}', '\u{627}',
+        '\u{64b}', '\u{627}', '\u{64b}', '\u{62a}', '\u{62c}', '\u{645}', '\u{62a}', '\u{62d}',
+        '\u{62c}', '\u{62a}', '\u{62d}', '\u{62c}', '\u{62a}', '\u{62d}', '\u{645}', '\u{62a}',
+        '\u{62e}', '\u{645}', '\u{62a}', '\u{645}', '\u{62c}', '\u{62a}', '\u{645}', '\u{62d}',
+        '\u{62a}', '\u{645}', '\u{62e}', '\u{62c}', '\u{645}', '\u{62d}', '\u{62c}', '\u{645}',
+        '\u{62d}', '\u{62d}', '\u{645}', '\u{64a}', '\u{62d}', '\u{645}', '\u{649}', '\u{633}',
+        '\u{62d}', '\u{62c}', '\u{633}', '\u{62c}', '\u{62d}', '\u{633}', '\u{62c}', '\u{649}',
+        '\u{633}', '\u{645}', '\u{62d}', '\u{633}', '\u{645}', '\u{62d}', '\u{633}', '\u{645}',
+        '\u{62c}', '\u{633}', '\u{645}', '\u{645}', '\u{633}', '\u{645}', '\u{645}', '\u{635}',
+        '\u{62d}', '\u{62d}', '\u{635}', '\u{62d}', '\u{62d}', '\u{635}', '\u{645}', '\u{645}',
+        '\u{634}', '\u{62d}', '\u{645}', '\u{634}', '\u{62d}', '\u{645}', '\u{634}', '\u{62c}',
+        '\u{64a}', '\u{634}', '\u{645}', '\u{62e}', '\u{634}', '\u{645}', '\u{62e}', '\u{634}',
+        '\u{645}', '\u{645}', '\u{634}', '\u{645}', '\u{645}', '\u{636}', '\u{62d}', '\u{649}',
+        '\u{636}', '\u{62e}', '\u{645}', '\u{636}', '\u{62e}', '\u{645}', '\u{637}', '\u{645}',
+        '\u{62d}', '\u{637}', '\u{645}', '\u{62d}', '\u{637}', '\u{645}', '\u{645}', '\u{637}',
+        '\u{645}', '\u{64a}', '\u{639}', '\u{62c}', '\u{645}', '\u{639}', '\u{645}', '\u{645}',
+        '\u{639}', '\u{645}', '\u{645}', '\u{639}', '\u{645}', '\u{649}', '\u{63a}', '\u{645}',
+        '\u{645}', '\u{63a}', '\u{645}', '\u{64a}', '\u{63a}', '\u{645}', '\u{649}', '\u{641}',
+        '\u{62e}', '\u{645}', '\u{641}', '\u{62e}', '\u{645}', '\u{642}', '\u{645}', '\u{62d}',
+        '\u{642}', '\u{645}', '\u{645}', '\u{644}', '\u{62d}', '\u{645}', '\u{644}', '\u{62d}',
+        '\u{64a}', '\u{644}', '\u{62d}', '\u{649}', '\u{644}', '\u{62c}', '\u{62c}', '\u{644}',
+        '\u{62c}', '\u{62c}', '\u{644}', '\u{62e}', '\u{645}', '\u{644}', '\u{62e}', '\u{645}',
+        '\u{644}', '\u{645}', '\u{62d}', '\u{644}', '\u{645}', '\u{62d}', '\u{645}', '\u{62d}',
+        '\u{62c}', '\u{645}', '\u{62d}', '\u{645}', '\u{645}', '\u{62d}', '\u{64a}', '\u{645}',
+        '\u{62c}', '\u{62d}', '\u{645}', '\u{62c}', '\u{645}', '\u{645}', '\u{62e}', '\u{62c}',
+        '\u{645}', '\u{62e}', '\u{645}', '\u{645}', '\u{62c}', '\u{62e}', '\u{647}', '\u{645}',
+        '\u{62c}', '\u{647}', '\u{645}', '\u{645}', '\u{646}', '\u{62d}', '\u{645}', '\u{646}',
+        '\u{62d}', '\u{649}', '\u{646}', '\u{62c}', '\u{645}', '\u{646}', '\u{62c}', '\u{645}',
+        '\u{646}', '\u{62c}', '\u{649}', '\u{646}', '\u{645}', '\u{64a}', '\u{646}', '\u{645}',
+        '\u{649}', '\u{64a}', '\u{645}', '\u{645}', '\u{64a}', '\u{645}', '\u{645}', '\u{628}',
+        '\u{62e}', '\u{64a}', '\u{62a}', '\u{62c}', '\u{64a}', '\u{62a}', '\u{62c}', '\u{649}',
+        '\u{62a}', '\u{62e}', '\u{64a}', '\u{62a}', '\u{62e}', '\u{649}', '\u{62a}', '\u{645}',
+        '\u{64a}', '\u{62a}', '\u{645}', '\u{649}', '\u{62c}', '\u{645}', '\u{64a}', '\u{62c}',
+        '\u{62d}', '\u{649}', '\u{62c}', '\u{645}', '\u{649}', '\u{633}', '\u{62e}', '\u{649}',
+        '\u{635}', '\u{62d}', '\u{64a}', '\u{634}', '\u{62d}', '\u{64a}', '\u{636}', '\u{62d}',
+        '\u{64a}', '\u{644}', '\u{62c}', '\u{64a}', '\u{644}', '\u{645}', '\u{64a}', '\u{64a}',
+        '\u{62d}', '\u{64a}', '\u{64a}', '\u{62c}', '\u{64a}', '\u{64a}', '\u{645}', '\u{64a}',
+        '\u{645}', '\u{645}', '\u{64a}', '\u{642}', '\u{645}', '\u{64a}', '\u{646}', '\u{62d}',
+        '\u{64a}', '\u{642}', '\u{645}', '\u{62d}', '\u{644}', '\u{62d}', '\u{645}', '\u{639}',
+        '\u{645}', '\u{64a}', '\u{643}', '\u{645}', '\u{64a}', '\u{646}', '\u{62c}', '\u{62d}',
+        '\u{645}', '\u{62e}', '\u{64a}', '\u{644}', '\u{62c}', '\u{645}', '\u{643}', '\u{645}',
+        '\u{645}', '\u{644}', '\u{62c}', '\u{645}', '\u{646}', '\u{62c}', '\u{62d}', '\u{62c}',
+        '\u{62d}', '\u{64a}', '\u{62d}', '\u{62c}', '\u{64a}', '\u{645}', '\u{62c}', '\u{64a}',
+        '\u{641}', '\u{645}', '\u{64a}', '\u{628}', '\u{62d}', '\u{64a}', '\u{643}', '\u{645}',
+        '\u{645}', '\u{639}', '\u{62c}', '\u{645}', '\u{635}', '\u{645}', '\u{645}', '\u{633}',
+        '\u{62e}', '\u{64a}', '\u{646}', '\u{62c}', '\u{64a}', '\u{635}', '\u{644}', '\u{6d2}',
+        '\u{642}', '\u{644}', '\u{6d2}', '\u{627}', '\u{644}', '\u{644}', '\u{647}', '\u{627}',
+        '\u{643}', '\u{628}', '\u{631}', '\u{645}', '\u{62d}', '\u{645}', '\u{62f}', '\u{635}',
+        '\u{644}', '\u{639}', '\u{645}', '\u{631}', '\u{633}', '\u{648}', '\u{644}', '\u{639}',
+        '\u{644}', '\u{64a}', '\u{647}', '\u{648}', '\u{633}', '\u{644}', '\u{645}', '\u{635}',
+        '\u{644}', '\u{649}', '\u{635}', '\u{644}', '\u{649}', ' ', '\u{627}', '\u{644}', '\u{644}',
+        '\u{647}', ' ', '\u{639}', '\u{644}', '\u{64a}', '\u{647}', ' ', '\u{648}', '\u{633}',
+        '\u{644}', '\u{645}', '\u{62c}', '\u{644}', ' ', '\u{62c}', '\u{644}', '\u{627}', '\u{644}',
+        '\u{647}', '\u{631}', '\u{6cc}', '\u{627}', '\u{644}', ',', '\u{3001}', '\u{3002}', ':',
+        ';', '!', '?', '\u{3016}', '\u{3017}', '.', '.', '.', '.', '.', '\u{2014}', '\u{2013}', '_',
+        '_', '(', ')', '{', '}', '\u{3014}', '\u{3015}', '\u{3010}', '\u{3011}', '\u{300a}',
+        '\u{300b}', '\u{3008}', '\u{3009}', '\u{300c}', '\u{300d}', '\u{300e}', '\u{300f}', '[',
+        ']', ' ', '\u{305}', ' ', '\u{305}', ' ', '\u{305}', ' ', '\u{305}', '_', '_', '_', ',',
+        '\u{3001}', '.', ';', ':', '?', '!', '\u{2014}', '(', ')', '{', '}', '\u{3014}', '\u{3015}',
+        '#', '&', '*', '+', '-', '<', '>', '=', '\\', '$', '%', '@', ' ', '\u{64b}', '\u{640}',
+        '\u{64b}', ' ', '\u{64c}', ' ', '\u{64d}', ' ', '\u{64e}', '\u{640}', '\u{64e}', ' ',
+        '\u{64f}', '\u{640}', '\u{64f}', ' ', '\u{650}', '\u{640}', '\u{650}', ' ', '\u{651}',
+        '\u{640}', '\u{651}', ' ', '\u{652}', '\u{640}', '\u{652}', '\u{621}', '\u{627}', '\u{653}',
+        '\u{627}', '\u{653}', '\u{627}', '\u{654}', '\u{627}', '\u{654}', '\u{648}', '\u{654}',
+        '\u{648}', '\u{654}', '\u{627}', '\u{655}', '\u{627}', '\u{655}', '\u{64a}', '\u{654}',
+        '\u{64a}', '\u{654}', '\u{64a}', '\u{654}', '\u{64a}', '\u{654}', '\u{627}', '\u{627}',
+        '\u{628}', '\u{628}', '\u{628}', '\u{628}', '\u{629}', '\u{629}', '\u{62a}', '\u{62a}',
+        '\u{62a}', '\u{62a}', '\u{62b}', '\u{62b}', '\u{62b}', '\u{62b}', '\u{62c}', '\u{62c}',
+        '\u{62c}', '\u{62c}', '\u{62d}', '\u{62d}', '\u{62d}', '\u{62d}', '\u{62e}', '\u{62e}',
+        '\u{62e}', '\u{62e}', '\u{62f}', '\u{62f}', '\u{630}', '\u{630}', '\u{631}', '\u{631}',
+        '\u{632}', '\u{632}', '\u{633}', '\u{633}', '\u{633}', '\u{633}', '\u{634}', '\u{634}',
+        '\u{634}', '\u{634}', '\u{635}', '\u{635}', '\u{635}', '\u{635}', '\u{636}', '\u{636}',
+        '\u{636}', '\u{636}', '\u{637}', '\u{637}', '\u{637}', '\u{637}', '\u{638}', '\u{638}',
+        '\u{638}', '\u{638}', '\u{639}', '\u{639}', '\u{639}', '\u{639}', '\u{63a}', '\u{63a}',
+        '\u{63a}', '\u{63a}', '\u{641}', '\u{641}', '\u{641}', '\u{641}', '\u{642}', '\u{642}',
+        '\u{642}', '\u{642}', '\u{643}', '\u{643}', '\u{643}', '\u{643}', '\u{644}', '\u{644}',
+        '\u{644}', '\u{644}', '\u{645}', '\u{645}', '\u{645}', '\u{645}', '\u{646}', '\u{646}',
+        '\u{646}', '\u{646}', '\u{647}', '\u{647}', '\u{647}', '\u{647}', '\u{648}', '\u{648}',
+        '\u{649}', '\u{649}', '\u{64a}', '\u{64a}', '\u{64a}', '\u{64a}', '\u{644}', '\u{627}',
+        '\u{653}', '\u{644}', '\u{627}', '\u{653}', '\u{644}', '\u{627}', '\u{654}', '\u{644}',
+        '\u{627}', '\u{654}', '\u{644}', '\u{627}', '\u{655}', '\u{644}', '\u{627}', '\u{655}',
+        '\u{644}', '\u{627}', '\u{644}', '\u{627}', '!', '\"', '#', '$', '%', '&', '\'', '(', ')',
+        '*', '+', ',', '-', '.', '/', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';',
+        '<', '=', '>', '?', '@', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+        'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '[', '\\', ']', '^', '_',
+        '`', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+        'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '{', '|', '}', '~', '\u{2985}', '\u{2986}',
+        '\u{3002}', '\u{300c}', '\u{300d}', '\u{3001}', '\u{30fb}', '\u{30f2}', '\u{30a1}',
+        '\u{30a3}', '\u{30a5}', '\u{30a7}', '\u{30a9}', '\u{30e3}', '\u{30e5}', '\u{30e7}',
+        '\u{30c3}', '\u{30fc}', '\u{30a2}', '\u{30a4}', '\u{30a6}', '\u{30a8}', '\u{30aa}',
+        '\u{30ab}', '\u{30ad}', '\u{30af}', '\u{30b1}', '\u{30b3}', '\u{30b5}', '\u{30b7}',
+        '\u{30b9}', '\u{30bb}', '\u{30bd}', '\u{30bf}', '\u{30c1}', '\u{30c4}', '\u{30c6}',
+        '\u{30c8}', '\u{30ca}', '\u{30cb}', '\u{30cc}', '\u{30cd}', '\u{30ce}', '\u{30cf}',
+        '\u{30d2}', '\u{30d5}', '\u{30d8}', '\u{30db}', '\u{30de}', '\u{30df}', '\u{30e0}',
+        '\u{30e1}', '\u{30e2}', '\u{30e4}', '\u{30e6}', '\u{30e8}', '\u{30e9}', '\u{30ea}',
+        '\u{30eb}', '\u{30ec}', '\u{30ed}', '\u{30ef}', '\u{30f3}', '\u{3099}', '\u{309a}',
+        '\u{1160}', '\u{1100}', '\u{1101}', '\u{11aa}', '\u{1102}', '\u{11ac}', '\u{11ad}',
+        '\u{1103}', '\u{1104}', '\u{1105}', '\u{11b0}', '\u{11b1}', '\u{11b2}', '\u{11b3}',
+        '\u{11b4}', '\u{11b5}', '\u{111a}', '\u{1106}', '\u{1107}', '\u{1108}', '\u{1121}',
+        '\u{1109}', '\u{110a}', '\u{110b}', '\u{110c}', '\u{110d}', '\u{110e}', '\u{110f}',
+        '\u{1110}', '\u{1111}', '\u{1112}', '\u{1161}', '\u{1162}', '\u{1163}', '\u{1164}',
+        '\u{1165}', '\u{1166}', '\u{1167}', '\u{1168}', '\u{1169}', '\u{116a}', '\u{116b}',
+        '\u{116c}', '\u{116d}', '\u{116e}', '\u{116f}', '\u{1170}', '\u{1171}', '\u{1172}',
+        '\u{1173}', '\u{1174}', '\u{1175}', '\u{a2}', '\u{a3}', '\u{ac}', ' ', '\u{304}', '\u{a6}',
+        '\u{a5}', '\u{20a9}', '\u{2502}', '\u{2190}', '\u{2191}', '\u{2192}', '\u{2193}',
+        '\u{25a0}', '\u{25cb}', '\u{2d0}', '\u{2d1}', '\u{e6}', '\u{299}', '\u{253}', '\u{2a3}',
+        '\u{ab66}', '\u{2a5}', '\u{2a4}', '\u{256}', '\u{257}', '\u{1d91}', '\u{258}', '\u{25e}',
+        '\u{2a9}', '\u{264}', '\u{262}', '\u{260}', '\u{29b}', '\u{127}', '\u{29c}', '\u{267}',
+        '\u{284}', '\u{2aa}', '\u{2ab}', '\u{26c}', '\u{1df04}', '\u{a78e}', '\u{26e}', '\u{1df05}',
+        '\u{28e}', '\u{1df06}', '\u{f8}', '\u{276}', '\u{277}', 'q', '\u{27a}', '\u{1df08}',
+        '\u{27d}', '\u{27e}', '\u{280}', '\u{2a8}', '\u{2a6}', '\u{ab67}', '\u{2a7}', '\u{288}',
+        '\u{2c71}', '\u{28f}', '\u{2a1}', '\u{2a2}', '\u{298}', '\u{1c0}', '\u{1c1}', '\u{1c2}',
+        '\u{1df0a}', '\u{1df1e}', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+        'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '0', '1', '2', '3', '4',
+        '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+        'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e',
+        'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w',
+        'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+        'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
+        'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+        'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R',
+        'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j',
+        'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'C',
+        'D', 'G', 'J', 'K', 'N', 'O', 'P', 'Q', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b',
+        'c', 'd', 'f', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w',
+        'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+        'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
+        'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+        'z', 'A', 'B', 'D', 'E', 'F', 'G', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'S', 'T', 'U',
+        'V', 'W', 'X', 'Y', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n',
+        'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'D', 'E', 'F', 'G',
+        'I', 'J', 'K', 'L', 'M', 'O', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'a', 'b', 'c', 'd', 'e',
+        'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w',
+        'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+        'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
+        'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+        'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+        'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i',
+        'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A',
+        'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+        'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k',
+        'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C',
+        'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U',
+        'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+        'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E',
+        'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W',
+        'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o',
+        'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G',
+        'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y',
+        'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+        'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{131}', '\u{237}', '\u{391}', '\u{392}',
+        '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39a}',
+        '\u{39b}', '\u{39c}', '\u{39d}', '\u{39e}', '\u{39f}', '\u{3a0}', '\u{3a1}', '\u{398}',
+        '\u{3a3}', '\u{3a4}', '\u{3a5}', '\u{3a6}', '\u{3a7}', '\u{3a8}', '\u{3a9}', '\u{2207}',
+        '\u{3b1}', '\u{3b2}', '\u{3b3}', '\u{3b4}', '\u{3b5}', '\u{3b6}', '\u{3b7}', '\u{3b8}',
+        '\u{3b9}', '\u{3ba}', '\u{3bb}', '\u{3bc}', '\u{3bd}', '\u{3be}', '\u{3bf}', '\u{3c0}',
+        '\u{3c1}', '\u{3c2}', '\u{3c3}', '\u{3c4}', '\u{3c5}', '\u{3c6}', '\u{3c7}', '\u{3c8}',
+        '\u{3c9}', '\u{2202}', '\u{3b5}', '\u{3b8}', '\u{3ba}', '\u{3c6}', '\u{3c1}', '\u{3c0}',
+        '\u{391}', '\u{392}', '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}',
+        '\u{399}', '\u{39a}', '\u{39b}', '\u{39c}', '\u{39d}', '\u{39e}', '\u{39f}', '\u{3a0}',
+        '\u{3a1}', '\u{398}', '\u{3a3}', '\u{3a4}', '\u{3a5}', '\u{3a6}', '\u{3a7}', '\u{3a8}',
+        '\u{3a9}', '\u{2207}', '\u{3b1}', '\u{3b2}', '\u{3b3}', '\u{3b4}', '\u{3b5}', '\u{3b6}',
+        '\u{3b7}', '\u{3b8}', '\u{3b9}', '\u{3ba}', '\u{3bb}', '\u{3bc}', '\u{3bd}', '\u{3be}',
+        '\u{3bf}', '\u{3c0}', '\u{3c1}', '\u{3c2}', '\u{3c3}', '\u{3c4}', '\u{3c5}', '\u{3c6}',
+        '\u{3c7}', '\u{3c8}', '\u{3c9}', '\u{2202}', '\u{3b5}', '\u{3b8}', '\u{3ba}', '\u{3c6}',
+        '\u{3c1}', '\u{3c0}', '\u{391}', '\u{392}', '\u{393}', '\u{394}', '\u{395}', '\u{396}',
+        '\u{397}', '\u{398}', '\u{399}', '\u{39a}', '\u{39b}', '\u{39c}', '\u{39d}', '\u{39e}',
+        '\u{39f}', '\u{3a0}', '\u{3a1}', '\u{398}', '\u{3a3}', '\u{3a4}', '\u{3a5}', '\u{3a6}',
+        '\u{3a7}', '\u{3a8}', '\u{3a9}', '\u{2207}', '\u{3b1}', '\u{3b2}', '\u{3b3}', '\u{3b4}',
+        '\u{3b5}', '\u{3b6}', '\u{3b7}', '\u{3b8}', '\u{3b9}', '\u{3ba}', '\u{3bb}', '\u{3bc}',
+        '\u{3bd}', '\u{3be}', '\u{3bf}', '\u{3c0}', '\u{3c1}', '\u{3c2}', '\u{3c3}', '\u{3c4}',
+        '\u{3c5}', '\u{3c6}', '\u{3c7}', '\u{3c8}', '\u{3c9}', '\u{2202}', '\u{3b5}', '\u{3b8}',
+        '\u{3ba}', '\u{3c6}', '\u{3c1}', '\u{3c0}', '\u{391}', '\u{392}', '\u{393}', '\u{394}',
+        '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39a}', '\u{39b}', '\u{39c}',
+        '\u{39d}', '\u{39e}', '\u{39f}', '\u{3a0}', '\u{3a1}', '\u{398}', '\u{3a3}', '\u{3a4}',
+        '\u{3a5}', '\u{3a6}', '\u{3a7}', '\u{3a8}', '\u{3a9}', '\u{2207}', '\u{3b1}', '\u{3b2}',
+        '\u{3b3}', '\u{3b4}', '\u{3b5}', '\u{3b6}', '\u{3b7}', '\u{3b8}', '\u{3b9}', '\u{3ba}',
+        '\u{3bb}', '\u{3bc}', '\u{3bd}', '\u{3be}', '\u{3bf}', '\u{3c0}', '\u{3c1}', '\u{3c2}',
+        '\u{3c3}', '\u{3c4}', '\u{3c5}', '\u{3c6}', '\u{3c7}', '\u{3c8}', '\u{3c9}', '\u{2202}',
+        '\u{3b5}', '\u{3b8}', '\u{3ba}', '\u{3c6}', '\u{3c1}', '\u{3c0}', '\u{391}', '\u{392}',
+        '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39a}',
+        '\u{39b}', '\u{39c}', '\u{39d}', '\u{39e}', '\u{39f}', '\u{3a0}', '\u{3a1}', '\u{398}',
+        '\u{3a3}', '\u{3a4}', '\u{3a5}', '\u{3a6}', '\u{3a7}', '\u{3a8}', '\u{3a9}', '\u{2207}',
+        '\u{3b1}', '\u{3b2}', '\u{3b3}', '\u{3b4}', '\u{3b5}', '\u{3b6}', '\u{3b7}', '\u{3b8}',
+        '\u{3b9}', '\u{3ba}', '\u{3bb}', '\u{3bc}', '\u{3bd}', '\u{3be}', '\u{3bf}', '\u{3c0}',
+        '\u{3c1}', '\u{3c2}', '\u{3c3}', '\u{3c4}', '\u{3c5}', '\u{3c6}', '\u{3c7}', '\u{3c8}',
+        '\u{3c9}', '\u{2202}', '\u{3b5}', '\u{3b8}', '\u{3ba}', '\u{3c6}', '\u{3c1}', '\u{3c0}',
+        '\u{3dc}', '\u{3dd}', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '0', '1', '2', '3',
+        '4', '5', '6', '7', '8', '9', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '0', '1',
+        '2', '3', '4', '5', '6', '7', '8', '9', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+        '\u{430}', '\u{431}', '\u{432}', '\u{433}', '\u{434}', '\u{435}', '\u{436}', '\u{437}',
+        '\u{438}', '\u{43a}', '\u{43b}', '\u{43c}', '\u{43e}', '\u{43f}', '\u{440}', '\u{441}',
+        '\u{442}', '\u{443}', '\u{444}', '\u{445}', '\u{446}', '\u{447}', '\u{448}', '\u{44b}',
+        '\u{44d}', '\u{44e}', '\u{a689}', '\u{4d9}', '\u{456}', '\u{458}', '\u{4e9}', '\u{4af}',
+        '\u{4cf}', '\u{430}', '\u{431}', '\u{432}', '\u{433}', '\u{434}', '\u{435}', '\u{436}',
+        '\u{437}', '\u{438}', '\u{43a}', '\u{43b}', '\u{43e}', '\u{43f}', '\u{441}', '\u{443}',
+        '\u{444}', '\u{445}', '\u{446}', '\u{447}', '\u{448}', '\u{44a}', '\u{44b}', '\u{491}',
+        '\u{456}', '\u{455}', '\u{45f}', '\u{4ab}', '\u{a651}', '\u{4b1}', '\u{627}', '\u{628}',
+        '\u{62c}', '\u{62f}', '\u{648}', '\u{632}', '\u{62d}', '\u{637}', '\u{64a}', '\u{643}',
+        '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}', '\u{642}',
+        '\u{631}', '\u{634}', '\u{62a}', '\u{62b}', '\u{62e}', '\u{630}', '\u{636}', '\u{638}',
+        '\u{63a}', '\u{66e}', '\u{6ba}', '\u{6a1}', '\u{66f}', '\u{628}', '\u{62c}', '\u{647}',
+        '\u{62d}', '\u{64a}', '\u{643}', '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}',
+        '\u{641}', '\u{635}', '\u{642}', '\u{634}', '\u{62a}', '\u{62b}', '\u{62e}', '\u{636}',
+        '\u{63a}', '\u{62c}', '\u{62d}', '\u{64a}', '\u{644}', '\u{646}', '\u{633}', '\u{639}',
+        '\u{635}', '\u{642}', '\u{634}', '\u{62e}', '\u{636}', '\u{63a}', '\u{6ba}', '\u{66f}',
+        '\u{628}', '\u{62c}', '\u{647}', '\u{62d}', '\u{637}', '\u{64a}', '\u{643}', '\u{645}',
+        '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}', '\u{642}', '\u{634}', '\u{62a}',
+        '\u{62b}', '\u{62e}', '\u{636}', '\u{638}', '\u{63a}', '\u{66e}', '\u{6a1}', '\u{627}',
+        '\u{628}', '\u{62c}', '\u{62f}', '\u{647}', '\u{648}', '\u{632}', '\u{62d}', '\u{637}',
+        '\u{64a}', '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}',
+        '\u{642}', '\u{631}', '\u{634}', '\u{62a}', '\u{62b}', '\u{62e}', '\u{630}', '\u{636}',
+        '\u{638}', '\u{63a}', '\u{628}', '\u{62c}', '\u{62f}', '\u{648}', '\u{632}', '\u{62d}',
+        '\u{637}', '\u{64a}', '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}',
+        '\u{635}', '\u{642}', '\u{631}', '\u{634}', '\u{62a}', '\u{62b}', '\u{62e}', '\u{630}',
+        '\u{636}', '\u{638}', '\u{63a}', '0', '.', '0', ',', '1', ',', '2', ',', '3', ',', '4', ',',
+        '5', ',', '6', ',', '7', ',', '8', ',', '9', ',', '(', 'A', ')', '(', 'B', ')', '(', 'C',
+        ')', '(', 'D', ')', '(', 'E', ')', '(', 'F', ')', '(', 'G', ')', '(', 'H', ')', '(', 'I',
+        ')', '(', 'J', ')', '(', 'K', ')', '(', 'L', ')', '(', 'M', ')', '(', 'N', ')', '(', 'O',
+        ')', '(', 'P', ')', '(', 'Q', ')', '(', 'R', ')', '(', 'S', ')', '(', 'T', ')', '(', 'U',
+        ')', '(', 'V', ')', '(', 'W', ')', '(', 'X', ')', '(', 'Y', ')', '(', 'Z', ')', '\u{3014}',
+        'S', '\u{3015}', 'C', 'R', 'C', 'D', 'W', 'Z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I',
+        'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'H',
+        'V', 'M', 'V', 'S', 'D', 'S', 'S', 'P', 'P', 'V', 'W', 'C', 'M', 'C', 'M', 'D', 'M', 'R',
+        'D', 'J', '\u{307b}', '\u{304b}', '\u{30b3}', '\u{30b3}', '\u{30b5}', '\u{624b}',
+        '\u{5b57}', '\u{53cc}', '\u{30c6}', '\u{3099}', '\u{4e8c}', '\u{591a}', '\u{89e3}',
+        '\u{5929}', '\u{4ea4}', '\u{6620}', '\u{7121}', '\u{6599}', '\u{524d}', '\u{5f8c}',
+        '\u{518d}', '\u{65b0}', '\u{521d}', '\u{7d42}', '\u{751f}', '\u{8ca9}', '\u{58f0}',
+        '\u{5439}', '\u{6f14}', '\u{6295}', '\u{6355}', '\u{4e00}', '\u{4e09}', '\u{904a}',
+        '\u{5de6}', '\u{4e2d}', '\u{53f3}', '\u{6307}', '\u{8d70}', '\u{6253}', '\u{7981}',
+        '\u{7a7a}', '\u{5408}', '\u{6e80}', '\u{6709}', '\u{6708}', '\u{7533}', '\u{5272}',
+        '\u{55b6}', '\u{914d}', '\u{3014}', '\u{672c}', '\u{3015}', '\u{3014}', '\u{4e09}',
+        '\u{3015}', '\u{3014}', '\u{4e8c}', '\u{3015}', '\u{3014}', '\u{5b89}', '\u{3015}',
+        '\u{3014}', '\u{70b9}', '\u{3015}', '\u{3014}', '\u{6253}', '\u{3015}', '\u{3014}',
+        '\u{76d7}', '\u{3015}', '\u{3014}', '\u{52dd}', '\u{3015}', '\u{3014}', '\u{6557}',
+        '\u{3015}', '\u{5f97}', '\u{53ef}', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+    ];
+
+    static COMPOSITION_TABLE: &[(char, char, char)] = &[
+        ('<', '\u{338}', '\u{226e}'), ('=', '\u{338}', '\u{2260}'), ('>', '\u{338}', '\u{226f}'),
+        ('A', '\u{300}', '\u{c0}'), ('A', '\u{301}', '\u{c1}'), ('A', '\u{302}', '\u{c2}'),
+        ('A', '\u{303}', '\u{c3}'), ('A', '\u{304}', '\u{100}'), ('A', '\u{306}', '\u{102}'),
+        ('A', '\u{307}', '\u{226}'), ('A', '\u{308}', '\u{c4}'), ('A', '\u{309}', '\u{1ea2}'),
+        ('A', '\u{30a}', '\u{c5}'), ('A', '\u{30c}', '\u{1cd}'), ('A', '\u{30f}', '\u{200}'),
+        ('A', '\u{311}', '\u{202}'), ('A', '\u{323}', '\u{1ea0}'), ('A', '\u{325}', '\u{1e00}'),
+        ('A', '\u{328}', '\u{104}'), ('B', '\u{307}', '\u{1e02}'), ('B', '\u{323}', '\u{1e04}'),
+        ('B', '\u{331}', '\u{1e06}'), ('C', '\u{301}', '\u{106}'), ('C', '\u{302}', '\u{108}'),
+        ('C', '\u{307}', '\u{10a}'), ('C', '\u{30c}', '\u{10c}'), ('C', '\u{327}', '\u{c7}'),
+        ('D', '\u{307}', '\u{1e0a}'), ('D', '\u{30c}', '\u{10e}'), ('D', '\u{323}', '\u{1e0c}'),
+        ('D', '\u{327}', '\u{1e10}'), ('D', '\u{32d}', '\u{1e12}'), ('D', '\u{331}', '\u{1e0e}'),
+        ('E', '\u{300}', '\u{c8}'), ('E', '\u{301}', '\u{c9}'), ('E', '\u{302}', '\u{ca}'),
+        ('E', '\u{303}', '\u{1ebc}'), ('E', '\u{304}', '\u{112}'), ('E', '\u{306}', '\u{114}'),
+        ('E', '\u{307}', '\u{116}'), ('E', '\u{308}', '\u{cb}'), ('E', '\u{309}', '\u{1eba}'),
+        ('E', '\u{30c}', '\u{11a}'), ('E', '\u{30f}', '\u{204}'), ('E', '\u{311}', '\u{206}'),
+        ('E', '\u{323}', '\u{1eb8}'), ('E', '\u{327}', '\u{228}'), ('E', '\u{328}', '\u{118}'),
+        ('E', '\u{32d}', '\u{1e18}'), ('E', '\u{330}', '\u{1e1a}'), ('F', '\u{307}', '\u{1e1e}'),
+        ('G', '\u{301}', '\u{1f4}'), ('G', '\u{302}', '\u{11c}'), ('G', '\u{304}', '\u{1e20}'),
+        ('G', '\u{306}', '\u{11e}'), ('G', '\u{307}', '\u{120}'), ('G', '\u{30c}', '\u{1e6}'),
+        ('G', '\u{327}', '\u{122}'), ('H', '\u{302}', '\u{124}'), ('H', '\u{307}', '\u{1e22}'),
+        ('H', '\u{308}', '\u{1e26}'), ('H', '\u{30c}', '\u{21e}'), ('H', '\u{323}', '\u{1e24}'),
+        ('H', '\u{327}', '\u{1e28}'), ('H', '\u{32e}', '\u{1e2a}'), ('I', '\u{300}', '\u{cc}'),
+        ('I', '\u{301}', '\u{cd}'), ('I', '\u{302}', '\u{ce}'), ('I', '\u{303}', '\u{128}'),
+        ('I', '\u{304}', '\u{12a}'), ('I', '\u{306}', '\u{12c}'), ('I', '\u{307}', '\u{130}'),
+        ('I', '\u{308}', '\u{cf}'), ('I', '\u{309}', '\u{1ec8}'), ('I', '\u{30c}', '\u{1cf}'),
+        ('I', '\u{30f}', '\u{208}'), ('I', '\u{311}', '\u{20a}'), ('I', '\u{323}', '\u{1eca}'),
+        ('I', '\u{328}', '\u{12e}'), ('I', '\u{330}', '\u{1e2c}'), ('J', '\u{302}', '\u{134}'),
+        ('K', '\u{301}', '\u{1e30}'), ('K', '\u{30c}', '\u{1e8}'), ('K', '\u{323}', '\u{1e32}'),
+        ('K', '\u{327}', '\u{136}'), ('K', '\u{331}', '\u{1e34}'), ('L', '\u{301}', '\u{139}'),
+        ('L', '\u{30c}', '\u{13d}'), ('L', '\u{323}', '\u{1e36}'), ('L', '\u{327}', '\u{13b}'),
+        ('L', '\u{32d}', '\u{1e3c}'), ('L', '\u{331}', '\u{1e3a}'), ('M', '\u{301}', '\u{1e3e}'),
+        ('M', '\u{307}', '\u{1e40}'), ('M', '\u{323}', '\u{1e42}'), ('N', '\u{300}', '\u{1f8}'),
+        ('N', '\u{301}', '\u{143}'), ('N', '\u{303}', '\u{d1}'), ('N', '\u{307}', '\u{1e44}'),
+        ('N', '\u{30c}', '\u{147}'), ('N', '\u{323}', '\u{1e46}'), ('N', '\u{327}', '\u{145}'),
+        ('N', '\u{32d}', '\u{1e4a}'), ('N', '\u{331}', '\u{1e48}'), ('O', '\u{300}', '\u{d2}'),
+        ('O', '\u{301}', '\u{d3}'), ('O', '\u{302}', '\u{d4}'), ('O', '\u{303}', '\u{d5}'),
+        ('O', '\u{304}', '\u{14c}'), ('O', '\u{306}', '\u{14e}'), ('O', '\u{307}', '\u{22e}'),
+        ('O', '\u{308}', '\u{d6}'), ('O', '\u{309}', '\u{1ece}'), ('O', '\u{30b}', '\u{150}'),
+        ('O', '\u{30c}', '\u{1d1}'), ('O', '\u{30f}', '\u{20c}'), ('O', '\u{311}', '\u{20e}'),
+        ('O', '\u{31b}', '\u{1a0}'), ('O', '\u{323}', '\u{1ecc}'), ('O', '\u{328}', '\u{1ea}'),
+        ('P', '\u{301}', '\u{1e54}'), ('P', '\u{307}', '\u{1e56}'), ('R', '\u{301}', '\u{154}'),
+        ('R', '\u{307}', '\u{1e58}'), ('R', '\u{30c}', '\u{158}'), ('R', '\u{30f}', '\u{210}'),
+        ('R', '\u{311}', '\u{212}'), ('R', '\u{323}', '\u{1e5a}'), ('R', '\u{327}', '\u{156}'),
+        ('R', '\u{331}', '\u{1e5e}'), ('S', '\u{301}', '\u{15a}'), ('S', '\u{302}', '\u{15c}'),
+        ('S', '\u{307}', '\u{1e60}'), ('S', '\u{30c}', '\u{160}'), ('S', '\u{323}', '\u{1e62}'),
+        ('S', '\u{326}', '\u{218}'), ('S', '\u{327}', '\u{15e}'), ('T', '\u{307}', '\u{1e6a}'),
+        ('T', '\u{30c}', '\u{164}'), ('T', '\u{323}', '\u{1e6c}'), ('T', '\u{326}', '\u{21a}'),
+        ('T', '\u{327}', '\u{162}'), ('T', '\u{32d}', '\u{1e70}'), ('T', '\u{331}', '\u{1e6e}'),
+        ('U', '\u{300}', '\u{d9}'), ('U', '\u{301}', '\u{da}'), ('U', '\u{302}', '\u{db}'),
+        ('U', '\u{303}', '\u{168}'), ('U', '\u{304}', '\u{16a}'), ('U', '\u{306}', '\u{16c}'),
+        ('U', '\u{308}', '\u{dc}'), ('U', '\u{309}', '\u{1ee6}'), ('U', '\u{30a}', '\u{16e}'),
+        ('U', '\u{30b}', '\u{170}'), ('U', '\u{30c}', '\u{1d3}'), ('U', '\u{30f}', '\u{214}'),
+        ('U', '\u{311}', '\u{216}'), ('U', '\u{31b}', '\u{1af}'), ('U', '\u{323}', '\u{1ee4}'),
+        ('U', '\u{324}', '\u{1e72}'), ('U', '\u{328}', '\u{172}'), ('U', '\u{32d}', '\u{1e76}'),
+        ('U', '\u{330}', '\u{1e74}'), ('V', '\u{303}', '\u{1e7c}'), ('V', '\u{323}', '\u{1e7e}'),
+        ('W', '\u{300}', '\u{1e80}'), ('W', '\u{301}', '\u{1e82}'), ('W', '\u{302}', '\u{174}'),
+        ('W', '\u{307}', '\u{1e86}'), ('W', '\u{308}', '\u{1e84}'), ('W', '\u{323}', '\u{1e88}'),
+        ('X', '\u{307}', '\u{1e8a}'), ('X', '\u{308}', '\u{1e8c}'), ('Y', '\u{300}', '\u{1ef2}'),
+        ('Y', '\u{301}', '\u{dd}'), ('Y', '\u{302}', '\u{176}'), ('Y', '\u{303}', '\u{1ef8}'),
+        ('Y', '\u{304}', '\u{232}'), ('Y', '\u{307}', '\u{1e8e}'), ('Y', '\u{308}', '\u{178}'),
+        ('Y', '\u{309}', '\u{1ef6}'), ('Y', '\u{323}', '\u{1ef4}'), ('Z', '\u{301}', '\u{179}'),
+        ('Z', '\u{302}', '\u{1e90}'), ('Z', '\u{307}', '\u{17b}'), ('Z', '\u{30c}', '\u{17d}'),
+        ('Z', '\u{323}', '\u{1e92}'), ('Z', '\u{331}', '\u{1e94}'), ('a', '\u{300}', '\u{e0}'),
+        ('a', '\u{301}', '\u{e1}'), ('a', '\u{302}', '\u{e2}'), ('a', '\u{303}', '\u{e3}'),
+        ('a', '\u{304}', '\u{101}'), ('a', '\u{306}', '\u{103}'), ('a', '\u{307}', '\u{227}'),
+        ('a', '\u{308}', '\u{e4}'), ('a', '\u{309}', '\u{1ea3}'), ('a', '\u{30a}', '\u{e5}'),
+        ('a', '\u{30c}', '\u{1ce}'), ('a', '\u{30f}', '\u{201}'), ('a', '\u{311}', '\u{203}'),
+        ('a', '\u{323}', '\u{1ea1}'), ('a', '\u{325}', '\u{1e01}'), ('a', '\u{328}', '\u{105}'),
+        ('b', '\u{307}', '\u{1e03}'), ('b', '\u{323}', '\u{1e05}'), ('b', '\u{331}', '\u{1e07}'),
+        ('c', '\u{301}', '\u{107}'), ('c', '\u{302}', '\u{109}'), ('c', '\u{307}', '\u{10b}'),
+        ('c', '\u{30c}', '\u{10d}'), ('c', '\u{327}', '\u{e7}'), ('d', '\u{307}', '\u{1e0b}'),
+        ('d', '\u{30c}', '\u{10f}'), ('d', '\u{323}', '\u{1e0d}'), ('d', '\u{327}', '\u{1e11}'),
+        ('d', '\u{32d}', '\u{1e13}'), ('d', '\u{331}', '\u{1e0f}'), ('e', '\u{300}', '\u{e8}'),
+        ('e', '\u{301}', '\u{e9}'), ('e', '\u{302}', '\u{ea}'), ('e', '\u{303}', '\u{1ebd}'),
+        ('e', '\u{304}', '\u{113}'), ('e', '\u{306}', '\u{115}'), ('e', '\u{307}', '\u{117}'),
+        ('e', '\u{308}', '\u{eb}'), ('e', '\u{309}', '\u{1ebb}'), ('e', '\u{30c}', '\u{11b}'),
+        ('e', '\u{30f}', '\u{205}'), ('e', '\u{311}', '\u{207}'), ('e', '\u{323}', '\u{1eb9}'),
+        ('e', '\u{327}', '\u{229}'), ('e', '\u{328}', '\u{119}'), ('e', '\u{32d}', '\u{1e19}'),
+        ('e', '\u{330}', '\u{1e1b}'), ('f', '\u{307}', '\u{1e1f}'), ('g', '\u{301}', '\u{1f5}'),
+        ('g', '\u{302}', '\u{11d}'), ('g', '\u{304}', '\u{1e21}'), ('g', '\u{306}', '\u{11f}'),
+        ('g', '\u{307}', '\u{121}'), ('g', '\u{30c}', '\u{1e7}'), ('g', '\u{327}', '\u{123}'),
+        ('h', '\u{302}', '\u{125}'), ('h', '\u{307}', '\u{1e23}'), ('h', '\u{308}', '\u{1e27}'),
+        ('h', '\u{30c}', '\u{21f}'), ('h', '\u{323}', '\u{1e25}'), ('h', '\u{327}', '\u{1e29}'),
+        ('h', '\u{32e}', '\u{1e2b}'), ('h', '\u{331}', '\u{1e96}'), ('i', '\u{300}', '\u{ec}'),
+        ('i', '\u{301}', '\u{ed}'), ('i', '\u{302}', '\u{ee}'), ('i', '\u{303}', '\u{129}'),
+        ('i', '\u{304}', '\u{12b}'), ('i', '\u{306}', '\u{12d}'), ('i', '\u{308}', '\u{ef}'),
+        ('i', '\u{309}', '\u{1ec9}'), ('i', '\u{30c}', '\u{1d0}'), ('i', '\u{30f}', '\u{209}'),
+        ('i', '\u{311}', '\u{20b}'), ('i', '\u{323}', '\u{1ecb}'), ('i', '\u{328}', '\u{12f}'),
+        ('i', '\u{330}', '\u{1e2d}'), ('j', '\u{302}', '\u{135}'), ('j', '\u{30c}', '\u{1f0}'),
+        ('k', '\u{301}', '\u{1e31}'), ('k', '\u{30c}', '\u{1e9}'), ('k', '\u{323}', '\u{1e33}'),
+        ('k', '\u{327}', '\u{137}'), ('k', '\u{331}', '\u{1e35}'), ('l', '\u{301}', '\u{13a}'),
+        ('l', '\u{30c}', '\u{13e}'), ('l', '\u{323}', '\u{1e37}'), ('l', '\u{327}', '\u{13c}'),
+        ('l', '\u{32d}', '\u{1e3d}'), ('l', '\u{331}', '\u{1e3b}'), ('m', '\u{301}', '\u{1e3f}'),
+        ('m', '\u{307}', '\u{1e41}'), ('m', '\u{323}', '\u{1e43}'), ('n', '\u{300}', '\u{1f9}'),
+        ('n', '\u{301}', '\u{144}'), ('n', '\u{303}', '\u{f1}'), ('n', '\u{307}', '\u{1e45}'),
+        ('n', '\u{30c}', '\u{148}'), ('n', '\u{323}', '\u{1e47}'), ('n', '\u{327}', '\u{146}'),
+        ('n', '\u{32d}', '\u{1e4b}'), ('n', '\u{331}', '\u{1e49}'), ('o', '\u{300}', '\u{f2}'),
+        ('o', '\u{301}', '\u{f3}'), ('o', '\u{302}', '\u{f4}'), ('o', '\u{303}', '\u{f5}'),
+        ('o', '\u{304}', '\u{14d}'), ('o', '\u{306}', '\u{14f}'), ('o', '\u{307}', '\u{22f}'),
+        ('o', '\u{308}', '\u{f6}'), ('o', '\u{309}', '\u{1ecf}'), ('o', '\u{30b}', '\u{151}'),
+        ('o', '\u{30c}', '\u{1d2}'), ('o', '\u{30f}', '\u{20d}'), ('o', '\u{311}', '\u{20f}'),
+        ('o', '\u{31b}', '\u{1a1}'), ('o', '\u{323}', '\u{1ecd}'), ('o', '\u{328}', '\u{1eb}'),
+        ('p', '\u{301}', '\u{1e55}'), ('p', '\u{307}', '\u{1e57}'), ('r', '\u{301}', '\u{155}'),
+        ('r', '\u{307}', '\u{1e59}'), ('r', '\u{30c}', '\u{159}'), ('r', '\u{30f}', '\u{211}'),
+        ('r', '\u{311}', '\u{213}'), ('r', '\u{323}', '\u{1e5b}'), ('r', '\u{327}', '\u{157}'),
+        ('r', '\u{331}', '\u{1e5f}'), ('s', '\u{301}', '\u{15b}'), ('s', '\u{302}', '\u{15d}'),
+        ('s', '\u{307}', '\u{1e61}'), ('s', '\u{30c}', '\u{161}'), ('s', '\u{323}', '\u{1e63}'),
+        ('s', '\u{326}', '\u{219}'), ('s', '\u{327}', '\u{15f}'), ('t', '\u{307}', '\u{1e6b}'),
+        ('t', '\u{308}', '\u{1e97}'), ('t', '\u{30c}', '\u{165}'), ('t', '\u{323}', '\u{1e6d}'),
+        ('t', '\u{326}', '\u{21b}'), ('t', '\u{327}', '\u{163}'), ('t', '\u{32d}', '\u{1e71}'),
+        ('t', '\u{331}', '\u{1e6f}'), ('u', '\u{300}', '\u{f9}'), ('u', '\u{301}', '\u{fa}'),
+        ('u', '\u{302}', '\u{fb}'), ('u', '\u{303}', '\u{169}'), ('u', '\u{304}', '\u{16b}'),
+        ('u', '\u{306}', '\u{16d}'), ('u', '\u{308}', '\u{fc}'), ('u', '\u{309}', '\u{1ee7}'),
+        ('u', '\u{30a}', '\u{16f}'), ('u', '\u{30b}', '\u{171}'), ('u', '\u{30c}', '\u{1d4}'),
+        ('u', '\u{30f}', '\u{215}'), ('u', '\u{311}', '\u{217}'), ('u', '\u{31b}', '\u{1b0}'),
+        ('u', '\u{323}', '\u{1ee5}'), ('u', '\u{324}', '\u{1e73}'), ('u', '\u{328}', '\u{173}'),
+        ('u', '\u{32d}', '\u{1e77}'), ('u', '\u{330}', '\u{1e75}'), ('v', '\u{303}', '\u{1e7d}'),
+        ('v', '\u{323}', '\u{1e7f}'), ('w', '\u{300}', '\u{1e81}'), ('w', '\u{301}', '\u{1e83}'),
+        ('w', '\u{302}', '\u{175}'), ('w', '\u{307}', '\u{1e87}'), ('w', '\u{308}', '\u{1e85}'),
+        ('w', '\u{30a}', '\u{1e98}'), ('w', '\u{323}', '\u{1e89}'), ('x', '\u{307}', '\u{1e8b}'),
+        ('x', '\u{308}', '\u{1e8d}'), ('y', '\u{300}', '\u{1ef3}'), ('y', '\u{301}', '\u{fd}'),
+        ('y', '\u{302}', '\u{177}'), ('y', '\u{303}', '\u{1ef9}'), ('y', '\u{304}', '\u{233}'),
+        ('y', '\u{307}', '\u{1e8f}'), ('y', '\u{308}', '\u{ff}'), ('y', '\u{309}', '\u{1ef7}'),
+        ('y', '\u{30a}', '\u{1e99}'), ('y', '\u{323}', '\u{1ef5}'), ('z', '\u{301}', '\u{17a}'),
+        ('z', '\u{302}', '\u{1e91}'), ('z', '\u{307}', '\u{17c}'), ('z', '\u{30c}', '\u{17e}'),
+        ('z', '\u{323}', '\u{1e93}'), ('z', '\u{331}', '\u{1e95}'),
+        ('\u{a8}', '\u{300}', '\u{1fed}'), ('\u{a8}', '\u{301}', '\u{385}'),
+        ('\u{a8}', '\u{342}', '\u{1fc1}'), ('\u{c2}', '\u{300}', '\u{1ea6}'),
+        ('\u{c2}', '\u{301}', '\u{1ea4}'), ('\u{c2}', '\u{303}', '\u{1eaa}'),
+        ('\u{c2}', '\u{309}', '\u{1ea8}'), ('\u{c4}', '\u{304}', '\u{1de}'),
+        ('\u{c5}', '\u{301}', '\u{1fa}'), ('\u{c6}', '\u{301}', '\u{1fc}'),
+        ('\u{c6}', '\u{304}', '\u{1e2}'), ('\u{c7}', '\u{301}', '\u{1e08}'),
+        ('\u{ca}', '\u{300}', '\u{1ec0}'), ('\u{ca}', '\u{301}', '\u{1ebe}'),
+        ('\u{ca}', '\u{303}', '\u{1ec4}'), ('\u{ca}', '\u{309}', '\u{1ec2}'),
+        ('\u{cf}', '\u{301}', '\u{1e2e}'), ('\u{d4}', '\u{300}', '\u{1ed2}'),
+        ('\u{d4}', '\u{301}', '\u{1ed0}'), ('\u{d4}', '\u{303}', '\u{1ed6}'),
+        ('\u{d4}', '\u{309}', '\u{1ed4}'), ('\u{d5}', '\u{301}', '\u{1e4c}'),
+        ('\u{d5}', '\u{304}', '\u{22c}'), ('\u{d5}', '\u{308}', '\u{1e4e}'),
+        ('\u{d6}', '\u{304}', '\u{22a}'), ('\u{d8}', '\u{301}', '\u{1fe}'),
+        ('\u{dc}', '\u{300}', '\u{1db}'), ('\u{dc}', '\u{301}', '\u{1d7}'),
+        ('\u{dc}', '\u{304}', '\u{1d5}'), ('\u{dc}', '\u{30c}', '\u{1d9}'),
+        ('\u{e2}', '\u{300}', '\u{1ea7}'), ('\u{e2}', '\u{301}', '\u{1ea5}'),
+        ('\u{e2}', '\u{303}', '\u{1eab}'), ('\u{e2}', '\u{309}', '\u{1ea9}'),
+        ('\u{e4}', '\u{304}', '\u{1df}'), ('\u{e5}', '\u{301}', '\u{1fb}'),
+        ('\u{e6}', '\u{301}', '\u{1fd}'), ('\u{e6}', '\u{304}', '\u{1e3}'),
+        ('\u{e7}', '\u{301}', '\u{1e09}'), ('\u{ea}', '\u{300}', '\u{1ec1}'),
+        ('\u{ea}', '\u{301}', '\u{1ebf}'), ('\u{ea}', '\u{303}', '\u{1ec5}'),
+        ('\u{ea}', '\u{309}', '\u{1ec3}'), ('\u{ef}', '\u{301}', '\u{1e2f}'),
+        ('\u{f4}', '\u{300}', '\u{1ed3}'), ('\u{f4}', '\u{301}', '\u{1ed1}'),
+        ('\u{f4}', '\u{303}', '\u{1ed7}'), ('\u{f4}', '\u{309}', '\u{1ed5}'),
+        ('\u{f5}', '\u{301}', '\u{1e4d}'), ('\u{f5}', '\u{304}', '\u{22d}'),
+        ('\u{f5}', '\u{308}', '\u{1e4f}'), ('\u{f6}', '\u{304}', '\u{22b}'),
+        ('\u{f8}', '\u{301}', '\u{1ff}'), ('\u{fc}', '\u{300}', '\u{1dc}'),
+        ('\u{fc}', '\u{301}', '\u{1d8}'), ('\u{fc}', '\u{304}', '\u{1d6}'),
+        ('\u{fc}', '\u{30c}', '\u{1da}'), ('\u{102}', '\u{300}', '\u{1eb0}'),
+        ('\u{102}', '\u{301}', '\u{1eae}'), ('\u{102}', '\u{303}', '\u{1eb4}'),
+        ('\u{102}', '\u{309}', '\u{1eb2}'), ('\u{103}', '\u{300}', '\u{1eb1}'),
+        ('\u{103}', '\u{301}', '\u{1eaf}'), ('\u{103}', '\u{303}', '\u{1eb5}'),
+        ('\u{103}', '\u{309}', '\u{1eb3}'), ('\u{112}', '\u{300}', '\u{1e14}'),
+        ('\u{112}', '\u{301}', '\u{1e16}'), ('\u{113}', '\u{300}', '\u{1e15}'),
+        ('\u{113}', '\u{301}', '\u{1e17}'), ('\u{14c}', '\u{300}', '\u{1e50}'),
+        ('\u{14c}', '\u{301}', '\u{1e52}'), ('\u{14d}', '\u{300}', '\u{1e51}'),
+        ('\u{14d}', '\u{301}', '\u{1e53}'), ('\u{15a}', '\u{307}', '\u{1e64}'),
+        ('\u{15b}', '\u{307}', '\u{1e65}'), ('\u{160}', '\u{307}', '\u{1e66}'),
+        ('\u{161}', '\u{307}', '\u{1e67}'), ('\u{168}', '\u{301}', '\u{1e78}'),
+        ('\u{169}', '\u{301}', '\u{1e79}'), ('\u{16a}', '\u{308}', '\u{1e7a}'),
+        ('\u{16b}', '\u{308}', '\u{1e7b}'), ('\u{17f}', '\u{307}', '\u{1e9b}'),
+        ('\u{1a0}', '\u{300}', '\u{1edc}'), ('\u{1a0}', '\u{301}', '\u{1eda}'),
+        ('\u{1a0}', '\u{303}', '\u{1ee0}'), ('\u{1a0}', '\u{309}', '\u{1ede}'),
+        ('\u{1a0}', '\u{323}', '\u{1ee2}'), ('\u{1a1}', '\u{300}', '\u{1edd}'),
+        ('\u{1a1}', '\u{301}', '\u{1edb}'), ('\u{1a1}', '\u{303}', '\u{1ee1}'),
+        ('\u{1a1}', '\u{309}', '\u{1edf}'), ('\u{1a1}', '\u{323}', '\u{1ee3}'),
+        ('\u{1af}', '\u{300}', '\u{1eea}'), ('\u{1af}', '\u{301}', '\u{1ee8}'),
+        ('\u{1af}', '\u{303}', '\u{1eee}'), ('\u{1af}', '\u{309}', '\u{1eec}'),
+        ('\u{1af}', '\u{323}', '\u{1ef0}'), ('\u{1b0}', '\u{300}', '\u{1eeb}'),
+        ('\u{1b0}', '\u{301}', '\u{1ee9}'), ('\u{1b0}', '\u{303}', '\u{1eef}'),
+        ('\u{1b0}', '\u{309}', '\u{1eed}'), ('\u{1b0}', '\u{323}', '\u{1ef1}'),
+        ('\u{1b7}', '\u{30c}', '\u{1ee}'), ('\u{1ea}', '\u{304}', '\u{1ec}'),
+        ('\u{1eb}', '\u{304}', '\u{1ed}'), ('\u{226}', '\u{304}', '\u{1e0}'),
+        ('\u{227}', '\u{304}', '\u{1e1}'), ('\u{228}', '\u{306}', '\u{1e1c}'),
+        ('\u{229}', '\u{306}', '\u{1e1d}'), ('\u{22e}', '\u{304}', '\u{230}'),
+        ('\u{22f}', '\u{304}', '\u{231}'), ('\u{292}', '\u{30c}', '\u{1ef}'),
+        ('\u{391}', '\u{300}', '\u{1fba}'), ('\u{391}', '\u{301}', '\u{386}'),
+        ('\u{391}', '\u{304}', '\u{1fb9}'), ('\u{391}', '\u{306}', '\u{1fb8}'),
+        ('\u{391}', '\u{313}', '\u{1f08}'), ('\u{391}', '\u{314}', '\u{1f09}'),
+        ('\u{391}', '\u{345}', '\u{1fbc}'), ('\u{395}', '\u{300}', '\u{1fc8}'),
+        ('\u{395}', '\u{301}', '\u{388}'), ('\u{395}', '\u{313}', '\u{1f18}'),
+        ('\u{395}', '\u{314}', '\u{1f19}'), ('\u{397}', '\u{300}', '\u{1fca}'),
+        ('\u{397}', '\u{301}', '\u{389}'), ('\u{397}', '\u{313}', '\u{1f28}'),
+        ('\u{397}', '\u{314}', '\u{1f29}'), ('\u{397}', '\u{345}', '\u{1fcc}'),
+        ('\u{399}', '\u{300}', '\u{1fda}'), ('\u{399}', '\u{301}', '\u{38a}'),
+        ('\u{399}', '\u{304}', '\u{1fd9}'), ('\u{399}', '\u{306}', '\u{1fd8}'),
+        ('\u{399}', '\u{308}', '\u{3aa}'), ('\u{399}', '\u{313}', '\u{1f38}'),
+        ('\u{399}', '\u{314}', '\u{1f39}'), ('\u{39f}', '\u{300}', '\u{1ff8}'),
+        ('\u{39f}', '\u{301}', '\u{38c}'), ('\u{39f}', '\u{313}', '\u{1f48}'),
+        ('\u{39f}', '\u{314}', '\u{1f49}'), ('\u{3a1}', '\u{314}', '\u{1fec}'),
+        ('\u{3a5}', '\u{300}', '\u{1fea}'), ('\u{3a5}', '\u{301}', '\u{38e}'),
+        ('\u{3a5}', '\u{304}', '\u{1fe9}'), ('\u{3a5}', '\u{306}', '\u{1fe8}'),
+        ('\u{3a5}', '\u{308}', '\u{3ab}'), ('\u{3a5}', '\u{314}', '\u{1f59}'),
+        ('\u{3a9}', '\u{300}', '\u{1ffa}'), ('\u{3a9}', '\u{301}', '\u{38f}'),
+        ('\u{3a9}', '\u{313}', '\u{1f68}'), ('\u{3a9}', '\u{314}', '\u{1f69}'),
+        ('\u{3a9}', '\u{345}', '\u{1ffc}'), ('\u{3ac}', '\u{345}', '\u{1fb4}'),
+        ('\u{3ae}', '\u{345}', '\u{1fc4}'), ('\u{3b1}', '\u{300}', '\u{1f70}'),
+        ('\u{3b1}', '\u{301}', '\u{3ac}'), ('\u{3b1}', '\u{304}', '\u{1fb1}'),
+        ('\u{3b1}', '\u{306}', '\u{1fb0}'), ('\u{3b1}', '\u{313}', '\u{1f00}'),
+        ('\u{3b1}', '\u{314}', '\u{1f01}'), ('\u{3b1}', '\u{342}', '\u{1fb6}'),
+        ('\u{3b1}', '\u{345}', '\u{1fb3}'), ('\u{3b5}', '\u{300}', '\u{1f72}'),
+        ('\u{3b5}', '\u{301}', '\u{3ad}'), ('\u{3b5}', '\u{313}', '\u{1f10}'),
+        ('\u{3b5}', '\u{314}', '\u{1f11}'), ('\u{3b7}', '\u{300}', '\u{1f74}'),
+        ('\u{3b7}', '\u{301}', '\u{3ae}'), ('\u{3b7}', '\u{313}', '\u{1f20}'),
+        ('\u{3b7}', '\u{314}', '\u{1f21}'), ('\u{3b7}', '\u{342}', '\u{1fc6}'),
+        ('\u{3b7}', '\u{345}', '\u{1fc3}'), ('\u{3b9}', '\u{300}', '\u{1f76}'),
+        ('\u{3b9}', '\u{301}', '\u{3af}'), ('\u{3b9}', '\u{304}', '\u{1fd1}'),
+        ('\u{3b9}', '\u{306}', '\u{1fd0}'), ('\u{3b9}', '\u{308}', '\u{3ca}'),
+        ('\u{3b9}', '\u{313}', '\u{1f30}'), ('\u{3b9}', '\u{314}', '\u{1f31}'),
+        ('\u{3b9}', '\u{342}', '\u{1fd6}'), ('\u{3bf}', '\u{300}', '\u{1f78}'),
+        ('\u{3bf}', '\u{301}', '\u{3cc}'), ('\u{3bf}', '\u{313}', '\u{1f40}'),
+        ('\u{3bf}', '\u{314}', '\u{1f41}'), ('\u{3c1}', '\u{313}', '\u{1fe4}'),
+        ('\u{3c1}', '\u{314}', '\u{1fe5}'), ('\u{3c5}', '\u{300}', '\u{1f7a}'),
+        ('\u{3c5}', '\u{301}', '\u{3cd}'), ('\u{3c5}', '\u{304}', '\u{1fe1}'),
+        ('\u{3c5}', '\u{306}', '\u{1fe0}'), ('\u{3c5}', '\u{308}', '\u{3cb}'),
+        ('\u{3c5}', '\u{313}', '\u{1f50}'), ('\u{3c5}', '\u{314}', '\u{1f51}'),
+        ('\u{3c5}', '\u{342}', '\u{1fe6}'), ('\u{3c9}', '\u{300}', '\u{1f7c}'),
+        ('\u{3c9}', '\u{301}', '\u{3ce}'), ('\u{3c9}', '\u{313}', '\u{1f60}'),
+        ('\u{3c9}', '\u{314}', '\u{1f61}'), ('\u{3c9}', '\u{342}', '\u{1ff6}'),
+        ('\u{3c9}', '\u{345}', '\u{1ff3}'), ('\u{3ca}', '\u{300}', '\u{1fd2}'),
+        ('\u{3ca}', '\u{301}', '\u{390}'), ('\u{3ca}', '\u{342}', '\u{1fd7}'),
+        ('\u{3cb}', '\u{300}', '\u{1fe2}'), ('\u{3cb}', '\u{301}', '\u{3b0}'),
+        ('\u{3cb}', '\u{342}', '\u{1fe7}'), ('\u{3ce}', '\u{345}', '\u{1ff4}'),
+        ('\u{3d2}', '\u{301}', '\u{3d3}'), ('\u{3d2}', '\u{308}', '\u{3d4}'),
+        ('\u{406}', '\u{308}', '\u{407}'), ('\u{410}', '\u{306}', '\u{4d0}'),
+        ('\u{410}', '\u{308}', '\u{4d2}'), ('\u{413}', '\u{301}', '\u{403}'),
+        ('\u{415}', '\u{300}', '\u{400}'), ('\u{415}', '\u{306}', '\u{4d6}'),
+        ('\u{415}', '\u{308}', '\u{401}'), ('\u{416}', '\u{306}', '\u{4c1}'),
+        ('\u{416}', '\u{308}', '\u{4dc}'), ('\u{417}', '\u{308}', '\u{4de}'),
+        ('\u{418}', '\u{300}', '\u{40d}'), ('\u{418}', '\u{304}', '\u{4e2}'),
+        ('\u{418}', '\u{306}', '\u{419}'), ('\u{418}', '\u{308}', '\u{4e4}'),
+        ('\u{41a}', '\u{301}', '\u{40c}'), ('\u{41e}', '\u{308}', '\u{4e6}'),
+        ('\u{423}', '\u{304}', '\u{4ee}'), ('\u{423}', '\u{306}', '\u{40e}'),
+        ('\u{423}', '\u{308}', '\u{4f0}'), ('\u{423}', '\u{30b}', '\u{4f2}'),
+        ('\u{427}', '\u{308}', '\u{4f4}'), ('\u{42b}', '\u{308}', '\u{4f8}'),
+        ('\u{42d}', '\u{308}', '\u{4ec}'), ('\u{430}', '\u{306}', '\u{4d1}'),
+        ('\u{430}', '\u{308}', '\u{4d3}'), ('\u{433}', '\u{301}', '\u{453}'),
+        ('\u{435}', '\u{300}', '\u{450}'), ('\u{435}', '\u{306}', '\u{4d7}'),
+        ('\u{435}', '\u{308}', '\u{451}'), ('\u{436}', '\u{306}', '\u{4c2}'),
+        ('\u{436}', '\u{308}', '\u{4dd}'), ('\u{437}', '\u{308}', '\u{4df}'),
+        ('\u{438}', '\u{300}', '\u{45d}'), ('\u{438}', '\u{304}', '\u{4e3}'),
+        ('\u{438}', '\u{306}', '\u{439}'), ('\u{438}', '\u{308}', '\u{4e5}'),
+        ('\u{43a}', '\u{301}', '\u{45c}'), ('\u{43e}', '\u{308}', '\u{4e7}'),
+        ('\u{443}', '\u{304}', '\u{4ef}'), ('\u{443}', '\u{306}', '\u{45e}'),
+        ('\u{443}', '\u{308}', '\u{4f1}'), ('\u{443}', '\u{30b}', '\u{4f3}'),
+        ('\u{447}', '\u{308}', '\u{4f5}'), ('\u{44b}', '\u{308}', '\u{4f9}'),
+        ('\u{44d}', '\u{308}', '\u{4ed}'), ('\u{456}', '\u{308}', '\u{457}'),
+        ('\u{474}', '\u{30f}', '\u{476}'), ('\u{475}', '\u{30f}', '\u{477}'),
+        ('\u{4d8}', '\u{308}', '\u{4da}'), ('\u{4d9}', '\u{308}', '\u{4db}'),
+        ('\u{4e8}', '\u{308}', '\u{4ea}'), ('\u{4e9}', '\u{308}', '\u{4eb}'),
+        ('\u{627}', '\u{653}', '\u{622}'), ('\u{627}', '\u{654}', '\u{623}'),
+        ('\u{627}', '\u{655}', '\u{625}'), ('\u{648}', '\u{654}', '\u{624}'),
+        ('\u{64a}', '\u{654}', '\u{626}'), ('\u{6c1}', '\u{654}', '\u{6c2}'),
+        ('\u{6d2}', '\u{654}', '\u{6d3}'), ('\u{6d5}', '\u{654}', '\u{6c0}'),
+        ('\u{928}', '\u{93c}', '\u{929}'), ('\u{930}', '\u{93c}', '\u{931}'),
+        ('\u{933}', '\u{93c}', '\u{934}'), ('\u{9c7}', '\u{9be}', '\u{9cb}'),
+        ('\u{9c7}', '\u{9d7}', '\u{9cc}'), ('\u{b47}', '\u{b3e}', '\u{b4b}'),
+        ('\u{b47}', '\u{b56}', '\u{b48}'), ('\u{b47}', '\u{b57}', '\u{b4c}'),
+        ('\u{b92}', '\u{bd7}', '\u{b94}'), ('\u{bc6}', '\u{bbe}', '\u{bca}'),
+        ('\u{bc6}', '\u{bd7}', '\u{bcc}'), ('\u{bc7}', '\u{bbe}', '\u{bcb}'),
+        ('\u{c46}', '\u{c56}', '\u{c48}'), ('\u{cbf}', '\u{cd5}', '\u{cc0}'),
+        ('\u{cc6}', '\u{cc2}', '\u{cca}'), ('\u{cc6}', '\u{cd5}', '\u{cc7}'),
+        ('\u{cc6}', '\u{cd6}', '\u{cc8}'), ('\u{cca}', '\u{cd5}', '\u{ccb}'),
+        ('\u{d46}', '\u{d3e}', '\u{d4a}'), ('\u{d46}', '\u{d57}', '\u{d4c}'),
+        ('\u{d47}', '\u{d3e}', '\u{d4b}'), ('\u{dd9}', '\u{dca}', '\u{dda}'),
+        ('\u{dd9}', '\u{dcf}', '\u{ddc}'), ('\u{dd9}', '\u{ddf}', '\u{dde}'),
+        ('\u{ddc}', '\u{dca}', '\u{ddd}'), ('\u{1025}', '\u{102e}', '\u{1026}'),
+        ('\u{1b05}', '\u{1b35}', '\u{1b06}'), ('\u{1b07}', '\u{1b35}', '\u{1b08}'),
+        ('\u{1b09}', '\u{1b35}', '\u{1b0a}'), ('\u{1b0b}', '\u{1b35}', '\u{1b0c}'),
+        ('\u{1b0d}', '\u{1b35}', '\u{1b0e}'), ('\u{1b11}', '\u{1b35}', '\u{1b12}'),
+        ('\u{1b3a}', '\u{1b35}', '\u{1b3b}'), ('\u{1b3c}', '\u{1b35}', '\u{1b3d}'),
+        ('\u{1b3e}', '\u{1b35}', '\u{1b40}'), ('\u{1b3f}', '\u{1b35}', '\u{1b41}'),
+        ('\u{1b42}', '\u{1b35}', '\u{1b43}'), ('\u{1e36}', '\u{304}', '\u{1e38}'),
+        ('\u{1e37}', '\u{304}', '\u{1e39}'), ('\u{1e5a}', '\u{304}', '\u{1e5c}'),
+        ('\u{1e5b}', '\u{304}', '\u{1e5d}'), ('\u{1e62}', '\u{307}', '\u{1e68}'),
+        ('\u{1e63}', '\u{307}', '\u{1e69}'), ('\u{1ea0}', '\u{302}', '\u{1eac}'),
+        ('\u{1ea0}', '\u{306}', '\u{1eb6}'), ('\u{1ea1}', '\u{302}', '\u{1ead}'),
+        ('\u{1ea1}', '\u{306}', '\u{1eb7}'), ('\u{1eb8}', '\u{302}', '\u{1ec6}'),
+        ('\u{1eb9}', '\u{302}', '\u{1ec7}'), ('\u{1ecc}', '\u{302}', '\u{1ed8}'),
+        ('\u{1ecd}', '\u{302}', '\u{1ed9}'), ('\u{1f00}', '\u{300}', '\u{1f02}'),
+        ('\u{1f00}', '\u{301}', '\u{1f04}'), ('\u{1f00}', '\u{342}', '\u{1f06}'),
+        ('\u{1f00}', '\u{345}', '\u{1f80}'), ('\u{1f01}', '\u{300}', '\u{1f03}'),
+        ('\u{1f01}', '\u{301}', '\u{1f05}'), ('\u{1f01}', '\u{342}', '\u{1f07}'),
+        ('\u{1f01}', '\u{345}', '\u{1f81}'), ('\u{1f02}', '\u{345}', '\u{1f82}'),
+        ('\u{1f03}', '\u{345}', '\u{1f83}'), ('\u{1f04}', '\u{345}', '\u{1f84}'),
+        ('\u{1f05}', '\u{345}', '\u{1f85}'), ('\u{1f06}', '\u{345}', '\u{1f86}'),
+        ('\u{1f07}', '\u{345}', '\u{1f87}'), ('\u{1f08}', '\u{300}', '\u{1f0a}'),
+        ('\u{1f08}', '\u{301}', '\u{1f0c}'), ('\u{1f08}', '\u{342}', '\u{1f0e}'),
+        ('\u{1f08}', '\u{345}', '\u{1f88}'), ('\u{1f09}', '\u{300}', '\u{1f0b}'),
+        ('\u{1f09}', '\u{301}', '\u{1f0d}'), ('\u{1f09}', '\u{342}', '\u{1f0f}'),
+        ('\u{1f09}', '\u{345}', '\u{1f89}'), ('\u{1f0a}', '\u{345}', '\u{1f8a}'),
+        ('\u{1f0b}', '\u{345}', '\u{1f8b}'), ('\u{1f0c}', '\u{345}', '\u{1f8c}'),
+        ('\u{1f0d}', '\u{345}', '\u{1f8d}'), ('\u{1f0e}', '\u{345}', '\u{1f8e}'),
+        ('\u{1f0f}', '\u{345}', '\u{1f8f}'), ('\u{1f10}', '\u{300}', '\u{1f12}'),
+        ('\u{1f10}', '\u{301}', '\u{1f14}'), ('\u{1f11}', '\u{300}', '\u{1f13}'),
+        ('\u{1f11}', '\u{301}', '\u{1f15}'), ('\u{1f18}', '\u{300}', '\u{1f1a}'),
+        ('\u{1f18}', '\u{301}', '\u{1f1c}'), ('\u{1f19}', '\u{300}', '\u{1f1b}'),
+        ('\u{1f19}', '\u{301}', '\u{1f1d}'), ('\u{1f20}', '\u{300}', '\u{1f22}'),
+        ('\u{1f20}', '\u{301}', '\u{1f24}'), ('\u{1f20}', '\u{342}', '\u{1f26}'),
+        ('\u{1f20}', '\u{345}', '\u{1f90}'), ('\u{1f21}', '\u{300}', '\u{1f23}'),
+        ('\u{1f21}', '\u{301}', '\u{1f25}'), ('\u{1f21}', '\u{342}', '\u{1f27}'),
+        ('\u{1f21}', '\u{345}', '\u{1f91}'), ('\u{1f22}', '\u{345}', '\u{1f92}'),
+        ('\u{1f23}', '\u{345}', '\u{1f93}'), ('\u{1f24}', '\u{345}', '\u{1f94}'),
+        ('\u{1f25}', '\u{345}', '\u{1f95}'), ('\u{1f26}', '\u{345}', '\u{1f96}'),
+        ('\u{1f27}', '\u{345}', '\u{1f97}'), ('\u{1f28}', '\u{300}', '\u{1f2a}'),
+        ('\u{1f28}', '\u{301}', '\u{1f2c}'), ('\u{1f28}', '\u{342}', '\u{1f2e}'),
+        ('\u{1f28}', '\u{345}', '\u{1f98}'), ('\u{1f29}', '\u{300}', '\u{1f2b}'),
+        ('\u{1f29}', '\u{301}', '\u{1f2d}'), ('\u{1f29}', '\u{342}', '\u{1f2f}'),
+        ('\u{1f29}', '\u{345}', '\u{1f99}'), ('\u{1f2a}', '\u{345}', '\u{1f9a}'),
+        ('\u{1f2b}', '\u{345}', '\u{1f9b}'), ('\u{1f2c}', '\u{345}', '\u{1f9c}'),
+        ('\u{1f2d}', '\u{345}', '\u{1f9d}'), ('\u{1f2e}', '\u{345}', '\u{1f9e}'),
+        ('\u{1f2f}', '\u{345}', '\u{1f9f}'), ('\u{1f30}', '\u{300}', '\u{1f32}'),
+        ('\u{1f30}', '\u{301}', '\u{1f34}'), ('\u{1f30}', '\u{342}', '\u{1f36}'),
+        ('\u{1f31}', '\u{300}', '\u{1f33}'), ('\u{1f31}', '\u{301}', '\u{1f35}'),
+        ('\u{1f31}', '\u{342}', '\u{1f37}'), ('\u{1f38}', '\u{300}', '\u{1f3a}'),
+        ('\u{1f38}', '\u{301}', '\u{1f3c}'), ('\u{1f38}', '\u{342}', '\u{1f3e}'),
+        ('\u{1f39}', '\u{300}', '\u{1f3b}'), ('\u{1f39}', '\u{301}', '\u{1f3d}'),
+        ('\u{1f39}', '\u{342}', '\u{1f3f}'), ('\u{1f40}', '\u{300}', '\u{1f42}'),
+        ('\u{1f40}', '\u{301}', '\u{1f44}'), ('\u{1f41}', '\u{300}', '\u{1f43}'),
+        ('\u{1f41}', '\u{301}', '\u{1f45}'), ('\u{1f48}', '\u{300}', '\u{1f4a}'),
+        ('\u{1f48}', '\u{301}', '\u{1f4c}'), ('\u{1f49}', '\u{300}', '\u{1f4b}'),
+        ('\u{1f49}', '\u{301}', '\u{1f4d}'), ('\u{1f50}', '\u{300}', '\u{1f52}'),
+        ('\u{1f50}', '\u{301}', '\u{1f54}'), ('\u{1f50}', '\u{342}', '\u{1f56}'),
+        ('\u{1f51}', '\u{300}', '\u{1f53}'), ('\u{1f51}', '\u{301}', '\u{1f55}'),
+        ('\u{1f51}', '\u{342}', '\u{1f57}'), ('\u{1f59}', '\u{300}', '\u{1f5b}'),
+        ('\u{1f59}', '\u{301}', '\u{1f5d}'), ('\u{1f59}', '\u{342}', '\u{1f5f}'),
+        ('\u{1f60}', '\u{300}', '\u{1f62}'), ('\u{1f60}', '\u{301}', '\u{1f64}'),
+        ('\u{1f60}', '\u{342}', '\u{1f66}'), ('\u{1f60}', '\u{345}', '\u{1fa0}'),
+        ('\u{1f61}', '\u{300}', '\u{1f63}'), ('\u{1f61}', '\u{301}', '\u{1f65}'),
+        ('\u{1f61}', '\u{342}', '\u{1f67}'), ('\u{1f61}', '\u{345}', '\u{1fa1}'),
+        ('\u{1f62}', '\u{345}', '\u{1fa2}'), ('\u{1f63}', '\u{345}', '\u{1fa3}'),
+        ('\u{1f64}', '\u{345}', '\u{1fa4}'), ('\u{1f65}', '\u{345}', '\u{1fa5}'),
+        ('\u{1f66}', '\u{345}', '\u{1fa6}'), ('\u{1f67}', '\u{345}', '\u{1fa7}'),
+        ('\u{1f68}', '\u{300}', '\u{1f6a}'), ('\u{1f68}', '\u{301}', '\u{1f6c}'),
+        ('\u{1f68}', '\u{342}', '\u{1f6e}'), ('\u{1f68}', '\u{345}', '\u{1fa8}'),
+        ('\u{1f69}', '\u{300}', '\u{1f6b}'), ('\u{1f69}', '\u{301}', '\u{1f6d}'),
+        ('\u{1f69}', '\u{342}', '\u{1f6f}'), ('\u{1f69}', '\u{345}', '\u{1fa9}'),
+        ('\u{1f6a}', '\u{345}', '\u{1faa}'), ('\u{1f6b}', '\u{345}', '\u{1fab}'),
+        ('\u{1f6c}', '\u{345}', '\u{1fac}'), ('\u{1f6d}', '\u{345}', '\u{1fad}'),
+        ('\u{1f6e}', '\u{345}', '\u{1fae}'), ('\u{1f6f}', '\u{345}', '\u{1faf}'),
+        ('\u{1f70}', '\u{345}', '\u{1fb2}'), ('\u{1f74}', '\u{345}', '\u{1fc2}'),
+        ('\u{1f7c}', '\u{345}', '\u{1ff2}'), ('\u{1fb6}', '\u{345}', '\u{1fb7}'),
+        ('\u{1fbf}', '\u{300}', '\u{1fcd}'), ('\u{1fbf}', '\u{301}', '\u{1fce}'),
+        ('\u{1fbf}', '\u{342}', '\u{1fcf}'), ('\u{1fc6}', '\u{345}', '\u{1fc7}'),
+        ('\u{1ff6}', '\u{345}', '\u{1ff7}'), ('\u{1ffe}', '\u{300}', '\u{1fdd}'),
+        ('\u{1ffe}', '\u{301}', '\u{1fde}'), ('\u{1ffe}', '\u{342}', '\u{1fdf}'),
+        ('\u{2190}', '\u{338}', '\u{219a}'), ('\u{2192}', '\u{338}', '\u{219b}'),
+        ('\u{2194}', '\u{338}', '\u{21ae}'), ('\u{21d0}', '\u{338}', '\u{21cd}'),
+        ('\u{21d2}', '\u{338}', '\u{21cf}'), ('\u{21d4}', '\u{338}', '\u{21ce}'),
+        ('\u{2203}', '\u{338}', '\u{2204}'), ('\u{2208}', '\u{338}', '\u{2209}'),
+        ('\u{220b}', '\u{338}', '\u{220c}'), ('\u{2223}', '\u{338}', '\u{2224}'),
+        ('\u{2225}', '\u{338}', '\u{2226}'), ('\u{223c}', '\u{338}', '\u{2241}'),
+        ('\u{2243}', '\u{338}', '\u{2244}'), ('\u{2245}', '\u{338}', '\u{2247}'),
+        ('\u{2248}', '\u{338}', '\u{2249}'), ('\u{224d}', '\u{338}', '\u{226d}'),
+        ('\u{2261}', '\u{338}', '\u{2262}'), ('\u{2264}', '\u{338}', '\u{2270}'),
+        ('\u{2265}', '\u{338}', '\u{2271}'), ('\u{2272}', '\u{338}', '\u{2274}'),
+        ('\u{2273}', '\u{338}', '\u{2275}'), ('\u{2276}', '\u{338}', '\u{2278}'),
+        ('\u{2277}', '\u{338}', '\u{2279}'), ('\u{227a}', '\u{338}', '\u{2280}'),
+        ('\u{227b}', '\u{338}', '\u{2281}'), ('\u{227c}', '\u{338}', '\u{22e0}'),
+        ('\u{227d}', '\u{338}', '\u{22e1}'), ('\u{2282}', '\u{338}', '\u{2284}'),
+        ('\u{2283}', '\u{338}', '\u{2285}'), ('\u{2286}', '\u{338}', '\u{2288}'),
+        ('\u{2287}', '\u{338}', '\u{2289}'), ('\u{2291}', '\u{338}', '\u{22e2}'),
+        ('\u{2292}', '\u{338}', '\u{22e3}'), ('\u{22a2}', '\u{338}', '\u{22ac}'),
+        ('\u{22a8}', '\u{338}', '\u{22ad}'), ('\u{22a9}', '\u{338}', '\u{22ae}'),
+        ('\u{22ab}', '\u{338}', '\u{22af}'), ('\u{22b2}', '\u{338}', '\u{22ea}'),
+        ('\u{22b3}', '\u{338}', '\u{22eb}'), ('\u{22b4}', '\u{338}', '\u{22ec}'),
+        ('\u{22b5}', '\u{338}', '\u{22ed}'), ('\u{3046}', '\u{3099}', '\u{3094}'),
+        ('\u{304b}', '\u{3099}', '\u{304c}'), ('\u{304d}', '\u{3099}', '\u{304e}'),
+        ('\u{304f}', '\u{3099}', '\u{3050}'), ('\u{3051}', '\u{3099}', '\u{3052}'),
+        ('\u{3053}', '\u{3099}', '\u{3054}'), ('\u{3055}', '\u{3099}', '\u{3056}'),
+        ('\u{3057}', '\u{3099}', '\u{3058}'), ('\u{3059}', '\u{3099}', '\u{305a}'),
+        ('\u{305b}', '\u{3099}', '\u{305c}'), ('\u{305d}', '\u{3099}', '\u{305e}'),
+        ('\u{305f}', '\u{3099}', '\u{3060}'), ('\u{3061}', '\u{3099}', '\u{3062}'),
+        ('\u{3064}', '\u{3099}', '\u{3065}'), ('\u{3066}', '\u{3099}', '\u{3067}'),
+        ('\u{3068}', '\u{3099}', '\u{3069}'), ('\u{306f}', '\u{3099}', '\u{3070}'),
+        ('\u{306f}', '\u{309a}', '\u{3071}'), ('\u{3072}', '\u{3099}', '\u{3073}'),
+        ('\u{3072}', '\u{309a}', '\u{3074}'), ('\u{3075}', '\u{3099}', '\u{3076}'),
+        ('\u{3075}', '\u{309a}', '\u{3077}'), ('\u{3078}', '\u{3099}', '\u{3079}'),
+        ('\u{3078}', '\u{309a}', '\u{307a}'), ('\u{307b}', '\u{3099}', '\u{307c}'),
+        ('\u{307b}', '\u{309a}', '\u{307d}'), ('\u{309d}', '\u{3099}', '\u{309e}'),
+        ('\u{30a6}', '\u{3099}', '\u{30f4}'), ('\u{30ab}', '\u{3099}', '\u{30ac}'),
+        ('\u{30ad}', '\u{3099}', '\u{30ae}'), ('\u{30af}', '\u{3099}', '\u{30b0}'),
+        ('\u{30b1}', '\u{3099}', '\u{30b2}'), ('\u{30b3}', '\u{3099}', '\u{30b4}'),
+        ('\u{30b5}', '\u{3099}', '\u{30b6}'), ('\u{30b7}', '\u{3099}', '\u{30b8}'),
+        ('\u{30b9}', '\u{3099}', '\u{30ba}'), ('\u{30bb}', '\u{3099}', '\u{30bc}'),
+        ('\u{30bd}', '\u{3099}', '\u{30be}'), ('\u{30bf}', '\u{3099}', '\u{30c0}'),
+        ('\u{30c1}', '\u{3099}', '\u{30c2}'), ('\u{30c4}', '\u{3099}', '\u{30c5}'),
+        ('\u{30c6}', '\u{3099}', '\u{30c7}'), ('\u{30c8}', '\u{3099}', '\u{30c9}'),
+        ('\u{30cf}', '\u{3099}', '\u{30d0}'), ('\u{30cf}', '\u{309a}', '\u{30d1}'),
+        ('\u{30d2}', '\u{3099}', '\u{30d3}'), ('\u{30d2}', '\u{309a}', '\u{30d4}'),
+        ('\u{30d5}', '\u{3099}', '\u{30d6}'), ('\u{30d5}', '\u{309a}', '\u{30d7}'),
+        ('\u{30d8}', '\u{3099}', '\u{30d9}'), ('\u{30d8}', '\u{309a}', '\u{30da}'),
+        ('\u{30db}', '\u{3099}', '\u{30dc}'), ('\u{30db}', '\u{309a}', '\u{30dd}'),
+        ('\u{30ef}', '\u{3099}', '\u{30f7}'), ('\u{30f0}', '\u{3099}', '\u{30f8}'),
+        ('\u{30f1}', '\u{3099}', '\u{30f9}'), ('\u{30f2}', '\u{3099}', '\u{30fa}'),
+        ('\u{30fd}', '\u{3099}', '\u{30fe}'), ('\u{105d2}', '\u{307}', '\u{105c9}'),
+        ('\u{105da}', '\u{307}', '\u{105e4}'), ('\u{11099}', '\u{110ba}', '\u{1109a}'),
+        ('\u{1109b}', '\u{110ba}', '\u{1109c}'), ('\u{110a5}', '\u{110ba}', '\u{110ab}'),
+        ('\u{11131}', '\u{11127}', '\u{1112e}'), ('\u{11132}', '\u{11127}', '\u{1112f}'),
+        ('\u{11347}', '\u{1133e}', '\u{1134b}'), ('\u{11347}', '\u{11357}', '\u{1134c}'),
+        ('\u{11382}', '\u{113c9}', '\u{11383}'), ('\u{11384}', '\u{113bb}', '\u{11385}'),
+        ('\u{1138b}', '\u{113c2}', '\u{1138e}'), ('\u{11390}', '\u{113c9}', '\u{11391}'),
+        ('\u{113c2}', '\u{113b8}', '\u{113c7}'), ('\u{113c2}', '\u{113c2}', '\u{113c5}'),
+        ('\u{113c2}', '\u{113c9}', '\u{113c8}'), ('\u{114b9}', '\u{114b0}', '\u{114bc}'),
+        ('\u{114b9}', '\u{114ba}', '\u{114bb}'), ('\u{114b9}', '\u{114bd}', '\u{114be}'),
+        ('\u{115b8}', '\u{115af}', '\u{115ba}'), ('\u{115b9}', '\u{115af}', '\u{115bb}'),
+        ('\u{11935}', '\u{11930}', '\u{11938}'), ('\u{1611e}', '\u{1611e}', '\u{16121}'),
+        ('\u{1611e}', '\u{1611f}', '\u{16123}'), ('\u{1611e}', '\u{16120}', '\u{16125}'),
+        ('\u{1611e}', '\u{16129}', '\u{16122}'), ('\u{16121}', '\u{1611f}', '\u{16126}'),
+        ('\u{16121}', '\u{16120}', '\u{16128}'), ('\u{16122}', '\u{1611f}', '\u{16127}'),
+        ('\u{16129}', '\u{1611f}', '\u{16124}'), ('\u{16d63}', '\u{16d67}', '\u{16d69}'),
+        ('\u{16d67}', '\u{16d67}', '\u{16d68}'), ('\u{16d69}', '\u{16d67}', '\u{16d6a}'),
+    ];
+}
+
+#[rustfmt::skip]
+pub mod grapheme_cluster_break {
+    use GraphemeCat::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum GraphemeCat {
+        Other,
+        Cr,
+        Lf,
+        Control,
+        Extend,
+        Zwj,
+        RegionalIndicator,
+        Prepend,
+        SpacingMark,
+        L,
+        V,
+        T,
+        Lv,
+        Lvt,
+        ExtendedPictographic,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum IndicConjunctBreak {
+        None,
+        Linker,
+        Consonant,
+        Extend,
+    }
+
+    pub fn lookup(c: char) -> GraphemeCat {
+        // Hangul syllables are LV unless they have a trailing consonant.
+        let s_index = (c as u32).wrapping_sub(0xAC00);
+        if s_index < 11172 {
+            return if s_index % 28 == 0 { Lv } else { Lvt };
+        }
+        search(GRAPHEME_CAT_TABLE, c).unwrap_or(Other)
+    }
+
+    pub fn indic_conjunct_break(c: char) -> IndicConjunctBreak {
+        search(INCB_TABLE, c).unwrap_or(IndicConjunctBreak::None)
+    }
+
+    fn search<T: Copy>(table: &[(char, char, T)], c: char) -> Option<T> {
+        let i = table.partition_point(|&(_, end, _)| end < c);
+        match table.get(i) {
+            Some(&(start, _, value)) if start <= c => Some(value),
+            _ => None,
+        }
+    }
+
+    static GRAPHEME_CAT_TABLE: &[(char, char, GraphemeCat)] = &[
+        ('\u{0}', '\t', Control), ('\n', '\n', Lf), ('\u{b}', '\u{c}', Control), ('\r', '\r', Cr),
+        ('\u{e}', '\u{1f}', Control), ('\u{7f}', '\u{9f}', Control),
+        ('\u{a9}', '\u{a9}', ExtendedPictographic), ('\u{ad}', '\u{ad}', Control),
+        ('\u{ae}', '\u{ae}', ExtendedPictographic), ('\u{300}', '\u{36f}', Extend),
+        ('\u{483}', '\u{489}', Extend), ('\u{591}', '\u{5bd}', Extend),
+        ('\u{5bf}', '\u{5bf}', Extend), ('\u{5c1}', '\u{5c2}', Extend),
+        ('\u{5c4}', '\u{5c5}', Extend), ('\u{5c7}', '\u{5c7}', Extend),
+        ('\u{600}', '\u{605}', Prepend), ('\u{610}', '\u{61a}', Extend),
+        ('\u{61c}', '\u{61c}', Control), ('\u{64b}', '\u{65f}', Extend),
+        ('\u{670}', '\u{670}', Extend), ('\u{6d6}', '\u{6dc}', Extend),
+        ('\u{6dd}', '\u{6dd}', Prepend), ('\u{6df}', '\u{6e4}', Extend),
+        ('\u{6e7}', '\u{6e8}', Extend), ('\u{6ea}', '\u{6ed}', Extend),
+        ('\u{70f}', '\u{70f}', Prepend), ('\u{711}', '\u{711}', Extend),
+        ('\u{730}', '\u{74a}', Extend), ('\u{7a6}', '\u{7b0}', Extend),
+        ('\u{7eb}', '\u{7f3}', Extend), ('\u{7fd}', '\u{7fd}', Extend),
+        ('\u{816}', '\u{819}', Extend), ('\u{81b}', '\u{823}', Extend),
+        ('\u{825}', '\u{827}', Extend), ('\u{829}', '\u{82d}', Extend),
+        ('\u{859}', '\u{85b}', Extend), ('\u{890}', '\u{891}', Prepend),
+        ('\u{897}', '\u{89f}', Extend), ('\u{8ca}', '\u{8e1}', Extend),
+        ('\u{8e2}', '\u{8e2}', Prepend), ('\u{8e3}', '\u{902}', Extend),
+        ('\u{903}', '\u{903}', SpacingMark), ('\u{93a}', '\u{93a}', Extend),
+        ('\u{93b}', '\u{93b}', SpacingMark), ('\u{93c}', '\u{93c}', Extend),
+        ('\u{93e}', '\u{940}', SpacingMark), ('\u{941}', '\u{948}', Extend),
+        ('\u{949}', '\u{94c}', SpacingMark), ('\u{94d}', '\u{94d}', Extend),
+        ('\u{94e}', '\u{94f}', SpacingMark), ('\u{951}', '\u{957}', Extend),
+        ('\u{962}', '\u{963}', Extend), ('\u{981}', '\u{981}', Extend),
+        ('\u{982}', '\u{983}', SpacingMark), ('\u{9bc}', '\u{9bc}', Extend),
+        ('\u{9be}', '\u{9be}', Extend), ('\u{9bf}', '\u{9c0}', SpacingMark),
+        ('\u{9c1}', '\u{9c4}', Extend), ('\u{9c7}', '\u{9c8}', SpacingMark),
+        ('\u{9cb}', '\u{9cc}', SpacingMark), ('\u{9cd}', '\u{9cd}', Extend),
+        ('\u{9d7}', '\u{9d7}', Extend), ('\u{9e2}', '\u{9e3}', Extend),
+        ('\u{9fe}', '\u{9fe}', Extend), ('\u{a01}', '\u{a02}', Extend),
+        ('\u{a03}', '\u{a03}', SpacingMark), ('\u{a3c}', '\u{a3c}', Extend),
+        ('\u{a3e}', '\u{a40}', SpacingMark), ('\u{a41}', '\u{a42}', Extend),
+        ('\u{a47}', '\u{a48}', Extend), ('\u{a4b}', '\u{a4d}', Extend),
+        ('\u{a51}', '\u{a51}', Extend), ('\u{a70}', '\u{a71}', Extend),
+        ('\u{a75}', '\u{a75}', Extend), ('\u{a81}', '\u{a82}', Extend),
+        ('\u{a83}', '\u{a83}', SpacingMark), ('\u{abc}', '\u{abc}', Extend),
+        ('\u{abe}', '\u{ac0}', SpacingMark), ('\u{ac1}', '\u{ac5}', Extend),
+        ('\u{ac7}', '\u{ac8}', Extend), ('\u{ac9}', '\u{ac9}', SpacingMark),
+        ('\u{acb}', '\u{acc}', SpacingMark), ('\u{acd}', '\u{acd}', Extend),
+        ('\u{ae2}', '\u{ae3}', Extend), ('\u{afa}', '\u{aff}', Extend),
+        ('\u{b01}', '\u{b01}', Extend), ('\u{b02}', '\u{b03}', SpacingMark),
+        ('\u{b3c}', '\u{b3c}', Extend), ('\u{b3e}', '\u{b3f}', Extend),
+        ('\u{b40}', '\u{b40}', SpacingMark), ('\u{b41}', '\u{b44}', Extend),
+        ('\u{b47}', '\u{b48}', SpacingMark), ('\u{b4b}', '\u{b4c}', SpacingMark),
+        ('\u{b4d}', '\u{b4d}', Extend), ('\u{b55}', '\u{b57}', Extend),
+        ('\u{b62}', '\u{b63}', Extend), ('\u{b82}', '\u{b82}', Extend),
+        ('\u{bbe}', '\u{bbe}', Extend), ('\u{bbf}', '\u{bbf}', SpacingMark),
+        ('\u{bc0}', '\u{bc0}', Extend), ('\u{bc1}', '\u{bc2}', SpacingMark),
+        ('\u{bc6}', '\u{bc8}', SpacingMark), ('\u{bca}', '\u{bcc}', SpacingMark),
+        ('\u{bcd}', '\u{bcd}', Extend), ('\u{bd7}', '\u{bd7}', Extend),
+        ('\u{c00}', '\u{c00}', Extend), ('\u{c01}', '\u{c03}', SpacingMark),
+        ('\u{c04}', '\u{c04}', Extend), ('\u{c3c}', '\u{c3c}', Extend),
+        ('\u{c3e}', '\u{c40}', Extend), ('\u{c41}', '\u{c44}', SpacingMark),
+        ('\u{c46}', '\u{c48}', Extend), ('\u{c4a}', '\u{c4d}', Extend),
+        ('\u{c55}', '\u{c56}', Extend), ('\u{c62}', '\u{c63}', Extend),
+        ('\u{c81}', '\u{c81}', Extend), ('\u{c82}', '\u{c83}', SpacingMark),
+        ('\u{cbc}', '\u{cbc}', Extend), ('\u{cbe}', '\u{cbe}', SpacingMark),
+        ('\u{cbf}', '\u{cc0}', Extend), ('\u{cc1}', '\u{cc1}', SpacingMark),
+        ('\u{cc2}', '\u{cc2}', Extend), ('\u{cc3}', '\u{cc4}', SpacingMark),
+        ('\u{cc6}', '\u{cc8}', Extend), ('\u{cca}', '\u{ccd}', Extend),
+        ('\u{cd5}', '\u{cd6}', Extend), ('\u{ce2}', '\u{ce3}', Extend),
+        ('\u{cf3}', '\u{cf3}', SpacingMark), ('\u{d00}', '\u{d01}', Extend),
+        ('\u{d02}', '\u{d03}', SpacingMark), ('\u{d3b}', '\u{d3c}', Extend),
+        ('\u{d3e}', '\u{d3e}', Extend), ('\u{d3f}', '\u{d40}', SpacingMark),
+        ('\u{d41}', '\u{d44}', Extend), ('\u{d46}', '\u{d48}', SpacingMark),
+        ('\u{d4a}', '\u{d4c}', SpacingMark), ('\u{d4d}', '\u{d4d}', Extend),
+        ('\u{d4e}', '\u{d4e}', Prepend), ('\u{d57}', '\u{d57}', Extend),
+        ('\u{d62}', '\u{d63}', Extend), ('\u{d81}', '\u{d81}', Extend),
+        ('\u{d82}', '\u{d83}', SpacingMark), ('\u{dca}', '\u{dca}', Extend),
+        ('\u{dcf}', '\u{dcf}', Extend), ('\u{dd0}', '\u{dd1}', SpacingMark),
+        ('\u{dd2}', '\u{dd4}', Extend), ('\u{dd6}', '\u{dd6}', Extend),
+        ('\u{dd8}', '\u{dde}', SpacingMark), ('\u{ddf}', '\u{ddf}', Extend),
+        ('\u{df2}', '\u{df3}', SpacingMark), ('\u{e31}', '\u{e31}', Extend),
+        ('\u{e33}', '\u{e33}', SpacingMark), ('\u{e34}', '\u{e3a}', Extend),
+        ('\u{e47}', '\u{e4e}', Extend), ('\u{eb1}', '\u{eb1}', Extend),
+        ('\u{eb3}', '\u{eb3}', SpacingMark), ('\u{eb4}', '\u{ebc}', Extend),
+        ('\u{ec8}', '\u{ece}', Extend), ('\u{f18}', '\u{f19}', Extend),
+        ('\u{f35}', '\u{f35}', Extend), ('\u{f37}', '\u{f37}', Extend),
+        ('\u{f39}', '\u{f39}', Extend), ('\u{f3e}', '\u{f3f}', SpacingMark),
+        ('\u{f71}', '\u{f7e}', Extend), ('\u{f7f}', '\u{f7f}', SpacingMark),
+        ('\u{f80}', '\u{f84}', Extend), ('\u{f86}', '\u{f87}', Extend),
+        ('\u{f8d}', '\u{f97}', Extend), ('\u{f99}', '\u{fbc}', Extend),
+        ('\u{fc6}', '\u{fc6}', Extend), ('\u{102d}', '\u{1030}', Extend),
+        ('\u{1031}', '\u{1031}', SpacingMark), ('\u{1032}', '\u{1037}', Extend),
+        ('\u{1039}', '\u{103a}', Extend), ('\u{103b}', '\u{103c}', SpacingMark),
+        ('\u{103d}', '\u{103e}', Extend), ('\u{1056}', '\u{1057}', SpacingMark),
+        ('\u{1058}', '\u{1059}', Extend), ('\u{105e}', '\u{1060}', Extend),
+        ('\u{1071}', '\u{1074}', Extend), ('\u{1082}', '\u{1082}', Extend),
+        ('\u{1084}', '\u{1084}', SpacingMark), ('\u{1085}', '\u{1086}', Extend),
+        ('\u{108d}', '\u{108d}', Extend), ('\u{109d}', '\u{109d}', Extend),
+        ('\u{1100}', '\u{115f}', L), ('\u{1160}', '\u{11a7}', V), ('\u{11a8}', '\u{11ff}', T),
+        ('\u{135d}', '\u{135f}', Extend), ('\u{1712}', '\u{1715}', Extend),
+        ('\u{1732}', '\u{1734}', Extend), ('\u{1752}', '\u{1753}', Extend),
+        ('\u{1772}', '\u{1773}', Extend), ('\u{17b4}', '\u{17b5}', Extend),
+        ('\u{17b6}', '\u{17b6}', SpacingMark), ('\u{17b7}', '\u{17bd}', Extend),
+        ('\u{17be}', '\u{17c5}', SpacingMark), ('\u{17c6}', '\u{17c6}', Extend),
+        ('\u{17c7}', '\u{17c8}', SpacingMark), ('\u{17c9}', '\u{17d3}', Extend),
+        ('\u{17dd}', '\u{17dd}', Extend), ('\u{180b}', '\u{180d}', Extend),
+        ('\u{180e}', '\u{180e}', Control), ('\u{180f}', '\u{180f}', Extend),
+        ('\u{1885}', '\u{1886}', Extend), ('\u{18a9}', '\u{18a9}', Extend),
+        ('\u{1920}', '\u{1922}', Extend), ('\u{1923}', '\u{1926}', SpacingMark),
+        ('\u{1927}', '\u{1928}', Extend), ('\u{1929}', '\u{192b}', SpacingMark),
+        ('\u{1930}', '\u{1931}', SpacingMark), ('\u{1932}', '\u{1932}', Extend),
+        ('\u{1933}', '\u{1938}', SpacingMark), ('\u{1939}', '\u{193b}', Extend),
+        ('\u{1a17}', '\u{1a18}', Extend), ('\u{1a19}', '\u{1a1a}', SpacingMark),
+        ('\u{1a1b}', '\u{1a1b}', Extend), ('\u{1a55}', '\u{1a55}', SpacingMark),
+        ('\u{1a56}', '\u{1a56}', Extend), ('\u{1a57}', '\u{1a57}', SpacingMark),
+        ('\u{1a58}', '\u{1a5e}', Extend), ('\u{1a60}', '\u{1a60}', Extend),
+        ('\u{1a62}', '\u{1a62}', Extend), ('\u{1a65}', '\u{1a6c}', Extend),
+        ('\u{1a6d}', '\u{1a72}', SpacingMark), ('\u{1a73}', '\u{1a7c}', Extend),
+        ('\u{1a7f}', '\u{1a7f}', Extend), ('\u{1ab0}', '\u{1ace}', Extend),
+        ('\u{1b00}', '\u{1b03}', Extend), ('\u{1b04}', '\u{1b04}', SpacingMark),
+        ('\u{1b34}', '\u{1b3d}', Extend), ('\u{1b3e}', '\u{1b41}', SpacingMark),
+        ('\u{1b42}', '\u{1b44}', Extend), ('\u{1b6b}', '\u{1b73}', Extend),
+        ('\u{1b80}', '\u{1b81}', Extend), ('\u{1b82}', '\u{1b82}', SpacingMark),
+        ('\u{1ba1}', '\u{1ba1}', SpacingMark), ('\u{1ba2}', '\u{1ba5}', Extend),
+        ('\u{1ba6}', '\u{1ba7}', SpacingMark), ('\u{1ba8}', '\u{1bad}', Extend),
+        ('\u{1be6}', '\u{1be6}', Extend), ('\u{1be7}', '\u{1be7}', SpacingMark),
+        ('\u{1be8}', '\u{1be9}', Extend), ('\u{1bea}', '\u{1bec}', SpacingMark),
+        ('\u{1bed}', '\u{1bed}', Extend), ('\u{1bee}', '\u{1bee}', SpacingMark),
+        ('\u{1bef}', '\u{1bf3}', Extend), ('\u{1c24}', '\u{1c2b}', SpacingMark),
+        ('\u{1c2c}', '\u{1c33}', Extend), ('\u{1c34}', '\u{1c35}', SpacingMark),
+        ('\u{1c36}', '\u{1c37}', Extend), ('\u{1cd0}', '\u{1cd2}', Extend),
+        ('\u{1cd4}', '\u{1ce0}', Extend), ('\u{1ce1}', '\u{1ce1}', SpacingMark),
+        ('\u{1ce2}', '\u{1ce8}', Extend), ('\u{1ced}', '\u{1ced}', Extend),
+        ('\u{1cf4}', '\u{1cf4}', Extend), ('\u{1cf7}', '\u{1cf7}', SpacingMark),
+        ('\u{1cf8}', '\u{1cf9}', Extend), ('\u{1dc0}', '\u{1dff}', Extend),
+        ('\u{200b}', '\u{200b}', Control), ('\u{200c}', '\u{200c}', Extend),
+        ('\u{200d}', '\u{200d}', Zwj), ('\u{200e}', '\u{200f}', Control),
+        ('\u{2028}', '\u{202e}', Control), ('\u{203c}', '\u{203c}', ExtendedPictographic),
+        ('\u{2049}', '\u{2049}', ExtendedPictographic), ('\u{2060}', '\u{206f}', Control),
+        ('\u{20d0}', '\u{20f0}', Extend), ('\u{2122}', '\u{2122}', ExtendedPictographic),
+        ('\u{2139}', '\u{2139}', ExtendedPictographic),
+        ('\u{2194}', '\u{2199}', ExtendedPictographic),
+        ('\u{21a9}', '\u{21aa}', ExtendedPictographic),
+        ('\u{231a}', '\u{231b}', ExtendedPictographic),
+        ('\u{2328}', '\u{2328}', ExtendedPictographic),
+        ('\u{2388}', '\u{2388}', ExtendedPictographic),
+        ('\u{23cf}', '\u{23cf}', ExtendedPictographic),
+        ('\u{23e9}', '\u{23f3}', ExtendedPictographic),
+        ('\u{23f8}', '\u{23fa}', ExtendedPictographic),
+        ('\u{24c2}', '\u{24c2}', ExtendedPictographic),
+        ('\u{25aa}', '\u{25ab}', ExtendedPictographic),
+        ('\u{25b6}', '\u{25b6}', ExtendedPictographic),
+        ('\u{25c0}', '\u{25c0}', ExtendedPictographic),
+        ('\u{25fb}', '\u{25fe}', ExtendedPictographic),
+        ('\u{2600}', '\u{2605}', ExtendedPictographic),
+        ('\u{2607}', '\u{2612}', ExtendedPictographic),
+        ('\u{2614}', '\u{2685}', ExtendedPictographic),
+        ('\u{2690}', '\u{2705}', ExtendedPictographic),
+        ('\u{2708}', '\u{2712}', ExtendedPictographic),
+        ('\u{2714}', '\u{2714}', ExtendedPictographic),
+        ('\u{2716}', '\u{2716}', ExtendedPictographic),
+        ('\u{271d}', '\u{271d}', ExtendedPictographic),
+        ('\u{2721}', '\u{2721}', ExtendedPictographic),
+        ('\u{2728}', '\u{2728}', ExtendedPictographic),
+        ('\u{2733}', '\u{2734}', ExtendedPictographic),
+        ('\u{2744}', '\u{2744}', ExtendedPictographic),
+        ('\u{2747}', '\u{2747}', ExtendedPictographic),
+        ('\u{274c}', '\u{274c}', ExtendedPictographic),
+        ('\u{274e}', '\u{274e}', ExtendedPictographic),
+        ('\u{2753}', '\u{2755}', ExtendedPictographic),
+        ('\u{2757}', '\u{2757}', ExtendedPictographic),
+        ('\u{2763}', '\u{2767}', ExtendedPictographic),
+        ('\u{2795}', '\u{2797}', ExtendedPictographic),
+        ('\u{27a1}', '\u{27a1}', ExtendedPictographic),
+        ('\u{27b0}', '\u{27b0}', ExtendedPictographic),
+        ('\u{27bf}', '\u{27bf}', ExtendedPictographic),
+        ('\u{2934}', '\u{2935}', ExtendedPictographic),
+        ('\u{2b05}', '\u{2b07}', ExtendedPictographic),
+        ('\u{2b1b}', '\u{2b1c}', ExtendedPictographic),
+        ('\u{2b50}', '\u{2b50}', ExtendedPictographic),
+        ('\u{2b55}', '\u{2b55}', ExtendedPictographic), ('\u{2cef}', '\u{2cf1}', Extend),
+        ('\u{2d7f}', '\u{2d7f}', Extend), ('\u{2de0}', '\u{2dff}', Extend),
+        ('\u{302a}', '\u{302f}', Extend), ('\u{3030}', '\u{3030}', ExtendedPictographic),
+        ('\u{303d}', '\u{303d}', ExtendedPictographic), ('\u{3099}', '\u{309a}', Extend),
+        ('\u{3297}', '\u{3297}', ExtendedPictographic),
+        ('\u{3299}', '\u{3299}', ExtendedPictographic), ('\u{a66f}', '\u{a672}', Extend),
+        ('\u{a674}', '\u{a67d}', Extend), ('\u{a69e}', '\u{a69f}', Extend),
+        ('\u{a6f0}', '\u{a6f1}', Extend), ('\u{a802}', '\u{a802}', Extend),
+        ('\u{a806}', '\u{a806}', Extend), ('\u{a80b}', '\u{a80b}', Extend),
+        ('\u{a823}', '\u{a824}', SpacingMark), ('\u{a825}', '\u{a826}', Extend),
+        ('\u{a827}', '\u{a827}', SpacingMark), ('\u{a82c}', '\u{a82c}', Extend),
+        ('\u{a880}', '\u{a881}', SpacingMark), ('\u{a8b4}', '\u{a8c3}', SpacingMark),
+        ('\u{a8c4}', '\u{a8c5}', Extend), ('\u{a8e0}', '\u{a8f1}', Extend),
+        ('\u{a8ff}', '\u{a8ff}', Extend), ('\u{a926}', '\u{a92d}', Extend),
+        ('\u{a947}', '\u{a951}', Extend), ('\u{a952}', '\u{a952}', SpacingMark),
+        ('\u{a953}', '\u{a953}', Extend), ('\u{a960}', '\u{a97c}', L),
+        ('\u{a980}', '\u{a982}', Extend), ('\u{a983}', '\u{a983}', SpacingMark),
+        ('\u{a9b3}', '\u{a9b3}', Extend), ('\u{a9b4}', '\u{a9b5}', SpacingMark),
+        ('\u{a9b6}', '\u{a9b9}', Extend), ('\u{a9ba}', '\u{a9bb}', SpacingMark),
+        ('\u{a9bc}', '\u{a9bd}', Extend), ('\u{a9be}', '\u{a9bf}', SpacingMark),
+        ('\u{a9c0}', '\u{a9c0}', Extend), ('\u{a9e5}', '\u{a9e5}', Extend),
+        ('\u{aa29}', '\u{aa2e}', Extend), ('\u{aa2f}', '\u{aa30}', SpacingMark),
+        ('\u{aa31}', '\u{aa32}', Extend), ('\u{aa33}', '\u{aa34}', SpacingMark),
+        ('\u{aa35}', '\u{aa36}', Extend), ('\u{aa43}', '\u{aa43}', Extend),
+        ('\u{aa4c}', '\u{aa4c}', Extend), ('\u{aa4d}', '\u{aa4d}', SpacingMark),
+        ('\u{aa7c}', '\u{aa7c}', Extend), ('\u{aab0}', '\u{aab0}', Extend),
+        ('\u{aab2}', '\u{aab4}', Extend), ('\u{aab7}', '\u{aab8}', Extend),
+        ('\u{aabe}', '\u{aabf}', Extend), ('\u{aac1}', '\u{aac1}', Extend),
+        ('\u{aaeb}', '\u{aaeb}', SpacingMark), ('\u{aaec}', '\u{aaed}', Extend),
+        ('\u{aaee}', '\u{aaef}', SpacingMark), ('\u{aaf5}', '\u{aaf5}', SpacingMark),
+        ('\u{aaf6}', '\u{aaf6}', Extend), ('\u{abe3}', '\u{abe4}', SpacingMark),
+        ('\u{abe5}', '\u{abe5}', Extend), ('\u{abe6}', '\u{abe7}', SpacingMark),
+        ('\u{abe8}', '\u{abe8}', Extend), ('\u{abe9}', '\u{abea}', SpacingMark),
+        ('\u{abec}', '\u{abec}', SpacingMark), ('\u{abed}', '\u{abed}', Extend),
+        ('\u{d7b0}', '\u{d7c6}', V), ('\u{d7cb}', '\u{d7fb}', T), ('\u{fb1e}', '\u{fb1e}', Extend),
+        ('\u{fe00}', '\u{fe0f}', Extend), ('\u{fe20}', '\u{fe2f}', Extend),
+        ('\u{feff}', '\u{feff}', Control), ('\u{ff9e}', '\u{ff9f}', Extend),
+        ('\u{fff0}', '\u{fffb}', Control), ('\u{101fd}', '\u{101fd}', Extend),
+        ('\u{102e0}', '\u{102e0}', Extend), ('\u{10376}', '\u{1037a}', Extend),
+        ('\u{10a01}', '\u{10a03}', Extend), ('\u{10a05}', '\u{10a06}', Extend),
+        ('\u{10a0c}', '\u{10a0f}', Extend), ('\u{10a38}', '\u{10a3a}', Extend),
+        ('\u{10a3f}', '\u{10a3f}', Extend), ('\u{10ae5}', '\u{10ae6}', Extend),
+        ('\u{10d24}', '\u{10d27}', Extend), ('\u{10d69}', '\u{10d6d}', Extend),
+        ('\u{10eab}', '\u{10eac}', Extend), ('\u{10efc}', '\u{10eff}', Extend),
+        ('\u{10f46}', '\u{10f50}', Extend), ('\u{10f82}', '\u{10f85}', Extend),
+        ('\u{11000}', '\u{11000}', SpacingMark), ('\u{11001}', '\u{11001}', Extend),
+        ('\u{11002}', '\u{11002}', SpacingMark), ('\u{11038}', '\u{11046}', Extend),
+        ('\u{11070}', '\u{11070}', Extend), ('\u{11073}', '\u{11074}', Extend),
+        ('\u{1107f}', '\u{11081}', Extend), ('\u{11082}', '\u{11082}', SpacingMark),
+        ('\u{110b0}', '\u{110b2}', SpacingMark), ('\u{110b3}', '\u{110b6}', Extend),
+        ('\u{110b7}', '\u{110b8}', SpacingMark), ('\u{110b9}', '\u{110ba}', Extend),
+        ('\u{110bd}', '\u{110bd}', Prepend), ('\u{110c2}', '\u{110c2}', Extend),
+        ('\u{110cd}', '\u{110cd}', Prepend), ('\u{11100}', '\u{11102}', Extend),
+        ('\u{11127}', '\u{1112b}', Extend), ('\u{1112c}', '\u{1112c}', SpacingMark),
+        ('\u{1112d}', '\u{11134}', Extend), ('\u{11145}', '\u{11146}', SpacingMark),
+        ('\u{11173}', '\u{11173}', Extend), ('\u{11180}', '\u{11181}', Extend),
+        ('\u{11182}', '\u{11182}', SpacingMark), ('\u{111b3}', '\u{111b5}', SpacingMark),
+        ('\u{111b6}', '\u{111be}', Extend), ('\u{111bf}', '\u{111bf}', SpacingMark),
+        ('\u{111c0}', '\u{111c0}', Extend), ('\u{111c2}', '\u{111c3}', Prepend),
+        ('\u{111c9}', '\u{111cc}', Extend), ('\u{111ce}', '\u{111ce}', SpacingMark),
+        ('\u{111cf}', '\u{111cf}', Extend), ('\u{1122c}', '\u{1122e}', SpacingMark),
+        ('\u{1122f}', '\u{11231}', Extend), ('\u{11232}', '\u{11233}', SpacingMark),
+        ('\u{11234}', '\u{11237}', Extend), ('\u{1123e}', '\u{1123e}', Extend),
+        ('\u{11241}', '\u{11241}', Extend), ('\u{112df}', '\u{112df}', Extend),
+        ('\u{112e0}', '\u{112e2}', SpacingMark), ('\u{112e3}', '\u{112ea}', Extend),
+        ('\u{11300}', '\u{11301}', Extend), ('\u{11302}', '\u{11303}', SpacingMark),
+        ('\u{1133b}', '\u{1133c}', Extend), ('\u{1133e}', '\u{1133e}', Extend),
+        ('\u{1133f}', '\u{1133f}', SpacingMark), ('\u{11340}', '\u{11340}', Extend),
+        ('\u{11341}', '\u{11344}', SpacingMark), ('\u{11347}', '\u{11348}', SpacingMark),
+        ('\u{1134b}', '\u{1134c}', SpacingMark), ('\u{1134d}', '\u{1134d}', Extend),
+        ('\u{11357}', '\u{11357}', Extend), ('\u{11362}', '\u{11363}', SpacingMark),
+        ('\u{11366}', '\u{1136c}', Extend), ('\u{11370}', '\u{11374}', Extend),
+        ('\u{113b8}', '\u{113b8}', Extend), ('\u{113b9}', '\u{113ba}', SpacingMark),
+        ('\u{113bb}', '\u{113c0}', Extend), ('\u{113c2}', '\u{113c2}', Extend),
+        ('\u{113c5}', '\u{113c5}', Extend), ('\u{113c7}', '\u{113c9}', Extend),
+        ('\u{113ca}', '\u{113ca}', SpacingMark), ('\u{113cc}', '\u{113cd}', SpacingMark),
+        ('\u{113ce}', '\u{113d0}', Extend), ('\u{113d1}', '\u{113d1}', Prepend),
+        ('\u{113d2}', '\u{113d2}', Extend), ('\u{113e1}', '\u{113e2}', Extend),
+        ('\u{11435}', '\u{11437}', SpacingMark), ('\u{11438}', '\u{1143f}', Extend),
+        ('\u{11440}', '\u{11441}', SpacingMark), ('\u{11442}', '\u{11444}', Extend),
+        ('\u{11445}', '\u{11445}', SpacingMark), ('\u{11446}', '\u{11446}', Extend),
+        ('\u{1145e}', '\u{1145e}', Extend), ('\u{114b0}', '\u{114b0}', Extend),
+        ('\u{114b1}', '\u{114b2}', SpacingMark), ('\u{114b3}', '\u{114b8}', Extend),
+        ('\u{114b9}', '\u{114b9}', SpacingMark), ('\u{114ba}', '\u{114ba}', Extend),
+        ('\u{114bb}', '\u{114bc}', SpacingMark), ('\u{114bd}', '\u{114bd}', Extend),
+        ('\u{114be}', '\u{114be}', SpacingMark), ('\u{114bf}', '\u{114c0}', Extend),
+        ('\u{114c1}', '\u{114c1}', SpacingMark), ('\u{114c2}', '\u{114c3}', Extend),
+        ('\u{115af}', '\u{115af}', Extend), ('\u{115b0}', '\u{115b1}', SpacingMark),
+        ('\u{115b2}', '\u{115b5}', Extend), ('\u{115b8}', '\u{115bb}', SpacingMark),
+        ('\u{115bc}', '\u{115bd}', Extend), ('\u{115be}', '\u{115be}', SpacingMark),
+        ('\u{115bf}', '\u{115c0}', Extend), ('\u{115dc}', '\u{115dd}', Extend),
+        ('\u{11630}', '\u{11632}', SpacingMark), ('\u{11633}', '\u{1163a}', Extend),
+        ('\u{1163b}', '\u{1163c}', SpacingMark), ('\u{1163d}', '\u{1163d}', Extend),
+        ('\u{1163e}', '\u{1163e}', SpacingMark), ('\u{1163f}', '\u{11640}', Extend),
+        ('\u{116ab}', '\u{116ab}', Extend), ('\u{116ac}', '\u{116ac}', SpacingMark),
+        ('\u{116ad}', '\u{116ad}', Extend), ('\u{116ae}', '\u{116af}', SpacingMark),
+        ('\u{116b0}', '\u{116b7}', Extend), ('\u{1171d}', '\u{1171d}', Extend),
+        ('\u{1171e}', '\u{1171e}', SpacingMark), ('\u{1171f}', '\u{1171f}', Extend),
+        ('\u{11722}', '\u{11725}', Extend), ('\u{11726}', '\u{11726}', SpacingMark),
+        ('\u{11727}', '\u{1172b}', Extend), ('\u{1182c}', '\u{1182e}', SpacingMark),
+        ('\u{1182f}', '\u{11837}', Extend), ('\u{11838}', '\u{11838}', SpacingMark),
+        ('\u{11839}', '\u{1183a}', Extend), ('\u{11930}', '\u{11930}', Extend),
+        ('\u{11931}', '\u{11935}', SpacingMark), ('\u{11937}', '\u{11938}', SpacingMark),
+        ('\u{1193b}', '\u{1193e}', Extend), ('\u{1193f}', '\u{1193f}', Prepend),
+        ('\u{11940}', '\u{11940}', SpacingMark), ('\u{11941}', '\u{11941}', Prepend),
+        ('\u{11942}', '\u{11942}', SpacingMark), ('\u{11943}', '\u{11943}', Extend),
+        ('\u{119d1}', '\u{119d3}', SpacingMark), ('\u{119d4}', '\u{119d7}', Extend),
+        ('\u{119da}', '\u{119db}', Extend), ('\u{119dc}', '\u{119df}', SpacingMark),
+        ('\u{119e0}', '\u{119e0}', Extend), ('\u{119e4}', '\u{119e4}', SpacingMark),
+        ('\u{11a01}', '\u{11a0a}', Extend), ('\u{11a33}', '\u{11a38}', Extend),
+        ('\u{11a39}', '\u{11a39}', SpacingMark), ('\u{11a3a}', '\u{11a3a}', Prepend),
+        ('\u{11a3b}', '\u{11a3e}', Extend), ('\u{11a47}', '\u{11a47}', Extend),
+        ('\u{11a51}', '\u{11a56}', Extend), ('\u{11a57}', '\u{11a58}', SpacingMark),
+        ('\u{11a59}', '\u{11a5b}', Extend), ('\u{11a84}', '\u{11a89}', Prepend),
+        ('\u{11a8a}', '\u{11a96}', Extend), ('\u{11a97}', '\u{11a97}', SpacingMark),
+        ('\u{11a98}', '\u{11a99}', Extend), ('\u{11c2f}', '\u{11c2f}', SpacingMark),
+        ('\u{11c30}', '\u{11c36}', Extend), ('\u{11c38}', '\u{11c3d}', Extend),
+        ('\u{11c3e}', '\u{11c3e}', SpacingMark), ('\u{11c3f}', '\u{11c3f}', Extend),
+        ('\u{11c92}', '\u{11ca7}', Extend), ('\u{11ca9}', '\u{11ca9}', SpacingMark),
+        ('\u{11caa}', '\u{11cb0}', Extend), ('\u{11cb1}', '\u{11cb1}', SpacingMark),
+        ('\u{11cb2}', '\u{11cb3}', Extend), ('\u{11cb4}', '\u{11cb4}', SpacingMark),
+        ('\u{11cb5}', '\u{11cb6}', Extend), ('\u{11d31}', '\u{11d36}', Extend),
+        ('\u{11d3a}', '\u{11d3a}', Extend), ('\u{11d3c}', '\u{11d3d}', Extend),
+        ('\u{11d3f}', '\u{11d45}', Extend), ('\u{11d46}', '\u{11d46}', Prepend),
+        ('\u{11d47}', '\u{11d47}', Extend), ('\u{11d8a}', '\u{11d8e}', SpacingMark),
+        ('\u{11d90}', '\u{11d91}', Extend), ('\u{11d93}', '\u{11d94}', SpacingMark),
+        ('\u{11d95}', '\u{11d95}', Extend), ('\u{11d96}', '\u{11d96}', SpacingMark),
+        ('\u{11d97}', '\u{11d97}', Extend), ('\u{11ef3}', '\u{11ef4}', Extend),
+        ('\u{11ef5}', '\u{11ef6}', SpacingMark), ('\u{11f00}', '\u{11f01}', Extend),
+        ('\u{11f02}', '\u{11f02}', Prepend), ('\u{11f03}', '\u{11f03}', SpacingMark),
+        ('\u{11f34}', '\u{11f35}', SpacingMark), ('\u{11f36}', '\u{11f3a}', Extend),
+        ('\u{11f3e}', '\u{11f3f}', SpacingMark), ('\u{11f40}', '\u{11f42}', Extend),
+        ('\u{11f5a}', '\u{11f5a}', Extend), ('\u{13430}', '\u{1343f}', Control),
+        ('\u{13440}', '\u{13440}', Extend), ('\u{13447}', '\u{13455}', Extend),
+        ('\u{1611e}', '\u{16129}', Extend), ('\u{1612a}', '\u{1612c}', SpacingMark),
+        ('\u{1612d}', '\u{1612f}', Extend), ('\u{16af0}', '\u{16af4}', Extend),
+        ('\u{16b30}', '\u{16b36}', Extend), ('\u{16d63}', '\u{16d63}', V),
+        ('\u{16d67}', '\u{16d6a}', V), ('\u{16f4f}', '\u{16f4f}', Extend),
+        ('\u{16f51}', '\u{16f87}', SpacingMark), ('\u{16f8f}', '\u{16f92}', Extend),
+        ('\u{16fe4}', '\u{16fe4}', Extend), ('\u{16ff0}', '\u{16ff1}', Extend),
+        ('\u{1bc9d}', '\u{1bc9e}', Extend), ('\u{1bca0}', '\u{1bca3}', Control),
+        ('\u{1cf00}', '\u{1cf2d}', Extend), ('\u{1cf30}', '\u{1cf46}', Extend),
+        ('\u{1d165}', '\u{1d169}', Extend), ('\u{1d16d}', '\u{1d172}', Extend),
+        ('\u{1d173}', '\u{1d17a}', Control), ('\u{1d17b}', '\u{1d182}', Extend),
+        ('\u{1d185}', '\u{1d18b}', Extend), ('\u{1d1aa}', '\u{1d1ad}', Extend),
+        ('\u{1d242}', '\u{1d244}', Extend), ('\u{1da00}', '\u{1da36}', Extend),
+        ('\u{1da3b}', '\u{1da6c}', Extend), ('\u{1da75}', '\u{1da75}', Extend),
+        ('\u{1da84}', '\u{1da84}', Extend), ('\u{1da9b}', '\u{1da9f}', Extend),
+        ('\u{1daa1}', '\u{1daaf}', Extend), ('\u{1e000}', '\u{1e006}', Extend),
+        ('\u{1e008}', '\u{1e018}', Extend), ('\u{1e01b}', '\u{1e021}', Extend),
+        ('\u{1e023}', '\u{1e024}', Extend), ('\u{1e026}', '\u{1e02a}', Extend),
+        ('\u{1e08f}', '\u{1e08f}', Extend), ('\u{1e130}', '\u{1e136}', Extend),
+        ('\u{1e2ae}', '\u{1e2ae}', Extend), ('\u{1e2ec}', '\u{1e2ef}', Extend),
+        ('\u{1e4ec}', '\u{1e4ef}', Extend), ('\u{1e5ee}', '\u{1e5ef}', Extend),
+        ('\u{1e8d0}', '\u{1e8d6}', Extend), ('\u{1e944}', '\u{1e94a}', Extend),
+        ('\u{1f000}', '\u{1f0ff}', ExtendedPictographic),
+        ('\u{1f10d}', '\u{1f10f}', ExtendedPictographic),
+        ('\u{1f12f}', '\u{1f12f}', ExtendedPictographic),
+        ('\u{1f16c}', '\u{1f171}', ExtendedPictographic),
+        ('\u{1f17e}', '\u{1f17f}', ExtendedPictographic),
+        ('\u{1f18e}', '\u{1f18e}', ExtendedPictographic),
+        ('\u{1f191}', '\u{1f19a}', ExtendedPictographic),
+        ('\u{1f1ad}', '\u{1f1e5}', ExtendedPictographic),
+        ('\u{1f1e6}', '\u{1f1ff}', RegionalIndicator),
+        ('\u{1f201}', '\u{1f20f}', ExtendedPictographic),
+        ('\u{1f21a}', '\u{1f21a}', ExtendedPictographic),
+        ('\u{1f22f}', '\u{1f22f}', ExtendedPictographic),
+        ('\u{1f232}', '\u{1f23a}', ExtendedPictographic),
+        ('\u{1f23c}', '\u{1f23f}', ExtendedPictographic),
+        ('\u{1f249}', '\u{1f3fa}', ExtendedPictographic), ('\u{1f3fb}', '\u{1f3ff}', Extend),
+        ('\u{1f400}', '\u{1f53d}', ExtendedPictographic),
+        ('\u{1f546}', '\u{1f64f}', ExtendedPictographic),
+        ('\u{1f680}', '\u{1f6ff}', ExtendedPictographic),
+        ('\u{1f774}', '\u{1f77f}', ExtendedPictographic),
+        ('\u{1f7d5}', '\u{1f7ff}', ExtendedPictographic),
+        ('\u{1f80c}', '\u{1f80f}', ExtendedPictographic),
+        ('\u{1f848}', '\u{1f84f}', ExtendedPictographic),
+        ('\u{1f85a}', '\u{1f85f}', ExtendedPictographic),
+        ('\u{1f888}', '\u{1f88f}', ExtendedPictographic),
+        ('\u{1f8ae}', '\u{1f8ff}', ExtendedPictographic),
+        ('\u{1f90c}', '\u{1f93a}', ExtendedPictographic),
+        ('\u{1f93c}', '\u{1f945}', ExtendedPictographic),
+        ('\u{1f947}', '\u{1faff}', ExtendedPictographic),
+        ('\u{1fc00}', '\u{1fffd}', ExtendedPictographic), ('\u{e0000}', '\u{e001f}', Control),
+        ('\u{e0020}', '\u{e007f}', Extend), ('\u{e0080}', '\u{e00ff}', Control),
+        ('\u{e0100}', '\u{e01ef}', Extend), ('\u{e01f0}', '\u{e0fff}', Control),
+    ];
+
+    static INCB_TABLE: &[(char, char, IndicConjunctBreak)] = &[
+        ('\u{300}', '\u{36f}', IndicConjunctBreak::Extend),
+        ('\u{483}', '\u{489}', IndicConjunctBreak::Extend),
+        ('\u{591}', '\u{5bd}', IndicConjunctBreak::Extend),
+        ('\u{5bf}', '\u{5bf}', IndicConjunctBreak::Extend),
+        ('\u{5c1}', '\u{5c2}', IndicConjunctBreak::Extend),
+        ('\u{5c4}', '\u{5c5}', IndicConjunctBreak::Extend),
+        ('\u{5c7}', '\u{5c7}', IndicConjunctBreak::Extend),
+        ('\u{610}', '\u{61a}', IndicConjunctBreak::Extend),
+        ('\u{64b}', '\u{65f}', IndicConjunctBreak::Extend),
+        ('\u{670}', '\u{670}', IndicConjunctBreak::Extend),
+        ('\u{6d6}', '\u{6dc}', IndicConjunctBreak::Extend),
+        ('\u{6df}', '\u{6e4}', IndicConjunctBreak::Extend),
+        ('\u{6e7}', '\u{6e8}', IndicConjunctBreak::Extend),
+        ('\u{6ea}', '\u{6ed}', IndicConjunctBreak::Extend),
+        ('\u{711}', '\u{711}', IndicConjunctBreak::Extend),
+        ('\u{730}', '\u{74a}', IndicConjunctBreak::Extend),
+        ('\u{7a6}', '\u{7b0}', IndicConjunctBreak::Extend),
+        ('\u{7eb}', '\u{7f3}', IndicConjunctBreak::Extend),
+        ('\u{7fd}', '\u{7fd}', IndicConjunctBreak::Extend),
+        ('\u{816}', '\u{819}', IndicConjunctBreak::Extend),
+        ('\u{81b}', '\u{823}', IndicConjunctBreak::Extend),
+        ('\u{825}', '\u{827}', IndicConjunctBreak::Extend),
+        ('\u{829}', '\u{82d}', IndicConjunctBreak::Extend),
+        ('\u{859}', '\u{85b}', IndicConjunctBreak::Extend),
+        ('\u{897}', '\u{89f}', IndicConjunctBreak::Extend),
+        ('\u{8ca}', '\u{8e1}', IndicConjunctBreak::Extend),
+        ('\u{8e3}', '\u{902}', IndicConjunctBreak::Extend),
+        ('\u{915}', '\u{939}', IndicConjunctBreak::Consonant),
+        ('\u{93a}', '\u{93a}', IndicConjunctBreak::Extend),
+        ('\u{93c}', '\u{93c}', IndicConjunctBreak::Extend),
+        ('\u{941}', '\u{948}', IndicConjunctBreak::Extend),
+        ('\u{94d}', '\u{94d}', IndicConjunctBreak::Linker),
+        ('\u{951}', '\u{957}', IndicConjunctBreak::Extend),
+        ('\u{958}', '\u{95f}', IndicConjunctBreak::Consonant),
+        ('\u{962}', '\u{963}', IndicConjunctBreak::Extend),
+        ('\u{978}', '\u{97f}', IndicConjunctBreak::Consonant),
+        ('\u{981}', '\u{981}', IndicConjunctBreak::Extend),
+        ('\u{995}', '\u{9a8}', IndicConjunctBreak::Consonant),
+        ('\u{9aa}', '\u{9b0}', IndicConjunctBreak::Consonant),
+        ('\u{9b2}', '\u{9b2}', IndicConjunctBreak::Consonant),
+        ('\u{9b6}', '\u{9b9}', IndicConjunctBreak::Consonant),
+        ('\u{9bc}', '\u{9bc}', IndicConjunctBreak::Extend),
+        ('\u{9be}', '\u{9be}', IndicConjunctBreak::Extend),
+        ('\u{9c1}', '\u{9c4}', IndicConjunctBreak::Extend),
+        ('\u{9cd}', '\u{9cd}', IndicConjunctBreak::Linker),
+        ('\u{9d7}', '\u{9d7}', IndicConjunctBreak::Extend),
+        ('\u{9dc}', '\u{9dd}', IndicConjunctBreak::Consonant),
+        ('\u{9df}', '\u{9df}', IndicConjunctBreak::Consonant),
+        ('\u{9e2}', '\u{9e3}', IndicConjunctBreak::Extend),
+        ('\u{9f0}', '\u{9f1}', IndicConjunctBreak::Consonant),
+        ('\u{9fe}', '\u{9fe}', IndicConjunctBreak::Extend),
+        ('\u{a01}', '\u{a02}', IndicConjunctBreak::Extend),
+        ('\u{a3c}', '\u{a3c}', IndicConjunctBreak::Extend),
+        ('\u{a41}', '\u{a42}', IndicConjunctBreak::Extend),
+        ('\u{a47}', '\u{a48}', IndicConjunctBreak::Extend),
+        ('\u{a4b}', '\u{a4d}', IndicConjunctBreak::Extend),
+        ('\u{a51}', '\u{a51}', IndicConjunctBreak::Extend),
+        ('\u{a70}', '\u{a71}', IndicConjunctBreak::Extend),
+        ('\u{a75}', '\u{a75}', IndicConjunctBreak::Extend),
+        ('\u{a81}', '\u{a82}', IndicConjunctBreak::Extend),
+        ('\u{a95}', '\u{aa8}', IndicConjunctBreak::Consonant),
+        ('\u{aaa}', '\u{ab0}', IndicConjunctBreak::Consonant),
+        ('\u{ab2}', '\u{ab3}', IndicConjunctBreak::Consonant),
+        ('\u{ab5}', '\u{ab9}', IndicConjunctBreak::Consonant),
+        ('\u{abc}', '\u{abc}', IndicConjunctBreak::Extend),
+        ('\u{ac1}', '\u{ac5}', IndicConjunctBreak::Extend),
+        ('\u{ac7}', '\u{ac8}', IndicConjunctBreak::Extend),
+        ('\u{acd}', '\u{acd}', IndicConjunctBreak::Linker),
+        ('\u{ae2}', '\u{ae3}', IndicConjunctBreak::Extend),
+        ('\u{af9}', '\u{af9}', IndicConjunctBreak::Consonant),
+        ('\u{afa}', '\u{aff}', IndicConjunctBreak::Extend),
+        ('\u{b01}', '\u{b01}', IndicConjunctBreak::Extend),
+        ('\u{b15}', '\u{b28}', IndicConjunctBreak::Consonant),
+        ('\u{b2a}', '\u{b30}', IndicConjunctBreak::Consonant),
+        ('\u{b32}', '\u{b33}', IndicConjunctBreak::Consonant),
+        ('\u{b35}', '\u{b39}', IndicConjunctBreak::Consonant),
+        ('\u{b3c}', '\u{b3c}', IndicConjunctBreak::Extend),
+        ('\u{b3e}', '\u{b3f}', IndicConjunctBreak::Extend),
+        ('\u{b41}', '\u{b44}', IndicConjunctBreak::Extend),
+        ('\u{b4d}', '\u{b4d}', IndicConjunctBreak::Linker),
+        ('\u{b55}', '\u{b57}', IndicConjunctBreak::Extend),
+        ('\u{b5c}', '\u{b5d}', IndicConjunctBreak::Consonant),
+        ('\u{b5f}', '\u{b5f}', IndicConjunctBreak::Consonant),
+        ('\u{b62}', '\u{b63}', IndicConjunctBreak::Extend),
+        ('\u{b71}', '\u{b71}', IndicConjunctBreak::Consonant),
+        ('\u{b82}', '\u{b82}', IndicConjunctBreak::Extend),
+        ('\u{bbe}', '\u{bbe}', IndicConjunctBreak::Extend),
+        ('\u{bc0}', '\u{bc0}', IndicConjunctBreak::Extend),
+        ('\u{bcd}', '\u{bcd}', IndicConjunctBreak::Extend),
+        ('\u{bd7}', '\u{bd7}', IndicConjunctBreak::Extend),
+        ('\u{c00}', '\u{c00}', IndicConjunctBreak::Extend),
+        ('\u{c04}', '\u{c04}', IndicConjunctBreak::Extend),
+        ('\u{c15}', '\u{c28}', IndicConjunctBreak::Consonant),
+        ('\u{c2a}', '\u{c39}', IndicConjunctBreak::Consonant),
+        ('\u{c3c}', '\u{c3c}', IndicConjunctBreak::Extend),
+        ('\u{c3e}', '\u{c40}', IndicConjunctBreak::Extend),
+        ('\u{c46}', '\u{c48}', IndicConjunctBreak::Extend),
+        ('\u{c4a}', '\u{c4c}', IndicConjunctBreak::Extend),
+        ('\u{c4d}', '\u{c4d}', IndicConjunctBreak::Linker),
+        ('\u{c55}', '\u{c56}', IndicConjunctBreak::Extend),
+        ('\u{c58}', '\u{c5a}', IndicConjunctBreak::Consonant),
+        ('\u{c62}', '\u{c63}', IndicConjunctBreak::Extend),
+        ('\u{c81}', '\u{c81}', IndicConjunctBreak::Extend),
+        ('\u{cbc}', '\u{cbc}', IndicConjunctBreak::Extend),
+        ('\u{cbf}', '\u{cc0}', IndicConjunctBreak::Extend),
+        ('\u{cc2}', '\u{cc2}', IndicConjunctBreak::Extend),
+        ('\u{cc6}', '\u{cc8}', IndicConjunctBreak::Extend),
+        ('\u{cca}', '\u{ccd}', IndicConjunctBreak::Extend),
+        ('\u{cd5}', '\u{cd6}', IndicConjunctBreak::Extend),
+        ('\u{ce2}', '\u{ce3}', IndicConjunctBreak::Extend),
+        ('\u{d00}', '\u{d01}', IndicConjunctBreak::Extend),
+        ('\u{d15}', '\u{d3a}', IndicConjunctBreak::Consonant),
+        ('\u{d3b}', '\u{d3c}', IndicConjunctBreak::Extend),
+        ('\u{d3e}', '\u{d3e}', IndicConjunctBreak::Extend),
+        ('\u{d41}', '\u{d44}', IndicConjunctBreak::Extend),
+        ('\u{d4d}', '\u{d4d}', IndicConjunctBreak::Linker),
+        ('\u{d57}', '\u{d57}', IndicConjunctBreak::Extend),
+        ('\u{d62}', '\u{d63}', IndicConjunctBreak::Extend),
+        ('\u{d81}', '\u{d81}', IndicConjunctBreak::Extend),
+        ('\u{dca}', '\u{dca}', IndicConjunctBreak::Extend),
+        ('\u{dcf}', '\u{dcf}', IndicConjunctBreak::Extend),
+        ('\u{dd2}', '\u{dd4}', IndicConjunctBreak::Extend),
+        ('\u{dd6}', '\u{dd6}', IndicConjunctBreak::Extend),
+        ('\u{ddf}', '\u{ddf}', IndicConjunctBreak::Extend),
+        ('\u{e31}', '\u{e31}', IndicConjunctBreak::Extend),
+        ('\u{e34}', '\u{e3a}', IndicConjunctBreak::Extend),
+        ('\u{e47}', '\u{e4e}', IndicConjunctBreak::Extend),
+        ('\u{eb1}', '\u{eb1}', IndicConjunctBreak::Extend),
+        ('\u{eb4}', '\u{ebc}', IndicConjunctBreak::Extend),
+        ('\u{ec8}', '\u{ece}', IndicConjunctBreak::Extend),
+        ('\u{f18}', '\u{f19}', IndicConjunctBreak::Extend),
+        ('\u{f35}', '\u{f35}', IndicConjunctBreak::Extend),
+        ('\u{f37}', '\u{f37}', IndicConjunctBreak::Extend),
+        ('\u{f39}', '\u{f39}', IndicConjunctBreak::Extend),
+        ('\u{f71}', '\u{f7e}', IndicConjunctBreak::Extend),
+        ('\u{f80}', '\u{f84}', IndicConjunctBreak::Extend),
+        ('\u{f86}', '\u{f87}', IndicConjunctBreak::Extend),
+        ('\u{f8d}', '\u{f97}', IndicConjunctBreak::Extend),
+        ('\u{f99}', '\u{fbc}', IndicConjunctBreak::Extend),
+        ('\u{fc6}', '\u{fc6}', IndicConjunctBreak::Extend),
+        ('\u{102d}', '\u{1030}', IndicConjunctBreak::Extend),
+        ('\u{1032}', '\u{1037}', IndicConjunctBreak::Extend),
+        ('\u{1039}', '\u{103a}', IndicConjunctBreak::Extend),
+        ('\u{103d}', '\u{103e}', IndicConjunctBreak::Extend),
+        ('\u{1058}', '\u{1059}', IndicConjunctBreak::Extend),
+        ('\u{105e}', '\u{1060}', IndicConjunctBreak::Extend),
+        ('\u{1071}', '\u{1074}', IndicConjunctBreak::Extend),
+        ('\u{1082}', '\u{1082}', IndicConjunctBreak::Extend),
+        ('\u{1085}', '\u{1086}', IndicConjunctBreak::Extend),
+        ('\u{108d}', '\u{108d}', IndicConjunctBreak::Extend),
+        ('\u{109d}', '\u{109d}', IndicConjunctBreak::Extend),
+        ('\u{135d}', '\u{135f}', IndicConjunctBreak::Extend),
+        ('\u{1712}', '\u{1715}', IndicConjunctBreak::Extend),
+        ('\u{1732}', '\u{1734}', IndicConjunctBreak::Extend),
+        ('\u{1752}', '\u{1753}', IndicConjunctBreak::Extend),
+        ('\u{1772}', '\u{1773}', IndicConjunctBreak::Extend),
+        ('\u{17b4}', '\u{17b5}', IndicConjunctBreak::Extend),
+        ('\u{17b7}', '\u{17bd}', IndicConjunctBreak::Extend),
+        ('\u{17c6}', '\u{17c6}', IndicConjunctBreak::Extend),
+        ('\u{17c9}', '\u{17d3}', IndicConjunctBreak::Extend),
+        ('\u{17dd}', '\u{17dd}', IndicConjunctBreak::Extend),
+        ('\u{180b}', '\u{180d}', IndicConjunctBreak::Extend),
+        ('\u{180f}', '\u{180f}', IndicConjunctBreak::Extend),
+        ('\u{1885}', '\u{1886}', IndicConjunctBreak::Extend),
+        ('\u{18a9}', '\u{18a9}', IndicConjunctBreak::Extend),
+        ('\u{1920}', '\u{1922}', IndicConjunctBreak::Extend),
+        ('\u{1927}', '\u{1928}', IndicConjunctBreak::Extend),
+        ('\u{1932}', '\u{1932}', IndicConjunctBreak::Extend),
+        ('\u{1939}', '\u{193b}', IndicConjunctBreak::Extend),
+        ('\u{1a17}', '\u{1a18}', IndicConjunctBreak::Extend),
+        ('\u{1a1b}', '\u{1a1b}', IndicConjunctBreak::Extend),
+        ('\u{1a56}', '\u{1a56}', IndicConjunctBreak::Extend),
+        ('\u{1a58}', '\u{1a5e}', IndicConjunctBreak::Extend),
+        ('\u{1a60}', '\u{1a60}', IndicConjunctBreak::Extend),
+        ('\u{1a62}', '\u{1a62}', IndicConjunctBreak::Extend),
+        ('\u{1a65}', '\u{1a6c}', IndicConjunctBreak::Extend),
+        ('\u{1a73}', '\u{1a7c}', IndicConjunctBreak::Extend),
+        ('\u{1a7f}', '\u{1a7f}', IndicConjunctBreak::Extend),
+        ('\u{1ab0}', '\u{1ace}', IndicConjunctBreak::Extend),
+        ('\u{1b00}', '\u{1b03}', IndicConjunctBreak::Extend),
+        ('\u{1b34}', '\u{1b3d}', IndicConjunctBreak::Extend),
+        ('\u{1b42}', '\u{1b44}', IndicConjunctBreak::Extend),
+        ('\u{1b6b}', '\u{1b73}', IndicConjunctBreak::Extend),
+        ('\u{1b80}', '\u{1b81}', IndicConjunctBreak::Extend),
+        ('\u{1ba2}', '\u{1ba5}', IndicConjunctBreak::Extend),
+        ('\u{1ba8}', '\u{1bad}', IndicConjunctBreak::Extend),
+        ('\u{1be6}', '\u{1be6}', IndicConjunctBreak::Extend),
+        ('\u{1be8}', '\u{1be9}', IndicConjunctBreak::Extend),
+        ('\u{1bed}', '\u{1bed}', IndicConjunctBreak::Extend),
+        ('\u{1bef}', '\u{1bf3}', IndicConjunctBreak::Extend),
+        ('\u{1c2c}', '\u{1c33}', IndicConjunctBreak::Extend),
+        ('\u{1c36}', '\u{1c37}', IndicConjunctBreak::Extend),
+        ('\u{1cd0}', '\u{1cd2}', IndicConjunctBreak::Extend),
+        ('\u{1cd4}', '\u{1ce0}', IndicConjunctBreak::Extend),
+        ('\u{1ce2}', '\u{1ce8}', IndicConjunctBreak::Extend),
+        ('\u{1ced}', '\u{1ced}', IndicConjunctBreak::Extend),
+        ('\u{1cf4}', '\u{1cf4}', IndicConjunctBreak::Extend),
+        ('\u{1cf8}', '\u{1cf9}', IndicConjunctBreak::Extend),
+        ('\u{1dc0}', '\u{1dff}', IndicConjunctBreak::Extend),
+        ('\u{200d}', '\u{200d}', IndicConjunctBreak::Extend),
+        ('\u{20d0}', '\u{20f0}', IndicConjunctBreak::Extend),
+        ('\u{2cef}', '\u{2cf1}', IndicConjunctBreak::Extend),
+        ('\u{2d7f}', '\u{2d7f}', IndicConjunctBreak::Extend),
+        ('\u{2de0}', '\u{2dff}', IndicConjunctBreak::Extend),
+        ('\u{302a}', '\u{302f}', IndicConjunctBreak::Extend),
+        ('\u{3099}', '\u{309a}', IndicConjunctBreak::Extend),
+        ('\u{a66f}', '\u{a672}', IndicConjunctBreak::Extend),
+        ('\u{a674}', '\u{a67d}', IndicConjunctBreak::Extend),
+        ('\u{a69e}', '\u{a69f}', IndicConjunctBreak::Extend),
+        ('\u{a6f0}', '\u{a6f1}', IndicConjunctBreak::Extend),
+        ('\u{a802}', '\u{a802}', IndicConjunctBreak::Extend),
+        ('\u{a806}', '\u{a806}', IndicConjunctBreak::Extend),
+        ('\u{a80b}', '\u{a80b}', IndicConjunctBreak::Extend),
+        ('\u{a825}', '\u{a826}', IndicConjunctBreak::Extend),
+        ('\u{a82c}', '\u{a82c}', IndicConjunctBreak::Extend),
+        ('\u{a8c4}', '\u{a8c5}', IndicConjunctBreak::Extend),
+        ('\u{a8e0}', '\u{a8f1}', IndicConjunctBreak::Extend),
+        ('\u{a8ff}', '\u{a8ff}', IndicConjunctBreak::Extend),
+        ('\u{a926}', '\u{a92d}', IndicConjunctBreak::Extend),
+        ('\u{a947}', '\u{a951}', IndicConjunctBreak::Extend),
+        ('\u{a953}', '\u{a953}', IndicConjunctBreak::Extend),
+        ('\u{a980}', '\u{a982}', IndicConjunctBreak::Extend),
+        ('\u{a9b3}', '\u{a9b3}', IndicConjunctBreak::Extend),
+        ('\u{a9b6}', '\u{a9b9}', IndicConjunctBreak::Extend),
+        ('\u{a9bc}', '\u{a9bd}', IndicConjunctBreak::Extend),
+        ('\u{a9c0}', '\u{a9c0}', IndicConjunctBreak::Extend),
+        ('\u{a9e5}', '\u{a9e5}', IndicConjunctBreak::Extend),
+        ('\u{aa29}', '\u{aa2e}', IndicConjunctBreak::Extend),
+        ('\u{aa31}', '\u{aa32}', IndicConjunctBreak::Extend),
+        ('\u{aa35}', '\u{aa36}', IndicConjunctBreak::Extend),
+        ('\u{aa43}', '\u{aa43}', IndicConjunctBreak::Extend),
+        ('\u{aa4c}', '\u{aa4c}', IndicConjunctBreak::Extend),
+        ('\u{aa7c}', '\u{aa7c}', IndicConjunctBreak::Extend),
+        ('\u{aab0}', '\u{aab0}', IndicConjunctBreak::Extend),
+        ('\u{aab2}', '\u{aab4}', IndicConjunctBreak::Extend),
+        ('\u{aab7}', '\u{aab8}', IndicConjunctBreak::Extend),
+        ('\u{aabe}', '\u{aabf}', IndicConjunctBreak::Extend),
+        ('\u{aac1}', '\u{aac1}', IndicConjunctBreak::Extend),
+        ('\u{aaec}', '\u{aaed}', IndicConjunctBreak::Extend),
+        ('\u{aaf6}', '\u{aaf6}', IndicConjunctBreak::Extend),
+        ('\u{abe5}', '\u{abe5}', IndicConjunctBreak::Extend),
+        ('\u{abe8}', '\u{abe8}', IndicConjunctBreak::Extend),
+        ('\u{abed}', '\u{abed}', IndicConjunctBreak::Extend),
+        ('\u{fb1e}', '\u{fb1e}', IndicConjunctBreak::Extend),
+        ('\u{fe00}', '\u{fe0f}', IndicConjunctBreak::Extend),
+        ('\u{fe20}', '\u{fe2f}', IndicConjunctBreak::Extend),
+        ('\u{ff9e}', '\u{ff9f}', IndicConjunctBreak::Extend),
+        ('\u{101fd}', '\u{101fd}', IndicConjunctBreak::Extend),
+        ('\u{102e0}', '\u{102e0}', IndicConjunctBreak::Extend),
+        ('\u{10376}', '\u{1037a}', IndicConjunctBreak::Extend),
+        ('\u{10a01}', '\u{10a03}', IndicConjunctBreak::Extend),
+        ('\u{10a05}', '\u{10a06}', IndicConjunctBreak::Extend),
+        ('\u{10a0c}', '\u{10a0f}', IndicConjunctBreak::Extend),
+        ('\u{10a38}', '\u{10a3a}', IndicConjunctBreak::Extend),
+        ('\u{10a3f}', '\u{10a3f}', IndicConjunctBreak::Extend),
+        ('\u{10ae5}', '\u{10ae6}', IndicConjunctBreak::Extend),
+        ('\u{10d24}', '\u{10d27}', IndicConjunctBreak::Extend),
+        ('\u{10d69}', '\u{10d6d}', IndicConjunctBreak::Extend),
+        ('\u{10eab}', '\u{10eac}', IndicConjunctBreak::Extend),
+        ('\u{10efc}', '\u{10eff}', IndicConjunctBreak::Extend),
+        ('\u{10f46}', '\u{10f50}', IndicConjunctBreak::Extend),
+        ('\u{10f82}', '\u{10f85}', IndicConjunctBreak::Extend),
+        ('\u{11001}', '\u{11001}', IndicConjunctBreak::Extend),
+        ('\u{11038}', '\u{11046}', IndicConjunctBreak::Extend),
+        ('\u{11070}', '\u{11070}', IndicConjunctBreak::Extend),
+        ('\u{11073}', '\u{11074}', IndicConjunctBreak::Extend),
+        ('\u{1107f}', '\u{11081}', IndicConjunctBreak::Extend),
+        ('\u{110b3}', '\u{110b6}', IndicConjunctBreak::Extend),
+        ('\u{110b9}', '\u{110ba}', IndicConjunctBreak::Extend),
+        ('\u{110c2}', '\u{110c2}', IndicConjunctBreak::Extend),
+        ('\u{11100}', '\u{11102}', IndicConjunctBreak::Extend),
+        ('\u{11127}', '\u{1112b}', IndicConjunctBreak::Extend),
+        ('\u{1112d}', '\u{11134}', IndicConjunctBreak::Extend),
+        ('\u{11173}', '\u{11173}', IndicConjunctBreak::Extend),
+        ('\u{11180}', '\u{11181}', IndicConjunctBreak::Extend),
+        ('\u{111b6}', '\u{111be}', IndicConjunctBreak::Extend),
+        ('\u{111c0}', '\u{111c0}', IndicConjunctBreak::Extend),
+        ('\u{111c9}', '\u{111cc}', IndicConjunctBreak::Extend),
+        ('\u{111cf}', '\u{111cf}', IndicConjunctBreak::Extend),
+        ('\u{1122f}', '\u{11231}', IndicConjunctBreak::Extend),
+        ('\u{11234}', '\u{11237}', IndicConjunctBreak::Extend),
+        ('\u{1123e}', '\u{1123e}', IndicConjunctBreak::Extend),
+        ('\u{11241}', '\u{11241}', IndicConjunctBreak::Extend),
+        ('\u{112df}', '\u{112df}', IndicConjunctBreak::Extend),
+        ('\u{112e3}', '\u{112ea}', IndicConjunctBreak::Extend),
+        ('\u{11300}', '\u{11301}', IndicConjunctBreak::Extend),
+        ('\u{1133b}', '\u{1133c}', IndicConjunctBreak::Extend),
+        ('\u{1133e}', '\u{1133e}', IndicConjunctBreak::Extend),
+        ('\u{11340}', '\u{11340}', IndicConjunctBreak::Extend),
+        ('\u{1134d}', '\u{1134d}', IndicConjunctBreak::Extend),
+        ('\u{11357}', '\u{11357}', IndicConjunctBreak::Extend),
+        ('\u{11366}', '\u{1136c}', IndicConjunctBreak::Extend),
+        ('\u{11370}', '\u{11374}', IndicConjunctBreak::Extend),
+        ('\u{113b8}', '\u{113b8}', IndicConjunctBreak::Extend),
+        ('\u{113bb}', '\u{113c0}', IndicConjunctBreak::Extend),
+        ('\u{113c2}', '\u{113c2}', IndicConjunctBreak::Extend),
+        ('\u{113c5}', '\u{113c5}', IndicConjunctBreak::Extend),
+        ('\u{113c7}', '\u{113c9}', IndicConjunctBreak::Extend),
+        ('\u{113ce}', '\u{113d0}', IndicConjunctBreak::Extend),
+        ('\u{113d2}', '\u{113d2}', IndicConjunctBreak::Extend),
+        ('\u{113e1}', '\u{113e2}', IndicConjunctBreak::Extend),
+        ('\u{11438}', '\u{1143f}', IndicConjunctBreak::Extend),
+        ('\u{11442}', '\u{11444}', IndicConjunctBreak::Extend),
+        ('\u{11446}', '\u{11446}', IndicConjunctBreak::Extend),
+        ('\u{1145e}', '\u{1145e}', IndicConjunctBreak::Extend),
+        ('\u{114b0}', '\u{114b0}', IndicConjunctBreak::Extend),
+        ('\u{114b3}', '\u{114b8}', IndicConjunctBreak::Extend),
+        ('\u{114ba}', '\u{114ba}', IndicConjunctBreak::Extend),
+        ('\u{114bd}', '\u{114bd}', IndicConjunctBreak::Extend),
+        ('\u{114bf}', '\u{114c0}', IndicConjunctBreak::Extend),
+        ('\u{114c2}', '\u{114c3}', IndicConjunctBreak::Extend),
+        ('\u{115af}', '\u{115af}', IndicConjunctBreak::Extend),
+        ('\u{115b2}', '\u{115b5}', IndicConjunctBreak::Extend),
+        ('\u{115bc}', '\u{115bd}', IndicConjunctBreak::Extend),
+        ('\u{115bf}', '\u{115c0}', IndicConjunctBreak::Extend),
+        ('\u{115dc}', '\u{115dd}', IndicConjunctBreak::Extend),
+        ('\u{11633}', '\u{1163a}', IndicConjunctBreak::Extend),
+        ('\u{1163d}', '\u{1163d}', IndicConjunctBreak::Extend),
+        ('\u{1163f}', '\u{11640}', IndicConjunctBreak::Extend),
+        ('\u{116ab}', '\u{116ab}', IndicConjunctBreak::Extend),
+        ('\u{116ad}', '\u{116ad}', IndicConjunctBreak::Extend),
+        ('\u{116b0}', '\u{116b7}', IndicConjunctBreak::Extend),
+        ('\u{1171d}', '\u{1171d}', IndicConjunctBreak::Extend),
+        ('\u{1171f}', '\u{1171f}', IndicConjunctBreak::Extend),
+        ('\u{11722}', '\u{11725}', IndicConjunctBreak::Extend),
+        ('\u{11727}', '\u{1172b}', IndicConjunctBreak::Extend),
+        ('\u{1182f}', '\u{11837}', IndicConjunctBreak::Extend),
+        ('\u{11839}', '\u{1183a}', IndicConjunctBreak::Extend),
+        ('\u{11930}', '\u{11930}', IndicConjunctBreak::Extend),
+        ('\u{1193b}', '\u{1193e}', IndicConjunctBreak::Extend),
+        ('\u{11943}', '\u{11943}', IndicConjunctBreak::Extend),
+        ('\u{119d4}', '\u{119d7}', IndicConjunctBreak::Extend),
+        ('\u{119da}', '\u{119db}', IndicConjunctBreak::Extend),
+        ('\u{119e0}', '\u{119e0}', IndicConjunctBreak::Extend),
+        ('\u{11a01}', '\u{11a0a}', IndicConjunctBreak::Extend),
+        ('\u{11a33}', '\u{11a38}', IndicConjunctBreak::Extend),
+        ('\u{11a3b}', '\u{11a3e}', IndicConjunctBreak::Extend),
+        ('\u{11a47}', '\u{11a47}', IndicConjunctBreak::Extend),
+        ('\u{11a51}', '\u{11a56}', IndicConjunctBreak::Extend),
+        ('\u{11a59}', '\u{11a5b}', IndicConjunctBreak::Extend),
+        ('\u{11a8a}', '\u{11a96}', IndicConjunctBreak::Extend),
+        ('\u{11a98}', '\u{11a99}', IndicConjunctBreak::Extend),
+        ('\u{11c30}', '\u{11c36}', IndicConjunctBreak::Extend),
+        ('\u{11c38}', '\u{11c3d}', IndicConjunctBreak::Extend),
+        ('\u{11c3f}', '\u{11c3f}', IndicConjunctBreak::Extend),
+        ('\u{11c92}', '\u{11ca7}', IndicConjunctBreak::Extend),
+        ('\u{11caa}', '\u{11cb0}', IndicConjunctBreak::Extend),
+        ('\u{11cb2}', '\u{11cb3}', IndicConjunctBreak::Extend),
+        ('\u{11cb5}', '\u{11cb6}', IndicConjunctBreak::Extend),
+        ('\u{11d31}', '\u{11d36}', IndicConjunctBreak::Extend),
+        ('\u{11d3a}', '\u{11d3a}', IndicConjunctBreak::Extend),
+        ('\u{11d3c}', '\u{11d3d}', IndicConjunctBreak::Extend),
+        ('\u{11d3f}', '\u{11d45}', IndicConjunctBreak::Extend),
+        ('\u{11d47}', '\u{11d47}', IndicConjunctBreak::Extend),
+        ('\u{11d90}', '\u{11d91}', IndicConjunctBreak::Extend),
+        ('\u{11d95}', '\u{11d95}', IndicConjunctBreak::Extend),
+        ('\u{11d97}', '\u{11d97}', IndicConjunctBreak::Extend),
+        ('\u{11ef3}', '\u{11ef4}', IndicConjunctBreak::Extend),
+        ('\u{11f00}', '\u{11f01}', IndicConjunctBreak::Extend),
+        ('\u{11f36}', '\u{11f3a}', IndicConjunctBreak::Extend),
+        ('\u{11f40}', '\u{11f42}', IndicConjunctBreak::Extend),
+        ('\u{11f5a}', '\u{11f5a}', IndicConjunctBreak::Extend),
+        ('\u{13440}', '\u{13440}', IndicConjunctBreak::Extend),
+        ('\u{13447}', '\u{13455}', IndicConjunctBreak::Extend),
+        ('\u{1611e}', '\u{16129}', IndicConjunctBreak::Extend),
+        ('\u{1612d}', '\u{1612f}', IndicConjunctBreak::Extend),
+        ('\u{16af0}', '\u{16af4}', IndicConjunctBreak::Extend),
+        ('\u{16b30}', '\u{16b36}', IndicConjunctBreak::Extend),
+        ('\u{16f4f}', '\u{16f4f}', IndicConjunctBreak::Extend),
+        ('\u{16f8f}', '\u{16f92}', IndicConjunctBreak::Extend),
+        ('\u{16fe4}', '\u{16fe4}', IndicConjunctBreak::Extend),
+        ('\u{16ff0}', '\u{16ff1}', IndicConjunctBreak::Extend),
+        ('\u{1bc9d}', '\u{1bc9e}', IndicConjunctBreak::Extend),
+        ('\u{1cf00}', '\u{1cf2d}', IndicConjunctBreak::Extend),
+        ('\u{1cf30}', '\u{1cf46}', IndicConjunctBreak::Extend),
+        ('\u{1d165}', '\u{1d169}', IndicConjunctBreak::Extend),
+        ('\u{1d16d}', '\u{1d172}', IndicConjunctBreak::Extend),
+        ('\u{1d17b}', '\u{1d182}', IndicConjunctBreak::Extend),
+        ('\u{1d185}', '\u{1d18b}', IndicConjunctBreak::Extend),
+        ('\u{1d1aa}', '\u{1d1ad}', IndicConjunctBreak::Extend),
+        ('\u{1d242}', '\u{1d244}', IndicConjunctBreak::Extend),
+        ('\u{1da00}', '\u{1da36}', IndicConjunctBreak::Extend),
+        ('\u{1da3b}', '\u{1da6c}', IndicConjunctBreak::Extend),
+        ('\u{1da75}', '\u{1da75}', IndicConjunctBreak::Extend),
+        ('\u{1da84}', '\u{1da84}', IndicConjunctBreak::Extend),
+        ('\u{1da9b}', '\u{1da9f}', IndicConjunctBreak::Extend),
+        ('\u{1daa1}', '\u{1daaf}', IndicConjunctBreak::Extend),
+        ('\u{1e000}', '\u{1e006}', IndicConjunctBreak::Extend),
+        ('\u{1e008}', '\u{1e018}', IndicConjunctBreak::Extend),
+        ('\u{1e01b}', '\u{1e021}', IndicConjunctBreak::Extend),
+        ('\u{1e023}', '\u{1e024}', IndicConjunctBreak::Extend),
+        ('\u{1e026}', '\u{1e02a}', IndicConjunctBreak::Extend),
+        ('\u{1e08f}', '\u{1e08f}', IndicConjunctBreak::Extend),
+        ('\u{1e130}', '\u{1e136}', IndicConjunctBreak::Extend),
+        ('\u{1e2ae}', '\u{1e2ae}', IndicConjunctBreak::Extend),
+        ('\u{1e2ec}', '\u{1e2ef}', IndicConjunctBreak::Extend),
+        ('\u{1e4ec}', '\u{1e4ef}', IndicConjunctBreak::Extend),
+        ('\u{1e5ee}', '\u{1e5ef}', IndicConjunctBreak::Extend),
+        ('\u{1e8d0}', '\u{1e8d6}', IndicConjunctBreak::Extend),
+        ('\u{1e944}', '\u{1e94a}', IndicConjunctBreak::Extend),
+        ('\u{1f3fb}', '\u{1f3ff}', IndicConjunctBreak::Extend),
+        ('\u{e0020}', '\u{e007f}', IndicConjunctBreak::Extend),
+        ('\u{e0100}', '\u{e01ef}', IndicConjunctBreak::Extend),
+    ];
+}
+
+#[rustfmt::skip]
+pub mod word_break {
+    use WordCat::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum WordCat {
+        Other,
+        Cr,
+        Lf,
+        Newline,
+        Extend,
+        Zwj,
+        RegionalIndicator,
+        Format,
+        Katakana,
+        HebrewLetter,
+        ALetter,
+        SingleQuote,
+        DoubleQuote,
+        MidNumLet,
+        MidLetter,
+        MidNum,
+        Numeric,
+        ExtendNumLet,
+        WSegSpace,
+    }
+
+    pub fn lookup(c: char) -> WordCat {
+        let i = WORD_CAT_TABLE.partition_point(|&(_, end, _)| end < c);
+        match WORD_CAT_TABLE.get(i) {
+            Some(&(start, _, category)) if start <= c => category,
+            _ => Other,
+        }
+    }
+
+    static WORD_CAT_TABLE: &[(char, char, WordCat)] = &[
+        ('\n', '\n', Lf), ('\u{b}', '\u{c}', Newline), ('\r', '\r', Cr), (' ', ' ', WSegSpace),
+        ('\"', '\"', DoubleQuote), ('\'', '\'', SingleQuote), (',', ',', MidNum),
+        ('.', '.', MidNumLet), ('0', '9', Numeric), (':', ':', MidLetter), (';', ';', MidNum),
+        ('A', 'Z', ALetter), ('_', '_', ExtendNumLet), ('a', 'z', ALetter),
+        ('\u{85}', '\u{85}', Newline), ('\u{aa}', '\u{aa}', ALetter), ('\u{ad}', '\u{ad}', Format),
+        ('\u{b5}', '\u{b5}', ALetter), ('\u{b7}', '\u{b7}', MidLetter),
+        ('\u{ba}', '\u{ba}', ALetter), ('\u{c0}', '\u{d6}', ALetter), ('\u{d8}', '\u{f6}', ALetter),
+        ('\u{f8}', '\u{2d7}', ALetter), ('\u{2de}', '\u{2ff}', ALetter),
+        ('\u{300}', '\u{36f}', Extend), ('\u{370}', '\u{374}', ALetter),
+        ('\u{376}', '\u{377}', ALetter), ('\u{37a}', '\u{37d}', ALetter),
+        ('\u{37e}', '\u{37e}', MidNum), ('\u{37f}', '\u{37f}', ALetter),
+        ('\u{386}', '\u{386}', ALetter), ('\u{387}', '\u{387}', MidLetter),
+        ('\u{388}', '\u{38a}', ALetter), ('\u{38c}', '\u{38c}', ALetter),
+        ('\u{38e}', '\u{3a1}', ALetter), ('\u{3a3}', '\u{3f5}', ALetter),
+        ('\u{3f7}', '\u{481}', ALetter), ('\u{483}', '\u{489}', Extend),
+        ('\u{48a}', '\u{52f}', ALetter), ('\u{531}', '\u{556}', ALetter),
+        ('\u{559}', '\u{55c}', ALetter), ('\u{55e}', '\u{55e}', ALetter),
+        ('\u{55f}', '\u{55f}', MidLetter), ('\u{560}', '\u{588}', ALetter),
+        ('\u{589}', '\u{589}', MidNum), ('\u{58a}', '\u{58a}', ALetter),
+        ('\u{591}', '\u{5bd}', Extend), ('\u{5bf}', '\u{5bf}', Extend),
+        ('\u{5c1}', '\u{5c2}', Extend), ('\u{5c4}', '\u{5c5}', Extend),
+        ('\u{5c7}', '\u{5c7}', Extend), ('\u{5d0}', '\u{5ea}', HebrewLetter),
+        ('\u{5ef}', '\u{5f2}', HebrewLetter), ('\u{5f3}', '\u{5f3}', ALetter),
+        ('\u{5f4}', '\u{5f4}', MidLetter), ('\u{600}', '\u{605}', Numeric),
+        ('\u{60c}', '\u{60d}', MidNum), ('\u{610}', '\u{61a}', Extend),
+        ('\u{61c}', '\u{61c}', Format), ('\u{620}', '\u{64a}', ALetter),
+        ('\u{64b}', '\u{65f}', Extend), ('\u{660}', '\u{669}', Numeric),
+        ('\u{66b}', '\u{66b}', Numeric), ('\u{66c}', '\u{66c}', MidNum),
+        ('\u{66e}', '\u{66f}', ALetter), ('\u{670}', '\u{670}', Extend),
+        ('\u{671}', '\u{6d3}', ALetter), ('\u{6d5}', '\u{6d5}', ALetter),
+        ('\u{6d6}', '\u{6dc}', Extend), ('\u{6dd}', '\u{6dd}', Numeric),
+        ('\u{6df}', '\u{6e4}', Extend), ('\u{6e5}', '\u{6e6}', ALetter),
+        ('\u{6e7}', '\u{6e8}', Extend), ('\u{6ea}', '\u{6ed}', Extend),
+        ('\u{6ee}', '\u{6ef}', ALetter), ('\u{6f0}', '\u{6f9}', Numeric),
+        ('\u{6fa}', '\u{6fc}', ALetter), ('\u{6ff}', '\u{6ff}', ALetter),
+        ('\u{70f}', '\u{710}', ALetter), ('\u{711}', '\u{711}', Extend),
+        ('\u{712}', '\u{72f}', ALetter), ('\u{730}', '\u{74a}', Extend),
+        ('\u{74d}', '\u{7a5}', ALetter), ('\u{7a6}', '\u{7b0}', Extend),
+        ('\u{7b1}', '\u{7b1}', ALetter), ('\u{7c0}', '\u{7c9}', Numeric),
+        ('\u{7ca}', '\u{7ea}', ALetter), ('\u{7eb}', '\u{7f3}', Extend),
+        ('\u{7f4}', '\u{7f5}', ALetter), ('\u{7f8}', '\u{7f8}', MidNum),
+        ('\u{7fa}', '\u{7fa}', ALetter), ('\u{7fd}', '\u{7fd}', Extend),
+        ('\u{800}', '\u{815}', ALetter), ('\u{816}', '\u{819}', Extend),
+        ('\u{81a}', '\u{81a}', ALetter), ('\u{81b}', '\u{823}', Extend),
+        ('\u{824}', '\u{824}', ALetter), ('\u{825}', '\u{827}', Extend),
+        ('\u{828}', '\u{828}', ALetter), ('\u{829}', '\u{82d}', Extend),
+        ('\u{840}', '\u{858}', ALetter), ('\u{859}', '\u{85b}', Extend),
+        ('\u{860}', '\u{86a}', ALetter), ('\u{870}', '\u{887}', ALetter),
+        ('\u{889}', '\u{88e}', ALetter), ('\u{890}', '\u{891}', Numeric),
+        ('\u{897}', '\u{89f}', Extend), ('\u{8a0}', '\u{8c9}', ALetter),
+        ('\u{8ca}', '\u{8e1}', Extend), ('\u{8e2}', '\u{8e2}', Numeric),
+        ('\u{8e3}', '\u{903}', Extend), ('\u{904}', '\u{939}', ALetter),
+        ('\u{93a}', '\u{93c}', Extend), ('\u{93d}', '\u{93d}', ALetter),
+        ('\u{93e}', '\u{94f}', Extend), ('\u{950}', '\u{950}', ALetter),
+        ('\u{951}', '\u{957}', Extend), ('\u{958}', '\u{961}', ALetter),
+        ('\u{962}', '\u{963}', Extend), ('\u{966}', '\u{96f}', Numeric),
+        ('\u{971}', '\u{980}', ALetter), ('\u{981}', '\u{983}', Extend),
+        ('\u{985}', '\u{98c}', ALetter), ('\u{98f}', '\u{990}', ALetter),
+        ('\u{993}', '\u{9a8}', ALetter), ('\u{9aa}', '\u{9b0}', ALetter),
+        ('\u{9b2}', '\u{9b2}', ALetter), ('\u{9b6}', '\u{9b9}', ALetter),
+        ('\u{9bc}', '\u{9bc}', Extend), ('\u{9bd}', '\u{9bd}', ALetter),
+        ('\u{9be}', '\u{9c4}', Extend), ('\u{9c7}', '\u{9c8}', Extend),
+        ('\u{9cb}', '\u{9cd}', Extend), ('\u{9ce}', '\u{9ce}', ALetter),
+        ('\u{9d7}', '\u{9d7}', Extend), ('\u{9dc}', '\u{9dd}', ALetter),
+        ('\u{9df}', '\u{9e1}', ALetter), ('\u{9e2}', '\u{9e3}', Extend),
+        ('\u{9e6}', '\u{9ef}', Numeric), ('\u{9f0}', '\u{9f1}', ALetter),
+        ('\u{9fc}', '\u{9fc}', ALetter), ('\u{9fe}', '\u{9fe}', Extend),
+        ('\u{a01}', '\u{a03}', Extend), ('\u{a05}', '\u{a0a}', ALetter),
+        ('\u{a0f}', '\u{a10}', ALetter), ('\u{a13}', '\u{a28}', ALetter),
+        ('\u{a2a}', '\u{a30}', ALetter), ('\u{a32}', '\u{a33}', ALetter),
+        ('\u{a35}', '\u{a36}', ALetter), ('\u{a38}', '\u{a39}', ALetter),
+        ('\u{a3c}', '\u{a3c}', Extend), ('\u{a3e}', '\u{a42}', Extend),
+        ('\u{a47}', '\u{a48}', Extend), ('\u{a4b}', '\u{a4d}', Extend),
+        ('\u{a51}', '\u{a51}', Extend), ('\u{a59}', '\u{a5c}', ALetter),
+        ('\u{a5e}', '\u{a5e}', ALetter), ('\u{a66}', '\u{a6f}', Numeric),
+        ('\u{a70}', '\u{a71}', Extend), ('\u{a72}', '\u{a74}', ALetter),
+        ('\u{a75}', '\u{a75}', Extend), ('\u{a81}', '\u{a83}', Extend),
+        ('\u{a85}', '\u{a8d}', ALetter), ('\u{a8f}', '\u{a91}', ALetter),
+        ('\u{a93}', '\u{aa8}', ALetter), ('\u{aaa}', '\u{ab0}', ALetter),
+        ('\u{ab2}', '\u{ab3}', ALetter), ('\u{ab5}', '\u{ab9}', ALetter),
+        ('\u{abc}', '\u{abc}', Extend), ('\u{abd}', '\u{abd}', ALetter),
+        ('\u{abe}', '\u{ac5}', Extend), ('\u{ac7}', '\u{ac9}', Extend),
+        ('\u{acb}', '\u{acd}', Extend), ('\u{ad0}', '\u{ad0}', ALetter),
+        ('\u{ae0}', '\u{ae1}', ALetter), ('\u{ae2}', '\u{ae3}', Extend),
+        ('\u{ae6}', '\u{aef}', Numeric), ('\u{af9}', '\u{af9}', ALetter),
+        ('\u{afa}', '\u{aff}', Extend), ('\u{b01}', '\u{b03}', Extend),
+        ('\u{b05}', '\u{b0c}', ALetter), ('\u{b0f}', '\u{b10}', ALetter),
+        ('\u{b13}', '\u{b28}', ALetter), ('\u{b2a}', '\u{b30}', ALetter),
+        ('\u{b32}', '\u{b33}', ALetter), ('\u{b35}', '\u{b39}', ALetter),
+        ('\u{b3c}', '\u{b3c}', Extend), ('\u{b3d}', '\u{b3d}', ALetter),
+        ('\u{b3e}', '\u{b44}', Extend), ('\u{b47}', '\u{b48}', Extend),
+        ('\u{b4b}', '\u{b4d}', Extend), ('\u{b55}', '\u{b57}', Extend),
+        ('\u{b5c}', '\u{b5d}', ALetter), ('\u{b5f}', '\u{b61}', ALetter),
+        ('\u{b62}', '\u{b63}', Extend), ('\u{b66}', '\u{b6f}', Numeric),
+        ('\u{b71}', '\u{b71}', ALetter), ('\u{b82}', '\u{b82}', Extend),
+        ('\u{b83}', '\u{b83}', ALetter), ('\u{b85}', '\u{b8a}', ALetter),
+        ('\u{b8e}', '\u{b90}', ALetter), ('\u{b92}', '\u{b95}', ALetter),
+        ('\u{b99}', '\u{b9a}', ALetter), ('\u{b9c}', '\u{b9c}', ALetter),
+        ('\u{b9e}', '\u{b9f}', ALetter), ('\u{ba3}', '\u{ba4}', ALetter),
+        ('\u{ba8}', '\u{baa}', ALetter), ('\u{bae}', '\u{bb9}', ALetter),
+        ('\u{bbe}', '\u{bc2}', Extend), ('\u{bc6}', '\u{bc8}', Extend),
+        ('\u{bca}', '\u{bcd}', Extend), ('\u{bd0}', '\u{bd0}', ALetter),
+        ('\u{bd7}', '\u{bd7}', Extend), ('\u{be6}', '\u{bef}', Numeric),
+        ('\u{c00}', '\u{c04}', Extend), ('\u{c05}', '\u{c0c}', ALetter),
+        ('\u{c0e}', '\u{c10}', ALetter), ('\u{c12}', '\u{c28}', ALetter),
+        ('\u{c2a}', '\u{c39}', ALetter), ('\u{c3c}', '\u{c3c}', Extend),
+        ('\u{c3d}', '\u{c3d}', ALetter), ('\u{c3e}', '\u{c44}', Extend),
+        ('\u{c46}', '\u{c48}', Extend), ('\u{c4a}', '\u{c4d}', Extend),
+        ('\u{c55}', '\u{c56}', Extend), ('\u{c58}', '\u{c5a}', ALetter),
+        ('\u{c5d}', '\u{c5d}', ALetter), ('\u{c60}', '\u{c61}', ALetter),
+        ('\u{c62}', '\u{c63}', Extend), ('\u{c66}', '\u{c6f}', Numeric),
+        ('\u{c80}', '\u{c80}', ALetter), ('\u{c81}', '\u{c83}', Extend),
+        ('\u{c85}', '\u{c8c}', ALetter), ('\u{c8e}', '\u{c90}', ALetter),
+        ('\u{c92}', '\u{ca8}', ALetter), ('\u{caa}', '\u{cb3}', ALetter),
+        ('\u{cb5}', '\u{cb9}', ALetter), ('\u{cbc}', '\u{cbc}', Extend),
+        ('\u{cbd}', '\u{cbd}', ALetter), ('\u{cbe}', '\u{cc4}', Extend),
+        ('\u{cc6}', '\u{cc8}', Extend), ('\u{cca}', '\u{ccd}', Extend),
+        ('\u{cd5}', '\u{cd6}', Extend), ('\u{cdd}', '\u{cde}', ALetter),
+        ('\u{ce0}', '\u{ce1}', ALetter), ('\u{ce2}', '\u{ce3}', Extend),
+        ('\u{ce6}', '\u{cef}', Numeric), ('\u{cf1}', '\u{cf2}', ALetter),
+        ('\u{cf3}', '\u{cf3}', Extend), ('\u{d00}', '\u{d03}', Extend),
+        ('\u{d04}', '\u{d0c}', ALetter), ('\u{d0e}', '\u{d10}', ALetter),
+        ('\u{d12}', '\u{d3a}', ALetter), ('\u{d3b}', '\u{d3c}', Extend),
+        ('\u{d3d}', '\u{d3d}', ALetter), ('\u{d3e}', '\u{d44}', Extend),
+        ('\u{d46}', '\u{d48}', Extend), ('\u{d4a}', '\u{d4d}', Extend),
+        ('\u{d4e}', '\u{d4e}', ALetter), ('\u{d54}', '\u{d56}', ALetter),
+        ('\u{d57}', '\u{d57}', Extend), ('\u{d5f}', '\u{d61}', ALetter),
+        ('\u{d62}', '\u{d63}', Extend), ('\u{d66}', '\u{d6f}', Numeric),
+        ('\u{d7a}', '\u{d7f}', ALetter), ('\u{d81}', '\u{d83}', Extend),
+        ('\u{d85}', '\u{d96}', ALetter), ('\u{d9a}', '\u{db1}', ALetter),
+        ('\u{db3}', '\u{dbb}', ALetter), ('\u{dbd}', '\u{dbd}', ALetter),
+        ('\u{dc0}', '\u{dc6}', ALetter), ('\u{dca}', '\u{dca}', Extend),
+        ('\u{dcf}', '\u{dd4}', Extend), ('\u{dd6}', '\u{dd6}', Extend),
+        ('\u{dd8}', '\u{ddf}', Extend), ('\u{de6}', '\u{def}', Numeric),
+        ('\u{df2}', '\u{df3}', Extend), ('\u{e31}', '\u{e31}', Extend),
+        ('\u{e34}', '\u{e3a}', Extend), ('\u{e47}', '\u{e4e}', Extend),
+        ('\u{e50}', '\u{e59}', Numeric), ('\u{eb1}', '\u{eb1}', Extend),
+        ('\u{eb4}', '\u{ebc}', Extend), ('\u{ec8}', '\u{ece}', Extend),
+        ('\u{ed0}', '\u{ed9}', Numeric), ('\u{f00}', '\u{f00}', ALetter),
+        ('\u{f18}', '\u{f19}', Extend), ('\u{f20}', '\u{f29}', Numeric),
+        ('\u{f35}', '\u{f35}', Extend), ('\u{f37}', '\u{f37}', Extend),
+        ('\u{f39}', '\u{f39}', Extend), ('\u{f3e}', '\u{f3f}', Extend),
+        ('\u{f40}', '\u{f47}', ALetter), ('\u{f49}', '\u{f6c}', ALetter),
+        ('\u{f71}', '\u{f84}', Extend), ('\u{f86}', '\u{f87}', Extend),
+        ('\u{f88}', '\u{f8c}', ALetter), ('\u{f8d}', '\u{f97}', Extend),
+        ('\u{f99}', '\u{fbc}', Extend), ('\u{fc6}', '\u{fc6}', Extend),
+        ('\u{102b}', '\u{103e}', Extend), ('\u{1040}', '\u{1049}', Numeric),
+        ('\u{1056}', '\u{1059}', Extend), ('\u{105e}', '\u{1060}', Extend),
+        ('\u{1062}', '\u{1064}', Extend), ('\u{1067}', '\u{106d}', Extend),
+        ('\u{1071}', '\u{1074}', Extend), ('\u{1082}', '\u{108d}', Extend),
+        ('\u{108f}', '\u{108f}', Extend), ('\u{1090}', '\u{1099}', Numeric),
+        ('\u{109a}', '\u{109d}', Extend), ('\u{10a0}', '\u{10c5}', ALetter),
+        ('\u{10c7}', '\u{10c7}', ALetter), ('\u{10cd}', '\u{10cd}', ALetter),
+        ('\u{10d0}', '\u{10fa}', ALetter), ('\u{10fc}', '\u{1248}', ALetter),
+        ('\u{124a}', '\u{124d}', ALetter), ('\u{1250}', '\u{1256}', ALetter),
+        ('\u{1258}', '\u{1258}', ALetter), ('\u{125a}', '\u{125d}', ALetter),
+        ('\u{1260}', '\u{1288}', ALetter), ('\u{128a}', '\u{128d}', ALetter),
+        ('\u{1290}', '\u{12b0}', ALetter), ('\u{12b2}', '\u{12b5}', ALetter),
+        ('\u{12b8}', '\u{12be}', ALetter), ('\u{12c0}', '\u{12c0}', ALetter),
+        ('\u{12c2}', '\u{12c5}', ALetter), ('\u{12c8}', '\u{12d6}', ALetter),
+        ('\u{12d8}', '\u{1310}', ALetter), ('\u{1312}', '\u{1315}', ALetter),
+        ('\u{1318}', '\u{135a}', ALetter), ('\u{135d}', '\u{135f}', Extend),
+        ('\u{1380}', '\u{138f}', ALetter), ('\u{13a0}', '\u{13f5}', ALetter),
+        ('\u{13f8}', '\u{13fd}', ALetter), ('\u{1401}', '\u{166c}', ALetter),
+        ('\u{166f}', '\u{167f}', ALetter), ('\u{1680}', '\u{1680}', WSegSpace),
+        ('\u{1681}', '\u{169a}', ALetter), ('\u{16a0}', '\u{16ea}', ALetter),
+        ('\u{16ee}', '\u{16f8}', ALetter), ('\u{1700}', '\u{1711}', ALetter),
+        ('\u{1712}', '\u{1715}', Extend), ('\u{171f}', '\u{1731}', ALetter),
+        ('\u{1732}', '\u{1734}', Extend), ('\u{1740}', '\u{1751}', ALetter),
+        ('\u{1752}', '\u{1753}', Extend), ('\u{1760}', '\u{176c}', ALetter),
+        ('\u{176e}', '\u{1770}', ALetter), ('\u{1772}', '\u{1773}', Extend),
+        ('\u{17b4}', '\u{17d3}', Extend), ('\u{17dd}', '\u{17dd}', Extend),
+        ('\u{17e0}', '\u{17e9}', Numeric), ('\u{180b}', '\u{180d}', Extend),
+        ('\u{180e}', '\u{180e}', Format), ('\u{180f}', '\u{180f}', Extend),
+        ('\u{1810}', '\u{1819}', Numeric), ('\u{1820}', '\u{1878}', ALetter),
+        ('\u{1880}', '\u{1884}', ALetter), ('\u{1885}', '\u{1886}', Extend),
+        ('\u{1887}', '\u{18a8}', ALetter), ('\u{18a9}', '\u{18a9}', Extend),
+        ('\u{18aa}', '\u{18aa}', ALetter), ('\u{18b0}', '\u{18f5}', ALetter),
+        ('\u{1900}', '\u{191e}', ALetter), ('\u{1920}', '\u{192b}', Extend),
+        ('\u{1930}', '\u{193b}', Extend), ('\u{1946}', '\u{194f}', Numeric),
+        ('\u{19d0}', '\u{19da}', Numeric), ('\u{1a00}', '\u{1a16}', ALetter),
+        ('\u{1a17}', '\u{1a1b}', Extend), ('\u{1a55}', '\u{1a5e}', Extend),
+        ('\u{1a60}', '\u{1a7c}', Extend), ('\u{1a7f}', '\u{1a7f}', Extend),
+        ('\u{1a80}', '\u{1a89}', Numeric), ('\u{1a90}', '\u{1a99}', Numeric),
+        ('\u{1ab0}', '\u{1ace}', Extend), ('\u{1b00}', '\u{1b04}', Extend),
+        ('\u{1b05}', '\u{1b33}', ALetter), ('\u{1b34}', '\u{1b44}', Extend),
+        ('\u{1b45}', '\u{1b4c}', ALetter), ('\u{1b50}', '\u{1b59}', Numeric),
+        ('\u{1b6b}', '\u{1b73}', Extend), ('\u{1b80}', '\u{1b82}', Extend),
+        ('\u{1b83}', '\u{1ba0}', ALetter), ('\u{1ba1}', '\u{1bad}', Extend),
+        ('\u{1bae}', '\u{1baf}', ALetter), ('\u{1bb0}', '\u{1bb9}', Numeric),
+        ('\u{1bba}', '\u{1be5}', ALetter), ('\u{1be6}', '\u{1bf3}', Extend),
+        ('\u{1c00}', '\u{1c23}', ALetter), ('\u{1c24}', '\u{1c37}', Extend),
+        ('\u{1c40}', '\u{1c49}', Numeric), ('\u{1c4d}', '\u{1c4f}', ALetter),
+        ('\u{1c50}', '\u{1c59}', Numeric), ('\u{1c5a}', '\u{1c7d}', ALetter),
+        ('\u{1c80}', '\u{1c8a}', ALetter), ('\u{1c90}', '\u{1cba}', ALetter),
+        ('\u{1cbd}', '\u{1cbf}', ALetter), ('\u{1cd0}', '\u{1cd2}', Extend),
+        ('\u{1cd4}', '\u{1ce8}', Extend), ('\u{1ce9}', '\u{1cec}', ALetter),
+        ('\u{1ced}', '\u{1ced}', Extend), ('\u{1cee}', '\u{1cf3}', ALetter),
+        ('\u{1cf4}', '\u{1cf4}', Extend), ('\u{1cf5}', '\u{1cf6}', ALetter),
+        ('\u{1cf7}', '\u{1cf9}', Extend), ('\u{1cfa}', '\u{1cfa}', ALetter),
+        ('\u{1d00}', '\u{1dbf}', ALetter), ('\u{1dc0}', '\u{1dff}', Extend),
+        ('\u{1e00}', '\u{1f15}', ALetter), ('\u{1f18}', '\u{1f1d}', ALetter),
+        ('\u{1f20}', '\u{1f45}', ALetter), ('\u{1f48}', '\u{1f4d}', ALetter),
+        ('\u{1f50}', '\u{1f57}', ALetter), ('\u{1f59}', '\u{1f59}', ALetter),
+        ('\u{1f5b}', '\u{1f5b}', ALetter), ('\u{1f5d}', '\u{1f5d}', ALetter),
+        ('\u{1f5f}', '\u{1f7d}', ALetter), ('\u{1f80}', '\u{1fb4}', ALetter),
+        ('\u{1fb6}', '\u{1fbc}', ALetter), ('\u{1fbe}', '\u{1fbe}', ALetter),
+        ('\u{1fc2}', '\u{1fc4}', ALetter), ('\u{1fc6}', '\u{1fcc}', ALetter),
+        ('\u{1fd0}', '\u{1fd3}', ALetter), ('\u{1fd6}', '\u{1fdb}', ALetter),
+        ('\u{1fe0}', '\u{1fec}', ALetter), ('\u{1ff2}', '\u{1ff4}', ALetter),
+        ('\u{1ff6}', '\u{1ffc}', ALetter), ('\u{2000}', '\u{2006}', WSegSpace),
+        ('\u{2008}', '\u{200a}', WSegSpace), ('\u{200c}', '\u{200c}', Extend),
+        ('\u{200d}', '\u{200d}', Zwj), ('\u{200e}', '\u{200f}', Format),
+        ('\u{2018}', '\u{2019}', MidNumLet), ('\u{2024}', '\u{2024}', MidNumLet),
+        ('\u{2027}', '\u{2027}', MidLetter), ('\u{2028}', '\u{2029}', Newline),
+        ('\u{202a}', '\u{202e}', Format), ('\u{202f}', '\u{202f}', ExtendNumLet),
+        ('\u{203f}', '\u{2040}', ExtendNumLet), ('\u{2044}', '\u{2044}', MidNum),
+        ('\u{2054}', '\u{2054}', ExtendNumLet), ('\u{205f}', '\u{205f}', WSegSpace),
+        ('\u{2060}', '\u{2064}', Format), ('\u{2066}', '\u{206f}', Format),
+        ('\u{2071}', '\u{2071}', ALetter), ('\u{207f}', '\u{207f}', ALetter),
+        ('\u{2090}', '\u{209c}', ALetter), ('\u{20d0}', '\u{20f0}', Extend),
+        ('\u{2102}', '\u{2102}', ALetter), ('\u{2107}', '\u{2107}', ALetter),
+        ('\u{210a}', '\u{2113}', ALetter), ('\u{2115}', '\u{2115}', ALetter),
+        ('\u{2119}', '\u{211d}', ALetter), ('\u{2124}', '\u{2124}', ALetter),
+        ('\u{2126}', '\u{2126}', ALetter), ('\u{2128}', '\u{2128}', ALetter),
+        ('\u{212a}', '\u{212d}', ALetter), ('\u{212f}', '\u{2139}', ALetter),
+        ('\u{213c}', '\u{213f}', ALetter), ('\u{2145}', '\u{2149}', ALetter),
+        ('\u{214e}', '\u{214e}', ALetter), ('\u{2160}', '\u{2188}', ALetter),
+        ('\u{24b6}', '\u{24e9}', ALetter), ('\u{2c00}', '\u{2ce4}', ALetter),
+        ('\u{2ceb}', '\u{2cee}', ALetter), ('\u{2cef}', '\u{2cf1}', Extend),
+        ('\u{2cf2}', '\u{2cf3}', ALetter), ('\u{2d00}', '\u{2d25}', ALetter),
+        ('\u{2d27}', '\u{2d27}', ALetter), ('\u{2d2d}', '\u{2d2d}', ALetter),
+        ('\u{2d30}', '\u{2d67}', ALetter), ('\u{2d6f}', '\u{2d6f}', ALetter),
+        ('\u{2d7f}', '\u{2d7f}', Extend), ('\u{2d80}', '\u{2d96}', ALetter),
+        ('\u{2da0}', '\u{2da6}', ALetter), ('\u{2da8}', '\u{2dae}', ALetter),
+        ('\u{2db0}', '\u{2db6}', ALetter), ('\u{2db8}', '\u{2dbe}', ALetter),
+        ('\u{2dc0}', '\u{2dc6}', ALetter), ('\u{2dc8}', '\u{2dce}', ALetter),
+        ('\u{2dd0}', '\u{2dd6}', ALetter), ('\u{2dd8}', '\u{2dde}', ALetter),
+        ('\u{2de0}', '\u{2dff}', Extend), ('\u{2e2f}', '\u{2e2f}', ALetter),
+        ('\u{3000}', '\u{3000}', WSegSpace), ('\u{3005}', '\u{3005}', ALetter),
+        ('\u{302a}', '\u{302f}', Extend), ('\u{3031}', '\u{3035}', Katakana),
+        ('\u{303b}', '\u{303c}', ALetter), ('\u{3099}', '\u{309a}', Extend),
+        ('\u{309b}', '\u{309c}', Katakana), ('\u{30a0}', '\u{30fa}', Katakana),
+        ('\u{30fc}', '\u{30ff}', Katakana), ('\u{3105}', '\u{312f}', ALetter),
+        ('\u{3131}', '\u{318e}', ALetter), ('\u{31a0}', '\u{31bf}', ALetter),
+        ('\u{31f0}', '\u{31ff}', Katakana), ('\u{32d0}', '\u{32fe}', Katakana),
+        ('\u{3300}', '\u{3357}', Katakana), ('\u{a000}', '\u{a48c}', ALetter),
+        ('\u{a4d0}', '\u{a4fd}', ALetter), ('\u{a500}', '\u{a60c}', ALetter),
+        ('\u{a610}', '\u{a61f}', ALetter), ('\u{a620}', '\u{a629}', Numeric),
+        ('\u{a62a}', '\u{a62b}', ALetter), ('\u{a640}', '\u{a66e}', ALetter),
+        ('\u{a66f}', '\u{a672}', Extend), ('\u{a674}', '\u{a67d}', Extend),
+        ('\u{a67f}', '\u{a69d}', ALetter), ('\u{a69e}', '\u{a69f}', Extend),
+        ('\u{a6a0}', '\u{a6ef}', ALetter), ('\u{a6f0}', '\u{a6f1}', Extend),
+        ('\u{a708}', '\u{a7cd}', ALetter), ('\u{a7d0}', '\u{a7d1}', ALetter),
+        ('\u{a7d3}', '\u{a7d3}', ALetter), ('\u{a7d5}', '\u{a7dc}', ALetter),
+        ('\u{a7f2}', '\u{a801}', ALetter), ('\u{a802}', '\u{a802}', Extend),
+        ('\u{a803}', '\u{a805}', ALetter), ('\u{a806}', '\u{a806}', Extend),
+        ('\u{a807}', '\u{a80a}', ALetter), ('\u{a80b}', '\u{a80b}', Extend),
+        ('\u{a80c}', '\u{a822}', ALetter), ('\u{a823}', '\u{a827}', Extend),
+        ('\u{a82c}', '\u{a82c}', Extend), ('\u{a840}', '\u{a873}', ALetter),
+        ('\u{a880}', '\u{a881}', Extend), ('\u{a882}', '\u{a8b3}', ALetter),
+        ('\u{a8b4}', '\u{a8c5}', Extend), ('\u{a8d0}', '\u{a8d9}', Numeric),
+        ('\u{a8e0}', '\u{a8f1}', Extend), ('\u{a8f2}', '\u{a8f7}', ALetter),
+        ('\u{a8fb}', '\u{a8fb}', ALetter), ('\u{a8fd}', '\u{a8fe}', ALetter),
+        ('\u{a8ff}', '\u{a8ff}', Extend), ('\u{a900}', '\u{a909}', Numeric),
+        ('\u{a90a}', '\u{a925}', ALetter), ('\u{a926}', '\u{a92d}', Extend),
+        ('\u{a930}', '\u{a946}', ALetter), ('\u{a947}', '\u{a953}', Extend),
+        ('\u{a960}', '\u{a97c}', ALetter), ('\u{a980}', '\u{a983}', Extend),
+        ('\u{a984}', '\u{a9b2}', ALetter), ('\u{a9b3}', '\u{a9c0}', Extend),
+        ('\u{a9cf}', '\u{a9cf}', ALetter), ('\u{a9d0}', '\u{a9d9}', Numeric),
+        ('\u{a9e5}', '\u{a9e5}', Extend), ('\u{a9f0}', '\u{a9f9}', Numeric),
+        ('\u{aa00}', '\u{aa28}', ALetter), ('\u{aa29}', '\u{aa36}', Extend),
+        ('\u{aa40}', '\u{aa42}', ALetter), ('\u{aa43}', '\u{aa43}', Extend),
+        ('\u{aa44}', '\u{aa4b}', ALetter), ('\u{aa4c}', '\u{aa4d}', Extend),
+        ('\u{aa50}', '\u{aa59}', Numeric), ('\u{aa7b}', '\u{aa7d}', Extend),
+        ('\u{aab0}', '\u{aab0}', Extend), ('\u{aab2}', '\u{aab4}', Extend),
+        ('\u{aab7}', '\u{aab8}', Extend), ('\u{aabe}', '\u{aabf}', Extend),
+        ('\u{aac1}', '\u{aac1}', Extend), ('\u{aae0}', '\u{aaea}', ALetter),
+        ('\u{aaeb}', '\u{aaef}', Extend), ('\u{aaf2}', '\u{aaf4}', ALetter),
+        ('\u{aaf5}', '\u{aaf6}', Extend), ('\u{ab01}', '\u{ab06}', ALetter),
+        ('\u{ab09}', '\u{ab0e}', ALetter), ('\u{ab11}', '\u{ab16}', ALetter),
+        ('\u{ab20}', '\u{ab26}', ALetter), ('\u{ab28}', '\u{ab2e}', ALetter),
+        ('\u{ab30}', '\u{ab69}', ALetter), ('\u{ab70}', '\u{abe2}', ALetter),
+        ('\u{abe3}', '\u{abea}', Extend), ('\u{abec}', '\u{abed}', Extend),
+        ('\u{abf0}', '\u{abf9}', Numeric), ('\u{ac00}', '\u{d7a3}', ALetter),
+        ('\u{d7b0}', '\u{d7c6}', ALetter), ('\u{d7cb}', '\u{d7fb}', ALetter),
+        ('\u{fb00}', '\u{fb06}', ALetter), ('\u{fb13}', '\u{fb17}', ALetter),
+        ('\u{fb1d}', '\u{fb1d}', HebrewLetter), ('\u{fb1e}', '\u{fb1e}', Extend),
+        ('\u{fb1f}', '\u{fb28}', HebrewLetter), ('\u{fb2a}', '\u{fb36}', HebrewLetter),
+        ('\u{fb38}', '\u{fb3c}', HebrewLetter), ('\u{fb3e}', '\u{fb3e}', HebrewLetter),
+        ('\u{fb40}', '\u{fb41}', HebrewLetter), ('\u{fb43}', '\u{fb44}', HebrewLetter),
+        ('\u{fb46}', '\u{fb4f}', HebrewLetter), ('\u{fb50}', '\u{fbb1}', ALetter),
+        ('\u{fbd3}', '\u{fd3d}', ALetter), ('\u{fd50}', '\u{fd8f}', ALetter),
+        ('\u{fd92}', '\u{fdc7}', ALetter), ('\u{fdf0}', '\u{fdfb}', ALetter),
+        ('\u{fe00}', '\u{fe0f}', Extend), ('\u{fe13}', '\u{fe13}', MidLetter),
+        ('\u{fe20}', '\u{fe2f}', Extend), ('\u{fe33}', '\u{fe34}', ExtendNumLet),
+        ('\u{fe4d}', '\u{fe4f}', ExtendNumLet), ('\u{fe50}', '\u{fe50}', MidNum),
+        ('\u{fe52}', '\u{fe52}', MidNumLet), ('\u{fe54}', '\u{fe54}', MidNum),
+        ('\u{fe55}', '\u{fe55}', MidLetter), ('\u{fe70}', '\u{fe74}', ALetter),
+        ('\u{fe76}', '\u{fefc}', ALetter), ('\u{feff}', '\u{feff}', Format),
+        ('\u{ff07}', '\u{ff07}', MidNumLet), ('\u{ff0c}', '\u{ff0c}', MidNum),
+        ('\u{ff0e}', '\u{ff0e}', MidNumLet), ('\u{ff10}', '\u{ff19}', Numeric),
+        ('\u{ff1a}', '\u{ff1a}', MidLetter), ('\u{ff1b}', '\u{ff1b}', MidNum),
+        ('\u{ff21}', '\u{ff3a}', ALetter), ('\u{ff3f}', '\u{ff3f}', ExtendNumLet),
+        ('\u{ff41}', '\u{ff5a}', ALetter), ('\u{ff66}', '\u{ff9d}', Katakana),
+        ('\u{ff9e}', '\u{ff9f}', Extend), ('\u{ffa0}', '\u{ffbe}', ALetter),
+        ('\u{ffc2}', '\u{ffc7}', ALetter), ('\u{ffca}', '\u{ffcf}', ALetter),
+        ('\u{ffd2}', '\u{ffd7}', ALetter), ('\u{ffda}', '\u{ffdc}', ALetter),
+        ('\u{fff9}', '\u{fffb}', Format), ('\u{10000}', '\u{1000b}', ALetter),
+        ('\u{1000d}', '\u{10026}', ALetter), ('\u{10028}', '\u{1003a}', ALetter),
+        ('\u{1003c}', '\u{1003d}', ALetter), ('\u{1003f}', '\u{1004d}', ALetter),
+        ('\u{10050}', '\u{1005d}', ALetter), ('\u{10080}', '\u{100fa}', ALetter),
+        ('\u{10140}', '\u{10174}', ALetter), ('\u{101fd}', '\u{101fd}', Extend),
+        ('\u{10280}', '\u{1029c}', ALetter), ('\u{102a0}', '\u{102d0}', ALetter),
+        ('\u{102e0}', '\u{102e0}', Extend), ('\u{10300}', '\u{1031f}', ALetter),
+        ('\u{1032d}', '\u{1034a}', ALetter), ('\u{10350}', '\u{10375}', ALetter),
+        ('\u{10376}', '\u{1037a}', Extend), ('\u{10380}', '\u{1039d}', ALetter),
+        ('\u{103a0}', '\u{103c3}', ALetter), ('\u{103c8}', '\u{103cf}', ALetter),
+        ('\u{103d1}', '\u{103d5}', ALetter), ('\u{10400}', '\u{1049d}', ALetter),
+        ('\u{104a0}', '\u{104a9}', Numeric), ('\u{104b0}', '\u{104d3}', ALetter),
+        ('\u{104d8}', '\u{104fb}', ALetter), ('\u{10500}', '\u{10527}', ALetter),
+        ('\u{10530}', '\u{10563}', ALetter), ('\u{10570}', '\u{1057a}', ALetter),
+        ('\u{1057c}', '\u{1058a}', ALetter), ('\u{1058c}', '\u{10592}', ALetter),
+        ('\u{10594}', '\u{10595}', ALetter), ('\u{10597}', '\u{105a1}', ALetter),
+        ('\u{105a3}', '\u{105b1}', ALetter), ('\u{105b3}', '\u{105b9}', ALetter),
+        ('\u{105bb}', '\u{105bc}', ALetter), ('\u{105c0}', '\u{105f3}', ALetter),
+        ('\u{10600}', '\u{10736}', ALetter), ('\u{10740}', '\u{10755}', ALetter),
+        ('\u{10760}', '\u{10767}', ALetter), ('\u{10780}', '\u{10785}', ALetter),
+        ('\u{10787}', '\u{107b0}', ALetter), ('\u{107b2}', '\u{107ba}', ALetter),
+        ('\u{10800}', '\u{10805}', ALetter), ('\u{10808}', '\u{10808}', ALetter),
+        ('\u{1080a}', '\u{10835}', ALetter), ('\u{10837}', '\u{10838}', ALetter),
+        ('\u{1083c}', '\u{1083c}', ALetter), ('\u{1083f}', '\u{10855}', ALetter),
+        ('\u{10860}', '\u{10876}', ALetter), ('\u{10880}', '\u{1089e}', ALetter),
+        ('\u{108e0}', '\u{108f2}', ALetter), ('\u{108f4}', '\u{108f5}', ALetter),
+        ('\u{10900}', '\u{10915}', ALetter), ('\u{10920}', '\u{10939}', ALetter),
+        ('\u{10980}', '\u{109b7}', ALetter), ('\u{109be}', '\u{109bf}', ALetter),
+        ('\u{10a00}', '\u{10a00}', ALetter), ('\u{10a01}', '\u{10a03}', Extend),
+        ('\u{10a05}', '\u{10a06}', Extend), ('\u{10a0c}', '\u{10a0f}', Extend),
+        ('\u{10a10}', '\u{10a13}', ALetter), ('\u{10a15}', '\u{10a17}', ALetter),
+        ('\u{10a19}', '\u{10a35}', ALetter), ('\u{10a38}', '\u{10a3a}', Extend),
+        ('\u{10a3f}', '\u{10a3f}', Extend), ('\u{10a60}', '\u{10a7c}', ALetter),
+        ('\u{10a80}', '\u{10a9c}', ALetter), ('\u{10ac0}', '\u{10ac7}', ALetter),
+        ('\u{10ac9}', '\u{10ae4}', ALetter), ('\u{10ae5}', '\u{10ae6}', Extend),
+        ('\u{10b00}', '\u{10b35}', ALetter), ('\u{10b40}', '\u{10b55}', ALetter),
+        ('\u{10b60}', '\u{10b72}', ALetter), ('\u{10b80}', '\u{10b91}', ALetter),
+        ('\u{10c00}', '\u{10c48}', ALetter), ('\u{10c80}', '\u{10cb2}', ALetter),
+        ('\u{10cc0}', '\u{10cf2}', ALetter), ('\u{10d00}', '\u{10d23}', ALetter),
+        ('\u{10d24}', '\u{10d27}', Extend), ('\u{10d30}', '\u{10d39}', Numeric),
+        ('\u{10d40}', '\u{10d49}', Numeric), ('\u{10d4a}', '\u{10d65}', ALetter),
+        ('\u{10d69}', '\u{10d6d}', Extend), ('\u{10d6f}', '\u{10d85}', ALetter),
+        ('\u{10e80}', '\u{10ea9}', ALetter), ('\u{10eab}', '\u{10eac}', Extend),
+        ('\u{10eb0}', '\u{10eb1}', ALetter), ('\u{10ec2}', '\u{10ec4}', ALetter),
+        ('\u{10efc}', '\u{10eff}', Extend), ('\u{10f00}', '\u{10f1c}', ALetter),
+        ('\u{10f27}', '\u{10f27}', ALetter), ('\u{10f30}', '\u{10f45}', ALetter),
+        ('\u{10f46}', '\u{10f50}', Extend), ('\u{10f70}', '\u{10f81}', ALetter),
+        ('\u{10f82}', '\u{10f85}', Extend), ('\u{10fb0}', '\u{10fc4}', ALetter),
+        ('\u{10fe0}', '\u{10ff6}', ALetter), ('\u{11000}', '\u{11002}', Extend),
+        ('\u{11003}', '\u{11037}', ALetter), ('\u{11038}', '\u{11046}', Extend),
+        ('\u{11066}', '\u{1106f}', Numeric), ('\u{11070}', '\u{11070}', Extend),
+        ('\u{11071}', '\u{11072}', ALetter), ('\u{11073}', '\u{11074}', Extend),
+        ('\u{11075}', '\u{11075}', ALetter), ('\u{1107f}', '\u{11082}', Extend),
+        ('\u{11083}', '\u{110af}', ALetter), ('\u{110b0}', '\u{110ba}', Extend),
+        ('\u{110bd}', '\u{110bd}', Numeric), ('\u{110c2}', '\u{110c2}', Extend),
+        ('\u{110cd}', '\u{110cd}', Numeric), ('\u{110d0}', '\u{110e8}', ALetter),
+        ('\u{110f0}', '\u{110f9}', Numeric), ('\u{11100}', '\u{11102}', Extend),
+        ('\u{11103}', '\u{11126}', ALetter), ('\u{11127}', '\u{11134}', Extend),
+        ('\u{11136}', '\u{1113f}', Numeric), ('\u{11144}', '\u{11144}', ALetter),
+        ('\u{11145}', '\u{11146}', Extend), ('\u{11147}', '\u{11147}', ALetter),
+        ('\u{11150}', '\u{11172}', ALetter), ('\u{11173}', '\u{11173}', Extend),
+        ('\u{11176}', '\u{11176}', ALetter), ('\u{11180}', '\u{11182}', Extend),
+        ('\u{11183}', '\u{111b2}', ALetter), ('\u{111b3}', '\u{111c0}', Extend),
+        ('\u{111c1}', '\u{111c4}', ALetter), ('\u{111c9}', '\u{111cc}', Extend),
+        ('\u{111ce}', '\u{111cf}', Extend), ('\u{111d0}', '\u{111d9}', Numeric),
+        ('\u{111da}', '\u{111da}', ALetter), ('\u{111dc}', '\u{111dc}', ALetter),
+        ('\u{11200}', '\u{11211}', ALetter), ('\u{11213}', '\u{1122b}', ALetter),
+        ('\u{1122c}', '\u{11237}', Extend), ('\u{1123e}', '\u{1123e}', Extend),
+        ('\u{1123f}', '\u{11240}', ALetter), ('\u{11241}', '\u{11241}', Extend),
+        ('\u{11280}', '\u{11286}', ALetter), ('\u{11288}', '\u{11288}', ALetter),
+        ('\u{1128a}', '\u{1128d}', ALetter), ('\u{1128f}', '\u{1129d}', ALetter),
+        ('\u{1129f}', '\u{112a8}', ALetter), ('\u{112b0}', '\u{112de}', ALetter),
+        ('\u{112df}', '\u{112ea}', Extend), ('\u{112f0}', '\u{112f9}', Numeric),
+        ('\u{11300}', '\u{11303}', Extend), ('\u{11305}', '\u{1130c}', ALetter),
+        ('\u{1130f}', '\u{11310}', ALetter), ('\u{11313}', '\u{11328}', ALetter),
+        ('\u{1132a}', '\u{11330}', ALetter), ('\u{11332}', '\u{11333}', ALetter),
+        ('\u{11335}', '\u{11339}', ALetter), ('\u{1133b}', '\u{1133c}', Extend),
+        ('\u{1133d}', '\u{1133d}', ALetter), ('\u{1133e}', '\u{11344}', Extend),
+        ('\u{11347}', '\u{11348}', Extend), ('\u{1134b}', '\u{1134d}', Extend),
+        ('\u{11350}', '\u{11350}', ALetter), ('\u{11357}', '\u{11357}', Extend),
+        ('\u{1135d}', '\u{11361}', ALetter), ('\u{11362}', '\u{11363}', Extend),
+        ('\u{11366}', '\u{1136c}', Extend), ('\u{11370}', '\u{11374}', Extend),
+        ('\u{11380}', '\u{11389}', ALetter), ('\u{1138b}', '\u{1138b}', ALetter),
+        ('\u{1138e}', '\u{1138e}', ALetter), ('\u{11390}', '\u{113b5}', ALetter),
+        ('\u{113b7}', '\u{113b7}', ALetter), ('\u{113b8}', '\u{113c0}', Extend),
+        ('\u{113c2}', '\u{113c2}', Extend), ('\u{113c5}', '\u{113c5}', Extend),
+        ('\u{113c7}', '\u{113ca}', Extend), ('\u{113cc}', '\u{113d0}', Extend),
+        ('\u{113d1}', '\u{113d1}', ALetter), ('\u{113d2}', '\u{113d2}', Extend),
+        ('\u{113d3}', '\u{113d3}', ALetter), ('\u{113e1}', '\u{113e2}', Extend),
+        ('\u{11400}', '\u{11434}', ALetter), ('\u{11435}', '\u{11446}', Extend),
+        ('\u{11447}', '\u{1144a}', ALetter), ('\u{11450}', '\u{11459}', Numeric),
+        ('\u{1145e}', '\u{1145e}', Extend), ('\u{1145f}', '\u{11461}', ALetter),
+        ('\u{11480}', '\u{114af}', ALetter), ('\u{114b0}', '\u{114c3}', Extend),
+        ('\u{114c4}', '\u{114c5}', ALetter), ('\u{114c7}', '\u{114c7}', ALetter),
+        ('\u{114d0}', '\u{114d9}', Numeric), ('\u{11580}', '\u{115ae}', ALetter),
+        ('\u{115af}', '\u{115b5}', Extend), ('\u{115b8}', '\u{115c0}', Extend),
+        ('\u{115d8}', '\u{115db}', ALetter), ('\u{115dc}', '\u{115dd}', Extend),
+        ('\u{11600}', '\u{1162f}', ALetter), ('\u{11630}', '\u{11640}', Extend),
+        ('\u{11644}', '\u{11644}', ALetter), ('\u{11650}', '\u{11659}', Numeric),
+        ('\u{11680}', '\u{116aa}', ALetter), ('\u{116ab}', '\u{116b7}', Extend),
+        ('\u{116b8}', '\u{116b8}', ALetter), ('\u{116c0}', '\u{116c9}', Numeric),
+        ('\u{116d0}', '\u{116e3}', Numeric), ('\u{1171d}', '\u{1172b}', Extend),
+        ('\u{11730}', '\u{11739}', Numeric), ('\u{11800}', '\u{1182b}', ALetter),
+        ('\u{1182c}', '\u{1183a}', Extend), ('\u{118a0}', '\u{118df}', ALetter),
+        ('\u{118e0}', '\u{118e9}', Numeric), ('\u{118ff}', '\u{11906}', ALetter),
+        ('\u{11909}', '\u{11909}', ALetter), ('\u{1190c}', '\u{11913}', ALetter),
+        ('\u{11915}', '\u{11916}', ALetter), ('\u{11918}', '\u{1192f}', ALetter),
+        ('\u{11930}', '\u{11935}', Extend), ('\u{11937}', '\u{11938}', Extend),
+        ('\u{1193b}', '\u{1193e}', Extend), ('\u{1193f}', '\u{1193f}', ALetter),
+        ('\u{11940}', '\u{11940}', Extend), ('\u{11941}', '\u{11941}', ALetter),
+        ('\u{11942}', '\u{11943}', Extend), ('\u{11950}', '\u{11959}', Numeric),
+        ('\u{119a0}', '\u{119a7}', ALetter), ('\u{119aa}', '\u{119d0}', ALetter),
+        ('\u{119d1}', '\u{119d7}', Extend), ('\u{119da}', '\u{119e0}', Extend),
+        ('\u{119e1}', '\u{119e1}', ALetter), ('\u{119e3}', '\u{119e3}', ALetter),
+        ('\u{119e4}', '\u{119e4}', Extend), ('\u{11a00}', '\u{11a00}', ALetter),
+        ('\u{11a01}', '\u{11a0a}', Extend), ('\u{11a0b}', '\u{11a32}', ALetter),
+        ('\u{11a33}', '\u{11a39}', Extend), ('\u{11a3a}', '\u{11a3a}', ALetter),
+        ('\u{11a3b}', '\u{11a3e}', Extend), ('\u{11a47}', '\u{11a47}', Extend),
+        ('\u{11a50}', '\u{11a50}', ALetter), ('\u{11a51}', '\u{11a5b}', Extend),
+        ('\u{11a5c}', '\u{11a89}', ALetter), ('\u{11a8a}', '\u{11a99}', Extend),
+        ('\u{11a9d}', '\u{11a9d}', ALetter), ('\u{11ab0}', '\u{11af8}', ALetter),
+        ('\u{11bc0}', '\u{11be0}', ALetter), ('\u{11bf0}', '\u{11bf9}', Numeric),
+        ('\u{11c00}', '\u{11c08}', ALetter), ('\u{11c0a}', '\u{11c2e}', ALetter),
+        ('\u{11c2f}', '\u{11c36}', Extend), ('\u{11c38}', '\u{11c3f}', Extend),
+        ('\u{11c40}', '\u{11c40}', ALetter), ('\u{11c50}', '\u{11c59}', Numeric),
+        ('\u{11c72}', '\u{11c8f}', ALetter), ('\u{11c92}', '\u{11ca7}', Extend),
+        ('\u{11ca9}', '\u{11cb6}', Extend), ('\u{11d00}', '\u{11d06}', ALetter),
+        ('\u{11d08}', '\u{11d09}', ALetter), ('\u{11d0b}', '\u{11d30}', ALetter),
+        ('\u{11d31}', '\u{11d36}', Extend), ('\u{11d3a}', '\u{11d3a}', Extend),
+        ('\u{11d3c}', '\u{11d3d}', Extend), ('\u{11d3f}', '\u{11d45}', Extend),
+        ('\u{11d46}', '\u{11d46}', ALetter), ('\u{11d47}', '\u{11d47}', Extend),
+        ('\u{11d50}', '\u{11d59}', Numeric), ('\u{11d60}', '\u{11d65}', ALetter),
+        ('\u{11d67}', '\u{11d68}', ALetter), ('\u{11d6a}', '\u{11d89}', ALetter),
+        ('\u{11d8a}', '\u{11d8e}', Extend), ('\u{11d90}', '\u{11d91}', Extend),
+        ('\u{11d93}', '\u{11d97}', Extend), ('\u{11d98}', '\u{11d98}', ALetter),
+        ('\u{11da0}', '\u{11da9}', Numeric), ('\u{11ee0}', '\u{11ef2}', ALetter),
+        ('\u{11ef3}', '\u{11ef6}', Extend), ('\u{11f00}', '\u{11f01}', Extend),
+        ('\u{11f02}', '\u{11f02}', ALetter), ('\u{11f03}', '\u{11f03}', Extend),
+        ('\u{11f04}', '\u{11f10}', ALetter), ('\u{11f12}', '\u{11f33}', ALetter),
+        ('\u{11f34}', '\u{11f3a}', Extend), ('\u{11f3e}', '\u{11f42}', Extend),
+        ('\u{11f50}', '\u{11f59}', Numeric), ('\u{11f5a}', '\u{11f5a}', Extend),
+        ('\u{11fb0}', '\u{11fb0}', ALetter), ('\u{12000}', '\u{12399}', ALetter),
+        ('\u{12400}', '\u{1246e}', ALetter), ('\u{12480}', '\u{12543}', ALetter),
+        ('\u{12f90}', '\u{12ff0}', ALetter), ('\u{13000}', '\u{1342f}', ALetter),
+        ('\u{13430}', '\u{1343f}', Format), ('\u{13440}', '\u{13440}', Extend),
+        ('\u{13441}', '\u{13446}', ALetter), ('\u{13447}', '\u{13455}', Extend),
+        ('\u{13460}', '\u{143fa}', ALetter), ('\u{14400}', '\u{14646}', ALetter),
+        ('\u{16100}', '\u{1611d}', ALetter), ('\u{1611e}', '\u{1612f}', Extend),
+        ('\u{16130}', '\u{16139}', Numeric), ('\u{16800}', '\u{16a38}', ALetter),
+        ('\u{16a40}', '\u{16a5e}', ALetter), ('\u{16a60}', '\u{16a69}', Numeric),
+        ('\u{16a70}', '\u{16abe}', ALetter), ('\u{16ac0}', '\u{16ac9}', Numeric),
+        ('\u{16ad0}', '\u{16aed}', ALetter), ('\u{16af0}', '\u{16af4}', Extend),
+        ('\u{16b00}', '\u{16b2f}', ALetter), ('\u{16b30}', '\u{16b36}', Extend),
+        ('\u{16b40}', '\u{16b43}', ALetter), ('\u{16b50}', '\u{16b59}', Numeric),
+        ('\u{16b63}', '\u{16b77}', ALetter), ('\u{16b7d}', '\u{16b8f}', ALetter),
+        ('\u{16d40}', '\u{16d6c}', ALetter), ('\u{16d70}', '\u{16d79}', Numeric),
+        ('\u{16e40}', '\u{16e7f}', ALetter), ('\u{16f00}', '\u{16f4a}', ALetter),
+        ('\u{16f4f}', '\u{16f4f}', Extend), ('\u{16f50}', '\u{16f50}', ALetter),
+        ('\u{16f51}', '\u{16f87}', Extend), ('\u{16f8f}', '\u{16f92}', Extend),
+        ('\u{16f93}', '\u{16f9f}', ALetter), ('\u{16fe0}', '\u{16fe1}', ALetter),
+        ('\u{16fe3}', '\u{16fe3}', ALetter), ('\u{16fe4}', '\u{16fe4}', Extend),
+        ('\u{16ff0}', '\u{16ff1}', Extend), ('\u{1aff0}', '\u{1aff3}', Katakana),
+        ('\u{1aff5}', '\u{1affb}', Katakana), ('\u{1affd}', '\u{1affe}', Katakana),
+        ('\u{1b000}', '\u{1b000}', Katakana), ('\u{1b120}', '\u{1b122}', Katakana),
+        ('\u{1b155}', '\u{1b155}', Katakana), ('\u{1b164}', '\u{1b167}', Katakana),
+        ('\u{1bc00}', '\u{1bc6a}', ALetter), ('\u{1bc70}', '\u{1bc7c}', ALetter),
+        ('\u{1bc80}', '\u{1bc88}', ALetter), ('\u{1bc90}', '\u{1bc99}', ALetter),
+        ('\u{1bc9d}', '\u{1bc9e}', Extend), ('\u{1bca0}', '\u{1bca3}', Format),
+        ('\u{1ccf0}', '\u{1ccf9}', Numeric), ('\u{1cf00}', '\u{1cf2d}', Extend),
+        ('\u{1cf30}', '\u{1cf46}', Extend), ('\u{1d165}', '\u{1d169}', Extend),
+        ('\u{1d16d}', '\u{1d172}', Extend), ('\u{1d173}', '\u{1d17a}', Format),
+        ('\u{1d17b}', '\u{1d182}', Extend), ('\u{1d185}', '\u{1d18b}', Extend),
+        ('\u{1d1aa}', '\u{1d1ad}', Extend), ('\u{1d242}', '\u{1d244}', Extend),
+        ('\u{1d400}', '\u{1d454}', ALetter), ('\u{1d456}', '\u{1d49c}', ALetter),
+        ('\u{1d49e}', '\u{1d49f}', ALetter), ('\u{1d4a2}', '\u{1d4a2}', ALetter),
+        ('\u{1d4a5}', '\u{1d4a6}', ALetter), ('\u{1d4a9}', '\u{1d4ac}', ALetter),
+        ('\u{1d4ae}', '\u{1d4b9}', ALetter), ('\u{1d4bb}', '\u{1d4bb}', ALetter),
+        ('\u{1d4bd}', '\u{1d4c3}', ALetter), ('\u{1d4c5}', '\u{1d505}', ALetter),
+        ('\u{1d507}', '\u{1d50a}', ALetter), ('\u{1d50d}', '\u{1d514}', ALetter),
+        ('\u{1d516}', '\u{1d51c}', ALetter), ('\u{1d51e}', '\u{1d539}', ALetter),
+        ('\u{1d53b}', '\u{1d53e}', ALetter), ('\u{1d540}', '\u{1d544}', ALetter),
+        ('\u{1d546}', '\u{1d546}', ALetter), ('\u{1d54a}', '\u{1d550}', ALetter),
+        ('\u{1d552}', '\u{1d6a5}', ALetter), ('\u{1d6a8}', '\u{1d6c0}', ALetter),
+        ('\u{1d6c2}', '\u{1d6da}', ALetter), ('\u{1d6dc}', '\u{1d6fa}', ALetter),
+        ('\u{1d6fc}', '\u{1d714}', ALetter), ('\u{1d716}', '\u{1d734}', ALetter),
+        ('\u{1d736}', '\u{1d74e}', ALetter), ('\u{1d750}', '\u{1d76e}', ALetter),
+        ('\u{1d770}', '\u{1d788}', ALetter), ('\u{1d78a}', '\u{1d7a8}', ALetter),
+        ('\u{1d7aa}', '\u{1d7c2}', ALetter), ('\u{1d7c4}', '\u{1d7cb}', ALetter),
+        ('\u{1d7ce}', '\u{1d7ff}', Numeric), ('\u{1da00}', '\u{1da36}', Extend),
+        ('\u{1da3b}', '\u{1da6c}', Extend), ('\u{1da75}', '\u{1da75}', Extend),
+        ('\u{1da84}', '\u{1da84}', Extend), ('\u{1da9b}', '\u{1da9f}', Extend),
+        ('\u{1daa1}', '\u{1daaf}', Extend), ('\u{1df00}', '\u{1df1e}', ALetter),
+        ('\u{1df25}', '\u{1df2a}', ALetter), ('\u{1e000}', '\u{1e006}', Extend),
+        ('\u{1e008}', '\u{1e018}', Extend), ('\u{1e01b}', '\u{1e021}', Extend),
+        ('\u{1e023}', '\u{1e024}', Extend), ('\u{1e026}', '\u{1e02a}', Extend),
+        ('\u{1e030}', '\u{1e06d}', ALetter), ('\u{1e08f}', '\u{1e08f}', Extend),
+        ('\u{1e100}', '\u{1e12c}', ALetter), ('\u{1e130}', '\u{1e136}', Extend),
+        ('\u{1e137}', '\u{1e13d}', ALetter), ('\u{1e140}', '\u{1e149}', Numeric),
+        ('\u{1e14e}', '\u{1e14e}', ALetter), ('\u{1e290}', '\u{1e2ad}', ALetter),
+        ('\u{1e2ae}', '\u{1e2ae}', Extend), ('\u{1e2c0}', '\u{1e2eb}', ALetter),
+        ('\u{1e2ec}', '\u{1e2ef}', Extend), ('\u{1e2f0}', '\u{1e2f9}', Numeric),
+        ('\u{1e4d0}', '\u{1e4eb}', ALetter), ('\u{1e4ec}', '\u{1e4ef}', Extend),
+        ('\u{1e4f0}', '\u{1e4f9}', Numeric), ('\u{1e5d0}', '\u{1e5ed}', ALetter),
+        ('\u{1e5ee}', '\u{1e5ef}', Extend), ('\u{1e5f0}', '\u{1e5f0}', ALetter),
+        ('\u{1e5f1}', '\u{1e5fa}', Numeric), ('\u{1e7e0}', '\u{1e7e6}', ALetter),
+        ('\u{1e7e8}', '\u{1e7eb}', ALetter), ('\u{1e7ed}', '\u{1e7ee}', ALetter),
+        ('\u{1e7f0}', '\u{1e7fe}', ALetter), ('\u{1e800}', '\u{1e8c4}', ALetter),
+        ('\u{1e8d0}', '\u{1e8d6}', Extend), ('\u{1e900}', '\u{1e943}', ALetter),
+        ('\u{1e944}', '\u{1e94a}', Extend), ('\u{1e94b}', '\u{1e94b}', ALetter),
+        ('\u{1e950}', '\u{1e959}', Numeric), ('\u{1ee00}', '\u{1ee03}', ALetter),
+        ('\u{1ee05}', '\u{1ee1f}', ALetter), ('\u{1ee21}', '\u{1ee22}', ALetter),
+        ('\u{1ee24}', '\u{1ee24}', ALetter), ('\u{1ee27}', '\u{1ee27}', ALetter),
+        ('\u{1ee29}', '\u{1ee32}', ALetter), ('\u{1ee34}', '\u{1ee37}', ALetter),
+        ('\u{1ee39}', '\u{1ee39}', ALetter), ('\u{1ee3b}', '\u{1ee3b}', ALetter),
+        ('\u{1ee42}', '\u{1ee42}', ALetter), ('\u{1ee47}', '\u{1ee47}', ALetter),
+        ('\u{1ee49}', '\u{1ee49}', ALetter), ('\u{1ee4b}', '\u{1ee4b}', ALetter),
+        ('\u{1ee4d}', '\u{1ee4f}', ALetter), ('\u{1ee51}', '\u{1ee52}', ALetter),
+        ('\u{1ee54}', '\u{1ee54}', ALetter), ('\u{1ee57}', '\u{1ee57}', ALetter),
+        ('\u{1ee59}', '\u{1ee59}', ALetter), ('\u{1ee5b}', '\u{1ee5b}', ALetter),
+        ('\u{1ee5d}', '\u{1ee5d}', ALetter), ('\u{1ee5f}', '\u{1ee5f}', ALetter),
+        ('\u{1ee61}', '\u{1ee62}', ALetter), ('\u{1ee64}', '\u{1ee64}', ALetter),
+        ('\u{1ee67}', '\u{1ee6a}', ALetter), ('\u{1ee6c}', '\u{1ee72}', ALetter),
+        ('\u{1ee74}', '\u{1ee77}', ALetter), ('\u{1ee79}', '\u{1ee7c}', ALetter),
+        ('\u{1ee7e}', '\u{1ee7e}', ALetter), ('\u{1ee80}', '\u{1ee89}', ALetter),
+        ('\u{1ee8b}', '\u{1ee9b}', ALetter), ('\u{1eea1}', '\u{1eea3}', ALetter),
+        ('\u{1eea5}', '\u{1eea9}', ALetter), ('\u{1eeab}', '\u{1eebb}', ALetter),
+        ('\u{1f130}', '\u{1f149}', ALetter), ('\u{1f150}', '\u{1f169}', ALetter),
+        ('\u{1f170}', '\u{1f189}', ALetter), ('\u{1f1e6}', '\u{1f1ff}', RegionalIndicator),
+        ('\u{1f3fb}', '\u{1f3ff}', Extend), ('\u{1fbf0}', '\u{1fbf9}', Numeric),
+        ('\u{e0001}', '\u{e0001}', Format), ('\u{e0020}', '\u{e007f}', Extend),
+        ('\u{e0100}', '\u{e01ef}', Extend),
+    ];
 }
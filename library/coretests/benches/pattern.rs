@@ -1,3 +1,5 @@
+use core::str::pattern::MultiStr;
+
 use test::{Bencher, black_box};
 
 #[bench]
@@ -39,3 +41,50 @@ fn ends_with_str(b: &mut Bencher) {
         }
     })
 }
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+/// Rust code, which is what keywords are usually looked for in.
+const SOURCE: &str = r#"
+impl<'a, 'b> Searcher<'a> for MultiStrSearcher<'a, 'b> {
+    fn next(&mut self) -> SearchStep {
+        let start = self.position;
+        if start == self.end {
+            return SearchStep::Done;
+        }
+        match self.find(false) {
+            Some((a, b)) if a == start => {
+                self.position = b;
+                SearchStep::Match(a, b)
+            }
+            Some((a, _)) => {
+                self.position = a;
+                SearchStep::Reject(start, a)
+            }
+            None => {
+                self.position = self.end;
+                SearchStep::Reject(start, self.end)
+            }
+        }
+    }
+}
+"#;
+
+#[bench]
+fn multi_str_keywords(b: &mut Bencher) {
+    let text = SOURCE.repeat(64);
+    let keywords = MultiStr::new(KEYWORDS);
+    b.iter(|| black_box(&text).matches(keywords).count())
+}
+
+#[bench]
+fn multi_str_keywords_back(b: &mut Bencher) {
+    let text = SOURCE.repeat(64);
+    let keywords = MultiStr::new(KEYWORDS);
+    b.iter(|| black_box(&text).rmatches(keywords).count())
+}
@@ -173,3 +173,49 @@ fn test_format_f32_rounds_ties_to_even() {
 fn is_exponential(s: &str) -> bool {
     s.contains("e") || s.contains("E")
 }
+
+#[test]
+fn test_format_into_matches_fmt() {
+    use core::fmt::FloatFormat;
+
+    let values = [
+        0.0,
+        -0.0,
+        1.0,
+        0.1,
+        -1.5,
+        1e-7,
+        123456.789,
+        1e16,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        -2.2250738585072014e-308,
+        5e-324,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+    let mut small = [0; 32];
+    let mut large = [0; 1100];
+    for x in values {
+        assert_eq!(x.format_into(&mut small), format!("{x:?}"));
+        assert_eq!(x.format_into_with(FloatFormat::Decimal, &mut large), Some(&*format!("{x}")));
+        assert_eq!(
+            x.format_into_with(FloatFormat::Exponent { upper: false }, &mut small),
+            Some(&*format!("{x:e}"))
+        );
+        assert_eq!(
+            x.format_into_with(FloatFormat::Fixed { precision: 3 }, &mut large),
+            Some(&*format!("{x:.3}"))
+        );
+        let format = FloatFormat::FixedExponent { precision: 4, upper: true };
+        assert_eq!(x.format_into_with(format, &mut small), Some(&*format!("{x:.4E}")));
+
+        let x = x as f32;
+        assert_eq!(x.format_into(&mut small), format!("{x:?}"));
+        assert_eq!(x.format_into_with(FloatFormat::Decimal, &mut large), Some(&*format!("{x}")));
+    }
+
+    assert_eq!(f64::MAX.format_into_with(FloatFormat::Decimal, &mut small), None);
+    assert_eq!(1.0f64.format_into_with(FloatFormat::Fixed { precision: 40 }, &mut small), None);
+}
@@ -176,3 +176,30 @@ fn massive_exponent() {
     assert_eq!(format!("1e-{max}000").parse(), Ok(0.0f64));
     assert_eq!(format!("1e{max}000").parse(), Ok(f64::INFINITY));
 }
+
+#[test]
+fn parse_prefix() {
+    assert_eq!(f64::parse_prefix(b"1.5,2"), Ok((1.5, 3)));
+    assert_eq!(f64::parse_prefix(b"-2.5e3]"), Ok((-2500.0, 6)));
+    assert_eq!(f32::parse_prefix(b"+.5x"), Ok((0.5, 3)));
+    assert_eq!(f64::parse_prefix(b"5."), Ok((5.0, 2)));
+    assert_eq!(f64::parse_prefix(b"1e"), Ok((1.0, 1)));
+    assert_eq!(f64::parse_prefix(b"1E+x"), Ok((1.0, 1)));
+    assert_eq!(f64::parse_prefix(b"7\xff"), Ok((7.0, 1)));
+    assert_eq!(f64::parse_prefix(b"infinity!"), Ok((f64::INFINITY, 8)));
+    assert_eq!(f64::parse_prefix(b"-infinit"), Ok((f64::NEG_INFINITY, 4)));
+    let (nan, len) = f64::parse_prefix(b"NaN,").unwrap();
+    assert!(nan.is_nan());
+    assert_eq!(len, 3);
+
+    // More than 19 significant digits, so rounding has to re-read exactly the digits of the prefix.
+    let long = "123456789012345678901234567890.123456789e-5";
+    let expected: f64 = long.parse().unwrap();
+    assert_eq!(f64::parse_prefix(format!("{long},1").as_bytes()), Ok((expected, long.len())));
+
+    assert!(f64::parse_prefix(b"").is_err());
+    assert!(f64::parse_prefix(b"-").is_err());
+    assert!(f64::parse_prefix(b".").is_err());
+    assert!(f64::parse_prefix(b"x1").is_err());
+    assert!(f32::parse_prefix(b"in").is_err());
+}
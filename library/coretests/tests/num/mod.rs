@@ -230,6 +230,25 @@ fn test_empty() {
     test_parse::<u8>("", Err(IntErrorKind::Empty));
 }
 
+#[test]
+fn test_parse_prefix() {
+    assert_eq!(u8::parse_prefix(b"127,0"), Ok((127, 3)));
+    assert_eq!(i32::parse_prefix(b"-42abc"), Ok((-42, 3)));
+    assert_eq!(u64::parse_prefix(b"+7"), Ok((7, 2)));
+    assert_eq!(u32::parse_prefix_radix(b"ffg", 16), Ok((255, 2)));
+    assert_eq!(i16::parse_prefix(b"1\xff"), Ok((1, 1)));
+
+    let kind = |r: Result<(i8, usize), ParseIntError>| r.map_err(|e| e.kind().clone());
+    assert_eq!(kind(i8::parse_prefix(b"")), Err(IntErrorKind::Empty));
+    assert_eq!(kind(i8::parse_prefix(b"x1")), Err(IntErrorKind::InvalidDigit));
+    assert_eq!(kind(i8::parse_prefix(b"-x")), Err(IntErrorKind::InvalidDigit));
+    assert_eq!(kind(i8::parse_prefix(b"128 ")), Err(IntErrorKind::PosOverflow));
+    assert_eq!(
+        u8::parse_prefix(b"-1").map_err(|e| e.kind().clone()),
+        Err(IntErrorKind::InvalidDigit)
+    );
+}
+
 #[test]
 fn test_infallible_try_from_int_error() {
     let func = |x: i8| -> Result<i32, TryFromIntError> { Ok(x.try_into()?) };
@@ -501,3 +501,114 @@ fn double_ended_regression_test() {
         [InRange(10, 13), Rejects(13, 14), InRange(37, 40), Rejects(34, 37), Done]
     );
 }
+
+#[test]
+fn test_multi_str_forward() {
+    let keywords = MultiStr::new(&["let", "fn", "letter"]);
+    search_asserts!(
+        "a letter fn",
+        keywords,
+        "leftmost-first forward search",
+        [next, next, next, next, next],
+        [Rejects(0, 2), Matches(2, 5), Rejects(5, 9), Matches(9, 11), Done]
+    );
+    search_asserts!(
+        "a letter fn",
+        keywords,
+        "leftmost-first forward matches",
+        [next_match, next_match, next_match],
+        [InRange(2, 5), InRange(9, 11), Done]
+    );
+}
+
+#[test]
+fn test_multi_str_reverse() {
+    let keywords = MultiStr::new(&["ab", "b", "abc"]);
+    search_asserts!(
+        "xabcab",
+        keywords,
+        "rightmost-end reverse search",
+        [next_back, next_back, next_back, next_back],
+        [Matches(4, 6), Matches(1, 4), Rejects(0, 1), Done]
+    );
+    search_asserts!(
+        "xabcab",
+        keywords,
+        "rightmost-end reverse matches",
+        [next_match_back, next_match_back, next_match_back],
+        [InRange(4, 6), InRange(1, 4), Done]
+    );
+}
+
+#[test]
+fn test_multi_str_str_methods() {
+    let seps = MultiStr::new(&[", ", ";", "→"]);
+    let v: Vec<&str> = "a, b;c→d".split(seps).collect();
+    assert_eq!(v, ["a", "b", "c", "d"]);
+    let v: Vec<&str> = "a, b;c→d".rsplit(seps).collect();
+    assert_eq!(v, ["d", "c", "b", "a"]);
+    assert_eq!("ünïcödé→".find(seps), Some(11));
+    assert!("→x".starts_with(seps));
+    assert!("x;".ends_with(seps));
+    assert_eq!("no separators".find(seps), None);
+    assert_eq!("anything".find(MultiStr::new(&[])), None);
+
+    let same_first = MultiStr::new(&["aab", "ab"]);
+    let v: Vec<(usize, &str)> = "aaab aab".match_indices(same_first).collect();
+    assert_eq!(v, [(1, "aab"), (5, "aab")]);
+}
+
+#[test]
+fn test_multi_str_overlapping_needles() {
+    // A match found first may start after one that is still being read.
+    let v: Vec<(usize, &str)> = "xabcd".match_indices(MultiStr::new(&["bc", "abcd"])).collect();
+    assert_eq!(v, [(1, "abcd")]);
+    // Among matches at the same position, the first listed needle wins, even if it ends later.
+    let v: Vec<(usize, &str)> = "abcdab".match_indices(MultiStr::new(&["abcd", "ab"])).collect();
+    assert_eq!(v, [(0, "abcd"), (4, "ab")]);
+    let v: Vec<(usize, &str)> = "abcdab".match_indices(MultiStr::new(&["ab", "abcd"])).collect();
+    assert_eq!(v, [(0, "ab"), (4, "ab")]);
+    let v: Vec<(usize, &str)> = "abcdab".rmatch_indices(MultiStr::new(&["cdab", "b"])).collect();
+    assert_eq!(v, [(2, "cdab"), (1, "b")]);
+}
+
+#[test]
+fn test_multi_str_many_needles() {
+    // Too many needles for an automaton, so they are checked one by one instead.
+    let numbers: Vec<String> = (0..1000).map(|i| format!("<{i}>")).collect();
+    let numbers: Vec<&str> = numbers.iter().map(|number| number.as_str()).collect();
+    let needles = MultiStr::new(&numbers);
+    let text = "<1><10><x><999><1000>";
+    let v: Vec<&str> = text.matches(needles).collect();
+    assert_eq!(v, ["<1>", "<10>", "<999>"]);
+    let v: Vec<&str> = text.rmatches(needles).collect();
+    assert_eq!(v, ["<999>", "<10>", "<1>"]);
+}
+
+#[test]
+#[should_panic]
+fn test_multi_str_empty_needle() {
+    let _ = MultiStr::new(&["a", ""]);
+}
+
+#[test]
+fn test_str_searcher_long_haystacks() {
+    // Long enough haystacks to go through the vectorized prefilter, with matches straddling
+    // every chunk boundary.
+    let haystack: String = (0..300).map(|i| if i % 37 == 0 { "xyz" } else { "xy" }).collect();
+    for needle in ["xyz", "yzx", "xyx", "zxyxy", "xyxyxyz"] {
+        let expected: Vec<usize> = haystack
+            .char_indices()
+            .map(|(i, _)| i)
+            .filter(|&i| haystack[i..].starts_with(needle))
+            .fold(Vec::new(), |mut acc, i| {
+                if acc.last().is_none_or(|&last| i >= last + needle.len()) {
+                    acc.push(i);
+                }
+                acc
+            });
+        let found: Vec<usize> = haystack.match_indices(needle).map(|(i, _)| i).collect();
+        assert_eq!(found, expected, "{needle}");
+        assert_eq!(haystack.contains(needle), !expected.is_empty(), "{needle}");
+    }
+}
@@ -0,0 +1,178 @@
+use core::random::{Distribution, RandomSource, SeededRng, choose, shuffle};
+
+/// A deterministic source that counts upwards one byte at a time.
+struct Counter(u8);
+
+impl RandomSource for Counter {
+    fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        for b in bytes {
+            *b = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+}
+
+/// A source that always produces the same byte.
+struct Constant(u8);
+
+impl RandomSource for Constant {
+    fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        bytes.fill(self.0);
+    }
+}
+
+#[test]
+fn integer_ranges_stay_in_bounds() {
+    let mut source = Counter(0);
+    for _ in 0..1000 {
+        let x: u32 = (10..17).sample(&mut source);
+        assert!((10..17).contains(&x));
+        let y: i8 = (-5..=5).sample(&mut source);
+        assert!((-5..=5).contains(&y));
+        let z: i64 = (i64::MIN..i64::MIN + 3).sample(&mut source);
+        assert!((i64::MIN..i64::MIN + 3).contains(&z));
+        let w: u128 = (0..=1).sample(&mut source);
+        assert!(w <= 1);
+    }
+}
+
+#[test]
+fn integer_ranges_cover_every_value() {
+    let mut source = Counter(0);
+    let mut seen = [false; 7];
+    for _ in 0..256 {
+        let x: usize = (0..7).sample(&mut source);
+        seen[x] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+}
+
+#[test]
+fn integer_range_is_unbiased() {
+    // 256 is not a multiple of 3, so one-byte draws for `0..3` must reject some values. Among the
+    // first draws that are accepted, every output has to appear equally often.
+    let mut counts = [0; 3];
+    for byte in 0..=255u8 {
+        let mut source = FirstThen { first: Some(byte), calls: 0 };
+        let x: u8 = (0..3).sample(&mut source);
+        if source.calls == 1 {
+            counts[x as usize] += 1;
+        }
+    }
+    assert_eq!(counts, [85, 85, 85]);
+}
+
+/// Produces `first`, then ones, and counts how many draws were made.
+struct FirstThen {
+    first: Option<u8>,
+    calls: usize,
+}
+
+impl RandomSource for FirstThen {
+    fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        self.calls += 1;
+        bytes.fill(self.first.take().unwrap_or(1));
+    }
+}
+
+#[test]
+fn full_width_inclusive_range() {
+    let mut source = Constant(0xAB);
+    let x: u8 = (0..=u8::MAX).sample(&mut source);
+    assert_eq!(x, 0xAB);
+    let y: i8 = (i8::MIN..=i8::MAX).sample(&mut source);
+    assert_eq!(y, 0xABu8 as i8);
+}
+
+#[test]
+#[should_panic]
+fn empty_range_panics() {
+    let _: u32 = (5..5).sample(&mut Counter(0));
+}
+
+#[test]
+fn float_ranges_stay_in_bounds() {
+    let mut source = Counter(0);
+    for _ in 0..1000 {
+        let x: f64 = (0.0..1.0).sample(&mut source);
+        assert!((0.0..1.0).contains(&x));
+        let y: f32 = (-2.0..=3.0).sample(&mut source);
+        assert!((-2.0..=3.0).contains(&y));
+    }
+    let max: f64 = (0.0..1.0).sample(&mut Constant(0xFF));
+    assert_eq!(max, 1.0 - f64::EPSILON / 2.0);
+    let top: f32 = (0.0..=1.0).sample(&mut Constant(0xFF));
+    assert_eq!(top, 1.0);
+    let zero: f64 = (0.0..1.0).sample(&mut Constant(0));
+    assert_eq!(zero, 0.0);
+}
+
+#[test]
+fn shuffle_permutes() {
+    let mut source = Counter(7);
+    let mut v: [u32; 32] = core::array::from_fn(|i| i as u32);
+    shuffle(&mut v, &mut source);
+    assert_ne!(v, core::array::from_fn(|i| i as u32));
+    v.sort();
+    assert_eq!(v, core::array::from_fn(|i| i as u32));
+
+    let mut empty: [u32; 0] = [];
+    shuffle(&mut empty, &mut source);
+}
+
+#[test]
+fn choose_picks_an_element() {
+    let mut source = Counter(0);
+    let v = [1, 2, 3];
+    for _ in 0..100 {
+        assert!(v.contains(choose(&v, &mut source).unwrap()));
+    }
+    assert_eq!(choose::<u8>(&[], &mut source), None);
+}
+
+#[test]
+fn seeded_rng_reference_stream() {
+    // Reference output of xoshiro256++ for the state `[1, 2, 3, 4]`.
+    let mut seed = [0; 32];
+    for (i, word) in seed.chunks_exact_mut(8).enumerate() {
+        word.copy_from_slice(&(i as u64 + 1).to_le_bytes());
+    }
+    let mut rng = SeededRng::from_seed(seed);
+    assert_eq!(rng.next_u64(), 0x2800001);
+    assert_eq!(rng.next_u64(), 0x3800067);
+    assert_eq!(rng.next_u64(), 0xcc00003800067);
+
+    let mut rng = SeededRng::seed_from_u64(0);
+    assert_eq!(rng.next_u64(), 0x53175d61490b23df);
+    assert_eq!(rng.next_u64(), 0x61da6f3dc380d507);
+    assert_eq!(rng.next_u64(), 0x5c0fdf91ec9a7bfc);
+}
+
+#[test]
+fn seeded_rng_zero_seed() {
+    assert_eq!(SeededRng::from_seed([0; 32]), SeededRng::seed_from_u64(0));
+}
+
+#[test]
+fn seeded_rng_fork() {
+    let mut seed = [0; 32];
+    for (i, word) in seed.chunks_exact_mut(8).enumerate() {
+        word.copy_from_slice(&(i as u64 + 1).to_le_bytes());
+    }
+    let mut parent = SeededRng::from_seed(seed);
+    let mut child = parent.fork();
+    assert_eq!(child.next_u64(), 0x2800001);
+    assert_eq!(parent.next_u64(), 0xec879073673df437);
+    assert_eq!(parent.next_u64(), 0x20d212a39aca1eaa);
+}
+
+#[test]
+fn seeded_rng_fill_bytes() {
+    let mut a = SeededRng::seed_from_u64(7);
+    let mut b = a.clone();
+    let mut bytes = [0; 12];
+    a.fill_bytes(&mut bytes);
+    assert_eq!(bytes[..8], b.next_u64().to_le_bytes());
+    assert_eq!(bytes[8..], b.next_u64().to_le_bytes()[..4]);
+    assert_eq!(a, b);
+}
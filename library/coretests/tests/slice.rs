@@ -1735,6 +1735,47 @@ fn select_nth_unstable_past_length() {
     [0i32; 10].select_nth_unstable(20);
 }
 
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn partial_sort_unstable() {
+    use rand::Rng;
+
+    let mut rng = crate::test_rng();
+
+    for len in (0..21).chain(500..501) {
+        for &modulus in &[5, 10, 1000] {
+            let orig: Vec<i32> = (0..len).map(|_| rng.random::<i32>() % modulus).collect();
+            let mut v_sorted = orig.clone();
+            v_sorted.sort();
+
+            for k in (0..len + 2).step_by(if len > 100 { 37 } else { 1 }) {
+                let front = k.min(len);
+
+                let mut v = orig.clone();
+                v.partial_sort_unstable(k);
+                assert_eq!(v[..front], v_sorted[..front]);
+                let mut rest = v[front..].to_vec();
+                rest.sort();
+                assert_eq!(rest, v_sorted[front..]);
+
+                let mut v = orig.clone();
+                v.partial_sort_unstable_by(k, |a, b| b.cmp(a));
+                assert!(v[..front].iter().eq(v_sorted.iter().rev().take(front)));
+
+                let mut v = orig.clone();
+                v.partial_sort_unstable_by_key(k, |&x| -x);
+                assert!(v[..front].iter().eq(v_sorted.iter().rev().take(front)));
+            }
+        }
+    }
+
+    // Should not panic.
+    [(); 10].partial_sort_unstable(5);
+    [(); 10].partial_sort_unstable(20);
+    [0i32; 0].partial_sort_unstable(1);
+}
+
 pub mod memchr {
     use core::slice::memchr::{memchr, memrchr};
 
@@ -235,6 +235,29 @@ unsafe fn to_int_unchecked<I: SimdCast>(self) -> Self::Cast<I>
     /// assert!(v.reduce_min().is_nan());
     /// ```
     fn reduce_min(self) -> Self::Scalar;
+
+    /// Returns the inclusive prefix sum of the vector.
+    ///
+    /// Element `i` of the result is the sum of elements `0..=i`. The additions are reassociated,
+    /// so rounding may differ from summing the elements sequentially.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let v = f32x4::from_array([1., 2., 3., 4.]);
+    /// assert_eq!(v.prefix_sum(), f32x4::from_array([1., 3., 6., 10.]));
+    /// ```
+    fn prefix_sum(self) -> Self;
+
+    /// Returns the inclusive prefix product of the vector.
+    ///
+    /// Element `i` of the result is the product of elements `0..=i`. The multiplications are
+    /// reassociated, so rounding may differ from multiplying the elements sequentially.
+    fn prefix_product(self) -> Self;
 }
 
 macro_rules! impl_trait {
@@ -445,6 +468,17 @@ fn reduce_min(self) -> Self::Scalar {
                 // Safety: `self` is a float vector
                 unsafe { core::intrinsics::simd::simd_reduce_min(self) }
             }
+
+            #[inline]
+            fn prefix_sum(self) -> Self {
+                // `-0.` is the additive identity: `-0. + 0.` is `0.`.
+                self.scan(-0., |a, b| a + b)
+            }
+
+            #[inline]
+            fn prefix_product(self) -> Self {
+                self.scan(1., |a, b| a * b)
+            }
         }
         )*
     }
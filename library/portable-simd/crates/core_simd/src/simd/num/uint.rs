@@ -94,6 +94,48 @@ pub trait SimdUint: Copy + Sealed {
     /// Returns the cumulative bitwise "xor" across the elements of the vector.
     fn reduce_xor(self) -> Self::Scalar;
 
+    /// Returns the inclusive prefix sum of the vector, with wrapping addition.
+    ///
+    /// Element `i` of the result is the sum of elements `0..=i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let v = u32x4::from_array([1, 2, 3, 4]);
+    /// assert_eq!(v.prefix_sum(), u32x4::from_array([1, 3, 6, 10]));
+    /// ```
+    fn prefix_sum(self) -> Self;
+
+    /// Returns the inclusive prefix product of the vector, with wrapping multiplication.
+    ///
+    /// Element `i` of the result is the product of elements `0..=i`.
+    fn prefix_product(self) -> Self;
+
+    /// Returns the running maximum of the vector.
+    ///
+    /// Element `i` of the result is the maximum of elements `0..=i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let v = u32x4::from_array([2, 1, 4, 3]);
+    /// assert_eq!(v.prefix_max(), u32x4::from_array([2, 2, 4, 4]));
+    /// ```
+    fn prefix_max(self) -> Self;
+
+    /// Returns the running minimum of the vector.
+    ///
+    /// Element `i` of the result is the minimum of elements `0..=i`.
+    fn prefix_min(self) -> Self;
+
     /// Reverses the byte order of each element.
     fn swap_bytes(self) -> Self;
 
@@ -209,6 +251,26 @@ fn reduce_xor(self) -> Self::Scalar {
                 unsafe { core::intrinsics::simd::simd_reduce_xor(self) }
             }
 
+            #[inline]
+            fn prefix_sum(self) -> Self {
+                self.scan(0, |a, b| a + b)
+            }
+
+            #[inline]
+            fn prefix_product(self) -> Self {
+                self.scan(1, |a, b| a * b)
+            }
+
+            #[inline]
+            fn prefix_max(self) -> Self {
+                self.scan(<$ty>::MIN, |a, b| a.simd_max(b))
+            }
+
+            #[inline]
+            fn prefix_min(self) -> Self {
+                self.scan(<$ty>::MAX, |a, b| a.simd_min(b))
+            }
+
             #[inline]
             fn swap_bytes(self) -> Self {
                 // Safety: `self` is an integer vector
@@ -375,14 +375,14 @@ pub fn load_or_default(slice: &[T]) -> Self
     #[must_use]
     #[inline]
     pub fn load_or(slice: &[T], or: Self) -> Self {
-        Self::load_select(slice, Mask::splat(true), or)
+        Self::load_select_or(slice, Mask::splat(true), or)
     }
 
     /// Reads contiguous elements from `slice`. Each element is read from memory if its
     /// corresponding element in `enable` is `true`.
     ///
     /// When the element is disabled or out of bounds for the slice, that memory location
-    /// is not accessed and the corresponding value from `or` is passed through.
+    /// is not accessed and the default value for the element type is returned.
     ///
     /// # Examples
     /// ```
@@ -390,12 +390,11 @@ pub fn load_or(slice: &[T], or: Self) -> Self {
     /// # #[cfg(feature = "as_crate")] use core_simd::simd;
     /// # #[cfg(not(feature = "as_crate"))] use core::simd;
     /// # use simd::{Simd, Mask};
-    /// let vec: Vec<i32> = vec![10, 11, 12, 13, 14, 15, 16, 17, 18];
-    /// let enable = Mask::from_array([true, true, false, true]);
-    /// let or = Simd::from_array([-5, -4, -3, -2]);
+    /// let vec: Vec<i32> = vec![10, 11, 12];
+    /// let enable = Mask::from_array([true, false, true, true]);
     ///
-    /// let result = Simd::load_select(&vec, enable, or);
-    /// assert_eq!(result, Simd::from_array([10, 11, -3, 13]));
+    /// let result = Simd::load_select_or_default(&vec, enable);
+    /// assert_eq!(result, Simd::from_array([10, 0, 12, 0]));
     /// ```
     #[must_use]
     #[inline]
@@ -403,7 +402,39 @@ pub fn load_select_or_default(slice: &[T], enable: Mask<<T as SimdElement>::Mask
     where
         T: Default,
     {
-        Self::load_select(slice, enable, Default::default())
+        Self::load_select_or(slice, enable, Default::default())
+    }
+
+    /// Reads contiguous elements from `slice`. Each element is read from memory if its
+    /// corresponding element in `enable` is `true`.
+    ///
+    /// When the element is disabled or out of bounds for the slice, that memory location
+    /// is not accessed and the corresponding value from `or` is passed through.
+    ///
+    /// This is the same as [`Self::load_select`], named after the other loads that take an `or`
+    /// vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, Mask};
+    /// let vec: Vec<i32> = vec![10, 11, 12];
+    /// let enable = Mask::from_array([true, false, true, true]);
+    /// let or = Simd::from_array([-5, -4, -3, -2]);
+    ///
+    /// let result = Simd::load_select_or(&vec, enable, or);
+    /// assert_eq!(result, Simd::from_array([10, -4, 12, -2]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn load_select_or(
+        slice: &[T],
+        enable: Mask<<T as SimdElement>::Mask, N>,
+        or: Self,
+    ) -> Self {
+        Self::load_select(slice, enable, or)
     }
 
     /// Reads contiguous elements from `slice`. Each element is read from memory if its
@@ -884,6 +915,48 @@ pub unsafe fn scatter_select_ptr(self, dest: Simd<*mut T, N>, enable: Mask<isize
         // Safety: The caller is responsible for upholding all invariants
         unsafe { core::intrinsics::simd::simd_scatter(self, dest, enable.to_int()) }
     }
+
+    /// Computes the inclusive scan of the vector with the associative operation `op`.
+    ///
+    /// Element `i` of the result is `x[0] op x[1] op ... op x[i]`. `identity` must be the identity
+    /// of `op` (e.g. `0` for addition) and is used to pad vectors as they are shifted.
+    ///
+    /// `op` is applied to whole vectors, `log2(N)` times, so when it is made of elementwise
+    /// operations the scan stays in SIMD registers. Because the operations are reassociated, the
+    /// result of a non-associative `op`, such as floating-point addition, may differ slightly
+    /// from a sequential scan.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let v = Simd::from_array([3, 1, 4, 1, 5, 9, 2, 6]);
+    /// assert_eq!(v.scan(0, |a, b| a + b).to_array(), [3, 4, 8, 9, 14, 23, 25, 31]);
+    /// assert_eq!(v.scan(i32::MIN, |a, b| a.simd_max(b)).to_array(), [3, 3, 4, 4, 5, 9, 9, 9]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn scan<F>(self, identity: T, mut op: F) -> Self
+    where
+        F: FnMut(Self, Self) -> Self,
+    {
+        // Hillis-Steele scan: after the step with offset `k`, each element holds the combination
+        // of the (up to) `2 * k` elements ending at it. Supported lane counts are at most 64.
+        let mut acc = self;
+        macro_rules! step {
+            { $($offset:literal),* } => {
+                $(
+                if N > $offset {
+                    acc = op(acc.shift_elements_right::<$offset>(identity), acc);
+                }
+                )*
+            }
+        }
+        step! { 1, 2, 4, 8, 16, 32 }
+        acc
+    }
 }
 
 impl<T, const N: usize> Copy for Simd<T, N>
@@ -0,0 +1,64 @@
+#![feature(portable_simd)]
+use core_simd::simd::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gather() {
+    let arr = [10i32, 11, 12, 13, 14];
+    // index 7 is OOB
+    let idxs = usizex4::from_array([4, 7, 0, 2]);
+    let enable = Mask::from_array([true, true, false, true]);
+    let or = i32x4::from_array([-1, -2, -3, -4]);
+
+    assert_eq!(i32x4::gather_or(&arr, idxs, or), i32x4::from_array([14, -2, 10, 12]));
+    assert_eq!(i32x4::gather_or_default(&arr, idxs), i32x4::from_array([14, 0, 10, 12]));
+    assert_eq!(i32x4::gather_select(&arr, enable, idxs, or), i32x4::from_array([14, -2, -3, 12]));
+
+    let in_bounds = enable & idxs.simd_lt(Simd::splat(arr.len()));
+    // SAFETY: the OOB index is disabled.
+    let unchecked = unsafe { i32x4::gather_select_unchecked(&arr, in_bounds, idxs, or) };
+    assert_eq!(unchecked, i32x4::from_array([14, -2, -3, 12]));
+
+    let ptrs = Simd::splat(arr.as_ptr()).wrapping_add(usizex4::from_array([4, 1, 0, 2]));
+    // SAFETY: all the pointers are in bounds of `arr`.
+    assert_eq!(unsafe { i32x4::gather_ptr(ptrs) }, i32x4::from_array([14, 11, 10, 12]));
+    // SAFETY: all the pointers are in bounds of `arr`.
+    let selected = unsafe { i32x4::gather_select_ptr(ptrs, enable, or) };
+    assert_eq!(selected, i32x4::from_array([14, 11, -3, 12]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scatter() {
+    let mut arr = [0i32; 5];
+    // index 7 is OOB and dropped
+    let idxs = usizex4::from_array([4, 7, 0, 2]);
+    let enable = Mask::from_array([true, true, false, true]);
+
+    i32x4::from_array([1, 2, 3, 4]).scatter(&mut arr, idxs);
+    assert_eq!(arr, [3, 0, 4, 0, 1]);
+
+    i32x4::from_array([5, 6, 7, 8]).scatter_select(&mut arr, enable, idxs);
+    assert_eq!(arr, [3, 0, 8, 0, 5]);
+
+    let in_bounds = enable & idxs.simd_lt(Simd::splat(arr.len()));
+    // SAFETY: the OOB index is disabled.
+    unsafe { i32x4::from_array([9, 10, 11, 12]).scatter_select_unchecked(&mut arr, in_bounds, idxs) };
+    assert_eq!(arr, [3, 0, 12, 0, 9]);
+
+    let ptrs = Simd::splat(arr.as_mut_ptr()).wrapping_add(usizex4::from_array([1, 3, 0, 2]));
+    // SAFETY: all the pointers are in bounds of `arr`.
+    unsafe { i32x4::from_array([13, 14, 15, 16]).scatter_select_ptr(ptrs, enable) };
+    assert_eq!(arr, [3, 13, 16, 14, 9]);
+
+    let ptrs = Simd::splat(arr.as_mut_ptr()).wrapping_add(usizex4::from_array([1, 3, 0, 2]));
+    // SAFETY: all the pointers are in bounds of `arr`.
+    unsafe { i32x4::from_array([17, 18, 19, 20]).scatter_ptr(ptrs) };
+    assert_eq!(arr, [19, 17, 20, 18, 9]);
+}
@@ -33,3 +33,45 @@ fn masked_load_store() {
         u8x4::from_array([3, 42, 0, 42])
     );
 }
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn masked_load_select() {
+    let arr = [10u8, 11, 12, 13, 14, 15];
+    let enable = Mask::from_array([true, false, true, true]);
+
+    // read from index 6 is OOB and dropped
+    assert_eq!(
+        u8x4::load_select_or(&arr[3..], enable, u8x4::splat(42)),
+        u8x4::from_array([13, 42, 15, 42])
+    );
+    assert_eq!(
+        u8x4::load_select_or_default(&arr[3..], enable),
+        u8x4::from_array([13, 0, 15, 0])
+    );
+    assert_eq!(u8x4::load_or_default(&arr[4..]), u8x4::from_array([14, 15, 0, 0]));
+
+    let in_bounds = enable & Mask::from_array([true, true, true, false]);
+    // SAFETY: the enabled elements are in bounds.
+    let unchecked = unsafe { u8x4::load_select_unchecked(&arr[3..], in_bounds, u8x4::splat(42)) };
+    assert_eq!(unchecked, u8x4::from_array([13, 42, 15, 42]));
+
+    // SAFETY: the enabled elements are in bounds.
+    let from_ptr = unsafe { u8x4::load_select_ptr(arr[2..].as_ptr(), enable, u8x4::splat(42)) };
+    assert_eq!(from_ptr, u8x4::from_array([12, 42, 14, 15]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn masked_store_select() {
+    let mut arr = [0u8; 6];
+    let enable = Mask::from_array([true, false, true, true]);
+
+    // SAFETY: the enabled elements are in bounds.
+    unsafe { u8x4::from_array([1, 2, 3, 4]).store_select_unchecked(&mut arr[2..], enable) };
+    assert_eq!(arr, [0, 0, 1, 0, 3, 4]);
+
+    // SAFETY: the enabled elements are in bounds.
+    unsafe { u8x4::from_array([5, 6, 7, 8]).store_select_ptr(arr.as_mut_ptr(), enable) };
+    assert_eq!(arr, [5, 0, 7, 8, 3, 4]);
+}
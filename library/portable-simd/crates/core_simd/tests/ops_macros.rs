@@ -200,6 +200,76 @@ fn reduce_min<const LANES: usize>() {
                 });
             }
 
+            fn scan<const LANES: usize>() {
+                test_helpers::test_1(&|x| {
+                    let mut expected = x;
+                    for i in 1..LANES {
+                        expected[i] = expected[i - 1] ^ x[i];
+                    }
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).scan(0, |a, b| a ^ b).to_array(),
+                        expected,
+                    );
+                    Ok(())
+                });
+            }
+
+            fn prefix_sum<const LANES: usize>() {
+                test_helpers::test_1(&|x| {
+                    let mut expected = x;
+                    for i in 1..LANES {
+                        expected[i] = expected[i - 1].wrapping_add(x[i]);
+                    }
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).prefix_sum().to_array(),
+                        expected,
+                    );
+                    Ok(())
+                });
+            }
+
+            fn prefix_product<const LANES: usize>() {
+                test_helpers::test_1(&|x| {
+                    let mut expected = x;
+                    for i in 1..LANES {
+                        expected[i] = expected[i - 1].wrapping_mul(x[i]);
+                    }
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).prefix_product().to_array(),
+                        expected,
+                    );
+                    Ok(())
+                });
+            }
+
+            fn prefix_max<const LANES: usize>() {
+                test_helpers::test_1(&|x| {
+                    let mut expected = x;
+                    for i in 1..LANES {
+                        expected[i] = expected[i - 1].max(x[i]);
+                    }
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).prefix_max().to_array(),
+                        expected,
+                    );
+                    Ok(())
+                });
+            }
+
+            fn prefix_min<const LANES: usize>() {
+                test_helpers::test_1(&|x| {
+                    let mut expected = x;
+                    for i in 1..LANES {
+                        expected[i] = expected[i - 1].min(x[i]);
+                    }
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).prefix_min().to_array(),
+                        expected,
+                    );
+                    Ok(())
+                });
+            }
+
             fn swap_bytes<const LANES: usize>() {
                 test_helpers::test_unary_elementwise(
                     &$vector::<LANES>::swap_bytes,
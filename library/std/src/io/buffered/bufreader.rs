@@ -2,11 +2,13 @@
 
 use buffer::Buffer;
 
-use crate::fmt;
+use core::slice::memchr;
+
 use crate::io::{
     self, BorrowedCursor, BufRead, DEFAULT_BUF_SIZE, IoSliceMut, Read, Seek, SeekFrom, SizeHint,
     SpecReadByte, uninlined_slow_read_byte,
 };
+use crate::{fmt, str};
 
 /// The `BufReader<R>` struct adds buffering to any reader.
 ///
@@ -150,6 +152,128 @@ pub fn peek(&mut self, n: usize) -> io::Result<&[u8]> {
         }
         Ok(&self.buf.buffer()[..n])
     }
+
+    /// Returns an iterator-like reader over the records of this reader split on the byte `delim`.
+    ///
+    /// Unlike [`BufRead::split`], the records are returned as slices pointing into this reader's
+    /// internal buffer, so no allocation happens per record. Each record is only valid until the
+    /// next call to [`SplitBorrowed::next`], which is why the returned type does not implement
+    /// [`Iterator`].
+    ///
+    /// Records do not include the delimiter. The last record is returned even if it is not
+    /// terminated by `delim`. If a record is longer than the buffer's [capacity], the buffer is
+    /// grown to hold it; the capacity is never reduced again.
+    ///
+    /// [capacity]: BufReader::capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufreader_borrowed_lines)]
+    /// use std::io::BufReader;
+    ///
+    /// let data = &b"lorem,ipsum,dolor"[..];
+    /// let mut reader = BufReader::with_capacity(4, data);
+    /// let mut split = reader.split_borrowed(b',');
+    /// assert_eq!(split.next().unwrap().unwrap(), b"lorem");
+    /// assert_eq!(split.next().unwrap().unwrap(), b"ipsum");
+    /// assert_eq!(split.next().unwrap().unwrap(), b"dolor");
+    /// assert!(split.next().is_none());
+    /// ```
+    #[unstable(feature = "bufreader_borrowed_lines", issue = "none")]
+    pub fn split_borrowed(&mut self, delim: u8) -> SplitBorrowed<'_, R> {
+        SplitBorrowed { reader: self, delim }
+    }
+
+    /// Calls `f` with each line of this reader, without allocating a `String` per line.
+    ///
+    /// Lines are split on newline bytes (`0xA`), and a trailing carriage return (`0xD`) is
+    /// removed, just like [`BufRead::lines`]. The line passed to `f` points into this reader's
+    /// internal buffer, which is grown only when a line is longer than its [capacity].
+    ///
+    /// [capacity]: BufReader::capacity
+    ///
+    /// # Errors
+    ///
+    /// Stops and returns an error if reading from the underlying reader fails, or if a line is
+    /// not valid UTF-8. Lines before the failing one have already been passed to `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufreader_borrowed_lines)]
+    /// use std::io::BufReader;
+    ///
+    /// let data = &b"first\r\nsecond\nthird"[..];
+    /// let mut lengths = Vec::new();
+    /// BufReader::new(data).for_each_line(|line| lengths.push(line.len())).unwrap();
+    /// assert_eq!(lengths, [5, 6, 5]);
+    /// ```
+    #[unstable(feature = "bufreader_borrowed_lines", issue = "none")]
+    pub fn for_each_line<F>(&mut self, mut f: F) -> io::Result<()>
+    where
+        F: FnMut(&str),
+    {
+        while let Some(line) = self.next_record(b'\n')? {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            f(str::from_utf8(line).map_err(|_| io::Error::INVALID_UTF8)?);
+        }
+        Ok(())
+    }
+
+    /// Returns the next `delim`-terminated record from the buffer (without the delimiter),
+    /// reading more data and growing the buffer as needed. Returns `None` at EOF.
+    fn next_record(&mut self, delim: u8) -> io::Result<Option<&[u8]>> {
+        // Bytes already known not to contain `delim`.
+        let mut searched = 0;
+        loop {
+            let available = self.buf.buffer();
+            if let Some(i) = memchr::memchr(delim, &available[searched..]) {
+                let record = self.buf.consume_ref(searched + i + 1);
+                return Ok(Some(&record[..record.len() - 1]));
+            }
+            searched = available.len();
+
+            // Make room for more data: first reclaim consumed bytes, then grow.
+            if self.buf.pos() > 0 {
+                self.buf.backshift();
+            }
+            if self.buf.filled() == self.buf.capacity() {
+                let capacity = self.buf.capacity().saturating_mul(2).max(DEFAULT_BUF_SIZE);
+                self.buf.grow(capacity);
+            }
+
+            match self.buf.read_more(&mut self.inner) {
+                Ok(0) if searched == 0 => return Ok(None),
+                Ok(0) => return Ok(Some(self.buf.consume_ref(searched))),
+                Ok(_) => {}
+                Err(e) if e.is_interrupted() => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// A reader over the records of a [`BufReader`] that borrows each record from the reader's
+/// internal buffer.
+///
+/// This struct is created by [`BufReader::split_borrowed`]. See its documentation for more.
+#[unstable(feature = "bufreader_borrowed_lines", issue = "none")]
+#[derive(Debug)]
+pub struct SplitBorrowed<'a, R: ?Sized> {
+    reader: &'a mut BufReader<R>,
+    delim: u8,
+}
+
+impl<R: Read + ?Sized> SplitBorrowed<'_, R> {
+    /// Returns the next record, or `None` once the underlying reader reaches EOF.
+    ///
+    /// The returned slice borrows from the reader's buffer and must be dropped before the next
+    /// call.
+    #[unstable(feature = "bufreader_borrowed_lines", issue = "none")]
+    pub fn next(&mut self) -> Option<io::Result<&[u8]>> {
+        self.reader.next_record(self.delim).transpose()
+    }
 }
 
 impl<R: ?Sized> BufReader<R> {
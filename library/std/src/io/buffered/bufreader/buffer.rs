@@ -102,6 +102,33 @@ pub fn consume_with<V>(&mut self, amt: usize, mut visitor: V) -> bool
         }
     }
 
+    /// Consumes `amt` bytes and returns them. The bytes stay valid in the buffer until the next
+    /// call that reads into or rearranges it.
+    ///
+    /// Panics if fewer than `amt` bytes are available.
+    #[inline]
+    pub fn consume_ref(&mut self, amt: usize) -> &[u8] {
+        let start = self.pos;
+        self.pos = start + amt;
+        assert!(self.pos <= self.filled);
+        // SAFETY: `start..self.pos` lies within `..self.filled`, which is initialized.
+        unsafe { self.buf.get_unchecked(start..self.pos).assume_init_ref() }
+    }
+
+    /// Moves the unconsumed bytes to the front of a new allocation of `capacity` bytes.
+    ///
+    /// `capacity` must be at least the number of unconsumed bytes.
+    pub fn grow(&mut self, capacity: usize) {
+        let len = self.filled - self.pos;
+        assert!(capacity >= len);
+        let mut buf = Box::new_uninit_slice(capacity);
+        buf[..len].copy_from_slice(&self.buf[self.pos..self.filled]);
+        self.buf = buf;
+        self.pos = 0;
+        self.filled = len;
+        self.initialized = len;
+    }
+
     #[inline]
     pub fn unconsume(&mut self, amt: usize) {
         self.pos = self.pos.saturating_sub(amt);
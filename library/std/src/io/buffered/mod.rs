@@ -8,6 +8,8 @@
 #[cfg(test)]
 mod tests;
 
+#[unstable(feature = "bufreader_borrowed_lines", issue = "none")]
+pub use bufreader::SplitBorrowed;
 #[stable(feature = "bufwriter_into_parts", since = "1.56.0")]
 pub use bufwriter::WriterPanicked;
 use linewritershim::LineWriterShim;
@@ -263,6 +263,35 @@ fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
     assert_eq!(reader.get_ref().pos, expected);
 }
 
+#[test]
+fn test_buffered_reader_split_borrowed() {
+    let data = b"ab,,a record longer than the buffer,c";
+    let mut reader = BufReader::with_capacity(4, &data[..]);
+    let mut split = reader.split_borrowed(b',');
+    let mut records = Vec::new();
+    while let Some(record) = split.next() {
+        records.push(record.unwrap().to_vec());
+    }
+    assert_eq!(records, [&b"ab"[..], b"", b"a record longer than the buffer", b"c"]);
+    assert!(reader.capacity() >= 31);
+    assert!(reader.split_borrowed(b',').next().is_none());
+}
+
+#[test]
+fn test_buffered_reader_for_each_line() {
+    let data = b"a\nbb\r\n\nlonger than the buffer\ntail";
+    let mut reader = BufReader::with_capacity(5, &data[..]);
+    let mut lines = Vec::new();
+    reader.for_each_line(|line| lines.push(line.to_owned())).unwrap();
+    assert_eq!(lines, ["a", "bb", "", "longer than the buffer", "tail"]);
+
+    let mut reader = BufReader::new(&b"ok\n\xff\n"[..]);
+    let mut lines = Vec::new();
+    let err = reader.for_each_line(|line| lines.push(line.to_owned())).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(lines, ["ok"]);
+}
+
 #[test]
 fn test_buffered_reader_seek_underflow_discard_buffer_between_seeks() {
     // gimmick reader that returns Err after first seek
@@ -4,6 +4,7 @@
 use crate::collections::VecDeque;
 use crate::io::IoSlice;
 use crate::mem::MaybeUninit;
+use crate::num::NonZero;
 
 #[cfg(test)]
 mod tests;
@@ -72,6 +73,143 @@ pub fn copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> Result<u64>
     }
 }
 
+/// Copies at most `limit` bytes from a reader into a writer.
+///
+/// This behaves like [`copy`] on `reader.take(limit)`: data is streamed from
+/// `reader` to `writer` until either `limit` bytes have been copied or
+/// `reader` returns EOF. Bytes beyond the limit are left unread in `reader`.
+///
+/// On success, the total number of bytes that were copied from `reader` to
+/// `writer` is returned. This is less than `limit` only if EOF was reached.
+///
+/// # Errors
+///
+/// This function will return an error immediately if any call to [`read`] or
+/// [`write`] returns an error. All instances of [`ErrorKind::Interrupted`] are
+/// handled by this function and the underlying operation is retried.
+///
+/// [`read`]: Read::read
+/// [`write`]: Write::write
+/// [`ErrorKind::Interrupted`]: crate::io::ErrorKind::Interrupted
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_copy_n)]
+/// use std::io;
+///
+/// fn main() -> io::Result<()> {
+///     let mut reader: &[u8] = b"hello world";
+///     let mut writer: Vec<u8> = vec![];
+///
+///     assert_eq!(io::copy_n(&mut reader, &mut writer, 5)?, 5);
+///
+///     assert_eq!(&b"hello"[..], &writer[..]);
+///     assert_eq!(&b" world"[..], reader);
+///     Ok(())
+/// }
+/// ```
+///
+/// # Platform-specific behavior
+///
+/// Like [`copy`], on Linux (including Android) this function uses
+/// `copy_file_range(2)`, `sendfile(2)` or `splice(2)` syscalls to move data
+/// directly between file descriptors if possible.
+///
+/// Note that platform-specific behavior [may change in the future][changes].
+///
+/// [changes]: crate::io#platform-specific-behavior
+#[unstable(feature = "io_copy_n", issue = "none")]
+pub fn copy_n<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W, limit: u64) -> Result<u64>
+where
+    R: Read,
+    W: Write,
+{
+    // `Take<&mut R>` is understood by the Linux `kernel_copy` specializations,
+    // which honor the limit when offloading to the kernel.
+    copy(&mut Read::take(reader, limit), writer)
+}
+
+/// Copies the entire contents of a reader into a writer, reporting progress
+/// along the way.
+///
+/// This behaves like [`copy`], except that the copy is performed in chunks
+/// of `interval` bytes and `progress` is called after each chunk with the
+/// total number of bytes copied so far. The last call may report less than a
+/// full chunk; no call is made for a chunk in which nothing was copied.
+///
+/// On success, the total number of bytes that were copied from `reader` to
+/// `writer` is returned.
+///
+/// # Errors
+///
+/// This function will return an error immediately if any call to [`read`] or
+/// [`write`] returns an error. All instances of [`ErrorKind::Interrupted`] are
+/// handled by this function and the underlying operation is retried. Bytes
+/// copied by the failing chunk are not reported to `progress`.
+///
+/// [`read`]: Read::read
+/// [`write`]: Write::write
+/// [`ErrorKind::Interrupted`]: crate::io::ErrorKind::Interrupted
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_copy_progress)]
+/// use std::io;
+/// use std::num::NonZero;
+///
+/// fn main() -> io::Result<()> {
+///     let mut reader: &[u8] = &[0; 10];
+///     let mut writer: Vec<u8> = vec![];
+///     let mut reports = vec![];
+///
+///     let interval = NonZero::new(4).unwrap();
+///     io::copy_with_progress(&mut reader, &mut writer, interval, |n| reports.push(n))?;
+///
+///     assert_eq!(reports, [4, 8, 10]);
+///     Ok(())
+/// }
+/// ```
+///
+/// # Platform-specific behavior
+///
+/// Each chunk is copied with [`copy_n`], so on Linux (including Android) the
+/// same kernel offloading as [`copy`] is used. Small intervals incur more
+/// syscalls; an interval of at least a few hundred kilobytes is recommended
+/// when copying between file descriptors.
+///
+/// Note that platform-specific behavior [may change in the future][changes].
+///
+/// [changes]: crate::io#platform-specific-behavior
+#[unstable(feature = "io_copy_progress", issue = "none")]
+pub fn copy_with_progress<R: ?Sized, W: ?Sized, F>(
+    reader: &mut R,
+    writer: &mut W,
+    interval: NonZero<u64>,
+    mut progress: F,
+) -> Result<u64>
+where
+    R: Read,
+    W: Write,
+    F: FnMut(u64),
+{
+    let interval = interval.get();
+    let mut total = 0u64;
+    loop {
+        let copied = copy_n(reader, writer, interval)?;
+        if copied == 0 {
+            return Ok(total);
+        }
+        total += copied;
+        progress(total);
+        // `copy_n` only stops short of its limit at EOF.
+        if copied < interval {
+            return Ok(total);
+        }
+    }
+}
+
 /// The userspace read-write-loop implementation of `io::copy` that is used when
 /// OS-specific specializations for copy offloading are not available or not applicable.
 pub(crate) fn generic_copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> Result<u64>
@@ -117,6 +117,34 @@ fn copy_specializes_from_slice() {
     assert_eq!(60 * 1024, sink.observed_buffer);
 }
 
+#[test]
+fn copy_n_stops_at_limit() {
+    let mut source = [1; 100].as_slice();
+    let mut sink = Vec::new();
+    assert_eq!(io::copy_n(&mut source, &mut sink, 30).unwrap(), 30);
+    assert_eq!(sink.len(), 30);
+    assert_eq!(source.len(), 70);
+
+    assert_eq!(io::copy_n(&mut source, &mut sink, 1000).unwrap(), 70);
+    assert_eq!(sink.len(), 100);
+}
+
+#[test]
+fn copy_with_progress_reports_totals() {
+    let mut r = repeat(0).take(10);
+    let mut w = sink();
+    let mut reports = Vec::new();
+    let interval = crate::num::NonZero::new(4).unwrap();
+    let copied = io::copy_with_progress(&mut r, &mut w, interval, |n| reports.push(n)).unwrap();
+    assert_eq!(copied, 10);
+    assert_eq!(reports, [4, 8, 10]);
+
+    let mut r = repeat(0).take(8);
+    reports.clear();
+    io::copy_with_progress(&mut r, &mut w, interval, |n| reports.push(n)).unwrap();
+    assert_eq!(reports, [4, 8]);
+}
+
 #[cfg(unix)]
 mod io_benches {
     use test::Bencher;
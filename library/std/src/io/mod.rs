@@ -301,6 +301,8 @@
 pub use core::io::{BorrowedBuf, BorrowedCursor};
 use core::slice::memchr;
 
+#[unstable(feature = "bufreader_borrowed_lines", issue = "none")]
+pub use self::buffered::SplitBorrowed;
 #[stable(feature = "bufwriter_into_parts", since = "1.56.0")]
 pub use self::buffered::WriterPanicked;
 #[unstable(feature = "raw_os_error_ty", issue = "107792")]
@@ -310,8 +312,17 @@
 pub use self::error::SimpleMessage;
 #[unstable(feature = "io_const_error", issue = "133448")]
 pub use self::error::const_error;
+#[unstable(feature = "io_copy_n", issue = "none")]
+pub use self::copy::copy_n;
+#[unstable(feature = "io_copy_progress", issue = "none")]
+pub use self::copy::copy_with_progress;
+#[unstable(feature = "pipe_options", issue = "none")]
+pub use self::pipe::PipeOptions;
 #[stable(feature = "anonymous_pipe", since = "1.87.0")]
 pub use self::pipe::{PipeReader, PipeWriter, pipe};
+#[cfg(unix)]
+#[unstable(feature = "io_poller", issue = "none")]
+pub use self::poll::{Event, Events, EventsIter, Interest, Poller};
 #[stable(feature = "is_terminal", since = "1.70.0")]
 pub use self::stdio::IsTerminal;
 pub(crate) use self::stdio::attempt_print_to_stderr;
@@ -340,6 +351,8 @@
 mod error;
 mod impls;
 mod pipe;
+#[cfg(unix)]
+mod poll;
 pub mod prelude;
 mod stdio;
 mod util;
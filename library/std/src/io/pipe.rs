@@ -1,7 +1,10 @@
 use crate::io;
-use crate::sys::anonymous_pipe::{AnonPipe, pipe as pipe_inner};
+use crate::sys::anonymous_pipe::{self as imp, AnonPipe, pipe as pipe_inner};
 use crate::sys_common::{FromInner, IntoInner};
 
+#[cfg(test)]
+mod tests;
+
 /// Creates an anonymous pipe.
 ///
 /// # Behavior
@@ -87,6 +90,100 @@ pub fn pipe() -> io::Result<(PipeReader, PipeWriter)> {
     pipe_inner().map(|(reader, writer)| (PipeReader(reader), PipeWriter(writer)))
 }
 
+/// Options and flags which can be used to configure how an anonymous pipe is created.
+///
+/// This builder exposes the ability to configure how a [`PipeReader`] and [`PipeWriter`] pair
+/// is created. Calling [`pipe`] is equivalent to calling `PipeOptions::new().open()`.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(pipe_options)]
+/// use std::io::PipeOptions;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let (reader, writer) = PipeOptions::new().nonblocking(true).capacity(1 << 20).open()?;
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "pipe_options", issue = "none")]
+#[derive(Clone, Debug, Default)]
+pub struct PipeOptions {
+    nonblocking: bool,
+    packet_mode: bool,
+    capacity: Option<usize>,
+}
+
+impl PipeOptions {
+    /// Creates a blank new set of options, equivalent to those used by [`pipe`].
+    #[unstable(feature = "pipe_options", issue = "none")]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether both ends of the pipe are created in non-blocking mode.
+    ///
+    /// Reads from an empty non-blocking pipe and writes to a full one return an error of kind
+    /// [`io::ErrorKind::WouldBlock`] instead of blocking. See also
+    /// [`PipeReader::set_nonblocking`] and [`PipeWriter::set_nonblocking`].
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This corresponds to the `O_NONBLOCK` flag on Unix. It is not supported on Windows, where
+    /// [`open`](PipeOptions::open) returns an error of kind [`io::ErrorKind::Unsupported`].
+    #[unstable(feature = "pipe_options", issue = "none")]
+    pub fn nonblocking(&mut self, nonblocking: bool) -> &mut Self {
+        self.nonblocking = nonblocking;
+        self
+    }
+
+    /// Sets whether the pipe operates in "packet" mode.
+    ///
+    /// In packet mode each write is a separate packet, and each read returns at most one packet.
+    /// Writes larger than `PIPE_BUF` are split into multiple packets.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This corresponds to the `O_DIRECT` flag of `pipe2` on Linux and Android. On other
+    /// platforms [`open`](PipeOptions::open) returns an error of kind
+    /// [`io::ErrorKind::Unsupported`].
+    #[unstable(feature = "pipe_options", issue = "none")]
+    pub fn packet_mode(&mut self, packet_mode: bool) -> &mut Self {
+        self.packet_mode = packet_mode;
+        self
+    }
+
+    /// Sets the requested capacity of the pipe buffer in bytes.
+    ///
+    /// The operating system may round the capacity up; use [`PipeWriter::capacity`] to query
+    /// the actual value.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This corresponds to `F_SETPIPE_SZ` on Linux and Android, where raising the capacity above
+    /// `/proc/sys/fs/pipe-max-size` requires `CAP_SYS_RESOURCE`. On Windows it is passed as the
+    /// size suggestion to `CreatePipe`. On other platforms [`open`](PipeOptions::open) returns an
+    /// error of kind [`io::ErrorKind::Unsupported`].
+    #[unstable(feature = "pipe_options", issue = "none")]
+    pub fn capacity(&mut self, capacity: usize) -> &mut Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Creates an anonymous pipe with the options specified by `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pipe cannot be created, or if an option is not supported on the
+    /// current platform.
+    #[unstable(feature = "pipe_options", issue = "none")]
+    pub fn open(&self) -> io::Result<(PipeReader, PipeWriter)> {
+        imp::pipe_with(self.nonblocking, self.packet_mode, self.capacity)
+            .map(|(reader, writer)| (PipeReader(reader), PipeWriter(writer)))
+    }
+}
+
 /// Read end of an anonymous pipe.
 #[stable(feature = "anonymous_pipe", since = "1.87.0")]
 #[derive(Debug)]
@@ -178,6 +275,45 @@ impl PipeReader {
     pub fn try_clone(&self) -> io::Result<Self> {
         self.0.try_clone().map(Self)
     }
+
+    /// Moves this end of the pipe into or out of non-blocking mode.
+    ///
+    /// In non-blocking mode, reading from an empty pipe returns an error of kind
+    /// [`io::ErrorKind::WouldBlock`] instead of blocking. The mode is a property of the
+    /// underlying file description, so it is shared with all clones of this reader.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This is not supported on Windows, where an error of kind [`io::ErrorKind::Unsupported`]
+    /// is returned.
+    #[unstable(feature = "pipe_options", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        imp::set_nonblocking(&self.0, nonblocking)
+    }
+
+    /// Returns the capacity of the pipe buffer in bytes.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This corresponds to `F_GETPIPE_SZ` on Linux and Android. On other platforms an error of
+    /// kind [`io::ErrorKind::Unsupported`] is returned.
+    #[unstable(feature = "pipe_options", issue = "none")]
+    pub fn capacity(&self) -> io::Result<usize> {
+        imp::capacity(&self.0)
+    }
+
+    /// Changes the capacity of the pipe buffer, returning the capacity actually set.
+    ///
+    /// See [`PipeOptions::capacity`] for details.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This corresponds to `F_SETPIPE_SZ` on Linux and Android. On other platforms an error of
+    /// kind [`io::ErrorKind::Unsupported`] is returned.
+    #[unstable(feature = "pipe_options", issue = "none")]
+    pub fn set_capacity(&self, capacity: usize) -> io::Result<usize> {
+        imp::set_capacity(&self.0, capacity)
+    }
 }
 
 impl PipeWriter {
@@ -217,6 +353,45 @@ impl PipeWriter {
     pub fn try_clone(&self) -> io::Result<Self> {
         self.0.try_clone().map(Self)
     }
+
+    /// Moves this end of the pipe into or out of non-blocking mode.
+    ///
+    /// In non-blocking mode, writing to a full pipe returns an error of kind
+    /// [`io::ErrorKind::WouldBlock`] instead of blocking. The mode is a property of the
+    /// underlying file description, so it is shared with all clones of this writer.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This is not supported on Windows, where an error of kind [`io::ErrorKind::Unsupported`]
+    /// is returned.
+    #[unstable(feature = "pipe_options", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        imp::set_nonblocking(&self.0, nonblocking)
+    }
+
+    /// Returns the capacity of the pipe buffer in bytes.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This corresponds to `F_GETPIPE_SZ` on Linux and Android. On other platforms an error of
+    /// kind [`io::ErrorKind::Unsupported`] is returned.
+    #[unstable(feature = "pipe_options", issue = "none")]
+    pub fn capacity(&self) -> io::Result<usize> {
+        imp::capacity(&self.0)
+    }
+
+    /// Changes the capacity of the pipe buffer, returning the capacity actually set.
+    ///
+    /// See [`PipeOptions::capacity`] for details.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This corresponds to `F_SETPIPE_SZ` on Linux and Android. On other platforms an error of
+    /// kind [`io::ErrorKind::Unsupported`] is returned.
+    #[unstable(feature = "pipe_options", issue = "none")]
+    pub fn set_capacity(&self, capacity: usize) -> io::Result<usize> {
+        imp::set_capacity(&self.0, capacity)
+    }
 }
 
 #[stable(feature = "anonymous_pipe", since = "1.87.0")]
@@ -16,3 +16,35 @@ fn pipe_creation_clone_and_rw() {
     drop(rx2);
     assert_eq!(s, "12345");
 }
+
+#[test]
+#[cfg(all(any(target_os = "linux", target_os = "android"), not(miri)))]
+fn pipe_options_nonblocking_and_capacity() {
+    use crate::io::{ErrorKind, PipeOptions};
+
+    let (mut rx, tx) = PipeOptions::new().nonblocking(true).capacity(64 * 1024).open().unwrap();
+    assert!(tx.capacity().unwrap() >= 64 * 1024);
+    assert_eq!(rx.capacity().unwrap(), tx.capacity().unwrap());
+
+    let mut buf = [0; 4];
+    assert_eq!(rx.read(&mut buf).unwrap_err().kind(), ErrorKind::WouldBlock);
+
+    rx.set_nonblocking(false).unwrap();
+    let new_capacity = tx.set_capacity(4096).unwrap();
+    assert!(new_capacity >= 4096);
+    assert_eq!(tx.capacity().unwrap(), new_capacity);
+}
+
+#[test]
+#[cfg(all(any(target_os = "linux", target_os = "android"), not(miri)))]
+fn pipe_options_packet_mode() {
+    use crate::io::PipeOptions;
+
+    let (mut rx, mut tx) = PipeOptions::new().packet_mode(true).open().unwrap();
+    tx.write_all(b"one").unwrap();
+    tx.write_all(b"two").unwrap();
+
+    let mut buf = [0; 16];
+    assert_eq!(rx.read(&mut buf).unwrap(), 3);
+    assert_eq!(&buf[..3], b"one");
+}
@@ -0,0 +1,374 @@
+#[cfg(test)]
+mod tests;
+
+use crate::os::fd::AsFd;
+use crate::sys::io::poll as imp;
+use crate::time::Duration;
+use crate::{fmt, io, ops};
+
+/// Waits for readiness events on many I/O sources at once.
+///
+/// A `Poller` lets a single thread wait until any of a set of registered
+/// sources — sockets, pipes, terminals and anything else implementing
+/// [`AsFd`] — can be read from or written to without blocking. Each source is
+/// registered with a caller-chosen `token`, which is handed back in the
+/// [`Event`]s returned by [`Poller::poll`] to identify which source is ready.
+///
+/// Registrations are *level-triggered*: as long as a source remains ready
+/// (for example, because unread data is still buffered), every call to
+/// [`poll`] will keep reporting it. Sources are usually put into
+/// non-blocking mode (for example with [`TcpStream::set_nonblocking`]) so that
+/// a read or write after a readiness event can't block if another reader
+/// raced for the data, and so that I/O can continue until it returns
+/// [`ErrorKind::WouldBlock`].
+///
+/// A source must be deregistered before it is closed. The poller does not
+/// take ownership of registered sources.
+///
+/// # Platform-specific behavior
+///
+/// This currently corresponds to `epoll` on Linux and Android and to `kqueue`
+/// on Apple platforms, FreeBSD and DragonFly BSD. On other Unix platforms
+/// [`Poller::new`] returns an error of kind [`ErrorKind::Unsupported`].
+///
+/// Note that this [may change in the future][changes].
+///
+/// [`poll`]: Poller::poll
+/// [`TcpStream::set_nonblocking`]: crate::net::TcpStream::set_nonblocking
+/// [`ErrorKind::WouldBlock`]: io::ErrorKind::WouldBlock
+/// [`ErrorKind::Unsupported`]: io::ErrorKind::Unsupported
+/// [changes]: io#platform-specific-behavior
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(io_poller)]
+/// use std::io::{Events, Interest, Poller, Read};
+/// use std::net::TcpListener;
+///
+/// fn main() -> std::io::Result<()> {
+///     let listener = TcpListener::bind("127.0.0.1:8080")?;
+///     listener.set_nonblocking(true)?;
+///
+///     let poller = Poller::new()?;
+///     poller.register(&listener, 0, Interest::READABLE)?;
+///
+///     let mut events = Events::with_capacity(64);
+///     loop {
+///         poller.poll(&mut events, None)?;
+///         for event in &events {
+///             if event.token() == 0 {
+///                 let (mut stream, addr) = listener.accept()?;
+///                 let mut buf = [0; 1024];
+///                 let n = stream.read(&mut buf)?;
+///                 println!("{addr} sent {n} bytes");
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[unstable(feature = "io_poller", issue = "none")]
+pub struct Poller {
+    inner: imp::Poller,
+}
+
+/// The set of readiness conditions a [`Poller`] watches for on a source.
+///
+/// Interests can be combined with `|`:
+///
+/// ```
+/// #![feature(io_poller)]
+/// use std::io::Interest;
+///
+/// let both = Interest::READABLE | Interest::WRITABLE;
+/// assert!(both.is_readable() && both.is_writable());
+/// ```
+#[unstable(feature = "io_poller", issue = "none")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interest(u8);
+
+impl Interest {
+    const READ: u8 = 0b01;
+    const WRITE: u8 = 0b10;
+
+    /// Interest in the source becoming readable.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub const READABLE: Interest = Interest(Self::READ);
+
+    /// Interest in the source becoming writable.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub const WRITABLE: Interest = Interest(Self::WRITE);
+
+    /// Returns `true` if this includes [`Interest::READABLE`].
+    #[unstable(feature = "io_poller", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_readable(self) -> bool {
+        self.0 & Self::READ != 0
+    }
+
+    /// Returns `true` if this includes [`Interest::WRITABLE`].
+    #[unstable(feature = "io_poller", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_writable(self) -> bool {
+        self.0 & Self::WRITE != 0
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl ops::BitOr for Interest {
+    type Output = Interest;
+
+    #[inline]
+    fn bitor(self, rhs: Interest) -> Interest {
+        Interest(self.0 | rhs.0)
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl ops::BitOrAssign for Interest {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Interest) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl fmt::Debug for Interest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interest")
+            .field("readable", &self.is_readable())
+            .field("writable", &self.is_writable())
+            .finish()
+    }
+}
+
+/// A readiness event reported by [`Poller::poll`].
+///
+/// Depending on the platform, a source that is both readable and writable may
+/// be reported as a single event or as two separate events with the same
+/// token.
+#[unstable(feature = "io_poller", issue = "none")]
+#[derive(Clone, Copy)]
+pub struct Event {
+    inner: imp::Event,
+}
+
+impl Event {
+    /// Returns the token the source was registered with.
+    #[unstable(feature = "io_poller", issue = "none")]
+    #[must_use]
+    pub fn token(&self) -> usize {
+        self.inner.token()
+    }
+
+    /// Returns `true` if the source can be read from without blocking.
+    #[unstable(feature = "io_poller", issue = "none")]
+    #[must_use]
+    pub fn is_readable(&self) -> bool {
+        self.inner.is_readable()
+    }
+
+    /// Returns `true` if the source can be written to without blocking.
+    #[unstable(feature = "io_poller", issue = "none")]
+    #[must_use]
+    pub fn is_writable(&self) -> bool {
+        self.inner.is_writable()
+    }
+
+    /// Returns `true` if an error condition is pending on the source.
+    ///
+    /// The error itself is reported by the next I/O operation on the source.
+    #[unstable(feature = "io_poller", issue = "none")]
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        self.inner.is_error()
+    }
+
+    /// Returns `true` if the peer closed its end of the source, or the read
+    /// side was shut down.
+    ///
+    /// Buffered data may still be available to read.
+    #[unstable(feature = "io_poller", issue = "none")]
+    #[must_use]
+    pub fn is_hangup(&self) -> bool {
+        self.inner.is_hangup()
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl fmt::Debug for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Event")
+            .field("token", &self.token())
+            .field("readable", &self.is_readable())
+            .field("writable", &self.is_writable())
+            .field("error", &self.is_error())
+            .field("hangup", &self.is_hangup())
+            .finish()
+    }
+}
+
+/// A buffer of [`Event`]s filled in by [`Poller::poll`].
+///
+/// At most [`capacity`](Events::capacity) events are returned by each call
+/// to `poll`; any further ready sources are reported by the next call.
+#[unstable(feature = "io_poller", issue = "none")]
+pub struct Events {
+    inner: Vec<imp::Event>,
+}
+
+impl Events {
+    /// Creates a buffer that can hold up to `capacity` events.
+    ///
+    /// A `capacity` of zero is rounded up to one.
+    #[unstable(feature = "io_poller", issue = "none")]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Events {
+        Events { inner: Vec::with_capacity(capacity.max(1)) }
+    }
+
+    /// Returns the maximum number of events a single `poll` can return.
+    #[unstable(feature = "io_poller", issue = "none")]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Returns the number of events returned by the last `poll`.
+    #[unstable(feature = "io_poller", issue = "none")]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the last `poll` returned no events, for example
+    /// because it timed out.
+    #[unstable(feature = "io_poller", issue = "none")]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns an iterator over the events returned by the last `poll`.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn iter(&self) -> EventsIter<'_> {
+        EventsIter { inner: self.inner.iter() }
+    }
+
+    /// Removes all events from the buffer, keeping its capacity.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl fmt::Debug for Events {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl<'a> IntoIterator for &'a Events {
+    type Item = Event;
+    type IntoIter = EventsIter<'a>;
+
+    fn into_iter(self) -> EventsIter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the events in an [`Events`] buffer.
+///
+/// This struct is created by [`Events::iter`].
+#[unstable(feature = "io_poller", issue = "none")]
+#[derive(Clone)]
+pub struct EventsIter<'a> {
+    inner: crate::slice::Iter<'a, imp::Event>,
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl Iterator for EventsIter<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        self.inner.next().map(|&inner| Event { inner })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl ExactSizeIterator for EventsIter<'_> {}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl fmt::Debug for EventsIter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl Poller {
+    /// Creates a new poller with no registered sources.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn new() -> io::Result<Poller> {
+        imp::Poller::new().map(|inner| Poller { inner })
+    }
+
+    /// Starts watching `source` for the readiness conditions in `interest`.
+    ///
+    /// Events for the source carry `token`. Registering a source that is
+    /// already registered with this poller returns an error; use
+    /// [`reregister`](Poller::reregister) to change its interest or token.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn register<S: AsFd + ?Sized>(
+        &self,
+        source: &S,
+        token: usize,
+        interest: Interest,
+    ) -> io::Result<()> {
+        self.inner.register(source.as_fd(), token, interest.is_readable(), interest.is_writable())
+    }
+
+    /// Changes the token and interest of a registered source.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn reregister<S: AsFd + ?Sized>(
+        &self,
+        source: &S,
+        token: usize,
+        interest: Interest,
+    ) -> io::Result<()> {
+        self.inner.reregister(source.as_fd(), token, interest.is_readable(), interest.is_writable())
+    }
+
+    /// Stops watching a registered source.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn deregister<S: AsFd + ?Sized>(&self, source: &S) -> io::Result<()> {
+        self.inner.deregister(source.as_fd())
+    }
+
+    /// Blocks until at least one registered source is ready or `timeout`
+    /// elapses, and fills `events` with the ready sources.
+    ///
+    /// Any events left in `events` from a previous call are discarded. A
+    /// `timeout` of `None` waits indefinitely, while `Some(Duration::ZERO)`
+    /// returns immediately. The call may also return early with no events,
+    /// for example when interrupted by a signal, so callers should not
+    /// assume that an empty result means the timeout has elapsed.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn poll(&self, events: &mut Events, timeout: Option<Duration>) -> io::Result<()> {
+        self.inner.wait(&mut events.inner, timeout)
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl fmt::Debug for Poller {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Poller").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,57 @@
+use crate::io::{Events, Interest, Poller, Read, Write, pipe};
+use crate::time::Duration;
+
+#[test]
+fn interest_bitor() {
+    let both = Interest::READABLE | Interest::WRITABLE;
+    assert!(both.is_readable());
+    assert!(both.is_writable());
+    assert!(!Interest::READABLE.is_writable());
+    assert!(!Interest::WRITABLE.is_readable());
+}
+
+#[test]
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_vendor = "apple",
+        target_os = "freebsd"
+    ),
+    not(miri)
+))]
+fn poll_pipe_readiness() {
+    let (mut rx, mut tx) = pipe().unwrap();
+    let poller = Poller::new().unwrap();
+    poller.register(&rx, 7, Interest::READABLE).unwrap();
+
+    let mut events = Events::with_capacity(8);
+    poller.poll(&mut events, Some(Duration::ZERO)).unwrap();
+    assert!(events.is_empty());
+
+    tx.write_all(b"ping").unwrap();
+    poller.poll(&mut events, Some(Duration::from_secs(10))).unwrap();
+    let event = events.iter().next().unwrap();
+    assert_eq!(event.token(), 7);
+    assert!(event.is_readable());
+
+    // Level-triggered: still ready until the data is consumed.
+    poller.poll(&mut events, Some(Duration::ZERO)).unwrap();
+    assert_eq!(events.len(), 1);
+
+    let mut buf = [0; 4];
+    rx.read_exact(&mut buf).unwrap();
+    poller.poll(&mut events, Some(Duration::ZERO)).unwrap();
+    assert!(events.is_empty());
+
+    poller.reregister(&rx, 9, Interest::READABLE).unwrap();
+    drop(tx);
+    poller.poll(&mut events, Some(Duration::from_secs(10))).unwrap();
+    let event = events.iter().next().unwrap();
+    assert_eq!(event.token(), 9);
+    assert!(event.is_hangup());
+
+    poller.deregister(&rx).unwrap();
+    poller.poll(&mut events, Some(Duration::ZERO)).unwrap();
+    assert!(events.is_empty());
+}
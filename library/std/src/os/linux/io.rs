@@ -0,0 +1,58 @@
+//! Linux-specific extensions to the [`std::io`] module.
+//!
+//! [`std::io`]: crate::io
+
+#![unstable(feature = "linux_splice", issue = "none")]
+
+use crate::io::Result;
+use crate::os::unix::io::{AsFd, AsRawFd};
+
+/// Moves up to `len` bytes from `from` to `to` without copying them through
+/// userspace, using the `splice(2)` system call.
+///
+/// At least one of `from` and `to` must be a pipe. This is the building block
+/// for zero-copy proxying between sockets: splice from the receiving socket
+/// into a pipe, then from the pipe into the sending socket.
+///
+/// Returns the number of bytes moved, which may be less than `len`. A return
+/// value of `0` means `from` reached EOF, or `len` was zero.
+///
+/// Whether the call blocks is determined by the `O_NONBLOCK` flag of the two
+/// file descriptors: if either is in non-blocking mode and the transfer cannot
+/// proceed, an error of kind [`ErrorKind::WouldBlock`] is returned.
+///
+/// Unlike [`io::copy`], which already uses `splice` internally where
+/// possible, this function performs exactly one transfer and never falls back
+/// to a read/write loop. Descriptors that don't support splicing report an
+/// error (typically `EINVAL`).
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_splice)]
+/// use std::io;
+/// use std::net::TcpStream;
+/// use std::os::linux::io::splice;
+///
+/// fn proxy(from: &TcpStream, to: &TcpStream) -> io::Result<u64> {
+///     let (pipe_reader, pipe_writer) = io::pipe()?;
+///     let mut total = 0;
+///     loop {
+///         let n = splice(from, &pipe_writer, 64 * 1024)?;
+///         if n == 0 {
+///             return Ok(total);
+///         }
+///         let mut remaining = n;
+///         while remaining > 0 {
+///             remaining -= splice(&pipe_reader, to, remaining)?;
+///         }
+///         total += n as u64;
+///     }
+/// }
+/// ```
+///
+/// [`ErrorKind::WouldBlock`]: crate::io::ErrorKind::WouldBlock
+/// [`io::copy`]: crate::io::copy
+pub fn splice<R: AsFd + ?Sized, W: AsFd + ?Sized>(from: &R, to: &W, len: usize) -> Result<usize> {
+    crate::sys::kernel_copy::splice_once(from.as_fd().as_raw_fd(), to.as_fd().as_raw_fd(), len)
+}
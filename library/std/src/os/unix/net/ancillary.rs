@@ -4,10 +4,10 @@
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::marker::PhantomData;
 use crate::mem::zeroed;
-use crate::os::unix::io::RawFd;
+use crate::os::unix::io::{AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
 use crate::path::Path;
 use crate::ptr::{eq, read_unaligned};
-use crate::slice::from_raw_parts;
+use crate::slice::{from_raw_parts, from_raw_parts_mut};
 use crate::sys::net::Socket;
 
 // FIXME(#43348): Make libc adapt #[doc(cfg(...))] so we don't need these fake definitions here?
@@ -86,6 +86,67 @@ pub(super) fn send_vectored_with_ancillary_to(
     }
 }
 
+/// Allocates a control message buffer that fits an `SCM_RIGHTS` message with `fds` descriptors.
+///
+/// The buffer is backed by `u64`s so that the `cmsghdr` structures written into it are aligned.
+fn rights_buffer(fds: usize) -> io::Result<Vec<u64>> {
+    if fds == 0 {
+        return Ok(Vec::new());
+    }
+    let too_many = || io::const_error!(io::ErrorKind::InvalidInput, "too many file descriptors");
+    let payload = fds.checked_mul(size_of::<RawFd>()).ok_or_else(too_many)?;
+    let payload = u32::try_from(payload).map_err(|_| too_many())?;
+    let space = unsafe { libc::CMSG_SPACE(payload) } as usize;
+    Ok(vec![0; space.div_ceil(size_of::<u64>())])
+}
+
+fn rights_bytes(buffer: &mut [u64]) -> &mut [u8] {
+    // SAFETY: any initialized `u64` is a valid sequence of bytes.
+    unsafe { from_raw_parts_mut(buffer.as_mut_ptr().cast(), size_of_val(buffer)) }
+}
+
+pub(super) fn send_with_fds(
+    socket: &Socket,
+    buf: &[u8],
+    fds: &[BorrowedFd<'_>],
+) -> io::Result<usize> {
+    let raw_fds: Vec<RawFd> = fds.iter().map(|fd| fd.as_raw_fd()).collect();
+    let mut buffer = rights_buffer(raw_fds.len())?;
+    let mut ancillary = SocketAncillary::new(rights_bytes(&mut buffer));
+    if !raw_fds.is_empty() {
+        // The buffer was sized for exactly this message.
+        assert!(ancillary.add_fds(&raw_fds));
+    }
+    send_vectored_with_ancillary_to(socket, None, &[IoSlice::new(buf)], &mut ancillary)
+}
+
+pub(super) fn recv_with_fds(
+    socket: &Socket,
+    buf: &mut [u8],
+    max_fds: usize,
+) -> io::Result<(usize, Vec<OwnedFd>)> {
+    let mut buffer = rights_buffer(max_fds)?;
+    let mut ancillary = SocketAncillary::new(rights_bytes(&mut buffer));
+    let (count, _, _) =
+        recv_vectored_with_ancillary_from(socket, &mut [IoSliceMut::new(buf)], &mut ancillary)?;
+
+    let mut fds = Vec::new();
+    for message in ancillary.messages() {
+        if let Ok(AncillaryData::ScmRights(rights)) = message {
+            // SAFETY: the kernel installed these descriptors in our table for us to own.
+            fds.extend(rights.map(|fd| unsafe { OwnedFd::from_raw_fd(fd) }));
+        }
+    }
+    if ancillary.truncated() {
+        // The kernel already closed the descriptors that didn't fit; drop the rest as well.
+        return Err(io::const_error!(
+            io::ErrorKind::InvalidData,
+            "received more file descriptors than requested",
+        ));
+    }
+    Ok((count, fds))
+}
+
 fn add_to_ancillary_data<T>(
     buffer: &mut [u8],
     length: &mut usize,
@@ -15,7 +15,10 @@
 
 use super::{SocketAddr, sockaddr_un};
 #[cfg(any(doc, target_os = "android", target_os = "linux"))]
-use super::{SocketAncillary, recv_vectored_with_ancillary_from, send_vectored_with_ancillary_to};
+use super::{
+    SocketAncillary, recv_vectored_with_ancillary_from, recv_with_fds,
+    send_vectored_with_ancillary_to, send_with_fds,
+};
 #[cfg(any(doc, target_os = "android", target_os = "linux"))]
 use crate::io::{IoSlice, IoSliceMut};
 use crate::net::Shutdown;
@@ -665,6 +668,29 @@ pub fn send_vectored_with_ancillary(
         send_vectored_with_ancillary_to(&self.0, None, bufs, ancillary)
     }
 
+    /// Sends `buf` as a single datagram to the connected peer, together with the file
+    /// descriptors in `fds`.
+    ///
+    /// See [`UnixStream::send_with_fds`](super::UnixStream::send_with_fds) for details.
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_send_fds", issue = "none")]
+    pub fn send_with_fds(&self, buf: &[u8], fds: &[BorrowedFd<'_>]) -> io::Result<usize> {
+        send_with_fds(&self.0, buf, fds)
+    }
+
+    /// Receives a single datagram into `buf` together with up to `max_fds` file descriptors.
+    ///
+    /// See [`UnixStream::recv_with_fds`](super::UnixStream::recv_with_fds) for details.
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_send_fds", issue = "none")]
+    pub fn recv_with_fds(
+        &self,
+        buf: &mut [u8],
+        max_fds: usize,
+    ) -> io::Result<(usize, Vec<OwnedFd>)> {
+        recv_with_fds(&self.0, buf, max_fds)
+    }
+
     /// Sets the read timeout for the socket.
     ///
     /// If the provided value is [`None`], then [`recv`] and [`recv_from`] calls will
@@ -0,0 +1,430 @@
+use super::{SocketAddr, sockaddr_un};
+#[cfg(any(doc, target_os = "android", target_os = "linux"))]
+use super::{recv_with_fds, send_with_fds};
+use crate::net::Shutdown;
+use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+use crate::path::Path;
+use crate::sys::cvt;
+use crate::sys::net::Socket;
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::time::Duration;
+use crate::{fmt, io, mem};
+
+/// A connected Unix sequenced-packet socket.
+///
+/// A `SOCK_SEQPACKET` socket combines properties of streams and datagrams: it
+/// is connection-oriented like [`UnixStream`], but preserves message
+/// boundaries like [`UnixDatagram`]. Every [`send`] is delivered as a single
+/// message, and every [`recv`] returns at most one message; any part of a
+/// message that does not fit into the receive buffer is discarded.
+///
+/// [`UnixStream`]: super::UnixStream
+/// [`UnixDatagram`]: super::UnixDatagram
+/// [`send`]: UnixSeqpacket::send
+/// [`recv`]: UnixSeqpacket::recv
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_seqpacket)]
+/// use std::os::unix::net::UnixSeqpacket;
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = UnixSeqpacket::connect("/path/to/my/socket")?;
+///     socket.send(b"hello world")?;
+///     let mut buf = [0; 100];
+///     let count = socket.recv(&mut buf)?;
+///     println!("response: {:?}", &buf[..count]);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub struct UnixSeqpacket(Socket);
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacket {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacket");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        if let Ok(addr) = self.peer_addr() {
+            builder.field("peer", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacket {
+    /// Connects to the sequenced-packet socket named by `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// let socket = match UnixSeqpacket::connect("/tmp/sock") {
+    ///     Ok(sock) => sock,
+    ///     Err(e) => {
+    ///         println!("Couldn't connect: {e:?}");
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn connect<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacket> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            let (addr, len) = sockaddr_un(path.as_ref())?;
+
+            cvt(libc::connect(inner.as_raw_fd(), (&raw const addr) as *const _, len))?;
+            Ok(UnixSeqpacket(inner))
+        }
+    }
+
+    /// Connects to the sequenced-packet socket specified by [`address`].
+    ///
+    /// [`address`]: crate::os::unix::net::SocketAddr
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn connect_addr(socket_addr: &SocketAddr) -> io::Result<UnixSeqpacket> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            cvt(libc::connect(
+                inner.as_raw_fd(),
+                (&raw const socket_addr.addr) as *const _,
+                socket_addr.len,
+            ))?;
+            Ok(UnixSeqpacket(inner))
+        }
+    }
+
+    /// Creates an unnamed pair of connected sockets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// let (sock1, sock2) = match UnixSeqpacket::pair() {
+    ///     Ok((sock1, sock2)) => (sock1, sock2),
+    ///     Err(e) => {
+    ///         println!("Couldn't create a pair of sockets: {e:?}");
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn pair() -> io::Result<(UnixSeqpacket, UnixSeqpacket)> {
+        let (i1, i2) = Socket::new_pair(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+        Ok((UnixSeqpacket(i1), UnixSeqpacket(i2)))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixSeqpacket` is a reference to the same socket that this
+    /// object references. Both handles can be used to send and receive
+    /// messages, and options set on one socket will affect the other.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacket> {
+        self.0.duplicate().map(UnixSeqpacket)
+    }
+
+    /// Returns the socket address of the local half of this connection.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Returns the socket address of the remote half of this connection.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getpeername(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Sends `buf` as a single message.
+    ///
+    /// On success, returns the number of bytes written, which is always the
+    /// length of `buf`. Messages larger than the socket's send buffer are
+    /// rejected with an error.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    /// Receives a single message into `buf`.
+    ///
+    /// On success, returns the number of bytes read. If the message is larger
+    /// than `buf`, the excess bytes are discarded. A return value of `0` means
+    /// the peer has closed the connection (or sent an empty message).
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    /// Receives a single message into `buf` without removing it from the
+    /// queue.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.peek(buf)
+    }
+
+    /// Sends `buf` as a single message, together with the file descriptors in
+    /// `fds`.
+    ///
+    /// See [`UnixStream::send_with_fds`](super::UnixStream::send_with_fds)
+    /// for details.
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_send_fds", issue = "none")]
+    pub fn send_with_fds(&self, buf: &[u8], fds: &[BorrowedFd<'_>]) -> io::Result<usize> {
+        send_with_fds(&self.0, buf, fds)
+    }
+
+    /// Receives a single message into `buf`, together with up to `max_fds`
+    /// file descriptors.
+    ///
+    /// See [`UnixStream::recv_with_fds`](super::UnixStream::recv_with_fds)
+    /// for details.
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_send_fds", issue = "none")]
+    pub fn recv_with_fds(
+        &self,
+        buf: &mut [u8],
+        max_fds: usize,
+    ) -> io::Result<(usize, Vec<OwnedFd>)> {
+        recv_with_fds(&self.0, buf, max_fds)
+    }
+
+    /// Sets the read timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`recv`] calls will block
+    /// indefinitely. An [`Err`] is returned if the zero [`Duration`] is passed
+    /// to this method.
+    ///
+    /// [`recv`]: UnixSeqpacket::recv
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_RCVTIMEO)
+    }
+
+    /// Sets the write timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`send`] calls will block
+    /// indefinitely. An [`Err`] is returned if the zero [`Duration`] is passed
+    /// to this method.
+    ///
+    /// [`send`]: UnixSeqpacket::send
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_SNDTIMEO)
+    }
+
+    /// Returns the read timeout of this socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn read_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_RCVTIMEO)
+    }
+
+    /// Returns the write timeout of this socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_SNDTIMEO)
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+
+    /// Shuts down the read, write, or both halves of this connection.
+    ///
+    /// This function will cause all pending and future I/O calls on the
+    /// specified portions to immediately return with an appropriate value
+    /// (see the documentation of [`Shutdown`]).
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        self.0.shutdown(how)
+    }
+}
+
+/// A structure representing a Unix sequenced-packet socket server.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_seqpacket)]
+/// use std::os::unix::net::UnixSeqpacketListener;
+///
+/// fn main() -> std::io::Result<()> {
+///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+///     loop {
+///         let (socket, _addr) = listener.accept()?;
+///         let mut buf = [0; 1024];
+///         let n = socket.recv(&mut buf)?;
+///         socket.send(&buf[..n])?;
+///     }
+/// }
+/// ```
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub struct UnixSeqpacketListener(Socket);
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacketListener {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacketListener");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacketListener {
+    /// Creates a new `UnixSeqpacketListener` bound to the specified socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacketListener> {
+        let (addr, len) = sockaddr_un(path.as_ref())?;
+        Self::bind_raw((&raw const addr) as *const _, len)
+    }
+
+    /// Creates a new `UnixSeqpacketListener` bound to the specified
+    /// [`socket address`].
+    ///
+    /// [`socket address`]: crate::os::unix::net::SocketAddr
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn bind_addr(socket_addr: &SocketAddr) -> io::Result<UnixSeqpacketListener> {
+        Self::bind_raw((&raw const socket_addr.addr) as *const _, socket_addr.len)
+    }
+
+    fn bind_raw(
+        addr: *const libc::sockaddr,
+        len: libc::socklen_t,
+    ) -> io::Result<UnixSeqpacketListener> {
+        // Like `UnixListener`, request the system's maximum backlog.
+        #[cfg(target_os = "linux")]
+        const BACKLOG: core::ffi::c_int = -1;
+        #[cfg(not(target_os = "linux"))]
+        const BACKLOG: core::ffi::c_int = libc::SOMAXCONN;
+
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            cvt(libc::bind(inner.as_raw_fd(), addr, len as _))?;
+            cvt(libc::listen(inner.as_raw_fd(), BACKLOG))?;
+            Ok(UnixSeqpacketListener(inner))
+        }
+    }
+
+    /// Accepts a new incoming connection to this listener.
+    ///
+    /// This function will block the calling thread until a new connection is
+    /// established. When established, the corresponding [`UnixSeqpacket`] and
+    /// the remote peer's address will be returned.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn accept(&self) -> io::Result<(UnixSeqpacket, SocketAddr)> {
+        let mut storage: libc::sockaddr_un = unsafe { mem::zeroed() };
+        let mut len = size_of_val(&storage) as libc::socklen_t;
+        let sock = self.0.accept((&raw mut storage) as *mut _, &mut len)?;
+        let addr = SocketAddr::from_parts(storage, len)?;
+        Ok((UnixSeqpacket(sock), addr))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacketListener> {
+        self.0.duplicate().map(UnixSeqpacketListener)
+    }
+
+    /// Returns the local socket address of this listener.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    ///
+    /// In nonblocking mode, [`accept`] returns an error of kind
+    /// [`io::ErrorKind::WouldBlock`] if there is no pending connection.
+    ///
+    /// [`accept`]: UnixSeqpacketListener::accept
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+}
+
+macro_rules! fd_impls {
+    ($($ty:ident)*) => {$(
+        #[unstable(feature = "unix_seqpacket", issue = "none")]
+        impl AsRawFd for $ty {
+            #[inline]
+            fn as_raw_fd(&self) -> RawFd {
+                self.0.as_inner().as_raw_fd()
+            }
+        }
+
+        #[unstable(feature = "unix_seqpacket", issue = "none")]
+        impl FromRawFd for $ty {
+            #[inline]
+            unsafe fn from_raw_fd(fd: RawFd) -> $ty {
+                $ty(Socket::from_inner(FromInner::from_inner(OwnedFd::from_raw_fd(fd))))
+            }
+        }
+
+        #[unstable(feature = "unix_seqpacket", issue = "none")]
+        impl IntoRawFd for $ty {
+            #[inline]
+            fn into_raw_fd(self) -> RawFd {
+                self.0.into_inner().into_inner().into_raw_fd()
+            }
+        }
+
+        #[unstable(feature = "unix_seqpacket", issue = "none")]
+        impl AsFd for $ty {
+            #[inline]
+            fn as_fd(&self) -> BorrowedFd<'_> {
+                self.0.as_inner().as_fd()
+            }
+        }
+
+        #[unstable(feature = "unix_seqpacket", issue = "none")]
+        impl From<$ty> for OwnedFd {
+            #[inline]
+            fn from(socket: $ty) -> OwnedFd {
+                unsafe { OwnedFd::from_raw_fd(socket.into_raw_fd()) }
+            }
+        }
+
+        #[unstable(feature = "unix_seqpacket", issue = "none")]
+        impl From<OwnedFd> for $ty {
+            #[inline]
+            fn from(owned: OwnedFd) -> Self {
+                unsafe { Self::from_raw_fd(owned.into_raw_fd()) }
+            }
+        }
+
+        impl AsInner<Socket> for $ty {
+            #[inline]
+            fn as_inner(&self) -> &Socket {
+                &self.0
+            }
+        }
+    )*};
+}
+
+fd_impls! { UnixSeqpacket UnixSeqpacketListener }
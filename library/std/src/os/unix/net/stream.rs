@@ -15,7 +15,10 @@
 
 use super::{SocketAddr, sockaddr_un};
 #[cfg(any(doc, target_os = "android", target_os = "linux"))]
-use super::{SocketAncillary, recv_vectored_with_ancillary_from, send_vectored_with_ancillary_to};
+use super::{
+    SocketAncillary, recv_vectored_with_ancillary_from, recv_with_fds,
+    send_vectored_with_ancillary_to, send_with_fds,
+};
 #[cfg(any(
     target_os = "android",
     target_os = "linux",
@@ -589,6 +592,82 @@ pub fn send_vectored_with_ancillary(
     ) -> io::Result<usize> {
         send_vectored_with_ancillary_to(&self.0, None, bufs, ancillary)
     }
+
+    /// Sends `buf` together with the file descriptors in `fds`.
+    ///
+    /// This is a convenience wrapper around [`send_vectored_with_ancillary`] that builds a
+    /// correctly sized `SCM_RIGHTS` control message. The receiving process gets its own
+    /// duplicates of the descriptors; the descriptors in `fds` stay open in this process.
+    ///
+    /// On success, returns the number of bytes written. The descriptors are sent along with the
+    /// first byte of `buf`, so `buf` should not be empty on stream sockets.
+    ///
+    /// [`send_vectored_with_ancillary`]: UnixStream::send_vectored_with_ancillary
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_send_fds)]
+    /// use std::fs::File;
+    /// use std::os::fd::AsFd;
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixStream::connect("/tmp/sock")?;
+    ///     let file = File::open("/etc/hosts")?;
+    ///     socket.send_with_fds(b"hosts", &[file.as_fd()])?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_send_fds", issue = "none")]
+    pub fn send_with_fds(&self, buf: &[u8], fds: &[BorrowedFd<'_>]) -> io::Result<usize> {
+        send_with_fds(&self.0, buf, fds)
+    }
+
+    /// Receives data into `buf` together with up to `max_fds` file descriptors.
+    ///
+    /// This is a convenience wrapper around [`recv_vectored_with_ancillary`] that sizes the
+    /// control message buffer for `max_fds` descriptors and takes ownership of the received
+    /// descriptors, which are created with the close-on-exec flag set.
+    ///
+    /// On success, returns the number of bytes read and the received descriptors.
+    ///
+    /// # Errors
+    ///
+    /// If the peer sent more than `max_fds` descriptors, all received descriptors are closed and
+    /// an error of kind [`io::ErrorKind::InvalidData`] is returned. The data that was read is
+    /// lost in that case.
+    ///
+    /// [`recv_vectored_with_ancillary`]: UnixStream::recv_vectored_with_ancillary
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_send_fds)]
+    /// use std::fs::File;
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixStream::connect("/tmp/sock")?;
+    ///     let mut buf = [0; 64];
+    ///     let (n, fds) = socket.recv_with_fds(&mut buf, 4)?;
+    ///     let files: Vec<File> = fds.into_iter().map(File::from).collect();
+    ///     println!("received {n} bytes and {} files", files.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_send_fds", issue = "none")]
+    pub fn recv_with_fds(
+        &self,
+        buf: &mut [u8],
+        max_fds: usize,
+    ) -> io::Result<(usize, Vec<OwnedFd>)> {
+        recv_with_fds(&self.0, buf, max_fds)
+    }
 }
 
 #[stable(feature = "unix_socket", since = "1.10.0")]
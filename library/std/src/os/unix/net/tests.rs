@@ -669,6 +669,85 @@ fn test_send_vectored_fds_unix_stream() {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_send_with_fds_unix_stream() {
+    use crate::os::fd::AsFd;
+
+    let (s1, s2) = or_panic!(UnixStream::pair());
+    let (r, w) = or_panic!(io::pipe());
+
+    let sent = or_panic!(s1.send_with_fds(b"fds", &[r.as_fd(), w.as_fd()]));
+    assert_eq!(sent, 3);
+
+    let mut buf = [0; 8];
+    let (n, fds) = or_panic!(s2.recv_with_fds(&mut buf, 2));
+    assert_eq!(&buf[..n], b"fds");
+    assert_eq!(fds.len(), 2);
+
+    // The received descriptors refer to the same pipe.
+    let mut w2 = io::PipeWriter::from(fds.into_iter().nth(1).unwrap());
+    let mut r = r;
+    or_panic!(w2.write_all(b"x"));
+    let mut byte = [0];
+    or_panic!(r.read_exact(&mut byte));
+    assert_eq!(&byte, b"x");
+
+    // Receiving more descriptors than requested is an error.
+    or_panic!(s1.send_with_fds(b"y", &[w.as_fd()]));
+    let err = s2.recv_with_fds(&mut buf, 0).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_unix_seqpacket_pair_preserves_boundaries() {
+    use crate::os::fd::AsFd;
+
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+    or_panic!(s1.send(b"first"));
+    or_panic!(s1.send(b"second"));
+
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(s2.recv(&mut buf)), 5);
+    assert_eq!(&buf[..5], b"first");
+    // Excess bytes of a message are discarded.
+    assert_eq!(or_panic!(s2.recv(&mut buf[..3])), 3);
+    assert_eq!(&buf[..3], b"sec");
+
+    or_panic!(s2.send_with_fds(b"fd", &[s2.as_fd()]));
+    let (n, fds) = or_panic!(s1.recv_with_fds(&mut buf, 1));
+    assert_eq!(n, 2);
+    assert_eq!(fds.len(), 1);
+
+    drop(s2);
+    drop(fds);
+    assert_eq!(or_panic!(s1.recv(&mut buf)), 0);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+#[cfg_attr(target_os = "android", ignore)] // Android SELinux rules prevent creating Unix sockets
+fn test_unix_seqpacket_listener() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("seqpacket");
+
+    let listener = or_panic!(UnixSeqpacketListener::bind(&socket_path));
+    let thread = thread::spawn(move || {
+        let (socket, _) = or_panic!(listener.accept());
+        let mut buf = [0; 16];
+        let n = or_panic!(socket.recv(&mut buf));
+        or_panic!(socket.send(&buf[..n]));
+    });
+
+    let socket = or_panic!(UnixSeqpacket::connect(&socket_path));
+    or_panic!(socket.send(b"echo"));
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(socket.recv(&mut buf)), 4);
+    assert_eq!(&buf[..4], b"echo");
+    thread.join().unwrap();
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 #[test]
 #[cfg_attr(target_os = "android", ignore)] // Android SELinux rules prevent creating Unix sockets
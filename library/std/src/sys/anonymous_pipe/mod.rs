@@ -3,12 +3,12 @@
 cfg_if::cfg_if! {
     if #[cfg(unix)] {
         mod unix;
-        pub use unix::{AnonPipe, pipe};
+        pub use unix::{AnonPipe, capacity, pipe, pipe_with, set_capacity, set_nonblocking};
     } else if #[cfg(windows)] {
         mod windows;
-        pub use windows::{AnonPipe, pipe};
+        pub use windows::{AnonPipe, capacity, pipe, pipe_with, set_capacity, set_nonblocking};
     } else {
         mod unsupported;
-        pub use unsupported::{AnonPipe, pipe};
+        pub use unsupported::{AnonPipe, capacity, pipe, pipe_with, set_capacity, set_nonblocking};
     }
 }
@@ -1,6 +1,10 @@
 use crate::io;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::os::fd::{AsRawFd, FromRawFd};
 use crate::sys::fd::FileDesc;
 use crate::sys::pipe::anon_pipe;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::sys::{cvt, cvt_r};
 use crate::sys_common::IntoInner;
 
 pub type AnonPipe = FileDesc;
@@ -9,3 +13,73 @@
 pub fn pipe() -> io::Result<(AnonPipe, AnonPipe)> {
     anon_pipe().map(|(rx, wx)| (rx.into_inner(), wx.into_inner()))
 }
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn pipe_with(
+    nonblocking: bool,
+    packet_mode: bool,
+    capacity: Option<usize>,
+) -> io::Result<(AnonPipe, AnonPipe)> {
+    let mut flags = libc::O_CLOEXEC;
+    if nonblocking {
+        flags |= libc::O_NONBLOCK;
+    }
+    if packet_mode {
+        flags |= libc::O_DIRECT;
+    }
+    let mut fds = [0; 2];
+    cvt(unsafe { libc::pipe2(fds.as_mut_ptr(), flags) })?;
+    let (rx, wx) = unsafe { (FileDesc::from_raw_fd(fds[0]), FileDesc::from_raw_fd(fds[1])) };
+    if let Some(capacity) = capacity {
+        set_capacity(&wx, capacity)?;
+    }
+    Ok((rx, wx))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn pipe_with(
+    nonblocking: bool,
+    packet_mode: bool,
+    capacity: Option<usize>,
+) -> io::Result<(AnonPipe, AnonPipe)> {
+    if packet_mode || capacity.is_some() {
+        return Err(io::Error::UNSUPPORTED_PLATFORM);
+    }
+    let (rx, wx) = pipe()?;
+    if nonblocking {
+        rx.set_nonblocking(true)?;
+        wx.set_nonblocking(true)?;
+    }
+    Ok((rx, wx))
+}
+
+#[inline]
+pub fn set_nonblocking(pipe: &AnonPipe, nonblocking: bool) -> io::Result<()> {
+    pipe.set_nonblocking(nonblocking)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn capacity(pipe: &AnonPipe) -> io::Result<usize> {
+    let size = cvt(unsafe { libc::fcntl(pipe.as_raw_fd(), libc::F_GETPIPE_SZ) })?;
+    Ok(size as usize)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn set_capacity(pipe: &AnonPipe, capacity: usize) -> io::Result<usize> {
+    // The kernel rounds the size up to a power-of-two number of pages and
+    // returns the resulting capacity.
+    let capacity = libc::c_int::try_from(capacity)
+        .map_err(|_| io::const_error!(io::ErrorKind::InvalidInput, "pipe capacity is too large"))?;
+    let size = cvt_r(|| unsafe { libc::fcntl(pipe.as_raw_fd(), libc::F_SETPIPE_SZ, capacity) })?;
+    Ok(size as usize)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn capacity(_pipe: &AnonPipe) -> io::Result<usize> {
+    Err(io::Error::UNSUPPORTED_PLATFORM)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn set_capacity(_pipe: &AnonPipe, _capacity: usize) -> io::Result<usize> {
+    Err(io::Error::UNSUPPORTED_PLATFORM)
+}
@@ -5,3 +5,20 @@
 pub fn pipe() -> io::Result<(AnonPipe, AnonPipe)> {
     Err(io::Error::UNSUPPORTED_PLATFORM)
 }
+
+#[inline]
+pub fn pipe_with(_: bool, _: bool, _: Option<usize>) -> io::Result<(AnonPipe, AnonPipe)> {
+    Err(io::Error::UNSUPPORTED_PLATFORM)
+}
+
+pub fn set_nonblocking(_pipe: &AnonPipe, _nonblocking: bool) -> io::Result<()> {
+    Err(io::Error::UNSUPPORTED_PLATFORM)
+}
+
+pub fn capacity(_pipe: &AnonPipe) -> io::Result<usize> {
+    Err(io::Error::UNSUPPORTED_PLATFORM)
+}
+
+pub fn set_capacity(_pipe: &AnonPipe, _capacity: usize) -> io::Result<usize> {
+    Err(io::Error::UNSUPPORTED_PLATFORM)
+}
@@ -6,10 +6,32 @@
 pub type AnonPipe = Handle;
 
 pub fn pipe() -> io::Result<(AnonPipe, AnonPipe)> {
+    create_pipe(0)
+}
+
+pub fn pipe_with(
+    nonblocking: bool,
+    packet_mode: bool,
+    capacity: Option<usize>,
+) -> io::Result<(AnonPipe, AnonPipe)> {
+    if nonblocking || packet_mode {
+        return Err(io::Error::UNSUPPORTED_PLATFORM);
+    }
+    // `CreatePipe` treats the size as a suggestion; zero selects the default.
+    let size = match capacity {
+        Some(capacity) => u32::try_from(capacity).map_err(|_| {
+            io::const_error!(io::ErrorKind::InvalidInput, "pipe capacity is too large")
+        })?,
+        None => 0,
+    };
+    create_pipe(size)
+}
+
+fn create_pipe(size: u32) -> io::Result<(AnonPipe, AnonPipe)> {
     let mut read_pipe = c::INVALID_HANDLE_VALUE;
     let mut write_pipe = c::INVALID_HANDLE_VALUE;
 
-    let ret = unsafe { c::CreatePipe(&mut read_pipe, &mut write_pipe, ptr::null_mut(), 0) };
+    let ret = unsafe { c::CreatePipe(&mut read_pipe, &mut write_pipe, ptr::null_mut(), size) };
 
     if ret == 0 {
         Err(io::Error::last_os_error())
@@ -17,3 +39,15 @@ pub fn pipe() -> io::Result<(AnonPipe, AnonPipe)> {
         unsafe { Ok((Handle::from_raw_handle(read_pipe), Handle::from_raw_handle(write_pipe))) }
     }
 }
+
+pub fn set_nonblocking(_pipe: &AnonPipe, _nonblocking: bool) -> io::Result<()> {
+    Err(io::Error::UNSUPPORTED_PLATFORM)
+}
+
+pub fn capacity(_pipe: &AnonPipe) -> io::Result<usize> {
+    Err(io::Error::UNSUPPORTED_PLATFORM)
+}
+
+pub fn set_capacity(_pipe: &AnonPipe, _capacity: usize) -> io::Result<usize> {
+    Err(io::Error::UNSUPPORTED_PLATFORM)
+}
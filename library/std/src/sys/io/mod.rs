@@ -36,6 +36,26 @@ mod is_terminal {
     }
 }
 
+#[cfg(unix)]
+pub mod poll {
+    cfg_if::cfg_if! {
+        if #[cfg(any(target_os = "linux", target_os = "android"))] {
+            mod epoll;
+            pub use epoll::*;
+        } else if #[cfg(any(
+            target_vendor = "apple",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+        ))] {
+            mod kqueue;
+            pub use kqueue::*;
+        } else {
+            mod unsupported;
+            pub use unsupported::*;
+        }
+    }
+}
+
 pub use io_slice::{IoSlice, IoSliceMut};
 pub use is_terminal::is_terminal;
 
@@ -0,0 +1,125 @@
+use crate::os::fd::{AsRawFd, BorrowedFd, FromRawFd, OwnedFd};
+use crate::sys::cvt;
+use crate::time::Duration;
+use crate::{cmp, io};
+
+pub struct Poller {
+    epfd: OwnedFd,
+}
+
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Event(libc::epoll_event);
+
+impl Poller {
+    pub fn new() -> io::Result<Poller> {
+        let epfd = cvt(unsafe { libc::epoll_create1(libc::EPOLL_CLOEXEC) })?;
+        Ok(Poller { epfd: unsafe { OwnedFd::from_raw_fd(epfd) } })
+    }
+
+    pub fn register(
+        &self,
+        fd: BorrowedFd<'_>,
+        token: usize,
+        readable: bool,
+        writable: bool,
+    ) -> io::Result<()> {
+        self.ctl(libc::EPOLL_CTL_ADD, fd, Some(event(token, readable, writable)))
+    }
+
+    pub fn reregister(
+        &self,
+        fd: BorrowedFd<'_>,
+        token: usize,
+        readable: bool,
+        writable: bool,
+    ) -> io::Result<()> {
+        self.ctl(libc::EPOLL_CTL_MOD, fd, Some(event(token, readable, writable)))
+    }
+
+    pub fn deregister(&self, fd: BorrowedFd<'_>) -> io::Result<()> {
+        // Kernels before 2.6.9 require a non-null event pointer even for
+        // `EPOLL_CTL_DEL`, so always pass one.
+        self.ctl(libc::EPOLL_CTL_DEL, fd, Some(event(0, false, false)))
+    }
+
+    fn ctl(
+        &self,
+        op: libc::c_int,
+        fd: BorrowedFd<'_>,
+        mut event: Option<libc::epoll_event>,
+    ) -> io::Result<()> {
+        let event = event.as_mut().map_or(crate::ptr::null_mut(), |e| e as *mut _);
+        cvt(unsafe { libc::epoll_ctl(self.epfd.as_raw_fd(), op, fd.as_raw_fd(), event) })?;
+        Ok(())
+    }
+
+    pub fn wait(&self, events: &mut Vec<Event>, timeout: Option<Duration>) -> io::Result<()> {
+        events.clear();
+        let timeout = match timeout {
+            None => -1,
+            // Round up so that a sub-millisecond timeout doesn't turn into a
+            // busy loop of zero-timeout calls.
+            Some(dur) => {
+                let ms = dur.as_millis() + u128::from(dur.subsec_nanos() % 1_000_000 != 0);
+                cmp::min(ms, libc::c_int::MAX as u128) as libc::c_int
+            }
+        };
+        let capacity = cmp::min(events.capacity(), libc::c_int::MAX as usize) as libc::c_int;
+        let n = match cvt(unsafe {
+            libc::epoll_wait(
+                self.epfd.as_raw_fd(),
+                events.as_mut_ptr().cast::<libc::epoll_event>(),
+                capacity,
+                timeout,
+            )
+        }) {
+            Ok(n) => n,
+            // A signal interrupted the wait; report a spurious wakeup with no
+            // events rather than restarting with the full timeout.
+            Err(e) if e.is_interrupted() => 0,
+            Err(e) => return Err(e),
+        };
+        // SAFETY: `Event` is a `repr(transparent)` wrapper around
+        // `epoll_event` and the kernel initialized the first `n` entries.
+        unsafe { events.set_len(n as usize) };
+        Ok(())
+    }
+}
+
+fn event(token: usize, readable: bool, writable: bool) -> libc::epoll_event {
+    let mut events = 0;
+    if readable {
+        events |= libc::EPOLLIN | libc::EPOLLRDHUP;
+    }
+    if writable {
+        events |= libc::EPOLLOUT;
+    }
+    libc::epoll_event { events: events as u32, u64: token as u64 }
+}
+
+impl Event {
+    pub fn token(&self) -> usize {
+        self.0.u64 as usize
+    }
+
+    pub fn is_readable(&self) -> bool {
+        self.flags() & (libc::EPOLLIN | libc::EPOLLPRI) != 0
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.flags() & libc::EPOLLOUT != 0
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.flags() & libc::EPOLLERR != 0
+    }
+
+    pub fn is_hangup(&self) -> bool {
+        self.flags() & (libc::EPOLLHUP | libc::EPOLLRDHUP) != 0
+    }
+
+    fn flags(&self) -> libc::c_int {
+        self.0.events as libc::c_int
+    }
+}
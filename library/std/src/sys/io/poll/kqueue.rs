@@ -0,0 +1,157 @@
+use crate::os::fd::{AsRawFd, BorrowedFd, FromRawFd, OwnedFd};
+use crate::sys::{cvt, cvt_r};
+use crate::time::Duration;
+use crate::{cmp, io, mem, ptr};
+
+pub struct Poller {
+    kq: OwnedFd,
+}
+
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Event(libc::kevent);
+
+impl Poller {
+    pub fn new() -> io::Result<Poller> {
+        let kq = cvt(unsafe { libc::kqueue() })?;
+        let kq = unsafe { OwnedFd::from_raw_fd(kq) };
+        cvt_r(|| unsafe { libc::fcntl(kq.as_raw_fd(), libc::F_SETFD, libc::FD_CLOEXEC) })?;
+        Ok(Poller { kq })
+    }
+
+    pub fn register(
+        &self,
+        fd: BorrowedFd<'_>,
+        token: usize,
+        readable: bool,
+        writable: bool,
+    ) -> io::Result<()> {
+        self.apply(fd, token, readable, writable, false)
+    }
+
+    pub fn reregister(
+        &self,
+        fd: BorrowedFd<'_>,
+        token: usize,
+        readable: bool,
+        writable: bool,
+    ) -> io::Result<()> {
+        self.apply(fd, token, readable, writable, true)
+    }
+
+    pub fn deregister(&self, fd: BorrowedFd<'_>) -> io::Result<()> {
+        self.apply(fd, 0, false, false, true)
+    }
+
+    /// Adds the wanted filters and (if `delete_unwanted` is set) removes the
+    /// others. Each filter is a separate kqueue registration, so this submits
+    /// both changes at once with `EV_RECEIPT` to collect per-change errors.
+    fn apply(
+        &self,
+        fd: BorrowedFd<'_>,
+        token: usize,
+        readable: bool,
+        writable: bool,
+        delete_unwanted: bool,
+    ) -> io::Result<()> {
+        let mut changes = [
+            change(fd, libc::EVFILT_READ, readable, token),
+            change(fd, libc::EVFILT_WRITE, writable, token),
+        ];
+        let mut n = 0;
+        for i in 0..changes.len() {
+            let wanted = changes[i].flags & libc::EV_ADD != 0;
+            if wanted || delete_unwanted {
+                changes[n] = changes[i];
+                n += 1;
+            }
+        }
+        if n == 0 {
+            return Ok(());
+        }
+        cvt(unsafe {
+            libc::kevent(
+                self.kq.as_raw_fd(),
+                changes.as_ptr(),
+                n as _,
+                changes.as_mut_ptr(),
+                n as _,
+                ptr::null(),
+            )
+        })?;
+        for change in &changes[..n] {
+            // With `EV_RECEIPT`, every change is echoed back with `EV_ERROR`
+            // set and the error code (or zero) in `data`. Deleting a filter
+            // that was never added reports `ENOENT`, which is expected here.
+            if change.flags & libc::EV_ERROR != 0 {
+                let err = change.data as i32;
+                if err != 0 && err != libc::ENOENT {
+                    return Err(io::Error::from_raw_os_error(err));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn wait(&self, events: &mut Vec<Event>, timeout: Option<Duration>) -> io::Result<()> {
+        events.clear();
+        let timeout = timeout.map(|dur| libc::timespec {
+            tv_sec: cmp::min(dur.as_secs(), libc::time_t::MAX as u64) as libc::time_t,
+            tv_nsec: dur.subsec_nanos() as _,
+        });
+        let timeout = timeout.as_ref().map_or(ptr::null(), |t| t as *const _);
+        let capacity = cmp::min(events.capacity(), libc::c_int::MAX as usize);
+        let n = match cvt(unsafe {
+            libc::kevent(
+                self.kq.as_raw_fd(),
+                ptr::null(),
+                0,
+                events.as_mut_ptr().cast::<libc::kevent>(),
+                capacity as _,
+                timeout,
+            )
+        }) {
+            Ok(n) => n,
+            // A signal interrupted the wait; report a spurious wakeup with no
+            // events rather than restarting with the full timeout.
+            Err(e) if e.is_interrupted() => 0,
+            Err(e) => return Err(e),
+        };
+        // SAFETY: `Event` is a `repr(transparent)` wrapper around `kevent`
+        // and the kernel initialized the first `n` entries.
+        unsafe { events.set_len(n as usize) };
+        Ok(())
+    }
+}
+
+fn change(fd: BorrowedFd<'_>, filter: i16, wanted: bool, token: usize) -> libc::kevent {
+    let mut ev: libc::kevent = unsafe { mem::zeroed() };
+    ev.ident = fd.as_raw_fd() as _;
+    ev.filter = filter as _;
+    ev.flags = if wanted { libc::EV_ADD } else { libc::EV_DELETE } | libc::EV_RECEIPT;
+    ev.udata = token as _;
+    ev
+}
+
+impl Event {
+    pub fn token(&self) -> usize {
+        self.0.udata as usize
+    }
+
+    pub fn is_readable(&self) -> bool {
+        self.0.filter == libc::EVFILT_READ as _
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.0.filter == libc::EVFILT_WRITE as _
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.0.flags & libc::EV_ERROR != 0
+            || (self.0.flags & libc::EV_EOF != 0 && self.0.fflags != 0)
+    }
+
+    pub fn is_hangup(&self) -> bool {
+        self.0.flags & libc::EV_EOF != 0
+    }
+}
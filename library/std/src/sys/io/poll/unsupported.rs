@@ -0,0 +1,55 @@
+use crate::io;
+use crate::os::fd::BorrowedFd;
+use crate::time::Duration;
+
+pub struct Poller(!);
+
+#[derive(Clone, Copy)]
+pub struct Event(!);
+
+impl Poller {
+    pub fn new() -> io::Result<Poller> {
+        Err(io::const_error!(
+            io::ErrorKind::Unsupported,
+            "readiness polling is not supported on this platform",
+        ))
+    }
+
+    pub fn register(&self, _: BorrowedFd<'_>, _: usize, _: bool, _: bool) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn reregister(&self, _: BorrowedFd<'_>, _: usize, _: bool, _: bool) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn deregister(&self, _: BorrowedFd<'_>) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn wait(&self, _: &mut Vec<Event>, _: Option<Duration>) -> io::Result<()> {
+        self.0
+    }
+}
+
+impl Event {
+    pub fn token(&self) -> usize {
+        self.0
+    }
+
+    pub fn is_readable(&self) -> bool {
+        self.0
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.0
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.0
+    }
+
+    pub fn is_hangup(&self) -> bool {
+        self.0
+    }
+}
@@ -712,6 +712,37 @@ fn probe_copy_file_range_support() -> u8 {
     CopyResult::Ended(written)
 }
 
+// Android builds use feature level 14, but the libc wrapper for splice is
+// gated on feature level 21+, so we have to invoke the syscall directly.
+#[cfg(target_os = "android")]
+syscall!(
+    fn splice(
+        srcfd: libc::c_int,
+        src_offset: *const i64,
+        dstfd: libc::c_int,
+        dst_offset: *const i64,
+        len: libc::size_t,
+        flags: libc::c_int,
+    ) -> libc::ssize_t;
+);
+
+#[cfg(target_os = "linux")]
+use libc::splice;
+
+/// Performs a single `splice(2)` call between two file descriptors, one of
+/// which must be a pipe, without any fallback.
+///
+/// Used by the explicit `os::linux::io::splice` API.
+#[cfg_attr(target_os = "android", allow(dead_code))]
+pub(crate) fn splice_once(reader: RawFd, writer: RawFd, len: usize) -> Result<usize> {
+    // Same per-call cap as `sendfile_splice` below.
+    let len = min(len, 0x7ffff000);
+    let ret = cvt(unsafe {
+        splice(reader, ptr::null_mut(), writer, ptr::null_mut(), len, libc::SPLICE_F_MOVE as _)
+    })?;
+    Ok(ret as usize)
+}
+
 #[derive(PartialEq)]
 enum SpliceMode {
     Sendfile,
@@ -724,23 +755,6 @@ fn sendfile_splice(mode: SpliceMode, reader: RawFd, writer: RawFd, len: u64) ->
     static HAS_SENDFILE: Atomic<bool> = AtomicBool::new(true);
     static HAS_SPLICE: Atomic<bool> = AtomicBool::new(true);
 
-    // Android builds use feature level 14, but the libc wrapper for splice is
-    // gated on feature level 21+, so we have to invoke the syscall directly.
-    #[cfg(target_os = "android")]
-    syscall!(
-        fn splice(
-            srcfd: libc::c_int,
-            src_offset: *const i64,
-            dstfd: libc::c_int,
-            dst_offset: *const i64,
-            len: libc::size_t,
-            flags: libc::c_int,
-        ) -> libc::ssize_t;
-    );
-
-    #[cfg(target_os = "linux")]
-    use libc::splice;
-
     match mode {
         SpliceMode::Sendfile if !HAS_SENDFILE.load(Ordering::Relaxed) => {
             return CopyResult::Fallback(0);
@@ -123,6 +123,50 @@ fn dont_splice_pipes_from_files() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn copy_n_file_to_file_respects_limit() -> Result<()> {
+    let tmp_path = tmpdir();
+    let source_path = tmp_path.join("copy_n.source");
+    let sink_path = tmp_path.join("copy_n.sink");
+    let mut source =
+        OpenOptions::new().create(true).truncate(true).write(true).read(true).open(&source_path)?;
+    source.write_all(b"0123456789")?;
+    source.seek(SeekFrom::Start(0))?;
+    let mut sink = OpenOptions::new().create(true).truncate(true).write(true).open(&sink_path)?;
+
+    assert_eq!(io::copy_n(&mut source, &mut sink, 4)?, 4);
+    assert_eq!(source.stream_position()?, 4);
+    assert_eq!(crate::fs::read(&sink_path)?, b"0123");
+
+    Ok(())
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn explicit_splice_through_pipe() -> Result<()> {
+    use crate::os::linux::io::splice;
+    use crate::os::unix::net::UnixStream;
+
+    let (mut a, b) = UnixStream::pair()?;
+    let (c, mut d) = UnixStream::pair()?;
+    let (pipe_reader, pipe_writer) = io::pipe()?;
+
+    a.write_all(b"spliced")?;
+    drop(a);
+
+    assert_eq!(splice(&b, &pipe_writer, 64)?, 7);
+    assert_eq!(splice(&b, &pipe_writer, 64)?, 0);
+    assert_eq!(splice(&pipe_reader, &c, 64)?, 7);
+    drop(c);
+
+    let mut out = String::new();
+    d.read_to_string(&mut out)?;
+    assert_eq!(out, "spliced");
+
+    Ok(())
+}
+
 #[bench]
 fn bench_file_to_file_copy(b: &mut test::Bencher) {
     const BYTES: usize = 128 * 1024;
@@ -7,6 +7,7 @@
 
 use rustc_data_structures::fx::FxIndexMap;
 use rustc_errors::DiagCtxtHandle;
+use rustc_errors::sarif::SharedLog;
 use rustc_session::config::{
     self, CodegenOptions, CrateType, ErrorOutputType, Externs, Input, JsonUnusedExterns,
     OptionsTargetModifiers, Sysroot, UnstableOptions, get_cmd_lint_options, nightly_options,
@@ -176,6 +177,9 @@ pub(crate) struct Options {
 
     /// Target modifiers.
     pub(crate) target_modifiers: BTreeMap<OptionsTargetModifiers, String>,
+
+    /// The log that diagnostics are collected in with `--error-format=sarif`.
+    pub(crate) sarif_log: Option<SharedLog>,
 }
 
 impl fmt::Debug for Options {
@@ -414,7 +418,9 @@ pub(crate) fn from_matches(
             }
         };
 
-        let dcx = new_dcx(error_format, None, diagnostic_width, &unstable_opts);
+        let sarif_log = matches!(error_format, ErrorOutputType::Sarif)
+            .then(|| SharedLog::new(Box::new(io::BufWriter::new(io::stderr()))));
+        let dcx = new_dcx(error_format, None, diagnostic_width, &unstable_opts, sarif_log.clone());
         let dcx = dcx.handle();
 
         // check for deprecated options
@@ -856,6 +862,7 @@ fn println_condition(condition: Condition) {
             expanded_args: args,
             doctest_build_args,
             target_modifiers,
+            sarif_log,
         };
         let render_options = RenderOptions {
             output,
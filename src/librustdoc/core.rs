@@ -10,6 +10,7 @@
     DynEmitter, HumanEmitter, HumanReadableErrorType, OutputTheme, stderr_destination,
 };
 use rustc_errors::json::JsonEmitter;
+use rustc_errors::sarif::{SarifEmitter, SharedLog};
 use rustc_feature::UnstableFeatures;
 use rustc_hir::def::Res;
 use rustc_hir::def_id::{DefId, DefIdMap, DefIdSet, LocalDefId};
@@ -141,13 +142,15 @@ pub(crate) fn is_json_output(&self) -> bool {
 
 /// Creates a new `DiagCtxt` that can be used to emit warnings and errors.
 ///
-/// If the given `error_format` is `ErrorOutputType::Json` and no `SourceMap` is given, a new one
-/// will be created for the `DiagCtxt`.
+/// If the given `error_format` is `ErrorOutputType::Json` or `ErrorOutputType::Sarif` and no
+/// `SourceMap` is given, a new one will be created for the `DiagCtxt`. With
+/// `ErrorOutputType::Sarif`, diagnostics are collected in `sarif_log`, if it is given.
 pub(crate) fn new_dcx(
     error_format: ErrorOutputType,
     source_map: Option<Arc<source_map::SourceMap>>,
     diagnostic_width: Option<usize>,
     unstable_opts: &UnstableOptions,
+    sarif_log: Option<SharedLog>,
 ) -> rustc_errors::DiagCtxt {
     let translator = rustc_driver::default_translator();
     let emitter: Box<DynEmitter> = match error_format {
@@ -186,6 +189,17 @@ pub(crate) fn new_dcx(
                 .terminal_url(TerminalUrl::No),
             )
         }
+        ErrorOutputType::Sarif => {
+            let source_map = source_map.unwrap_or_else(|| {
+                Arc::new(source_map::SourceMap::new(source_map::FilePathMapping::empty()))
+            });
+            let log = sarif_log
+                .unwrap_or_else(|| SharedLog::new(Box::new(io::BufWriter::new(io::stderr()))));
+            // The session's emitter writes the log even if nothing was emitted.
+            Box::new(
+                SarifEmitter::with_log(log, Some(source_map), translator).write_empty_log(false),
+            )
+        }
     };
 
     rustc_errors::DiagCtxt::new(emitter).with_flags(unstable_opts.dcx_flags(true))
@@ -215,6 +229,7 @@ pub(crate) fn create_config(
         expanded_args,
         remap_path_prefix,
         target_modifiers,
+        sarif_log,
         ..
     }: RustdocOptions,
     render_options: &RenderOptions,
@@ -326,6 +341,7 @@ pub(crate) fn create_config(
         ice_file: None,
         using_internal_features: &USING_INTERNAL_FEATURES,
         expanded_args,
+        sarif_log,
     }
 }
 
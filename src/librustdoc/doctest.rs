@@ -154,6 +154,7 @@ pub(crate) fn run(dcx: DiagCtxtHandle<'_>, input: Input, options: RustdocOptions
         ice_file: None,
         using_internal_features: &rustc_driver::USING_INTERNAL_FEATURES,
         expanded_args: options.expanded_args.clone(),
+        sarif_log: options.sarif_log.clone(),
     };
 
     let externs = options.externs.clone();
@@ -805,8 +805,13 @@ fn main_args(early_dcx: &mut EarlyDiagCtxt, at_args: &[String]) {
             None => return,
         };
 
-    let dcx =
-        core::new_dcx(options.error_format, None, options.diagnostic_width, &options.unstable_opts);
+    let dcx = core::new_dcx(
+        options.error_format,
+        None,
+        options.diagnostic_width,
+        &options.unstable_opts,
+        options.sarif_log.clone(),
+    );
     let dcx = dcx.handle();
 
     let input = match input {
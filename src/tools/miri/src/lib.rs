@@ -175,7 +175,7 @@ pub mod native_lib {
     "-Zmir-emit-retag",
     "-Zmir-preserve-ub",
     "-Zmir-opt-level=0",
-    "-Zmir-enable-passes=-CheckAlignment,-CheckNull,-CheckEnums",
+    "-Zmir-enable-passes=-CheckAlignment,-CheckNull,-CheckEnums,-CheckScalarValidity",
     // Deduplicating diagnostics means we miss events when tracking what happens during an
     // execution. Let's not do that.
     "-Zdeduplicate-diagnostics=no",
@@ -16,6 +16,8 @@ pub(crate) fn generate_case_mapping(data: &UnicodeData) -> String {
     file.push_str(&generate_tables("LOWER", &data.to_lower));
     file.push_str("\n\n");
     file.push_str(&generate_tables("UPPER", &data.to_upper));
+    file.push_str("\n\n");
+    file.push_str(&generate_tables("FOLD", &data.case_fold));
     file
 }
 
@@ -100,4 +102,21 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             .unwrap_or([c, '\0', '\0'])
     }
 }
+
+pub fn to_folded(c: char) -> [char; 3] {
+    if c.is_ascii() {
+        [(c as u8).to_ascii_lowercase() as char, '\0', '\0']
+    } else {
+        FOLDCASE_TABLE
+            .binary_search_by(|&(key, _)| key.cmp(&c))
+            .map(|i| {
+                let u = FOLDCASE_TABLE[i].1;
+                char::from_u32(u).map(|c| [c, '\0', '\0']).unwrap_or_else(|| {
+                    // SAFETY: Index comes from statically generated table
+                    unsafe { *FOLDCASE_TABLE_MULTI.get_unchecked((u & (INDEX_MASK - 1)) as usize) }
+                })
+            })
+            .unwrap_or([c, '\0', '\0'])
+    }
+}
 ";
@@ -71,14 +71,17 @@
 //! index of that offset is utilized as the answer to whether we're in the set
 //! or not.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::ops::Range;
+use std::path::Path;
 
 use ucd_parse::Codepoints;
 
 mod cascading_map;
 mod case_mapping;
+mod normalization;
 mod raw_emitter;
+mod segmentation;
 mod skiplist;
 mod unicode_download;
 
@@ -100,6 +103,22 @@ struct UnicodeData {
     ranges: Vec<(&'static str, Vec<Range<u32>>)>,
     to_upper: BTreeMap<u32, (u32, u32, u32)>,
     to_lower: BTreeMap<u32, (u32, u32, u32)>,
+    /// Full case folding (statuses C and F of `CaseFolding.txt`).
+    case_fold: BTreeMap<u32, (u32, u32, u32)>,
+    /// Nonzero canonical combining classes.
+    combining_class: BTreeMap<u32, u8>,
+    /// Single-level decomposition mappings, with whether they are compatibility mappings.
+    decompositions: BTreeMap<u32, (bool, Vec<u32>)>,
+    /// Codepoints listed in `CompositionExclusions.txt`.
+    composition_exclusions: BTreeSet<u32>,
+    /// `Grapheme_Cluster_Break` values, except the default `Other`.
+    grapheme_cluster_break: BTreeMap<u32, String>,
+    /// Codepoints with the `Extended_Pictographic` emoji property.
+    extended_pictographic: BTreeSet<u32>,
+    /// `Indic_Conjunct_Break` values, except the default `None`.
+    indic_conjunct_break: BTreeMap<u32, String>,
+    /// `Word_Break` values, except the default `Other`.
+    word_break: BTreeMap<u32, String>,
 }
 
 fn to_mapping(origin: u32, codepoints: Vec<ucd_parse::Codepoint>) -> Option<(u32, u32, u32)> {
@@ -145,6 +164,8 @@ fn load_data() -> UnicodeData {
 
     let mut to_lower = BTreeMap::new();
     let mut to_upper = BTreeMap::new();
+    let mut combining_class = BTreeMap::new();
+    let mut decompositions = BTreeMap::new();
     for row in ucd_parse::UnicodeDataExpander::new(
         ucd_parse::parse::<_, ucd_parse::UnicodeData>(&UNICODE_DIRECTORY).unwrap(),
     ) {
@@ -160,6 +181,17 @@ fn load_data() -> UnicodeData {
                 .push(Codepoints::Single(row.codepoint));
         }
 
+        if row.canonical_combining_class != 0 {
+            combining_class.insert(row.codepoint.value(), row.canonical_combining_class);
+        }
+        let mapping = row.decomposition.mapping();
+        if mapping != [row.codepoint] {
+            decompositions.insert(
+                row.codepoint.value(),
+                (row.decomposition.tag.is_some(), mapping.iter().map(|c| c.value()).collect()),
+            );
+        }
+
         if let Some(mapped) = row.simple_lowercase_mapping
             && mapped != row.codepoint
         {
@@ -187,6 +219,60 @@ fn load_data() -> UnicodeData {
         }
     }
 
+    let mut case_fold = BTreeMap::new();
+    for row in ucd_parse::parse::<_, ucd_parse::CaseFold>(&UNICODE_DIRECTORY).unwrap() {
+        // Simple and Turkic foldings are not part of full, default case folding.
+        if matches!(row.status, ucd_parse::CaseStatus::Common | ucd_parse::CaseStatus::Full)
+            && let Some(folded) = to_mapping(row.codepoint.value(), row.mapping)
+        {
+            case_fold.insert(row.codepoint.value(), folded);
+        }
+    }
+
+    let exclusions =
+        std::fs::read_to_string(Path::new(UNICODE_DIRECTORY).join("CompositionExclusions.txt"))
+            .unwrap();
+    let composition_exclusions = exclusions
+        .lines()
+        .map(|line| line.split('#').next().unwrap().trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| u32::from_str_radix(line, 16).unwrap())
+        .collect();
+
+    let mut grapheme_cluster_break = BTreeMap::new();
+    for row in ucd_parse::parse::<_, ucd_parse::GraphemeClusterBreak>(&UNICODE_DIRECTORY).unwrap() {
+        for c in scalars(row.codepoints) {
+            grapheme_cluster_break.insert(c, row.value.clone());
+        }
+    }
+    let mut word_break = BTreeMap::new();
+    for row in ucd_parse::parse::<_, ucd_parse::WordBreak>(&UNICODE_DIRECTORY).unwrap() {
+        for c in scalars(row.codepoints) {
+            word_break.insert(c, row.value.clone());
+        }
+    }
+    let mut extended_pictographic = BTreeSet::new();
+    for row in ucd_parse::parse::<_, ucd_parse::EmojiProperty>(&UNICODE_DIRECTORY).unwrap() {
+        if row.property == "Extended_Pictographic" {
+            extended_pictographic.extend(scalars(row.codepoints));
+        }
+    }
+
+    // `ucd_parse` doesn't know about the enumerated `InCB` property, whose lines look like
+    // `094D ; InCB; Linker # ...`.
+    let derived =
+        std::fs::read_to_string(Path::new(UNICODE_DIRECTORY).join("DerivedCoreProperties.txt"))
+            .unwrap();
+    let mut indic_conjunct_break = BTreeMap::new();
+    for line in derived.lines() {
+        let fields = line.split('#').next().unwrap().split(';').map(str::trim).collect::<Vec<_>>();
+        if let [codepoints, "InCB", value] = fields[..] {
+            for c in scalars(codepoints.parse().unwrap()) {
+                indic_conjunct_break.insert(c, value.to_owned());
+            }
+        }
+    }
+
     let mut properties: HashMap<&'static str, Vec<Range<u32>>> = properties
         .into_iter()
         .map(|(k, v)| {
@@ -215,7 +301,24 @@ fn load_data() -> UnicodeData {
 
     let mut properties = properties.into_iter().collect::<Vec<_>>();
     properties.sort_by_key(|p| p.0);
-    UnicodeData { ranges: properties, to_lower, to_upper }
+    UnicodeData {
+        ranges: properties,
+        to_lower,
+        to_upper,
+        case_fold,
+        combining_class,
+        decompositions,
+        composition_exclusions,
+        grapheme_cluster_break,
+        extended_pictographic,
+        indic_conjunct_break,
+        word_break,
+    }
+}
+
+/// The codepoints in `codepoints`, skipping surrogates.
+fn scalars(codepoints: Codepoints) -> impl Iterator<Item = u32> {
+    codepoints.into_iter().filter_map(|c| c.scalar()).map(|c| c as u32)
 }
 
 fn main() {
@@ -281,6 +384,15 @@ fn main() {
     table_file.push('\n');
 
     modules.push((String::from("conversions"), case_mapping::generate_case_mapping(&unicode_data)));
+    modules.push((
+        String::from("normalization"),
+        normalization::generate_normalization(&unicode_data),
+    ));
+    modules.push((
+        String::from("grapheme_cluster_break"),
+        segmentation::generate_grapheme_cluster_break(&unicode_data),
+    ));
+    modules.push((String::from("word_break"), segmentation::generate_word_break(&unicode_data)));
 
     for (name, contents) in modules {
         table_file.push_str("#[rustfmt::skip]\n");
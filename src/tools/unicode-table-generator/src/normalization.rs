@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use crate::{UnicodeData, fmt_list};
+
+/// Emits the tables used by `str::nfd`, `str::nfc` and their compatibility variants.
+///
+/// Decompositions are stored fully expanded (but not reordered), so lookups never recurse.
+/// Hangul syllables are decomposed and composed algorithmically and have no entries here.
+pub(crate) fn generate_normalization(data: &UnicodeData) -> String {
+    let mut file = String::new();
+
+    file.push_str(HEADER.trim_start());
+    file.push('\n');
+    file.push_str(&generate_combining_classes(&data.combining_class));
+    file.push_str("\n\n");
+
+    let canonical = full_decompositions(data, false);
+    let compatibility = full_decompositions(data, true)
+        .into_iter()
+        .filter(|(c, mapping)| canonical.get(c) != Some(mapping))
+        .collect();
+    file.push_str(&generate_decompositions("CANONICAL", &canonical));
+    file.push_str("\n\n");
+    file.push_str(&generate_decompositions("COMPATIBILITY", &compatibility));
+    file.push_str("\n\n");
+    file.push_str(&generate_compositions(data));
+    file
+}
+
+fn generate_combining_classes(classes: &BTreeMap<u32, u8>) -> String {
+    let mut ranges: Vec<(u32, u32, u8)> = Vec::new();
+    for (&c, &class) in classes {
+        match ranges.last_mut() {
+            Some((_, end, last)) if *end + 1 == c && *last == class => *end = c,
+            _ => ranges.push((c, c, class)),
+        }
+    }
+    let ranges =
+        ranges.into_iter().map(|(start, end, class)| (char_escape(start), char_escape(end), class));
+
+    let mut table = String::new();
+    write!(table, "static COMBINING_CLASS_TABLE: &[(char, char, u8)] = &[{}];", fmt_list(ranges))
+        .unwrap();
+    table
+}
+
+/// Recursively expands the decomposition mappings of every codepoint that has one.
+fn full_decompositions(data: &UnicodeData, compatibility: bool) -> BTreeMap<u32, Vec<u32>> {
+    fn expand(data: &UnicodeData, compatibility: bool, c: u32, out: &mut Vec<u32>) {
+        match data.decompositions.get(&c) {
+            Some((is_compat, mapping)) if compatibility || !is_compat => {
+                for &m in mapping {
+                    expand(data, compatibility, m, out);
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    let mut decompositions = BTreeMap::new();
+    for (&c, (is_compat, _)) in &data.decompositions {
+        if compatibility || !is_compat {
+            let mut mapping = Vec::new();
+            expand(data, compatibility, c, &mut mapping);
+            decompositions.insert(c, mapping);
+        }
+    }
+    decompositions
+}
+
+fn generate_decompositions(kind: &str, decompositions: &BTreeMap<u32, Vec<u32>>) -> String {
+    let mut entries = Vec::with_capacity(decompositions.len());
+    let mut chars = Vec::new();
+    for (&c, mapping) in decompositions {
+        let start = u16::try_from(chars.len()).unwrap();
+        let len = u8::try_from(mapping.len()).unwrap();
+        entries.push((char_escape(c), start, len));
+        chars.extend(mapping.iter().map(|&m| char_escape(m)));
+    }
+
+    let mut tables = String::new();
+    write!(
+        tables,
+        "static {kind}_DECOMPOSED_TABLE: &[(char, u16, u8)] = &[{}];",
+        fmt_list(entries)
+    )
+    .unwrap();
+    tables.push_str("\n\n");
+    write!(tables, "static {kind}_DECOMPOSED_CHARS: &[char] = &[{}];", fmt_list(chars)).unwrap();
+    tables
+}
+
+/// Primary composites: canonical pair decompositions that are not fully excluded from
+/// composition (listed exclusions and decompositions of, or starting with, a non-starter).
+fn generate_compositions(data: &UnicodeData) -> String {
+    let class = |c: u32| data.combining_class.get(&c).copied().unwrap_or(0);
+
+    let mut compositions = Vec::new();
+    for (&c, (is_compat, mapping)) in &data.decompositions {
+        if *is_compat || mapping.len() != 2 || data.composition_exclusions.contains(&c) {
+            continue;
+        }
+        if class(c) != 0 || class(mapping[0]) != 0 {
+            continue;
+        }
+        compositions.push((mapping[0], mapping[1], c));
+    }
+    compositions.sort();
+    let compositions =
+        compositions.into_iter().map(|(a, b, c)| (char_escape(a), char_escape(b), char_escape(c)));
+
+    let mut table = String::new();
+    write!(
+        table,
+        "static COMPOSITION_TABLE: &[(char, char, char)] = &[{}];",
+        fmt_list(compositions)
+    )
+    .unwrap();
+    table
+}
+
+fn char_escape(c: u32) -> CharEscape {
+    CharEscape(char::from_u32(c).unwrap())
+}
+
+struct CharEscape(char);
+
+impl fmt::Debug for CharEscape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'", self.0.escape_default())
+    }
+}
+
+static HEADER: &str = r"
+pub fn combining_class(c: char) -> u8 {
+    let i = COMBINING_CLASS_TABLE.partition_point(|&(_, end, _)| end < c);
+    match COMBINING_CLASS_TABLE.get(i) {
+        Some(&(start, _, class)) if start <= c => class,
+        _ => 0,
+    }
+}
+
+pub fn decompose_canonical(c: char) -> Option<&'static [char]> {
+    decompose(c, CANONICAL_DECOMPOSED_TABLE, CANONICAL_DECOMPOSED_CHARS)
+}
+
+pub fn decompose_compatible(c: char) -> Option<&'static [char]> {
+    decompose(c, COMPATIBILITY_DECOMPOSED_TABLE, COMPATIBILITY_DECOMPOSED_CHARS)
+        .or_else(|| decompose_canonical(c))
+}
+
+fn decompose(
+    c: char,
+    table: &[(char, u16, u8)],
+    chars: &'static [char],
+) -> Option<&'static [char]> {
+    // Nothing below U+00A0 decomposes.
+    if (c as u32) < 0xa0 {
+        return None;
+    }
+    let i = table.binary_search_by(|&(key, _, _)| key.cmp(&c)).ok()?;
+    let (_, start, len) = table[i];
+    chars.get(start as usize..start as usize + len as usize)
+}
+
+pub fn compose(a: char, b: char) -> Option<char> {
+    COMPOSITION_TABLE
+        .binary_search_by(|&(x, y, _)| (x, y).cmp(&(a, b)))
+        .ok()
+        .map(|i| COMPOSITION_TABLE[i].2)
+}
+";
@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use crate::{UnicodeData, fmt_list};
+
+/// Emits the property tables used by `str::graphemes`.
+///
+/// `Extended_Pictographic` is folded into the grapheme cluster break category, since it only
+/// ever applies to codepoints whose category is otherwise `Other`. Hangul syllables are
+/// classified algorithmically and have no entries here.
+pub(crate) fn generate_grapheme_cluster_break(data: &UnicodeData) -> String {
+    let mut categories = BTreeMap::new();
+    for (&c, value) in &data.grapheme_cluster_break {
+        if value == "LV" || value == "LVT" {
+            assert!(is_hangul_syllable(c), "{c:04X} is {value} but not a Hangul syllable");
+            continue;
+        }
+        categories.insert(c, variant_name(value));
+    }
+    for &c in &data.extended_pictographic {
+        let previous = categories.insert(c, String::from("ExtendedPictographic"));
+        assert!(previous.is_none(), "{c:04X} is Extended_Pictographic and {previous:?}");
+    }
+    let incb = data
+        .indic_conjunct_break
+        .iter()
+        .map(|(&c, value)| (c, format!("IndicConjunctBreak::{}", variant_name(value))));
+
+    let mut file = String::new();
+    file.push_str(GRAPHEME_HEADER.trim_start());
+    file.push('\n');
+    file.push_str(&generate_ranges("GRAPHEME_CAT_TABLE", "GraphemeCat", categories));
+    file.push_str("\n\n");
+    file.push_str(&generate_ranges("INCB_TABLE", "IndicConjunctBreak", incb));
+    file
+}
+
+/// Emits the property table used by `str::unicode_words`.
+pub(crate) fn generate_word_break(data: &UnicodeData) -> String {
+    let categories = data.word_break.iter().map(|(&c, value)| (c, variant_name(value)));
+
+    let mut file = String::new();
+    file.push_str(WORD_HEADER.trim_start());
+    file.push('\n');
+    file.push_str(&generate_ranges("WORD_CAT_TABLE", "WordCat", categories));
+    file
+}
+
+fn is_hangul_syllable(c: u32) -> bool {
+    (0xAC00..=0xD7A3).contains(&c)
+}
+
+/// `Regional_Indicator` becomes `RegionalIndicator`, `LVT` becomes `Lvt`.
+fn variant_name(value: &str) -> String {
+    let name = value.replace('_', "");
+    if name.len() > 1 && name.chars().all(|c| c.is_ascii_uppercase()) {
+        name[..1].to_owned() + &name[1..].to_ascii_lowercase()
+    } else {
+        name
+    }
+}
+
+/// Merges adjacent codepoints with the same value into `(start, end, value)` ranges.
+fn generate_ranges(
+    name: &str,
+    ty: &str,
+    values: impl IntoIterator<Item = (u32, String)>,
+) -> String {
+    let mut ranges: Vec<(u32, u32, String)> = Vec::new();
+    for (c, value) in values {
+        match ranges.last_mut() {
+            Some((_, end, last)) if *end + 1 == c && *last == value => *end = c,
+            _ => ranges.push((c, c, value)),
+        }
+    }
+    let ranges = ranges
+        .into_iter()
+        .map(|(start, end, value)| (char_escape(start), char_escape(end), Variant(value)));
+
+    let mut table = String::new();
+    write!(table, "static {name}: &[(char, char, {ty})] = &[{}];", fmt_list(ranges)).unwrap();
+    table
+}
+
+fn char_escape(c: u32) -> CharEscape {
+    CharEscape(char::from_u32(c).unwrap())
+}
+
+struct CharEscape(char);
+
+impl fmt::Debug for CharEscape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'", self.0.escape_default())
+    }
+}
+
+struct Variant(String);
+
+impl fmt::Debug for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+static GRAPHEME_HEADER: &str = r"
+use GraphemeCat::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphemeCat {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndicConjunctBreak {
+    None,
+    Linker,
+    Consonant,
+    Extend,
+}
+
+pub fn lookup(c: char) -> GraphemeCat {
+    // Hangul syllables are LV unless they have a trailing consonant.
+    let s_index = (c as u32).wrapping_sub(0xAC00);
+    if s_index < 11172 {
+        return if s_index % 28 == 0 { Lv } else { Lvt };
+    }
+    search(GRAPHEME_CAT_TABLE, c).unwrap_or(Other)
+}
+
+pub fn indic_conjunct_break(c: char) -> IndicConjunctBreak {
+    search(INCB_TABLE, c).unwrap_or(IndicConjunctBreak::None)
+}
+
+fn search<T: Copy>(table: &[(char, char, T)], c: char) -> Option<T> {
+    let i = table.partition_point(|&(_, end, _)| end < c);
+    match table.get(i) {
+        Some(&(start, _, value)) if start <= c => Some(value),
+        _ => None,
+    }
+}
+";
+
+static WORD_HEADER: &str = r"
+use WordCat::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WordCat {
+    Other,
+    Cr,
+    Lf,
+    Newline,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Format,
+    Katakana,
+    HebrewLetter,
+    ALetter,
+    SingleQuote,
+    DoubleQuote,
+    MidNumLet,
+    MidLetter,
+    MidNum,
+    Numeric,
+    ExtendNumLet,
+    WSegSpace,
+}
+
+pub fn lookup(c: char) -> WordCat {
+    let i = WORD_CAT_TABLE.partition_point(|&(_, end, _)| end < c);
+    match WORD_CAT_TABLE.get(i) {
+        Some(&(start, _, category)) if start <= c => category,
+        _ => Other,
+    }
+}
+";
@@ -7,8 +7,17 @@
 
 static README: &str = "ReadMe.txt";
 
-static RESOURCES: &[&str] =
-    &["DerivedCoreProperties.txt", "PropList.txt", "UnicodeData.txt", "SpecialCasing.txt"];
+static RESOURCES: &[&str] = &[
+    "DerivedCoreProperties.txt",
+    "PropList.txt",
+    "UnicodeData.txt",
+    "SpecialCasing.txt",
+    "CaseFolding.txt",
+    "CompositionExclusions.txt",
+    "auxiliary/GraphemeBreakProperty.txt",
+    "auxiliary/WordBreakProperty.txt",
+    "emoji/emoji-data.txt",
+];
 
 #[track_caller]
 fn fetch(url: &str) -> Output {
@@ -41,6 +50,8 @@ pub fn fetch_latest() {
 
     for resource in RESOURCES {
         let output = fetch(resource);
-        std::fs::write(directory.join(resource), output.stdout).unwrap();
+        let path = directory.join(resource);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, output.stdout).unwrap();
     }
 }
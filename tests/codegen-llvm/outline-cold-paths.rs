@@ -0,0 +1,33 @@
+// Checks that the blocks dominated by a call to a `#[cold]` function, which end in a panic,
+// are moved out of the function into a `cold` and `noinline` shim.
+
+//@ revisions: CALLER SHIM
+//@ compile-flags: -Copt-level=0 -Zmir-opt-level=3
+
+#![crate_type = "lib"]
+
+#[cold]
+#[inline(never)]
+fn log_error(code: u32) {
+    std::hint::black_box(code);
+}
+
+// CALLER-LABEL: define{{.*}} i32 @{{.*}}checked_sum
+// CALLER-NOT: log_error
+// CALLER: call void @{{[^(]*}}cold{{.}}path{{[^(]*}}(ptr
+// CALLER-NEXT: unreachable
+// CALLER-NOT: log_error
+// CALLER: ret i32
+
+// SHIM: define internal {{.*}}void @{{.*}}checked_sum{{.*}}cold{{.}}path{{.*}} #[[ATTRS:[0-9]+]]
+// SHIM: call void @{{.*}}log_error
+// SHIM: call void @{{.*}}log_error
+// SHIM: attributes #[[ATTRS]] = { {{.*}}cold{{.*}}noinline
+pub fn checked_sum(a: u32, b: u32) -> u32 {
+    if a > 1000 || b > 1000 {
+        log_error(a);
+        log_error(b);
+        panic!("operands out of range: {a}, {b}");
+    }
+    a + b
+}
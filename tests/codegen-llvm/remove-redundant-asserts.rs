@@ -0,0 +1,60 @@
+// Checks that the bounds checks and overflow checks that the value ranges of MIR locals prove to
+// be redundant are removed by `RemoveRedundantAsserts`, without relying on LLVM optimizations.
+
+//@ compile-flags: -Copt-level=0 -Coverflow-checks=on -Zmir-enable-passes=+RemoveRedundantAsserts
+
+#![crate_type = "lib"]
+
+// CHECK-LABEL: @loop_over_array
+#[no_mangle]
+pub fn loop_over_array(array: [u8; 8]) -> u8 {
+    // CHECK-NOT: panic_bounds_check
+    // CHECK-NOT: panic_const_add_overflow
+    let mut max = 0;
+    let mut i = 0;
+    while i < 8 {
+        if array[i] > max {
+            max = array[i];
+        }
+        i += 1;
+    }
+    max
+}
+
+// CHECK-LABEL: @loop_over_slice
+#[no_mangle]
+pub fn loop_over_slice(slice: &[u8]) -> u8 {
+    // CHECK-NOT: panic_bounds_check
+    // CHECK-NOT: panic_const_add_overflow
+    let mut max = 0;
+    let mut i = 0;
+    while i < slice.len() {
+        if slice[i] > max {
+            max = slice[i];
+        }
+        i += 1;
+    }
+    max
+}
+
+// CHECK-LABEL: @masked_index
+#[no_mangle]
+pub fn masked_index(array: [u8; 8], i: usize) -> u8 {
+    // CHECK-NOT: panic_bounds_check
+    // CHECK-NOT: panic_const_rem_by_zero
+    array[i & 7] + array[i % 8]
+}
+
+// CHECK-LABEL: @widened_add
+#[no_mangle]
+pub fn widened_add(a: u8, b: u8) -> u16 {
+    // CHECK-NOT: panic_const_add_overflow
+    a as u16 + b as u16
+}
+
+// CHECK-LABEL: @unknown_index
+#[no_mangle]
+pub fn unknown_index(array: [u8; 8], i: usize) -> u8 {
+    // CHECK: panic_bounds_check
+    array[i]
+}
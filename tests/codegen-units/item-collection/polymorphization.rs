@@ -0,0 +1,64 @@
+//@ compile-flags:-Clink-dead-code -Zinline-mir=no -Zpolymorphize
+
+// With -Zpolymorphize, the type parameters that a function does not use are replaced with `()`,
+// so that the instantiations which only differ in those parameters share a single mono item.
+
+#![deny(dead_code)]
+#![crate_type = "lib"]
+
+fn unused<T>() -> u32 {
+    1
+}
+
+fn partially_used<T, U>(a: U) -> U {
+    a
+}
+
+fn used<T>(a: T) -> T {
+    a
+}
+
+// Parameters used behind a pointer are still used.
+fn used_behind_pointer<T>(a: &T) -> *const T {
+    a
+}
+
+trait Trait {
+    fn method<T>(&self) -> u32;
+}
+
+impl Trait for u32 {
+    fn method<T>(&self) -> u32 {
+        *self
+    }
+}
+
+//~ MONO_ITEM fn start
+#[no_mangle]
+pub fn start(_: isize, _: *const *const u8) -> isize {
+    //~ MONO_ITEM fn unused::<()>
+    let _ = unused::<u8>();
+    let _ = unused::<&str>();
+
+    //~ MONO_ITEM fn partially_used::<(), u32>
+    let _ = partially_used::<u8, u32>(1);
+    let _ = partially_used::<char, u32>(1);
+    //~ MONO_ITEM fn partially_used::<(), char>
+    let _ = partially_used::<u8, char>('a');
+
+    //~ MONO_ITEM fn used::<u8>
+    let _ = used(1u8);
+    //~ MONO_ITEM fn used::<char>
+    let _ = used('a');
+
+    //~ MONO_ITEM fn used_behind_pointer::<u8>
+    let _ = used_behind_pointer(&1u8);
+    //~ MONO_ITEM fn used_behind_pointer::<char>
+    let _ = used_behind_pointer(&'a');
+
+    //~ MONO_ITEM fn <u32 as Trait>::method::<()>
+    let _ = 1u32.method::<u8>();
+    let _ = 1u32.method::<char>();
+
+    0
+}
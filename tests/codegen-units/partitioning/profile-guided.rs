@@ -0,0 +1,27 @@
+//@ incremental
+//@ compile-flags: -Copt-level=0
+//@ compile-flags: -Zcgu-partitioning-profile={{src-base}}/partitioning/auxiliary/profile-guided.txt
+
+#![crate_type = "lib"]
+
+// This test checks that with a profile, a hot callee is placed in the CGU of its caller, while
+// a cold one stays in the CGU of its module.
+
+pub mod hot {
+    //~ MONO_ITEM fn hot::entry @@ profile_guided-hot[External]
+    #[no_mangle]
+    pub fn entry() {
+        crate::helpers::helper();
+        crate::helpers::rarely();
+    }
+}
+
+pub mod helpers {
+    //~ MONO_ITEM fn helpers::helper @@ profile_guided-hot[External]
+    #[no_mangle]
+    pub fn helper() {}
+
+    //~ MONO_ITEM fn helpers::rarely @@ profile_guided-helpers[External]
+    #[no_mangle]
+    pub fn rarely() {}
+}
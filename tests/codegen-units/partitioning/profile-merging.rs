@@ -0,0 +1,51 @@
+//@ incremental
+//@ compile-flags: -Copt-level=0 -Ccodegen-units=2
+//@ compile-flags: -Zcgu-partitioning-profile={{src-base}}/partitioning/auxiliary/merging.txt
+
+#![crate_type = "rlib"]
+
+// This test checks that with a profile, codegen units are merged with the ones they call the
+// most, rather than in order of size.
+//
+// There is one CGU per module, so with 4 modules and codegen-units=2, `ccc` is merged first.
+// Without a profile, it would be merged with `bbb`, the biggest of the smaller CGUs, and `ddd`
+// with `aaa`. The edge between `ccc` and `aaa` is too cold to move `aaa_foo` into `ccc`, but it
+// makes `ccc` merge with `aaa` instead, and `ddd` with `bbb`. The hottest edge is within `ddd`.
+
+pub mod aaa {
+    //~ MONO_ITEM fn aaa::aaa_foo @@ profile_merging-aaa--profile_merging-ccc[External]
+    #[no_mangle]
+    pub fn aaa_foo(a: u64) -> u64 {
+        a + 1
+    }
+}
+
+pub mod bbb {
+    //~ MONO_ITEM fn bbb::bbb_foo @@ profile_merging-bbb--profile_merging-ddd[External]
+    #[no_mangle]
+    pub fn bbb_foo(a: u64, b: u64) -> u64 {
+        a + b + 1
+    }
+}
+
+pub mod ccc {
+    //~ MONO_ITEM fn ccc::ccc_foo @@ profile_merging-aaa--profile_merging-ccc[External]
+    #[no_mangle]
+    pub fn ccc_foo(a: u64, b: u64, c: u64) -> u64 {
+        crate::aaa::aaa_foo(a) + b + c + 1
+    }
+}
+
+pub mod ddd {
+    //~ MONO_ITEM fn ddd::ddd_hot @@ profile_merging-bbb--profile_merging-ddd[External]
+    #[no_mangle]
+    pub fn ddd_hot(a: u64, b: u64, c: u64, d: u64) -> u64 {
+        ddd_callee(a, b) + c + d + 1
+    }
+
+    //~ MONO_ITEM fn ddd::ddd_callee @@ profile_merging-bbb--profile_merging-ddd[External]
+    #[no_mangle]
+    pub fn ddd_callee(a: u64, b: u64) -> u64 {
+        a + b
+    }
+}
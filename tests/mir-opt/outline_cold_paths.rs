@@ -0,0 +1,137 @@
+//@ test-mir-pass: OutlineColdPaths
+
+// Checks which locals are passed to the shim of a cold path, by value or through a pointer, and
+// that small cold paths and generic functions are left alone.
+
+#![feature(custom_mir, core_intrinsics)]
+
+use std::intrinsics::mir::*;
+
+#[cold]
+#[inline(never)]
+fn fail(code: u32) {
+    std::hint::black_box(code);
+}
+
+// EMIT_MIR outline_cold_paths.shared_value.OutlineColdPaths.diff
+// EMIT_MIR outline_cold_paths.shared_value.cold-path-0.SimplifyCfg-make_shim.after.mir
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn shared_value(x: u32, y: u32) -> u32 {
+    // CHECK-LABEL: fn shared_value(
+    // CHECK: bb1: {
+    // CHECK-NEXT: = const {shared_value as fn(u32) -> !}(copy _2) -> unwind unreachable;
+    mir! {
+        let a: u32;
+        let b: u32;
+        let c: ();
+        {
+            match x {
+                0 => bb1,
+                _ => bb2,
+            }
+        }
+        bb1 = {
+            a = y;
+            b = a;
+            Call(c = fail(b), ReturnTo(bb3), UnwindUnreachable())
+        }
+        bb2 = {
+            RET = x;
+            Return()
+        }
+        bb3 = {
+            Unreachable()
+        }
+    }
+}
+
+// EMIT_MIR outline_cold_paths.shared_pointer.OutlineColdPaths.diff
+// EMIT_MIR outline_cold_paths.shared_pointer.cold-path-0.SimplifyCfg-make_shim.after.mir
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn shared_pointer(x: u32, y: u32) -> u32 {
+    // CHECK-LABEL: fn shared_pointer(
+    // CHECK: bb1: {
+    // CHECK-NEXT: [[ptr:_.*]] = &raw mut _2;
+    // CHECK-NEXT: = const {shared_pointer as fn(*mut u32) -> !}(move [[ptr]]) -> unwind
+    mir! {
+        let r: &u32;
+        let a: u32;
+        let c: ();
+        {
+            match x {
+                0 => bb1,
+                _ => bb2,
+            }
+        }
+        bb1 = {
+            r = &y;
+            a = *r;
+            Call(c = fail(a), ReturnTo(bb3), UnwindUnreachable())
+        }
+        bb2 = {
+            RET = x;
+            Return()
+        }
+        bb3 = {
+            Unreachable()
+        }
+    }
+}
+
+// EMIT_MIR outline_cold_paths.too_small.OutlineColdPaths.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn too_small(x: u32) -> u32 {
+    // CHECK-LABEL: fn too_small(
+    // CHECK: bb1: {
+    // CHECK-NEXT: = fail(copy _1) -> [return: bb3, unwind unreachable];
+    mir! {
+        let c: ();
+        {
+            match x {
+                0 => bb1,
+                _ => bb2,
+            }
+        }
+        bb1 = {
+            Call(c = fail(x), ReturnTo(bb3), UnwindUnreachable())
+        }
+        bb2 = {
+            RET = x;
+            Return()
+        }
+        bb3 = {
+            Unreachable()
+        }
+    }
+}
+
+// EMIT_MIR outline_cold_paths.generic.OutlineColdPaths.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn generic<T>(x: u32, y: u32) -> u32 {
+    // CHECK-LABEL: fn generic(
+    // CHECK: bb1: {
+    // CHECK: = fail(copy _4) -> [return: bb3, unwind unreachable];
+    mir! {
+        let a: u32;
+        let b: u32;
+        let c: ();
+        {
+            match x {
+                0 => bb1,
+                _ => bb2,
+            }
+        }
+        bb1 = {
+            a = y;
+            b = a;
+            Call(c = fail(b), ReturnTo(bb3), UnwindUnreachable())
+        }
+        bb2 = {
+            RET = x;
+            Return()
+        }
+        bb3 = {
+            Unreachable()
+        }
+    }
+}
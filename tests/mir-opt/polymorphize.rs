@@ -0,0 +1,31 @@
+// skip-filecheck
+//@ compile-flags: -Zmir-opt-level=0 -Zpolymorphize
+
+// Checks which type parameters the polymorphization analysis finds unused.
+
+// EMIT_MIR polymorphize.unused.polymorphize.0.mir
+pub fn unused<T>() -> u32 {
+    1
+}
+
+// EMIT_MIR polymorphize.used.polymorphize.0.mir
+pub fn used<T>(x: T) -> T {
+    x
+}
+
+// EMIT_MIR polymorphize.partially_used.polymorphize.0.mir
+pub fn partially_used<T, U>(x: U) -> U {
+    x
+}
+
+// Parameters only used behind a pointer are still used.
+// EMIT_MIR polymorphize.used_behind_pointer.polymorphize.0.mir
+pub fn used_behind_pointer<T>(x: &T) -> &T {
+    x
+}
+
+// The promoted constant is instantiated with `T`, but does not use it.
+// EMIT_MIR polymorphize.promoted.polymorphize.0.mir
+pub fn promoted<T>() -> &'static u32 {
+    &42
+}
@@ -0,0 +1,56 @@
+//@ test-mir-pass: RemoveRedundantAsserts
+//@ compile-flags: -Zmir-enable-passes=+LowerSliceLenCalls -Coverflow-checks=on
+// EMIT_MIR_FOR_EACH_PANIC_STRATEGY
+
+// EMIT_MIR remove_redundant_asserts.index_below_len.RemoveRedundantAsserts.after.mir
+pub fn index_below_len(index: usize, slice: &[u8]) -> u8 {
+    // CHECK-LABEL: fn index_below_len(
+    // CHECK-NOT: assert(
+    if index < slice.len() { slice[index] } else { 42 }
+}
+
+// EMIT_MIR remove_redundant_asserts.increment_below_len.RemoveRedundantAsserts.after.mir
+pub fn increment_below_len(index: usize, slice: &[u8]) -> usize {
+    // CHECK-LABEL: fn increment_below_len(
+    // CHECK-NOT: assert(
+    if index < slice.len() { index + 1 } else { 0 }
+}
+
+// EMIT_MIR remove_redundant_asserts.index_up_to_len.RemoveRedundantAsserts.after.mir
+pub fn index_up_to_len(index: usize, slice: &[u8]) -> u8 {
+    // CHECK-LABEL: fn index_up_to_len(
+    // CHECK: assert(
+    if index <= slice.len() { slice[index] } else { 42 }
+}
+
+// EMIT_MIR remove_redundant_asserts.slice_reassigned.RemoveRedundantAsserts.after.mir
+pub fn slice_reassigned<'a>(index: usize, mut slice: &'a [u8], other: &'a [u8]) -> u8 {
+    // CHECK-LABEL: fn slice_reassigned(
+    // CHECK: assert({{.*}}"index out of bounds
+    if index < slice.len() {
+        slice = other;
+        slice[index]
+    } else {
+        42
+    }
+}
+
+// EMIT_MIR remove_redundant_asserts.index_incremented.RemoveRedundantAsserts.after.mir
+pub fn index_incremented(mut index: usize, slice: &[u8]) -> u8 {
+    // CHECK-LABEL: fn index_incremented(
+    // CHECK: assert({{.*}}"index out of bounds
+    if index < slice.len() {
+        index += 1;
+        slice[index]
+    } else {
+        42
+    }
+}
+
+fn main() {
+    let _ = index_below_len(1, &[1, 2, 3]);
+    let _ = increment_below_len(1, &[1, 2, 3]);
+    let _ = index_up_to_len(1, &[1, 2, 3]);
+    let _ = slice_reassigned(1, &[1, 2, 3], &[1]);
+    let _ = index_incremented(1, &[1, 2, 3]);
+}
@@ -0,0 +1,12 @@
+pub fn root() {
+    outer(1u8);
+    outer("a");
+}
+
+fn outer<T: Copy>(x: T) -> T {
+    inner(x)
+}
+
+fn inner<T: Copy>(x: T) -> T {
+    x
+}
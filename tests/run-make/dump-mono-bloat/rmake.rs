@@ -0,0 +1,20 @@
+//@ needs-target-std
+//
+// -Zdump-mono-bloat groups the instantiations of generic items and shows which uses caused
+// them. This test checks that the generic functions are reported, and that the instantiations
+// of `inner` are traced back to the non-generic `root` through `outer`.
+
+use run_make_support::{cwd, rfs, rustc};
+
+fn main() {
+    rustc()
+        .crate_type("lib")
+        .input("foo.rs")
+        .arg(format!("-Zdump-mono-bloat={}", cwd().display()))
+        .arg("-Zdump-mono-stats-format=json")
+        .run();
+    let report = rfs::read_to_string("foo.mono_bloat.json");
+    assert!(report.contains(r#""name":"inner","instantiation_count":2"#));
+    assert!(report.contains(r#""name":"outer","instantiation_count":2"#));
+    assert!(report.contains(r#""used_by":["fn root","fn outer::<u8>","fn inner::<u8>"]"#));
+}
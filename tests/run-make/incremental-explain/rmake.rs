@@ -0,0 +1,45 @@
+//@ needs-target-std
+//
+// -Zincremental-explain writes, for every query of the previous session that had to be
+// evaluated again, the chain of red dependencies that forced it. This test edits one function
+// and checks that the queries of that function are traced back to a changed input, and that
+// changing the command-line arguments is reported as discarding the whole previous session.
+
+use run_make_support::serde_json::Value;
+use run_make_support::{rfs, rustc, serde_json};
+
+fn compile(source: &str, opt_level: &str) -> Value {
+    rfs::write("foo.rs", source);
+    rustc()
+        .input("foo.rs")
+        .crate_type("lib")
+        .incremental("incremental")
+        .opt_level(opt_level)
+        .arg("-Zincremental-explain=explain.json")
+        .run();
+    serde_json::from_str(&rfs::read_to_string("explain.json")).unwrap()
+}
+
+fn typeck_of<'a>(explanation: &'a Value, function: &str) -> Option<&'a Value> {
+    explanation["reexecutions"].as_array().unwrap().iter().find(|reexecution| {
+        let query = reexecution["query"].as_str().unwrap();
+        query.starts_with("typeck(") && query.ends_with(&format!("::{function})"))
+    })
+}
+
+fn main() {
+    let first = compile("pub fn changed() -> u32 { 1 }\npub fn unchanged() -> u32 { 2 }\n", "0");
+    assert_eq!(first["discarded_previous_session"], Value::Null);
+    assert_eq!(first["reexecutions"], Value::Array(vec![]));
+
+    let second = compile("pub fn changed() -> u32 { 3 }\npub fn unchanged() -> u32 { 2 }\n", "0");
+    assert_eq!(second["discarded_previous_session"], Value::Null);
+    let typeck = typeck_of(&second, "changed").expect("`changed` was not type-checked again");
+    assert_eq!(typeck["cause"], "input changed");
+    assert!(!typeck["red_dependencies"].as_array().unwrap().is_empty());
+    assert!(typeck_of(&second, "unchanged").is_none());
+
+    let third = compile("pub fn changed() -> u32 { 3 }\npub fn unchanged() -> u32 { 2 }\n", "1");
+    assert_eq!(third["discarded_previous_session"], "the command-line arguments changed");
+    assert_eq!(third["reexecutions"], Value::Array(vec![]));
+}
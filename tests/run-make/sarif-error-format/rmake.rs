@@ -0,0 +1,70 @@
+//@ needs-target-std
+//
+// `--error-format=sarif` writes the diagnostics of the whole invocation as a single SARIF log.
+// This test checks that the log parses, that an early warning about the command line ends up in
+// the same log as the diagnostics about the crate, and that lints get their rule ids, machine
+// applicable suggestions their `fixes`, and secondary spans their `relatedLocations`.
+
+use run_make_support::serde_json::Value;
+use run_make_support::{rustc, serde_json};
+
+fn main() {
+    let output = rustc()
+        .input("main.rs")
+        .arg("-Zunstable-options")
+        .error_format("sarif")
+        .arg("-Car=deprecated")
+        .run();
+
+    // The whole output is one document, which would not parse if several logs were written.
+    let log: Value = serde_json::from_str(&output.stderr_utf8()).unwrap();
+    assert_eq!(log["version"], "2.1.0");
+    let runs = log["runs"].as_array().unwrap();
+    assert_eq!(runs.len(), 1);
+    let results = runs[0]["results"].as_array().unwrap();
+    let result = |prefix: &str| {
+        results
+            .iter()
+            .find(|result| {
+                result["message"]["text"].as_str().is_some_and(|text| text.starts_with(prefix))
+            })
+            .unwrap_or_else(|| panic!("no result starting with {prefix:?} in {results:#?}"))
+    };
+
+    let early = result("`-C ar`");
+    assert_eq!(early["level"], "warning");
+    assert!(early.get("locations").is_none_or(|locations| locations == &Value::Array(vec![])));
+
+    let parens = result("unnecessary parentheses");
+    assert_eq!(parens["ruleId"], "unused_parens");
+    assert_eq!(parens["level"], "warning");
+    let fixes = parens["fixes"].as_array().unwrap();
+    assert_eq!(fixes.len(), 1);
+    let changes = fixes[0]["artifactChanges"].as_array().unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0]["artifactLocation"]["uri"], "main.rs");
+    // Both parentheses are removed.
+    let replacements = changes[0]["replacements"].as_array().unwrap();
+    assert_eq!(replacements.len(), 2);
+    for (replacement, column) in replacements.iter().zip([17, 19]) {
+        assert_eq!(replacement["insertedContent"]["text"], "");
+        assert_eq!(replacement["deletedRegion"]["startLine"], 2);
+        assert_eq!(replacement["deletedRegion"]["startColumn"], column);
+        assert_eq!(replacement["deletedRegion"]["byteLength"], 1);
+    }
+
+    let unreachable = result("unreachable statement");
+    assert_eq!(unreachable["ruleId"], "unreachable_code");
+    assert_eq!(unreachable["locations"][0]["physicalLocation"]["region"]["startLine"], 4);
+    let related = &unreachable["relatedLocations"][0];
+    assert_eq!(related["message"]["text"], "any code following this expression is unreachable");
+    assert_eq!(related["physicalLocation"]["region"]["startLine"], 3);
+
+    // Every rule id refers to a rule of the tool.
+    let rules = runs[0]["tool"]["driver"]["rules"].as_array().unwrap();
+    for result in results {
+        if let Some(index) = result["ruleIndex"].as_u64() {
+            assert_eq!(rules[index as usize]["id"], result["ruleId"]);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//@ needs-target-std
+//
+// -Zself-profile-format=chrome-json writes the self-profiling events as a Chrome trace, instead
+// of measureme data. This test checks that query events are complete events named after their
+// query, with their key as an argument when query keys are recorded, and that the threads that
+// recorded events get named tracks.
+
+use run_make_support::serde_json::Value;
+use run_make_support::{has_extension, rfs, rustc, serde_json, shallow_find_files};
+
+fn main() {
+    rfs::write("foo.rs", "pub fn foo() -> u32 { 1 }\n");
+    rustc()
+        .input("foo.rs")
+        .crate_type("lib")
+        .arg("-Zself-profile=profile")
+        .arg("-Zself-profile-format=chrome-json")
+        .arg("-Zself-profile-events=default,query-keys")
+        .run();
+
+    let traces = shallow_find_files("profile", |path| has_extension(path, "json"));
+    assert_eq!(traces.len(), 1, "expected a single trace, found {traces:?}");
+    assert!(shallow_find_files("profile", |path| has_extension(path, "mm_profdata")).is_empty());
+
+    let trace: Value = serde_json::from_str(&rfs::read_to_string(&traces[0])).unwrap();
+    let events = trace["traceEvents"].as_array().unwrap();
+
+    assert!(
+        events.iter().any(|event| event["ph"] == "M" && event["name"] == "thread_name"),
+        "no thread has a named track"
+    );
+
+    let typeck = events
+        .iter()
+        .find(|event| {
+            event["ph"] == "X"
+                && event["cat"] == "Query"
+                && event["name"] == "typeck"
+                && event["args"]["key"].as_str().is_some_and(|key| key.ends_with("foo"))
+        })
+        .expect("no event for the type-checking of `foo`");
+    assert!(typeck["dur"].is_number());
+    assert!(typeck["tid"].is_u64());
+}
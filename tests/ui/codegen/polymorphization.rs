@@ -0,0 +1,56 @@
+// Checks that the instances which polymorphization redirects to a shared instance link, and
+// behave as if they had not been redirected.
+//@ run-pass
+//@ compile-flags: -Zpolymorphize
+
+// `T` is unused, so this is only instantiated once.
+fn unused<T>() -> u32 {
+    1
+}
+
+// The closure captures `x`, so `F` is used, but `T` is not.
+fn call<T, F: Fn(u32) -> u32>(f: F, x: u32) -> u32 {
+    f(x)
+}
+
+// The closure is generic over `T` too, so `T` is used here.
+fn make_closure<T: Default + ToString>() -> impl Fn() -> String {
+    || T::default().to_string()
+}
+
+// The promoted constant is instantiated with `T`, but does not use it.
+fn promoted<T>() -> &'static u32 {
+    &42
+}
+
+// The promoted constant mentions `T`, so `T` is used.
+fn promoted_used<T>() -> &'static Option<T> {
+    &None
+}
+
+fn main() {
+    assert_eq!(unused::<u8>(), 1);
+    assert_eq!(unused::<String>(), 1);
+
+    let f: fn() -> u32 = unused::<Vec<u16>>;
+    assert_eq!(f(), 1);
+    let g: fn() -> u32 = unused::<()>;
+    assert_eq!(g(), 1);
+
+    let offset = 3;
+    let add = move |x| x + offset;
+    assert_eq!(call::<u8, _>(add, 1), 4);
+    assert_eq!(call::<String, _>(add, 2), 5);
+    assert_eq!(call::<(), _>(|x| x * 2, 3), 6);
+
+    assert_eq!(make_closure::<u8>()(), "0");
+    assert_eq!(make_closure::<String>()(), "");
+    assert_eq!(make_closure::<bool>()(), "false");
+
+    assert_eq!(*promoted::<u8>(), 42);
+    assert_eq!(*promoted::<String>(), 42);
+    assert!(std::ptr::eq(promoted::<u8>(), promoted::<String>()));
+
+    assert!(promoted_used::<u64>().is_none());
+    assert!(promoted_used::<String>().is_none());
+}
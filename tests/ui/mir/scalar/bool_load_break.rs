@@ -0,0 +1,9 @@
+//@ run-crash
+//@ compile-flags: -C debug-assertions
+//@ error-pattern: constructing an invalid value 0x2, expected a value in 0x0..=0x1
+
+fn main() {
+    let byte = 2u8;
+    let ptr = &raw const byte as *const bool;
+    let _val: bool = unsafe { *ptr };
+}
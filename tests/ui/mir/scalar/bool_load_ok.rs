@@ -0,0 +1,11 @@
+//@ run-pass
+//@ compile-flags: -C debug-assertions
+
+fn main() {
+    let bytes = [0u8, 1u8];
+    let ptr = &raw const bytes as *const [bool; 2];
+    let val: [bool; 2] = unsafe { *ptr };
+    assert_eq!(val, [false, true]);
+    let ptr = &raw const bytes[1] as *const bool;
+    assert!(unsafe { *ptr });
+}
@@ -0,0 +1,15 @@
+//@ run-crash
+//@ compile-flags: -C debug-assertions
+//@ error-pattern: constructing an invalid value 0x2, expected a value in 0x0..=0x1
+
+fn main() {
+    let byte = 2u8;
+    let ptr = &raw const byte as *const bool;
+    // The match switches on the place behind the pointer, so the read is in the terminator.
+    let _val = unsafe {
+        match *ptr {
+            true => 1,
+            false => 0,
+        }
+    };
+}
@@ -0,0 +1,7 @@
+//@ run-crash
+//@ compile-flags: -C debug-assertions
+//@ error-pattern: constructing an invalid value 0x110000, expected a value in 0x0..=0x10ffff
+
+fn main() {
+    let _val: char = unsafe { std::mem::transmute::<u32, char>(0x110000) };
+}
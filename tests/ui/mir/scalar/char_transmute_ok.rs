@@ -0,0 +1,9 @@
+//@ run-pass
+//@ compile-flags: -C debug-assertions
+
+fn main() {
+    let val: char = unsafe { std::mem::transmute::<u32, char>(0x10ffff) };
+    assert_eq!(val, char::MAX);
+    let val: char = unsafe { std::mem::transmute::<u32, char>(0x61) };
+    assert_eq!(val, 'a');
+}
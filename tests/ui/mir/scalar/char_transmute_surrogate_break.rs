@@ -0,0 +1,7 @@
+//@ run-crash
+//@ compile-flags: -C debug-assertions
+//@ error-pattern: constructing an invalid value 0xd800, expected a value in 0xe000..=0xd7ff
+
+fn main() {
+    let _val: char = unsafe { std::mem::transmute::<u32, char>(0xD800) };
+}
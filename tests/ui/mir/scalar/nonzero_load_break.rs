@@ -0,0 +1,15 @@
+//@ run-crash
+//@ compile-flags: -C debug-assertions
+//@ error-pattern: constructing an invalid value 0x0, expected a value in 0x1..=0xff
+
+use std::num::NonZero;
+
+struct Wrapper {
+    value: NonZero<u8>,
+}
+
+fn main() {
+    let bytes = [0u8];
+    let ptr = &raw const bytes as *const Wrapper;
+    let _val: NonZero<u8> = unsafe { (*ptr).value };
+}
@@ -0,0 +1,9 @@
+//@ run-crash
+//@ compile-flags: -C debug-assertions
+//@ error-pattern: constructing an invalid value 0x0, expected a value in 0x1..=0xffffffff
+
+use std::num::NonZero;
+
+fn main() {
+    let _val: NonZero<u32> = unsafe { std::mem::transmute::<u32, NonZero<u32>>(0) };
+}
@@ -0,0 +1,11 @@
+//@ run-pass
+//@ compile-flags: -C debug-assertions
+
+use std::num::NonZero;
+
+fn main() {
+    let val: NonZero<u32> = unsafe { std::mem::transmute::<u32, NonZero<u32>>(u32::MAX) };
+    assert_eq!(val.get(), u32::MAX);
+    let val: NonZero<i8> = unsafe { std::mem::transmute::<i8, NonZero<i8>>(-1) };
+    assert_eq!(val.get(), -1);
+}
@@ -0,0 +1,7 @@
+//@ run-crash
+//@ compile-flags: -C debug-assertions
+//@ error-pattern: constructing an invalid value 0x0, expected a value in 0x1..=
+
+fn main() {
+    let _val: &u8 = unsafe { std::mem::transmute::<usize, &u8>(0) };
+}
@@ -0,0 +1,32 @@
+//@ compile-flags: -Z print-type-sizes -Z print-type-sizes-format=json --crate-type=lib
+//@ build-pass
+//@ ignore-pass
+//  ^-- needed because `--pass check` does not emit the output needed.
+
+// This file illustrates the JSON form of `-Z print-type-sizes`, which also describes how the
+// variant of an enum is encoded, the largest niche of each type, and where its padding is.
+//
+// It avoids using u64/i64 because on some targets that is only 4-byte
+// aligned (while on most it is 8-byte aligned) and so the resulting
+// padding and overall computed sizes can be quite different.
+
+#![allow(dead_code)]
+
+struct S {
+    a: u8,
+    g: i32,
+}
+
+enum E1 {
+    A(i32, i8),
+    B(S),
+}
+
+pub enum MyOption<T> {
+    None,
+    Some(T),
+}
+
+pub fn test() {
+    let _x: MyOption<bool> = MyOption::None;
+}